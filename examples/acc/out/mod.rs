@@ -4,6 +4,7 @@ enum Activation {
     On,
     Off,
 }
+const _: fn(f64) -> f64 = utils::convert;
 fn safety_distance(sv_v: f64, fv_v: f64) -> f64 {
     let sv_d_stop = (sv_v * 1.0f64) + ((sv_v * sv_v) / (2.0f64 * (0.6f64 * 9.81f64)));
     let fv_d_stop = (fv_v * fv_v) / (2.0f64 * (0.6f64 * 9.81f64));
@@ -25,6 +26,7 @@ impl grust::core::Component for AccState {
     fn init() -> AccState {
         AccState {}
     }
+    fn reset(&mut self) {}
     fn step(&mut self, input: AccInput) -> AccOutput {
         let (d_safe, b, fv_v) = match input.c {
             true => {
@@ -71,6 +73,11 @@ impl grust::core::Component for ActivateState {
             last_r_mem: 0.0f64,
         }
     }
+    fn reset(&mut self) {
+        self.last_active = false;
+        self.last_approach = false;
+        self.last_r_mem = 0.0f64;
+    }
     fn step(&mut self, input: ActivateInput) -> ActivateOutput {
         let (active, r_mem, approach) = match (input.act, input.r) {
             (Some(act), _) => {
@@ -126,6 +133,11 @@ impl grust::core::Component for DeriveOnState {
             last_x_mem: 0.0f64,
         }
     }
+    fn reset(&mut self) {
+        self.last_t_mem = 0.0f64;
+        self.last_v = 0.0f64;
+        self.last_x_mem = 0.0f64;
+    }
     fn step(&mut self, input: DeriveOnInput) -> DeriveOnOutput {
         let (x_mem, v, t_mem) = match (input.e) {
             (Some(e)) => {
@@ -36,6 +36,9 @@ impl grust::core::Component for BrakingStateState {
             last_state: Braking::NoBrake,
         }
     }
+    fn reset(&mut self) {
+        self.last_state = Braking::NoBrake;
+    }
     fn step(&mut self, input: BrakingStateInput) -> BrakingStateOutput {
         let state = match (input.pedest, input.timeout_pedestrian) {
             (Some(d), _) => {
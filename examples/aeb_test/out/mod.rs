@@ -36,21 +36,28 @@ impl grust::core::Component for BrakingStateState {
             last_state: Braking::NoBrake,
         }
     }
+    fn reset(&mut self) {
+        self.last_state = Braking::NoBrake;
+    }
     fn step(&mut self, input: BrakingStateInput) -> BrakingStateOutput {
         let state = match (input.pedest, input.timeout_pedestrian) {
             (Some(d), _) => {
+                Self::coverage().hit(0);
                 let state = brakes(d, input.speed);
                 state
             }
             (_, Some(_)) if self.last_state == Braking::UrgentBrake => {
+                Self::coverage().hit(1);
                 let state = Braking::SoftBrake;
                 state
             }
             (_, Some(_)) => {
+                Self::coverage().hit(2);
                 let state = Braking::NoBrake;
                 state
             }
             (_, _) => {
+                Self::coverage().hit(3);
                 let state = self.last_state;
                 state
             }
@@ -59,6 +66,45 @@ impl grust::core::Component for BrakingStateState {
         BrakingStateOutput { state }
     }
 }
+impl BrakingStateState {
+    pub fn coverage() -> &'static grust::core::coverage::Probes<4> {
+        static PROBES: grust::core::coverage::Probes<4> = grust::core::coverage::Probes::new([
+            grust::core::coverage::Point {
+                owner: "braking_state",
+                branch: grust::core::coverage::Branch::Arm,
+                label: "let d = pedest?",
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            },
+            grust::core::coverage::Point {
+                owner: "braking_state",
+                branch: grust::core::coverage::Branch::Arm,
+                label: "let _ = timeout_pedestrian?",
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            },
+            grust::core::coverage::Point {
+                owner: "braking_state",
+                branch: grust::core::coverage::Branch::Arm,
+                label: "let _ = timeout_pedestrian?",
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            },
+            grust::core::coverage::Point {
+                owner: "braking_state",
+                branch: grust::core::coverage::Branch::Arm,
+                label: "_",
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            },
+        ]);
+        &PROBES
+    }
+}
 pub mod runtime {
     use super::*;
     use grust::futures::{sink::SinkExt, stream::StreamExt};
@@ -353,6 +399,7 @@ pub mod runtime {
                 _pedestrian_r_instant: std::time::Instant,
                 pedestrian_r: f64,
             ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                Self::coverage().hit(0);
                 if self.delayed {
                     self.reset_time_constraints(_pedestrian_r_instant).await?;
                     self.context.reset();
@@ -400,6 +447,7 @@ pub mod runtime {
                 &mut self,
                 _grust_reserved_instant: std::time::Instant,
             ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                Self::coverage().hit(1);
                 self.context.reset();
                 if self.input_store.not_empty() {
                     self.reset_time_constraints(_grust_reserved_instant).await?;
@@ -487,6 +535,7 @@ pub mod runtime {
                 _speed_km_h_instant: std::time::Instant,
                 speed_km_h: f64,
             ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                Self::coverage().hit(2);
                 if self.delayed {
                     self.reset_time_constraints(_speed_km_h_instant).await?;
                     self.context.reset();
@@ -525,6 +574,7 @@ pub mod runtime {
                 &mut self,
                 _timeout_aeb_instant: std::time::Instant,
             ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                Self::coverage().hit(3);
                 self.reset_time_constraints(_timeout_aeb_instant).await?;
                 self.context.reset();
                 self.send_output(
@@ -549,6 +599,7 @@ pub mod runtime {
                 _pedestrian_l_instant: std::time::Instant,
                 pedestrian_l: f64,
             ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                Self::coverage().hit(4);
                 if self.delayed {
                     self.reset_time_constraints(_pedestrian_l_instant).await?;
                     self.context.reset();
@@ -596,6 +647,7 @@ pub mod runtime {
                 &mut self,
                 _timeout_timeout_pedestrian_instant: std::time::Instant,
             ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                Self::coverage().hit(5);
                 if self.delayed {
                     self.reset_time_constraints(_timeout_timeout_pedestrian_instant)
                         .await?;
@@ -640,6 +692,60 @@ pub mod runtime {
                 }
                 Ok(())
             }
+            pub fn coverage() -> &'static grust::core::coverage::Probes<6> {
+                static PROBES: grust::core::coverage::Probes<6> =
+                    grust::core::coverage::Probes::new([
+                        grust::core::coverage::Point {
+                            owner: "aeb",
+                            branch: grust::core::coverage::Branch::Handler,
+                            label: "pedestrian_r",
+                            file: file!(),
+                            line: line!(),
+                            column: column!(),
+                        },
+                        grust::core::coverage::Point {
+                            owner: "aeb",
+                            branch: grust::core::coverage::Branch::Handler,
+                            label: "delay_aeb",
+                            file: file!(),
+                            line: line!(),
+                            column: column!(),
+                        },
+                        grust::core::coverage::Point {
+                            owner: "aeb",
+                            branch: grust::core::coverage::Branch::Handler,
+                            label: "speed_km_h",
+                            file: file!(),
+                            line: line!(),
+                            column: column!(),
+                        },
+                        grust::core::coverage::Point {
+                            owner: "aeb",
+                            branch: grust::core::coverage::Branch::Handler,
+                            label: "timeout_aeb",
+                            file: file!(),
+                            line: line!(),
+                            column: column!(),
+                        },
+                        grust::core::coverage::Point {
+                            owner: "aeb",
+                            branch: grust::core::coverage::Branch::Handler,
+                            label: "pedestrian_l",
+                            file: file!(),
+                            line: line!(),
+                            column: column!(),
+                        },
+                        grust::core::coverage::Point {
+                            owner: "aeb",
+                            branch: grust::core::coverage::Branch::Handler,
+                            label: "timeout_timeout_pedestrian",
+                            file: file!(),
+                            line: line!(),
+                            column: column!(),
+                        },
+                    ]);
+                &PROBES
+            }
             #[inline]
             pub async fn reset_time_constraints(
                 &mut self,
@@ -698,3 +804,101 @@ pub fn run(
     });
     output_stream
 }
+#[cfg(test)]
+#[test]
+#[allow(unused_variables)]
+fn braking_nominal() {
+    let mut state = <BrakingStateState as grust::core::Component>::init();
+    {
+        let input = BrakingStateInput {
+            pedest: Some(10.0f64),
+            timeout_pedestrian: None,
+            speed: 30.0f64,
+        };
+        let inputs = format!(
+            "pedest = {:?}, timeout_pedestrian = {:?}, speed = {:?}",
+            input.pedest, input.timeout_pedestrian, input.speed
+        );
+        let output = <BrakingStateState as grust::core::Component>::step(&mut state, input);
+        let expected = Braking::SoftBrake;
+        assert!(
+            output.state == expected,
+            "test `braking_nominal` failed at step 0: `state` is {:?}, expected {:?}\n  inputs: {}",
+            output.state,
+            expected,
+            inputs
+        );
+    }
+    {
+        let input = BrakingStateInput {
+            pedest: Some(5.0f64),
+            timeout_pedestrian: None,
+            speed: 30.0f64,
+        };
+        let inputs = format!(
+            "pedest = {:?}, timeout_pedestrian = {:?}, speed = {:?}",
+            input.pedest, input.timeout_pedestrian, input.speed
+        );
+        let output = <BrakingStateState as grust::core::Component>::step(&mut state, input);
+        let expected = Braking::UrgentBrake;
+        assert!(
+            output.state == expected,
+            "test `braking_nominal` failed at step 1: `state` is {:?}, expected {:?}\n  inputs: {}",
+            output.state,
+            expected,
+            inputs
+        );
+    }
+    {
+        let input = BrakingStateInput {
+            pedest: None,
+            timeout_pedestrian: Some(()),
+            speed: 30.0f64,
+        };
+        let inputs = format!(
+            "pedest = {:?}, timeout_pedestrian = {:?}, speed = {:?}",
+            input.pedest, input.timeout_pedestrian, input.speed
+        );
+        let output = <BrakingStateState as grust::core::Component>::step(&mut state, input);
+        let expected = Braking::SoftBrake;
+        assert!(
+            output.state == expected,
+            "test `braking_nominal` failed at step 2: `state` is {:?}, expected {:?}\n  inputs: {}",
+            output.state,
+            expected,
+            inputs
+        );
+    }
+    {
+        let input = BrakingStateInput {
+            pedest: None,
+            timeout_pedestrian: Some(()),
+            speed: 30.0f64,
+        };
+        let inputs = format!(
+            "pedest = {:?}, timeout_pedestrian = {:?}, speed = {:?}",
+            input.pedest, input.timeout_pedestrian, input.speed
+        );
+        let output = <BrakingStateState as grust::core::Component>::step(&mut state, input);
+        let expected = Braking::NoBrake;
+        assert!(
+            output.state == expected,
+            "test `braking_nominal` failed at step 3: `state` is {:?}, expected {:?}\n  inputs: {}",
+            output.state,
+            expected,
+            inputs
+        );
+    }
+}
+#[doc = r" Coverage of the process, counters are shared by all instances and threads."]
+pub fn coverage_report() -> grust::core::coverage::Report {
+    let mut report = grust::core::coverage::Report::new();
+    report.add(BrakingStateState::coverage());
+    report.add(runtime::aeb_service::AebService::coverage());
+    report
+}
+#[doc = r" Resets the coverage counters of the process."]
+pub fn reset_coverage() {
+    BrakingStateState::coverage().reset();
+    runtime::aeb_service::AebService::coverage().reset();
+}
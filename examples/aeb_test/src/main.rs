@@ -3,7 +3,7 @@ mod aeb {
     use grust::grust;
 
     grust! {
-        #![mode = test, dump = "examples/aeb_test/out/mod.rs"]
+        #![mode = test, coverage, dump = "examples/aeb_test/out/mod.rs"]
         import signal car::speed_km_h                   : float;
         import event  car::detect::left::pedestrian_l   : float;
         import event  car::detect::right::pedestrian_r  : float;
@@ -48,6 +48,17 @@ mod aeb {
             let event timeout_pedestrian: unit = timeout(pedestrian, 2000);
             brakes = braking_state(pedestrian, timeout_pedestrian, speed_km_h);
         }
+
+        test braking_nominal for braking_state {
+            step   { pedest = some(10.), speed = 30. }
+            expect { state = Braking::SoftBrake }
+            step   { pedest = some(5.) }
+            expect { state = Braking::UrgentBrake }
+            step   { timeout_pedestrian = some(()) }
+            expect { state = Braking::SoftBrake }
+            step   { timeout_pedestrian = some(()) }
+            expect { state = Braking::NoBrake }
+        }
     }
 }
 
@@ -148,4 +159,7 @@ async fn main() {
 
     // finalize JSON file
     end_json(OUTPUT_PATH);
+
+    // report the branches never exercised
+    print!("{}", aeb::coverage_report());
 }
@@ -4,11 +4,21 @@ enum Activation {
     On,
     Off,
 }
+const _: fn(f64) -> f64 = utils::convert;
 fn safety_distance(sv_v: f64, fv_v: f64) -> f64 {
     let sv_d_stop = (sv_v * 1.0f64) + ((sv_v * sv_v) / (2.0f64 * 5.886f64));
     let fv_d_stop = (fv_v * fv_v) / (2.0f64 * 5.886f64);
     sv_d_stop - fv_d_stop
 }
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64) -> (f64,) = |x, t| {
+    let grust::std::time::derivation::DeriveOutput { d } =
+        <grust::std::time::derivation::DeriveState as grust::core::Component>::step(
+            &mut <grust::std::time::derivation::DeriveState as grust::core::Component>::init(),
+            grust::std::time::derivation::DeriveInput { x, t },
+        );
+    (d,)
+};
 struct AccInput {
     c: bool,
     d: f64,
@@ -25,6 +35,7 @@ impl grust::core::Component for AccState {
     fn init() -> AccState {
         AccState {}
     }
+    fn reset(&mut self) {}
     fn step(&mut self, input: AccInput) -> AccOutput {
         let (d_safe, b, fv_v) = match input.c {
             true => {
@@ -64,6 +75,11 @@ impl grust::core::Component for ActivateState {
             last_d: 0.0f64,
         }
     }
+    fn reset(&mut self) {
+        self.last_active = false;
+        self.last_approach = false;
+        self.last_d = 0.0f64;
+    }
     fn step(&mut self, input: ActivateInput) -> ActivateOutput {
         let (active, d, approach) = match (input.act, input.r) {
             (Some(act), _) => {
@@ -0,0 +1,61 @@
+pub fn add(a: i64, b: i64) -> i64 {
+    i64::saturating_add(a, b)
+}
+pub fn div(a: i64, b: i64) -> i64 {
+    match (a, b) {
+        (0, 0) => 0,
+        (lft, 0) if lft > 0 => i64::MAX,
+        (_, 0) => i64::MIN,
+        (lft, rgt) => i64::saturating_div(lft, rgt),
+    }
+}
+pub fn rem(a: i64, b: i64) -> i64 {
+    match (a, b) {
+        (lft, 0) => lft,
+        (lft, rgt) => i64::wrapping_rem(lft, rgt),
+    }
+}
+pub fn wrapping_mul(a: i64, b: i64) -> i64 {
+    i64::wrapping_mul(a, b)
+}
+pub fn wrapping_div(a: i64, b: i64) -> i64 {
+    match (a, b) {
+        (_, 0) => 0,
+        (lft, rgt) => i64::wrapping_div(lft, rgt),
+    }
+}
+pub fn checked_sub(a: i64, b: i64) -> i64 {
+    i64::checked_sub(a, b).expect("attempt to subtract with overflow")
+}
+pub fn average(a: f64, b: f64) -> f64 {
+    (a + b) / 2.0f64
+}
+pub struct OdometerInput {
+    pub ticks: i64,
+}
+pub struct OdometerOutput {
+    pub distance: i64,
+}
+pub struct OdometerState {
+    last_distance: i64,
+}
+impl grust::core::Component for OdometerState {
+    type Input = OdometerInput;
+    type Output = OdometerOutput;
+    fn init() -> OdometerState {
+        OdometerState {
+            last_distance: 0i64,
+        }
+    }
+    fn reset(&mut self) {
+        self.last_distance = 0i64;
+    }
+    fn step(&mut self, input: OdometerInput) -> OdometerOutput {
+        let distance = i64::saturating_add(
+            self.last_distance,
+            i64::saturating_mul(input.ticks, 1000i64),
+        );
+        self.last_distance = distance;
+        OdometerOutput { distance }
+    }
+}
@@ -24,6 +24,9 @@ impl grust::core::Component for SumState {
     fn init() -> SumState {
         SumState { last_o: 0i64 }
     }
+    fn reset(&mut self) {
+        self.last_o = 0i64;
+    }
     fn step(&mut self, input: SumInput) -> SumOutput {
         let x = add(self.last_o, input.i);
         let o = if input.reset { 0i64 } else { x };
@@ -53,6 +56,11 @@ impl grust::core::Component for AutomatonState {
             sum: <SumState as grust::core::Component>::init(),
         }
     }
+    fn reset(&mut self) {
+        self.last_next_state = State::Off;
+        self.last_x = 0i64;
+        <SumState as grust::core::Component>::reset(&mut self.sum);
+    }
     fn step(&mut self, input: AutomatonInput) -> AutomatonOutput {
         let state = self.last_next_state;
         let (next_state, x, o) = match state {
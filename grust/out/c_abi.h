@@ -0,0 +1,33 @@
+/* Generated by grust, do not edit. */
+
+#ifndef C_ABI_H
+#define C_ABI_H
+
+#include <stdbool.h>
+#include <stdint.h>
+
+typedef enum Gear {
+    Gear_Park,
+    Gear_Drive,
+    Gear_Reverse,
+} Gear;
+
+typedef struct Wheels {
+    double speeds[4];
+    Gear gear;
+} Wheels;
+
+typedef struct OdometerInput {
+    Wheels wheels;
+    double dt;
+} OdometerInput;
+typedef struct OdometerOutput {
+    double speed;
+    double distance;
+} OdometerOutput;
+typedef struct OdometerState OdometerState;
+OdometerState *odometer_init(void);
+OdometerOutput odometer_step(OdometerState *state, OdometerInput input);
+void odometer_free(OdometerState *state);
+
+#endif /* C_ABI_H */
@@ -0,0 +1,82 @@
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+#[repr(C)]
+pub enum Gear {
+    #[default]
+    Park,
+    Drive,
+    Reverse,
+}
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+#[repr(C)]
+pub struct Wheels {
+    pub speeds: [f64; 4usize],
+    pub gear: Gear,
+}
+pub fn mean(speeds: [f64; 4usize]) -> f64 {
+    let sum = ((speeds[0] + speeds[1]) + speeds[2]) + speeds[3];
+    sum / 4.0f64
+}
+#[repr(C)]
+pub struct OdometerInput {
+    pub wheels: Wheels,
+    pub dt: f64,
+}
+#[repr(C)]
+pub struct OdometerOutput {
+    pub speed: f64,
+    pub distance: f64,
+}
+pub struct OdometerState {
+    last_distance: f64,
+}
+impl grust::core::Component for OdometerState {
+    type Input = OdometerInput;
+    type Output = OdometerOutput;
+    fn init() -> OdometerState {
+        OdometerState {
+            last_distance: 0.0f64,
+        }
+    }
+    fn reset(&mut self) {
+        self.last_distance = 0.0f64;
+    }
+    fn step(&mut self, input: OdometerInput) -> OdometerOutput {
+        let speed = if input.wheels.gear == Gear::Reverse {
+            -(mean(input.wheels.speeds))
+        } else {
+            mean(input.wheels.speeds)
+        };
+        let distance = self.last_distance + (speed * input.dt);
+        self.last_distance = distance;
+        OdometerOutput { speed, distance }
+    }
+}
+#[unsafe(no_mangle)]
+pub extern "C" fn odometer_init() -> *mut OdometerState {
+    Box::into_raw(Box::new(<OdometerState as grust::core::Component>::init()))
+}
+#[doc = r" # Safety"]
+#[doc = r""]
+#[doc = r" `state` must be null or a pointer returned by the init function that was not freed,"]
+#[doc = r" the process aborts when it is null."]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn odometer_step(
+    state: *mut OdometerState,
+    input: OdometerInput,
+) -> OdometerOutput {
+    if state.is_null() {
+        std::process::abort()
+    }
+    let state = unsafe { &mut *state };
+    <OdometerState as grust::core::Component>::step(state, input)
+}
+#[doc = r" # Safety"]
+#[doc = r""]
+#[doc = r" `state` must be null or a pointer returned by the init function that was not freed,"]
+#[doc = r" it cannot be used after this call."]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn odometer_free(state: *mut OdometerState) {
+    if !state.is_null() {
+        drop(unsafe { Box::from_raw(state) })
+    }
+}
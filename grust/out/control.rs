@@ -0,0 +1,172 @@
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64, f64, f64, f64, f64, f64, f64) -> (f64,) =
+    |e, t, kp, ki, kd, tf, u_min, u_max| {
+        let grust::std::control::PidOutput { u } =
+            <grust::std::control::PidState as grust::core::Component>::step(
+                &mut <grust::std::control::PidState as grust::core::Component>::init(),
+                grust::std::control::PidInput {
+                    e,
+                    t,
+                    kp,
+                    ki,
+                    kd,
+                    tf,
+                    u_min,
+                    u_max,
+                },
+            );
+        (u,)
+    };
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64, f64, f64) -> (f64,) = |x, t, rising, falling| {
+    let grust::std::control::RateLimiterOutput { y } =
+        <grust::std::control::RateLimiterState as grust::core::Component>::step(
+            &mut <grust::std::control::RateLimiterState as grust::core::Component>::init(),
+            grust::std::control::RateLimiterInput {
+                x,
+                t,
+                rising,
+                falling,
+            },
+        );
+    (y,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64, f64, f64) -> (f64, bool) = |x, lo, hi, h| {
+    let grust::std::control::SaturationOutput { y, sat } =
+        <grust::std::control::SaturationState as grust::core::Component>::step(
+            &mut <grust::std::control::SaturationState as grust::core::Component>::init(),
+            grust::std::control::SaturationInput { x, lo, hi, h },
+        );
+    (y, sat)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64, bool, f64, f64) -> (f64,) = |auto_u, manual_u, manual, t, tau| {
+    let grust::std::control::BumplessTransferOutput { u } =
+        <grust::std::control::BumplessTransferState as grust::core::Component>::step(
+            &mut <grust::std::control::BumplessTransferState as grust::core::Component>::init(),
+            grust::std::control::BumplessTransferInput {
+                auto_u,
+                manual_u,
+                manual,
+                t,
+                tau,
+            },
+        );
+    (u,)
+};
+pub struct SpeedControlInput {
+    pub target: f64,
+    pub speed: f64,
+    pub pedal: f64,
+    pub manual: bool,
+    pub t: f64,
+}
+pub struct SpeedControlOutput {
+    pub throttle: f64,
+    pub limited: bool,
+}
+pub struct SpeedControlState {
+    rate_limiter: grust::std::control::RateLimiterState,
+    pid: grust::std::control::PidState,
+    bumpless_transfer: grust::std::control::BumplessTransferState,
+    saturation: grust::std::control::SaturationState,
+}
+impl grust::core::Component for SpeedControlState {
+    type Input = SpeedControlInput;
+    type Output = SpeedControlOutput;
+    fn init() -> SpeedControlState {
+        SpeedControlState {
+            rate_limiter: <grust::std::control::RateLimiterState as grust::core::Component>::init(),
+            pid: <grust::std::control::PidState as grust::core::Component>::init(),
+            bumpless_transfer:
+                <grust::std::control::BumplessTransferState as grust::core::Component>::init(),
+            saturation: <grust::std::control::SaturationState as grust::core::Component>::init(),
+        }
+    }
+    fn reset(&mut self) {
+        <grust::std::control::RateLimiterState as grust::core::Component>::reset(
+            &mut self.rate_limiter,
+        );
+        <grust::std::control::PidState as grust::core::Component>::reset(&mut self.pid);
+        <grust::std::control::BumplessTransferState as grust::core::Component>::reset(
+            &mut self.bumpless_transfer,
+        );
+        <grust::std::control::SaturationState as grust::core::Component>::reset(
+            &mut self.saturation,
+        );
+    }
+    fn step(&mut self, input: SpeedControlInput) -> SpeedControlOutput {
+        let x = 2.0f64;
+        let x_1 = 5.0f64;
+        let setpoint = {
+            let grust::std::control::RateLimiterOutput { y } =
+                <grust::std::control::RateLimiterState as grust::core::Component>::step(
+                    &mut self.rate_limiter,
+                    grust::std::control::RateLimiterInput {
+                        x: input.target,
+                        t: input.t,
+                        rising: x,
+                        falling: x_1,
+                    },
+                );
+            (y)
+        };
+        let x_2 = setpoint - input.speed;
+        let x_3 = 0.5f64;
+        let x_4 = 0.1f64;
+        let x_5 = 0.0f64;
+        let x_6 = 0.0f64;
+        let x_7 = -1.0f64;
+        let x_8 = 1.0f64;
+        let command = {
+            let grust::std::control::PidOutput { u } =
+                <grust::std::control::PidState as grust::core::Component>::step(
+                    &mut self.pid,
+                    grust::std::control::PidInput {
+                        e: x_2,
+                        t: input.t,
+                        kp: x_3,
+                        ki: x_4,
+                        kd: x_5,
+                        tf: x_6,
+                        u_min: x_7,
+                        u_max: x_8,
+                    },
+                );
+            (u)
+        };
+        let x_9 = 0.5f64;
+        let u = {
+            let grust::std::control::BumplessTransferOutput { u } =
+                <grust::std::control::BumplessTransferState as grust::core::Component>::step(
+                    &mut self.bumpless_transfer,
+                    grust::std::control::BumplessTransferInput {
+                        auto_u: command,
+                        manual_u: input.pedal,
+                        manual: input.manual,
+                        t: input.t,
+                        tau: x_9,
+                    },
+                );
+            (u)
+        };
+        let x_10 = 0.0f64;
+        let x_11 = 1.0f64;
+        let x_12 = 0.1f64;
+        let (throttle, limited) = {
+            let grust::std::control::SaturationOutput { y, sat } =
+                <grust::std::control::SaturationState as grust::core::Component>::step(
+                    &mut self.saturation,
+                    grust::std::control::SaturationInput {
+                        x: u,
+                        lo: x_10,
+                        hi: x_11,
+                        h: x_12,
+                    },
+                );
+            (y, sat)
+        };
+        SpeedControlOutput { throttle, limited }
+    }
+}
@@ -18,6 +18,9 @@ impl grust::core::Component for CounterState {
     fn init() -> CounterState {
         CounterState { last_o: 0i64 }
     }
+    fn reset(&mut self) {
+        self.last_o = 0i64;
+    }
     fn step(&mut self, input: CounterInput) -> CounterOutput {
         let inc = if input.tick { 1i64 } else { 0i64 };
         let o = if input.res {
@@ -48,6 +51,11 @@ impl grust::core::Component for TestState {
             counter: <CounterState as grust::core::Component>::init(),
         }
     }
+    fn reset(&mut self) {
+        self.last_not_half = false;
+        self.last_stop = false;
+        <CounterState as grust::core::Component>::reset(&mut self.counter);
+    }
     fn step(&mut self, input: TestInput) -> TestOutput {
         let half = self.last_not_half;
         let x = self.last_stop;
@@ -13,6 +13,9 @@ impl grust::core::Component for NextState {
     fn init() -> NextState {
         NextState { last_i: 1i64 }
     }
+    fn reset(&mut self) {
+        self.last_i = 1i64;
+    }
     fn step(&mut self, input: NextInput) -> NextOutput {
         let next_o = input.i + self.last_i;
         self.last_i = input.i;
@@ -38,6 +41,10 @@ impl grust::core::Component for SemiFibState {
             next: <NextState as grust::core::Component>::init(),
         }
     }
+    fn reset(&mut self) {
+        self.last_next_o = 0i64;
+        <NextState as grust::core::Component>::reset(&mut self.next);
+    }
     fn step(&mut self, input: SemiFibInput) -> SemiFibOutput {
         let next_o = {
             let NextOutput { next_o } = <NextState as grust::core::Component>::step(
@@ -70,6 +77,11 @@ impl grust::core::Component for FibCallState {
             next_1: <NextState as grust::core::Component>::init(),
         }
     }
+    fn reset(&mut self) {
+        self.last_next_o_1 = 0i64;
+        <NextState as grust::core::Component>::reset(&mut self.next);
+        <NextState as grust::core::Component>::reset(&mut self.next_1);
+    }
     fn step(&mut self, input: FibCallInput) -> FibCallOutput {
         let fib = self.last_next_o_1;
         let next_o = {
@@ -105,6 +117,10 @@ impl grust::core::Component for FibState {
             last_next_o: 0i64,
         }
     }
+    fn reset(&mut self) {
+        self.last_fib = 1i64;
+        self.last_next_o = 0i64;
+    }
     fn step(&mut self, input: FibInput) -> FibOutput {
         let fib = self.last_next_o;
         let next_o = fib + self.last_fib;
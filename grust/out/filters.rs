@@ -0,0 +1,105 @@
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64, i64) -> (f64,) = |x, t, n| {
+    let grust::std::filters::MedianOutput { y } =
+        <grust::std::filters::MedianState as grust::core::Component>::step(
+            &mut <grust::std::filters::MedianState as grust::core::Component>::init(),
+            grust::std::filters::MedianInput { x, t, n },
+        );
+    (y,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64, f64) -> (f64,) = |x, t, tau| {
+    let grust::std::filters::LowPassOutput { y } =
+        <grust::std::filters::LowPassState as grust::core::Component>::step(
+            &mut <grust::std::filters::LowPassState as grust::core::Component>::init(),
+            grust::std::filters::LowPassInput { x, t, tau },
+        );
+    (y,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64, f64, f64) -> (f64, f64) = |z, t, q, r| {
+    let grust::std::filters::KalmanOutput { est, var } =
+        <grust::std::filters::KalmanState as grust::core::Component>::step(
+            &mut <grust::std::filters::KalmanState as grust::core::Component>::init(),
+            grust::std::filters::KalmanInput { z, t, q, r },
+        );
+    (est, var)
+};
+pub struct RadarFilterInput {
+    pub distance: f64,
+    pub t: f64,
+}
+pub struct RadarFilterOutput {
+    pub smooth: f64,
+    pub est: f64,
+}
+pub struct RadarFilterState {
+    median: grust::std::filters::MedianState,
+    low_pass: grust::std::filters::LowPassState,
+    kalman: grust::std::filters::KalmanState,
+}
+impl grust::core::Component for RadarFilterState {
+    type Input = RadarFilterInput;
+    type Output = RadarFilterOutput;
+    fn init() -> RadarFilterState {
+        RadarFilterState {
+            median: <grust::std::filters::MedianState as grust::core::Component>::init(),
+            low_pass: <grust::std::filters::LowPassState as grust::core::Component>::init(),
+            kalman: <grust::std::filters::KalmanState as grust::core::Component>::init(),
+        }
+    }
+    fn reset(&mut self) {
+        <grust::std::filters::MedianState as grust::core::Component>::reset(&mut self.median);
+        <grust::std::filters::LowPassState as grust::core::Component>::reset(&mut self.low_pass);
+        <grust::std::filters::KalmanState as grust::core::Component>::reset(&mut self.kalman);
+    }
+    fn step(&mut self, input: RadarFilterInput) -> RadarFilterOutput {
+        let x = 3i64;
+        let despiked = {
+            let grust::std::filters::MedianOutput { y } =
+                <grust::std::filters::MedianState as grust::core::Component>::step(
+                    &mut self.median,
+                    grust::std::filters::MedianInput {
+                        x: input.distance,
+                        t: input.t,
+                        n: x,
+                    },
+                );
+            (y)
+        };
+        let x_1 = 0.2f64;
+        let smooth = {
+            let grust::std::filters::LowPassOutput { y } =
+                <grust::std::filters::LowPassState as grust::core::Component>::step(
+                    &mut self.low_pass,
+                    grust::std::filters::LowPassInput {
+                        x: despiked,
+                        t: input.t,
+                        tau: x_1,
+                    },
+                );
+            (y)
+        };
+        let x_2 = 0.5f64;
+        let x_3 = 4.0f64;
+        let (estimate, variance) = {
+            let grust::std::filters::KalmanOutput { est, var } =
+                <grust::std::filters::KalmanState as grust::core::Component>::step(
+                    &mut self.kalman,
+                    grust::std::filters::KalmanInput {
+                        z: input.distance,
+                        t: input.t,
+                        q: x_2,
+                        r: x_3,
+                    },
+                );
+            (est, var)
+        };
+        let est = if variance < 10.0f64 {
+            estimate
+        } else {
+            input.distance
+        };
+        RadarFilterOutput { smooth, est }
+    }
+}
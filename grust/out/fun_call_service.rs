@@ -1,3 +1,4 @@
+const _: fn(f64) -> i64 = utils::floor;
 pub mod runtime {
     use super::*;
     use grust::futures::{sink::SinkExt, stream::StreamExt};
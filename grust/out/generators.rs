@@ -0,0 +1,612 @@
+const _: fn([f64; 4usize], [f64; 4usize], f64) -> f64 = grust::std::generators::profile;
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64, f64, f64) -> (f64,) = |t, delay, slope, initial| {
+    let grust::std::generators::RampOutput { y } =
+        <grust::std::generators::RampState as grust::core::Component>::step(
+            &mut <grust::std::generators::RampState as grust::core::Component>::init(),
+            grust::std::generators::RampInput {
+                t,
+                delay,
+                slope,
+                initial,
+            },
+        );
+    (y,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64, f64, f64, f64) -> (f64,) = |t, amplitude, frequency, phase, offset| {
+    let grust::std::generators::SineOutput { y } =
+        <grust::std::generators::SineState as grust::core::Component>::step(
+            &mut <grust::std::generators::SineState as grust::core::Component>::init(),
+            grust::std::generators::SineInput {
+                t,
+                amplitude,
+                frequency,
+                phase,
+                offset,
+            },
+        );
+    (y,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(f64, i64, f64) -> (f64,) = |t, seed, amplitude| {
+    let grust::std::generators::NoiseOutput { y } =
+        <grust::std::generators::NoiseState as grust::core::Component>::step(
+            &mut <grust::std::generators::NoiseState as grust::core::Component>::init(),
+            grust::std::generators::NoiseInput { t, seed, amplitude },
+        );
+    (y,)
+};
+pub struct SpeedStimulusInput {
+    pub t_ms: f64,
+}
+pub struct SpeedStimulusOutput {
+    pub speed: f64,
+}
+pub struct SpeedStimulusState {
+    ramp: grust::std::generators::RampState,
+    sine: grust::std::generators::SineState,
+    noise: grust::std::generators::NoiseState,
+}
+impl grust::core::Component for SpeedStimulusState {
+    type Input = SpeedStimulusInput;
+    type Output = SpeedStimulusOutput;
+    fn init() -> SpeedStimulusState {
+        SpeedStimulusState {
+            ramp: <grust::std::generators::RampState as grust::core::Component>::init(),
+            sine: <grust::std::generators::SineState as grust::core::Component>::init(),
+            noise: <grust::std::generators::NoiseState as grust::core::Component>::init(),
+        }
+    }
+    fn reset(&mut self) {
+        <grust::std::generators::RampState as grust::core::Component>::reset(&mut self.ramp);
+        <grust::std::generators::SineState as grust::core::Component>::reset(&mut self.sine);
+        <grust::std::generators::NoiseState as grust::core::Component>::reset(&mut self.noise);
+    }
+    fn step(&mut self, input: SpeedStimulusInput) -> SpeedStimulusOutput {
+        let t = input.t_ms / 1000.0f64;
+        let x = 0.0f64;
+        let x_1 = 1.0f64;
+        let x_2 = 0.0f64;
+        let elapsed = {
+            let grust::std::generators::RampOutput { y } =
+                <grust::std::generators::RampState as grust::core::Component>::step(
+                    &mut self.ramp,
+                    grust::std::generators::RampInput {
+                        t: t,
+                        delay: x,
+                        slope: x_1,
+                        initial: x_2,
+                    },
+                );
+            (y)
+        };
+        let nominal = grust::std::generators::profile(
+            [0.0f64, 1.0f64, 3.0f64, 4.0f64],
+            [0.0f64, 20.0f64, 20.0f64, 0.0f64],
+            elapsed,
+        );
+        let x_3 = 0.5f64;
+        let x_4 = 0.5f64;
+        let x_5 = 0.0f64;
+        let x_6 = 0.0f64;
+        let comp_app_sine = {
+            let grust::std::generators::SineOutput { y } =
+                <grust::std::generators::SineState as grust::core::Component>::step(
+                    &mut self.sine,
+                    grust::std::generators::SineInput {
+                        t: t,
+                        amplitude: x_3,
+                        frequency: x_4,
+                        phase: x_5,
+                        offset: x_6,
+                    },
+                );
+            (y)
+        };
+        let x_7 = 42i64;
+        let x_8 = 0.1f64;
+        let comp_app_noise = {
+            let grust::std::generators::NoiseOutput { y } =
+                <grust::std::generators::NoiseState as grust::core::Component>::step(
+                    &mut self.noise,
+                    grust::std::generators::NoiseInput {
+                        t: t,
+                        seed: x_7,
+                        amplitude: x_8,
+                    },
+                );
+            (y)
+        };
+        let speed = (nominal + comp_app_sine) + comp_app_noise;
+        SpeedStimulusOutput { speed }
+    }
+}
+pub mod runtime {
+    use super::*;
+    use grust::futures::{sink::SinkExt, stream::StreamExt};
+    #[derive(Debug)]
+    pub enum RuntimeInput {
+        Timer(T, std::time::Instant),
+    }
+    use RuntimeInput as I;
+    impl grust::core::priority_stream::Reset for RuntimeInput {
+        fn do_reset(&self) -> bool {
+            match self {
+                I::Timer(timer, _) => grust::core::timer_stream::Timing::do_reset(timer),
+                _ => false,
+            }
+        }
+    }
+    impl PartialEq for RuntimeInput {
+        fn eq(&self, other: &Self) -> bool {
+            match (self, other) {
+                (I::Timer(this, _), I::Timer(other, _)) => this.eq(other),
+                _ => false,
+            }
+        }
+    }
+    impl RuntimeInput {
+        pub fn get_instant(&self) -> std::time::Instant {
+            match self {
+                I::Timer(_, _grust_reserved_instant) => *_grust_reserved_instant,
+            }
+        }
+        pub fn order(v1: &Self, v2: &Self) -> std::cmp::Ordering {
+            v1.get_instant().cmp(&v2.get_instant())
+        }
+    }
+    #[derive(Debug, PartialEq)]
+    pub enum RuntimeOutput {
+        Speed(f64, std::time::Instant),
+    }
+    use RuntimeOutput as O;
+    #[derive(Debug, Default)]
+    pub struct RuntimeInit {}
+    #[derive(Debug, PartialEq)]
+    pub enum RuntimeTimer {
+        PeriodClock,
+        DelaySimulation,
+        TimeoutSimulation,
+    }
+    use RuntimeTimer as T;
+    impl grust::core::timer_stream::Timing for RuntimeTimer {
+        fn get_duration(&self) -> std::time::Duration {
+            match self {
+                T::PeriodClock => std::time::Duration::from_millis(10u64),
+                T::DelaySimulation => std::time::Duration::from_millis(10u64),
+                T::TimeoutSimulation => std::time::Duration::from_millis(3000u64),
+            }
+        }
+        fn do_reset(&self) -> bool {
+            match self {
+                T::PeriodClock => false,
+                T::DelaySimulation => true,
+                T::TimeoutSimulation => true,
+            }
+        }
+    }
+    pub struct Runtime {
+        _grust_reserved_init_instant: std::time::Instant,
+        simulation: simulation_service::SimulationService,
+        output: grust::futures::channel::mpsc::Sender<O>,
+        timer: grust::futures::channel::mpsc::Sender<(T, std::time::Instant)>,
+    }
+    impl Runtime {
+        pub fn new(
+            _grust_reserved_init_instant: std::time::Instant,
+            output: grust::futures::channel::mpsc::Sender<O>,
+            timer: grust::futures::channel::mpsc::Sender<(T, std::time::Instant)>,
+        ) -> Runtime {
+            let simulation = simulation_service::SimulationService::init(
+                _grust_reserved_init_instant,
+                output.clone(),
+                timer.clone(),
+            );
+            Runtime {
+                _grust_reserved_init_instant,
+                simulation,
+                output,
+                timer,
+            }
+        }
+        #[inline]
+        pub async fn send_timer(
+            &mut self,
+            timer: T,
+            instant: std::time::Instant,
+        ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+            self.timer.send((timer, instant)).await?;
+            Ok(())
+        }
+        pub async fn run_loop(
+            self,
+            input: impl grust::futures::Stream<Item = I>,
+            init_vals: RuntimeInit,
+        ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+            grust::futures::pin_mut!(input);
+            let mut runtime = self;
+            let RuntimeInit {} = init_vals;
+            runtime.simulation.handle_init().await?;
+            while let Some(input) = input.next().await {
+                match input {
+                    I::Timer(T::TimeoutSimulation, _grust_reserved_instant) => {
+                        runtime
+                            .simulation
+                            .handle_timeout_simulation(_grust_reserved_instant)
+                            .await?;
+                    }
+                    I::Timer(T::DelaySimulation, _grust_reserved_instant) => {
+                        runtime
+                            .simulation
+                            .handle_delay_simulation(_grust_reserved_instant)
+                            .await?;
+                    }
+                    I::Timer(T::PeriodClock, _grust_reserved_instant) => {
+                        runtime
+                            .simulation
+                            .handle_period_clock(_grust_reserved_instant)
+                            .await?;
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+    pub mod simulation_service {
+        use super::*;
+        use grust::futures::{sink::SinkExt, stream::StreamExt};
+        mod ctx_ty {
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct T(f64, bool);
+            impl T {
+                pub fn set(&mut self, t: f64) {
+                    self.1 = self.0 != t;
+                    self.0 = t;
+                }
+                pub fn get(&self) -> f64 {
+                    self.0
+                }
+                pub fn take(&mut self) -> f64 {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct Speed(f64, bool);
+            impl Speed {
+                pub fn set(&mut self, speed: f64) {
+                    self.1 = self.0 != speed;
+                    self.0 = speed;
+                }
+                pub fn get(&self) -> f64 {
+                    self.0
+                }
+                pub fn take(&mut self) -> f64 {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct Now(f64, bool);
+            impl Now {
+                pub fn set(&mut self, now: f64) {
+                    self.1 = self.0 != now;
+                    self.0 = now;
+                }
+                pub fn get(&self) -> f64 {
+                    self.0
+                }
+                pub fn take(&mut self) -> f64 {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+        }
+        #[derive(Clone, Copy, PartialEq, Default, Debug)]
+        pub struct Context {
+            pub t: ctx_ty::T,
+            pub speed: ctx_ty::Speed,
+            pub now: ctx_ty::Now,
+        }
+        impl Context {
+            fn init() -> Context {
+                Default::default()
+            }
+            fn reset(&mut self) {
+                self.t.reset();
+                self.speed.reset();
+                self.now.reset();
+            }
+        }
+        #[derive(Default)]
+        pub struct SimulationServiceStore {
+            period_clock: Option<((), std::time::Instant)>,
+        }
+        impl SimulationServiceStore {
+            pub fn not_empty(&self) -> bool {
+                self.period_clock.is_some()
+            }
+        }
+        pub struct SimulationService {
+            _grust_reserved_init_instant: std::time::Instant,
+            context: Context,
+            delayed: bool,
+            input_store: SimulationServiceStore,
+            speed_stimulus: SpeedStimulusState,
+            output: grust::futures::channel::mpsc::Sender<O>,
+            timer: grust::futures::channel::mpsc::Sender<(T, std::time::Instant)>,
+        }
+        impl SimulationService {
+            pub fn init(
+                _grust_reserved_init_instant: std::time::Instant,
+                output: grust::futures::channel::mpsc::Sender<O>,
+                timer: grust::futures::channel::mpsc::Sender<(T, std::time::Instant)>,
+            ) -> SimulationService {
+                let context = Context::init();
+                let delayed = true;
+                let input_store = Default::default();
+                let speed_stimulus = <SpeedStimulusState as grust::core::Component>::init();
+                SimulationService {
+                    _grust_reserved_init_instant,
+                    context,
+                    delayed,
+                    input_store,
+                    speed_stimulus,
+                    output,
+                    timer,
+                }
+            }
+            pub async fn handle_init(
+                &mut self,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                let _grust_reserved_instant = self._grust_reserved_init_instant;
+                self.reset_service_timeout(_grust_reserved_instant).await?;
+                let clock_ref = &mut None;
+                self.send_timer(T::PeriodClock, _grust_reserved_instant)
+                    .await?;
+                *clock_ref = Some(
+                    (_grust_reserved_instant
+                        .duration_since(self._grust_reserved_init_instant)
+                        .as_millis()) as f64,
+                );
+                let now = (_grust_reserved_instant
+                    .duration_since(self._grust_reserved_init_instant)
+                    .as_millis()) as f64;
+                self.context.now.set(now);
+                if clock_ref.is_some() {
+                    self.context.t.set(now);
+                }
+                let SpeedStimulusOutput { speed: speed } =
+                    <SpeedStimulusState as grust::core::Component>::step(
+                        &mut self.speed_stimulus,
+                        SpeedStimulusInput {
+                            t_ms: self.context.t.get(),
+                        },
+                    );
+                self.context.speed.set(speed);
+                self.send_output(
+                    O::Speed(self.context.speed.get(), _grust_reserved_instant),
+                    _grust_reserved_instant,
+                )
+                .await?;
+                Ok(())
+            }
+            pub async fn handle_timeout_simulation(
+                &mut self,
+                _timeout_simulation_instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.reset_time_constraints(_timeout_simulation_instant)
+                    .await?;
+                self.context.reset();
+                let now = (_timeout_simulation_instant
+                    .duration_since(self._grust_reserved_init_instant)
+                    .as_millis()) as f64;
+                self.context.now.set(now);
+                if self.context.t.is_new() {
+                    let SpeedStimulusOutput { speed: speed } =
+                        <SpeedStimulusState as grust::core::Component>::step(
+                            &mut self.speed_stimulus,
+                            SpeedStimulusInput {
+                                t_ms: self.context.t.get(),
+                            },
+                        );
+                    self.context.speed.set(speed);
+                }
+                self.send_output(
+                    O::Speed(self.context.speed.get(), _timeout_simulation_instant),
+                    _timeout_simulation_instant,
+                )
+                .await?;
+                Ok(())
+            }
+            #[inline]
+            pub async fn reset_service_timeout(
+                &mut self,
+                _timeout_simulation_instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.timer
+                    .send((T::TimeoutSimulation, _timeout_simulation_instant))
+                    .await?;
+                Ok(())
+            }
+            pub async fn handle_period_clock(
+                &mut self,
+                _period_clock_instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                if self.delayed {
+                    self.reset_time_constraints(_period_clock_instant).await?;
+                    self.context.reset();
+                    let clock_ref = &mut None;
+                    self.send_timer(T::PeriodClock, _period_clock_instant)
+                        .await?;
+                    *clock_ref = Some(
+                        (_period_clock_instant
+                            .duration_since(self._grust_reserved_init_instant)
+                            .as_millis()) as f64,
+                    );
+                    let now = (_period_clock_instant
+                        .duration_since(self._grust_reserved_init_instant)
+                        .as_millis()) as f64;
+                    self.context.now.set(now);
+                    if clock_ref.is_some() {
+                        self.context.t.set(now);
+                    }
+                    if self.context.t.is_new() {
+                        let SpeedStimulusOutput { speed: speed } =
+                            <SpeedStimulusState as grust::core::Component>::step(
+                                &mut self.speed_stimulus,
+                                SpeedStimulusInput {
+                                    t_ms: self.context.t.get(),
+                                },
+                            );
+                        self.context.speed.set(speed);
+                    }
+                    if self.context.speed.is_new() {
+                        self.send_output(
+                            O::Speed(self.context.speed.get(), _period_clock_instant),
+                            _period_clock_instant,
+                        )
+                        .await?;
+                    }
+                } else {
+                    let unique = self
+                        .input_store
+                        .period_clock
+                        .replace(((), _period_clock_instant));
+                    assert!
+                    (unique.is_none(),
+                    "flow `period_clock` changes twice within one minimal delay of the service, consider reducing this delay");
+                }
+                Ok(())
+            }
+            pub async fn handle_delay_simulation(
+                &mut self,
+                _grust_reserved_instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.context.reset();
+                if self.input_store.not_empty() {
+                    self.reset_time_constraints(_grust_reserved_instant).await?;
+                    let clock_ref = &mut None;
+                    let period_clock_ref = &mut None;
+                    let _period_clock_input_store = self.input_store.period_clock.take();
+                    if let Some((_, _period_clock_instant)) = _period_clock_input_store {
+                        self.send_timer(T::PeriodClock, _period_clock_instant)
+                            .await?;
+                    }
+                    *period_clock_ref = _period_clock_input_store.map(|(x, _)| x);
+                    *clock_ref = _period_clock_input_store.map(|(_, y)| {
+                        (y.duration_since(self._grust_reserved_init_instant)
+                            .as_millis()) as f64
+                    });
+                    let now = (_grust_reserved_instant
+                        .duration_since(self._grust_reserved_init_instant)
+                        .as_millis()) as f64;
+                    self.context.now.set(now);
+                    if clock_ref.is_some() {
+                        self.context.t.set(now);
+                    }
+                    if self.context.t.is_new() {
+                        let SpeedStimulusOutput { speed: speed } =
+                            <SpeedStimulusState as grust::core::Component>::step(
+                                &mut self.speed_stimulus,
+                                SpeedStimulusInput {
+                                    t_ms: self.context.t.get(),
+                                },
+                            );
+                        self.context.speed.set(speed);
+                    }
+                    if self.context.speed.is_new() {
+                        self.send_output(
+                            O::Speed(self.context.speed.get(), _grust_reserved_instant),
+                            _grust_reserved_instant,
+                        )
+                        .await?;
+                    }
+                } else {
+                    self.delayed = true;
+                }
+                Ok(())
+            }
+            #[inline]
+            pub async fn reset_service_delay(
+                &mut self,
+                _grust_reserved_instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.timer
+                    .send((T::DelaySimulation, _grust_reserved_instant))
+                    .await?;
+                self.delayed = false;
+                Ok(())
+            }
+            #[inline]
+            pub async fn reset_time_constraints(
+                &mut self,
+                instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.reset_service_delay(instant).await?;
+                Ok(())
+            }
+            #[inline]
+            pub async fn send_output(
+                &mut self,
+                output: O,
+                instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.reset_service_timeout(instant).await?;
+                self.output.feed(output).await?;
+                Ok(())
+            }
+            #[inline]
+            pub async fn send_timer(
+                &mut self,
+                timer: T,
+                instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.timer.feed((timer, instant)).await?;
+                Ok(())
+            }
+        }
+    }
+}
+use grust::futures::{Stream, StreamExt};
+pub fn run(
+    _grust_reserved_init_instant: std::time::Instant,
+    input_stream: impl Stream<Item = runtime::RuntimeInput> + Send + 'static,
+    init_signals: runtime::RuntimeInit,
+) -> grust::futures::channel::mpsc::Receiver<runtime::RuntimeOutput> {
+    const TIMER_CHANNEL_SIZE: usize = 3usize + 2;
+    const TIMER_STREAM_SIZE: usize = 3usize + 2;
+    let (timers_sink, timers_stream) = grust::futures::channel::mpsc::channel(TIMER_CHANNEL_SIZE);
+    let timers_stream =
+        grust::core::timer_stream::timer_stream::<_, _, TIMER_STREAM_SIZE>(timers_stream)
+            .map(|(timer, deadline)| runtime::RuntimeInput::Timer(timer, deadline));
+    const OUTPUT_CHANNEL_SIZE: usize = 1usize;
+    let (output_sink, output_stream) = grust::futures::channel::mpsc::channel(OUTPUT_CHANNEL_SIZE);
+    const PRIO_STREAM_SIZE: usize = 1usize;
+    let prio_stream = grust::core::priority_stream::prio_stream::<_, _, PRIO_STREAM_SIZE>(
+        grust::futures::stream::select(input_stream, timers_stream),
+        runtime::RuntimeInput::order,
+    );
+    let service = runtime::Runtime::new(_grust_reserved_init_instant, output_sink, timers_sink);
+    grust::tokio::spawn(async move {
+        let result = service.run_loop(prio_stream, init_signals).await;
+        assert!(result.is_ok())
+    });
+    output_stream
+}
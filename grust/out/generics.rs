@@ -0,0 +1,247 @@
+pub fn max<T: Copy + Default + std::fmt::Debug + PartialOrd>(a: T, b: T) -> T {
+    if a >= b {
+        a
+    } else {
+        b
+    }
+}
+pub fn min<T: Copy + Default + std::fmt::Debug + PartialOrd>(a: T, b: T) -> T {
+    if a <= b {
+        a
+    } else {
+        b
+    }
+}
+pub fn clamp<T: Copy + Default + std::fmt::Debug + PartialOrd>(x: T, lo: T, hi: T) -> T {
+    max(lo, min(x, hi))
+}
+pub fn dist<
+    T: Copy
+        + Default
+        + std::fmt::Debug
+        + std::ops::Add<Output = T>
+        + std::ops::Sub<Output = T>
+        + std::ops::Mul<Output = T>
+        + std::ops::Div<Output = T>
+        + std::ops::Rem<Output = T>
+        + std::ops::Neg<Output = T>
+        + PartialOrd,
+>(
+    a: T,
+    b: T,
+) -> T {
+    if a >= b {
+        a - b
+    } else {
+        b - a
+    }
+}
+pub fn same<T: Copy + Default + std::fmt::Debug + PartialEq>(a: T, b: T) -> bool {
+    a == b
+}
+pub struct SelectInput<T> {
+    pub a: T,
+    pub b: T,
+    pub greatest: bool,
+    pub _marker: std::marker::PhantomData<(T,)>,
+}
+pub struct SelectOutput<T> {
+    pub o: T,
+    pub _marker: std::marker::PhantomData<(T,)>,
+}
+pub struct SelectState<T> {
+    _marker: std::marker::PhantomData<(T,)>,
+}
+impl<T: Copy + Default + std::fmt::Debug + PartialOrd> grust::core::Component for SelectState<T> {
+    type Input = SelectInput<T>;
+    type Output = SelectOutput<T>;
+    fn init() -> SelectState<T> {
+        SelectState {
+            _marker: std::marker::PhantomData,
+        }
+    }
+    fn reset(&mut self) {}
+    fn step(&mut self, input: SelectInput<T>) -> SelectOutput<T> {
+        let o = if input.greatest {
+            max(input.a, input.b)
+        } else {
+            min(input.a, input.b)
+        };
+        SelectOutput {
+            o,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+pub struct WidestInput<T> {
+    pub a: T,
+    pub b: T,
+    pub _marker: std::marker::PhantomData<(T,)>,
+}
+pub struct WidestOutput<T> {
+    pub o: T,
+    pub ties: i64,
+    pub _marker: std::marker::PhantomData<(T,)>,
+}
+pub struct WidestState<T> {
+    last_ties: i64,
+    select: SelectState<T>,
+    _marker: std::marker::PhantomData<(T,)>,
+}
+impl<T: Copy + Default + std::fmt::Debug + PartialOrd> grust::core::Component for WidestState<T> {
+    type Input = WidestInput<T>;
+    type Output = WidestOutput<T>;
+    fn init() -> WidestState<T> {
+        WidestState {
+            last_ties: 0i64,
+            select: <SelectState<T> as grust::core::Component>::init(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+    fn reset(&mut self) {
+        self.last_ties = 0i64;
+        <SelectState<T> as grust::core::Component>::reset(&mut self.select);
+    }
+    fn step(&mut self, input: WidestInput<T>) -> WidestOutput<T> {
+        let x = true;
+        let o = {
+            let SelectOutput { o, .. } = <SelectState<T> as grust::core::Component>::step(
+                &mut self.select,
+                SelectInput {
+                    a: input.a,
+                    b: input.b,
+                    greatest: x,
+                    _marker: std::marker::PhantomData,
+                },
+            );
+            (o)
+        };
+        let ties = self.last_ties + (if input.a == input.b { 1i64 } else { 0i64 });
+        self.last_ties = ties;
+        WidestOutput {
+            o,
+            ties,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+pub struct CountInput<T> {
+    pub a: T,
+    pub b: T,
+    pub _marker: std::marker::PhantomData<(T,)>,
+}
+pub struct CountOutput<T> {
+    pub n: i64,
+    pub _marker: std::marker::PhantomData<(T,)>,
+}
+pub struct CountState<T> {
+    last_n: i64,
+    _marker: std::marker::PhantomData<(T,)>,
+}
+impl<T: Copy + Default + std::fmt::Debug + PartialEq> grust::core::Component for CountState<T> {
+    type Input = CountInput<T>;
+    type Output = CountOutput<T>;
+    fn init() -> CountState<T> {
+        CountState {
+            last_n: 0i64,
+            _marker: std::marker::PhantomData,
+        }
+    }
+    fn reset(&mut self) {
+        self.last_n = 0i64;
+    }
+    fn step(&mut self, input: CountInput<T>) -> CountOutput<T> {
+        let n = self.last_n + (if input.a == input.b { 1i64 } else { 0i64 });
+        self.last_n = n;
+        CountOutput {
+            n,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+pub struct TestInput {
+    pub i: i64,
+    pub x: f64,
+}
+pub struct TestOutput {
+    pub pos: i64,
+    pub sat: f64,
+    pub gap: f64,
+    pub eq: bool,
+    pub sel: i64,
+    pub wid: f64,
+    pub ties: i64,
+    pub dup: i64,
+}
+pub struct TestState {
+    select: SelectState<i64>,
+    widest: WidestState<f64>,
+    count: CountState<i64>,
+}
+impl grust::core::Component for TestState {
+    type Input = TestInput;
+    type Output = TestOutput;
+    fn init() -> TestState {
+        TestState {
+            select: <SelectState<i64> as grust::core::Component>::init(),
+            widest: <WidestState<f64> as grust::core::Component>::init(),
+            count: <CountState<i64> as grust::core::Component>::init(),
+        }
+    }
+    fn reset(&mut self) {
+        <SelectState<i64> as grust::core::Component>::reset(&mut self.select);
+        <WidestState<f64> as grust::core::Component>::reset(&mut self.widest);
+        <CountState<i64> as grust::core::Component>::reset(&mut self.count);
+    }
+    fn step(&mut self, input: TestInput) -> TestOutput {
+        let pos = max(input.i, 0i64);
+        let sat = clamp(input.x, -1.0f64, 1.0f64);
+        let gap = dist(input.x, sat);
+        let eq = same([input.i, pos], [pos, input.i]);
+        let x = false;
+        let sel = {
+            let SelectOutput { o, .. } = <SelectState<i64> as grust::core::Component>::step(
+                &mut self.select,
+                SelectInput {
+                    a: input.i,
+                    b: pos,
+                    greatest: x,
+                    _marker: std::marker::PhantomData,
+                },
+            );
+            (o)
+        };
+        let (wid, ties) = {
+            let WidestOutput { o, ties, .. } = <WidestState<f64> as grust::core::Component>::step(
+                &mut self.widest,
+                WidestInput {
+                    a: input.x,
+                    b: sat,
+                    _marker: std::marker::PhantomData,
+                },
+            );
+            (o, ties)
+        };
+        let dup = {
+            let CountOutput { n, .. } = <CountState<i64> as grust::core::Component>::step(
+                &mut self.count,
+                CountInput {
+                    a: input.i,
+                    b: pos,
+                    _marker: std::marker::PhantomData,
+                },
+            );
+            (n)
+        };
+        TestOutput {
+            pos,
+            sat,
+            gap,
+            eq,
+            sel,
+            wid,
+            ties,
+            dup,
+        }
+    }
+}
@@ -0,0 +1,37 @@
+const _: fn([f64; 3usize], [f64; 3usize]) -> [f64; 3usize] = grust::std::maths::vector::sub;
+const _: fn([f64; 3usize]) -> f64 = grust::std::maths::vector::norm;
+const _: fn([[f64; 3usize]; 3usize], [f64; 3usize]) -> [f64; 3usize] =
+    grust::std::maths::matrix::mul_vec;
+const _: fn([[f64; 3usize]; 3usize]) -> [[f64; 3usize]; 3usize] =
+    grust::std::maths::matrix::transpose;
+const _: fn([f64; 3usize], f64) -> [f64; 4usize] = grust::std::maths::quaternion::from_axis_angle;
+const _: fn([f64; 4usize]) -> [[f64; 3usize]; 3usize] = grust::std::maths::quaternion::to_matrix;
+pub struct EgoMotionInput {
+    pub position: [f64; 3usize],
+    pub translation: [f64; 3usize],
+    pub yaw: f64,
+}
+pub struct EgoMotionOutput {
+    pub compensated: [f64; 3usize],
+    pub range: f64,
+}
+pub struct EgoMotionState {}
+impl grust::core::Component for EgoMotionState {
+    type Input = EgoMotionInput;
+    type Output = EgoMotionOutput;
+    fn init() -> EgoMotionState {
+        EgoMotionState {}
+    }
+    fn reset(&mut self) {}
+    fn step(&mut self, input: EgoMotionInput) -> EgoMotionOutput {
+        let rotation = grust::std::maths::quaternion::to_matrix(
+            grust::std::maths::quaternion::from_axis_angle([0.0f64, 0.0f64, 1.0f64], input.yaw),
+        );
+        let compensated = grust::std::maths::matrix::mul_vec(
+            grust::std::maths::matrix::transpose(rotation),
+            grust::std::maths::vector::sub(input.position, input.translation),
+        );
+        let range = grust::std::maths::vector::norm(compensated);
+        EgoMotionOutput { compensated, range }
+    }
+}
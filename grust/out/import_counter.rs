@@ -1,3 +1,11 @@
+#[allow(clippy::type_complexity)]
+const _: fn(bool, Option<()>) -> (i64,) = |res, tick| {
+    let utils::CounterOutput { o } = <utils::CounterState as grust::core::Component>::step(
+        &mut <utils::CounterState as grust::core::Component>::init(),
+        utils::CounterInput { res, tick },
+    );
+    (o,)
+};
 pub struct TestInput {
     pub tick: Option<()>,
 }
@@ -17,6 +25,10 @@ impl grust::core::Component for TestState {
             counter: <utils::CounterState as grust::core::Component>::init(),
         }
     }
+    fn reset(&mut self) {
+        self.last_stop = false;
+        <utils::CounterState as grust::core::Component>::reset(&mut self.counter);
+    }
     fn step(&mut self, input: TestInput) -> TestOutput {
         let x = self.last_stop;
         let y = {
@@ -30,6 +30,12 @@ impl grust::core::Component for DelayedAlarmState {
             },
         }
     }
+    fn reset(&mut self) {
+        self.last_temp = Alarm {
+            prio: Priority::Low,
+            data: 0i64,
+        };
+    }
     fn step(&mut self, input: DelayedAlarmInput) -> DelayedAlarmOutput {
         let delayed = self.last_temp;
         let temp = input.alarm;
@@ -0,0 +1,132 @@
+#[allow(clippy::type_complexity)]
+const _: fn(bool) -> (bool,) = |x| {
+    let grust::std::logic::RisingEdgeOutput { y } =
+        <grust::std::logic::RisingEdgeState as grust::core::Component>::step(
+            &mut <grust::std::logic::RisingEdgeState as grust::core::Component>::init(),
+            grust::std::logic::RisingEdgeInput { x },
+        );
+    (y,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(bool, bool) -> (bool,) = |s, r| {
+    let grust::std::logic::RsOutput { q } =
+        <grust::std::logic::RsState as grust::core::Component>::step(
+            &mut <grust::std::logic::RsState as grust::core::Component>::init(),
+            grust::std::logic::RsInput { s, r },
+        );
+    (q,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(bool, f64, f64) -> (bool, f64) = |x, pt, t| {
+    let grust::std::logic::TonOutput { q, et } =
+        <grust::std::logic::TonState as grust::core::Component>::step(
+            &mut <grust::std::logic::TonState as grust::core::Component>::init(),
+            grust::std::logic::TonInput { x, pt, t },
+        );
+    (q, et)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(bool, bool, i64) -> (bool, i64) = |cu, reset, pv| {
+    let grust::std::logic::CtuOutput { q, cv } =
+        <grust::std::logic::CtuState as grust::core::Component>::step(
+            &mut <grust::std::logic::CtuState as grust::core::Component>::init(),
+            grust::std::logic::CtuInput { cu, reset, pv },
+        );
+    (q, cv)
+};
+pub struct PumpInput {
+    pub start: bool,
+    pub stop: bool,
+    pub dry: bool,
+    pub t: f64,
+}
+pub struct PumpOutput {
+    pub running: bool,
+    pub dry_alarm: bool,
+    pub dry_time: f64,
+    pub starts_alarm: bool,
+    pub starts: i64,
+}
+pub struct PumpState {
+    rs: grust::std::logic::RsState,
+    ton: grust::std::logic::TonState,
+    rising_edge: grust::std::logic::RisingEdgeState,
+    ctu: grust::std::logic::CtuState,
+}
+impl grust::core::Component for PumpState {
+    type Input = PumpInput;
+    type Output = PumpOutput;
+    fn init() -> PumpState {
+        PumpState {
+            rs: <grust::std::logic::RsState as grust::core::Component>::init(),
+            ton: <grust::std::logic::TonState as grust::core::Component>::init(),
+            rising_edge: <grust::std::logic::RisingEdgeState as grust::core::Component>::init(),
+            ctu: <grust::std::logic::CtuState as grust::core::Component>::init(),
+        }
+    }
+    fn reset(&mut self) {
+        <grust::std::logic::RsState as grust::core::Component>::reset(&mut self.rs);
+        <grust::std::logic::TonState as grust::core::Component>::reset(&mut self.ton);
+        <grust::std::logic::RisingEdgeState as grust::core::Component>::reset(
+            &mut self.rising_edge,
+        );
+        <grust::std::logic::CtuState as grust::core::Component>::reset(&mut self.ctu);
+    }
+    fn step(&mut self, input: PumpInput) -> PumpOutput {
+        let running = {
+            let grust::std::logic::RsOutput { q } =
+                <grust::std::logic::RsState as grust::core::Component>::step(
+                    &mut self.rs,
+                    grust::std::logic::RsInput {
+                        s: input.start,
+                        r: input.stop,
+                    },
+                );
+            (q)
+        };
+        let x = running && input.dry;
+        let x_1 = 2.0f64;
+        let (dry_alarm, dry_time) = {
+            let grust::std::logic::TonOutput { q, et } =
+                <grust::std::logic::TonState as grust::core::Component>::step(
+                    &mut self.ton,
+                    grust::std::logic::TonInput {
+                        x: x,
+                        pt: x_1,
+                        t: input.t,
+                    },
+                );
+            (q, et)
+        };
+        let comp_app_rising_edge = {
+            let grust::std::logic::RisingEdgeOutput { y } =
+                <grust::std::logic::RisingEdgeState as grust::core::Component>::step(
+                    &mut self.rising_edge,
+                    grust::std::logic::RisingEdgeInput { x: running },
+                );
+            (y)
+        };
+        let x_2 = comp_app_rising_edge;
+        let x_3 = false;
+        let x_4 = 3i64;
+        let (starts_alarm, starts) = {
+            let grust::std::logic::CtuOutput { q, cv } =
+                <grust::std::logic::CtuState as grust::core::Component>::step(
+                    &mut self.ctu,
+                    grust::std::logic::CtuInput {
+                        cu: x_2,
+                        reset: x_3,
+                        pv: x_4,
+                    },
+                );
+            (q, cv)
+        };
+        PumpOutput {
+            running,
+            dry_alarm,
+            dry_time,
+            starts_alarm,
+            starts,
+        }
+    }
+}
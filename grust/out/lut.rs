@@ -0,0 +1,36 @@
+const _: fn([f64; 4usize], [f64; 4usize], f64) -> f64 = grust::std::lut::interp1;
+const _: fn([f64; 3usize], [f64; 2usize], [[f64; 2usize]; 3usize], f64, f64) -> f64 =
+    grust::std::lut::interp2_extrap;
+pub struct CruiseInput {
+    pub speed: f64,
+    pub slope: f64,
+    pub load: f64,
+}
+pub struct CruiseOutput {
+    pub gain: f64,
+    pub torque: f64,
+}
+pub struct CruiseState {}
+impl grust::core::Component for CruiseState {
+    type Input = CruiseInput;
+    type Output = CruiseOutput;
+    fn init() -> CruiseState {
+        CruiseState {}
+    }
+    fn reset(&mut self) {}
+    fn step(&mut self, input: CruiseInput) -> CruiseOutput {
+        let gain = grust::std::lut::interp1(
+            [0.0f64, 10.0f64, 20.0f64, 40.0f64],
+            [1.0f64, 0.8f64, 0.5f64, 0.5f64],
+            input.speed,
+        );
+        let torque = grust::std::lut::interp2_extrap(
+            [-5.0f64, 0.0f64, 5.0f64],
+            [0.0f64, 1.0f64],
+            [[10.0f64, 20.0f64], [20.0f64, 40.0f64], [30.0f64, 60.0f64]],
+            input.slope,
+            input.load,
+        );
+        CruiseOutput { gain, torque }
+    }
+}
@@ -1,93 +1,124 @@
+const _: fn(f64) -> f64 = std::maths::trigo::cos;
 pub fn that_use_cos(theta: f64) -> f64 {
     std::maths::trigo::cos(theta)
 }
+const _: fn(f64) -> f64 = std::maths::trigo::sin;
 pub fn that_use_sin(theta: f64) -> f64 {
     std::maths::trigo::sin(theta)
 }
+const _: fn(f64) -> f64 = std::maths::trigo::tan;
 pub fn that_use_tan(theta: f64) -> f64 {
     std::maths::trigo::tan(theta)
 }
+const _: fn(f64) -> f64 = std::maths::trigo::acos;
 pub fn that_use_acos(theta: f64) -> f64 {
     std::maths::trigo::acos(theta)
 }
+const _: fn(f64) -> f64 = std::maths::trigo::asin;
 pub fn that_use_asin(theta: f64) -> f64 {
     std::maths::trigo::asin(theta)
 }
+const _: fn(f64) -> f64 = std::maths::trigo::atan;
 pub fn that_use_atan(theta: f64) -> f64 {
     std::maths::trigo::atan(theta)
 }
+const _: fn(f64, f64) -> f64 = std::maths::trigo::atan2;
 pub fn that_use_atan2(y: f64, x: f64) -> f64 {
     std::maths::trigo::atan2(y, x)
 }
+const _: fn(f64) -> f64 = std::maths::trigo::cosh;
 pub fn that_use_cosh(theta: f64) -> f64 {
     std::maths::trigo::cosh(theta)
 }
+const _: fn(f64) -> f64 = std::maths::trigo::sinh;
 pub fn that_use_sinh(theta: f64) -> f64 {
     std::maths::trigo::sinh(theta)
 }
+const _: fn(f64) -> f64 = std::maths::trigo::tanh;
 pub fn that_use_tanh(theta: f64) -> f64 {
     std::maths::trigo::tanh(theta)
 }
+const _: fn(f64) -> f64 = std::maths::trigo::acosh;
 pub fn that_use_acosh(theta: f64) -> f64 {
     std::maths::trigo::acosh(theta)
 }
+const _: fn(f64) -> f64 = std::maths::trigo::asinh;
 pub fn that_use_asinh(theta: f64) -> f64 {
     std::maths::trigo::asinh(theta)
 }
+const _: fn(f64) -> f64 = std::maths::trigo::atanh;
 pub fn that_use_atanh(theta: f64) -> f64 {
     std::maths::trigo::atanh(theta)
 }
+const _: fn(f64) -> i64 = std::maths::round::round;
 pub fn that_use_round(x: f64) -> i64 {
     std::maths::round::round(x)
 }
+const _: fn(f64) -> i64 = std::maths::round::floor;
 pub fn that_use_floor(x: f64) -> i64 {
     std::maths::round::floor(x)
 }
+const _: fn(f64) -> i64 = std::maths::round::ceil;
 pub fn that_use_ceil(x: f64) -> i64 {
     std::maths::round::ceil(x)
 }
+const _: fn(f64) -> f64 = std::maths::exponential::exp;
 pub fn that_use_exp(x: f64) -> f64 {
     std::maths::exponential::exp(x)
 }
+const _: fn(f64) -> f64 = std::maths::exponential::ln;
 pub fn that_use_ln(x: f64) -> f64 {
     std::maths::exponential::ln(x)
 }
+const _: fn(f64, f64) -> f64 = std::maths::exponential::log;
 pub fn that_use_log(x: f64, n: f64) -> f64 {
     std::maths::exponential::log(x, n)
 }
+const _: fn(f64) -> f64 = std::maths::exponential::log10;
 pub fn that_use_log10(x: f64) -> f64 {
     std::maths::exponential::log10(x)
 }
+const _: fn(f64) -> f64 = std::maths::exponential::log2;
 pub fn that_use_log2(x: f64) -> f64 {
     std::maths::exponential::log2(x)
 }
+const _: fn(f64, f64) -> f64 = std::maths::usuals::min;
 pub fn that_use_min(x: f64, y: f64) -> f64 {
     std::maths::usuals::min(x, y)
 }
+const _: fn(i64, i64) -> i64 = std::maths::usuals::min_i;
 pub fn that_use_min_i(x: i64, y: i64) -> i64 {
     std::maths::usuals::min_i(x, y)
 }
+const _: fn(f64, f64) -> f64 = std::maths::usuals::max;
 pub fn that_use_max(x: f64, y: f64) -> f64 {
     std::maths::usuals::max(x, y)
 }
+const _: fn(i64, i64) -> i64 = std::maths::usuals::max_i;
 pub fn that_use_max_i(x: i64, y: i64) -> i64 {
     std::maths::usuals::max_i(x, y)
 }
+const _: fn(f64) -> f64 = std::maths::usuals::abs;
 pub fn that_use_abs(x: f64) -> f64 {
     std::maths::usuals::abs(x)
 }
+const _: fn(i64) -> i64 = std::maths::usuals::abs_i;
 pub fn that_use_abs_i(x: i64) -> i64 {
     std::maths::usuals::abs_i(x)
 }
+const _: fn(f64, f64) -> f64 = std::maths::usuals::pow;
 pub fn that_use_pow(x: f64, n: f64) -> f64 {
     std::maths::usuals::pow(x, n)
 }
+const _: fn(i64, i64) -> i64 = std::maths::usuals::pow_i;
 pub fn that_use_pow_i(x: i64, n: i64) -> i64 {
     std::maths::usuals::pow_i(x, n)
 }
+const _: fn(f64) -> f64 = std::maths::usuals::sqrt;
 pub fn that_use_sqrt(x: f64) -> f64 {
     std::maths::usuals::sqrt(x)
 }
+const _: fn(i64) -> f64 = std::maths::usuals::sqrt_i;
 pub fn that_use_sqrt_i(x: i64) -> f64 {
     std::maths::usuals::sqrt_i(x)
 }
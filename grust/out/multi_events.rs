@@ -25,6 +25,13 @@ impl grust::core::Component for MultipleEventsState {
             last_z: 0i64,
         }
     }
+    fn reset(&mut self) {
+        self.last_aux1 = 0i64;
+        self.last_aux2 = 0i64;
+        self.last_aux3 = 0i64;
+        self.last_x = false;
+        self.last_z = 0i64;
+    }
     fn step(&mut self, input: MultipleEventsInput) -> MultipleEventsOutput {
         let c = self.last_z;
         let x = input.v > 50i64;
@@ -91,6 +98,10 @@ impl grust::core::Component for DefineEventsState {
             last_z: 0i64,
         }
     }
+    fn reset(&mut self) {
+        self.last_d = 0.0f64;
+        self.last_z = 0i64;
+    }
     fn step(&mut self, input: DefineEventsInput) -> DefineEventsOutput {
         let (z, y, x) = match (input.a, input.b) {
             (Some(a), Some(e)) => {
@@ -151,6 +162,11 @@ impl grust::core::Component for FinalTestState {
             last_z: 0i64,
         }
     }
+    fn reset(&mut self) {
+        self.last_test = false;
+        self.last_u = 0i64;
+        self.last_z = 0i64;
+    }
     fn step(&mut self, input: FinalTestInput) -> FinalTestOutput {
         let (z, y, x) = match (input.a, input.b) {
             (Some(a), Some(_)) => {
@@ -13,6 +13,9 @@ impl grust::core::Component for AuxState {
     fn init() -> AuxState {
         AuxState { last_i: 0i64 }
     }
+    fn reset(&mut self) {
+        self.last_i = 0i64;
+    }
     fn step(&mut self, input: AuxInput) -> AuxOutput {
         let ((i3, i1, i2), ()) = {
             let (i3, i1, i2) = {
@@ -190,6 +193,12 @@ impl grust::core::Component for TestState {
             aux_2: <AuxState as grust::core::Component>::init(),
         }
     }
+    fn reset(&mut self) {
+        self.last_i = 0i64;
+        <AuxState as grust::core::Component>::reset(&mut self.aux);
+        <AuxState as grust::core::Component>::reset(&mut self.aux_1);
+        <AuxState as grust::core::Component>::reset(&mut self.aux_2);
+    }
     fn step(&mut self, input: TestInput) -> TestOutput {
         let ((i1_1, i1_2), i1_3) = std::thread::scope(|reserved_grust_thread_scope| {
             let reserved_grust_thread_kid_0 = reserved_grust_thread_scope.spawn(|| {
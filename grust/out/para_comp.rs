@@ -13,6 +13,9 @@ impl grust::core::Component for TestThreadsAuxState {
     fn init() -> TestThreadsAuxState {
         TestThreadsAuxState { last_i: 0i64 }
     }
+    fn reset(&mut self) {
+        self.last_i = 0i64;
+    }
     fn step(&mut self, input: TestThreadsAuxInput) -> TestThreadsAuxOutput {
         let ((i3, i2, (i1, i12)), ()) = {
             let (i3, i2, (i1, i12)) = ({ 7i64 * input.i }, { (input.i + 54i64) * 2i64 }, {
@@ -65,6 +68,12 @@ impl grust::core::Component for TestThreadsState {
             test_threads_aux_2: <TestThreadsAuxState as grust::core::Component>::init(),
         }
     }
+    fn reset(&mut self) {
+        self.last_i = 0i64;
+        <TestThreadsAuxState as grust::core::Component>::reset(&mut self.test_threads_aux);
+        <TestThreadsAuxState as grust::core::Component>::reset(&mut self.test_threads_aux_1);
+        <TestThreadsAuxState as grust::core::Component>::reset(&mut self.test_threads_aux_2);
+    }
     fn step(&mut self, input: TestThreadsInput) -> TestThreadsOutput {
         let ((i1_1, i1_2), i1_3) = std::thread::scope(|reserved_grust_thread_scope| {
             let reserved_grust_thread_kid_0 = reserved_grust_thread_scope.spawn(|| {
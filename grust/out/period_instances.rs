@@ -0,0 +1,1153 @@
+pub mod runtime {
+    use super::*;
+    use grust::futures::{sink::SinkExt, stream::StreamExt};
+    #[derive(Debug)]
+    pub enum RuntimeInput {
+        FastInputE(i64, std::time::Instant),
+        SlowInputE(i64, std::time::Instant),
+        FastInputS(i64, std::time::Instant),
+        SlowInputS(i64, std::time::Instant),
+        Timer(T, std::time::Instant),
+    }
+    use RuntimeInput as I;
+    impl grust::core::priority_stream::Reset for RuntimeInput {
+        fn do_reset(&self) -> bool {
+            match self {
+                I::Timer(timer, _) => grust::core::timer_stream::Timing::do_reset(timer),
+                _ => false,
+            }
+        }
+    }
+    impl PartialEq for RuntimeInput {
+        fn eq(&self, other: &Self) -> bool {
+            match (self, other) {
+                (I::FastInputE(this, _), I::FastInputE(other, _)) => this.eq(other),
+                (I::SlowInputE(this, _), I::SlowInputE(other, _)) => this.eq(other),
+                (I::FastInputS(this, _), I::FastInputS(other, _)) => this.eq(other),
+                (I::SlowInputS(this, _), I::SlowInputS(other, _)) => this.eq(other),
+                (I::Timer(this, _), I::Timer(other, _)) => this.eq(other),
+                _ => false,
+            }
+        }
+    }
+    impl RuntimeInput {
+        pub fn get_instant(&self) -> std::time::Instant {
+            match self {
+                I::FastInputE(_, _grust_reserved_instant) => *_grust_reserved_instant,
+                I::SlowInputE(_, _grust_reserved_instant) => *_grust_reserved_instant,
+                I::FastInputS(_, _grust_reserved_instant) => *_grust_reserved_instant,
+                I::SlowInputS(_, _grust_reserved_instant) => *_grust_reserved_instant,
+                I::Timer(_, _grust_reserved_instant) => *_grust_reserved_instant,
+            }
+        }
+        pub fn order(v1: &Self, v2: &Self) -> std::cmp::Ordering {
+            v1.get_instant().cmp(&v2.get_instant())
+        }
+    }
+    #[derive(Debug, PartialEq)]
+    pub enum RuntimeOutput {
+        SlowScanned(i64, std::time::Instant),
+        FastSampled(i64, std::time::Instant),
+        SlowSampled(i64, std::time::Instant),
+        FastScanned(i64, std::time::Instant),
+    }
+    use RuntimeOutput as O;
+    #[derive(Debug, Default)]
+    pub struct RuntimeInit {
+        pub fast_input_s: i64,
+        pub slow_input_s: i64,
+    }
+    #[derive(Debug, PartialEq)]
+    pub enum RuntimeTimer {
+        PeriodClock,
+        DelayFastTest,
+        TimeoutFastTest,
+        PeriodClock1,
+        DelaySlowTest,
+        TimeoutSlowTest,
+    }
+    use RuntimeTimer as T;
+    impl grust::core::timer_stream::Timing for RuntimeTimer {
+        fn get_duration(&self) -> std::time::Duration {
+            match self {
+                T::PeriodClock => std::time::Duration::from_millis(50u64),
+                T::DelayFastTest => std::time::Duration::from_millis(10u64),
+                T::TimeoutFastTest => std::time::Duration::from_millis(2000u64),
+                T::PeriodClock1 => std::time::Duration::from_millis(100u64),
+                T::DelaySlowTest => std::time::Duration::from_millis(10u64),
+                T::TimeoutSlowTest => std::time::Duration::from_millis(2000u64),
+            }
+        }
+        fn do_reset(&self) -> bool {
+            match self {
+                T::PeriodClock => false,
+                T::DelayFastTest => true,
+                T::TimeoutFastTest => true,
+                T::PeriodClock1 => false,
+                T::DelaySlowTest => true,
+                T::TimeoutSlowTest => true,
+            }
+        }
+    }
+    pub struct Runtime {
+        _grust_reserved_init_instant: std::time::Instant,
+        fast_test: fast_test_service::FastTestService,
+        slow_test: slow_test_service::SlowTestService,
+        output: grust::futures::channel::mpsc::Sender<O>,
+        timer: grust::futures::channel::mpsc::Sender<(T, std::time::Instant)>,
+    }
+    impl Runtime {
+        pub fn new(
+            _grust_reserved_init_instant: std::time::Instant,
+            output: grust::futures::channel::mpsc::Sender<O>,
+            timer: grust::futures::channel::mpsc::Sender<(T, std::time::Instant)>,
+        ) -> Runtime {
+            let fast_test = fast_test_service::FastTestService::init(
+                _grust_reserved_init_instant,
+                output.clone(),
+                timer.clone(),
+            );
+            let slow_test = slow_test_service::SlowTestService::init(
+                _grust_reserved_init_instant,
+                output.clone(),
+                timer.clone(),
+            );
+            Runtime {
+                _grust_reserved_init_instant,
+                fast_test,
+                slow_test,
+                output,
+                timer,
+            }
+        }
+        #[inline]
+        pub async fn send_timer(
+            &mut self,
+            timer: T,
+            instant: std::time::Instant,
+        ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+            self.timer.send((timer, instant)).await?;
+            Ok(())
+        }
+        pub async fn run_loop(
+            self,
+            input: impl grust::futures::Stream<Item = I>,
+            init_vals: RuntimeInit,
+        ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+            grust::futures::pin_mut!(input);
+            let mut runtime = self;
+            let RuntimeInit {
+                fast_input_s,
+                slow_input_s,
+            } = init_vals;
+            runtime.fast_test.handle_init(fast_input_s).await?;
+            runtime.slow_test.handle_init(slow_input_s).await?;
+            while let Some(input) = input.next().await {
+                match input {
+                    I::Timer(T::TimeoutFastTest, _grust_reserved_instant) => {
+                        runtime
+                            .fast_test
+                            .handle_timeout_fast_test(_grust_reserved_instant)
+                            .await?;
+                    }
+                    I::Timer(T::PeriodClock, _grust_reserved_instant) => {
+                        runtime
+                            .fast_test
+                            .handle_period_clock(_grust_reserved_instant)
+                            .await?;
+                    }
+                    I::SlowInputE(slow_input_e, _grust_reserved_instant) => {
+                        runtime
+                            .slow_test
+                            .handle_slow_input_e(_grust_reserved_instant, slow_input_e)
+                            .await?;
+                    }
+                    I::Timer(T::PeriodClock1, _grust_reserved_instant) => {
+                        runtime
+                            .slow_test
+                            .handle_period_clock_1(_grust_reserved_instant)
+                            .await?;
+                    }
+                    I::Timer(T::DelaySlowTest, _grust_reserved_instant) => {
+                        runtime
+                            .slow_test
+                            .handle_delay_slow_test(_grust_reserved_instant)
+                            .await?;
+                    }
+                    I::FastInputE(fast_input_e, _grust_reserved_instant) => {
+                        runtime
+                            .fast_test
+                            .handle_fast_input_e(_grust_reserved_instant, fast_input_e)
+                            .await?;
+                    }
+                    I::Timer(T::DelayFastTest, _grust_reserved_instant) => {
+                        runtime
+                            .fast_test
+                            .handle_delay_fast_test(_grust_reserved_instant)
+                            .await?;
+                    }
+                    I::Timer(T::TimeoutSlowTest, _grust_reserved_instant) => {
+                        runtime
+                            .slow_test
+                            .handle_timeout_slow_test(_grust_reserved_instant)
+                            .await?;
+                    }
+                    I::FastInputS(fast_input_s, _grust_reserved_instant) => {
+                        runtime
+                            .fast_test
+                            .handle_fast_input_s(_grust_reserved_instant, fast_input_s)
+                            .await?;
+                    }
+                    I::SlowInputS(slow_input_s, _grust_reserved_instant) => {
+                        runtime
+                            .slow_test
+                            .handle_slow_input_s(_grust_reserved_instant, slow_input_s)
+                            .await?;
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+    pub mod fast_test_service {
+        use super::*;
+        use grust::futures::{sink::SinkExt, stream::StreamExt};
+        mod ctx_ty {
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct SlowScanned(i64, bool);
+            impl SlowScanned {
+                pub fn set(&mut self, slow_scanned: i64) {
+                    self.1 = self.0 != slow_scanned;
+                    self.0 = slow_scanned;
+                }
+                pub fn get(&self) -> i64 {
+                    self.0
+                }
+                pub fn take(&mut self) -> i64 {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct FastScanned(i64, bool);
+            impl FastScanned {
+                pub fn set(&mut self, fast_scanned: i64) {
+                    self.1 = self.0 != fast_scanned;
+                    self.0 = fast_scanned;
+                }
+                pub fn get(&self) -> i64 {
+                    self.0
+                }
+                pub fn take(&mut self) -> i64 {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct FastInputS(i64, bool);
+            impl FastInputS {
+                pub fn set(&mut self, fast_input_s: i64) {
+                    self.1 = self.0 != fast_input_s;
+                    self.0 = fast_input_s;
+                }
+                pub fn get(&self) -> i64 {
+                    self.0
+                }
+                pub fn take(&mut self) -> i64 {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct SlowInputS(i64, bool);
+            impl SlowInputS {
+                pub fn set(&mut self, slow_input_s: i64) {
+                    self.1 = self.0 != slow_input_s;
+                    self.0 = slow_input_s;
+                }
+                pub fn get(&self) -> i64 {
+                    self.0
+                }
+                pub fn take(&mut self) -> i64 {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct FastSampled(Option<i64>, bool);
+            impl FastSampled {
+                pub fn set(&mut self, fast_sampled: Option<i64>) {
+                    self.1 = self.0 != fast_sampled;
+                    self.0 = fast_sampled;
+                }
+                pub fn get(&self) -> Option<i64> {
+                    self.0
+                }
+                pub fn take(&mut self) -> Option<i64> {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct FastInputE(Option<i64>, bool);
+            impl FastInputE {
+                pub fn set(&mut self, fast_input_e: Option<i64>) {
+                    self.1 = self.0 != fast_input_e;
+                    self.0 = fast_input_e;
+                }
+                pub fn get(&self) -> Option<i64> {
+                    self.0
+                }
+                pub fn take(&mut self) -> Option<i64> {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+        }
+        #[derive(Clone, Copy, PartialEq, Default, Debug)]
+        pub struct Context {
+            pub slow_scanned: ctx_ty::SlowScanned,
+            pub fast_scanned: ctx_ty::FastScanned,
+            pub fast_input_s: ctx_ty::FastInputS,
+            pub slow_input_s: ctx_ty::SlowInputS,
+            pub fast_sampled: ctx_ty::FastSampled,
+            pub fast_input_e: ctx_ty::FastInputE,
+        }
+        impl Context {
+            fn init() -> Context {
+                Default::default()
+            }
+            fn reset(&mut self) {
+                self.slow_scanned.reset();
+                self.fast_scanned.reset();
+                self.fast_input_s.reset();
+                self.slow_input_s.reset();
+                self.fast_sampled.reset();
+                self.fast_input_e.reset();
+            }
+        }
+        #[derive(Default)]
+        pub struct FastTestServiceStore {
+            fast_input_e: Option<(i64, std::time::Instant)>,
+            fast_input_s: Option<(i64, std::time::Instant)>,
+            period_clock: Option<((), std::time::Instant)>,
+        }
+        impl FastTestServiceStore {
+            pub fn not_empty(&self) -> bool {
+                self.fast_input_e.is_some()
+                    || self.fast_input_s.is_some()
+                    || self.period_clock.is_some()
+            }
+        }
+        pub struct FastTestService {
+            _grust_reserved_init_instant: std::time::Instant,
+            context: Context,
+            delayed: bool,
+            input_store: FastTestServiceStore,
+            output: grust::futures::channel::mpsc::Sender<O>,
+            timer: grust::futures::channel::mpsc::Sender<(T, std::time::Instant)>,
+        }
+        impl FastTestService {
+            pub fn init(
+                _grust_reserved_init_instant: std::time::Instant,
+                output: grust::futures::channel::mpsc::Sender<O>,
+                timer: grust::futures::channel::mpsc::Sender<(T, std::time::Instant)>,
+            ) -> FastTestService {
+                let context = Context::init();
+                let delayed = true;
+                let input_store = Default::default();
+                FastTestService {
+                    _grust_reserved_init_instant,
+                    context,
+                    delayed,
+                    input_store,
+                    output,
+                    timer,
+                }
+            }
+            pub async fn handle_init(
+                &mut self,
+                fast_input_s: i64,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                let _grust_reserved_instant = self._grust_reserved_init_instant;
+                self.reset_service_timeout(_grust_reserved_instant).await?;
+                let fast_sampled_ref = &mut None;
+                let clock_ref = &mut None;
+                self.send_timer(T::PeriodClock, _grust_reserved_instant)
+                    .await?;
+                *clock_ref = Some(
+                    (_grust_reserved_instant
+                        .duration_since(self._grust_reserved_init_instant)
+                        .as_millis()) as f64,
+                );
+                if clock_ref.is_some() {
+                    *fast_sampled_ref = self.context.fast_input_e.take();
+                }
+                self.context.fast_input_s.set(fast_input_s);
+                if clock_ref.is_some() {
+                    self.context.fast_scanned.set(fast_input_s);
+                }
+                self.send_output(
+                    O::SlowScanned(self.context.slow_scanned.get(), _grust_reserved_instant),
+                    _grust_reserved_instant,
+                )
+                .await?;
+                if let Some(fast_sampled) = *fast_sampled_ref {
+                    self.send_output(
+                        O::FastSampled(fast_sampled, _grust_reserved_instant),
+                        _grust_reserved_instant,
+                    )
+                    .await?;
+                }
+                self.send_output(
+                    O::FastScanned(self.context.fast_scanned.get(), _grust_reserved_instant),
+                    _grust_reserved_instant,
+                )
+                .await?;
+                Ok(())
+            }
+            pub async fn handle_delay_fast_test(
+                &mut self,
+                _grust_reserved_instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.context.reset();
+                if self.input_store.not_empty() {
+                    self.reset_time_constraints(_grust_reserved_instant).await?;
+                    let period_clock_ref = &mut None;
+                    let fast_input_e_ref = &mut None;
+                    let clock_ref = &mut None;
+                    let fast_sampled_ref = &mut None;
+                    let _period_clock_input_store = self.input_store.period_clock.take();
+                    if let Some((_, _period_clock_instant)) = _period_clock_input_store {
+                        self.send_timer(T::PeriodClock, _period_clock_instant)
+                            .await?;
+                    }
+                    *period_clock_ref = _period_clock_input_store.map(|(x, _)| x);
+                    *clock_ref = _period_clock_input_store.map(|(_, y)| {
+                        (y.duration_since(self._grust_reserved_init_instant)
+                            .as_millis()) as f64
+                    });
+                    let _fast_input_s_input_store = self.input_store.fast_input_s.take();
+                    if let Some((fast_input_s, _)) = _fast_input_s_input_store {
+                        self.context.fast_input_s.set(fast_input_s);
+                    }
+                    if clock_ref.is_some() {
+                        self.context
+                            .fast_scanned
+                            .set(self.context.fast_input_s.get());
+                    }
+                    if self.context.fast_scanned.is_new() {
+                        self.send_output(
+                            O::FastScanned(
+                                self.context.fast_scanned.get(),
+                                _grust_reserved_instant,
+                            ),
+                            _grust_reserved_instant,
+                        )
+                        .await?;
+                    }
+                    let _fast_input_e_input_store = self.input_store.fast_input_e.take();
+                    *fast_input_e_ref = _fast_input_e_input_store.map(|(x, _)| x);
+                    if fast_input_e_ref.is_some() {
+                        self.context.fast_input_e.set(*fast_input_e_ref);
+                    }
+                    if clock_ref.is_some() {
+                        *fast_sampled_ref = self.context.fast_input_e.take();
+                    }
+                    if let Some(fast_sampled) = *fast_sampled_ref {
+                        self.send_output(
+                            O::FastSampled(fast_sampled, _grust_reserved_instant),
+                            _grust_reserved_instant,
+                        )
+                        .await?;
+                    }
+                } else {
+                    self.delayed = true;
+                }
+                Ok(())
+            }
+            #[inline]
+            pub async fn reset_service_delay(
+                &mut self,
+                _grust_reserved_instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.timer
+                    .send((T::DelayFastTest, _grust_reserved_instant))
+                    .await?;
+                self.delayed = false;
+                Ok(())
+            }
+            pub async fn handle_fast_input_e(
+                &mut self,
+                _fast_input_e_instant: std::time::Instant,
+                fast_input_e: i64,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                if self.delayed {
+                    self.reset_time_constraints(_fast_input_e_instant).await?;
+                    self.context.reset();
+                    let fast_input_e_ref = &mut None;
+                    *fast_input_e_ref = Some(fast_input_e);
+                    if fast_input_e_ref.is_some() {
+                        self.context.fast_input_e.set(*fast_input_e_ref);
+                    }
+                } else {
+                    let unique = self
+                        .input_store
+                        .fast_input_e
+                        .replace((fast_input_e, _fast_input_e_instant));
+                    assert!
+                    (unique.is_none(),
+                    "flow `fast_input_e` changes twice within one minimal delay of the service, consider reducing this delay");
+                }
+                Ok(())
+            }
+            pub async fn handle_timeout_fast_test(
+                &mut self,
+                _timeout_fast_test_instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.reset_time_constraints(_timeout_fast_test_instant)
+                    .await?;
+                self.context.reset();
+                self.send_output(
+                    O::SlowScanned(self.context.slow_scanned.get(), _timeout_fast_test_instant),
+                    _timeout_fast_test_instant,
+                )
+                .await?;
+                self.send_output(
+                    O::FastScanned(self.context.fast_scanned.get(), _timeout_fast_test_instant),
+                    _timeout_fast_test_instant,
+                )
+                .await?;
+                Ok(())
+            }
+            #[inline]
+            pub async fn reset_service_timeout(
+                &mut self,
+                _timeout_fast_test_instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.timer
+                    .send((T::TimeoutFastTest, _timeout_fast_test_instant))
+                    .await?;
+                Ok(())
+            }
+            pub async fn handle_fast_input_s(
+                &mut self,
+                _fast_input_s_instant: std::time::Instant,
+                fast_input_s: i64,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                if self.delayed {
+                    self.reset_time_constraints(_fast_input_s_instant).await?;
+                    self.context.reset();
+                    self.context.fast_input_s.set(fast_input_s);
+                    if self.context.fast_scanned.is_new() {
+                        self.send_output(
+                            O::FastScanned(self.context.fast_scanned.get(), _fast_input_s_instant),
+                            _fast_input_s_instant,
+                        )
+                        .await?;
+                    }
+                } else {
+                    let unique = self
+                        .input_store
+                        .fast_input_s
+                        .replace((fast_input_s, _fast_input_s_instant));
+                    assert!
+                    (unique.is_none(),
+                    "flow `fast_input_s` changes twice within one minimal delay of the service, consider reducing this delay");
+                }
+                Ok(())
+            }
+            pub async fn handle_period_clock(
+                &mut self,
+                _period_clock_instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                if self.delayed {
+                    self.reset_time_constraints(_period_clock_instant).await?;
+                    self.context.reset();
+                    let fast_sampled_ref = &mut None;
+                    let clock_ref = &mut None;
+                    self.send_timer(T::PeriodClock, _period_clock_instant)
+                        .await?;
+                    *clock_ref = Some(
+                        (_period_clock_instant
+                            .duration_since(self._grust_reserved_init_instant)
+                            .as_millis()) as f64,
+                    );
+                    if clock_ref.is_some() {
+                        self.context
+                            .fast_scanned
+                            .set(self.context.fast_input_s.get());
+                    }
+                    if self.context.fast_scanned.is_new() {
+                        self.send_output(
+                            O::FastScanned(self.context.fast_scanned.get(), _period_clock_instant),
+                            _period_clock_instant,
+                        )
+                        .await?;
+                    }
+                    if clock_ref.is_some() {
+                        *fast_sampled_ref = self.context.fast_input_e.take();
+                    }
+                    if let Some(fast_sampled) = *fast_sampled_ref {
+                        self.send_output(
+                            O::FastSampled(fast_sampled, _period_clock_instant),
+                            _period_clock_instant,
+                        )
+                        .await?;
+                    }
+                } else {
+                    let unique = self
+                        .input_store
+                        .period_clock
+                        .replace(((), _period_clock_instant));
+                    assert!
+                    (unique.is_none(),
+                    "flow `period_clock` changes twice within one minimal delay of the service, consider reducing this delay");
+                }
+                Ok(())
+            }
+            #[inline]
+            pub async fn reset_time_constraints(
+                &mut self,
+                instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.reset_service_delay(instant).await?;
+                Ok(())
+            }
+            #[inline]
+            pub async fn send_output(
+                &mut self,
+                output: O,
+                instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.reset_service_timeout(instant).await?;
+                self.output.feed(output).await?;
+                Ok(())
+            }
+            #[inline]
+            pub async fn send_timer(
+                &mut self,
+                timer: T,
+                instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.timer.feed((timer, instant)).await?;
+                Ok(())
+            }
+        }
+    }
+    pub mod slow_test_service {
+        use super::*;
+        use grust::futures::{sink::SinkExt, stream::StreamExt};
+        mod ctx_ty {
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct FastScanned(i64, bool);
+            impl FastScanned {
+                pub fn set(&mut self, fast_scanned: i64) {
+                    self.1 = self.0 != fast_scanned;
+                    self.0 = fast_scanned;
+                }
+                pub fn get(&self) -> i64 {
+                    self.0
+                }
+                pub fn take(&mut self) -> i64 {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct FastInputS(i64, bool);
+            impl FastInputS {
+                pub fn set(&mut self, fast_input_s: i64) {
+                    self.1 = self.0 != fast_input_s;
+                    self.0 = fast_input_s;
+                }
+                pub fn get(&self) -> i64 {
+                    self.0
+                }
+                pub fn take(&mut self) -> i64 {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct SlowInputS(i64, bool);
+            impl SlowInputS {
+                pub fn set(&mut self, slow_input_s: i64) {
+                    self.1 = self.0 != slow_input_s;
+                    self.0 = slow_input_s;
+                }
+                pub fn get(&self) -> i64 {
+                    self.0
+                }
+                pub fn take(&mut self) -> i64 {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct SlowInputE(Option<i64>, bool);
+            impl SlowInputE {
+                pub fn set(&mut self, slow_input_e: Option<i64>) {
+                    self.1 = self.0 != slow_input_e;
+                    self.0 = slow_input_e;
+                }
+                pub fn get(&self) -> Option<i64> {
+                    self.0
+                }
+                pub fn take(&mut self) -> Option<i64> {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct SlowSampled(Option<i64>, bool);
+            impl SlowSampled {
+                pub fn set(&mut self, slow_sampled: Option<i64>) {
+                    self.1 = self.0 != slow_sampled;
+                    self.0 = slow_sampled;
+                }
+                pub fn get(&self) -> Option<i64> {
+                    self.0
+                }
+                pub fn take(&mut self) -> Option<i64> {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct SlowScanned(i64, bool);
+            impl SlowScanned {
+                pub fn set(&mut self, slow_scanned: i64) {
+                    self.1 = self.0 != slow_scanned;
+                    self.0 = slow_scanned;
+                }
+                pub fn get(&self) -> i64 {
+                    self.0
+                }
+                pub fn take(&mut self) -> i64 {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+        }
+        #[derive(Clone, Copy, PartialEq, Default, Debug)]
+        pub struct Context {
+            pub fast_scanned: ctx_ty::FastScanned,
+            pub fast_input_s: ctx_ty::FastInputS,
+            pub slow_input_s: ctx_ty::SlowInputS,
+            pub slow_input_e: ctx_ty::SlowInputE,
+            pub slow_sampled: ctx_ty::SlowSampled,
+            pub slow_scanned: ctx_ty::SlowScanned,
+        }
+        impl Context {
+            fn init() -> Context {
+                Default::default()
+            }
+            fn reset(&mut self) {
+                self.fast_scanned.reset();
+                self.fast_input_s.reset();
+                self.slow_input_s.reset();
+                self.slow_input_e.reset();
+                self.slow_sampled.reset();
+                self.slow_scanned.reset();
+            }
+        }
+        #[derive(Default)]
+        pub struct SlowTestServiceStore {
+            slow_input_e: Option<(i64, std::time::Instant)>,
+            period_clock_1: Option<((), std::time::Instant)>,
+            slow_input_s: Option<(i64, std::time::Instant)>,
+        }
+        impl SlowTestServiceStore {
+            pub fn not_empty(&self) -> bool {
+                self.slow_input_e.is_some()
+                    || self.period_clock_1.is_some()
+                    || self.slow_input_s.is_some()
+            }
+        }
+        pub struct SlowTestService {
+            _grust_reserved_init_instant: std::time::Instant,
+            context: Context,
+            delayed: bool,
+            input_store: SlowTestServiceStore,
+            output: grust::futures::channel::mpsc::Sender<O>,
+            timer: grust::futures::channel::mpsc::Sender<(T, std::time::Instant)>,
+        }
+        impl SlowTestService {
+            pub fn init(
+                _grust_reserved_init_instant: std::time::Instant,
+                output: grust::futures::channel::mpsc::Sender<O>,
+                timer: grust::futures::channel::mpsc::Sender<(T, std::time::Instant)>,
+            ) -> SlowTestService {
+                let context = Context::init();
+                let delayed = true;
+                let input_store = Default::default();
+                SlowTestService {
+                    _grust_reserved_init_instant,
+                    context,
+                    delayed,
+                    input_store,
+                    output,
+                    timer,
+                }
+            }
+            pub async fn handle_init(
+                &mut self,
+                slow_input_s: i64,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                let _grust_reserved_instant = self._grust_reserved_init_instant;
+                self.reset_service_timeout(_grust_reserved_instant).await?;
+                let slow_sampled_ref = &mut None;
+                let clock_ref = &mut None;
+                self.context.slow_input_s.set(slow_input_s);
+                self.send_timer(T::PeriodClock1, _grust_reserved_instant)
+                    .await?;
+                *clock_ref = Some(
+                    (_grust_reserved_instant
+                        .duration_since(self._grust_reserved_init_instant)
+                        .as_millis()) as f64,
+                );
+                if clock_ref.is_some() {
+                    self.context.slow_scanned.set(slow_input_s);
+                }
+                if clock_ref.is_some() {
+                    *slow_sampled_ref = self.context.slow_input_e.take();
+                }
+                self.send_output(
+                    O::SlowScanned(self.context.slow_scanned.get(), _grust_reserved_instant),
+                    _grust_reserved_instant,
+                )
+                .await?;
+                if let Some(slow_sampled) = *slow_sampled_ref {
+                    self.send_output(
+                        O::SlowSampled(slow_sampled, _grust_reserved_instant),
+                        _grust_reserved_instant,
+                    )
+                    .await?;
+                }
+                self.send_output(
+                    O::FastScanned(self.context.fast_scanned.get(), _grust_reserved_instant),
+                    _grust_reserved_instant,
+                )
+                .await?;
+                Ok(())
+            }
+            pub async fn handle_timeout_slow_test(
+                &mut self,
+                _timeout_slow_test_instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.reset_time_constraints(_timeout_slow_test_instant)
+                    .await?;
+                self.context.reset();
+                self.send_output(
+                    O::SlowScanned(self.context.slow_scanned.get(), _timeout_slow_test_instant),
+                    _timeout_slow_test_instant,
+                )
+                .await?;
+                self.send_output(
+                    O::FastScanned(self.context.fast_scanned.get(), _timeout_slow_test_instant),
+                    _timeout_slow_test_instant,
+                )
+                .await?;
+                Ok(())
+            }
+            #[inline]
+            pub async fn reset_service_timeout(
+                &mut self,
+                _timeout_slow_test_instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.timer
+                    .send((T::TimeoutSlowTest, _timeout_slow_test_instant))
+                    .await?;
+                Ok(())
+            }
+            pub async fn handle_slow_input_e(
+                &mut self,
+                _slow_input_e_instant: std::time::Instant,
+                slow_input_e: i64,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                if self.delayed {
+                    self.reset_time_constraints(_slow_input_e_instant).await?;
+                    self.context.reset();
+                    let slow_input_e_ref = &mut None;
+                    *slow_input_e_ref = Some(slow_input_e);
+                    if slow_input_e_ref.is_some() {
+                        self.context.slow_input_e.set(*slow_input_e_ref);
+                    }
+                } else {
+                    let unique = self
+                        .input_store
+                        .slow_input_e
+                        .replace((slow_input_e, _slow_input_e_instant));
+                    assert!
+                    (unique.is_none(),
+                    "flow `slow_input_e` changes twice within one minimal delay of the service, consider reducing this delay");
+                }
+                Ok(())
+            }
+            pub async fn handle_period_clock_1(
+                &mut self,
+                _period_clock_1_instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                if self.delayed {
+                    self.reset_time_constraints(_period_clock_1_instant).await?;
+                    self.context.reset();
+                    let slow_sampled_ref = &mut None;
+                    let clock_ref = &mut None;
+                    self.send_timer(T::PeriodClock1, _period_clock_1_instant)
+                        .await?;
+                    *clock_ref = Some(
+                        (_period_clock_1_instant
+                            .duration_since(self._grust_reserved_init_instant)
+                            .as_millis()) as f64,
+                    );
+                    if clock_ref.is_some() {
+                        self.context
+                            .slow_scanned
+                            .set(self.context.slow_input_s.get());
+                    }
+                    if self.context.slow_scanned.is_new() {
+                        self.send_output(
+                            O::SlowScanned(
+                                self.context.slow_scanned.get(),
+                                _period_clock_1_instant,
+                            ),
+                            _period_clock_1_instant,
+                        )
+                        .await?;
+                    }
+                    if clock_ref.is_some() {
+                        *slow_sampled_ref = self.context.slow_input_e.take();
+                    }
+                    if let Some(slow_sampled) = *slow_sampled_ref {
+                        self.send_output(
+                            O::SlowSampled(slow_sampled, _period_clock_1_instant),
+                            _period_clock_1_instant,
+                        )
+                        .await?;
+                    }
+                } else {
+                    let unique = self
+                        .input_store
+                        .period_clock_1
+                        .replace(((), _period_clock_1_instant));
+                    assert!
+                    (unique.is_none(),
+                    "flow `period_clock_1` changes twice within one minimal delay of the service, consider reducing this delay");
+                }
+                Ok(())
+            }
+            pub async fn handle_delay_slow_test(
+                &mut self,
+                _grust_reserved_instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.context.reset();
+                if self.input_store.not_empty() {
+                    self.reset_time_constraints(_grust_reserved_instant).await?;
+                    let slow_input_e_ref = &mut None;
+                    let slow_sampled_ref = &mut None;
+                    let period_clock_1_ref = &mut None;
+                    let clock_ref = &mut None;
+                    let _slow_input_s_input_store = self.input_store.slow_input_s.take();
+                    if let Some((slow_input_s, _)) = _slow_input_s_input_store {
+                        self.context.slow_input_s.set(slow_input_s);
+                    }
+                    let _period_clock_1_input_store = self.input_store.period_clock_1.take();
+                    if let Some((_, _period_clock_1_instant)) = _period_clock_1_input_store {
+                        self.send_timer(T::PeriodClock1, _period_clock_1_instant)
+                            .await?;
+                    }
+                    *period_clock_1_ref = _period_clock_1_input_store.map(|(x, _)| x);
+                    *clock_ref = _period_clock_1_input_store.map(|(_, y)| {
+                        (y.duration_since(self._grust_reserved_init_instant)
+                            .as_millis()) as f64
+                    });
+                    if clock_ref.is_some() {
+                        self.context
+                            .slow_scanned
+                            .set(self.context.slow_input_s.get());
+                    }
+                    if self.context.slow_scanned.is_new() {
+                        self.send_output(
+                            O::SlowScanned(
+                                self.context.slow_scanned.get(),
+                                _grust_reserved_instant,
+                            ),
+                            _grust_reserved_instant,
+                        )
+                        .await?;
+                    }
+                    let _slow_input_e_input_store = self.input_store.slow_input_e.take();
+                    *slow_input_e_ref = _slow_input_e_input_store.map(|(x, _)| x);
+                    if slow_input_e_ref.is_some() {
+                        self.context.slow_input_e.set(*slow_input_e_ref);
+                    }
+                    if clock_ref.is_some() {
+                        *slow_sampled_ref = self.context.slow_input_e.take();
+                    }
+                    if let Some(slow_sampled) = *slow_sampled_ref {
+                        self.send_output(
+                            O::SlowSampled(slow_sampled, _grust_reserved_instant),
+                            _grust_reserved_instant,
+                        )
+                        .await?;
+                    }
+                } else {
+                    self.delayed = true;
+                }
+                Ok(())
+            }
+            #[inline]
+            pub async fn reset_service_delay(
+                &mut self,
+                _grust_reserved_instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.timer
+                    .send((T::DelaySlowTest, _grust_reserved_instant))
+                    .await?;
+                self.delayed = false;
+                Ok(())
+            }
+            pub async fn handle_slow_input_s(
+                &mut self,
+                _slow_input_s_instant: std::time::Instant,
+                slow_input_s: i64,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                if self.delayed {
+                    self.reset_time_constraints(_slow_input_s_instant).await?;
+                    self.context.reset();
+                    self.context.slow_input_s.set(slow_input_s);
+                    if self.context.slow_scanned.is_new() {
+                        self.send_output(
+                            O::SlowScanned(self.context.slow_scanned.get(), _slow_input_s_instant),
+                            _slow_input_s_instant,
+                        )
+                        .await?;
+                    }
+                } else {
+                    let unique = self
+                        .input_store
+                        .slow_input_s
+                        .replace((slow_input_s, _slow_input_s_instant));
+                    assert!
+                    (unique.is_none(),
+                    "flow `slow_input_s` changes twice within one minimal delay of the service, consider reducing this delay");
+                }
+                Ok(())
+            }
+            #[inline]
+            pub async fn reset_time_constraints(
+                &mut self,
+                instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.reset_service_delay(instant).await?;
+                Ok(())
+            }
+            #[inline]
+            pub async fn send_output(
+                &mut self,
+                output: O,
+                instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.reset_service_timeout(instant).await?;
+                self.output.feed(output).await?;
+                Ok(())
+            }
+            #[inline]
+            pub async fn send_timer(
+                &mut self,
+                timer: T,
+                instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.timer.feed((timer, instant)).await?;
+                Ok(())
+            }
+        }
+    }
+}
+use grust::futures::{Stream, StreamExt};
+pub fn run(
+    _grust_reserved_init_instant: std::time::Instant,
+    input_stream: impl Stream<Item = runtime::RuntimeInput> + Send + 'static,
+    init_signals: runtime::RuntimeInit,
+) -> grust::futures::channel::mpsc::Receiver<runtime::RuntimeOutput> {
+    const TIMER_CHANNEL_SIZE: usize = 6usize + 2;
+    const TIMER_STREAM_SIZE: usize = 6usize + 2;
+    let (timers_sink, timers_stream) = grust::futures::channel::mpsc::channel(TIMER_CHANNEL_SIZE);
+    let timers_stream =
+        grust::core::timer_stream::timer_stream::<_, _, TIMER_STREAM_SIZE>(timers_stream)
+            .map(|(timer, deadline)| runtime::RuntimeInput::Timer(timer, deadline));
+    const OUTPUT_CHANNEL_SIZE: usize = 4usize;
+    let (output_sink, output_stream) = grust::futures::channel::mpsc::channel(OUTPUT_CHANNEL_SIZE);
+    const PRIO_STREAM_SIZE: usize = 5usize;
+    let prio_stream = grust::core::priority_stream::prio_stream::<_, _, PRIO_STREAM_SIZE>(
+        grust::futures::stream::select(input_stream, timers_stream),
+        runtime::RuntimeInput::order,
+    );
+    let service = runtime::Runtime::new(_grust_reserved_init_instant, output_sink, timers_sink);
+    grust::tokio::spawn(async move {
+        let result = service.run_loop(prio_stream, init_signals).await;
+        assert!(result.is_ok())
+    });
+    output_stream
+}
@@ -0,0 +1,80 @@
+pub struct CounterInput {
+    pub res: bool,
+    pub tick: bool,
+}
+pub struct CounterOutput {
+    pub o: i64,
+}
+pub struct CounterState {
+    last_o: i64,
+}
+impl grust::core::Component for CounterState {
+    type Input = CounterInput;
+    type Output = CounterOutput;
+    fn init() -> CounterState {
+        CounterState { last_o: 0i64 }
+    }
+    fn reset(&mut self) {
+        self.last_o = 0i64;
+    }
+    fn step(&mut self, input: CounterInput) -> CounterOutput {
+        let inc = if input.tick { 1i64 } else { 0i64 };
+        let o = if input.res { 0i64 } else { self.last_o + inc };
+        self.last_o = o;
+        CounterOutput { o }
+    }
+}
+pub struct LapCounterInput {
+    pub tick: bool,
+    pub lap: bool,
+}
+pub struct LapCounterOutput {
+    pub total: i64,
+    pub current: i64,
+}
+pub struct LapCounterState {
+    counter: CounterState,
+    counter_1: CounterState,
+}
+impl grust::core::Component for LapCounterState {
+    type Input = LapCounterInput;
+    type Output = LapCounterOutput;
+    fn init() -> LapCounterState {
+        LapCounterState {
+            counter: <CounterState as grust::core::Component>::init(),
+            counter_1: <CounterState as grust::core::Component>::init(),
+        }
+    }
+    fn reset(&mut self) {
+        <CounterState as grust::core::Component>::reset(&mut self.counter);
+        <CounterState as grust::core::Component>::reset(&mut self.counter_1);
+    }
+    fn step(&mut self, input: LapCounterInput) -> LapCounterOutput {
+        let x = false;
+        let total = {
+            let CounterOutput { o } = <CounterState as grust::core::Component>::step(
+                &mut self.counter,
+                CounterInput {
+                    res: x,
+                    tick: input.tick,
+                },
+            );
+            (o)
+        };
+        let x_1 = false;
+        let current = {
+            if input.lap {
+                <CounterState as grust::core::Component>::reset(&mut self.counter_1);
+            }
+            let CounterOutput { o } = <CounterState as grust::core::Component>::step(
+                &mut self.counter_1,
+                CounterInput {
+                    res: x_1,
+                    tick: input.tick,
+                },
+            );
+            (o)
+        };
+        LapCounterOutput { total, current }
+    }
+}
@@ -112,6 +112,9 @@ impl grust::core::Component for ProcessSetSpeedState {
     fn init() -> ProcessSetSpeedState {
         ProcessSetSpeedState { last_v_set: 0.0f64 }
     }
+    fn reset(&mut self) {
+        self.last_v_set = 0.0f64;
+    }
     fn step(&mut self, input: ProcessSetSpeedInput) -> ProcessSetSpeedOutput {
         let prev_v_set = self.last_v_set;
         let v_set = match (input.set_speed) {
@@ -151,6 +154,10 @@ impl grust::core::Component for SpeedLimiterOnState {
             last_kickdown_state: Kickdown::Deactivated,
         }
     }
+    fn reset(&mut self) {
+        self.last_hysterisis = new_hysterisis(0.0f64);
+        self.last_kickdown_state = Kickdown::Deactivated;
+    }
     fn step(&mut self, input: SpeedLimiterOnInput) -> SpeedLimiterOnOutput {
         let prev_hysterisis = self.last_hysterisis;
         let kickdown_state = match (input.kickdown) {
@@ -237,6 +244,11 @@ impl grust::core::Component for SpeedLimiterState {
             speed_limiter_on: <SpeedLimiterOnState as grust::core::Component>::init(),
         }
     }
+    fn reset(&mut self) {
+        self.last_on_state = SpeedLimiterOn::StandBy;
+        self.last_state = SpeedLimiter::Off;
+        <SpeedLimiterOnState as grust::core::Component>::reset(&mut self.speed_limiter_on);
+    }
     fn step(&mut self, input: SpeedLimiterInput) -> SpeedLimiterOutput {
         let prev_state = self.last_state;
         let prev_on_state = self.last_on_state;
@@ -0,0 +1,108 @@
+pub struct TestInput {
+    pub a: [f64; 3usize],
+    pub tick: bool,
+}
+pub struct TestOutput {
+    pub s: f64,
+    pub up: i64,
+    pub down: i64,
+}
+pub struct TestState {
+    counter: Counter010State,
+    counter_1: Counter100M1State,
+}
+impl grust::core::Component for TestState {
+    type Input = TestInput;
+    type Output = TestOutput;
+    fn init() -> TestState {
+        TestState {
+            counter: <Counter010State as grust::core::Component>::init(),
+            counter_1: <Counter100M1State as grust::core::Component>::init(),
+        }
+    }
+    fn reset(&mut self) {
+        <Counter010State as grust::core::Component>::reset(&mut self.counter);
+        <Counter100M1State as grust::core::Component>::reset(&mut self.counter_1);
+    }
+    fn step(&mut self, input: TestInput) -> TestOutput {
+        let s = scaled_sum_3_0p5(input.a);
+        let up = {
+            let Counter010Output { o } = <Counter010State as grust::core::Component>::step(
+                &mut self.counter,
+                Counter010Input { tick: input.tick },
+            );
+            (o)
+        };
+        let down = {
+            let Counter100M1Output { o } = <Counter100M1State as grust::core::Component>::step(
+                &mut self.counter_1,
+                Counter100M1Input { tick: input.tick },
+            );
+            (o)
+        };
+        TestOutput { s, up, down }
+    }
+}
+pub struct Counter100M1Input {
+    pub tick: bool,
+}
+pub struct Counter100M1Output {
+    pub o: i64,
+}
+pub struct Counter100M1State {
+    last_o: i64,
+}
+impl grust::core::Component for Counter100M1State {
+    type Input = Counter100M1Input;
+    type Output = Counter100M1Output;
+    fn init() -> Counter100M1State {
+        Counter100M1State { last_o: 100i64 }
+    }
+    fn reset(&mut self) {
+        self.last_o = 100i64;
+    }
+    fn step(&mut self, input: Counter100M1Input) -> Counter100M1Output {
+        let o = if input.tick {
+            self.last_o + -1i64
+        } else {
+            self.last_o
+        };
+        self.last_o = o;
+        Counter100M1Output { o }
+    }
+}
+pub struct Counter010Input {
+    pub tick: bool,
+}
+pub struct Counter010Output {
+    pub o: i64,
+}
+pub struct Counter010State {
+    last_o: i64,
+}
+impl grust::core::Component for Counter010State {
+    type Input = Counter010Input;
+    type Output = Counter010Output;
+    fn init() -> Counter010State {
+        Counter010State { last_o: 0i64 }
+    }
+    fn reset(&mut self) {
+        self.last_o = 0i64;
+    }
+    fn step(&mut self, input: Counter010Input) -> Counter010Output {
+        let o = if input.tick {
+            self.last_o + 10i64
+        } else {
+            self.last_o
+        };
+        self.last_o = o;
+        Counter010Output { o }
+    }
+}
+pub fn scaled_sum_3_0p5(a: [f64; 3usize]) -> f64 {
+    0.5f64 * sum_3(a)
+}
+pub fn sum_3(a: [f64; 3usize]) -> f64 {
+    a.into_iter()
+        .fold(0.0f64, |acc: f64, x: f64| -> f64 { acc + x })
+}
@@ -0,0 +1,436 @@
+const _: fn(f64) -> f64 = grust::std::maths::trigo::cos;
+const _: fn(f64) -> f64 = grust::std::maths::trigo::sin;
+const _: fn(f64) -> f64 = grust::std::maths::trigo::tan;
+const _: fn(f64) -> f64 = grust::std::maths::trigo::acos;
+const _: fn(f64) -> f64 = grust::std::maths::trigo::asin;
+const _: fn(f64) -> f64 = grust::std::maths::trigo::atan;
+const _: fn(f64, f64) -> f64 = grust::std::maths::trigo::atan2;
+const _: fn(f64) -> f64 = grust::std::maths::trigo::cosh;
+const _: fn(f64) -> f64 = grust::std::maths::trigo::sinh;
+const _: fn(f64) -> f64 = grust::std::maths::trigo::tanh;
+const _: fn(f64) -> f64 = grust::std::maths::trigo::acosh;
+const _: fn(f64) -> f64 = grust::std::maths::trigo::asinh;
+const _: fn(f64) -> f64 = grust::std::maths::trigo::atanh;
+const _: fn(f64) -> i64 = grust::std::maths::round::round;
+const _: fn(f64) -> i64 = grust::std::maths::round::floor;
+const _: fn(f64) -> i64 = grust::std::maths::round::ceil;
+const _: fn(f64) -> f64 = grust::std::maths::exponential::exp;
+const _: fn(f64) -> f64 = grust::std::maths::exponential::ln;
+const _: fn(f64, f64) -> f64 = grust::std::maths::exponential::log;
+const _: fn(f64) -> f64 = grust::std::maths::exponential::log10;
+const _: fn(f64) -> f64 = grust::std::maths::exponential::log2;
+const _: fn(f64, f64) -> f64 = grust::std::maths::usuals::min;
+const _: fn(i64, i64) -> i64 = grust::std::maths::usuals::min_i;
+const _: fn(f64, f64) -> f64 = grust::std::maths::usuals::max;
+const _: fn(i64, i64) -> i64 = grust::std::maths::usuals::max_i;
+const _: fn(f64) -> f64 = grust::std::maths::usuals::abs;
+const _: fn(i64) -> i64 = grust::std::maths::usuals::abs_i;
+const _: fn(f64, f64) -> f64 = grust::std::maths::usuals::pow;
+const _: fn(i64, i64) -> i64 = grust::std::maths::usuals::pow_i;
+const _: fn(f64) -> f64 = grust::std::maths::usuals::sqrt;
+const _: fn(i64) -> f64 = grust::std::maths::usuals::sqrt_i;
+const _: fn([f64; 3usize], [f64; 3usize]) -> [f64; 3usize] = grust::std::maths::vector::cross;
+const _: fn([[f64; 2usize]; 2usize]) -> f64 = grust::std::maths::matrix::det2;
+const _: fn([[f64; 3usize]; 3usize]) -> f64 = grust::std::maths::matrix::det3;
+const _: fn([[f64; 2usize]; 2usize]) -> [[f64; 2usize]; 2usize] =
+    grust::std::maths::matrix::inverse2;
+const _: fn([[f64; 3usize]; 3usize]) -> [[f64; 3usize]; 3usize] =
+    grust::std::maths::matrix::inverse3;
+const _: fn([f64; 4usize], [f64; 4usize]) -> [f64; 4usize] = grust::std::maths::quaternion::mul;
+const _: fn([f64; 4usize]) -> [f64; 4usize] = grust::std::maths::quaternion::conjugate;
+const _: fn([f64; 4usize]) -> [f64; 4usize] = grust::std::maths::quaternion::normalize;
+const _: fn([f64; 3usize], f64) -> [f64; 4usize] = grust::std::maths::quaternion::from_axis_angle;
+const _: fn([f64; 4usize], [f64; 3usize]) -> [f64; 3usize] = grust::std::maths::quaternion::rotate;
+const _: fn([f64; 4usize]) -> [[f64; 3usize]; 3usize] = grust::std::maths::quaternion::to_matrix;
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64) -> (f64,) = |x, t| {
+    let grust :: std :: time :: integration :: BackwardEulerOutput { i } = <
+    grust :: std :: time :: integration :: BackwardEulerState as grust :: core
+    :: Component > ::
+    step(& mut < grust :: std :: time :: integration :: BackwardEulerState as
+    grust :: core :: Component > :: init(), grust :: std :: time ::
+    integration :: BackwardEulerInput { x, t },);
+    (i,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64) -> (f64,) = |x, t| {
+    let grust::std::time::integration::TrapezeOutput { i } =
+        <grust::std::time::integration::TrapezeState as grust::core::Component>::step(
+            &mut <grust::std::time::integration::TrapezeState as grust::core::Component>::init(),
+            grust::std::time::integration::TrapezeInput { x, t },
+        );
+    (i,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64) -> (f64,) = |x, t| {
+    let grust::std::time::derivation::DeriveOutput { d } =
+        <grust::std::time::derivation::DeriveState as grust::core::Component>::step(
+            &mut <grust::std::time::derivation::DeriveState as grust::core::Component>::init(),
+            grust::std::time::derivation::DeriveInput { x, t },
+        );
+    (d,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64, f64, f64, f64, f64, f64, f64) -> (f64,) =
+    |e, t, kp, ki, kd, tf, u_min, u_max| {
+        let grust::std::control::PidOutput { u } =
+            <grust::std::control::PidState as grust::core::Component>::step(
+                &mut <grust::std::control::PidState as grust::core::Component>::init(),
+                grust::std::control::PidInput {
+                    e,
+                    t,
+                    kp,
+                    ki,
+                    kd,
+                    tf,
+                    u_min,
+                    u_max,
+                },
+            );
+        (u,)
+    };
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64, f64, f64) -> (f64, bool) = |x, lo, hi, h| {
+    let grust::std::control::SaturationOutput { y, sat } =
+        <grust::std::control::SaturationState as grust::core::Component>::step(
+            &mut <grust::std::control::SaturationState as grust::core::Component>::init(),
+            grust::std::control::SaturationInput { x, lo, hi, h },
+        );
+    (y, sat)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64, f64, f64) -> (f64,) = |x, t, rising, falling| {
+    let grust::std::control::RateLimiterOutput { y } =
+        <grust::std::control::RateLimiterState as grust::core::Component>::step(
+            &mut <grust::std::control::RateLimiterState as grust::core::Component>::init(),
+            grust::std::control::RateLimiterInput {
+                x,
+                t,
+                rising,
+                falling,
+            },
+        );
+    (y,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64, bool, f64, f64) -> (f64,) = |auto_u, manual_u, manual, t, tau| {
+    let grust::std::control::BumplessTransferOutput { u } =
+        <grust::std::control::BumplessTransferState as grust::core::Component>::step(
+            &mut <grust::std::control::BumplessTransferState as grust::core::Component>::init(),
+            grust::std::control::BumplessTransferInput {
+                auto_u,
+                manual_u,
+                manual,
+                t,
+                tau,
+            },
+        );
+    (u,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64, f64) -> (f64,) = |x, t, tau| {
+    let grust::std::filters::LowPassOutput { y } =
+        <grust::std::filters::LowPassState as grust::core::Component>::step(
+            &mut <grust::std::filters::LowPassState as grust::core::Component>::init(),
+            grust::std::filters::LowPassInput { x, t, tau },
+        );
+    (y,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64, f64) -> (f64,) = |x, t, tau| {
+    let grust::std::filters::HighPassOutput { y } =
+        <grust::std::filters::HighPassState as grust::core::Component>::step(
+            &mut <grust::std::filters::HighPassState as grust::core::Component>::init(),
+            grust::std::filters::HighPassInput { x, t, tau },
+        );
+    (y,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64, f64, f64) -> (f64,) = |x, t, w, zeta| {
+    let grust::std::filters::LowPass2Output { y } =
+        <grust::std::filters::LowPass2State as grust::core::Component>::step(
+            &mut <grust::std::filters::LowPass2State as grust::core::Component>::init(),
+            grust::std::filters::LowPass2Input { x, t, w, zeta },
+        );
+    (y,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64, f64, f64) -> (f64,) = |x, t, w, zeta| {
+    let grust::std::filters::HighPass2Output { y } =
+        <grust::std::filters::HighPass2State as grust::core::Component>::step(
+            &mut <grust::std::filters::HighPass2State as grust::core::Component>::init(),
+            grust::std::filters::HighPass2Input { x, t, w, zeta },
+        );
+    (y,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64, i64) -> (f64,) = |x, t, n| {
+    let grust::std::filters::MovingAverageOutput { y } =
+        <grust::std::filters::MovingAverageState as grust::core::Component>::step(
+            &mut <grust::std::filters::MovingAverageState as grust::core::Component>::init(),
+            grust::std::filters::MovingAverageInput { x, t, n },
+        );
+    (y,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64, i64) -> (f64,) = |x, t, n| {
+    let grust::std::filters::MedianOutput { y } =
+        <grust::std::filters::MedianState as grust::core::Component>::step(
+            &mut <grust::std::filters::MedianState as grust::core::Component>::init(),
+            grust::std::filters::MedianInput { x, t, n },
+        );
+    (y,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64, f64, f64) -> (f64, f64) = |z, t, q, r| {
+    let grust::std::filters::KalmanOutput { est, var } =
+        <grust::std::filters::KalmanState as grust::core::Component>::step(
+            &mut <grust::std::filters::KalmanState as grust::core::Component>::init(),
+            grust::std::filters::KalmanInput { z, t, q, r },
+        );
+    (est, var)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(bool) -> (bool,) = |x| {
+    let grust::std::logic::RisingEdgeOutput { y } =
+        <grust::std::logic::RisingEdgeState as grust::core::Component>::step(
+            &mut <grust::std::logic::RisingEdgeState as grust::core::Component>::init(),
+            grust::std::logic::RisingEdgeInput { x },
+        );
+    (y,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(bool) -> (bool,) = |x| {
+    let grust::std::logic::FallingEdgeOutput { y } =
+        <grust::std::logic::FallingEdgeState as grust::core::Component>::step(
+            &mut <grust::std::logic::FallingEdgeState as grust::core::Component>::init(),
+            grust::std::logic::FallingEdgeInput { x },
+        );
+    (y,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(bool) -> (bool,) = |x| {
+    let grust::std::logic::AnyEdgeOutput { y } =
+        <grust::std::logic::AnyEdgeState as grust::core::Component>::step(
+            &mut <grust::std::logic::AnyEdgeState as grust::core::Component>::init(),
+            grust::std::logic::AnyEdgeInput { x },
+        );
+    (y,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(bool, bool) -> (bool,) = |s, r| {
+    let grust::std::logic::SrOutput { q } =
+        <grust::std::logic::SrState as grust::core::Component>::step(
+            &mut <grust::std::logic::SrState as grust::core::Component>::init(),
+            grust::std::logic::SrInput { s, r },
+        );
+    (q,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(bool, bool) -> (bool,) = |s, r| {
+    let grust::std::logic::RsOutput { q } =
+        <grust::std::logic::RsState as grust::core::Component>::step(
+            &mut <grust::std::logic::RsState as grust::core::Component>::init(),
+            grust::std::logic::RsInput { s, r },
+        );
+    (q,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(bool, f64, f64) -> (bool, f64) = |x, pt, t| {
+    let grust::std::logic::TonOutput { q, et } =
+        <grust::std::logic::TonState as grust::core::Component>::step(
+            &mut <grust::std::logic::TonState as grust::core::Component>::init(),
+            grust::std::logic::TonInput { x, pt, t },
+        );
+    (q, et)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(bool, f64, f64) -> (bool, f64) = |x, pt, t| {
+    let grust::std::logic::TofOutput { q, et } =
+        <grust::std::logic::TofState as grust::core::Component>::step(
+            &mut <grust::std::logic::TofState as grust::core::Component>::init(),
+            grust::std::logic::TofInput { x, pt, t },
+        );
+    (q, et)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(bool, f64, f64) -> (bool, f64) = |x, pt, t| {
+    let grust::std::logic::TpOutput { q, et } =
+        <grust::std::logic::TpState as grust::core::Component>::step(
+            &mut <grust::std::logic::TpState as grust::core::Component>::init(),
+            grust::std::logic::TpInput { x, pt, t },
+        );
+    (q, et)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(bool, bool, i64) -> (bool, i64) = |cu, reset, pv| {
+    let grust::std::logic::CtuOutput { q, cv } =
+        <grust::std::logic::CtuState as grust::core::Component>::step(
+            &mut <grust::std::logic::CtuState as grust::core::Component>::init(),
+            grust::std::logic::CtuInput { cu, reset, pv },
+        );
+    (q, cv)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(bool, bool, i64) -> (bool, i64) = |cd, load, pv| {
+    let grust::std::logic::CtdOutput { q, cv } =
+        <grust::std::logic::CtdState as grust::core::Component>::step(
+            &mut <grust::std::logic::CtdState as grust::core::Component>::init(),
+            grust::std::logic::CtdInput { cd, load, pv },
+        );
+    (q, cv)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(bool, bool, bool, bool, i64) -> (bool, bool, i64) = |cu, cd, reset, load, pv| {
+    let grust::std::logic::CtudOutput { qu, qd, cv } =
+        <grust::std::logic::CtudState as grust::core::Component>::step(
+            &mut <grust::std::logic::CtudState as grust::core::Component>::init(),
+            grust::std::logic::CtudInput {
+                cu,
+                cd,
+                reset,
+                load,
+                pv,
+            },
+        );
+    (qu, qd, cv)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64, f64, f64) -> (f64,) = |t, delay, initial, last| {
+    let grust::std::generators::StepOutput { y } =
+        <grust::std::generators::StepState as grust::core::Component>::step(
+            &mut <grust::std::generators::StepState as grust::core::Component>::init(),
+            grust::std::generators::StepInput {
+                t,
+                delay,
+                initial,
+                last,
+            },
+        );
+    (y,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64, f64, f64) -> (f64,) = |t, delay, slope, initial| {
+    let grust::std::generators::RampOutput { y } =
+        <grust::std::generators::RampState as grust::core::Component>::step(
+            &mut <grust::std::generators::RampState as grust::core::Component>::init(),
+            grust::std::generators::RampInput {
+                t,
+                delay,
+                slope,
+                initial,
+            },
+        );
+    (y,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64, f64, f64, f64) -> (f64,) = |t, amplitude, frequency, phase, offset| {
+    let grust::std::generators::SineOutput { y } =
+        <grust::std::generators::SineState as grust::core::Component>::step(
+            &mut <grust::std::generators::SineState as grust::core::Component>::init(),
+            grust::std::generators::SineInput {
+                t,
+                amplitude,
+                frequency,
+                phase,
+                offset,
+            },
+        );
+    (y,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64, f64, f64, f64) -> (f64,) = |t, amplitude, period, duty, offset| {
+    let grust::std::generators::SquareOutput { y } =
+        <grust::std::generators::SquareState as grust::core::Component>::step(
+            &mut <grust::std::generators::SquareState as grust::core::Component>::init(),
+            grust::std::generators::SquareInput {
+                t,
+                amplitude,
+                period,
+                duty,
+                offset,
+            },
+        );
+    (y,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64, f64, f64, f64) -> (f64,) = |t, amplitude, f0, f1, duration| {
+    let grust::std::generators::ChirpOutput { y } =
+        <grust::std::generators::ChirpState as grust::core::Component>::step(
+            &mut <grust::std::generators::ChirpState as grust::core::Component>::init(),
+            grust::std::generators::ChirpInput {
+                t,
+                amplitude,
+                f0,
+                f1,
+                duration,
+            },
+        );
+    (y,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(f64, i64, f64) -> (f64,) = |t, seed, amplitude| {
+    let grust::std::generators::NoiseOutput { y } =
+        <grust::std::generators::NoiseState as grust::core::Component>::step(
+            &mut <grust::std::generators::NoiseState as grust::core::Component>::init(),
+            grust::std::generators::NoiseInput { t, seed, amplitude },
+        );
+    (y,)
+};
+pub struct BlinkerInput {
+    pub t: f64,
+}
+pub struct BlinkerOutput {
+    pub y: f64,
+    pub on: bool,
+}
+pub struct BlinkerState {
+    square: grust::std::generators::SquareState,
+    rising_edge: grust::std::logic::RisingEdgeState,
+}
+impl grust::core::Component for BlinkerState {
+    type Input = BlinkerInput;
+    type Output = BlinkerOutput;
+    fn init() -> BlinkerState {
+        BlinkerState {
+            square: <grust::std::generators::SquareState as grust::core::Component>::init(),
+            rising_edge: <grust::std::logic::RisingEdgeState as grust::core::Component>::init(),
+        }
+    }
+    fn reset(&mut self) {
+        <grust::std::generators::SquareState as grust::core::Component>::reset(&mut self.square);
+        <grust::std::logic::RisingEdgeState as grust::core::Component>::reset(
+            &mut self.rising_edge,
+        );
+    }
+    fn step(&mut self, input: BlinkerInput) -> BlinkerOutput {
+        let x = 1.0f64;
+        let x_1 = 2.0f64;
+        let x_2 = 0.5f64;
+        let x_3 = 0.0f64;
+        let y = {
+            let grust::std::generators::SquareOutput { y } =
+                <grust::std::generators::SquareState as grust::core::Component>::step(
+                    &mut self.square,
+                    grust::std::generators::SquareInput {
+                        t: input.t,
+                        amplitude: x,
+                        period: x_1,
+                        duty: x_2,
+                        offset: x_3,
+                    },
+                );
+            (y)
+        };
+        let x_4 = y > 0.0f64;
+        let on = {
+            let grust::std::logic::RisingEdgeOutput { y } =
+                <grust::std::logic::RisingEdgeState as grust::core::Component>::step(
+                    &mut self.rising_edge,
+                    grust::std::logic::RisingEdgeInput { x: x_4 },
+                );
+            (y)
+        };
+        BlinkerOutput { y, on }
+    }
+}
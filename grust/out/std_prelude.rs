@@ -0,0 +1,240 @@
+const _: fn(f64) -> f64 = grust::std::maths::trigo::cos;
+const _: fn(f64) -> f64 = grust::std::maths::trigo::sin;
+const _: fn(f64) -> f64 = grust::std::maths::trigo::tan;
+const _: fn(f64) -> f64 = grust::std::maths::trigo::acos;
+const _: fn(f64) -> f64 = grust::std::maths::trigo::asin;
+const _: fn(f64) -> f64 = grust::std::maths::trigo::atan;
+const _: fn(f64, f64) -> f64 = grust::std::maths::trigo::atan2;
+const _: fn(f64) -> f64 = grust::std::maths::trigo::cosh;
+const _: fn(f64) -> f64 = grust::std::maths::trigo::sinh;
+const _: fn(f64) -> f64 = grust::std::maths::trigo::tanh;
+const _: fn(f64) -> f64 = grust::std::maths::trigo::acosh;
+const _: fn(f64) -> f64 = grust::std::maths::trigo::asinh;
+const _: fn(f64) -> f64 = grust::std::maths::trigo::atanh;
+const _: fn(f64) -> i64 = grust::std::maths::round::round;
+const _: fn(f64) -> i64 = grust::std::maths::round::floor;
+const _: fn(f64) -> i64 = grust::std::maths::round::ceil;
+const _: fn(f64) -> f64 = grust::std::maths::exponential::exp;
+const _: fn(f64) -> f64 = grust::std::maths::exponential::ln;
+const _: fn(f64, f64) -> f64 = grust::std::maths::exponential::log;
+const _: fn(f64) -> f64 = grust::std::maths::exponential::log10;
+const _: fn(f64) -> f64 = grust::std::maths::exponential::log2;
+const _: fn(f64, f64) -> f64 = grust::std::maths::usuals::min;
+const _: fn(i64, i64) -> i64 = grust::std::maths::usuals::min_i;
+const _: fn(f64, f64) -> f64 = grust::std::maths::usuals::max;
+const _: fn(i64, i64) -> i64 = grust::std::maths::usuals::max_i;
+const _: fn(f64) -> f64 = grust::std::maths::usuals::abs;
+const _: fn(i64) -> i64 = grust::std::maths::usuals::abs_i;
+const _: fn(f64, f64) -> f64 = grust::std::maths::usuals::pow;
+const _: fn(i64, i64) -> i64 = grust::std::maths::usuals::pow_i;
+const _: fn(f64) -> f64 = grust::std::maths::usuals::sqrt;
+const _: fn(i64) -> f64 = grust::std::maths::usuals::sqrt_i;
+const _: fn([f64; 3usize], [f64; 3usize]) -> [f64; 3usize] = grust::std::maths::vector::cross;
+const _: fn([[f64; 2usize]; 2usize]) -> f64 = grust::std::maths::matrix::det2;
+const _: fn([[f64; 3usize]; 3usize]) -> f64 = grust::std::maths::matrix::det3;
+const _: fn([[f64; 2usize]; 2usize]) -> [[f64; 2usize]; 2usize] =
+    grust::std::maths::matrix::inverse2;
+const _: fn([[f64; 3usize]; 3usize]) -> [[f64; 3usize]; 3usize] =
+    grust::std::maths::matrix::inverse3;
+const _: fn([f64; 4usize], [f64; 4usize]) -> [f64; 4usize] = grust::std::maths::quaternion::mul;
+const _: fn([f64; 4usize]) -> [f64; 4usize] = grust::std::maths::quaternion::conjugate;
+const _: fn([f64; 4usize]) -> [f64; 4usize] = grust::std::maths::quaternion::normalize;
+const _: fn([f64; 3usize], f64) -> [f64; 4usize] = grust::std::maths::quaternion::from_axis_angle;
+const _: fn([f64; 4usize], [f64; 3usize]) -> [f64; 3usize] = grust::std::maths::quaternion::rotate;
+const _: fn([f64; 4usize]) -> [[f64; 3usize]; 3usize] = grust::std::maths::quaternion::to_matrix;
+pub fn heading(x: f64, y: f64) -> f64 {
+    grust::std::maths::trigo::atan2(y, x)
+}
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64, f64, f64, f64, f64, f64, f64) -> (f64,) =
+    |e, t, kp, ki, kd, tf, u_min, u_max| {
+        let grust::std::control::PidOutput { u } =
+            <grust::std::control::PidState as grust::core::Component>::step(
+                &mut <grust::std::control::PidState as grust::core::Component>::init(),
+                grust::std::control::PidInput {
+                    e,
+                    t,
+                    kp,
+                    ki,
+                    kd,
+                    tf,
+                    u_min,
+                    u_max,
+                },
+            );
+        (u,)
+    };
+#[allow(clippy::type_complexity)]
+const _: fn(bool) -> (bool,) = |x| {
+    let grust::std::logic::RisingEdgeOutput { y } =
+        <grust::std::logic::RisingEdgeState as grust::core::Component>::step(
+            &mut <grust::std::logic::RisingEdgeState as grust::core::Component>::init(),
+            grust::std::logic::RisingEdgeInput { x },
+        );
+    (y,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(bool) -> (bool,) = |x| {
+    let grust::std::logic::FallingEdgeOutput { y } =
+        <grust::std::logic::FallingEdgeState as grust::core::Component>::step(
+            &mut <grust::std::logic::FallingEdgeState as grust::core::Component>::init(),
+            grust::std::logic::FallingEdgeInput { x },
+        );
+    (y,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(bool) -> (bool,) = |x| {
+    let grust::std::logic::AnyEdgeOutput { y } =
+        <grust::std::logic::AnyEdgeState as grust::core::Component>::step(
+            &mut <grust::std::logic::AnyEdgeState as grust::core::Component>::init(),
+            grust::std::logic::AnyEdgeInput { x },
+        );
+    (y,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(bool, bool) -> (bool,) = |s, r| {
+    let grust::std::logic::SrOutput { q } =
+        <grust::std::logic::SrState as grust::core::Component>::step(
+            &mut <grust::std::logic::SrState as grust::core::Component>::init(),
+            grust::std::logic::SrInput { s, r },
+        );
+    (q,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(bool, bool) -> (bool,) = |s, r| {
+    let grust::std::logic::RsOutput { q } =
+        <grust::std::logic::RsState as grust::core::Component>::step(
+            &mut <grust::std::logic::RsState as grust::core::Component>::init(),
+            grust::std::logic::RsInput { s, r },
+        );
+    (q,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(bool, f64, f64) -> (bool, f64) = |x, pt, t| {
+    let grust::std::logic::TonOutput { q, et } =
+        <grust::std::logic::TonState as grust::core::Component>::step(
+            &mut <grust::std::logic::TonState as grust::core::Component>::init(),
+            grust::std::logic::TonInput { x, pt, t },
+        );
+    (q, et)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(bool, f64, f64) -> (bool, f64) = |x, pt, t| {
+    let grust::std::logic::TofOutput { q, et } =
+        <grust::std::logic::TofState as grust::core::Component>::step(
+            &mut <grust::std::logic::TofState as grust::core::Component>::init(),
+            grust::std::logic::TofInput { x, pt, t },
+        );
+    (q, et)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(bool, f64, f64) -> (bool, f64) = |x, pt, t| {
+    let grust::std::logic::TpOutput { q, et } =
+        <grust::std::logic::TpState as grust::core::Component>::step(
+            &mut <grust::std::logic::TpState as grust::core::Component>::init(),
+            grust::std::logic::TpInput { x, pt, t },
+        );
+    (q, et)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(bool, bool, i64) -> (bool, i64) = |cu, reset, pv| {
+    let grust::std::logic::CtuOutput { q, cv } =
+        <grust::std::logic::CtuState as grust::core::Component>::step(
+            &mut <grust::std::logic::CtuState as grust::core::Component>::init(),
+            grust::std::logic::CtuInput { cu, reset, pv },
+        );
+    (q, cv)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(bool, bool, i64) -> (bool, i64) = |cd, load, pv| {
+    let grust::std::logic::CtdOutput { q, cv } =
+        <grust::std::logic::CtdState as grust::core::Component>::step(
+            &mut <grust::std::logic::CtdState as grust::core::Component>::init(),
+            grust::std::logic::CtdInput { cd, load, pv },
+        );
+    (q, cv)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(bool, bool, bool, bool, i64) -> (bool, bool, i64) = |cu, cd, reset, load, pv| {
+    let grust::std::logic::CtudOutput { qu, qd, cv } =
+        <grust::std::logic::CtudState as grust::core::Component>::step(
+            &mut <grust::std::logic::CtudState as grust::core::Component>::init(),
+            grust::std::logic::CtudInput {
+                cu,
+                cd,
+                reset,
+                load,
+                pv,
+            },
+        );
+    (qu, qd, cv)
+};
+pub struct SteeringInput {
+    pub x: f64,
+    pub y: f64,
+    pub target: f64,
+    pub t: f64,
+    pub enabled: bool,
+}
+pub struct SteeringOutput {
+    pub u: f64,
+    pub engaged: bool,
+}
+pub struct SteeringState {
+    sr: grust::std::logic::SrState,
+    pid: grust::std::control::PidState,
+}
+impl grust::core::Component for SteeringState {
+    type Input = SteeringInput;
+    type Output = SteeringOutput;
+    fn init() -> SteeringState {
+        SteeringState {
+            sr: <grust::std::logic::SrState as grust::core::Component>::init(),
+            pid: <grust::std::control::PidState as grust::core::Component>::init(),
+        }
+    }
+    fn reset(&mut self) {
+        <grust::std::logic::SrState as grust::core::Component>::reset(&mut self.sr);
+        <grust::std::control::PidState as grust::core::Component>::reset(&mut self.pid);
+    }
+    fn step(&mut self, input: SteeringInput) -> SteeringOutput {
+        let x = !(input.enabled);
+        let engaged = {
+            let grust::std::logic::SrOutput { q } =
+                <grust::std::logic::SrState as grust::core::Component>::step(
+                    &mut self.sr,
+                    grust::std::logic::SrInput {
+                        s: input.enabled,
+                        r: x,
+                    },
+                );
+            (q)
+        };
+        let x_1 = input.target - heading(input.x, input.y);
+        let x_2 = 2.0f64;
+        let x_3 = 0.0f64;
+        let x_4 = 0.0f64;
+        let x_5 = 0.0f64;
+        let x_6 = -1.0f64;
+        let x_7 = 1.0f64;
+        let command = {
+            let grust::std::control::PidOutput { u } =
+                <grust::std::control::PidState as grust::core::Component>::step(
+                    &mut self.pid,
+                    grust::std::control::PidInput {
+                        e: x_1,
+                        t: input.t,
+                        kp: x_2,
+                        ki: x_3,
+                        kd: x_4,
+                        tf: x_5,
+                        u_min: x_6,
+                        u_max: x_7,
+                    },
+                );
+            (u)
+        };
+        let u = if engaged { command } else { 0.0f64 };
+        SteeringOutput { u, engaged }
+    }
+}
@@ -39,6 +39,9 @@ impl grust::core::Component for BrakingStateState {
             last_state: Braking::NoBrake,
         }
     }
+    fn reset(&mut self) {
+        self.last_state = Braking::NoBrake;
+    }
     #[grust::tracing::instrument]
     fn step(&mut self, input: BrakingStateInput) -> BrakingStateOutput {
         let state = match (input.pedest, input.timeout_pedestrian) {
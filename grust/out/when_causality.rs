@@ -11,6 +11,7 @@ impl grust::core::Component for MatchOkState {
     fn init() -> MatchOkState {
         MatchOkState {}
     }
+    fn reset(&mut self) {}
     fn step(&mut self, input: MatchOkInput) -> MatchOkOutput {
         let (sampled, mem) = match input.input {
             x if x < 0i64 => {
@@ -43,6 +44,9 @@ impl grust::core::Component for WhenNowOkState {
     fn init() -> WhenNowOkState {
         WhenNowOkState { last_mem: 0i64 }
     }
+    fn reset(&mut self) {
+        self.last_mem = 0i64;
+    }
     fn step(&mut self, input: WhenNowOkInput) -> WhenNowOkOutput {
         let (sampled, mem) = match (input.input, input.ck) {
             (Some(input), _) => {
@@ -79,6 +83,9 @@ impl grust::core::Component for WhenOkState {
     fn init() -> WhenOkState {
         WhenOkState { last_mem: 0i64 }
     }
+    fn reset(&mut self) {
+        self.last_mem = 0i64;
+    }
     fn step(&mut self, input: WhenOkInput) -> WhenOkOutput {
         let mem = match (input.input) {
             (Some(input)) => input,
@@ -0,0 +1,87 @@
+use grust::{core::Component, grust};
+
+grust! {
+    #![dump = "grust/out/arith.rs", arith = saturating]
+
+    function add(a: int, b: int) -> int {
+        return a + b;
+    }
+    function div(a: int, b: int) -> int {
+        return a / b;
+    }
+    function rem(a: int, b: int) -> int {
+        return a % b;
+    }
+    function wrapping_mul(a: int, b: int) -> int {
+        return a *% b;
+    }
+    function wrapping_div(a: int, b: int) -> int {
+        return a /% b;
+    }
+    function checked_sub(a: int, b: int) -> int {
+        return a -? b;
+    }
+    function average(a: float, b: float) -> float {
+        return (a + b) / 2.0;
+    }
+
+    component odometer(ticks: int) -> (distance: int) {
+        init distance = 0;
+        distance = last distance + ticks * 1000;
+    }
+}
+
+mod native {
+    use grust::grust;
+
+    grust! {
+        #![dump = "grust/out/arith_native.rs"]
+
+        function div_offset(a: int, b: int, c: int) -> int {
+            return a /% b + c;
+        }
+        function rem_offset(a: int, b: int, c: int) -> int {
+            return c - a %| b;
+        }
+    }
+
+    #[test]
+    fn should_use_policy_divisions_as_operands() {
+        assert_eq!(div_offset(7, 2, 1), 4);
+        assert_eq!(div_offset(7, 0, 1), 1);
+        assert_eq!(rem_offset(7, 2, 3), 2);
+        assert_eq!(rem_offset(7, 0, 3), -4);
+    }
+}
+
+#[test]
+fn should_follow_the_global_saturating_policy() {
+    assert_eq!(add(i64::MAX, 1), i64::MAX);
+    assert_eq!(add(i64::MIN, -1), i64::MIN);
+    assert_eq!(div(i64::MIN, -1), i64::MAX);
+    assert_eq!(div(7, 0), i64::MAX);
+    assert_eq!(div(-7, 0), i64::MIN);
+    assert_eq!(div(0, 0), 0);
+    assert_eq!(rem(7, 0), 7);
+    assert_eq!(rem(i64::MIN, -1), 0);
+    assert_eq!(average(1.0, 2.0), 1.5);
+
+    let mut odometer = OdometerState::init();
+    odometer.step(OdometerInput { ticks: i64::MAX });
+    let OdometerOutput { distance } = odometer.step(OdometerInput { ticks: 1 });
+    assert_eq!(distance, i64::MAX);
+}
+
+#[test]
+fn should_follow_operator_policies() {
+    assert_eq!(wrapping_mul(i64::MAX, 2), -2);
+    assert_eq!(wrapping_div(i64::MIN, -1), i64::MIN);
+    assert_eq!(wrapping_div(7, 0), 0);
+    assert_eq!(checked_sub(3, 5), -2);
+}
+
+#[test]
+#[should_panic(expected = "attempt to subtract with overflow")]
+fn should_panic_on_checked_overflow() {
+    checked_sub(i64::MIN, 1);
+}
@@ -0,0 +1,41 @@
+use grust::grust;
+
+grust! {
+    #![dump = "grust/out/c_abi.rs", dump_header = "grust/out/c_abi.h", c_abi]
+
+    enum Gear { Park, Drive, Reverse }
+
+    struct Wheels {
+        speeds: [float; 4],
+        gear: Gear,
+    }
+
+    function mean(speeds: [float; 4]) -> float {
+        let sum: float = speeds[0] + speeds[1] + speeds[2] + speeds[3];
+        return sum / 4.0;
+    }
+
+    component odometer(wheels: Wheels, dt: float) -> (speed: float, distance: float) {
+        init distance = 0.0;
+        speed = if wheels.gear == Gear::Reverse then -mean(wheels.speeds) else mean(wheels.speeds);
+        distance = last distance + speed * dt;
+    }
+}
+
+#[test]
+fn should_step_through_c_abi() {
+    let wheels = Wheels {
+        speeds: [10., 10., 12., 12.],
+        gear: Gear::Reverse,
+    };
+    let state = odometer_init();
+    // SAFETY: `state` was allocated by `odometer_init` and is freed only once
+    unsafe {
+        let out = odometer_step(state, OdometerInput { wheels, dt: 0.5 });
+        assert_eq!(out.speed, -11.);
+        assert_eq!(out.distance, -5.5);
+        let out = odometer_step(state, OdometerInput { wheels, dt: 1. });
+        assert_eq!(out.distance, -16.5);
+        odometer_free(state);
+    }
+}
@@ -0,0 +1,52 @@
+use grust::{core::Component, grust};
+
+grust! {
+    #![dump = "grust/out/control.rs"]
+    use component grust::std::control::pid(
+        e: float, t: float, kp: float, ki: float, kd: float, tf: float, u_min: float, u_max: float,
+    ) -> (u: float);
+    use component grust::std::control::rate_limiter(
+        x: float, t: float, rising: float, falling: float,
+    ) -> (y: float);
+    use component grust::std::control::saturation(
+        x: float, lo: float, hi: float, h: float,
+    ) -> (y: float, sat: bool);
+    use component grust::std::control::bumpless_transfer(
+        auto_u: float, manual_u: float, manual: bool, t: float, tau: float,
+    ) -> (u: float);
+
+    component speed_control(target: float, speed: float, pedal: float, manual: bool, t: float) -> (
+        throttle: float,
+        limited: bool,
+    ) {
+        let setpoint: float = rate_limiter(target, t, 2.0, 5.0);
+        let command: float = pid(setpoint - speed, t, 0.5, 0.1, 0.0, 0.0, -1.0, 1.0);
+        let u: float = bumpless_transfer(command, pedal, manual, t, 0.5);
+        (throttle, limited) = saturation(u, 0.0, 1.0, 0.1);
+    }
+}
+
+#[test]
+fn should_control_speed_with_std_blocks() {
+    let mut state = SpeedControlState::init();
+    let mut step = |speed, manual, t| {
+        let SpeedControlOutput { throttle, limited } = state.step(SpeedControlInput {
+            target: 30.0,
+            speed,
+            pedal: 0.2,
+            manual,
+            t,
+        });
+        (throttle, limited)
+    };
+    // the setpoint starts at the target
+    assert_eq!(step(0.0, false, 0.0), (1.0, true));
+    // the controller is saturated, the output stays in bounds
+    assert_eq!(step(10.0, false, 1.0), (1.0, true));
+    // switching to manual does not bump the throttle
+    let (throttle, _) = step(10.0, true, 1.1);
+    assert_eq!(throttle, 1.0);
+    let (throttle, limited) = step(10.0, true, 10.0);
+    assert!((throttle - 0.2).abs() < 1e-6);
+    assert!(!limited);
+}
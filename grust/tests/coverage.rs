@@ -0,0 +1,69 @@
+#![allow(warnings)]
+use grust::grust;
+
+grust! {
+    #![coverage]
+
+    enum Light { Red, Green, Broken }
+
+    component light(switch: bool, fault: bool) -> (color: Light) {
+        init color = Light::Red;
+        color = if fault then Light::Broken else match last color {
+            Light::Red => if switch then Light::Green else Light::Red,
+            Light::Green => if switch then Light::Red else Light::Green,
+            Light::Broken => Light::Broken,
+        };
+    }
+}
+
+#[test]
+fn should_report_uncovered_branches() {
+    use grust::core::Component;
+    reset_coverage();
+    let mut light = LightState::init();
+    for switch in [true, true, false] {
+        light.step(LightInput {
+            switch,
+            fault: false,
+        });
+    }
+
+    let report = coverage_report();
+    // `if fault`, three arms and two `if switch`
+    assert_eq!(report.total(), 9);
+    assert_eq!(report.covered(), 6);
+    let uncovered = report
+        .uncovered()
+        .map(|point| (point.branch, point.label))
+        .collect::<Vec<_>>();
+    use grust::core::coverage::Branch;
+    assert_eq!(
+        uncovered,
+        vec![
+            (Branch::Then, "fault"),
+            (Branch::Else, "switch"),
+            (Branch::Arm, "Light::Broken"),
+        ]
+    );
+    assert!(report
+        .uncovered()
+        .all(|point| point.file.ends_with("coverage.rs")));
+    // `then Light::Broken`, the second `else Light::Green` and the `Light::Broken =>` arm
+    let locations = report
+        .uncovered()
+        .map(|point| (point.line, point.column))
+        .collect::<Vec<_>>();
+    assert_eq!(locations, vec![(11, 31), (13, 60), (14, 13)]);
+
+    light.step(LightInput {
+        switch: false,
+        fault: true,
+    });
+    light.step(LightInput {
+        switch: false,
+        fault: false,
+    });
+    assert_eq!(coverage_report().covered(), 8);
+    reset_coverage();
+    assert_eq!(coverage_report().covered(), 0);
+}
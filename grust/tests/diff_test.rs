@@ -0,0 +1,72 @@
+#![allow(warnings)]
+use grust::grust;
+
+grust! {
+    #![mode = test, diff_test, component_para_threads, prop_cases = 64, prop_depth = 12]
+
+    enum Gear { Park, Drive, Reverse }
+
+    component spread(i: int) -> (o: int)
+        requires { -1000 <= i && i <= 1000 }
+    {
+        let i1: int = (i - 54) * 2;
+        let i2: int = (i + 54) * 2;
+        let i3: int = 7 * i;
+        o = i1 + i2 + i3;
+    }
+
+    // Sums the spreads of the ticks in drive
+    component total(tick: int?, gear: Gear) -> (sum: int, moving: bool) {
+        let a: int = spread(last sum % 1000);
+        let b: int = spread(-(last sum % 1000));
+        sum = when {
+            init => 0,
+            let t = tick? => if gear == Gear::Drive then (last sum + a + b + t % 1000) % 100000 else last sum,
+        };
+        moving = gear != Gear::Park;
+    }
+
+    test total_sums_ticks for total {
+        step { tick = some(3), gear = Gear::Drive }
+        expect { sum = 3, moving = true }
+        step { tick = none }
+        expect { sum = 3 }
+        step { tick = some(4), gear = Gear::Park }
+        expect { sum = 3, moving = false }
+    }
+}
+
+mod rayon {
+    use grust::grust;
+
+    grust! {
+        #![mode = test, diff_test, component_para_rayon, prop_cases = 64, prop_depth = 12]
+
+        component spread_rayon(i: int) -> (o: int)
+            requires { -1000 <= i && i <= 1000 }
+        {
+            let i1: int = (i - 54) * 2;
+            let i2: int = (i + 54) * 2;
+            let i3: int = 7 * i;
+            o = i1 + i2 + i3;
+        }
+    }
+}
+
+#[test]
+fn diff_tests_run_both_steps() {
+    use grust::core::Component;
+    let mut para = TotalState::init();
+    let mut seq = TotalState::init();
+    for tick in [Some(1), None, Some(7), Some(-2)] {
+        let para = para.step(TotalInput {
+            tick,
+            gear: Gear::Drive,
+        });
+        let seq = seq.step_seq(TotalInput {
+            tick,
+            gear: Gear::Drive,
+        });
+        assert_eq!(para.sum, seq.sum);
+    }
+}
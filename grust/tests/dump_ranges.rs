@@ -0,0 +1,19 @@
+#![allow(warnings)]
+
+grust::grust! {
+    #![dump_ranges = "grust/out/ranges.json"]
+
+    component scale(x: int) -> (y: int)
+        requires { 0 <= x && x <= 10 }
+    {
+        y = 2 * x + 1;
+    }
+}
+
+#[test]
+fn should_dump_ranges() {
+    let dump = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/out/ranges.json"))
+        .expect("the ranges should be dumped at compile time");
+    assert!(dump.contains(r#""name": "scale""#));
+    assert!(dump.contains(r#""range": "[1, 21]""#));
+}
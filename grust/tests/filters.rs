@@ -0,0 +1,32 @@
+use grust::{core::Component, grust};
+
+grust! {
+    #![dump = "grust/out/filters.rs"]
+    use component grust::std::filters::median(x: float, t: float, n: int) -> (y: float);
+    use component grust::std::filters::low_pass(x: float, t: float, tau: float) -> (y: float);
+    use component grust::std::filters::kalman(
+        z: float, t: float, q: float, r: float,
+    ) -> (est: float, var: float);
+
+    component radar_filter(distance: float, t: float) -> (smooth: float, est: float) {
+        let despiked: float = median(distance, t, 3);
+        smooth = low_pass(despiked, t, 0.2);
+        let (estimate: float, variance: float) = kalman(distance, t, 0.5, 4.0);
+        est = if variance < 10.0 then estimate else distance;
+    }
+}
+
+#[test]
+fn should_filter_noisy_distance() {
+    let mut state = RadarFilterState::init();
+    let mut step = |distance, t| {
+        let RadarFilterOutput { smooth, est } = state.step(RadarFilterInput { distance, t });
+        (smooth, est)
+    };
+    assert_eq!(step(50.0, 0.0), (50.0, 50.0));
+    let _ = step(50.0, 0.1);
+    // the spike is removed by the median filter
+    let (smooth, est) = step(500.0, 0.2);
+    assert_eq!(smooth, 50.0);
+    assert!(est > 50.0);
+}
@@ -0,0 +1,49 @@
+#![allow(warnings)]
+
+use grust::{core::Component, grust};
+
+grust! {
+    #![dump = "grust/out/generators.rs", mode = demo]
+    use component grust::std::generators::ramp(
+        t: float, delay: float, slope: float, initial: float,
+    ) -> (y: float);
+    use component grust::std::generators::sine(
+        t: float, amplitude: float, frequency: float, phase: float, offset: float,
+    ) -> (y: float);
+    use component grust::std::generators::noise(t: float, seed: int, amplitude: float) -> (y: float);
+    use function grust::std::generators::profile(
+        ts: [float; 4], values: [float; 4], t: float,
+    ) -> float;
+
+    #[monotonic]
+    const PROFILE_TIMES: [float; 4] = [0.0, 1.0, 3.0, 4.0];
+    const PROFILE_SPEEDS: [float; 4] = [0.0, 20.0, 20.0, 0.0];
+
+    // noisy speed profile with a slow oscillation, `t_ms` in milliseconds
+    component speed_stimulus(t_ms: float) -> (speed: float) {
+        let t: float = t_ms / 1000.0;
+        let elapsed: float = ramp(t, 0.0, 1.0, 0.0);
+        let nominal: float = profile(PROFILE_TIMES, PROFILE_SPEEDS, elapsed);
+        speed = nominal + sine(t, 0.5, 0.5, 0.0, 0.0) + noise(t, 42, 0.1);
+    }
+
+    export signal speed: float;
+
+    service simulation @[10, 3000] {
+        let event clock: float = period(10);
+        let signal now: float = time();
+        let signal t: float = scan_on(now, clock);
+        speed = speed_stimulus(t);
+    }
+}
+
+#[test]
+fn should_synthesise_speed_profile() {
+    let mut state = SpeedStimulusState::init();
+    let mut stimulus = |t| state.step(SpeedStimulusInput { t_ms: t }).speed;
+    assert!(stimulus(5000.0).abs() <= 0.1);
+    assert!((stimulus(6000.0) - 20.0).abs() <= 0.6);
+    assert!((stimulus(8000.0) - 20.0).abs() <= 0.6);
+    assert!(stimulus(9500.0).abs() <= 0.6);
+    assert!(stimulus(12000.0).abs() <= 0.6);
+}
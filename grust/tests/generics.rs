@@ -0,0 +1,95 @@
+use grust::{core::Component, grust};
+
+grust! {
+    #![dump = "grust/out/generics.rs"]
+
+    function max<T: ord>(a: T, b: T) -> T {
+        return if a >= b then a else b;
+    }
+
+    function min<T: ord>(a: T, b: T) -> T {
+        return if a <= b then a else b;
+    }
+
+    function clamp<T: ord>(x: T, lo: T, hi: T) -> T {
+        return max(lo, min(x, hi));
+    }
+
+    function dist<T: num + ord>(a: T, b: T) -> T {
+        return if a >= b then a - b else b - a;
+    }
+
+    function same<T: eq>(a: T, b: T) -> bool {
+        return a == b;
+    }
+
+    component select<T: ord>(a: T, b: T, greatest: bool) -> (o: T) {
+        o = if greatest then max(a, b) else min(a, b);
+    }
+
+    component widest<T: ord>(a: T, b: T) -> (o: T, ties: int) {
+        init ties = 0;
+        o = select(a, b, true);
+        ties = last ties + (if a == b then 1 else 0);
+    }
+
+    component count<T: eq>(a: T, b: T) -> (n: int) {
+        init n = 0;
+        n = last n + (if a == b then 1 else 0);
+    }
+
+    component test(i: int, x: float) -> (
+        pos: int,
+        sat: float,
+        gap: float,
+        eq: bool,
+        sel: int,
+        wid: float,
+        ties: int,
+        dup: int,
+    ) {
+        pos = max(i, 0);
+        sat = clamp(x, -1.0, 1.0);
+        gap = dist(x, sat);
+        eq = same([i, pos], [pos, i]);
+        sel = select(i, pos, false);
+        (wid, ties) = widest(x, sat);
+        dup = count(i, pos);
+    }
+}
+
+#[test]
+fn should_call_generic_functions() {
+    assert_eq!(max(3, 5), 5);
+    assert_eq!(max(1.5, -2.0), 1.5);
+    assert_eq!(dist(2, 7), 5);
+    assert!(same(true, true));
+}
+
+#[test]
+fn should_infer_type_parameters() {
+    let mut state = TestState::init();
+    let TestOutput {
+        pos,
+        sat,
+        gap,
+        eq,
+        sel,
+        wid,
+        ties,
+        dup,
+    } = state.step(TestInput { i: -3, x: 2.5 });
+    assert_eq!((pos, sat, gap, eq, sel), (0, 1.0, 1.5, false, -3));
+    assert_eq!((wid, ties, dup), (2.5, 0, 0));
+    let TestOutput {
+        pos,
+        eq,
+        sel,
+        wid,
+        ties,
+        dup,
+        ..
+    } = state.step(TestInput { i: 4, x: 0.5 });
+    assert_eq!((pos, eq, sel), (4, true, 4));
+    assert_eq!((wid, ties, dup), (0.5, 1, 1));
+}
@@ -0,0 +1,40 @@
+use grust::{core::Component, grust};
+
+grust! {
+    #![dump = "grust/out/geometry.rs"]
+    use function grust::std::maths::vector::sub(u: [float; 3], v: [float; 3]) -> [float; 3];
+    use function grust::std::maths::vector::norm(u: [float; 3]) -> float;
+    use function grust::std::maths::matrix::mul_vec(
+        a: [[float; 3]; 3], v: [float; 3],
+    ) -> [float; 3];
+    use function grust::std::maths::matrix::transpose(a: [[float; 3]; 3]) -> [[float; 3]; 3];
+    use function grust::std::maths::quaternion::from_axis_angle(
+        axis: [float; 3], angle: float,
+    ) -> [float; 4];
+    use function grust::std::maths::quaternion::to_matrix(q: [float; 4]) -> [[float; 3]; 3];
+
+    // expresses an object seen at `position` in the ego frame of the previous step, in the ego
+    // frame of the current step, after a motion of `translation` and a rotation of `yaw` radians
+    component ego_motion(position: [float; 3], translation: [float; 3], yaw: float) -> (
+        compensated: [float; 3],
+        range: float,
+    ) {
+        let rotation: [[float; 3]; 3] = to_matrix(from_axis_angle([0.0, 0.0, 1.0], yaw));
+        compensated = mul_vec(transpose(rotation), sub(position, translation));
+        range = norm(compensated);
+    }
+}
+
+#[test]
+fn should_compensate_ego_motion() {
+    let mut state = EgoMotionState::init();
+    let EgoMotionOutput { compensated, range } = state.step(EgoMotionInput {
+        position: [10.0, 0.0, 0.0],
+        translation: [4.0, 0.0, 0.0],
+        yaw: std::f64::consts::FRAC_PI_2,
+    });
+    // the object is now 6 meters away, on the right of the ego vehicle
+    assert!((compensated[0] - 0.0).abs() < 1e-10);
+    assert!((compensated[1] + 6.0).abs() < 1e-10);
+    assert!((range - 6.0).abs() < 1e-10);
+}
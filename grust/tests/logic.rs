@@ -0,0 +1,51 @@
+use grust::{core::Component, grust};
+
+grust! {
+    #![dump = "grust/out/logic.rs"]
+    use component grust::std::logic::rising_edge(x: bool) -> (y: bool);
+    use component grust::std::logic::rs(s: bool, r: bool) -> (q: bool);
+    use component grust::std::logic::ton(x: bool, pt: float, t: float) -> (q: bool, et: float);
+    use component grust::std::logic::ctu(cu: bool, reset: bool, pv: int) -> (q: bool, cv: int);
+
+    // a pump runs from the press of start to the press of stop, it alarms when running dry for
+    // too long or when started too many times
+    component pump(start: bool, stop: bool, dry: bool, t: float) -> (
+        running: bool,
+        dry_alarm: bool,
+        dry_time: float,
+        starts_alarm: bool,
+        starts: int,
+    ) {
+        running = rs(start, stop);
+        (dry_alarm, dry_time) = ton(running && dry, 2.0, t);
+        (starts_alarm, starts) = ctu(rising_edge(running), false, 3);
+    }
+}
+
+#[test]
+fn should_drive_pump_with_logic_blocks() {
+    let mut state = PumpState::init();
+    let mut step = |start, stop, dry, t| {
+        let PumpOutput {
+            running,
+            dry_alarm,
+            dry_time,
+            starts_alarm,
+            starts,
+        } = state.step(PumpInput {
+            start,
+            stop,
+            dry,
+            t,
+        });
+        (running, dry_alarm, dry_time, starts_alarm, starts)
+    };
+    assert_eq!(step(true, false, false, 0.0), (true, false, 0.0, false, 1));
+    assert_eq!(step(false, false, true, 1.0), (true, false, 0.0, false, 1));
+    assert_eq!(step(false, false, true, 2.0), (true, false, 1.0, false, 1));
+    assert_eq!(step(false, false, true, 3.0), (true, true, 2.0, false, 1));
+    assert_eq!(step(false, true, true, 4.0), (false, false, 0.0, false, 1));
+    assert_eq!(step(true, false, false, 5.0), (true, false, 0.0, false, 2));
+    assert_eq!(step(false, true, false, 6.0), (false, false, 0.0, false, 2));
+    assert_eq!(step(true, false, false, 7.0), (true, false, 0.0, true, 3));
+}
@@ -0,0 +1,37 @@
+use grust::{core::Component, grust};
+
+grust! {
+    #![dump = "grust/out/lut.rs"]
+    use function grust::std::lut::interp1(xs: [float; 4], ys: [float; 4], x: float) -> float;
+    use function grust::std::lut::interp2_extrap(
+        xs: [float; 3], ys: [float; 2], table: [[float; 2]; 3], x: float, y: float,
+    ) -> float;
+
+    #[monotonic]
+    const SPEEDS: [float; 4] = [0.0, 10.0, 20.0, 40.0];
+    const GAINS: [float; 4] = [1.0, 0.8, 0.5, 0.5];
+
+    #[monotonic]
+    const SLOPES: [float; 3] = [-5.0, 0.0, 5.0];
+    #[monotonic]
+    const LOADS: [float; 2] = [0.0, 1.0];
+    const TORQUE_MAP: [[float; 2]; 3] = [[10.0, 20.0], [20.0, 40.0], [30.0, 60.0]];
+
+    component cruise(speed: float, slope: float, load: float) -> (gain: float, torque: float) {
+        gain = interp1(SPEEDS, GAINS, speed);
+        torque = interp2_extrap(SLOPES, LOADS, TORQUE_MAP, slope, load);
+    }
+}
+
+#[test]
+fn should_look_up_constant_tables() {
+    let mut state = CruiseState::init();
+    let mut step = |speed, slope, load| {
+        let CruiseOutput { gain, torque } = state.step(CruiseInput { speed, slope, load });
+        (gain, torque)
+    };
+    assert_eq!(step(0.0, 0.0, 0.0), (1.0, 20.0));
+    assert_eq!(step(15.0, 2.5, 0.5), (0.65, 37.5));
+    // gains are clamped, torques extrapolated
+    assert_eq!(step(100.0, 10.0, 1.0), (0.5, 80.0));
+}
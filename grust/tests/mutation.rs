@@ -0,0 +1,58 @@
+#![allow(warnings)]
+
+mod original {
+    use grust::grust;
+
+    grust! {
+        #![mode = test, mutation]
+
+        // Counts the ticks while enabled, up to 3
+        component saturate(tick: bool, enabled: bool) -> (count: int) {
+            init count = 0;
+            count = if tick && enabled && last count < 3 then last count + 1 else last count;
+        }
+    }
+}
+
+mod mutated {
+    use grust::grust;
+
+    grust! {
+        #![mode = test, mutant = "saturate#0"]
+
+        // Counts the ticks while enabled, up to 3
+        component saturate(tick: bool, enabled: bool) -> (count: int) {
+            init count = 0;
+            count = if tick && enabled && last count < 3 then last count + 1 else last count;
+        }
+    }
+}
+
+#[test]
+fn should_list_mutants() {
+    let mutants = original::mutants();
+    assert_eq!(mutants.len(), 1);
+    assert_eq!(mutants[0].id, "saturate#0");
+    assert_eq!(mutants[0].owner, "saturate");
+    assert_eq!(mutants[0].description, "replace `<` with `>=`");
+    assert_eq!(mutants[0].file, file!());
+}
+
+#[test]
+fn should_apply_mutant() {
+    use grust::core::Component;
+    assert_eq!(mutated::ACTIVE_MUTANT, Some("saturate#0"));
+    let mut original = original::SaturateState::init();
+    let mut mutated = mutated::SaturateState::init();
+    let output = original.step(original::SaturateInput {
+        tick: true,
+        enabled: true,
+    });
+    assert_eq!(output.count, 1);
+    // `last count >= 3` never holds from 0
+    let output = mutated.step(mutated::SaturateInput {
+        tick: true,
+        enabled: true,
+    });
+    assert_eq!(output.count, 0);
+}
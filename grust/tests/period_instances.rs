@@ -0,0 +1,41 @@
+#![allow(warnings)]
+
+use grust::grust;
+
+grust! {
+    #![dump = "grust/out/period_instances.rs", mode = demo]
+
+    import signal input_s: int;
+    import event input_e: int;
+
+    export signal scanned: int;
+    export event sampled: int;
+
+    service test<const PERIOD: int> for fast<50>, slow<100> @[10, 2000] {
+        let event clock: float = period(PERIOD);
+        scanned = scan_on(input_s, clock);
+        sampled = sample_on(input_e, clock);
+    }
+}
+
+#[test]
+fn should_have_one_flow_family_per_instance() {
+    use runtime::{RuntimeInit, RuntimeInput, RuntimeOutput};
+    let now = std::time::Instant::now();
+    let _ = RuntimeInit {
+        fast_input_s: 0,
+        slow_input_s: 0,
+    };
+    let _ = [
+        RuntimeInput::FastInputS(1, now),
+        RuntimeInput::SlowInputS(1, now),
+        RuntimeInput::FastInputE(1, now),
+        RuntimeInput::SlowInputE(1, now),
+    ];
+    let _ = [
+        RuntimeOutput::FastScanned(1, now),
+        RuntimeOutput::SlowScanned(1, now),
+        RuntimeOutput::FastSampled(1, now),
+        RuntimeOutput::SlowSampled(1, now),
+    ];
+}
@@ -0,0 +1,75 @@
+#![allow(warnings)]
+use grust::grust;
+
+grust! {
+    #![mode = test, prop_test, prop_cases = 128, prop_depth = 12]
+
+    enum Gear { Park, Drive, Reverse }
+
+    function clamp(x: int, lo: int, hi: int) -> int {
+        return if x < lo then lo else if x > hi then hi else x;
+    }
+
+    // Counts activations, saturating at 100
+    component counter(tick: bool, gear: Gear) -> (count: int)
+        ensures { 0 <= count && count <= 100 }
+        ensures { last count <= count }
+        invariant { 0 <= last count && last count <= 100 }
+    {
+        init count = 0;
+        count = if tick && gear == Gear::Drive then clamp(last count + 1, 0, 100) else last count;
+    }
+
+    // Braking command in correct interval
+    component brakes(d: int, v: int) -> (b: int)
+        requires { 0 < d && d < 150 }
+        requires { v < 0 && -v <= 10 }
+        ensures  { 0 <= b && b <= 6 }
+        ensures  { d > 50 => b <= 1 }
+    {
+        b = clamp((v * v) / (2 * d), 0, 6);
+    }
+
+    // Raises an alarm after two consecutive high samples
+    component alarm(x: int) -> (raised: bool)
+        ensures { raised => within 2 x > 90 }
+    {
+        init raised = false;
+        let high: bool = x > 90;
+        init high = false;
+        raised = high && last high;
+    }
+}
+
+#[test]
+fn should_shrink_counter_example() {
+    use grust::core::prop::{self, Arbitrary};
+    let res = std::panic::catch_unwind(|| {
+        prop::check(
+            "counter_bug",
+            64,
+            12,
+            |_: &(bool, Gear)| true,
+            |inputs: &[(bool, Gear)]| {
+                // bug: the count should never reach 3 with a parked car
+                let mut state = <CounterState as grust::core::Component>::init();
+                for (step, (tick, gear)) in inputs.iter().enumerate() {
+                    let CounterOutput { count } = <CounterState as grust::core::Component>::step(
+                        &mut state,
+                        CounterInput {
+                            tick: *tick,
+                            gear: Gear::Drive,
+                        },
+                    );
+                    if *gear == Gear::Park && count >= 3 {
+                        return Err(prop::Failure::new(step, "parked"));
+                    }
+                }
+                Ok(())
+            },
+        )
+    });
+    let msg = *res.unwrap_err().downcast::<String>().unwrap();
+    assert!(msg.contains("failed at step 2: parked"), "{msg}");
+    assert_eq!(Gear::Drive.shrink(), vec![Gear::Park]);
+}
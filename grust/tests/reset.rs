@@ -0,0 +1,45 @@
+use grust::{core::Component, grust};
+
+grust! {
+    #![dump = "grust/out/reset.rs"]
+
+    component counter(res: bool, tick: bool) -> (o: int) {
+        init o = 0;
+        o = if res then 0 else last o + inc;
+        let inc: int = if tick then 1 else 0;
+    }
+
+    component lap_counter(tick: bool, lap: bool) -> (total: int, current: int) {
+        total = counter(false, tick);
+        current = counter(false, tick) every lap;
+    }
+}
+
+#[test]
+fn should_reset_nested_state_every_lap() {
+    let mut state = LapCounterState::init();
+    let mut step = |tick, lap| {
+        let LapCounterOutput { total, current } = state.step(LapCounterInput { tick, lap });
+        (total, current)
+    };
+    assert_eq!(step(true, false), (1, 1));
+    assert_eq!(step(true, false), (2, 2));
+    assert_eq!(step(true, true), (3, 1));
+    assert_eq!(step(false, false), (3, 1));
+    assert_eq!(step(false, true), (3, 0));
+}
+
+#[test]
+fn should_reset_whole_state() {
+    let mut state = LapCounterState::init();
+    let _ = state.step(LapCounterInput {
+        tick: true,
+        lap: false,
+    });
+    state.reset();
+    let LapCounterOutput { total, current } = state.step(LapCounterInput {
+        tick: true,
+        lap: false,
+    });
+    assert_eq!((total, current), (1, 1));
+}
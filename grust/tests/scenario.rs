@@ -0,0 +1,49 @@
+#![allow(warnings)]
+use grust::grust;
+
+grust! {
+    #![mode = test]
+
+    enum Gear { Park, Drive, Reverse }
+
+    struct Point { x: int, y: int }
+
+    // Counts the ticks in drive
+    component counter(tick: unit?, gear: Gear) -> (count: int, moving: bool) {
+        count = when {
+            init => 0,
+            tick? => if gear == Gear::Drive then last count + 1 else last count,
+        };
+        moving = gear != Gear::Park;
+    }
+
+    component shift(p: Point, dx: int) -> (q: Point) {
+        q = Point { x: p.x + dx, y: p.y };
+    }
+
+    test counter_counts_in_drive for counter {
+        step { tick = some(()), gear = Gear::Park }
+        expect { count = 0, moving = false }
+        step { tick = some(()), gear = Gear::Drive }
+        expect { count = 1, moving = true }
+        step { }
+        expect { count = 1 }
+        step { tick = some(()) }
+        expect { count = 2 }
+        step { tick = none, gear = Gear::Reverse }
+        expect { count = 2, moving = true }
+    }
+
+    test shift_moves_right for shift {
+        step { p = Point { x: 1, y: 2 }, dx = 3 }
+        expect { q = Point { x: 4, y: 2 } }
+        step { dx = -1 }
+        expect { q = Point { x: 0, y: 2 } }
+    }
+}
+
+#[test]
+fn scenarios_are_regular_tests() {
+    counter_counts_in_drive();
+    shift_moves_right();
+}
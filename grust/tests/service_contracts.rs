@@ -0,0 +1,54 @@
+#![allow(warnings)]
+
+use grust::grust;
+
+grust! {
+    #![mode = test]
+
+    import signal car::speed: float;
+    import event  car::pedestrian: float;
+    export signal car::brakes: bool;
+
+    // Brakes when a pedestrian is closer than the current speed
+    component braking(pedestrian: float?, speed: float) -> (brakes: bool) {
+        brakes = when {
+            init => false,
+            let d = pedestrian? => d < speed,
+        };
+    }
+
+    service aeb ensures { brakes within 50 ms after pedestrian } {
+        brakes = braking(pedestrian, speed);
+    }
+}
+
+#[test]
+fn should_accept_unchanged_signals_as_responses() {
+    use grust::core::futures::{channel::mpsc, executor::block_on, stream, StreamExt};
+    use runtime::{Runtime, RuntimeInit, RuntimeInput, RuntimeOutput, RuntimeTimer};
+
+    let now = std::time::Instant::now();
+    let ms = |n| now + std::time::Duration::from_millis(n);
+    let (output_sink, output_stream) = mpsc::channel(8);
+    let (timers_sink, _timers_stream) = mpsc::channel(8);
+    let runtime = Runtime::new(now, output_sink, timers_sink);
+    // the second pedestrian leaves `brakes` unchanged, response timers expire after both
+    let inputs = stream::iter([
+        RuntimeInput::Pedestrian(1.0, ms(10)),
+        RuntimeInput::Timer(RuntimeTimer::ResponseAeb, ms(60)),
+        RuntimeInput::Pedestrian(2.0, ms(200)),
+        RuntimeInput::Timer(RuntimeTimer::ResponseAeb, ms(250)),
+        RuntimeInput::Speed(20.0, ms(400)),
+    ]);
+    let (result, outputs) = block_on(async {
+        grust::core::futures::join!(
+            runtime.run_loop(inputs, RuntimeInit { speed: 10.0 }),
+            output_stream.collect::<Vec<_>>(),
+        )
+    });
+    assert!(result.is_ok());
+    assert!(!outputs
+        .iter()
+        .any(|output| matches!(output, RuntimeOutput::ContractViolation(..))));
+    assert!(outputs.contains(&RuntimeOutput::Brakes(true, ms(10))));
+}
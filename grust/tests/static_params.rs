@@ -0,0 +1,40 @@
+use grust::{core::Component, grust};
+
+grust! {
+    #![dump = "grust/out/static_params.rs"]
+
+    function sum<const N: int>(a: [float; N]) -> float {
+        return a.fold(0.0, |acc: float, x: float| acc + x);
+    }
+
+    function scaled_sum<const N: int, const K: float>(a: [float; N]) -> float {
+        return K * sum::<N>(a);
+    }
+
+    component counter<const START: int, const STEP: int>(tick: bool) -> (o: int) {
+        init o = START;
+        o = if tick then last o + STEP else last o;
+    }
+
+    component test(a: [float; 3], tick: bool) -> (s: float, up: int, down: int) {
+        s = scaled_sum::<3, 0.5>(a);
+        up = counter::<0, 10>(tick);
+        down = counter::<100, -1>(tick);
+    }
+}
+
+#[test]
+fn should_instantiate_static_parameters() {
+    assert_eq!(sum_3([1.0, 2.0, 3.0]), 6.0);
+    let mut state = TestState::init();
+    let TestOutput { s, up, down } = state.step(TestInput {
+        a: [1.0, 2.0, 3.0],
+        tick: true,
+    });
+    assert_eq!((s, up, down), (3.0, 10, 99));
+    let TestOutput { up, down, .. } = state.step(TestInput {
+        a: [1.0, 2.0, 3.0],
+        tick: true,
+    });
+    assert_eq!((up, down), (20, 98));
+}
@@ -0,0 +1,21 @@
+use grust::{core::Component, grust};
+
+// imports the whole standard library manifest, the generated static assertions check each of its
+// signatures against `grust_std`
+grust! {
+    #![dump = "grust/out/std_manifest.rs"]
+    use std::*;
+
+    component blinker(t: float) -> (y: float, on: bool) {
+        y = square(t, 1.0, 2.0, 0.5, 0.0);
+        on = rising_edge(y > 0.0);
+    }
+}
+
+#[test]
+fn should_import_whole_std_manifest() {
+    let mut state = BlinkerState::init();
+    let BlinkerOutput { y, on } = state.step(BlinkerInput { t: 0.5 });
+    assert_eq!(y, 1.0);
+    assert!(on);
+}
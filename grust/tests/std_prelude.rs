@@ -0,0 +1,35 @@
+use grust::{core::Component, grust};
+
+grust! {
+    #![dump = "grust/out/std_prelude.rs"]
+    use std::maths::*;
+    use std::{control::pid, logic::*};
+
+    function heading(x: float, y: float) -> float {
+        return atan2(y, x);
+    }
+
+    component steering(x: float, y: float, target: float, t: float, enabled: bool) -> (
+        u: float,
+        engaged: bool,
+    ) {
+        engaged = sr(enabled, !enabled);
+        let command: float = pid(target - heading(x, y), t, 2.0, 0.0, 0.0, 0.0, -1.0, 1.0);
+        u = if engaged then command else 0.0;
+    }
+}
+
+#[test]
+fn should_use_std_prelude() {
+    assert!((heading(0.0, 1.0) - std::f64::consts::FRAC_PI_2).abs() < 1e-10);
+    let mut state = SteeringState::init();
+    let SteeringOutput { u, engaged } = state.step(SteeringInput {
+        x: 1.0,
+        y: 0.0,
+        target: 0.25,
+        t: 0.0,
+        enabled: true,
+    });
+    assert!(engaged);
+    assert_eq!(u, 0.5);
+}
@@ -52,6 +52,14 @@ pub mod typ {
         Ok(())
     }
 
+    /// Fails if `!typ.satisfies(constraint)`, at location `loc`.
+    pub fn constraint(loc: Loc, typ: &Typ, constraint: Constraint) -> URes {
+        if !typ.satisfies(constraint) {
+            bail!( @loc => ErrorKind::unsatisfied_constraint(typ.clone(), constraint) )
+        }
+        Ok(())
+    }
+
     /// Checks that `typ.eq(expected)`.
     pub fn expect(loc: Loc, typ: &Typ, expected: &Typ) -> URes {
         if !typ.eq(expected) {
@@ -4,6 +4,10 @@ prelude! {
     synced::WeightBounds,
 }
 
+/// Environment variable selecting the mutant to apply in mutation testing, mirrors
+/// `grust::core::mutation::MUTANT_VAR`.
+pub const MUTANT_VAR: &str = "GRUST_MUTANT";
+
 /// Services configuration for the propagation of
 /// events and signals changes.
 #[derive(Clone, Default)]
@@ -67,6 +71,7 @@ pub enum CompilationMode {
     #[default]
     Demo,
     Greusot,
+    Kani,
     Test,
 }
 impl CompilationMode {
@@ -77,6 +82,13 @@ impl CompilationMode {
         }
         false
     }
+    /// Tells if the compilation mode is Kani or not.
+    pub fn kani(&self) -> bool {
+        if let Self::Kani = self {
+            return true;
+        }
+        false
+    }
     /// Tells if the compilation mode is Test or not.
     pub fn test(&self) -> bool {
         if let Self::Test = self {
@@ -198,6 +210,84 @@ build_conf! {
         tracing: bool = false =>
             /// Item for the `tracing` configuration value.
             Tracing,
+        c_abi: bool = false =>
+            /// Item for the `c_abi` configuration value.
+            CAbi,
+        dump_header: Option<syn::LitStr> = None =>
+            /// Item for the `dump_header` configuration value.
+            DumpHeader,
+        arith: Arith = Arith::default() =>
+            /// Item for the `arith` configuration value.
+            Arith,
+        kani_depth: usize = 3 =>
+            /// Item for the `kani_depth` configuration value.
+            KaniDepth,
+        range_analysis: bool = false =>
+            /// Item for the `range_analysis` configuration value.
+            RangeAnalysis,
+        dump_ranges: Option<syn::LitStr> = None =>
+            /// Item for the `dump_ranges` configuration value.
+            DumpRanges,
+        infer_invariants: bool = false =>
+            /// Item for the `infer_invariants` configuration value.
+            InferInvariants,
+        suggest_invariants: bool = false =>
+            /// Item for the `suggest_invariants` configuration value.
+            SuggestInvariants,
+        prop_test: bool = false =>
+            /// Item for the `prop_test` configuration value.
+            PropTest,
+        prop_cases: usize = 256 =>
+            /// Item for the `prop_cases` configuration value.
+            PropCases,
+        prop_depth: usize = 16 =>
+            /// Item for the `prop_depth` configuration value.
+            PropDepth,
+        coverage: bool = false =>
+            /// Item for the `coverage` configuration value.
+            Coverage,
+        diff_test: bool = false =>
+            /// Item for the `diff_test` configuration value.
+            DiffTest,
+        mutation: bool = false =>
+            /// Item for the `mutation` configuration value.
+            Mutation,
+        mutant: Option<syn::LitStr> = None =>
+            /// Item for the `mutant` configuration value.
+            Mutant,
+    }
+}
+
+impl Conf {
+    /// Tells if the generated code counts the executions of its branches, not in GReusot mode.
+    pub fn instrument_coverage(&self) -> bool {
+        self.coverage && !self.mode.greusot()
+    }
+
+    /// Tells if the contracts of the components are checked by property-based tests, only in test
+    /// mode.
+    pub fn prop_testing(&self) -> bool {
+        self.prop_test && self.mode.test()
+    }
+
+    /// Tells if the parallel components are tested against their sequential version, only in
+    /// test mode.
+    pub fn diff_testing(&self) -> bool {
+        self.diff_test && self.mode.test() && !self.component_para.is_none()
+    }
+
+    /// Tells if the components are mutated, only in test mode.
+    pub fn mutation_testing(&self) -> bool {
+        (self.mutation || self.mutant.is_some()) && self.mode.test()
+    }
+
+    /// Identifier of the mutant to apply, given by the `mutant` configuration value or else by the
+    /// `GRUST_MUTANT` environment variable.
+    pub fn active_mutant(&self) -> Option<String> {
+        match &self.mutant {
+            Some(mutant) => Some(mutant.value()),
+            None => std::env::var(MUTANT_VAR).ok(),
+        }
     }
 }
 
@@ -229,13 +319,31 @@ mod parsing {
                     let ident: Ident = input.parse()?;
                     match ident.to_string().as_str() {
                         "greusot" => Self::Mode(span, CompilationMode::Greusot),
+                        "kani" => Self::Mode(span, CompilationMode::Kani),
                         "test" => Self::Mode(span, CompilationMode::Test),
                         "demo" => Self::Mode(span, CompilationMode::Demo),
                         _ => {
                             return Err(syn::Error::new_spanned(
                                 ident,
                                 "unexpected compilation mode, \
-                                expected `demo`, `test` or `greusot`",
+                                expected `demo`, `test`, `greusot` or `kani`",
+                            ));
+                        }
+                    }
+                }
+                "arith" => {
+                    let _: Token![=] = input.parse()?;
+                    let ident: Ident = input.parse()?;
+                    match ident.to_string().as_str() {
+                        "native" => Self::Arith(span, Arith::Native),
+                        "wrapping" => Self::Arith(span, Arith::Wrapping),
+                        "saturating" => Self::Arith(span, Arith::Saturating),
+                        "checked" => Self::Arith(span, Arith::Checked),
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                ident,
+                                "unexpected arithmetic policy, \
+                                expected `native`, `wrapping`, `saturating` or `checked`",
                             ));
                         }
                     }
@@ -250,6 +358,29 @@ mod parsing {
                     let val: syn::LitStr = input.parse()?;
                     Self::DumpGraph(span, Some(val))
                 }
+                "dump_ranges" => {
+                    let _: Token![=] = input.parse()?;
+                    let val: syn::LitStr = input.parse()?;
+                    Self::DumpRanges(span, Some(val))
+                }
+                "kani_depth" => {
+                    let _: Token![=] = input.parse()?;
+                    let val: syn::LitInt = input.parse()?;
+                    let val: usize = val.base10_parse()?;
+                    Self::KaniDepth(span, val)
+                }
+                "prop_cases" => {
+                    let _: Token![=] = input.parse()?;
+                    let val: syn::LitInt = input.parse()?;
+                    let val: usize = val.base10_parse()?;
+                    Self::PropCases(span, val)
+                }
+                "prop_depth" => {
+                    let _: Token![=] = input.parse()?;
+                    let val: syn::LitInt = input.parse()?;
+                    let val: usize = val.base10_parse()?;
+                    Self::PropDepth(span, val)
+                }
                 "stats_depth" => {
                     let _: Token![=] = input.parse()?;
                     let val: syn::LitInt = input.parse()?;
@@ -265,6 +396,24 @@ mod parsing {
                     Self::PubThings(span, val)
                 }
                 "tracing" => Self::Tracing(span, true),
+                "c_abi" => Self::CAbi(span, true),
+                "range_analysis" => Self::RangeAnalysis(span, true),
+                "infer_invariants" => Self::InferInvariants(span, true),
+                "suggest_invariants" => Self::SuggestInvariants(span, true),
+                "prop_test" => Self::PropTest(span, true),
+                "coverage" => Self::Coverage(span, true),
+                "diff_test" => Self::DiffTest(span, true),
+                "mutation" => Self::Mutation(span, true),
+                "mutant" => {
+                    let _: Token![=] = input.parse()?;
+                    let val: syn::LitStr = input.parse()?;
+                    Self::Mutant(span, Some(val))
+                }
+                "dump_header" => {
+                    let _: Token![=] = input.parse()?;
+                    let val: syn::LitStr = input.parse()?;
+                    Self::DumpHeader(span, Some(val))
+                }
                 "levenshtein" => {
                     let _: Token![=] = input.parse()?;
                     let val: syn::LitBool = input.parse()?;
@@ -195,6 +195,13 @@ pub enum ErrorKind {
         /// Given type.
         given_type: Typ,
     },
+    /// Type does not satisfy the constraint of a type parameter.
+    UnsatisfiedConstraint {
+        /// Given type.
+        given_type: Typ,
+        /// Constraint of the type parameter.
+        constraint: Constraint,
+    },
     /// Expected lambda with input type.
     ExpectLambda {
         /// Expected types as input for the lambda.
@@ -239,9 +246,9 @@ pub enum ErrorKind {
     /// Incompatible array length.
     IncompatibleLength {
         /// Given length.
-        given_length: usize,
+        given_length: Size,
         /// Expected length.
-        expected_length: usize,
+        expected_length: Size,
     },
     /// Can not infer type.
     NoTypeInference,
@@ -286,8 +293,8 @@ mk_new! { impl ErrorKind =>
         given_type: Typ, expected_type: Typ,
     }
     IncompatibleLength: incompatible_length {
-        given_length: usize,
-        expected_length: usize
+        given_length: Size,
+        expected_length: Size,
     }
     IncompatibleMatchStatements: incompatible_match {
         expected: usize,
@@ -298,6 +305,9 @@ mk_new! { impl ErrorKind =>
     ExpectArithType: expected_arith_type {
         given_type: Typ,
     }
+    UnsatisfiedConstraint: unsatisfied_constraint {
+        given_type: Typ, constraint: Constraint,
+    }
     ExpectLambda: expected_lambda {
         input_types: Vec<Typ>, given_type: Typ,
     }
@@ -453,6 +463,13 @@ impl Display for ErrorKind {
                     "expected a number such as `7` or `1.32`, got a value of type`{given_type}`"
                 )
             }
+            UnsatisfiedConstraint {
+                given_type,
+                constraint,
+            } => write!(
+                f,
+                "type `{given_type}` does not satisfy constraint `{constraint}`"
+            ),
             ExpectLambda { given_type, .. } => write!(f, "expected lambda but given {given_type}"),
             ExpectOption { given_type } => write!(f, "expected option but given {given_type}"),
             ExpectStructure { given_type } => {
@@ -592,6 +609,26 @@ impl Error {
         d
     }
 
+    #[cfg(feature = "diagnostics")]
+    pub fn to_warning_diagnostic(self) -> macro1::Diagnostic {
+        use macro1::*;
+        let (error_kind, notes) = self.val;
+        let loc = self
+            .loc
+            .expect("internal error: warning has no location >_<")
+            .unwrap();
+        let mut d = Diagnostic::spanned(&[loc] as &[Span], Level::Warning, error_kind.to_string());
+        for note in notes {
+            let msg = note.val;
+            if let Some(loc) = note.loc {
+                d = d.span_note(&[loc.unwrap()] as &[Span], msg.to_string());
+            } else {
+                d = d.note(msg.to_string());
+            }
+        }
+        d
+    }
+
     #[cfg(feature = "diagnostics")]
     pub fn emit(self) {
         self.to_diagnostic().emit();
@@ -601,6 +638,11 @@ impl Error {
     pub fn emit_note(self) {
         self.to_note_diagnostic().emit();
     }
+
+    #[cfg(feature = "diagnostics")]
+    pub fn emit_warning(self) {
+        self.to_warning_diagnostic().emit();
+    }
 }
 
 /// [`Result`]-type with [`Error`] as errors.
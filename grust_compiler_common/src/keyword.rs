@@ -5,6 +5,11 @@ custom_keyword!(component);
 custom_keyword!(function);
 custom_keyword!(service);
 
+// Test scenarios keywords.
+custom_keyword!(test);
+custom_keyword!(step);
+custom_keyword!(expect);
+
 // Period keywords.
 custom_keyword!(ms);
 
@@ -14,6 +19,12 @@ custom_keyword!(ensures);
 custom_keyword!(invariant);
 custom_keyword!(assert);
 custom_keyword!(forall);
+custom_keyword!(exists);
+custom_keyword!(once);
+custom_keyword!(historically);
+custom_keyword!(since);
+custom_keyword!(within);
+custom_keyword!(after);
 custom_keyword!(result);
 
 // Typedefs keywords.
@@ -45,6 +56,11 @@ custom_keyword!(float);
 custom_keyword!(bool);
 custom_keyword!(unit);
 
+// Type constraints.
+custom_keyword!(num);
+custom_keyword!(eq);
+custom_keyword!(ord);
+
 // Operators and constructs.
 custom_keyword!(when);
 custom_keyword!(then);
@@ -58,3 +74,4 @@ custom_keyword!(fold);
 custom_keyword!(map);
 custom_keyword!(zip);
 custom_keyword!(log);
+custom_keyword!(every);
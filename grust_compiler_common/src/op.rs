@@ -169,6 +169,11 @@ impl std::fmt::Display for BOp {
     }
 }
 impl BOp {
+    /// True on arithmetic operators, which follow the [Arith] policy on integers.
+    pub fn is_arith(&self) -> bool {
+        matches!(self, BOp::Mul | BOp::Div | BOp::Mod | BOp::Add | BOp::Sub)
+    }
+
     fn both_arith(loc: Loc, lft: &Typ, rgt: &Typ, and_same_type: bool) -> URes {
         check::typ::arith_like(loc, lft)?;
         check::typ::arith_like(loc, rgt)?;
@@ -185,9 +190,21 @@ impl BOp {
         Ok(Typ::function(vec![lft.clone(), rgt], lft))
     }
 
+    /// Type parameters must be ordered, other types must be numbers.
+    fn both_ordered(loc: Loc, lft: &Typ, rgt: &Typ) -> URes {
+        for typ in [lft, rgt] {
+            if let Typ::Param { .. } = typ {
+                typ.check_constraint(loc, Constraint::Ordered)?;
+            } else {
+                check::typ::arith_like(loc, typ)?;
+            }
+        }
+        lft.expect(loc, rgt)
+    }
+
     fn numerical_comparison(input_types: Vec<Typ>, loc: Loc) -> Res<Typ> {
         let (lft, rgt) = check::arity::binary(loc, input_types)?;
-        Self::both_arith(loc, &lft, &rgt, true)
+        Self::both_ordered(loc, &lft, &rgt)
             .err_note(lnote!( @loc => "in this numerical-value comparison" ))?;
         Ok(Typ::function(vec![lft, rgt], Typ::bool()))
     }
@@ -196,6 +213,10 @@ impl BOp {
         let (lft, rgt) = check::arity::binary(loc, input_types)?;
         lft.expect(loc, &rgt)
             .err_note(lnote!( @loc => "in this equality"))?;
+        if let Typ::Param { .. } = lft {
+            lft.check_constraint(loc, Constraint::Equality)
+                .err_note(lnote!( @loc => "in this equality"))?;
+        }
         Ok(Typ::function(vec![lft, rgt], Typ::bool()))
     }
 
@@ -227,6 +248,66 @@ impl BOp {
     }
 }
 
+/// Integer arithmetic policies.
+///
+/// [Arith] tells how the integer arithmetic operators `+`, `-`, `*`, `/` and `%` behave on
+/// overflow and division by zero. The policy is set globally with the `arith` configuration item,
+/// and per-expression by suffixing the operator:
+///
+/// - [Arith::Native] uses Rust's operators, overflow panics in debug and wraps in release
+/// - [Arith::Wrapping], `x +% y`, wraps around on overflow, `x / 0` is `0` and `x % 0` is `x`
+/// - [Arith::Saturating], `x +| y`, saturates at the numeric bounds, `x / 0` saturates towards the
+///   sign of `x` and `x % 0` is `x`
+/// - [Arith::Checked], `x +? y`, panics on overflow and division by zero, in release too
+#[derive(EnumIter, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Arith {
+    /// Rust's native operators.
+    #[default]
+    Native,
+    /// Wrapping arithmetic, `x +% y`.
+    Wrapping,
+    /// Saturating arithmetic, `x +| y`.
+    Saturating,
+    /// Checked arithmetic, `x +? y`.
+    Checked,
+}
+impl Arith {
+    /// Tells if an arithmetic policy suffix follows.
+    pub fn peek_suffix(input: ParseStream) -> bool {
+        input.peek(Token![%]) || input.peek(Token![|]) || input.peek(Token![?])
+    }
+
+    /// Parses the optional policy suffix of a binary operator.
+    ///
+    /// Only arithmetic operators accept a suffix.
+    pub fn parse_suffix(op: BOp, input: ParseStream) -> syn::Res<Option<Self>> {
+        if !op.is_arith() || !Self::peek_suffix(input) {
+            return Ok(None);
+        }
+        let arith = if input.peek(Token![%]) {
+            let _: Token![%] = input.parse()?;
+            Self::Wrapping
+        } else if input.peek(Token![|]) {
+            let _: Token![|] = input.parse()?;
+            Self::Saturating
+        } else {
+            let _: Token![?] = input.parse()?;
+            Self::Checked
+        };
+        Ok(Some(arith))
+    }
+}
+impl std::fmt::Display for Arith {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Arith::Native => "native".fmt(f),
+            Arith::Wrapping => "wrapping".fmt(f),
+            Arith::Saturating => "saturating".fmt(f),
+            Arith::Checked => "checked".fmt(f),
+        }
+    }
+}
+
 /// GRust unary operators.
 ///
 /// [UOp] enumeration represents all possible unary operations that can be used in a GRust
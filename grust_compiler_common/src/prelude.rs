@@ -147,7 +147,7 @@ pub use crate::{
     note,
     noteln,
     once_cell,
-    op::{BOp, OtherOp, UOp},
+    op::{Arith, BOp, OtherOp, UOp},
     petgraph,
     quote,
     res_vec,
@@ -159,7 +159,7 @@ pub use crate::{
     synced,
     todoo,
     token,
-    typ::Typ,
+    typ::{Constraint, Size, Typ},
     w8,
 };
 
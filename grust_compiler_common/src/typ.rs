@@ -12,7 +12,8 @@ prelude! {
 /// - [Typ::Float] are [f64] floats, if `r = 1.0` then `r: float`
 /// - [Typ::Boolean] is the [bool] type for booleans, if `b = true` then `b: bool`
 /// - [Typ::Unit] is the unit type, if `u = ()` then `u: unit`
-/// - [Typ::Array] is the array type, if `a = [1, 2, 3]` then `a: [int; 3]`
+/// - [Typ::Array] is the array type, if `a = [1, 2, 3]` then `a: [int; 3]`, its [Size] can be
+///   a static parameter `[int; N]`
 /// - [Typ::Option] is the event type for StateMachine, noted `n: int?`
 /// - [Typ::Enumeration] is a user-defined enumeration:
 ///   - if `c = Color.Yellow`,
@@ -41,7 +42,7 @@ pub enum Typ {
         bracket_token: token::Bracket,
         ty: Box<Typ>,
         semi_token: Token![;],
-        size: syn::LitInt,
+        size: Size,
     },
     /// Option type, noted `n: int?`
     Option {
@@ -89,6 +90,13 @@ pub enum Typ {
     NotDefinedYet(Ident),
     /// Polymorphic type, if `add = |x, y| x+y` then `add: 't : Typ -> t -> 't -> 't`
     Polymorphism(fn(Vec<Typ>, Loc) -> Res<Typ>),
+    /// Type parameter, if `function max<T: ord>(a: T, b: T) -> T` then `a: T`
+    Param {
+        /// Parameter's name.
+        name: Ident,
+        /// Constraints the parameter satisfies.
+        constraints: Vec<Constraint>,
+    },
     /// Match any type.
     Any,
 }
@@ -158,6 +166,9 @@ impl PartialEq for Typ {
                     ..
                 },
             ) => l_elements.iter().zip(r_elements).all(|(a, b)| a == b),
+            (Self::Param { name: l_name, .. }, Self::Param { name: r_name, .. }) => {
+                l_name == r_name
+            }
             (Self::Signal { ty: l_ty, .. }, Self::Signal { ty: r_ty, .. }) => l_ty == r_ty,
             (Self::Event { ty: l_ty, .. }, Self::Event { ty: r_ty, .. }) => l_ty == r_ty,
             _ => false,
@@ -197,10 +208,142 @@ impl Display for Typ {
             Typ::Event { ty, .. } => write!(f, "Event<{}>", *ty),
             Typ::NotDefinedYet(s) => write!(f, "{s}"),
             Typ::Polymorphism(v_t) => write!(f, "{v_t:#?}"),
+            Typ::Param { name, .. } => write!(f, "{name}"),
             Typ::Any => write!(f, "any"),
         }
     }
 }
+/// Size of an array type, a literal `[int; 3]` or a static parameter `[int; N]`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Size {
+    /// Literal size.
+    Lit(syn::LitInt),
+    /// Static parameter of a template.
+    Param(Ident),
+}
+impl Size {
+    /// The literal size, if any.
+    pub fn as_usize(&self) -> Option<usize> {
+        match self {
+            Self::Lit(lit) => lit.base10_parse().ok(),
+            Self::Param(_) => None,
+        }
+    }
+}
+impl Display for Size {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Lit(lit) => write!(f, "{lit}"),
+            Self::Param(name) => write!(f, "{name}"),
+        }
+    }
+}
+impl Parse for Size {
+    fn parse(input: ParseStream) -> syn::Res<Self> {
+        if input.peek(syn::LitInt) {
+            Ok(Self::Lit(input.parse()?))
+        } else {
+            Ok(Self::Param(input.parse()?))
+        }
+    }
+}
+impl ToTokens for Size {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        match self {
+            Self::Lit(lit) => {
+                syn::LitInt::new(&(lit.base10_digits().to_owned() + "usize"), lit.span())
+                    .to_tokens(tokens)
+            }
+            Self::Param(name) => name.to_tokens(tokens),
+        }
+    }
+}
+impl ToLogicTokens for Size {
+    fn to_logic_tokens(&self, tokens: &mut TokenStream2) {
+        match self {
+            Self::Lit(lit) => lit.to_tokens(tokens),
+            Self::Param(name) => name.to_tokens(tokens),
+        }
+    }
+}
+
+/// Constraint on a type parameter.
+///
+/// - [Constraint::Numeric] is noted `T: num`, `T` supports arithmetic operators
+/// - [Constraint::Equality] is noted `T: eq`, `T` supports `==` and `!=`
+/// - [Constraint::Ordered] is noted `T: ord`, `T` supports comparisons and equality
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Constraint {
+    /// Numeric types, `int` and `float`.
+    Numeric,
+    /// Types supporting equality.
+    Equality,
+    /// Ordered types, `int` and `float`.
+    Ordered,
+}
+impl Display for Constraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Numeric => write!(f, "num"),
+            Self::Equality => write!(f, "eq"),
+            Self::Ordered => write!(f, "ord"),
+        }
+    }
+}
+impl Parse for Constraint {
+    fn parse(input: ParseStream) -> syn::Res<Self> {
+        if input.peek(keyword::num) {
+            let _: keyword::num = input.parse()?;
+            Ok(Self::Numeric)
+        } else if input.peek(keyword::eq) {
+            let _: keyword::eq = input.parse()?;
+            Ok(Self::Equality)
+        } else if input.peek(keyword::ord) {
+            let _: keyword::ord = input.parse()?;
+            Ok(Self::Ordered)
+        } else {
+            Err(input.error("expected constraint: `num`, `eq` or `ord`"))
+        }
+    }
+}
+impl Constraint {
+    /// True if satisfying `self` implies satisfying `other`.
+    pub fn implies(self, other: Self) -> bool {
+        self == other || (self == Self::Ordered && other == Self::Equality)
+    }
+
+    /// Rust trait bounds corresponding to the constraint, for a type parameter `param`.
+    pub fn rust_bounds(self, param: &Ident) -> TokenStream2 {
+        match self {
+            Self::Numeric => quote! {
+                std::ops::Add<Output = #param>
+                    + std::ops::Sub<Output = #param>
+                    + std::ops::Mul<Output = #param>
+                    + std::ops::Div<Output = #param>
+                    + std::ops::Rem<Output = #param>
+                    + std::ops::Neg<Output = #param>
+            },
+            Self::Equality => quote!(PartialEq),
+            Self::Ordered => quote!(PartialOrd),
+        }
+    }
+}
+
+impl Typ {
+    /// Parses a type parameter `T: num + ord` into a [Typ::Param].
+    pub fn parse_param(input: ParseStream) -> syn::Res<Self> {
+        let name: Ident = input.parse()?;
+        let mut constraints = vec![];
+        if input.peek(Token![:]) {
+            let _: Token![:] = input.parse()?;
+            let bounds: Punctuated<Constraint, Token![+]> =
+                Punctuated::parse_separated_nonempty(input)?;
+            constraints.extend(bounds);
+        }
+        Ok(Typ::Param { name, constraints })
+    }
+}
+
 impl Parse for Typ {
     fn parse(input: ParseStream) -> syn::Res<Self> {
         let mut ty = if input.peek(keyword::int) {
@@ -231,7 +374,7 @@ impl Parse for Typ {
             } else {
                 let ty = content.parse()?;
                 let semi_token: Token![;] = content.parse()?;
-                let size: syn::LitInt = content.parse()?;
+                let size: Size = content.parse()?;
                 Typ::Array {
                     bracket_token,
                     ty: Box::new(ty),
@@ -301,7 +444,13 @@ mk_new! { impl Typ =>
         bracket_token = Default::default(),
         ty: Typ = ty.into(),
         semi_token = Default::default(),
-        size: usize = syn::LitInt::new(&format!("{size}"), Span::call_site()),
+        size: usize = Size::Lit(syn::LitInt::new(&format!("{size}"), Span::call_site())),
+    }
+    Array: array_of_size {
+        bracket_token = Default::default(),
+        ty: Typ = ty.into(),
+        semi_token = Default::default(),
+        size: Size,
     }
     Enumeration: enumeration {
         name: Ident,
@@ -353,12 +502,44 @@ mk_new! { impl Typ =>
         name: impl Into<String> = Ident::new(&name.into(), Span::call_site())
     )
     Polymorphism: poly(f : fn(Vec<Self>, Loc) -> Res<Self> = f)
+    Param: param {
+        name: impl Into<Ident> = name.into(),
+        constraints: Vec<Constraint>,
+    }
     Any: any()
 }
 
 pub mod typ_tokens {
     use super::*;
 
+    impl Typ {
+        /// Rust generic parameters with their trait bounds, `<T: Copy + Default + Debug + PartialOrd>`.
+        ///
+        /// All GRust values are copied, have a default value and can be logged.
+        pub fn rust_generics(typ_params: &[Typ]) -> TokenStream2 {
+            if typ_params.is_empty() {
+                return quote! {};
+            }
+            let params = typ_params.iter().map(|typ| match typ {
+                Typ::Param { name, constraints } => {
+                    let bounds = constraints.iter().map(|c| c.rust_bounds(name));
+                    quote!(#name: Copy + Default + std::fmt::Debug #(+ #bounds)*)
+                }
+                _ => noErrorDesc!(),
+            });
+            quote!(<#(#params),*>)
+        }
+
+        /// Rust type `ty` applied to type arguments, `SelectState<i64>`.
+        pub fn rust_applied(ty: impl ToTokens, typ_args: &[Typ]) -> TokenStream2 {
+            if typ_args.is_empty() {
+                ty.to_token_stream()
+            } else {
+                quote!(#ty<#(#typ_args),*>)
+            }
+        }
+    }
+
     impl ToTokens for Typ {
         fn to_tokens(&self, tokens: &mut TokenStream2) {
             match self {
@@ -369,13 +550,7 @@ pub mod typ_tokens {
                 Typ::Enumeration { name, .. } | Typ::Structure { name, .. } => {
                     name.to_tokens(tokens)
                 }
-                Typ::Array { ty, size, .. } => {
-                    let size = syn::Lit::Int(syn::LitInt::new(
-                        &(size.base10_digits().to_owned() + "usize"),
-                        size.span(),
-                    ));
-                    quote!( [#ty; #size] ).to_tokens(tokens)
-                }
+                Typ::Array { ty, size, .. } => quote!( [#ty; #size] ).to_tokens(tokens),
                 Typ::Fn { inputs, output, .. } => {
                     let inputs = inputs.iter();
                     quote!(impl Fn(#(#inputs),*) -> #output).to_tokens(tokens)
@@ -386,6 +561,7 @@ pub mod typ_tokens {
                 }
                 Typ::Event { ty, .. } | Typ::Signal { ty, .. } => ty.to_tokens(tokens),
                 Typ::Option { ty, .. } => quote!(Option<#ty>).to_tokens(tokens),
+                Typ::Param { name, .. } => name.to_tokens(tokens),
                 Typ::NotDefinedYet(_) | Typ::Polymorphism(_) | Typ::Any => {
                     noErrorDesc!()
                 }
@@ -400,6 +576,7 @@ pub mod typ_tokens {
                 Typ::Float(_) => quote!(Float).to_tokens(tokens),
                 Typ::Array { ty, size, .. } => {
                     let ty = ty.to_logic();
+                    let size = size.to_logic();
                     quote!([#ty; #size]).to_tokens(tokens)
                 }
                 Typ::Tuple { elements, .. } => {
@@ -413,7 +590,8 @@ pub mod typ_tokens {
                 Typ::Boolean(_)
                 | Typ::Unit(_)
                 | Typ::Enumeration { .. }
-                | Typ::Structure { .. } => self.to_tokens(tokens),
+                | Typ::Structure { .. }
+                | Typ::Param { .. } => self.to_tokens(tokens),
                 Typ::Fn { .. }
                 | Typ::Event { .. }
                 | Typ::Signal { .. }
@@ -437,10 +615,6 @@ pub mod typ_tokens {
                     quote!(#path::#name).to_tokens(tokens)
                 }
                 Typ::Array { ty, size, .. } => {
-                    let size = syn::Lit::Int(syn::LitInt::new(
-                        &(size.base10_digits().to_owned() + "usize"),
-                        size.span(),
-                    ));
                     let ty = ty.to_prefix(path);
                     quote!( [#ty; #size] ).to_tokens(tokens)
                 }
@@ -458,6 +632,7 @@ pub mod typ_tokens {
                     let ty = ty.to_prefix(path);
                     quote!(Option<#ty>).to_tokens(tokens)
                 }
+                Typ::Param { name, .. } => name.to_tokens(tokens),
                 Typ::NotDefinedYet(_) | Typ::Polymorphism(_) | Typ::Any => {
                     noErrorDesc!()
                 }
@@ -475,7 +650,8 @@ impl Typ {
             | Typ::Unit(_)
             | Typ::Enumeration { .. }
             | Typ::Structure { .. }
-            | Typ::Option { .. } => false,
+            | Typ::Option { .. }
+            | Typ::Param { .. } => false,
             Typ::Fn { .. }
             | Typ::Event { .. }
             | Typ::Signal { .. }
@@ -505,7 +681,7 @@ impl Typ {
             Typ::Tuple { paren_token, .. } => Some(paren_token.span.join().into()),
             Typ::Event { ty, event_token } => ty.loc()?.try_join(event_token.span),
             Typ::Signal { ty, signal_token } => ty.loc()?.try_join(signal_token.span),
-            Typ::NotDefinedYet(id) => Some(id.span().into()),
+            Typ::NotDefinedYet(id) | Typ::Param { name: id, .. } => Some(id.span().into()),
             Typ::Polymorphism(_) | Typ::Any => None,
         }
     }
@@ -519,6 +695,7 @@ impl Typ {
     pub fn is_arith_like(&self) -> bool {
         match self {
             Self::Integer(_) | Self::Float(_) | Self::Any => true,
+            Self::Param { constraints, .. } => constraints.contains(&Constraint::Numeric),
             Typ::Boolean(_)
             | Typ::Unit(_)
             | Typ::Array { .. }
@@ -539,6 +716,232 @@ impl Typ {
         check::typ::arith_like(loc, self)
     }
 
+    /// True if this type satisfies the `constraint` of a type parameter.
+    ///
+    /// ```rust
+    /// # grust_compiler_common::prelude! {}
+    /// assert!(Typ::int().satisfies(Constraint::Ordered));
+    /// assert!(Typ::array(Typ::bool(), 3).satisfies(Constraint::Equality));
+    /// assert!(!Typ::bool().satisfies(Constraint::Numeric));
+    /// ```
+    pub fn satisfies(&self, constraint: Constraint) -> bool {
+        match self {
+            Typ::Param { constraints, .. } => constraints.iter().any(|c| c.implies(constraint)),
+            Typ::Integer(_) | Typ::Float(_) | Typ::Any => true,
+            _ if constraint != Constraint::Equality => false,
+            Typ::Boolean(_) | Typ::Unit(_) | Typ::Enumeration { .. } | Typ::Structure { .. } => {
+                true
+            }
+            Typ::Array { ty, .. } | Typ::Option { ty, .. } => ty.satisfies(constraint),
+            Typ::Tuple { elements, .. } => elements.iter().all(|ty| ty.satisfies(constraint)),
+            Typ::Fn { .. }
+            | Typ::Signal { .. }
+            | Typ::Event { .. }
+            | Typ::NotDefinedYet(_)
+            | Typ::Polymorphism(_) => false,
+        }
+    }
+
+    /// Fails if `self` does not satisfy `constraint` at location `loc`.
+    pub fn check_constraint(&self, loc: Loc, constraint: Constraint) -> URes {
+        check::typ::constraint(loc, self, constraint)
+    }
+
+    /// True if this type mentions a type parameter.
+    pub fn has_params(&self) -> bool {
+        match self {
+            Typ::Param { .. } => true,
+            Typ::Array { ty, .. }
+            | Typ::Option { ty, .. }
+            | Typ::Signal { ty, .. }
+            | Typ::Event { ty, .. } => ty.has_params(),
+            Typ::Tuple { elements, .. } => elements.iter().any(Typ::has_params),
+            Typ::Fn { inputs, output, .. } => {
+                inputs.iter().any(Typ::has_params) || output.has_params()
+            }
+            Typ::Integer(_)
+            | Typ::Float(_)
+            | Typ::Boolean(_)
+            | Typ::Unit(_)
+            | Typ::Enumeration { .. }
+            | Typ::Structure { .. }
+            | Typ::NotDefinedYet(_)
+            | Typ::Polymorphism(_)
+            | Typ::Any => false,
+        }
+    }
+
+    /// Unifies `self`, that can mention type parameters, with the `given` type.
+    ///
+    /// Type parameters bound in `subst` must match the given type, unbound parameters are bound
+    /// to the given type if it satisfies their constraints.
+    pub fn unify(&self, loc: Loc, given: &Typ, subst: &mut Vec<(Ident, Typ)>) -> URes {
+        match (self, given) {
+            (Typ::Param { name, constraints }, _) => {
+                if let Some((_, bound)) = subst.iter().find(|(param, _)| param == name) {
+                    return given.expect(loc, bound);
+                }
+                for constraint in constraints {
+                    given.check_constraint(loc, *constraint)?;
+                }
+                subst.push((name.clone(), given.clone()));
+                Ok(())
+            }
+            (
+                Typ::Array { ty, size, .. },
+                Typ::Array {
+                    ty: given_ty,
+                    size: given_size,
+                    ..
+                },
+            ) if size == given_size => ty.unify(loc, given_ty, subst),
+            (Typ::Option { ty, .. }, Typ::Option { ty: given_ty, .. })
+            | (Typ::Signal { ty, .. }, Typ::Signal { ty: given_ty, .. })
+            | (Typ::Event { ty, .. }, Typ::Event { ty: given_ty, .. }) => {
+                ty.unify(loc, given_ty, subst)
+            }
+            (
+                Typ::Tuple { elements, .. },
+                Typ::Tuple {
+                    elements: given_elements,
+                    ..
+                },
+            ) if elements.len() == given_elements.len() => elements
+                .iter()
+                .zip(given_elements)
+                .try_for_each(|(ty, given_ty)| ty.unify(loc, given_ty, subst)),
+            (
+                Typ::Fn { inputs, output, .. },
+                Typ::Fn {
+                    inputs: given_inputs,
+                    output: given_output,
+                    ..
+                },
+            ) if inputs.len() == given_inputs.len() => {
+                inputs
+                    .iter()
+                    .zip(given_inputs)
+                    .try_for_each(|(ty, given_ty)| ty.unify(loc, given_ty, subst))?;
+                output.unify(loc, given_output, subst)
+            }
+            _ if self.has_params() => {
+                bail!(@loc => ErrorKind::incompatible_types(given.clone(), self.clone()))
+            }
+            _ => given.expect(loc, self),
+        }
+    }
+
+    /// Replaces the type parameters of `self` by their binding in `subst`.
+    ///
+    /// Fails on type parameters that are not bound.
+    pub fn instantiate(&self, loc: Loc, subst: &[(Ident, Typ)]) -> Res<Typ> {
+        let mut res = self.clone();
+        res.instantiate_mut(loc, subst)?;
+        Ok(res)
+    }
+    fn instantiate_mut(&mut self, loc: Loc, subst: &[(Ident, Typ)]) -> URes {
+        match self {
+            Typ::Param { name, .. } => match subst.iter().find(|(param, _)| param == name) {
+                Some((_, bound)) => *self = bound.clone(),
+                None => bail!(@loc => "cannot infer type parameter `{name}`"),
+            },
+            Typ::Array { ty, .. }
+            | Typ::Option { ty, .. }
+            | Typ::Signal { ty, .. }
+            | Typ::Event { ty, .. } => ty.instantiate_mut(loc, subst)?,
+            Typ::Tuple { elements, .. } => elements
+                .iter_mut()
+                .try_for_each(|ty| ty.instantiate_mut(loc, subst))?,
+            Typ::Fn { inputs, output, .. } => {
+                inputs
+                    .iter_mut()
+                    .try_for_each(|ty| ty.instantiate_mut(loc, subst))?;
+                output.instantiate_mut(loc, subst)?
+            }
+            Typ::Integer(_)
+            | Typ::Float(_)
+            | Typ::Boolean(_)
+            | Typ::Unit(_)
+            | Typ::Enumeration { .. }
+            | Typ::Structure { .. }
+            | Typ::NotDefinedYet(_)
+            | Typ::Polymorphism(_)
+            | Typ::Any => (),
+        }
+        Ok(())
+    }
+
+    /// True if the size of an array in this type is a static parameter.
+    pub fn has_size_params(&self) -> bool {
+        match self {
+            Typ::Array {
+                size: Size::Param(_),
+                ..
+            } => true,
+            Typ::Array { ty, .. }
+            | Typ::Option { ty, .. }
+            | Typ::Signal { ty, .. }
+            | Typ::Event { ty, .. } => ty.has_size_params(),
+            Typ::Tuple { elements, .. } => elements.iter().any(Typ::has_size_params),
+            Typ::Fn { inputs, output, .. } => {
+                inputs.iter().any(Typ::has_size_params) || output.has_size_params()
+            }
+            Typ::Integer(_)
+            | Typ::Float(_)
+            | Typ::Boolean(_)
+            | Typ::Unit(_)
+            | Typ::Enumeration { .. }
+            | Typ::Structure { .. }
+            | Typ::NotDefinedYet(_)
+            | Typ::Polymorphism(_)
+            | Typ::Param { .. }
+            | Typ::Any => false,
+        }
+    }
+
+    /// Replaces the static parameters in the array sizes of `self` by their binding in `subst`.
+    ///
+    /// Static parameters that are not bound are left as is.
+    pub fn instantiate_sizes(&self, subst: &[(Ident, Size)]) -> Typ {
+        let mut res = self.clone();
+        res.instantiate_sizes_mut(subst);
+        res
+    }
+    fn instantiate_sizes_mut(&mut self, subst: &[(Ident, Size)]) {
+        match self {
+            Typ::Array { ty, size, .. } => {
+                if let Size::Param(name) = size {
+                    if let Some((_, bound)) = subst.iter().find(|(param, _)| param == name) {
+                        *size = bound.clone()
+                    }
+                }
+                ty.instantiate_sizes_mut(subst)
+            }
+            Typ::Option { ty, .. } | Typ::Signal { ty, .. } | Typ::Event { ty, .. } => {
+                ty.instantiate_sizes_mut(subst)
+            }
+            Typ::Tuple { elements, .. } => elements
+                .iter_mut()
+                .for_each(|ty| ty.instantiate_sizes_mut(subst)),
+            Typ::Fn { inputs, output, .. } => {
+                inputs
+                    .iter_mut()
+                    .for_each(|ty| ty.instantiate_sizes_mut(subst));
+                output.instantiate_sizes_mut(subst)
+            }
+            Typ::Integer(_)
+            | Typ::Float(_)
+            | Typ::Boolean(_)
+            | Typ::Unit(_)
+            | Typ::Enumeration { .. }
+            | Typ::Structure { .. }
+            | Typ::NotDefinedYet(_)
+            | Typ::Polymorphism(_)
+            | Typ::Param { .. }
+            | Typ::Any => (),
+        }
+    }
+
     pub fn expect(&self, loc: Loc, expected: &Self) -> URes {
         check::typ::expect(loc, self, expected)
     }
@@ -573,6 +976,26 @@ impl Typ {
         match self {
             // if self is a lambda, check if the input types are equal
             // and return the output type as the type of the application
+            // if self is a generic function, unify the input types with the expected ones to
+            // infer the type parameters
+            Typ::Fn { .. } if self.has_params() => {
+                let inputs = self.get_inputs().cloned().collect_vec();
+                check::arity::expect(loc, input_types.len(), inputs.len()).dewrap(errors)?;
+                let mut subst = vec![];
+                for (idx, (typ, expected)) in input_types.iter().zip(inputs.iter()).enumerate() {
+                    expected
+                        .unify(loc, typ, &mut subst)
+                        .err_note(lnote!(
+                            @loc => "on argument #{} of this generic function application", idx + 1,
+                        ))
+                        .dewrap(errors)?;
+                }
+                *self = self.instantiate(loc, &subst).dewrap(errors)?;
+                match self {
+                    Typ::Fn { output, .. } => Ok((**output).clone()),
+                    _ => noErrorDesc!(),
+                }
+            }
             Typ::Fn { inputs, output, .. } => {
                 check::arity::expect(loc, input_types.len(), inputs.len()).move_err(errors);
                 let mut fail = false;
@@ -741,6 +1164,7 @@ impl Typ {
                 | Unit(_)
                 | Enumeration { .. }
                 | Structure { .. }
+                | Param { .. }
                 | Any => (),
                 // components we need to go down into
                 Array { ty, .. } | Option { ty, .. } | Signal { ty, .. } | Event { ty, .. } => {
@@ -861,6 +1285,52 @@ mod test {
         assert_eq!(polymorphic_type, control);
     }
 
+    #[test]
+    fn should_infer_type_parameters_of_generic_fn() {
+        let mut errors = vec![];
+
+        let typ_t = Typ::param(Loc::test_id("T"), vec![Constraint::Ordered]);
+        let mut generic_type = Typ::function(vec![typ_t.clone(), typ_t.clone()], typ_t);
+
+        let application_result = generic_type
+            .apply(
+                vec![Typ::float(), Typ::float()],
+                Loc::test_dummy(),
+                &mut errors,
+            )
+            .unwrap();
+
+        assert_eq!(application_result, Typ::float());
+        assert_eq!(
+            generic_type,
+            Typ::function(vec![Typ::float(), Typ::float()], Typ::float())
+        );
+    }
+
+    #[test]
+    fn should_raise_error_when_constraint_not_satisfied() {
+        let mut errors = vec![];
+
+        let typ_t = Typ::param(Loc::test_id("T"), vec![Constraint::Numeric]);
+        let mut generic_type = Typ::function(vec![typ_t.clone(), typ_t.clone()], typ_t.clone());
+
+        let _ = generic_type
+            .clone()
+            .apply(
+                vec![Typ::bool(), Typ::bool()],
+                Loc::test_dummy(),
+                &mut errors,
+            )
+            .unwrap_err();
+        let _ = generic_type
+            .apply(
+                vec![Typ::int(), Typ::float()],
+                Loc::test_dummy(),
+                &mut errors,
+            )
+            .unwrap_err();
+    }
+
     #[test]
     fn should_return_inputs_from_fn_type() {
         let fn_type = Typ::function(vec![Typ::int(), Typ::int()], Typ::int());
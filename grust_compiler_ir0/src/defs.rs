@@ -4,17 +4,20 @@ pub mod contract;
 pub mod equation;
 pub mod expr;
 pub mod interface;
+pub mod scenario;
 pub mod stmt;
 pub mod stream;
 
 prelude! {}
 
-/// An `ir0` context, gathers a symbol table and a [`Conf`].
+/// An `ir0` context, gathers a symbol table, a [`Conf`] and the compilation warnings.
 ///
 /// For convenience, this type [`std::ops::Deref`]s/[`std::ops::DerefMut`]s to [`symbol::Table`].
 pub struct Ctx {
     pub table: symbol::Table,
     pub conf: conf::Conf,
+    /// Warnings raised during the compilation.
+    pub warnings: Vec<Error>,
 }
 impl std::ops::Deref for Ctx {
     type Target = symbol::Table;
@@ -30,7 +33,11 @@ impl std::ops::DerefMut for Ctx {
 impl Ctx {
     /// Constructor.
     pub fn new(table: symbol::Table, conf: Conf) -> Self {
-        Self { table, conf }
+        Self {
+            table,
+            conf,
+            warnings: vec![],
+        }
     }
 
     /// Constructor from an existing configuration (empty table).
@@ -100,7 +107,7 @@ impl Ctx {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Colon<U, V> {
     pub left: U,
     pub colon: syn::token::Colon,
@@ -176,6 +183,10 @@ pub struct ConstDecl {
     pub value: Expr,
     /// Closing semicolon.
     pub semi_token: Token![;],
+    /// Whether the constant is a `#[monotonic]` lookup table.
+    ///
+    /// The (innermost) rows of monotonic tables must be strictly increasing.
+    pub monotonic: bool,
 }
 impl HasLoc for ConstDecl {
     fn loc(&self) -> Loc {
@@ -188,6 +199,10 @@ pub struct Component {
     pub comp_token: keyword::component,
     /// Component identifier.
     pub ident: Ident,
+    /// Component's static parameters `const N: int` and their types.
+    pub static_params: Vec<(Ident, Typ)>,
+    /// Component's type parameters, as [Typ::Param]s.
+    pub typ_params: Vec<Typ>,
     pub args_paren: syn::token::Paren,
     /// Component's inputs identifiers and their types.
     pub args: syn::Punctuated<Colon<Ident, Typ>, Token![,]>,
@@ -214,6 +229,10 @@ pub struct Function {
     pub function_token: keyword::function,
     /// Function identifier.
     pub ident: Ident,
+    /// Function's static parameters `const N: int` and their types.
+    pub static_params: Vec<(Ident, Typ)>,
+    /// Function's type parameters, as [Typ::Param]s.
+    pub typ_params: Vec<Typ>,
     pub args_paren: syn::token::Paren,
     /// Function's inputs identifiers and their types.
     pub args: syn::Punctuated<Colon<Ident, Typ>, Token![,]>,
@@ -233,6 +252,24 @@ impl HasLoc for Function {
     }
 }
 
+/// GRust test scenario of a component, see [scenario].
+pub struct Scenario {
+    pub test_token: keyword::test,
+    /// Name of the test.
+    pub ident: Ident,
+    pub for_token: Token![for],
+    /// Component under test.
+    pub comp: Ident,
+    pub brace: syn::token::Brace,
+    /// Steps of the scenario.
+    pub steps: Vec<scenario::Step>,
+}
+impl HasLoc for Scenario {
+    fn loc(&self) -> Loc {
+        Loc::from(self.test_token.span).join(self.brace.span.join())
+    }
+}
+
 /// Things that can appear in a GRust program.
 pub enum Item {
     /// GRust synchronous component.
@@ -248,6 +285,8 @@ pub enum Item {
     ExtFun(ExtFunDecl),
     ExtComp(ExtCompDecl),
     Const(ConstDecl),
+    /// GRust test scenario.
+    Test(Scenario),
 }
 impl HasLoc for Item {
     fn loc(&self) -> Loc {
@@ -261,6 +300,7 @@ impl HasLoc for Item {
             Self::ExtComp(c) => c.loc(),
             Self::ExtFun(f) => f.loc(),
             Self::Const(c) => c.loc(),
+            Self::Test(t) => t.loc(),
         }
     }
 }
@@ -270,6 +310,94 @@ pub struct Ast {
     /// Items contained in the GRust program.
     pub items: Vec<Item>,
 }
+impl Ast {
+    /// Expands multi-instance services.
+    ///
+    /// Each instance of a service gets its own copy of the imports/exports the service uses,
+    /// prefixed by the instance identifier. Imports/exports only used by multi-instance services
+    /// are removed, the others are kept for the singleton services.
+    pub fn instantiate_services(&mut self, errors: &mut Vec<Error>) -> TRes<()> {
+        // imported/exported flows
+        let interface = self
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Import(import) => Some(import.ident().clone()),
+                Item::Export(export) => Some(export.ident().clone()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        // flows used by singleton services, and `(instance, flow)` used by multi-instance ones
+        let mut shared_flows: Vec<Ident> = vec![];
+        let mut instance_flows: Vec<(Ident, Ident)> = vec![];
+        for item in self.items.iter() {
+            if let Item::Service(service) = item {
+                if service.is_multi_instance() {
+                    for instance in service.instances.iter() {
+                        for flow in service.global_flows(&interface) {
+                            let instance_flow = (instance.ident.clone(), flow.clone());
+                            if !instance_flows.contains(&instance_flow) {
+                                instance_flows.push(instance_flow)
+                            }
+                        }
+                    }
+                } else if !service.params.is_empty() {
+                    bad!(errors, @service.ident.loc() =>
+                        ErrorKind::msg("parameterized services must be instantiated with `for`")
+                    )
+                } else {
+                    shared_flows.extend(service.global_flows(&interface).into_iter().cloned())
+                }
+            }
+        }
+        if instance_flows.is_empty() {
+            return Ok(());
+        }
+
+        let is_shared = |flow: &Ident| {
+            shared_flows.contains(flow) || instance_flows.iter().all(|(_, f)| f != flow)
+        };
+        let instances_of = |flow: &Ident| {
+            instance_flows
+                .iter()
+                .filter(|(_, f)| f == flow)
+                .map(|(instance, _)| instance)
+                .collect::<Vec<_>>()
+        };
+
+        let mut items = Vec::with_capacity(self.items.len());
+        for item in std::mem::take(&mut self.items) {
+            match item {
+                Item::Import(import) => {
+                    for instance in instances_of(import.ident()) {
+                        items.push(Item::Import(import.instantiate(instance)))
+                    }
+                    if is_shared(import.ident()) {
+                        items.push(Item::Import(import))
+                    }
+                }
+                Item::Export(export) => {
+                    for instance in instances_of(export.ident()) {
+                        items.push(Item::Export(export.instantiate(instance)))
+                    }
+                    if is_shared(export.ident()) {
+                        items.push(Item::Export(export))
+                    }
+                }
+                Item::Service(service) if service.is_multi_instance() => {
+                    for instance in service.instances.iter() {
+                        items.push(Item::Service(
+                            service.instantiate(instance, &interface, errors)?,
+                        ))
+                    }
+                }
+                item => items.push(item),
+            }
+        }
+        self.items = items;
+        Ok(())
+    }
+}
 
 pub struct Top {
     pub ast: Ast,
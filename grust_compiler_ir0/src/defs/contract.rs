@@ -29,6 +29,191 @@ mk_new! { impl ForAll =>
     }
 }
 
+#[derive(Debug, PartialEq, Clone)]
+/// Exists term.
+pub struct Exists {
+    pub exists_token: keyword::exists,
+    pub ident: Ident,
+    pub colon_token: Token![:],
+    pub ty: Typ,
+    pub comma_token: Token![,],
+    pub term: Box<Term>,
+}
+impl HasLoc for Exists {
+    fn loc(&self) -> Loc {
+        Loc::from(self.exists_token.span).join(self.term.loc())
+    }
+}
+
+mk_new! { impl Exists =>
+    new {
+        exists_token: keyword::exists,
+        ident: impl Into<Ident> = ident.into(),
+        colon_token: Token![:],
+        ty: Typ,
+        comma_token: Token![,],
+        term: Term = term.into(),
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+/// Quantifier of a bounded term.
+pub enum Quantifier {
+    ForAll(keyword::forall),
+    Exists(keyword::exists),
+}
+impl HasLoc for Quantifier {
+    fn loc(&self) -> Loc {
+        match self {
+            Self::ForAll(kw) => kw.span.into(),
+            Self::Exists(kw) => kw.span.into(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+/// Bounded quantifier term, `forall i in 0..N, P(i)` or `exists i in 0..N, P(i)`.
+///
+/// The integer `i` ranges from `lo` included to `hi` excluded.
+pub struct Bounded {
+    pub quantifier: Quantifier,
+    pub ident: Ident,
+    pub in_token: Token![in],
+    pub lo: Box<Term>,
+    pub range_token: Token![..],
+    pub hi: Box<Term>,
+    pub comma_token: Token![,],
+    pub term: Box<Term>,
+}
+impl HasLoc for Bounded {
+    fn loc(&self) -> Loc {
+        self.quantifier.loc().join(self.term.loc())
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+/// Array access term, `a[i]`.
+pub struct ArrayAccess {
+    pub term: Box<Term>,
+    pub bracket_token: syn::token::Bracket,
+    pub index: Box<Term>,
+}
+impl HasLoc for ArrayAccess {
+    fn loc(&self) -> Loc {
+        self.term.loc().join(self.bracket_token.span.join())
+    }
+}
+
+mk_new! { impl ArrayAccess =>
+    new {
+        term: Term = term.into(),
+        bracket_token: syn::token::Bracket,
+        index: Term = index.into(),
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+/// Field access term, `s.f`.
+pub struct FieldAccess {
+    pub term: Box<Term>,
+    pub dot_token: Token![.],
+    pub field: Ident,
+}
+impl HasLoc for FieldAccess {
+    fn loc(&self) -> Loc {
+        self.term.loc().join(self.field.loc())
+    }
+}
+
+mk_new! { impl FieldAccess =>
+    new {
+        term: Term = term.into(),
+        dot_token: Token![.],
+        field: impl Into<Ident> = field.into(),
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+/// Past-time temporal term `once P`, `P` held at least once until now.
+pub struct Once {
+    pub once_token: keyword::once,
+    pub term: Box<Term>,
+}
+impl HasLoc for Once {
+    fn loc(&self) -> Loc {
+        Loc::from(self.once_token.span).join(self.term.loc())
+    }
+}
+
+mk_new! { impl Once =>
+    new {
+        once_token: keyword::once,
+        term: Term = term.into(),
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+/// Past-time temporal term `historically P`, `P` always held until now.
+pub struct Historically {
+    pub historically_token: keyword::historically,
+    pub term: Box<Term>,
+}
+impl HasLoc for Historically {
+    fn loc(&self) -> Loc {
+        Loc::from(self.historically_token.span).join(self.term.loc())
+    }
+}
+
+mk_new! { impl Historically =>
+    new {
+        historically_token: keyword::historically,
+        term: Term = term.into(),
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+/// Past-time temporal term `P since Q`, `Q` held once and `P` always held after it.
+pub struct Since {
+    pub left: Box<Term>,
+    pub since_token: keyword::since,
+    pub right: Box<Term>,
+}
+impl HasLoc for Since {
+    fn loc(&self) -> Loc {
+        self.left.loc().join(self.right.loc())
+    }
+}
+
+mk_new! { impl Since =>
+    new {
+        left: Term = left.into(),
+        since_token: keyword::since,
+        right: Term = right.into(),
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+/// Bounded past-time temporal term `within n P`, `P` held during the last `n` instants.
+pub struct Within {
+    pub within_token: keyword::within,
+    /// Number of instants, including the current one.
+    pub bound: syn::LitInt,
+    pub term: Box<Term>,
+}
+impl HasLoc for Within {
+    fn loc(&self) -> Loc {
+        Loc::from(self.within_token.span).join(self.term.loc())
+    }
+}
+
+mk_new! { impl Within =>
+    new {
+        within_token: keyword::within,
+        bound: syn::LitInt,
+        term: Term = term.into(),
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 /// Implication term.
 pub struct Implication {
@@ -180,6 +365,14 @@ pub enum Term {
     Unary(Unary),
     Binary(Binary),
     ForAll(ForAll),
+    Exists(Exists),
+    Bounded(Bounded),
+    ArrayAccess(ArrayAccess),
+    FieldAccess(FieldAccess),
+    Once(Once),
+    Historically(Historically),
+    Since(Since),
+    Within(Within),
     Implication(Implication),
     EventImplication(EventImplication),
     Application(Application),
@@ -196,6 +389,14 @@ impl HasLoc for Term {
             Self::Unary(u) => u.loc(),
             Self::Binary(b) => b.loc(),
             Self::ForAll(f) => f.loc(),
+            Self::Exists(e) => e.loc(),
+            Self::Bounded(b) => b.loc(),
+            Self::ArrayAccess(a) => a.loc(),
+            Self::FieldAccess(f) => f.loc(),
+            Self::Once(o) => o.loc(),
+            Self::Historically(h) => h.loc(),
+            Self::Since(s) => s.loc(),
+            Self::Within(w) => w.loc(),
             Self::Implication(i) => i.loc(),
             Self::EventImplication(ei) => ei.loc(),
             Self::Application(app) => app.loc(),
@@ -214,11 +415,83 @@ mk_new! { impl Term =>
     Unary: unary (val: Unary = val)
     Binary: binary (val: Binary = val)
     ForAll: forall (val: ForAll = val)
+    Exists: exists (val: Exists = val)
+    Bounded: bounded (val: Bounded = val)
+    ArrayAccess: array_access (val: ArrayAccess = val)
+    FieldAccess: field_access (val: FieldAccess = val)
+    Once: once (val: Once = val)
+    Historically: historically (val: Historically = val)
+    Since: since (val: Since = val)
+    Within: within (val: Within = val)
     Implication: implication (val: Implication = val)
     EventImplication: event (val: EventImplication = val)
     Application: app (val: Application = val)
 }
 
+impl Term {
+    /// Renames the free occurrences of the identifier `old` into `new`.
+    pub fn rename_ident(&mut self, old: &Ident, new: &Ident) {
+        match self {
+            Self::Identifier(ident) | Self::Last(ident) => {
+                if ident == old {
+                    *ident = new.clone()
+                }
+            }
+            Self::Constant(_) | Self::Result(_) | Self::Enumeration(_) => (),
+            Self::Paren(term)
+            | Self::Unary(Unary { term, .. })
+            | Self::FieldAccess(FieldAccess { term, .. })
+            | Self::Once(Once { term, .. })
+            | Self::Historically(Historically { term, .. })
+            | Self::Within(Within { term, .. }) => term.rename_ident(old, new),
+            Self::Binary(Binary { left, right, .. })
+            | Self::Since(Since { left, right, .. })
+            | Self::Implication(Implication { left, right, .. }) => {
+                left.rename_ident(old, new);
+                right.rename_ident(old, new)
+            }
+            Self::ArrayAccess(ArrayAccess { term, index, .. }) => {
+                term.rename_ident(old, new);
+                index.rename_ident(old, new)
+            }
+            Self::ForAll(ForAll { ident, term, .. }) | Self::Exists(Exists { ident, term, .. }) => {
+                if ident != old {
+                    term.rename_ident(old, new)
+                }
+            }
+            Self::Bounded(Bounded {
+                ident,
+                lo,
+                hi,
+                term,
+                ..
+            }) => {
+                lo.rename_ident(old, new);
+                hi.rename_ident(old, new);
+                if ident != old {
+                    term.rename_ident(old, new)
+                }
+            }
+            Self::EventImplication(EventImplication {
+                pattern,
+                event,
+                term,
+                ..
+            }) => {
+                if event == old {
+                    *event = new.clone()
+                }
+                if pattern != old {
+                    term.rename_ident(old, new)
+                }
+            }
+            Self::Application(Application { inputs, .. }) => inputs
+                .iter_mut()
+                .for_each(|input| input.rename_ident(old, new)),
+        }
+    }
+}
+
 impl TryFrom<ir0::Expr> for Term {
     type Error = Error;
 
@@ -240,6 +513,9 @@ impl TryFrom<ir0::Expr> for Term {
                     rgt,
                 )))
             }
+            ir0::Expr::Application(application) if !application.static_args.is_empty() => Err(
+                error!(@application.loc() => ErrorKind::msg("template applications are not supported in contracts")),
+            ),
             ir0::Expr::Application(application) => match *application.fun {
                 Expr::Identifier(fun) => {
                     let inputs = application
@@ -31,6 +31,8 @@ pub struct BinOp<E> {
     pub op: BOp,
     /// Operator's location.
     pub op_loc: Loc,
+    /// Arithmetic policy given by the operator's suffix, if any.
+    pub arith: Option<Arith>,
     /// The left expression.
     pub lft: Box<E>,
     /// The right expression.
@@ -45,10 +47,17 @@ mk_new! { impl{E} BinOp<E> =>
     new {
         op : BOp,
         op_loc: Loc,
+        arith = None,
+        lft: impl Into<Box<E>> = lft.into(),
+        rgt: impl Into<Box<E>> = rgt.into(),
+    }
+    with_arith {
+        op : BOp,
+        op_loc: Loc,
+        arith: Option<Arith>,
         lft: impl Into<Box<E>> = lft.into(),
         rgt: impl Into<Box<E>> = rgt.into(),
     }
-
 }
 
 /// IfThenElse expression.
@@ -84,6 +93,8 @@ pub struct Application<E> {
     pub loc: Loc,
     /// The expression applied.
     pub fun: Box<E>,
+    /// The static arguments of a template application `filter::<3, 0.5>(x)`.
+    pub static_args: Vec<Expr>,
     /// The inputs to the expression.
     pub inputs: Vec<E>,
 }
@@ -96,6 +107,13 @@ mk_new! { impl{E} Application<E> =>
     new {
         loc: impl Into<Loc> = loc.into(),
         fun: impl Into<Box<E>> = fun.into(),
+        static_args = vec![],
+        inputs: Vec<E>,
+    }
+    new_static {
+        loc: impl Into<Loc> = loc.into(),
+        fun: impl Into<Box<E>> = fun.into(),
+        static_args: Vec<Expr>,
         inputs: Vec<E>,
     }
 }
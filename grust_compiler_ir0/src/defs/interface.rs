@@ -3,6 +3,7 @@ prelude! {
 }
 
 /// GReact `sample` operator.
+#[derive(Clone)]
 pub struct Sample {
     pub sample_token: keyword::sample,
     pub paren_token: token::Paren,
@@ -30,6 +31,7 @@ mk_new! { impl Sample =>
 }
 
 /// GReact `scan` operator.
+#[derive(Clone)]
 pub struct Scan {
     pub scan_token: keyword::scan,
     pub paren_token: token::Paren,
@@ -57,6 +59,7 @@ mk_new! { impl Scan =>
 }
 
 /// GReact `timeout` operator.
+#[derive(Clone)]
 pub struct Timeout {
     pub timeout_token: keyword::timeout,
     pub paren_token: token::Paren,
@@ -84,6 +87,7 @@ mk_new! { impl Timeout =>
 }
 
 /// GReact `throttle` operator.
+#[derive(Clone)]
 pub struct Throttle {
     pub throttle_token: keyword::throttle,
     pub paren_token: token::Paren,
@@ -105,6 +109,7 @@ mk_new! { impl Throttle =>
 }
 
 /// GReact `on_change` operator.
+#[derive(Clone)]
 pub struct OnChange {
     pub on_change_token: keyword::on_change,
     pub paren_token: token::Paren,
@@ -121,6 +126,7 @@ mk_new! { impl OnChange =>
 }
 
 /// GReact `persist` operator.
+#[derive(Clone)]
 pub struct Persist {
     pub persist_token: keyword::persist,
     pub paren_token: token::Paren,
@@ -137,6 +143,7 @@ mk_new! { impl Persist =>
 }
 
 /// GReact `merge` operator.
+#[derive(Clone)]
 pub struct Merge {
     pub merge_token: keyword::merge,
     pub paren_token: token::Paren,
@@ -157,6 +164,7 @@ mk_new! { impl Merge =>
 }
 
 /// GReact `time` operator.
+#[derive(Clone)]
 pub struct Time {
     pub time_token: keyword::time,
     pub paren_token: token::Paren,
@@ -170,6 +178,7 @@ mk_new! { impl Time =>
 }
 
 /// GReact `period` operator.
+#[derive(Clone)]
 pub struct Period {
     pub period_token: keyword::period,
     pub paren_token: token::Paren,
@@ -190,6 +199,7 @@ mk_new! { impl Period =>
 }
 
 /// GReact `sample_on` operator.
+#[derive(Clone)]
 pub struct SampleOn {
     pub sample_on_token: keyword::sample_on,
     pub paren_token: token::Paren,
@@ -210,6 +220,7 @@ mk_new! { impl SampleOn =>
 }
 
 /// GReact `scan_on` operator.
+#[derive(Clone)]
 pub struct ScanOn {
     pub scan_on_token: keyword::scan_on,
     pub paren_token: token::Paren,
@@ -230,6 +241,7 @@ mk_new! { impl ScanOn =>
 }
 
 /// Call.
+#[derive(Clone)]
 pub struct Call {
     /// Identifier to the called component/function.
     pub ident: Ident,
@@ -239,6 +251,7 @@ pub struct Call {
 }
 
 /// Flow expression kinds.
+#[derive(Clone)]
 pub enum FlowExpression {
     /// GReact `sample` operator.
     Sample(Sample),
@@ -303,6 +316,7 @@ impl FlowKind {
     }
 }
 
+#[derive(Clone)]
 pub enum FlowPattern {
     Tuple {
         paren_token: token::Paren,
@@ -328,6 +342,7 @@ impl HasLoc for FlowPattern {
 }
 
 /// Flow statement AST.
+#[derive(Clone)]
 pub struct FlowDeclaration {
     pub let_token: Token![let],
     /// Pattern of declared flows and their type.
@@ -344,6 +359,7 @@ impl HasLoc for FlowDeclaration {
 }
 
 /// Flow statement AST.
+#[derive(Clone)]
 pub struct FlowInstantiation {
     /// Pattern of instantiated flows.
     pub pattern: FlowPattern,
@@ -358,13 +374,79 @@ impl HasLoc for FlowInstantiation {
     }
 }
 
+/// Minimal period between two values of a flow, `every 10 ms`.
+#[derive(Clone)]
+pub struct Rate {
+    pub every_token: keyword::every,
+    /// Period in milliseconds.
+    pub period_ms: Either<LitInt, Ident>,
+    pub ms_token: keyword::ms,
+}
+impl HasLoc for Rate {
+    fn loc(&self) -> Loc {
+        Loc::from(self.every_token.span).join(self.ms_token.span)
+    }
+}
+
+/// Response deadline of a service, `brakes within 50 ms after pedestrian`.
+#[derive(Clone)]
+pub struct Response {
+    /// Exported flow responding.
+    pub output: Ident,
+    pub within_token: keyword::within,
+    /// Deadline in milliseconds.
+    pub deadline_ms: Either<LitInt, Ident>,
+    pub ms_token: keyword::ms,
+    pub after_token: keyword::after,
+    /// Imported flow triggering the response.
+    pub input: Ident,
+}
+impl HasLoc for Response {
+    fn loc(&self) -> Loc {
+        self.output.loc().join(self.input.loc())
+    }
+}
+
+/// Specification of an interface clause.
+#[derive(Clone)]
+pub enum InterfaceSpec {
+    /// Predicate on the values of the flow.
+    Value(contract::Term),
+    /// Minimal period between two values of the flow.
+    Rate(Rate),
+    /// Response deadline of the service.
+    Response(Response),
+}
+impl HasLoc for InterfaceSpec {
+    fn loc(&self) -> Loc {
+        match self {
+            Self::Value(term) => term.loc(),
+            Self::Rate(rate) => rate.loc(),
+            Self::Response(response) => response.loc(),
+        }
+    }
+}
+
+/// Interface clause, checked at runtime by the service handlers.
+///
+/// Imports accept `requires` clauses, exports and services accept `ensures` clauses.
+#[derive(Clone)]
+pub struct InterfaceClause {
+    pub kind: contract::ClauseKind,
+    pub brace: token::Brace,
+    pub spec: InterfaceSpec,
+}
+
 /// Flow statement AST.
+#[derive(Clone)]
 pub struct FlowImport {
     pub import_token: keyword::import,
     /// Flow's kind.
     pub kind: FlowKind,
     /// Identifier of the flow and its type.
     pub typed_path: Colon<syn::Path, Typ>,
+    /// Contract on the imported values.
+    pub contract: Vec<InterfaceClause>,
     pub semi_token: Token![;],
 }
 impl HasLoc for FlowImport {
@@ -374,12 +456,15 @@ impl HasLoc for FlowImport {
 }
 
 /// Flow statement AST.
+#[derive(Clone)]
 pub struct FlowExport {
     pub export_token: keyword::export,
     /// Flow's kind.
     pub kind: FlowKind,
     /// Identifier of the flow and its type.
     pub typed_path: Colon<syn::Path, Typ>,
+    /// Contract on the exported values.
+    pub contract: Vec<InterfaceClause>,
     pub semi_token: Token![;],
 }
 impl HasLoc for FlowExport {
@@ -518,6 +603,7 @@ impl HasLoc for ExtFunDecl {
     }
 }
 
+#[derive(Clone)]
 pub enum FlowStatement {
     Declaration(FlowDeclaration),
     Instantiation(FlowInstantiation),
@@ -532,6 +618,7 @@ impl HasLoc for FlowStatement {
 }
 
 /// Service's time range.
+#[derive(Clone)]
 pub struct TimeRange {
     pub at_token: Token![@],
     pub bracket_token: token::Bracket,
@@ -540,13 +627,55 @@ pub struct TimeRange {
     pub max: Either<LitInt, Ident>,
 }
 
+/// Service's constant parameter, `const N: int`.
+#[derive(Clone)]
+pub struct ServiceParam {
+    pub const_token: Token![const],
+    /// Parameter's identifier and its type.
+    pub typed_ident: Colon<Ident, Typ>,
+}
+impl HasLoc for ServiceParam {
+    fn loc(&self) -> Loc {
+        Loc::from(self.const_token.span).join(self.typed_ident.left.span())
+    }
+}
+
+/// Service's instance, `left<10>`.
+#[derive(Clone)]
+pub struct ServiceInstance {
+    /// Instance identifier, prefixes the flows of the instance.
+    pub ident: Ident,
+    pub lt_token: Option<Token![<]>,
+    /// Values of the service's constant parameters.
+    pub args: Punctuated<Constant, Token![,]>,
+    pub gt_token: Option<Token![>]>,
+}
+impl HasLoc for ServiceInstance {
+    fn loc(&self) -> Loc {
+        match self.gt_token {
+            Some(gt_token) => self.ident.loc().join(gt_token.span),
+            None => self.ident.loc(),
+        }
+    }
+}
+
 /// GRust service AST.
+#[derive(Clone)]
 pub struct Service {
     pub service_token: keyword::service,
     /// Service identifier.
     pub ident: Ident,
+    pub lt_token: Option<Token![<]>,
+    /// Service's constant parameters.
+    pub params: Punctuated<ServiceParam, Token![,]>,
+    pub gt_token: Option<Token![>]>,
+    pub for_token: Option<Token![for]>,
+    /// Service's instances, the service is a singleton when empty.
+    pub instances: Punctuated<ServiceInstance, Token![,]>,
     /// Service's time range.
     pub time_range: Option<TimeRange>,
+    /// Service's response deadlines.
+    pub contract: Vec<InterfaceClause>,
     pub brace: token::Brace,
     /// Service's flow statements.
     pub flow_statements: Vec<FlowStatement>,
@@ -556,3 +685,320 @@ impl HasLoc for Service {
         Loc::from(self.service_token.span).join(self.brace.span.join())
     }
 }
+
+/// Identifier of `ident` in the service instance `instance`.
+fn instance_ident(instance: &Ident, ident: &Ident) -> Ident {
+    format_ident!("{}_{}", instance, ident, span = ident.span())
+}
+
+/// Prefixes a flow path with a service instance, `car::speed` becomes `left::car::left_speed`.
+fn instance_path(instance: &Ident, path: &syn::Path) -> syn::Path {
+    let mut path = path.clone();
+    if let Some(last) = path.segments.last_mut() {
+        last.ident = instance_ident(instance, &last.ident);
+    }
+    path.segments.insert(0, instance.clone().into());
+    path
+}
+
+/// Replaces a constant parameter by its value.
+fn instantiate_time(time: &mut Either<LitInt, Ident>, consts: &HashMap<Ident, LitInt>) {
+    if let Either::Right(ident) = time {
+        if let Some(lit) = consts.get(ident) {
+            *time = Either::Left(LitInt::new(&lit.to_string(), ident.span()));
+        }
+    }
+}
+
+impl FlowExpression {
+    /// Identifiers of the flows used by the expression.
+    fn used_flows<'a>(&'a self, flows: &mut Vec<&'a Ident>) {
+        match self {
+            Self::Ident(ident) => flows.push(ident),
+            Self::Sample(Sample { expr, .. })
+            | Self::Scan(Scan { expr, .. })
+            | Self::Timeout(Timeout { expr, .. })
+            | Self::Throttle(Throttle { expr, .. })
+            | Self::OnChange(OnChange { expr, .. })
+            | Self::Persist(Persist { expr, .. }) => expr.used_flows(flows),
+            Self::Merge(Merge { expr_1, expr_2, .. }) => {
+                expr_1.used_flows(flows);
+                expr_2.used_flows(flows)
+            }
+            Self::SampleOn(SampleOn { expr, event, .. })
+            | Self::ScanOn(ScanOn { expr, event, .. }) => {
+                expr.used_flows(flows);
+                event.used_flows(flows)
+            }
+            Self::Call(Call { inputs, .. }) => {
+                inputs.iter().for_each(|input| input.used_flows(flows))
+            }
+            Self::Time(_) | Self::Period(_) => (),
+        }
+    }
+
+    /// Renames flows and replaces constant parameters by their values.
+    fn instantiate(&mut self, flows: &HashMap<Ident, Ident>, consts: &HashMap<Ident, LitInt>) {
+        match self {
+            Self::Ident(ident) => {
+                if let Some(new_ident) = flows.get(ident) {
+                    *ident = new_ident.clone()
+                }
+            }
+            Self::Sample(Sample {
+                expr, period_ms, ..
+            })
+            | Self::Scan(Scan {
+                expr, period_ms, ..
+            }) => {
+                instantiate_time(period_ms, consts);
+                expr.instantiate(flows, consts)
+            }
+            Self::Timeout(Timeout { expr, deadline, .. }) => {
+                instantiate_time(deadline, consts);
+                expr.instantiate(flows, consts)
+            }
+            Self::Throttle(Throttle { expr, .. })
+            | Self::OnChange(OnChange { expr, .. })
+            | Self::Persist(Persist { expr, .. }) => expr.instantiate(flows, consts),
+            Self::Merge(Merge { expr_1, expr_2, .. }) => {
+                expr_1.instantiate(flows, consts);
+                expr_2.instantiate(flows, consts)
+            }
+            Self::SampleOn(SampleOn { expr, event, .. })
+            | Self::ScanOn(ScanOn { expr, event, .. }) => {
+                expr.instantiate(flows, consts);
+                event.instantiate(flows, consts)
+            }
+            Self::Call(Call { inputs, .. }) => inputs
+                .iter_mut()
+                .for_each(|input| input.instantiate(flows, consts)),
+            Self::Period(Period { period_ms, .. }) => instantiate_time(period_ms, consts),
+            Self::Time(_) => (),
+        }
+    }
+}
+
+impl FlowPattern {
+    /// Identifiers of the flows instantiated (not declared) by the pattern.
+    fn used_flows<'a>(&'a self, flows: &mut Vec<&'a Ident>) {
+        match self {
+            Self::Single { ident } => flows.push(ident),
+            Self::SingleTyped { .. } => (),
+            Self::Tuple { patterns, .. } => patterns
+                .iter()
+                .for_each(|pattern| pattern.used_flows(flows)),
+        }
+    }
+
+    /// Identifiers of the flows declared by the pattern.
+    fn declared_flows<'a>(&'a self, flows: &mut Vec<&'a Ident>) {
+        match self {
+            Self::Single { .. } => (),
+            Self::SingleTyped { ident, .. } => flows.push(ident),
+            Self::Tuple { patterns, .. } => patterns
+                .iter()
+                .for_each(|pattern| pattern.declared_flows(flows)),
+        }
+    }
+
+    /// Renames instantiated flows.
+    fn instantiate(&mut self, flows: &HashMap<Ident, Ident>) {
+        match self {
+            Self::Single { ident } => {
+                if let Some(new_ident) = flows.get(ident) {
+                    *ident = new_ident.clone()
+                }
+            }
+            Self::SingleTyped { .. } => (),
+            Self::Tuple { patterns, .. } => patterns
+                .iter_mut()
+                .for_each(|pattern| pattern.instantiate(flows)),
+        }
+    }
+}
+
+impl FlowStatement {
+    fn pattern(&self) -> &FlowPattern {
+        match self {
+            Self::Declaration(FlowDeclaration { typed_pattern, .. }) => typed_pattern,
+            Self::Instantiation(FlowInstantiation { pattern, .. }) => pattern,
+        }
+    }
+    fn expr(&self) -> &FlowExpression {
+        match self {
+            Self::Declaration(FlowDeclaration { expr, .. })
+            | Self::Instantiation(FlowInstantiation { expr, .. }) => expr,
+        }
+    }
+}
+
+impl Service {
+    /// Tells if the service is instantiated several times.
+    pub fn is_multi_instance(&self) -> bool {
+        self.for_token.is_some()
+    }
+
+    /// Identifiers of the imported/exported flows used by the service.
+    ///
+    /// Flows declared inside the service are local and ignored.
+    pub fn global_flows(&self, interface: &[Ident]) -> Vec<&Ident> {
+        let mut declared = vec![];
+        let mut used = vec![];
+        for statement in self.flow_statements.iter() {
+            statement.pattern().declared_flows(&mut declared);
+            statement.pattern().used_flows(&mut used);
+            statement.expr().used_flows(&mut used);
+        }
+        let mut globals: Vec<&Ident> = vec![];
+        for ident in used {
+            if !declared.contains(&ident) && !globals.contains(&ident) && interface.contains(ident)
+            {
+                globals.push(ident)
+            }
+        }
+        globals
+    }
+
+    /// Checks the arguments of an instance against the service's parameters.
+    fn instance_consts(
+        &self,
+        instance: &ServiceInstance,
+        errors: &mut Vec<Error>,
+    ) -> TRes<HashMap<Ident, LitInt>> {
+        if instance.args.len() != self.params.len() {
+            bad!(errors, @instance.loc() =>
+                ErrorKind::arity_mismatch(instance.args.len(), self.params.len())
+            )
+        }
+        let mut consts = HashMap::with_capacity(self.params.len());
+        for (param, arg) in self.params.iter().zip(instance.args.iter()) {
+            match (&param.typed_ident.right, arg) {
+                (Typ::Integer(_), Constant::Integer(lit)) => {
+                    let _ = consts.insert(param.typed_ident.left.clone(), lit.clone());
+                }
+                (Typ::Integer(_), _) => {
+                    bad!(errors, @instance.loc() =>
+                        ErrorKind::incompatible_types(arg.get_typ(), param.typed_ident.right.clone())
+                    )
+                }
+                _ => bad!(errors, @param.loc() =>
+                    ErrorKind::msg("service parameters must be integers")
+                ),
+            }
+        }
+        Ok(consts)
+    }
+
+    /// Creates the service `instance`.
+    ///
+    /// The instance is a service named `<instance>_<service>`, using the flows `<instance>_<flow>`
+    /// instead of the imported/exported flows `<flow>`, and where the constant parameters are
+    /// replaced by the arguments of the instance.
+    pub fn instantiate(
+        &self,
+        instance: &ServiceInstance,
+        interface: &[Ident],
+        errors: &mut Vec<Error>,
+    ) -> TRes<Service> {
+        let consts = self.instance_consts(instance, errors)?;
+        let flows = self
+            .global_flows(interface)
+            .into_iter()
+            .map(|ident| (ident.clone(), instance_ident(&instance.ident, ident)))
+            .collect::<HashMap<_, _>>();
+
+        let mut service = self.clone();
+        service.ident = instance_ident(&instance.ident, &self.ident);
+        service.lt_token = None;
+        service.params = Punctuated::new();
+        service.gt_token = None;
+        service.for_token = None;
+        service.instances = Punctuated::new();
+        if let Some(TimeRange { min, max, .. }) = service.time_range.as_mut() {
+            instantiate_time(min, &consts);
+            instantiate_time(max, &consts);
+        }
+        for statement in service.flow_statements.iter_mut() {
+            match statement {
+                FlowStatement::Declaration(FlowDeclaration { expr, .. }) => {
+                    expr.instantiate(&flows, &consts)
+                }
+                FlowStatement::Instantiation(FlowInstantiation { pattern, expr, .. }) => {
+                    pattern.instantiate(&flows);
+                    expr.instantiate(&flows, &consts)
+                }
+            }
+        }
+        for clause in service.contract.iter_mut() {
+            if let InterfaceSpec::Response(Response {
+                output,
+                deadline_ms,
+                input,
+                ..
+            }) = &mut clause.spec
+            {
+                instantiate_time(deadline_ms, &consts);
+                for ident in [output, input] {
+                    if interface.contains(ident) {
+                        *ident = instance_ident(&instance.ident, ident)
+                    }
+                }
+            }
+        }
+        Ok(service)
+    }
+}
+
+/// Renames the constrained flow `old` into `new` in the value predicates of the clauses.
+fn rename_contract_flow(contract: &mut [InterfaceClause], old: &Ident, new: &Ident) {
+    for clause in contract.iter_mut() {
+        if let InterfaceSpec::Value(term) = &mut clause.spec {
+            term.rename_ident(old, new)
+        }
+    }
+}
+
+impl FlowImport {
+    /// Copy of the import for the service instance `instance`.
+    pub fn instantiate(&self, instance: &Ident) -> Self {
+        let mut import = self.clone();
+        import.typed_path.left = instance_path(instance, &self.typed_path.left);
+        let new = import.ident().clone();
+        rename_contract_flow(&mut import.contract, self.ident(), &new);
+        import
+    }
+
+    /// Identifier of the imported flow.
+    pub fn ident(&self) -> &Ident {
+        &self
+            .typed_path
+            .left
+            .segments
+            .last()
+            .expect("internal error: empty path")
+            .ident
+    }
+}
+
+impl FlowExport {
+    /// Copy of the export for the service instance `instance`.
+    pub fn instantiate(&self, instance: &Ident) -> Self {
+        let mut export = self.clone();
+        export.typed_path.left = instance_path(instance, &self.typed_path.left);
+        let new = export.ident().clone();
+        rename_contract_flow(&mut export.contract, self.ident(), &new);
+        export
+    }
+
+    /// Identifier of the exported flow.
+    pub fn ident(&self) -> &Ident {
+        &self
+            .typed_path
+            .left
+            .segments
+            .last()
+            .expect("internal error: empty path")
+            .ident
+    }
+}
@@ -0,0 +1,72 @@
+//! Test scenarios of components.
+//!
+//! ```grust
+//! test braking_nominal for braking_state {
+//!     step { pedest = some(10.), speed = 30. }
+//!     expect { state = Braking::SoftBrake }
+//!     step { speed = 30. }
+//!     expect { state = Braking::SoftBrake }
+//! }
+//! ```
+
+prelude! {
+    syn::{Punctuated, token},
+}
+
+/// Value of an input or an output in a step.
+pub enum Value {
+    /// Present event, `some(e)`.
+    Some {
+        some_token: keyword::some,
+        paren_token: token::Paren,
+        expr: Expr,
+    },
+    /// Absent event, `none`.
+    None { none_token: keyword::none },
+    /// Signal value.
+    Expr(Expr),
+}
+impl HasLoc for Value {
+    fn loc(&self) -> Loc {
+        match self {
+            Self::Some {
+                some_token,
+                paren_token,
+                ..
+            } => Loc::from(some_token.span).join(paren_token.span.join()),
+            Self::None { none_token } => none_token.span.into(),
+            Self::Expr(expr) => expr.loc(),
+        }
+    }
+}
+
+/// Assignment of a value to an input or an output, `speed = 30.`.
+pub struct Assign {
+    /// The input or output.
+    pub ident: Ident,
+    pub eq_token: Token![=],
+    /// Its value.
+    pub value: Value,
+}
+
+/// A step of a scenario, its inputs and the outputs expected.
+///
+/// Absent events can be omitted, as well as signals keeping their value of the previous step.
+/// Outputs not in the `expect` block are not checked.
+pub struct Step {
+    pub step_token: keyword::step,
+    pub step_brace: token::Brace,
+    /// Inputs of the step.
+    pub inputs: Punctuated<Assign, Token![,]>,
+    /// Expected outputs, if any.
+    pub expect: Option<(keyword::expect, token::Brace, Punctuated<Assign, Token![,]>)>,
+}
+impl HasLoc for Step {
+    fn loc(&self) -> Loc {
+        let end = match &self.expect {
+            Some((_, brace, _)) => brace.span.join(),
+            None => self.step_brace.span.join(),
+        };
+        Loc::from(self.step_token.span).join(end)
+    }
+}
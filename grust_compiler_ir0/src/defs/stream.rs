@@ -101,6 +101,31 @@ mk_new! { impl Emit =>
     }
 }
 
+/// Component application reset by a condition: `counter(res, tick) every r`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Every {
+    /// Location.
+    pub loc: Loc,
+    /// The component application.
+    pub app: Application<Expr>,
+    pub every_token: keyword::every,
+    /// The reset condition.
+    pub reset: Box<Expr>,
+}
+impl HasLoc for Every {
+    fn loc(&self) -> Loc {
+        self.loc
+    }
+}
+mk_new! { impl Every =>
+    new {
+        loc: impl Into<Loc> = loc.into(),
+        app: Application<Expr>,
+        every_token: keyword::every,
+        reset: impl Into<Box<Expr >> = reset.into(),
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 /// GRust stream expression kind AST.
 pub enum Expr {
@@ -146,6 +171,8 @@ pub enum Expr {
     Last(Last),
     /// Emit event.
     Emit(Emit),
+    /// Component application with reset.
+    Every(Every),
 }
 mk_new! { impl Expr =>
     Constant: cst(arg: Constant = arg)
@@ -170,6 +197,7 @@ mk_new! { impl Expr =>
     Zip: zip(arg: Zip<Self> = arg)
     Last: last(arg: Last = arg)
     Emit: emit(arg: Emit = arg)
+    Every: every(arg: Every = arg)
 }
 
 impl HasLoc for Expr {
@@ -200,6 +228,7 @@ impl HasLoc for Expr {
             Zip(z) => z.loc(),
             Last(l) => l.loc(),
             Emit(e) => e.loc(),
+            Every(e) => e.loc(),
         }
     }
 }
@@ -218,7 +247,13 @@ impl TryFrom<ir0::Expr> for Expr {
             ir0::Expr::BinOp(bin_op) => {
                 let lft: Expr = (*bin_op.lft).try_into()?;
                 let rgt: Expr = (*bin_op.rgt).try_into()?;
-                Ok(Self::binop(BinOp::new(bin_op.op, bin_op.op_loc, lft, rgt)))
+                Ok(Self::binop(BinOp::with_arith(
+                    bin_op.op,
+                    bin_op.op_loc,
+                    bin_op.arith,
+                    lft,
+                    rgt,
+                )))
             }
             ir0::Expr::IfThenElse(if_then_else) => {
                 let cnd: Expr = (*if_then_else.cnd).try_into()?;
@@ -233,7 +268,12 @@ impl TryFrom<ir0::Expr> for Expr {
                     .into_iter()
                     .map(|expr| -> Res<_> { expr.try_into() })
                     .collect::<Res<_>>()?;
-                Ok(Self::app(Application::new(application.loc, fun, inputs)))
+                Ok(Self::app(Application::new_static(
+                    application.loc,
+                    fun,
+                    application.static_args,
+                    inputs,
+                )))
             }
             ir0::Expr::Lambda(lambda) => Ok(Self::type_lambda(Lambda::new(
                 lambda.loc,
@@ -354,6 +394,7 @@ impl Expr {
             stream::Expr::Lambda { .. }
             | stream::Expr::MatchExpr { .. }
             | stream::Expr::Emit { .. }
+            | stream::Expr::Every { .. }
             | stream::Expr::FieldAccess { .. }
             | stream::Expr::TupleElementAccess { .. }
             | stream::Expr::ArrayAccess { .. }
@@ -369,8 +410,8 @@ impl Expr {
             stream::Expr::Identifier(ident) => {
                 // check id exists
                 let id = table.get_ident(ident, false, true, errors)?;
-                // check it is a function or and operator
-                if table.is_function(id) {
+                // check it is a function, an operator or a static parameter
+                if table.is_function(id) || table.is_static_param(id) {
                     Ok(())
                 } else {
                     bad!(errors, @ident.span() => ErrorKind::expected_constant())
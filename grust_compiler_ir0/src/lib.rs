@@ -5,8 +5,10 @@ pub extern crate grust_compiler_common as common;
 pub mod prelude;
 
 mod defs;
+mod manifest;
 mod parsing;
 pub mod symbol;
+mod temporal;
 
 pub mod import {
     pub use crate::prelude::ir0;
@@ -0,0 +1,296 @@
+//! Manifest of the GRust standard library.
+//!
+//! An import `use std::maths::*;` brings the declarations of all the standard library items under
+//! `std::maths`, as if the corresponding `use function`/`use component` items were written by
+//! hand. Single items are imported by their path `use std::control::pid;`, and several paths can
+//! be grouped `use std::{logic::ton, filters::*};`. Note that `*` imports recursively all the
+//! items under a path.
+//!
+//! Items whose signature depends on the size of their arrays (lookup tables, vector operations
+//! over `[float; N]`...) are not part of the manifest and must be declared explicitly.
+
+prelude! {
+    syn::Parse,
+}
+
+/// Signatures of the standard library items, type-checked as any external declaration.
+const MANIFEST: &str = "
+    use function grust::std::maths::trigo::cos(x: float) -> float;
+    use function grust::std::maths::trigo::sin(x: float) -> float;
+    use function grust::std::maths::trigo::tan(x: float) -> float;
+    use function grust::std::maths::trigo::acos(x: float) -> float;
+    use function grust::std::maths::trigo::asin(x: float) -> float;
+    use function grust::std::maths::trigo::atan(x: float) -> float;
+    use function grust::std::maths::trigo::atan2(y: float, x: float) -> float;
+    use function grust::std::maths::trigo::cosh(x: float) -> float;
+    use function grust::std::maths::trigo::sinh(x: float) -> float;
+    use function grust::std::maths::trigo::tanh(x: float) -> float;
+    use function grust::std::maths::trigo::acosh(x: float) -> float;
+    use function grust::std::maths::trigo::asinh(x: float) -> float;
+    use function grust::std::maths::trigo::atanh(x: float) -> float;
+
+    use function grust::std::maths::round::round(x: float) -> int;
+    use function grust::std::maths::round::floor(x: float) -> int;
+    use function grust::std::maths::round::ceil(x: float) -> int;
+
+    use function grust::std::maths::exponential::exp(x: float) -> float;
+    use function grust::std::maths::exponential::ln(x: float) -> float;
+    use function grust::std::maths::exponential::log(x: float, n: float) -> float;
+    use function grust::std::maths::exponential::log10(x: float) -> float;
+    use function grust::std::maths::exponential::log2(x: float) -> float;
+
+    use function grust::std::maths::usuals::min(x: float, y: float) -> float;
+    use function grust::std::maths::usuals::min_i(x: int, y: int) -> int;
+    use function grust::std::maths::usuals::max(x: float, y: float) -> float;
+    use function grust::std::maths::usuals::max_i(x: int, y: int) -> int;
+    use function grust::std::maths::usuals::abs(x: float) -> float;
+    use function grust::std::maths::usuals::abs_i(x: int) -> int;
+    use function grust::std::maths::usuals::pow(x: float, n: float) -> float;
+    use function grust::std::maths::usuals::pow_i(x: int, n: int) -> int;
+    use function grust::std::maths::usuals::sqrt(x: float) -> float;
+    use function grust::std::maths::usuals::sqrt_i(x: int) -> float;
+
+    use function grust::std::maths::vector::cross(u: [float; 3], v: [float; 3]) -> [float; 3];
+
+    use function grust::std::maths::matrix::det2(a: [[float; 2]; 2]) -> float;
+    use function grust::std::maths::matrix::det3(a: [[float; 3]; 3]) -> float;
+    use function grust::std::maths::matrix::inverse2(a: [[float; 2]; 2]) -> [[float; 2]; 2];
+    use function grust::std::maths::matrix::inverse3(a: [[float; 3]; 3]) -> [[float; 3]; 3];
+
+    use function grust::std::maths::quaternion::mul(p: [float; 4], q: [float; 4]) -> [float; 4];
+    use function grust::std::maths::quaternion::conjugate(q: [float; 4]) -> [float; 4];
+    use function grust::std::maths::quaternion::normalize(q: [float; 4]) -> [float; 4];
+    use function grust::std::maths::quaternion::from_axis_angle(
+        axis: [float; 3], angle: float,
+    ) -> [float; 4];
+    use function grust::std::maths::quaternion::rotate(q: [float; 4], v: [float; 3]) -> [float; 3];
+    use function grust::std::maths::quaternion::to_matrix(q: [float; 4]) -> [[float; 3]; 3];
+
+    use component grust::std::time::integration::backward_euler(x: float, t: float) -> (i: float);
+    use component grust::std::time::integration::trapeze(x: float, t: float) -> (i: float);
+    use component grust::std::time::derivation::derive(x: float, t: float) -> (d: float);
+
+    use component grust::std::control::pid(
+        e: float, t: float, kp: float, ki: float, kd: float, tf: float, u_min: float, u_max: float,
+    ) -> (u: float);
+    use component grust::std::control::saturation(
+        x: float, lo: float, hi: float, h: float,
+    ) -> (y: float, sat: bool);
+    use component grust::std::control::rate_limiter(
+        x: float, t: float, rising: float, falling: float,
+    ) -> (y: float);
+    use component grust::std::control::bumpless_transfer(
+        auto_u: float, manual_u: float, manual: bool, t: float, tau: float,
+    ) -> (u: float);
+
+    use component grust::std::filters::low_pass(x: float, t: float, tau: float) -> (y: float);
+    use component grust::std::filters::high_pass(x: float, t: float, tau: float) -> (y: float);
+    use component grust::std::filters::low_pass2(
+        x: float, t: float, w: float, zeta: float,
+    ) -> (y: float);
+    use component grust::std::filters::high_pass2(
+        x: float, t: float, w: float, zeta: float,
+    ) -> (y: float);
+    use component grust::std::filters::moving_average(x: float, t: float, n: int) -> (y: float);
+    use component grust::std::filters::median(x: float, t: float, n: int) -> (y: float);
+    use component grust::std::filters::kalman(
+        z: float, t: float, q: float, r: float,
+    ) -> (est: float, var: float);
+
+    use component grust::std::logic::rising_edge(x: bool) -> (y: bool);
+    use component grust::std::logic::falling_edge(x: bool) -> (y: bool);
+    use component grust::std::logic::any_edge(x: bool) -> (y: bool);
+    use component grust::std::logic::sr(s: bool, r: bool) -> (q: bool);
+    use component grust::std::logic::rs(s: bool, r: bool) -> (q: bool);
+    use component grust::std::logic::ton(x: bool, pt: float, t: float) -> (q: bool, et: float);
+    use component grust::std::logic::tof(x: bool, pt: float, t: float) -> (q: bool, et: float);
+    use component grust::std::logic::tp(x: bool, pt: float, t: float) -> (q: bool, et: float);
+    use component grust::std::logic::ctu(cu: bool, reset: bool, pv: int) -> (q: bool, cv: int);
+    use component grust::std::logic::ctd(cd: bool, load: bool, pv: int) -> (q: bool, cv: int);
+    use component grust::std::logic::ctud(
+        cu: bool, cd: bool, reset: bool, load: bool, pv: int,
+    ) -> (qu: bool, qd: bool, cv: int);
+
+    use component grust::std::generators::step(
+        t: float, delay: float, initial: float, last: float,
+    ) -> (y: float);
+    use component grust::std::generators::ramp(
+        t: float, delay: float, slope: float, initial: float,
+    ) -> (y: float);
+    use component grust::std::generators::sine(
+        t: float, amplitude: float, frequency: float, phase: float, offset: float,
+    ) -> (y: float);
+    use component grust::std::generators::square(
+        t: float, amplitude: float, period: float, duty: float, offset: float,
+    ) -> (y: float);
+    use component grust::std::generators::chirp(
+        t: float, amplitude: float, f0: float, f1: float, duration: float,
+    ) -> (y: float);
+    use component grust::std::generators::noise(t: float, seed: int, amplitude: float) -> (y: float);
+";
+
+/// Path prefix of the standard library items in the manifest.
+const STD_PREFIX: [&str; 2] = ["grust", "std"];
+
+/// Standard library import, `use std::maths::*;`.
+pub struct StdImport {
+    /// The imported tree, rooted at `std`.
+    pub tree: syn::UseTree,
+}
+impl StdImport {
+    pub fn peek(input: ParseStream) -> bool {
+        let fork = input.fork();
+        fork.parse::<Token![use]>().is_ok() && fork.parse::<Ident>().is_ok_and(|id| id == "std")
+    }
+
+    /// Paths imported by the tree, with a flag indicating glob imports.
+    fn paths(&self) -> syn::Res<Vec<(Vec<String>, bool, Span)>> {
+        fn flatten(
+            tree: &syn::UseTree,
+            prefix: &mut Vec<String>,
+            paths: &mut Vec<(Vec<String>, bool, Span)>,
+        ) -> syn::Res<()> {
+            match tree {
+                syn::UseTree::Path(path) => {
+                    prefix.push(path.ident.to_string());
+                    flatten(&path.tree, prefix, paths)?;
+                    prefix.pop();
+                }
+                syn::UseTree::Name(name) => {
+                    let mut path = prefix.clone();
+                    path.push(name.ident.to_string());
+                    paths.push((path, false, name.ident.span()));
+                }
+                syn::UseTree::Glob(glob) => {
+                    paths.push((prefix.clone(), true, glob.star_token.span));
+                }
+                syn::UseTree::Group(group) => {
+                    for tree in group.items.iter() {
+                        flatten(tree, prefix, paths)?
+                    }
+                }
+                syn::UseTree::Rename(rename) => {
+                    return Err(syn::Error::new_spanned(
+                        rename,
+                        "renaming standard library items is not supported",
+                    ))
+                }
+            }
+            Ok(())
+        }
+        let mut paths = vec![];
+        flatten(&self.tree, &mut vec![], &mut paths)?;
+        Ok(paths)
+    }
+
+    /// External declarations of the imported items.
+    ///
+    /// Items already in `imported` are skipped, newly imported ones are added to it.
+    pub fn into_items(self, imported: &mut BTreeSet<String>) -> syn::Res<Vec<Item>> {
+        let manifest = syn::parse::Parser::parse_str(
+            |input: ParseStream| {
+                let mut items = vec![];
+                while !input.is_empty() {
+                    items.push(input.parse::<Item>()?);
+                }
+                Ok(items)
+            },
+            MANIFEST,
+        )?;
+        let std_path = |item: &Item| {
+            let path = match item {
+                Item::ExtFun(fun) => &fun.path,
+                Item::ExtComp(comp) => &comp.path,
+                _ => unreachable!("the manifest only contains external declarations"),
+            };
+            let mut segments = path.segments.iter().map(|seg| seg.ident.to_string());
+            debug_assert!(segments.by_ref().take(2).eq(STD_PREFIX));
+            // `std` root, as in imports
+            std::iter::once("std".to_string())
+                .chain(segments)
+                .collect::<Vec<_>>()
+        };
+
+        let mut manifest = manifest
+            .into_iter()
+            .map(|item| (std_path(&item), Some(item)))
+            .collect::<Vec<_>>();
+        let mut items = vec![];
+        for (path, glob, span) in self.paths()? {
+            let mut found = false;
+            for (item_path, item) in manifest.iter_mut() {
+                let is_imported = if glob {
+                    item_path.starts_with(&path)
+                } else {
+                    *item_path == path
+                };
+                if is_imported {
+                    found = true;
+                    if imported.insert(item_path.join("::")) {
+                        items.extend(item.take())
+                    }
+                }
+            }
+            if !found {
+                let glob = if glob { "::*" } else { "" };
+                let msg = format!(
+                    "no item `{}{glob}` in the GRust standard library",
+                    path.join("::"),
+                );
+                return Err(syn::Error::new(span, msg));
+            }
+        }
+        Ok(items)
+    }
+}
+impl Parse for StdImport {
+    fn parse(input: ParseStream) -> syn::Res<Self> {
+        let item: syn::ItemUse = input.parse()?;
+        if let Some(attr) = item.attrs.first() {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "standard library imports do not accept attributes",
+            ));
+        }
+        Ok(Self { tree: item.tree })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    prelude! {}
+    use super::StdImport;
+
+    fn import(tokens: TokenStream2) -> syn::Res<Vec<String>> {
+        let import: StdImport = syn::parse2(tokens)?;
+        let items = import.into_items(&mut BTreeSet::new())?;
+        Ok(items
+            .into_iter()
+            .map(|item| match item {
+                Item::ExtFun(fun) => fun.ident.to_string(),
+                Item::ExtComp(comp) => comp.ident.to_string(),
+                _ => unreachable!(),
+            })
+            .collect())
+    }
+
+    #[test]
+    fn should_import_std_items() {
+        let trigo = import(quote! { use std::maths::trigo::*; }).unwrap();
+        assert_eq!(trigo.len(), 13);
+        assert!(trigo.contains(&"atan2".to_string()));
+
+        let maths = import(quote! { use std::maths::*; }).unwrap();
+        assert!(maths.contains(&"cos".to_string()) && maths.contains(&"inverse3".to_string()));
+
+        let items = import(quote! { use std::{control::pid, logic::{ton, tof}}; }).unwrap();
+        assert_eq!(items, vec!["pid", "ton", "tof"]);
+    }
+
+    #[test]
+    fn should_reject_unknown_std_items() {
+        assert!(import(quote! { use std::maths::trigo::cosine; }).is_err());
+        assert!(import(quote! { use std::lut::*; }).is_err());
+        assert!(import(quote! { use std::control::pid as controller; }).is_err());
+    }
+}
@@ -4,6 +4,8 @@ prelude! {
     syn::{Parse, Punctuated, token, LitInt, Res},
 }
 
+use crate::manifest::StdImport;
+
 impl<U: Parse, V: Parse> Parse for Colon<U, V> {
     fn parse(input: ParseStream) -> syn::Res<Self> {
         Ok(Self {
@@ -26,7 +28,7 @@ impl Parse for Item {
             };
         }
         if ConstDecl::peek(input) {
-            Ok(Item::Const(input.parse()?))
+            Ok(Item::Const(ConstDecl::parse_item(input, attrs)?))
         } else if Component::peek(input) {
             Ok(Item::Component(Component::parse_item(input, attrs)?))
         } else if Function::peek(input) {
@@ -47,10 +49,13 @@ impl Parse for Item {
             Ok(Item::ExtFun(ExtFunDecl::parse_item(input, attrs)?))
         } else if ExtCompDecl::peek(input) {
             Ok(Item::ExtComp(ExtCompDecl::parse_item(input, attrs)?))
+        } else if Scenario::peek(input) {
+            no_attrs!();
+            Ok(Item::Test(input.parse()?))
         } else {
             Err(input.error(
                 "expected either a flow import/export, a type, a component or function \
-                definition/import, or a service definition",
+                definition/import, a service definition, or a test scenario",
             ))
         }
     }
@@ -60,8 +65,15 @@ impl Parse for Ast {
     fn parse(input: ParseStream) -> Res<Self> {
         let items: Vec<Item> = {
             let mut items = Vec::with_capacity(100);
+            // standard library items already imported
+            let mut std_imported = BTreeSet::new();
             while !input.is_empty() {
-                items.push(input.parse()?);
+                if StdImport::peek(input) {
+                    let import: StdImport = input.parse()?;
+                    items.extend(import.into_items(&mut std_imported)?);
+                } else {
+                    items.push(input.parse()?);
+                }
             }
             items.shrink_to_fit();
             items
@@ -92,7 +104,7 @@ where
 mod interface {
     use super::*;
     prelude! { just
-        interface::*, ParseItem
+        interface::*, ParseItem, contract::ClauseKind,
     }
 
     impl Sample {
@@ -237,6 +249,7 @@ mod interface {
         fn parse(input: ParseStream) -> syn::Res<Self> {
             let function_token: keyword::function = input.parse()?;
             let ident: Ident = input.parse()?;
+            let (static_params, typ_params) = parse_params(input)?;
             let content;
             let args_paren: token::Paren = parenthesized!(content in input);
             let args: Punctuated<Colon<Ident, Typ>, Token![,]> =
@@ -256,6 +269,8 @@ mod interface {
             Ok(Function {
                 function_token,
                 ident,
+                static_params,
+                typ_params,
                 args_paren,
                 args,
                 arrow_token,
@@ -561,6 +576,88 @@ mod interface {
         }
     }
 
+    impl Parse for Rate {
+        fn parse(input: ParseStream) -> syn::Res<Self> {
+            let every_token: keyword::every = input.parse()?;
+            let period_ms: Either<LitInt, Ident> = if input.peek(LitInt) {
+                Either::Left(input.parse()?)
+            } else {
+                Either::Right(input.parse()?)
+            };
+            let ms_token: keyword::ms = input.parse()?;
+            Ok(Rate {
+                every_token,
+                period_ms,
+                ms_token,
+            })
+        }
+    }
+
+    impl Parse for Response {
+        fn parse(input: ParseStream) -> syn::Res<Self> {
+            let output: Ident = input.parse()?;
+            let within_token: keyword::within = input.parse()?;
+            let deadline_ms: Either<LitInt, Ident> = if input.peek(LitInt) {
+                Either::Left(input.parse()?)
+            } else {
+                Either::Right(input.parse()?)
+            };
+            let ms_token: keyword::ms = input.parse()?;
+            let after_token: keyword::after = input.parse()?;
+            let input_flow: Ident = input.parse()?;
+            Ok(Response {
+                output,
+                within_token,
+                deadline_ms,
+                ms_token,
+                after_token,
+                input: input_flow,
+            })
+        }
+    }
+
+    impl Parse for InterfaceSpec {
+        fn parse(input: ParseStream) -> syn::Res<Self> {
+            if input.peek(keyword::every) {
+                Ok(InterfaceSpec::Rate(input.parse()?))
+            } else if input.peek(Ident) && input.peek2(keyword::within) {
+                Ok(InterfaceSpec::Response(input.parse()?))
+            } else {
+                Ok(InterfaceSpec::Value(input.parse()?))
+            }
+        }
+    }
+
+    impl InterfaceClause {
+        /// Parses the `requires` and `ensures` clauses of an import, export or service.
+        pub fn parse_contract(input: ParseStream) -> syn::Res<Vec<Self>> {
+            let mut contract = vec![];
+            while input.peek(keyword::requires) || input.peek(keyword::ensures) {
+                contract.push(input.parse()?)
+            }
+            Ok(contract)
+        }
+    }
+    impl Parse for InterfaceClause {
+        fn parse(input: ParseStream) -> syn::Res<Self> {
+            let kind = if input.peek(keyword::requires) {
+                ClauseKind::Requires(input.parse()?)
+            } else if input.peek(keyword::ensures) {
+                ClauseKind::Ensures(input.parse()?)
+            } else {
+                return Err(input.error("expected 'requires' or 'ensures'"));
+            };
+            let content;
+            let brace = braced!(content in input);
+            let spec = content.parse()?;
+            if content.is_empty() {
+                Ok(InterfaceClause { kind, brace, spec })
+            } else {
+                Err(content.error("expected end of clause"))
+            }
+        }
+    }
+
     impl FlowImport {
         pub fn peek(input: ParseStream) -> bool {
             input.peek(keyword::import)
@@ -571,11 +668,13 @@ mod interface {
             let import_token: keyword::import = input.parse()?;
             let kind: FlowKind = input.parse()?;
             let typed_path: Colon<syn::Path, Typ> = input.parse()?;
+            let contract = InterfaceClause::parse_contract(input)?;
             let semi_token: Token![;] = input.parse()?;
             Ok(FlowImport {
                 import_token,
                 kind,
                 typed_path,
+                contract,
                 semi_token,
             })
         }
@@ -591,11 +690,13 @@ mod interface {
             let export_token: keyword::export = input.parse()?;
             let kind: FlowKind = input.parse()?;
             let typed_path: Colon<syn::Path, Typ> = input.parse()?;
+            let contract = InterfaceClause::parse_contract(input)?;
             let semi_token: Token![;] = input.parse()?;
             Ok(FlowExport {
                 export_token,
                 kind,
                 typed_path,
+                contract,
                 semi_token,
             })
         }
@@ -773,6 +874,44 @@ mod interface {
         }
     }
 
+    impl Parse for ServiceParam {
+        fn parse(input: ParseStream) -> syn::Res<Self> {
+            let const_token: Token![const] = input.parse()?;
+            let typed_ident: Colon<Ident, Typ> = input.parse()?;
+            Ok(ServiceParam {
+                const_token,
+                typed_ident,
+            })
+        }
+    }
+
+    impl Parse for ServiceInstance {
+        fn parse(input: ParseStream) -> syn::Res<Self> {
+            let ident: Ident = input.parse()?;
+            let (lt_token, args, gt_token) = if input.peek(Token![<]) {
+                let lt_token: Token![<] = input.parse()?;
+                let mut args: Punctuated<Constant, Token![,]> = Punctuated::new();
+                while !input.peek(Token![>]) {
+                    args.push_value(input.parse()?);
+                    if input.peek(Token![>]) {
+                        break;
+                    }
+                    args.push_punct(input.parse()?);
+                }
+                let gt_token: Token![>] = input.parse()?;
+                (Some(lt_token), args, Some(gt_token))
+            } else {
+                (None, Punctuated::new(), None)
+            };
+            Ok(ServiceInstance {
+                ident,
+                lt_token,
+                args,
+                gt_token,
+            })
+        }
+    }
+
     impl Service {
         pub fn peek(input: ParseStream) -> bool {
             input.peek(keyword::service)
@@ -782,11 +921,35 @@ mod interface {
         fn parse(input: ParseStream) -> syn::Res<Self> {
             let service_token: keyword::service = input.parse()?;
             let ident: Ident = input.parse()?;
+            let (lt_token, params, gt_token) = if input.peek(Token![<]) {
+                let lt_token: Token![<] = input.parse()?;
+                let mut params: Punctuated<ServiceParam, Token![,]> = Punctuated::new();
+                while !input.peek(Token![>]) {
+                    params.push_value(input.parse()?);
+                    if input.peek(Token![>]) {
+                        break;
+                    }
+                    params.push_punct(input.parse()?);
+                }
+                let gt_token: Token![>] = input.parse()?;
+                (Some(lt_token), params, Some(gt_token))
+            } else {
+                (None, Punctuated::new(), None)
+            };
+            let (for_token, instances) = if input.peek(Token![for]) {
+                let for_token: Token![for] = input.parse()?;
+                let instances: Punctuated<ServiceInstance, Token![,]> =
+                    Punctuated::parse_separated_nonempty(input)?;
+                (Some(for_token), instances)
+            } else {
+                (None, Punctuated::new())
+            };
             let time_range = if TimeRange::peek(input) {
                 Some(input.parse()?)
             } else {
                 None
             };
+            let contract = InterfaceClause::parse_contract(input)?;
             let content;
             let brace: token::Brace = syn::braced!(content in input);
             let flow_statements: Vec<FlowStatement> = {
@@ -799,7 +962,13 @@ mod interface {
             Ok(Service {
                 service_token,
                 ident,
+                lt_token,
+                params,
+                gt_token,
+                for_token,
+                instances,
                 time_range,
+                contract,
                 brace,
                 flow_statements,
             })
@@ -807,6 +976,43 @@ mod interface {
     }
 }
 
+/// Static parameters and type parameters of a component or function.
+type Params = (Vec<(Ident, Typ)>, Vec<Typ>);
+
+/// Parses the optional static and type parameters `<const N: int, T: ord>` of a component or
+/// function.
+fn parse_params(input: ParseStream) -> Res<Params> {
+    let mut static_params = vec![];
+    let mut typ_params = vec![];
+    if input.peek(Token![<]) {
+        let _: Token![<] = input.parse()?;
+        while !input.peek(Token![>]) {
+            if input.peek(Token![const]) {
+                let _: Token![const] = input.parse()?;
+                let ident: Ident = input.parse()?;
+                let _: Token![:] = input.parse()?;
+                let typ: Typ = input.parse()?;
+                if !matches!(typ, Typ::Integer(_) | Typ::Float(_) | Typ::Boolean(_)) {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "static parameters must be of type `int`, `float` or `bool`",
+                    ));
+                }
+                static_params.push((ident, typ));
+            } else {
+                typ_params.push(Typ::parse_param(input)?);
+            }
+            if input.peek(Token![,]) {
+                let _: Token![,] = input.parse()?;
+            } else {
+                break;
+            }
+        }
+        let _: Token![>] = input.parse()?;
+    }
+    Ok((static_params, typ_params))
+}
+
 impl Component {
     pub fn peek(input: ParseStream) -> bool {
         input.peek(keyword::component)
@@ -816,6 +1022,7 @@ impl syn::Parse for Component {
     fn parse(input: ParseStream) -> Res<Self> {
         let comp_token: keyword::component = input.parse()?;
         let ident: Ident = input.parse()?;
+        let (static_params, typ_params) = parse_params(input)?;
         let content;
         let args_paren: token::Paren = parenthesized!(content in input);
         let args: Punctuated<Colon<Ident, Typ>, Token![,]> =
@@ -838,6 +1045,8 @@ impl syn::Parse for Component {
         Ok(Component {
             comp_token,
             ident,
+            static_params,
+            typ_params,
             args_paren,
             args,
             arrow_token,
@@ -894,10 +1103,123 @@ impl Parse for ConstDecl {
             eq_token,
             value,
             semi_token,
+            monotonic: false,
+        })
+    }
+}
+impl ParseItem for ConstDecl {
+    const DESC: &str = "constant";
+
+    fn parse_attributes(mut self, attrs: Vec<syn::Attribute>) -> syn::Res<Self> {
+        for attr in attrs {
+            let span = attr.bracket_token.span.join();
+            match &attr.meta {
+                syn::Meta::Path(path) if path.is_ident("monotonic") => {
+                    if self.monotonic {
+                        let msg = format!("this {} is already monotonic", Self::DESC);
+                        return Err(syn::Error::new(span, msg));
+                    }
+                    self.monotonic = true
+                }
+                _ => return Err(syn::Error::new(span, "unexpected attribute name")),
+            }
+        }
+        Ok(self)
+    }
+}
+
+impl Scenario {
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::test)
+    }
+}
+impl Parse for Scenario {
+    fn parse(input: ParseStream) -> Res<Self> {
+        let test_token: keyword::test = input.parse()?;
+        let ident: Ident = input.parse()?;
+        let for_token: Token![for] = input.parse()?;
+        let comp: Ident = input.parse()?;
+        let content;
+        let brace: token::Brace = braced!(content in input);
+        let mut steps = vec![];
+        while !content.is_empty() {
+            steps.push(content.parse()?);
+        }
+        Ok(Scenario {
+            test_token,
+            ident,
+            for_token,
+            comp,
+            brace,
+            steps,
         })
     }
 }
 
+mod scenario_impl {
+    use super::*;
+    prelude! { just
+        scenario::{Assign, Step, Value},
+    }
+
+    impl Parse for Value {
+        fn parse(input: ParseStream) -> Res<Self> {
+            if input.peek(keyword::some) {
+                let some_token: keyword::some = input.parse()?;
+                let content;
+                let paren_token: token::Paren = parenthesized!(content in input);
+                let expr: Expr = content.parse()?;
+                if !content.is_empty() {
+                    return Err(content.error("expected one expression"));
+                }
+                Ok(Value::Some {
+                    some_token,
+                    paren_token,
+                    expr,
+                })
+            } else if input.peek(keyword::none) {
+                let none_token: keyword::none = input.parse()?;
+                Ok(Value::None { none_token })
+            } else {
+                Ok(Value::Expr(input.parse()?))
+            }
+        }
+    }
+
+    impl Parse for Assign {
+        fn parse(input: ParseStream) -> Res<Self> {
+            Ok(Assign {
+                ident: input.parse()?,
+                eq_token: input.parse()?,
+                value: input.parse()?,
+            })
+        }
+    }
+
+    impl Parse for Step {
+        fn parse(input: ParseStream) -> Res<Self> {
+            let step_token: keyword::step = input.parse()?;
+            let content;
+            let step_brace: token::Brace = braced!(content in input);
+            let inputs = Punctuated::parse_terminated(&content)?;
+            let expect = if input.peek(keyword::expect) {
+                let expect_token: keyword::expect = input.parse()?;
+                let content;
+                let brace: token::Brace = braced!(content in input);
+                Some((expect_token, brace, Punctuated::parse_terminated(&content)?))
+            } else {
+                None
+            };
+            Ok(Step {
+                step_token,
+                step_brace,
+                inputs,
+                expect,
+            })
+        }
+    }
+}
+
 impl Typedef {
     pub fn peek(input: ParseStream) -> bool {
         input.peek(Token![struct]) || input.peek(Token![enum]) || input.peek(keyword::array)
@@ -1049,6 +1371,12 @@ mod parse_stream {
         }
     }
 
+    impl stream::Every {
+        pub fn peek(input: ParseStream) -> bool {
+            input.peek(keyword::every)
+        }
+    }
+
     impl ParsePrec for stream::Expr {
         fn parse_term(input: ParseStream) -> syn::Res<Self> {
             let mut expression = if Constant::peek(input) {
@@ -1176,6 +1504,20 @@ mod parse_stream {
             } else {
                 Self::parse_prec4(input)?
             };
+            if stream::Every::peek(input) {
+                let Self::Application(app) = expression else {
+                    return Err(input.error("'every' should follow a component application"));
+                };
+                let every_token: keyword::every = input.parse()?;
+                let reset: Self = Self::parse_prec4(input)?;
+                let loc = app.loc.join(reset.loc());
+                return Ok(Self::Every(stream::Every::new(
+                    loc,
+                    app,
+                    every_token,
+                    reset,
+                )));
+            }
             Ok(expression)
         }
     }
@@ -1342,26 +1684,30 @@ mod parse_expr {
         pub fn parse_term(lhs: E, input: ParseStream) -> syn::Res<Self> {
             let op_loc = input.span().into();
             let op = input.parse()?;
+            let arith = Arith::parse_suffix(op, input)?;
             let rhs = E::parse_term(input)?;
-            Ok(BinOp::new(op, op_loc, lhs, rhs))
+            Ok(BinOp::with_arith(op, op_loc, arith, lhs, rhs))
         }
         pub fn parse_prec1(lhs: E, input: ParseStream) -> syn::Res<Self> {
             let op = input.parse()?;
+            let arith = Arith::parse_suffix(op, input)?;
             let op_loc = input.span().into();
             let rhs = E::parse_prec1(input)?;
-            Ok(BinOp::new(op, op_loc, lhs, rhs))
+            Ok(BinOp::with_arith(op, op_loc, arith, lhs, rhs))
         }
         pub fn parse_prec2(lhs: E, input: ParseStream) -> syn::Res<Self> {
             let op_loc = input.span().into();
             let op = input.parse()?;
+            let arith = Arith::parse_suffix(op, input)?;
             let rhs = E::parse_prec2(input)?;
-            Ok(BinOp::new(op, op_loc, lhs, rhs))
+            Ok(BinOp::with_arith(op, op_loc, arith, lhs, rhs))
         }
         pub fn parse_prec3(lhs: E, input: ParseStream) -> syn::Res<Self> {
             let op_loc = input.span().into();
             let op = input.parse()?;
+            let arith = Arith::parse_suffix(op, input)?;
             let rhs = E::parse_prec3(input)?;
-            Ok(BinOp::new(op, op_loc, lhs, rhs))
+            Ok(BinOp::with_arith(op, op_loc, arith, lhs, rhs))
         }
     }
 
@@ -1398,34 +1744,60 @@ mod parse_expr {
         E: Parse + HasLoc,
     {
         pub fn peek(input: ParseStream) -> bool {
-            input.peek(token::Paren)
+            input.peek(token::Paren) || (input.peek(Token![::]) && input.peek3(Token![<]))
         }
 
         pub fn parse(function: E, input: ParseStream) -> syn::Res<Self> {
+            let static_args = parse_static_args(input)?;
             let content;
             let parens = syn::parenthesized!(content in input);
             let inputs: Punctuated<E, Token![,]> = Punctuated::parse_terminated(&content)?;
-            Ok(Application::new(
+            Ok(Application::new_static(
                 function.loc().join(parens.span.join()),
                 function,
+                static_args,
                 inputs.into_iter().collect(),
             ))
         }
     }
+
+    /// Parses the static arguments `::<3, -1, 0.5, N>` of a template application, if any.
+    ///
+    /// Static arguments are literals, negated literals or static parameters.
+    fn parse_static_args(input: ParseStream) -> syn::Res<Vec<Expr>> {
+        let mut static_args = vec![];
+        if !input.peek(Token![::]) {
+            return Ok(static_args);
+        }
+        let _: Token![::] = input.parse()?;
+        let _: Token![<] = input.parse()?;
+        while !input.peek(Token![>]) {
+            let arg = if input.peek(Token![-]) {
+                let op_loc: Loc = input.span().into();
+                let _: Token![-] = input.parse()?;
+                let constant: Constant = input.parse()?;
+                Expr::unop(UnOp::new(UOp::Neg, op_loc, Expr::Constant(constant)))
+            } else if Constant::peek(input) {
+                Expr::Constant(input.parse()?)
+            } else {
+                Expr::Identifier(input.parse()?)
+            };
+            static_args.push(arg);
+            if input.peek(Token![>]) {
+                break;
+            }
+            let _: Token![,] = input.parse()?;
+        }
+        let _: Token![>] = input.parse()?;
+        Ok(static_args)
+    }
     impl<E> Parse for Application<E>
     where
         E: Parse + HasLoc,
     {
         fn parse(input: ParseStream) -> syn::Res<Self> {
             let function: E = input.parse()?;
-            let content;
-            let parens = parenthesized!(content in input);
-            let inputs: Punctuated<E, Token![,]> = Punctuated::parse_terminated(&content)?;
-            Ok(Application::new(
-                function.loc().join(parens.span.join()),
-                function,
-                inputs.into_iter().collect(),
-            ))
+            Application::parse(function, input)
         }
     }
 
@@ -1520,7 +1892,9 @@ mod parse_expr {
 
     impl<E> Enumeration<E> {
         pub fn peek(input: ParseStream) -> bool {
-            input.peek(Ident) && input.peek2(Token![::])
+            // not a template application `filter::<..>`
+            let fork = input.fork();
+            fork.parse::<Ident>().is_ok() && fork.parse::<Token![::]>().is_ok() && fork.peek(Ident)
         }
     }
 
@@ -2359,6 +2733,117 @@ mod parse_contract {
         }
     }
 
+    impl Exists {
+        fn peek(input: ParseStream) -> bool {
+            input.peek(keyword::exists)
+        }
+    }
+
+    impl Parse for Exists {
+        fn parse(input: ParseStream) -> syn::Res<Self> {
+            let exists_token: keyword::exists = input.parse()?;
+            let ident: Ident = input.parse()?;
+            let colon_token: Token![:] = input.parse()?;
+            let ty: Typ = input.parse()?;
+            let comma_token: Token![,] = input.parse()?;
+            let term: Term = input.parse()?;
+            Ok(Exists::new(
+                exists_token,
+                ident,
+                colon_token,
+                ty,
+                comma_token,
+                term,
+            ))
+        }
+    }
+
+    impl Bounded {
+        fn peek(input: ParseStream) -> bool {
+            (input.peek(keyword::forall) || input.peek(keyword::exists))
+                && input.peek2(Ident)
+                && input.peek3(Token![in])
+        }
+    }
+
+    impl Parse for Bounded {
+        fn parse(input: ParseStream) -> syn::Res<Self> {
+            let quantifier = if input.peek(keyword::forall) {
+                Quantifier::ForAll(input.parse()?)
+            } else {
+                Quantifier::Exists(input.parse()?)
+            };
+            let ident: Ident = input.parse()?;
+            let in_token: Token![in] = input.parse()?;
+            let lo: Term = Term::parse_prec4(input)?;
+            let range_token: Token![..] = input.parse()?;
+            let hi: Term = Term::parse_prec4(input)?;
+            let comma_token: Token![,] = input.parse()?;
+            let term: Term = input.parse()?;
+            Ok(Bounded {
+                quantifier,
+                ident,
+                in_token,
+                lo: lo.into(),
+                range_token,
+                hi: hi.into(),
+                comma_token,
+                term: term.into(),
+            })
+        }
+    }
+
+    impl Once {
+        fn peek(input: ParseStream) -> bool {
+            input.peek(keyword::once)
+        }
+    }
+    impl Parse for Once {
+        fn parse(input: ParseStream) -> syn::Res<Self> {
+            let once_token: keyword::once = input.parse()?;
+            let term: Term = Term::parse_prec3(input)?;
+            Ok(Once::new(once_token, term))
+        }
+    }
+
+    impl Historically {
+        fn peek(input: ParseStream) -> bool {
+            input.peek(keyword::historically)
+        }
+    }
+    impl Parse for Historically {
+        fn parse(input: ParseStream) -> syn::Res<Self> {
+            let historically_token: keyword::historically = input.parse()?;
+            let term: Term = Term::parse_prec3(input)?;
+            Ok(Historically::new(historically_token, term))
+        }
+    }
+
+    impl Since {
+        fn peek(input: ParseStream) -> bool {
+            input.peek(keyword::since)
+        }
+        fn parse(input: ParseStream, left: Term) -> syn::Res<Self> {
+            let since_token: keyword::since = input.parse()?;
+            let right: Term = Term::parse_prec3(input)?;
+            Ok(Since::new(left, since_token, right))
+        }
+    }
+
+    impl Within {
+        fn peek(input: ParseStream) -> bool {
+            input.peek(keyword::within)
+        }
+    }
+    impl Parse for Within {
+        fn parse(input: ParseStream) -> syn::Res<Self> {
+            let within_token: keyword::within = input.parse()?;
+            let bound: LitInt = input.parse()?;
+            let term: Term = Term::parse_prec3(input)?;
+            Ok(Within::new(within_token, bound, term))
+        }
+    }
+
     impl Implication {
         fn peek(input: ParseStream) -> bool {
             input.peek(Token![=>])
@@ -2494,6 +2979,12 @@ mod parse_contract {
                 Term::enumeration(input.parse()?)
             } else if Unary::peek(input) {
                 Term::unary(input.parse()?)
+            } else if Once::peek(input) {
+                Term::once(input.parse()?)
+            } else if Historically::peek(input) {
+                Term::historically(input.parse()?)
+            } else if Within::peek(input) {
+                Term::within(input.parse()?)
             } else if input.peek(Ident) {
                 let ident: Ident = input.parse()?;
                 if Application::peek(input) {
@@ -2505,6 +2996,21 @@ mod parse_contract {
                 return Err(input.error("expected expression"));
             };
 
+            loop {
+                if input.peek(token::Bracket) {
+                    let content;
+                    let bracket_token = bracketed!(content in input);
+                    let index: Term = content.parse()?;
+                    term = Term::array_access(ArrayAccess::new(term, bracket_token, index));
+                } else if input.peek(Token![.]) && !input.peek(Token![..]) {
+                    let dot_token: Token![.] = input.parse()?;
+                    let field: Ident = input.parse()?;
+                    term = Term::field_access(FieldAccess::new(term, dot_token, field));
+                } else {
+                    break;
+                }
+            }
+
             loop {
                 if input.peek(Token![^]) {
                     let op_loc = input.span();
@@ -2558,6 +3064,11 @@ mod parse_contract {
                     break;
                 }
             }
+
+            // `since` binds tighter than `&&` and `||`, and associates to the right
+            if Since::peek(input) {
+                term = Term::since(Since::parse(input, term)?);
+            }
             Ok(term)
         }
 
@@ -2577,8 +3088,12 @@ mod parse_contract {
     }
     impl Parse for Term {
         fn parse(input: ParseStream) -> syn::Res<Self> {
-            let mut term = if ForAll::peek(input) {
+            let mut term = if Bounded::peek(input) {
+                Self::bounded(input.parse()?)
+            } else if ForAll::peek(input) {
                 Self::forall(input.parse()?)
+            } else if Exists::peek(input) {
+                Self::exists(input.parse()?)
             } else if EventImplication::peek(input) {
                 Self::event(input.parse()?)
             } else {
@@ -2663,6 +3178,23 @@ mod parsing_tests {
         };
     }
 
+    #[test]
+    fn multi_instance_service() {
+        let service: Service = parse_quote! {
+            service aeb<const PERIOD: int, const DEADLINE: int> for left<10, 100>, right<20, 200> {
+                let event pedestrian: float = merge(pedestrian_l, pedestrian_r);
+                let signal t: float = sample(speed_km_h, PERIOD);
+                let event timeout_pedestrian: unit = timeout(pedestrian, DEADLINE);
+                brakes = braking_state(pedestrian, timeout_pedestrian, t);
+            }
+        };
+        assert!(service.is_multi_instance());
+        assert_eq!(service.params.len(), 2);
+        assert_eq!(service.instances.len(), 2);
+        assert_eq!(service.instances[1].ident, "right");
+        assert_eq!(service.instances[1].args.len(), 2);
+    }
+
     #[test]
     fn component() {
         let _: Component = parse_quote! {
@@ -2681,10 +3213,31 @@ mod parsing_tests {
         };
     }
 
+    #[test]
+    fn monotonic_const_decl() {
+        let item: Item = parse_quote! {
+            #[monotonic]
+            const BREAKPOINTS: [float; 3] = [0.0, 1.0, 2.0];
+        };
+        assert!(matches!(
+            item,
+            Item::Const(ConstDecl {
+                monotonic: true,
+                ..
+            })
+        ));
+
+        let res: syn::Res<Item> = syn::parse2(quote::quote! {
+            #[monotonic(strict)]
+            const BREAKPOINTS: [float; 3] = [0.0, 1.0, 2.0];
+        });
+        assert!(res.is_err());
+    }
+
     #[cfg(test)]
     mod parse_stream {
         prelude! {
-            stream::{Expr, ReactExpr, Last, Emit, WhenExpr},
+            stream::{Expr, ReactExpr, Last, Emit, Every, WhenExpr},
             expr::*,
         }
 
@@ -2960,6 +3513,28 @@ mod parsing_tests {
             assert_eq!(expression, control)
         }
 
+        #[test]
+        fn should_parse_every() {
+            let expression: ReactExpr = syn::parse_quote! {counter(x) every r};
+            let control = ReactExpr::expr(Expr::every(Every::new(
+                Loc::test_dummy(),
+                Application::new(
+                    Loc::test_dummy(),
+                    Expr::test_ident("counter"),
+                    vec![Expr::test_ident("x")],
+                ),
+                Default::default(),
+                Expr::test_ident("r"),
+            )));
+            assert_eq!(expression, control)
+        }
+
+        #[test]
+        fn should_not_parse_every_without_application() {
+            let expression: syn::Result<ReactExpr> = syn::parse_str("x every r");
+            assert!(expression.is_err())
+        }
+
         #[test]
         fn should_parse_when() {
             let expression: ReactExpr = syn::parse_quote! { when {let d = p? => emit x} };
@@ -3229,6 +3804,36 @@ mod parsing_tests {
             assert_eq!(term, control)
         }
 
+        #[test]
+        fn should_parse_binop_with_arithmetic_policy() {
+            let expr: Expr = parse_quote! {a +| b *% c -? d % e};
+            let control = Expr::binop(BinOp::with_arith(
+                BOp::Sub,
+                Loc::test_dummy(),
+                Some(Arith::Checked),
+                Expr::binop(BinOp::with_arith(
+                    BOp::Add,
+                    Loc::test_dummy(),
+                    Some(Arith::Saturating),
+                    Expr::test_ident("a"),
+                    Expr::binop(BinOp::with_arith(
+                        BOp::Mul,
+                        Loc::test_dummy(),
+                        Some(Arith::Wrapping),
+                        Expr::test_ident("b"),
+                        Expr::test_ident("c"),
+                    )),
+                )),
+                Expr::binop(BinOp::new(
+                    BOp::Mod,
+                    Loc::test_dummy(),
+                    Expr::test_ident("d"),
+                    Expr::test_ident("e"),
+                )),
+            ));
+            assert_eq!(expr, control)
+        }
+
         #[test]
         fn should_parse_modulo() {
             let term: Expr = parse_quote! {(-x + 1) % 3};
@@ -3960,5 +4565,83 @@ mod parsing_tests {
             ));
             assert_eq!(term, control)
         }
+
+        #[test]
+        fn should_parse_exists() {
+            let term: Term = parse_quote! { exists d: int, d > x};
+            let control = Term::exists(Exists::new(
+                Default::default(),
+                Loc::test_id("d"),
+                Default::default(),
+                Typ::int(),
+                Default::default(),
+                Term::binary(Binary::new(
+                    Loc::test_dummy(),
+                    Term::test_ident("d"),
+                    BOp::Gt,
+                    Term::test_ident("x"),
+                )),
+            ));
+            assert_eq!(term, control)
+        }
+
+        #[test]
+        fn should_parse_bounded_forall_over_array() {
+            let term: Term = parse_quote! { forall i in 0..3, a[i] > s.x};
+            let control = Term::bounded(Bounded {
+                quantifier: Quantifier::ForAll(Default::default()),
+                ident: Loc::test_id("i"),
+                in_token: Default::default(),
+                lo: Term::constant(Constant::int(parse_quote!(0))).into(),
+                range_token: Default::default(),
+                hi: Term::constant(Constant::int(parse_quote!(3))).into(),
+                comma_token: Default::default(),
+                term: Term::binary(Binary::new(
+                    Loc::test_dummy(),
+                    Term::array_access(ArrayAccess::new(
+                        Term::test_ident("a"),
+                        Default::default(),
+                        Term::test_ident("i"),
+                    )),
+                    BOp::Gt,
+                    Term::field_access(FieldAccess::new(
+                        Term::test_ident("s"),
+                        Default::default(),
+                        Loc::test_id("x"),
+                    )),
+                ))
+                .into(),
+            });
+            assert_eq!(term, control)
+        }
+
+        #[test]
+        fn should_parse_temporal_operators() {
+            let term: Term = parse_quote! { p => within 3 x > 0 && y since once z};
+            let control = Term::implication(Implication::new(
+                Term::test_ident("p"),
+                Default::default(),
+                Term::binary(Binary::new(
+                    Loc::test_dummy(),
+                    Term::within(Within::new(
+                        Default::default(),
+                        parse_quote!(3),
+                        Term::binary(Binary::new(
+                            Loc::test_dummy(),
+                            Term::test_ident("x"),
+                            BOp::Gt,
+                            Term::constant(Constant::int(parse_quote!(0))),
+                        )),
+                    )),
+                    BOp::And,
+                    Term::since(Since::new(
+                        Term::test_ident("y"),
+                        Default::default(),
+                        Term::once(Once::new(Default::default(), Term::test_ident("z"))),
+                    )),
+                )),
+            ));
+            assert_eq!(term, control)
+        }
     }
 }
@@ -33,8 +33,10 @@ pub mod ir0 {
             interface::{
                 self, ExtCompDecl, ExtFunDecl, FlowExport, FlowImport, Service, TimeRange,
             },
+            scenario,
             stmt::{self, LetDecl, LogStmt, Stmt},
-            stream, Ast, Colon, Component, ConstDecl, Ctx, Function, Item, Top, Typedef,
+            stream, Ast, Colon, Component, ConstDecl, Ctx, Function, Item, Scenario, Top,
+            Typedef,
         },
         symbol,
     };
@@ -44,6 +44,10 @@ pub enum SymbolKind {
     },
     /// Function kind.
     Function {
+        /// Type parameters, as [Typ::Param]s.
+        typ_params: Vec<Typ>,
+        /// Static parameters identifiers.
+        static_params: Vec<usize>,
         /// Inputs identifiers.
         inputs: Vec<usize>,
         /// Output type.
@@ -60,6 +64,10 @@ pub enum SymbolKind {
     },
     /// Component kind.
     Component {
+        /// Component's type parameters, as [Typ::Param]s.
+        typ_params: Vec<Typ>,
+        /// Component's static parameters identifiers.
+        static_params: Vec<usize>,
         /// Component's input identifiers.
         inputs: Vec<usize>,
         /// Component's output identifiers.
@@ -108,6 +116,22 @@ impl SymbolKind {
             _ => None,
         }
     }
+
+    /// Mutable references to the types of the symbol.
+    fn typings_mut(&mut self) -> Vec<&mut Typ> {
+        match self {
+            Self::Identifier { typing, .. } | Self::Init { typing, .. } => {
+                typing.iter_mut().collect()
+            }
+            Self::Flow { typing, .. } => vec![typing],
+            Self::Function {
+                typing,
+                output_type,
+                ..
+            } => typing.iter_mut().chain(output_type.iter_mut()).collect(),
+            _ => vec![],
+        }
+    }
 }
 impl PartialEq for SymbolKind {
     fn eq(&self, other: &Self) -> bool {
@@ -287,6 +311,13 @@ pub struct Table {
     fresh_id: usize,
     /// Context of known symbols.
     known_symbols: Context,
+    /// Type parameters of the function in the current local context.
+    typ_params: Vec<Typ>,
+    /// Static parameters of all templates, mapped to their template once it is stored.
+    static_params: HashMap<usize, Option<usize>>,
+    /// Symbols overwritten by [Table::bind_static_params], restored by
+    /// [Table::unbind_static_params].
+    bound: Vec<(usize, Symbol)>,
 }
 impl Default for Table {
     fn default() -> Self {
@@ -294,6 +325,9 @@ impl Default for Table {
             table: HashMap::new(),
             fresh_id: 0,
             known_symbols: Default::default(),
+            typ_params: vec![],
+            static_params: HashMap::new(),
+            bound: vec![],
         }
     }
 }
@@ -304,6 +338,9 @@ impl Table {
             table: HashMap::new(),
             fresh_id: 0,
             known_symbols: Context::new(),
+            typ_params: vec![],
+            static_params: HashMap::new(),
+            bound: vec![],
         }
     }
 
@@ -387,6 +424,19 @@ impl Table {
     pub fn global(&mut self) {
         let prev = std::mem::take(&mut self.known_symbols);
         self.known_symbols = prev.get_global_context();
+        self.typ_params.clear();
+    }
+
+    /// Set the type parameters of the current local context.
+    pub fn set_typ_params(&mut self, typ_params: Vec<Typ>) {
+        self.typ_params = typ_params;
+    }
+
+    /// Get the type parameter `name` of the current local context, if any.
+    pub fn get_typ_param(&self, name: &Ident) -> Option<&Typ> {
+        self.typ_params
+            .iter()
+            .find(|typ| matches!(typ, Typ::Param { name: param, .. } if param == name))
     }
 
     /// Insert raw symbol in symbol table.
@@ -486,6 +536,18 @@ impl Table {
         self.insert_symbol(symbol, local, errors)
     }
 
+    /// Insert static parameter in symbol table.
+    pub fn insert_static_param(
+        &mut self,
+        name: Ident,
+        typing: Typ,
+        errors: &mut Vec<Error>,
+    ) -> TRes<usize> {
+        let id = self.insert_local_ident(name, Some(typing), true, errors)?;
+        self.static_params.insert(id, None);
+        Ok(id)
+    }
+
     /// Insert constant identifier in symbol table.
     pub fn insert_constant(
         &mut self,
@@ -571,9 +633,12 @@ impl Table {
     }
 
     /// Insert function in symbol table.
+    #[allow(clippy::too_many_arguments)]
     pub fn insert_function(
         &mut self,
         name: Ident,
+        typ_params: Vec<Typ>,
+        static_params: Vec<usize>,
         inputs: Vec<usize>,
         path_opt: Option<syn::Path>,
         weight_percent_hint: Option<usize>,
@@ -581,6 +646,8 @@ impl Table {
     ) -> TRes<usize> {
         let symbol = Symbol::new(
             SymbolKind::Function {
+                typ_params,
+                static_params,
                 inputs,
                 output_type: None,
                 typing: None,
@@ -590,13 +657,18 @@ impl Table {
             name,
         );
 
-        self.insert_symbol(symbol, false, errors)
+        let id = self.insert_symbol(symbol, false, errors)?;
+        self.set_static_params_template(id);
+        Ok(id)
     }
 
     /// Insert component in symbol table.
+    #[allow(clippy::too_many_arguments)]
     pub fn insert_comp(
         &mut self,
         name: Ident,
+        typ_params: Vec<Typ>,
+        static_params: Vec<usize>,
         inputs_outputs: (Vec<usize>, Vec<(Ident, usize)>),
         locals_inits: Option<(HashMap<Ident, usize>, HashMap<Ident, usize>)>,
         path_opt: Option<syn::Path>,
@@ -607,6 +679,8 @@ impl Table {
         let (locals, inits) = locals_inits.unzip();
         let symbol = Symbol::new(
             SymbolKind::Component {
+                typ_params,
+                static_params,
                 inputs,
                 outputs,
                 locals,
@@ -617,7 +691,9 @@ impl Table {
             name,
         );
 
-        self.insert_symbol(symbol, false, errors)
+        let id = self.insert_symbol(symbol, false, errors)?;
+        self.set_static_params_template(id);
+        Ok(id)
     }
 
     /// Insert service in symbol table.
@@ -832,16 +908,27 @@ impl Table {
             .unwrap_or_else(|| panic!("expect symbol for {id}"))
             .clone();
         match symbol.kind() {
-            SymbolKind::Function { inputs, .. } => {
+            SymbolKind::Function {
+                typ_params,
+                static_params,
+                inputs,
+                ..
+            } => {
+                self.restore_context_from(static_params.iter());
                 self.restore_context_from(inputs.iter());
+                self.typ_params = typ_params.clone();
             }
             SymbolKind::Component {
+                typ_params,
+                static_params,
                 inputs,
                 outputs,
                 locals,
                 inits,
                 ..
             } => {
+                self.typ_params = typ_params.clone();
+                self.restore_context_from(static_params.iter());
                 self.restore_context_from(inputs.iter());
                 self.restore_context_from(outputs.iter().map(|(_, id)| id));
                 if let Some(locals) = locals {
@@ -905,6 +992,146 @@ impl Table {
         }
     }
 
+    /// Get function type parameters from identifier.
+    pub fn get_function_typ_params(&self, id: usize) -> &Vec<Typ> {
+        let symbol = self
+            .get_symbol(id)
+            .unwrap_or_else(|| panic!("expect symbol for {id}"));
+        match symbol.kind() {
+            SymbolKind::Function { typ_params, .. } => typ_params,
+            _ => noErrorDesc!(),
+        }
+    }
+
+    /// Get component type parameters from identifier.
+    pub fn get_comp_typ_params(&self, id: usize) -> &Vec<Typ> {
+        let symbol = self
+            .get_symbol(id)
+            .unwrap_or_else(|| panic!("expect symbol for {id}"));
+        match symbol.kind() {
+            SymbolKind::Component { typ_params, .. } => typ_params,
+            _ => noErrorDesc!(),
+        }
+    }
+
+    /// Get function or component static parameters from identifier.
+    pub fn get_static_params(&self, id: usize) -> &Vec<usize> {
+        let symbol = self
+            .get_symbol(id)
+            .unwrap_or_else(|| panic!("expect symbol for {id}"));
+        match symbol.kind() {
+            SymbolKind::Function { static_params, .. }
+            | SymbolKind::Component { static_params, .. } => static_params,
+            _ => noErrorDesc!(),
+        }
+    }
+
+    /// Tells if the identifier is a template, *i.e.* a function or component with static
+    /// parameters.
+    pub fn is_template(&self, id: usize) -> bool {
+        let symbol = self
+            .get_symbol(id)
+            .unwrap_or_else(|| panic!("expect symbol for {id}"));
+        match symbol.kind() {
+            SymbolKind::Function { static_params, .. }
+            | SymbolKind::Component { static_params, .. } => !static_params.is_empty(),
+            _ => false,
+        }
+    }
+
+    /// Tells if the identifier is a static parameter.
+    pub fn is_static_param(&self, id: usize) -> bool {
+        self.static_params.contains_key(&id)
+    }
+
+    /// Get the template of a static parameter.
+    pub fn get_static_param_template(&self, id: usize) -> usize {
+        self.static_params
+            .get(&id)
+            .copied()
+            .flatten()
+            .unwrap_or_else(|| panic!("expect template for static parameter {id}"))
+    }
+
+    /// Sets the template of its static parameters.
+    fn set_static_params_template(&mut self, id: usize) {
+        for param in self.get_static_params(id).clone() {
+            self.static_params.insert(param, Some(id));
+        }
+    }
+
+    /// Binds the static parameters of template `id` to `args`, and renames it `name`.
+    ///
+    /// Until [Table::unbind_static_params] is called, the static parameters are constants and
+    /// the array sizes they define are literals in all the types of the table.
+    pub fn bind_static_params(&mut self, id: usize, args: &[Constant], name: Ident) {
+        debug_assert!(self.bound.is_empty());
+        let params = self.get_static_params(id).clone();
+        debug_assert_eq!(params.len(), args.len());
+        let mut sizes = vec![];
+        for (param, arg) in params.into_iter().zip(args) {
+            let symbol = self
+                .table
+                .get_mut(&param)
+                .unwrap_or_else(|| panic!("expect symbol for {param}"));
+            self.bound.push((param, symbol.clone()));
+            if let Constant::Integer(size) = arg {
+                sizes.push((symbol.name.clone(), Size::Lit(size.clone())));
+            }
+            match &mut symbol.kind {
+                SymbolKind::Identifier { constant, .. } => {
+                    *constant = Some(Expr::Constant(arg.clone()))
+                }
+                _ => noErrorDesc!(),
+            }
+        }
+        if !sizes.is_empty() {
+            for (id, symbol) in self.table.iter_mut() {
+                let typings = symbol.kind.typings_mut();
+                if typings.iter().any(|typ| typ.has_size_params()) {
+                    let backup = symbol.clone();
+                    for typ in symbol.kind.typings_mut() {
+                        *typ = typ.instantiate_sizes(&sizes);
+                    }
+                    self.bound.push((*id, backup));
+                }
+            }
+        }
+        let symbol = self
+            .table
+            .get_mut(&id)
+            .unwrap_or_else(|| panic!("expect symbol for {id}"));
+        self.bound.push((id, symbol.clone()));
+        symbol.name_string = name.to_string();
+        symbol.name = name;
+    }
+
+    /// Restores the symbols modified by [Table::bind_static_params].
+    pub fn unbind_static_params(&mut self) {
+        for (id, symbol) in std::mem::take(&mut self.bound).into_iter().rev() {
+            self.table.insert(id, symbol);
+        }
+    }
+
+    /// Instantiates the array sizes of `typ` with the currently bound static parameters.
+    pub fn instantiate_sizes(&self, typ: &Typ) -> Typ {
+        if !typ.has_size_params() {
+            return typ.clone();
+        }
+        let sizes = self
+            .bound
+            .iter()
+            .filter(|(id, _)| self.is_static_param(*id))
+            .filter_map(|(id, symbol)| match self.try_get_const(*id) {
+                Some(Expr::Constant(Constant::Integer(size))) => {
+                    Some((symbol.name.clone(), Size::Lit(size.clone())))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        typ.instantiate_sizes(&sizes)
+    }
+
     /// Get function input identifiers from identifier.
     pub fn get_function_input(&self, id: usize) -> &Vec<usize> {
         let symbol = self
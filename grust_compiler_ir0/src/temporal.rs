@@ -0,0 +1,374 @@
+//! Compilation of past-time temporal contracts into synchronous observers.
+//!
+//! A clause using `once`, `historically`, `since` or `within` is compiled into observer
+//! equations, added to the observed component, whose flow `<observer>_holds` tells if the clause
+//! holds at the current instant. The clause becomes the invariant `last <observer>_holds`.
+//!
+//! Equations are inlined rather than put in an observer component, so that the verifier sees how
+//! the observer's state evolves with the component's. A second invariant ties the verdict to the
+//! observer's state, which is at its initial values until the first step: holding clauses are
+//! then inductive.
+//!
+//! ```GR
+//! component brakes(pedestrian: bool) -> (b: Braking)
+//!     ensures { pedestrian => within 3 b != Braking::NoBrake }
+//! { ... }
+//! ```
+//!
+//! becomes
+//!
+//! ```GR
+//! component brakes(pedestrian: bool) -> (b: Braking)
+//!     invariant { last brakes_observer_0_holds }
+//!     invariant {
+//!         (last brakes_observer_0_started => (last brakes_observer_0_holds
+//!             == (!(last brakes_observer_0_p0) || (last brakes_observer_0_c1 < 3))))
+//!         && (last brakes_observer_0_started || (last brakes_observer_0_c1 == 3))
+//!     }
+//! {
+//!     init brakes_observer_0_p0 = false;
+//!     let brakes_observer_0_p0: bool = pedestrian;
+//!     init brakes_observer_0_c1 = 3;
+//!     let brakes_observer_0_c1: int = if (b != Braking::NoBrake) then 0
+//!         else if last brakes_observer_0_c1 < 3 then last brakes_observer_0_c1 + 1
+//!         else 3;
+//!     let brakes_observer_0_t2: bool = brakes_observer_0_c1 < 3;
+//!     init brakes_observer_0_holds = true;
+//!     let brakes_observer_0_holds: bool = !(brakes_observer_0_p0) || brakes_observer_0_t2;
+//!     init brakes_observer_0_started = false;
+//!     let brakes_observer_0_started: bool = true;
+//!     ...
+//! }
+//! ```
+
+prelude! {
+    contract::{Clause, ClauseKind, Term},
+}
+
+impl Term {
+    /// Tells if the term contains past-time temporal operators.
+    pub fn is_temporal(&self) -> bool {
+        match self {
+            Self::Once(_) | Self::Historically(_) | Self::Since(_) | Self::Within(_) => true,
+            Self::Constant(_)
+            | Self::Result(_)
+            | Self::Identifier(_)
+            | Self::Last(_)
+            | Self::Enumeration(_) => false,
+            Self::Paren(term) => term.is_temporal(),
+            Self::Unary(unary) => unary.term.is_temporal(),
+            Self::Binary(binary) => binary.left.is_temporal() || binary.right.is_temporal(),
+            Self::ForAll(forall) => forall.term.is_temporal(),
+            Self::Exists(exists) => exists.term.is_temporal(),
+            Self::Bounded(bounded) => {
+                bounded.lo.is_temporal() || bounded.hi.is_temporal() || bounded.term.is_temporal()
+            }
+            Self::ArrayAccess(access) => access.term.is_temporal() || access.index.is_temporal(),
+            Self::FieldAccess(access) => access.term.is_temporal(),
+            Self::Implication(implication) => {
+                implication.left.is_temporal() || implication.right.is_temporal()
+            }
+            Self::EventImplication(implication) => implication.term.is_temporal(),
+            Self::Application(app) => app.inputs.iter().any(Term::is_temporal),
+        }
+    }
+
+    /// Stream expression tokens computing a non-temporal term.
+    fn to_expr_tokens(&self) -> Res<TokenStream2> {
+        let tokens = match self {
+            Self::Constant(Constant::Integer(i)) => quote!(#i),
+            Self::Constant(Constant::Float(f)) => quote!(#f),
+            Self::Constant(Constant::Boolean(b)) => quote!(#b),
+            Self::Paren(term) => {
+                let term = term.to_expr_tokens()?;
+                quote!((#term))
+            }
+            Self::Identifier(ident) => quote!(#ident),
+            Self::Last(ident) => quote!(last #ident),
+            Self::Enumeration(contract::Enumeration {
+                enum_name,
+                elem_name,
+            }) => quote!(#enum_name::#elem_name),
+            Self::Unary(contract::Unary { op, term, .. }) => {
+                let term = term.to_expr_tokens()?;
+                quote!(#op #term)
+            }
+            Self::Binary(contract::Binary {
+                left, op, right, ..
+            }) => {
+                // contract and expression operators do not share the same precedences
+                let (left, right) = (left.to_expr_tokens()?, right.to_expr_tokens()?);
+                quote!((#left #op #right))
+            }
+            Self::Implication(contract::Implication { left, right, .. }) => {
+                let (left, right) = (left.to_expr_tokens()?, right.to_expr_tokens()?);
+                quote!((!(#left) || (#right)))
+            }
+            Self::Application(contract::Application { fun, inputs, .. }) => {
+                let inputs = inputs
+                    .iter()
+                    .map(Term::to_expr_tokens)
+                    .collect::<Res<Vec<_>>>()?;
+                quote!(#fun(#(#inputs),*))
+            }
+            Self::ArrayAccess(contract::ArrayAccess { term, index, .. }) => {
+                let (term, index) = (term.to_expr_tokens()?, index.to_expr_tokens()?);
+                quote!(#term[#index])
+            }
+            Self::FieldAccess(contract::FieldAccess { term, field, .. }) => {
+                let term = term.to_expr_tokens()?;
+                quote!(#term.#field)
+            }
+            Self::Constant(_)
+            | Self::Result(_)
+            | Self::ForAll(_)
+            | Self::Exists(_)
+            | Self::Bounded(_)
+            | Self::EventImplication(_)
+            | Self::Once(_)
+            | Self::Historically(_)
+            | Self::Since(_)
+            | Self::Within(_) => bail!(@self.loc() => ErrorKind::msg(
+                "temporal operators only apply to executable propositions"
+            )),
+        };
+        Ok(tokens)
+    }
+}
+
+/// Observer of a temporal clause under construction.
+struct Observer {
+    /// Name of the observer, prefix of its flows.
+    name: Ident,
+    /// Equations of the observer.
+    equations: Vec<TokenStream2>,
+    /// Terms telling that memories of the observer are at their initial values.
+    inits: Vec<TokenStream2>,
+    /// Number of flows already introduced.
+    fresh: usize,
+}
+impl Observer {
+    fn new(name: Ident) -> Self {
+        Self {
+            name,
+            equations: vec![],
+            inits: vec![],
+            fresh: 0,
+        }
+    }
+
+    /// Adds equations computing the term.
+    ///
+    /// Returns the observer's expression of its value, and the contract term of its value at the
+    /// previous instant. When `memorize` is true, observed propositions are memorized for the
+    /// latter, otherwise it is left unused.
+    fn compile(&mut self, term: &Term, memorize: bool) -> Res<(TokenStream2, TokenStream2)> {
+        if !term.is_temporal() {
+            let term = term.to_expr_tokens()?;
+            if !memorize {
+                return Ok((quote!((#term)), quote!((#term))));
+            }
+            let p = self.fresh_ident("p");
+            self.equations.push(quote! {
+                init #p = false;
+                let #p: bool = #term;
+            });
+            return Ok((quote!(#p), quote!(last #p)));
+        }
+        let tokens = match term {
+            Term::Paren(term) => {
+                let (term, last) = self.compile(term, memorize)?;
+                (quote!((#term)), quote!((#last)))
+            }
+            Term::Unary(contract::Unary {
+                op: UOp::Not, term, ..
+            }) => {
+                let (term, last) = self.compile(term, memorize)?;
+                (quote!(!#term), quote!(!#last))
+            }
+            Term::Binary(contract::Binary {
+                left,
+                op: op @ (BOp::And | BOp::Or),
+                right,
+                ..
+            }) => {
+                let (left, left_last) = self.compile(left, memorize)?;
+                let (right, right_last) = self.compile(right, memorize)?;
+                (
+                    quote!((#left #op #right)),
+                    quote!((#left_last #op #right_last)),
+                )
+            }
+            Term::Implication(contract::Implication { left, right, .. }) => {
+                let (left, left_last) = self.compile(left, memorize)?;
+                let (right, right_last) = self.compile(right, memorize)?;
+                (
+                    quote!((!(#left) || (#right))),
+                    quote!((!(#left_last) || (#right_last))),
+                )
+            }
+            Term::Once(contract::Once { term, .. }) => {
+                let (term, _) = self.compile(term, false)?;
+                let t = self.fresh_ident("t");
+                self.equations.push(quote! {
+                    init #t = false;
+                    let #t: bool = #term || last #t;
+                });
+                self.inits.push(quote!(last #t == false));
+                (quote!(#t), quote!(last #t))
+            }
+            Term::Historically(contract::Historically { term, .. }) => {
+                let (term, _) = self.compile(term, false)?;
+                let t = self.fresh_ident("t");
+                self.equations.push(quote! {
+                    init #t = true;
+                    let #t: bool = #term && last #t;
+                });
+                self.inits.push(quote!(last #t == true));
+                (quote!(#t), quote!(last #t))
+            }
+            Term::Since(contract::Since { left, right, .. }) => {
+                let (left, _) = self.compile(left, false)?;
+                let (right, _) = self.compile(right, false)?;
+                let t = self.fresh_ident("t");
+                self.equations.push(quote! {
+                    init #t = false;
+                    let #t: bool = #right || (#left && last #t);
+                });
+                self.inits.push(quote!(last #t == false));
+                (quote!(#t), quote!(last #t))
+            }
+            Term::Within(contract::Within { bound, term, .. }) => {
+                if bound
+                    .base10_parse::<u64>()
+                    .map_err(|e| error!(@bound.span() => "{}", e))?
+                    == 0
+                {
+                    bail!(@bound.span() => ErrorKind::msg("`within` bound must be positive"))
+                }
+                let (term, _) = self.compile(term, false)?;
+                let (c, t) = (self.fresh_ident("c"), self.fresh_ident("t"));
+                // `c` counts the instants since `term` last held, saturating at `bound`
+                self.equations.push(quote! {
+                    init #c = #bound;
+                    let #c: int = if #term then 0
+                        else if last #c < #bound then last #c + 1
+                        else #bound;
+                    let #t: bool = #c < #bound;
+                });
+                self.inits.push(quote!(last #c == #bound));
+                (quote!(#t), quote!(last #c < #bound))
+            }
+            _ => bail!(@term.loc() => ErrorKind::msg(
+                "temporal operators can only be combined with `!`, `&&`, `||` and `=>`"
+            )),
+        };
+        Ok(tokens)
+    }
+
+    fn fresh_ident(&mut self, prefix: &str) -> Ident {
+        let ident = format_ident!("{}_{prefix}{}", self.name, self.fresh);
+        self.fresh += 1;
+        ident
+    }
+}
+
+impl Ast {
+    /// Compiles temporal contracts into synchronous observers.
+    ///
+    /// When `observe` is false, contracts are not checked and temporal clauses are dropped.
+    pub fn observe_temporal_contracts(
+        &mut self,
+        observe: bool,
+        errors: &mut Vec<Error>,
+    ) -> TRes<()> {
+        for item in self.items.iter_mut() {
+            match item {
+                Item::Component(comp)
+                    if comp.contract.clauses.iter().any(|c| c.term.is_temporal()) =>
+                {
+                    comp.observe_temporal_clauses(observe).dewrap(errors)?
+                }
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Component {
+    /// Replaces temporal clauses by invariants on observer equations added to the component.
+    fn observe_temporal_clauses(&mut self, observe: bool) -> Res<()> {
+        let mut observers = 0;
+        let mut clauses = Vec::with_capacity(self.contract.clauses.len());
+        for clause in std::mem::take(&mut self.contract.clauses) {
+            if !clause.term.is_temporal() {
+                clauses.push(clause);
+                continue;
+            }
+            let loc = clause.term.loc();
+            match clause.kind {
+                ClauseKind::Ensures(_) | ClauseKind::Invariant(_) => (),
+                ClauseKind::Requires(_) | ClauseKind::Assert(_) => bail!(@loc => ErrorKind::msg(
+                    "temporal operators are only supported in `ensures` and `invariant` clauses"
+                )),
+            }
+            if !observe {
+                continue;
+            }
+
+            let name = format_ident!("{}_observer_{observers}", self.ident);
+            observers += 1;
+            let mut observer = Observer::new(name.clone());
+            let (holds, last_holds) = observer.compile(&clause.term, true)?;
+            let (flow, started) = (
+                format_ident!("{name}_holds"),
+                format_ident!("{name}_started"),
+            );
+            observer.equations.push(quote! {
+                init #flow = true;
+                let #flow: bool = #holds;
+                init #started = false;
+                let #started: bool = true;
+            });
+            for tokens in observer.equations {
+                self.equations.extend(parse_equations(tokens)?);
+            }
+            clauses.push(Clause::new(
+                ClauseKind::Invariant(keyword::invariant(loc.span)),
+                clause.paren,
+                Term::last(flow.clone()),
+            ));
+            // ties the observer's verdict to its state, for the invariant to be inductive
+            let inits = observer.inits;
+            clauses.push(Clause::new(
+                ClauseKind::Invariant(keyword::invariant(loc.span)),
+                clause.paren,
+                parse_generated(quote! {
+                    (last #started => (last #flow == (#last_holds)))
+                        && (last #started || (#(#inits)&&*))
+                })?,
+            ));
+        }
+        self.contract.clauses = clauses;
+        Ok(())
+    }
+}
+
+/// Parses generated equations.
+fn parse_equations(tokens: TokenStream2) -> Res<Vec<equation::ReactEq>> {
+    let parser = |input: syn::ParseStream| {
+        let mut equations = vec![];
+        while !input.is_empty() {
+            equations.push(input.parse()?)
+        }
+        Ok(equations)
+    };
+    syn::parse::Parser::parse2(parser, tokens)
+        .map_err(|e| error!(@e.span() => "[internal] generated observer: {}", e))
+}
+
+/// Parses generated tokens.
+fn parse_generated<T: syn::Parse>(tokens: TokenStream2) -> Res<T> {
+    syn::parse2(tokens).map_err(|e| error!(@e.span() => "[internal] generated observer: {}", e))
+}
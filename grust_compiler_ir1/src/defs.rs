@@ -10,6 +10,7 @@ pub mod ctx;
 pub mod expr;
 pub mod memory;
 pub mod pattern;
+pub mod scenario;
 pub mod stmt;
 pub mod stream;
 pub mod typedef;
@@ -56,11 +57,16 @@ pub fn from_ast_timed(
         ast, symbols, errors
     ));
     check_errors!("type-checking (ir1)", |_| ir1.typ_check(symbols, errors));
+    if symbols.conf.mutation_testing() {
+        check_errors!("mutation (ir1)", |_| ir1.mutate(symbols, errors));
+    }
     check_errors!("dependency graph generation (ir1)", |sub_stats| ir1
         .generate_dependency_graphs(symbols, sub_stats, errors),);
     check_errors!("causality analysis (ir1)", |_| ir1
         .causality_analysis(symbols, errors));
     check_errors!("normalization (ir1)", |_| ir1.normalize(symbols, errors));
+    check_errors!("template instantiation (ir1)", |_| ir1
+        .instantiate(symbols, errors));
     Ok(ir1)
 }
 
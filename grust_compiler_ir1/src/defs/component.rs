@@ -136,10 +136,14 @@ impl Component {
     /// Inline component application when it is needed.
     ///
     /// Inlining needed for "shifted causality loop".
-    pub fn inline_when_needed(&mut self, components: &HashMap<usize, Component>, ctx: &mut Ctx) {
+    pub fn inline_when_needed(
+        &mut self,
+        components: &HashMap<usize, Component>,
+        ctx: &mut Ctx,
+    ) -> URes {
         match &mut self.body_or_path {
             Either::Left(body) => body.inline_when_needed(components, ctx),
-            Either::Right(_) => (),
+            Either::Right(_) => Ok(()),
         }
     }
 
@@ -575,7 +579,11 @@ impl ComponentBody {
     /// Inline component application when it is needed.
     ///
     /// Inlining needed for "shifted causality loop".
-    pub fn inline_when_needed(&mut self, components: &HashMap<usize, Component>, ctx: &mut Ctx) {
+    pub fn inline_when_needed(
+        &mut self,
+        components: &HashMap<usize, Component>,
+        ctx: &mut Ctx,
+    ) -> URes {
         // create identifier creator containing the idents
         let mut identifier_creator = IdentifierCreator::from(self.get_idents_names(ctx));
 
@@ -584,21 +592,20 @@ impl ComponentBody {
 
         // compute new statements for the component
         let mut new_statements: Vec<stream::Stmt> = vec![];
-        std::mem::take(&mut self.statements)
-            .into_iter()
-            .for_each(|statement| {
-                let mut retrieved_statements = statement.inline_when_needed_recursive(
-                    &subgraph,
-                    &mut self.memory,
-                    &mut identifier_creator,
-                    ctx,
-                    components,
-                );
-                new_statements.append(&mut retrieved_statements)
-            });
+        for statement in std::mem::take(&mut self.statements) {
+            let mut retrieved_statements = statement.inline_when_needed_recursive(
+                &subgraph,
+                &mut self.memory,
+                &mut identifier_creator,
+                ctx,
+                components,
+            )?;
+            new_statements.append(&mut retrieved_statements)
+        }
 
         // update component's stmt
-        self.update_statements(&new_statements)
+        self.update_statements(&new_statements);
+        Ok(())
     }
 
     /// Instantiate component's statements with inputs.
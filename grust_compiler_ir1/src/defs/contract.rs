@@ -6,6 +6,13 @@ prelude! {
     graph::*,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+/// Quantifier of a bounded term.
+pub enum Quantifier {
+    ForAll,
+    Exists,
+}
+
 #[derive(Debug, PartialEq, Clone)]
 /// A contract term kind.
 pub enum Kind {
@@ -56,6 +63,35 @@ pub enum Kind {
     },
     /// Forall term: `forall x, P(x)`.
     ForAll { id: usize, term: Box<Term> },
+    /// Exists term: `exists x, P(x)`.
+    Exists { id: usize, term: Box<Term> },
+    /// Bounded quantifier term: `forall i in lo..hi, P(i)`, `exists i in lo..hi, P(i)`.
+    Bounded {
+        /// The quantifier.
+        quantifier: Quantifier,
+        /// The integer identifier, from `lo` included to `hi` excluded.
+        id: usize,
+        /// Lower bound.
+        lo: Box<Term>,
+        /// Upper bound.
+        hi: Box<Term>,
+        /// The term.
+        term: Box<Term>,
+    },
+    /// Array access term: `a[i]`.
+    ArrayAccess {
+        /// The array.
+        term: Box<Term>,
+        /// The index.
+        index: Box<Term>,
+    },
+    /// Field access term: `s.f`.
+    FieldAccess {
+        /// The structure.
+        term: Box<Term>,
+        /// The field.
+        field: Ident,
+    },
     /// Implication term: `P => Q`.
     Implication { left: Box<Term>, right: Box<Term> },
     /// Present event pattern.
@@ -78,6 +114,8 @@ pub enum Kind {
         memory_id: Option<usize>,
         /// The called component identifier.
         comp_id: usize,
+        /// The called component type arguments, inferred at typing.
+        typ_args: Vec<Typ>,
         /// The inputs to the term.
         inputs: Vec<(usize, Term)>,
     },
@@ -105,6 +143,25 @@ mk_new! { impl Kind =>
         id: usize,
         term: Term = term.into(),
     }
+    Exists: exists {
+        id: usize,
+        term: Term = term.into(),
+    }
+    Bounded: bounded {
+        quantifier: Quantifier,
+        id: usize,
+        lo: Term = lo.into(),
+        hi: Term = hi.into(),
+        term: Term = term.into(),
+    }
+    ArrayAccess: array_access {
+        term: Term = term.into(),
+        index: Term = index.into(),
+    }
+    FieldAccess: field_access {
+        term: Term = term.into(),
+        field: Ident,
+    }
     Implication: implication {
         left: Term = left.into(),
         right: Term = right.into(),
@@ -120,6 +177,7 @@ mk_new! { impl Kind =>
     ComponentCall: call {
         memory_id = None,
         comp_id: usize,
+        typ_args = vec![],
         inputs: Vec<(usize, Term)>,
     }
 }
@@ -163,11 +221,29 @@ impl Term {
                     vec![*id]
                 }
             }
-            Kind::ForAll { id, term, .. } => term
+            Kind::ForAll { id, term, .. } | Kind::Exists { id, term } => term
                 .compute_dependencies(ctx)
                 .into_iter()
                 .filter(|ident| id != ident)
                 .collect(),
+            Kind::Bounded {
+                id, lo, hi, term, ..
+            } => {
+                let mut dependencies = lo.compute_dependencies(ctx);
+                dependencies.extend(hi.compute_dependencies(ctx));
+                dependencies.extend(
+                    term.compute_dependencies(ctx)
+                        .into_iter()
+                        .filter(|ident| id != ident),
+                );
+                dependencies
+            }
+            Kind::ArrayAccess { term, index } => {
+                let mut dependencies = term.compute_dependencies(ctx);
+                dependencies.extend(index.compute_dependencies(ctx));
+                dependencies
+            }
+            Kind::FieldAccess { term, .. } => term.compute_dependencies(ctx),
             Kind::Last { .. } => vec![],
             Kind::Application { inputs, .. } => inputs
                 .iter()
@@ -180,6 +256,42 @@ impl Term {
         }
     }
 
+    /// Identifiers whose memory is read by the term, `x` in `last x`.
+    pub fn memory_accesses(&self, accesses: &mut Vec<usize>) {
+        match &self.kind {
+            Kind::Last { ident_id, .. } => accesses.push(*ident_id),
+            Kind::Constant { .. }
+            | Kind::Identifier { .. }
+            | Kind::Enumeration { .. }
+            | Kind::PresentEvent { .. } => (),
+            Kind::Unary { term, .. }
+            | Kind::Paren { term }
+            | Kind::ForAll { term, .. }
+            | Kind::Exists { term, .. }
+            | Kind::FieldAccess { term, .. } => term.memory_accesses(accesses),
+            Kind::Bounded { lo, hi, term, .. } => {
+                lo.memory_accesses(accesses);
+                hi.memory_accesses(accesses);
+                term.memory_accesses(accesses);
+            }
+            Kind::ArrayAccess {
+                term: left,
+                index: right,
+            }
+            | Kind::Binary { left, right, .. }
+            | Kind::Implication { left, right } => {
+                left.memory_accesses(accesses);
+                right.memory_accesses(accesses);
+            }
+            Kind::Application { inputs, .. } => inputs
+                .iter()
+                .for_each(|term| term.memory_accesses(accesses)),
+            Kind::ComponentCall { inputs, .. } => inputs
+                .iter()
+                .for_each(|(_, term)| term.memory_accesses(accesses)),
+        }
+    }
+
     /// Add dependencies of a term to the graph.
     pub fn add_term_dependencies(&self, comp_graph: &mut DiGraphMap<usize, Label>, ctx: &Ctx) {
         let dependencies = self.compute_dependencies(ctx);
@@ -204,6 +316,7 @@ impl Term {
         match &mut self.kind {
             contract::Kind::ComponentCall {
                 comp_id,
+                typ_args,
                 memory_id: comp_memory_id,
                 ..
             } => {
@@ -213,7 +326,7 @@ impl Term {
                 let memory_name =
                     identifier_creator.new_identifier(comp_name.loc(), comp_name.to_string());
                 let memory_id = ctx.insert_fresh_ident(memory_name, Scope::Local, None);
-                memory.add_ghost_comp(memory_id, *comp_id);
+                memory.add_ghost_comp(memory_id, *comp_id, typ_args.clone());
                 // put the 'memory_id' of the called component
                 *comp_memory_id = Some(memory_id);
             }
@@ -222,8 +335,19 @@ impl Term {
             | Kind::Last { .. }
             | Kind::Enumeration { .. }
             | Kind::PresentEvent { .. } => (),
-            Kind::Unary { term, .. } | Kind::Paren { term } | Kind::ForAll { term, .. } => {
-                term.memorize(identifier_creator, memory, ctx)
+            Kind::Unary { term, .. }
+            | Kind::Paren { term }
+            | Kind::ForAll { term, .. }
+            | Kind::Exists { term, .. }
+            | Kind::FieldAccess { term, .. } => term.memorize(identifier_creator, memory, ctx),
+            Kind::Bounded { lo, hi, term, .. } => {
+                lo.memorize(identifier_creator, memory, ctx);
+                hi.memorize(identifier_creator, memory, ctx);
+                term.memorize(identifier_creator, memory, ctx);
+            }
+            Kind::ArrayAccess { term, index } => {
+                term.memorize(identifier_creator, memory, ctx);
+                index.memorize(identifier_creator, memory, ctx);
             }
             Kind::Binary { left, right, .. } | Kind::Implication { left, right, .. } => {
                 left.memorize(identifier_creator, memory, ctx);
@@ -261,8 +385,14 @@ impl Term {
                     *init_id = new_id;
                 }
             }
-            Kind::Unary { ref mut term, .. } | Kind::Paren { ref mut term } => {
+            Kind::Unary { ref mut term, .. }
+            | Kind::Paren { ref mut term }
+            | Kind::FieldAccess { ref mut term, .. } => {
+                term.substitution(old_id, new_id);
+            }
+            Kind::ArrayAccess { term, index } => {
                 term.substitution(old_id, new_id);
+                index.substitution(old_id, new_id);
             }
             Kind::Binary {
                 ref mut left,
@@ -277,12 +407,21 @@ impl Term {
                 left.substitution(old_id, new_id);
                 right.substitution(old_id, new_id);
             }
-            Kind::ForAll { id, term, .. } => {
+            Kind::ForAll { id, term, .. } | Kind::Exists { id, term } => {
                 if old_id != *id {
                     term.substitution(old_id, new_id)
                 }
                 // if 'id to replace' is equal to 'id of the forall' then nothing to do
             }
+            Kind::Bounded {
+                id, lo, hi, term, ..
+            } => {
+                lo.substitution(old_id, new_id);
+                hi.substitution(old_id, new_id);
+                if old_id != *id {
+                    term.substitution(old_id, new_id)
+                }
+            }
             Kind::Application { fun_id, inputs } => {
                 if *fun_id == old_id {
                     *fun_id = new_id;
@@ -330,6 +469,17 @@ impl Contract {
             .for_each(|term| term.substitution(old_id, new_id));
     }
 
+    /// Identifiers whose memory is read by the contract.
+    pub fn memory_accesses(&self) -> Vec<usize> {
+        let mut accesses = vec![];
+        self.requires
+            .iter()
+            .chain(self.ensures.iter())
+            .chain(self.invariant.iter())
+            .for_each(|term| term.memory_accesses(&mut accesses));
+        accesses
+    }
+
     /// Add dependencies of a contract to the graph.
     pub fn add_dependencies(&self, comp_graph: &mut DiGraphMap<usize, Label>, ctx: &Ctx) {
         self.requires
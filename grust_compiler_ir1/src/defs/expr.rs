@@ -28,6 +28,8 @@ pub enum Kind<E> {
     BinOp {
         /// The unary operator.
         op: BOp,
+        /// Arithmetic policy given by the operator's suffix, if any.
+        arith: Option<Arith>,
         /// The left expression.
         lft: Box<E>,
         /// The right expression.
@@ -46,6 +48,8 @@ pub enum Kind<E> {
     Application {
         /// The expression applied.
         fun: Box<E>,
+        /// The static arguments, bound to the static parameters of the applied template.
+        static_args: Vec<(usize, Expr)>,
         /// The inputs to the expression.
         inputs: Vec<E>,
     },
@@ -149,6 +153,13 @@ mk_new! { impl{E} Kind<E> =>
     }
     BinOp: binop {
         op: BOp,
+        arith = None,
+        lft: E = lft.into(),
+        rgt: E = rgt.into(),
+    }
+    BinOp: arith_binop {
+        op: BOp,
+        arith: Option<Arith>,
         lft: E = lft.into(),
         rgt: E = rgt.into(),
     }
@@ -159,6 +170,12 @@ mk_new! { impl{E} Kind<E> =>
     }
     Application: app {
         fun: E = fun.into(),
+        static_args = vec![],
+        inputs: Vec<E>,
+    }
+    Application: static_app {
+        fun: E = fun.into(),
+        static_args: Vec<(usize, Expr)>,
         inputs: Vec<E>,
     }
     Lambda: lambda {
@@ -224,7 +241,7 @@ where
             IfThenElse { cnd, thn, els } => {
                 cnd.weight(wb, ctx) + thn.weight(wb, ctx).max(els.weight(wb, ctx)) + weight::lo
             }
-            Application { fun, inputs } => {
+            Application { fun, inputs, .. } => {
                 fun.weight(wb, ctx) + w8!(wb, ctx => sum inputs) + weight::hi
             }
             Lambda { expr, .. } => expr.weight(wb, ctx),
@@ -283,7 +300,9 @@ impl<E> Kind<E> {
             Kind::IfThenElse { cnd, thn, els } => {
                 expr_pred(cnd) && expr_pred(thn) && expr_pred(els)
             }
-            Kind::Application { fun, inputs } => expr_pred(fun) && inputs.iter().all(&expr_pred),
+            Kind::Application { fun, inputs, .. } => {
+                expr_pred(fun) && inputs.iter().all(&expr_pred)
+            }
             Kind::Structure { fields, .. } => {
                 fields.iter().all(|(_, expression)| expr_pred(expression))
             }
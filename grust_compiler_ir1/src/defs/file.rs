@@ -13,6 +13,12 @@ pub struct File {
     pub components: Vec<ir1::Component>,
     /// Program interface. It represents the system.
     pub interface: ir1::Interface,
+    /// Program test scenarios.
+    pub scenarios: Vec<ir1::Scenario>,
+    /// Mutants of the components, in mutation testing.
+    pub mutants: Vec<ir1::Mutant>,
+    /// Instances of the templates.
+    pub instances: Vec<ir1::Instance>,
     /// Program location.
     pub loc: Loc,
 }
@@ -84,15 +90,16 @@ impl File {
     /// Inline component application when it is needed.
     ///
     /// Inlining needed for "shifted causality loop".
-    pub fn inline_when_needed(&mut self, ctx: &mut Ctx) {
+    pub fn inline_when_needed(&mut self, ctx: &mut Ctx) -> URes {
         let components = self
             .components
             .iter()
             .map(|component| (component.get_id(), component.clone()))
             .collect::<HashMap<_, _>>();
-        self.components
-            .iter_mut()
-            .for_each(|component| component.inline_when_needed(&components, ctx))
+        for component in self.components.iter_mut() {
+            component.inline_when_needed(&components, ctx)?;
+        }
+        Ok(())
     }
 
     /// Schedule components' equations.
@@ -114,7 +121,7 @@ impl File {
         self.generate_flows_dependency_graphs();
         self.no_unused(ctx).dewrap(errors)?;
         self.memorize(ctx).dewrap(errors)?;
-        self.inline_when_needed(ctx);
+        self.inline_when_needed(ctx).dewrap(errors)?;
         self.schedule();
         Ok(())
     }
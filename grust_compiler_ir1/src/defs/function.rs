@@ -2,7 +2,7 @@
 
 prelude! {}
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 /// GRust function AST.
 pub struct FunctionBody {
     /// Function's contract.
@@ -15,7 +15,7 @@ pub struct FunctionBody {
     pub returned: ir1::Expr,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 /// GRust function AST.
 pub struct Function {
     /// Function identifier.
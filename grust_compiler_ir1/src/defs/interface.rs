@@ -11,6 +11,8 @@ pub struct Service {
     pub statements: HashMap<usize, FlowStatement>,
     /// Flows dependency graph, nodes are statements ids.
     pub graph: DiGraphMap<usize, ()>,
+    /// Service's response deadlines, checked at runtime.
+    pub responses: Vec<ResponseCheck>,
 }
 impl Service {
     pub fn get_flows_names<'a>(&'a self, ctx: &'a Ctx) -> impl Iterator<Item = Ident> + 'a {
@@ -256,6 +258,27 @@ pub struct FlowInstantiation {
     pub expr: ir1::flow::Expr,
     pub semi_token: Token![;],
 }
+/// Runtime check of an interface clause.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InterfaceCheck {
+    /// Predicate on the values of the flow, the value is named after the flow.
+    Value(contract::Term),
+    /// Minimal period between two values of the flow, in milliseconds.
+    Rate(u64),
+}
+
+/// Response deadline of a service: `output` is emitted at most `deadline_ms` after `input`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResponseCheck {
+    pub loc: Loc,
+    /// Exported flow's identifier.
+    pub output: usize,
+    /// Deadline in milliseconds.
+    pub deadline_ms: u64,
+    /// Imported flow's identifier.
+    pub input: usize,
+}
+
 /// Flow statement.
 #[derive(Clone)]
 pub struct FlowImport {
@@ -265,6 +288,8 @@ pub struct FlowImport {
     pub path: syn::Path,
     pub colon_token: Token![:],
     pub flow_type: Typ,
+    /// Checks of the imported values.
+    pub requires: Vec<InterfaceCheck>,
     pub semi_token: Token![;],
 }
 /// Flow statement.
@@ -276,6 +301,8 @@ pub struct FlowExport {
     pub path: syn::Path,
     pub colon_token: Token![:],
     pub flow_type: Typ,
+    /// Checks of the exported values.
+    pub ensures: Vec<InterfaceCheck>,
     pub semi_token: Token![;],
 }
 
@@ -175,6 +175,10 @@ pub struct Buffer {
 pub struct CalledComponent {
     /// component name.
     pub comp_id: usize,
+    /// component type arguments.
+    pub typ_args: Vec<Typ>,
+    /// component static arguments.
+    pub static_args: Vec<(usize, Expr)>,
 }
 
 /// Called ghost component' name.
@@ -182,6 +186,8 @@ pub struct CalledComponent {
 pub struct GhostComponent {
     /// component name.
     pub comp_id: usize,
+    /// component type arguments.
+    pub typ_args: Vec<Typ>,
 }
 
 impl Memory {
@@ -242,18 +248,29 @@ impl Memory {
     }
 
     /// Adds called component to memory.
-    pub fn add_called_comp(&mut self, memory_id: usize, comp_id: usize) {
-        let _unique = self
-            .called_comps
-            .insert(memory_id, CalledComponent { comp_id });
+    pub fn add_called_comp(
+        &mut self,
+        memory_id: usize,
+        comp_id: usize,
+        typ_args: Vec<Typ>,
+        static_args: Vec<(usize, Expr)>,
+    ) {
+        let _unique = self.called_comps.insert(
+            memory_id,
+            CalledComponent {
+                comp_id,
+                typ_args,
+                static_args,
+            },
+        );
         debug_assert!(_unique.is_none());
     }
 
     /// Adds a ghost component to memory.
-    pub fn add_ghost_comp(&mut self, memory_id: usize, comp_id: usize) {
+    pub fn add_ghost_comp(&mut self, memory_id: usize, comp_id: usize, typ_args: Vec<Typ>) {
         let _unique = self
             .ghost_comps
-            .insert(memory_id, GhostComponent { comp_id });
+            .insert(memory_id, GhostComponent { comp_id, typ_args });
         debug_assert!(_unique.is_none());
     }
 }
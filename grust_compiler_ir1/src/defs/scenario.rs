@@ -0,0 +1,53 @@
+//! [Scenario] module.
+
+prelude! {}
+
+/// Value of an input or an output in a step.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Value {
+    /// Present event.
+    Some(ir1::Expr),
+    /// Absent event.
+    None,
+    /// Signal value.
+    Expr(ir1::Expr),
+}
+
+/// A step of a scenario.
+#[derive(Debug, PartialEq)]
+pub struct Step {
+    /// Values of all the inputs of the component, in order.
+    pub inputs: Vec<(usize, Value)>,
+    /// Expected values of some outputs.
+    pub expect: Vec<(usize, Value)>,
+    /// Step location.
+    pub loc: Loc,
+}
+mk_new! { impl Step =>
+    new {
+        inputs: Vec<(usize, Value)>,
+        expect: Vec<(usize, Value)>,
+        loc: impl Into<Loc> = loc.into(),
+    }
+}
+
+/// A test scenario of a component.
+#[derive(Debug, PartialEq)]
+pub struct Scenario {
+    /// Name of the test.
+    pub ident: Ident,
+    /// Component under test.
+    pub comp_id: usize,
+    /// Steps of the scenario.
+    pub steps: Vec<Step>,
+    /// Scenario location.
+    pub loc: Loc,
+}
+mk_new! { impl Scenario =>
+    new {
+        ident: Ident,
+        comp_id: usize,
+        steps: Vec<Step>,
+        loc: impl Into<Loc> = loc.into(),
+    }
+}
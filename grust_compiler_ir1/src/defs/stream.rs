@@ -83,10 +83,15 @@ impl Stmt {
             stream::Kind::ComponentApplication {
                 called_comp_id,
                 ref mut inputs,
+                ref mut reset,
                 ..
             } => {
                 let (mut new_stmts, mut new_inits) = (vec![], vec![]);
-                for (_, expr) in inputs.iter_mut() {
+                for expr in inputs
+                    .iter_mut()
+                    .map(|(_, expr)| expr)
+                    .chain(reset.iter_mut().map(AsMut::as_mut))
+                {
                     let (add_stmts, add_inits) =
                         expr.into_ident_call(components_reduced_graphs, identifier_creator, ctx);
                     new_stmts.extend(add_stmts);
@@ -94,27 +99,13 @@ impl Stmt {
                 }
 
                 // change dependencies to be the sum of inputs dependencies
-                let reduced_graph = components_reduced_graphs.get(&called_comp_id).unwrap();
-                self.expr.dependencies = Dependencies::from(
-                    inputs
-                        .iter()
-                        .flat_map(|(input_id, expr)| {
-                            ctx.get_comp_outputs(called_comp_id).iter().flat_map(
-                                |(_, output_id)| {
-                                    reduced_graph
-                                        .edge_weight(*output_id, *input_id)
-                                        .into_iter()
-                                        .flat_map(|label1| {
-                                            expr.get_dependencies()
-                                                .clone()
-                                                .into_iter()
-                                                .map(|(id, label2)| (id, label1.add(&label2)))
-                                        })
-                                },
-                            )
-                        })
-                        .collect(),
-                );
+                self.expr.dependencies = Dependencies::from(comp_app_dependencies(
+                    called_comp_id,
+                    inputs,
+                    reset.as_deref(),
+                    components_reduced_graphs.get(&called_comp_id).unwrap(),
+                    ctx,
+                ));
 
                 (new_stmts, new_inits)
             }
@@ -254,27 +245,24 @@ impl Stmt {
         identifier_creator: &mut IdentifierCreator,
         ctx: &mut Ctx,
         components: &HashMap<usize, Component>,
-    ) -> Vec<stream::Stmt> {
+    ) -> Res<Vec<stream::Stmt>> {
         let mut current_statements = vec![self.clone()];
         let mut new_statements =
-            self.inline_when_needed(subgraph, memory, identifier_creator, ctx, components);
+            self.inline_when_needed(subgraph, memory, identifier_creator, ctx, components)?;
         while current_statements != new_statements {
             current_statements = new_statements;
-            new_statements = current_statements
-                .clone()
-                .into_iter()
-                .flat_map(|statement| {
-                    statement.inline_when_needed(
-                        subgraph,
-                        memory,
-                        identifier_creator,
-                        ctx,
-                        components,
-                    )
-                })
-                .collect();
+            new_statements = vec![];
+            for statement in current_statements.clone() {
+                new_statements.append(&mut statement.inline_when_needed(
+                    subgraph,
+                    memory,
+                    identifier_creator,
+                    ctx,
+                    components,
+                )?);
+            }
         }
-        new_statements
+        Ok(new_statements)
     }
 
     fn inline_when_needed(
@@ -284,19 +272,25 @@ impl Stmt {
         identifier_creator: &mut IdentifierCreator,
         ctx: &mut Ctx,
         components: &HashMap<usize, Component>,
-    ) -> Vec<stream::Stmt> {
+    ) -> Res<Vec<stream::Stmt>> {
         match &self.expr.kind {
             stream::Kind::ComponentApplication {
                 called_comp_id,
+                typ_args,
+                static_args,
                 inputs,
                 memory_id,
-                ..
+                reset,
             } => {
                 // a loop in the graph induces that "component call" inputs depends on output
                 let is_loop = {
                     let mut graph = subgraph.clone();
                     let outs = self.pattern.identifiers();
-                    let in_deps = inputs.iter().flat_map(|(_, expr)| expr.get_dependencies());
+                    let in_deps = inputs
+                        .iter()
+                        .map(|(_, expr)| expr)
+                        .chain(reset.as_deref())
+                        .flat_map(|expr| expr.get_dependencies());
                     for (to, label) in in_deps {
                         for from in outs.iter() {
                             graph.add_edge(*from, *to, *label);
@@ -307,6 +301,22 @@ impl Stmt {
 
                 // then component call must be inlined
                 if is_loop {
+                    // reset applications depend on all their inputs, they can not be in a loop
+                    debug_assert!(reset.is_none());
+                    // the statements of a generic component are not typed with the arguments
+                    if !typ_args.is_empty() {
+                        bail!(@self.loc =>
+                            "generic component `{}` is in a causality loop and cannot be inlined",
+                            ctx.get_name(*called_comp_id)
+                        )
+                    }
+                    // the statements of a template are not typed with the static arguments
+                    if !static_args.is_empty() {
+                        bail!(@self.loc =>
+                            "template component `{}` is in a causality loop and cannot be inlined",
+                            ctx.get_name(*called_comp_id)
+                        )
+                    }
                     let called_comp = components.get(called_comp_id).unwrap();
 
                     // get statements from called component, with corresponding inputs
@@ -322,13 +332,13 @@ impl Stmt {
                     memory.remove_called_comp(memory_id.unwrap());
 
                     memory.combine(retrieved_memory);
-                    retrieved_statements
+                    Ok(retrieved_statements)
                 } else {
                     // otherwise, just return self
-                    vec![self]
+                    Ok(vec![self])
                 }
             }
-            _ => vec![self],
+            _ => Ok(vec![self]),
         }
     }
 }
@@ -412,7 +422,7 @@ impl ExprKind {
                 thn.memorize(identifier_creator, memory, ctx)?;
                 els.memorize(identifier_creator, memory, ctx)?;
             }
-            Self::Application { fun, inputs } => {
+            Self::Application { fun, inputs, .. } => {
                 fun.memorize(identifier_creator, memory, ctx)?;
                 for expr in inputs.iter_mut() {
                     expr.memorize(identifier_creator, memory, ctx)?;
@@ -936,6 +946,48 @@ impl ExprKind {
     }
 }
 
+/// Dependencies of a component application, from the called component's reduced graph.
+///
+/// A reset application depends on all its inputs: its outputs are computed from a possibly
+/// re-initialized state, so it can not be inlined to break a causality loop.
+fn comp_app_dependencies(
+    called_comp_id: usize,
+    inputs: &[(usize, Expr)],
+    reset: Option<&Expr>,
+    reduced_graph: &DiGraphMap<usize, Label>,
+    ctx: &Ctx,
+) -> Vec<(usize, Label)> {
+    let mut dependencies = if reset.is_some() {
+        inputs
+            .iter()
+            .flat_map(|(_, expr)| expr.get_dependencies().clone())
+            .collect::<Vec<_>>()
+    } else {
+        inputs
+            .iter()
+            .flat_map(|(input_id, expr)| {
+                ctx.get_comp_outputs(called_comp_id)
+                    .iter()
+                    .flat_map(|(_, output_id)| {
+                        reduced_graph
+                            .edge_weight(*output_id, *input_id)
+                            .into_iter()
+                            .flat_map(|label1| {
+                                expr.get_dependencies()
+                                    .clone()
+                                    .into_iter()
+                                    .map(|(id, label2)| (id, label1.add(&label2)))
+                            })
+                    })
+            })
+            .collect()
+    };
+    if let Some(reset) = reset {
+        dependencies.extend(reset.get_dependencies().iter().cloned());
+    }
+    dependencies
+}
+
 #[derive(Debug, PartialEq, Clone)]
 /// GRust stream expression kind AST.
 pub enum Kind {
@@ -957,8 +1009,14 @@ pub enum Kind {
         memory_id: Option<usize>,
         /// Called component's id in Symbol Table.
         called_comp_id: usize,
+        /// Called component's type arguments, inferred at typing.
+        typ_args: Vec<Typ>,
+        /// Called component's static arguments, bound to its static parameters.
+        static_args: Vec<(usize, ir1::Expr)>,
         /// The inputs to the expression.
         inputs: Vec<(usize, Expr)>,
+        /// The reset condition, re-initializes the called component's state when true.
+        reset: Option<Box<Expr>>,
     },
     /// Detect a rising edge of the expression.
     RisingEdge {
@@ -992,7 +1050,18 @@ mk_new! { impl Kind =>
     ComponentApplication: call {
         memory_id = None,
         called_comp_id: usize,
+        typ_args = vec![],
+        static_args = vec![],
+        inputs: Vec<(usize, Expr)>,
+        reset = None,
+    }
+    ComponentApplication: static_call {
+        memory_id = None,
+        called_comp_id: usize,
+        typ_args = vec![],
+        static_args: Vec<(usize, ir1::Expr)>,
         inputs: Vec<(usize, Expr)>,
+        reset = None,
     }
     RisingEdge: rising_edge {
         expr: Expr = expr.into(),
@@ -1010,11 +1079,13 @@ impl HasWeight for Kind {
         match self {
             Expression { expr } => expr.weight(wb, ctx),
             Last { .. } => weight::lo,
-            ComponentApplication { inputs, .. } => {
+            ComponentApplication { inputs, reset, .. } => {
                 // we **don't** want to rayon-ize component applications as it would (seem to) borrow
                 // the component's state multiple time in the rayon-closure: we need the weight to be
                 // at least `wb.rayon_ubx`
-                wb.rayon_ubx() + w8!(sum inputs, |(_, e)| e.weight(wb, ctx))
+                wb.rayon_ubx()
+                    + w8!(sum inputs, |(_, e)| e.weight(wb, ctx))
+                    + w8!(wb, ctx => weight? reset.as_deref())
             }
             RisingEdge { expr } => expr.weight(wb, ctx) + weight::mid,
             SomeEvent { expr } => expr.weight(wb, ctx) + weight::mid,
@@ -1085,8 +1156,9 @@ impl Expr {
                 expr.propagate_predicate(predicate_expr, predicate_statement)
             }
             Kind::Last { .. } => true,
-            Kind::ComponentApplication { inputs, .. } => {
+            Kind::ComponentApplication { inputs, reset, .. } => {
                 inputs.iter().all(|(_, expr)| predicate_expr(expr))
+                    && reset.as_deref().is_none_or(predicate_expr)
             }
             Kind::SomeEvent { expr } => predicate_expr(expr),
             Kind::NoneEvent => true,
@@ -1117,8 +1189,9 @@ impl Expr {
                     statement.expr.no_rising_edge()
                 }),
             Kind::Last { .. } => true,
-            Kind::ComponentApplication { inputs, .. } => {
+            Kind::ComponentApplication { inputs, reset, .. } => {
                 inputs.iter().all(|(_, expr)| expr.no_rising_edge())
+                    && reset.as_deref().is_none_or(Self::no_rising_edge)
             }
             Kind::SomeEvent { expr } => expr.no_rising_edge(),
             Kind::NoneEvent => true,
@@ -1149,6 +1222,8 @@ impl Expr {
             stream::Kind::Last { .. } => (),
             stream::Kind::ComponentApplication {
                 called_comp_id,
+                typ_args,
+                static_args,
                 memory_id: comp_memory_id,
                 ..
             } => {
@@ -1158,7 +1233,12 @@ impl Expr {
                 let memory_name =
                     identifier_creator.new_identifier(comp_name.loc(), comp_name.to_string());
                 let memory_id = ctx.insert_fresh_ident(memory_name, Scope::Local, None);
-                memory.add_called_comp(memory_id, *called_comp_id);
+                memory.add_called_comp(
+                    memory_id,
+                    *called_comp_id,
+                    typ_args.clone(),
+                    static_args.clone(),
+                );
                 // put the 'memory_id' of the called component
                 *comp_memory_id = Some(memory_id);
             }
@@ -1292,10 +1372,15 @@ impl Expr {
             stream::Kind::ComponentApplication {
                 called_comp_id,
                 ref mut inputs,
+                ref mut reset,
                 ..
             } => {
                 let (mut new_stmts, mut new_inits) = (vec![], vec![]);
-                for (_, expr) in inputs.iter_mut() {
+                for expr in inputs
+                    .iter_mut()
+                    .map(|(_, expr)| expr)
+                    .chain(reset.iter_mut().map(AsMut::as_mut))
+                {
                     let (add_stmts, add_inits) =
                         expr.into_ident_call(components_reduced_graphs, identifier_creator, ctx);
                     new_stmts.extend(add_stmts);
@@ -1303,27 +1388,13 @@ impl Expr {
                 }
 
                 // change dependencies to be the sum of inputs dependencies
-                let reduced_graph = components_reduced_graphs.get(&called_comp_id).unwrap();
-                self.dependencies = Dependencies::from(
-                    inputs
-                        .iter()
-                        .flat_map(|(input_id, expr)| {
-                            ctx.get_comp_outputs(called_comp_id).iter().flat_map(
-                                |(_, output_id)| {
-                                    reduced_graph
-                                        .edge_weight(*output_id, *input_id)
-                                        .into_iter()
-                                        .flat_map(|label1| {
-                                            expr.get_dependencies()
-                                                .clone()
-                                                .into_iter()
-                                                .map(|(id, label2)| (id, label1.add(&label2)))
-                                        })
-                                },
-                            )
-                        })
-                        .collect(),
-                );
+                self.dependencies = Dependencies::from(comp_app_dependencies(
+                    called_comp_id,
+                    inputs,
+                    reset.as_deref(),
+                    components_reduced_graphs.get(&called_comp_id).unwrap(),
+                    ctx,
+                ));
 
                 // create fresh identifier for the new statement
                 let fresh_name = identifier_creator.fresh_identifier(
@@ -1455,6 +1526,7 @@ impl Expr {
             stream::Kind::ComponentApplication {
                 ref mut memory_id,
                 ref mut inputs,
+                ref mut reset,
                 ..
             } => {
                 // replace the id of the called component
@@ -1477,12 +1549,17 @@ impl Expr {
                 inputs
                     .iter_mut()
                     .for_each(|(_, expr)| expr.replace_by_context(context_map));
+                if let Some(reset) = reset.as_mut() {
+                    reset.replace_by_context(context_map);
+                }
 
                 // change dependencies to be the sum of inputs dependencies
                 self.dependencies = Dependencies::from(
                     inputs
                         .iter()
-                        .flat_map(|(_, expr)| expr.get_dependencies().clone())
+                        .map(|(_, expr)| expr)
+                        .chain(reset.as_deref())
+                        .flat_map(|expr| expr.get_dependencies().clone())
                         .collect(),
                 );
             }
@@ -356,7 +356,7 @@ impl stream::ExprKind {
             | Self::Identifier { .. }
             | Self::Enumeration { .. }
             | Self::Lambda { .. } => (),
-            Self::Application { fun, inputs } => {
+            Self::Application { fun, inputs, .. } => {
                 inputs.iter().for_each(|e| e.get_called_comps(target));
                 fun.get_called_comps(target);
             }
@@ -524,8 +524,9 @@ impl stream::ExprKind {
 
     /// Compute dependencies of an identifier.
     pub fn ident_deps(ctx: &Ctx, id: usize) -> TRes<Vec<(usize, Label)>> {
-        // identifier depends on called identifier with label weight of 0
-        if ctx.is_function(id) {
+        // identifier depends on called identifier with label weight of 0, functions and static
+        // parameters have no dependencies
+        if ctx.is_function(id) || ctx.is_static_param(id) {
             Ok(vec![])
         } else {
             Ok(vec![(id, Label::Weight(0))])
@@ -832,11 +833,14 @@ impl stream::Expr {
             stream::Kind::ComponentApplication {
                 called_comp_id,
                 inputs,
+                reset,
                 ..
             } => {
                 inputs
                     .iter()
-                    .for_each(|(_, expr)| expr.get_called_comps(target));
+                    .map(|(_, expr)| expr)
+                    .chain(reset.as_deref())
+                    .for_each(|expr| expr.get_called_comps(target));
                 target.push(*called_comp_id);
             }
         }
@@ -867,6 +871,7 @@ impl stream::Expr {
             stream::Kind::ComponentApplication {
                 ref called_comp_id,
                 ref inputs,
+                ref reset,
                 ..
             } => {
                 let deps = {
@@ -893,6 +898,12 @@ impl stream::Expr {
                         // get reduced graph (graph with only inputs/outputs idents)
                         let reduced_graph = reduced_graphs.get_mut(called_comp_id).unwrap();
 
+                        // a reset application depends on all its inputs
+                        if reset.is_some() {
+                            vec.extend(input_expression.get_dependencies().iter().cloned());
+                            continue;
+                        }
+
                         // for each component's output, get dependencies from output to inputs
                         for (_, output_ident) in ctx0.get_comp_outputs(*called_comp_id).iter() {
                             if let Some(label1) =
@@ -904,6 +915,11 @@ impl stream::Expr {
                             }
                         }
                     }
+                    // outputs depend on the reset condition
+                    if let Some(reset) = reset {
+                        handle!(reset.compute_dependencies(ctx));
+                        vec.extend(reset.get_dependencies().iter().cloned());
+                    }
                     res?;
                     vec
                 };
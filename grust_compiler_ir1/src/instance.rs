@@ -0,0 +1,271 @@
+//! Template instantiation.
+//!
+//! Templates are functions and components with static parameters, such as
+//! `component filter<const N: int, const K: float>(x: float) -> (y: float)`. They are type-checked
+//! once with their static parameters as symbols, array sizes included. Each template applied with
+//! some static arguments, `filter::<3, 0.5>(x)`, gives an [Instance] that ir2 generates by
+//! binding the static parameters to the arguments with [Ctx::bind_static_params].
+
+prelude! { itertools::Itertools }
+
+/// Instance of a template.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Instance {
+    /// The template's identifier.
+    pub id: usize,
+    /// The values of the template's static parameters.
+    pub args: Vec<Constant>,
+}
+
+impl Instance {
+    /// The instance applied with `static_args`, if any.
+    ///
+    /// Static arguments are evaluated under the current bindings of the static parameters.
+    pub fn of_static_args(static_args: &[(usize, Expr)], ctx: &Ctx) -> Option<Self> {
+        let (param, _) = static_args.first()?;
+        let args = static_args
+            .iter()
+            .map(|(_, arg)| eval(arg, ctx).expect("internal error: unbound static argument"))
+            .collect();
+        Some(Self {
+            id: ctx.get_static_param_template(*param),
+            args,
+        })
+    }
+
+    /// The name of the instance, `filter_3_0p5` for `filter::<3, 0.5>`.
+    ///
+    /// The arguments are in normal form, see [eval]. In the arguments, `-` becomes `m` and `.`
+    /// becomes `p`.
+    pub fn name(&self, ctx: &Ctx) -> Ident {
+        let template = ctx.get_name(self.id);
+        let args = self
+            .args
+            .iter()
+            .map(|arg| {
+                let arg = match arg {
+                    Constant::Integer(i) => i.base10_digits().to_string(),
+                    Constant::Float(f) => f.base10_digits().to_string(),
+                    Constant::Boolean(b) => b.value.to_string(),
+                    // static parameters are of type `int`, `float` or `bool`
+                    Constant::Unit(_) | Constant::Default(_) => noErrorDesc!(),
+                };
+                arg.replace('-', "m").replace('.', "p")
+            })
+            .join("_");
+        Ident::new(&format!("{template}_{args}"), template.span())
+    }
+}
+
+/// Evaluates a static argument under the current bindings of the static parameters.
+///
+/// Static arguments are literals, negated literals or static parameters. The result is in normal
+/// form, so that equal values give the same instance: `1.` and `1.0` both evaluate to `1.0`.
+pub fn eval(arg: &Expr, ctx: &Ctx) -> Option<Constant> {
+    match &arg.kind {
+        expr::Kind::Constant { constant } => normalize(constant),
+        expr::Kind::Identifier { id } => match ctx.try_get_const(*id)? {
+            ir0::Expr::Constant(constant) => normalize(constant),
+            _ => None,
+        },
+        expr::Kind::UnOp { op: UOp::Neg, expr } => match eval(expr, ctx)? {
+            Constant::Integer(i) => {
+                Some(int(i.base10_parse::<i64>().ok()?.checked_neg()?, i.span()))
+            }
+            Constant::Float(f) => Some(float(-f.base10_parse::<f64>().ok()?, f.span())),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The constant with the same value as `constant`, written in normal form.
+fn normalize(constant: &Constant) -> Option<Constant> {
+    match constant {
+        Constant::Integer(i) => Some(int(i.base10_parse().ok()?, i.span())),
+        Constant::Float(f) => Some(float(f.base10_parse().ok()?, f.span())),
+        Constant::Boolean(_) => Some(constant.clone()),
+        Constant::Unit(_) | Constant::Default(_) => None,
+    }
+}
+
+fn int(value: i64, span: Span) -> Constant {
+    Constant::Integer(syn::LitInt::new(&value.to_string(), span))
+}
+
+fn float(value: f64, span: Span) -> Constant {
+    // `-0.0` and `0.0` are the same argument
+    let value = if value == 0.0 { 0.0 } else { value };
+    Constant::Float(syn::LitFloat::new(&format!("{value:?}"), span))
+}
+
+impl File {
+    /// Collects the instances of the templates applied in the program.
+    ///
+    /// The applications of non-template items give the first instances, the applications in
+    /// these instances give the next ones, and so on.
+    pub fn instantiate(&mut self, ctx: &mut Ctx, errors: &mut Vec<Error>) -> TRes<()> {
+        let mut todo = vec![];
+        for function in self.functions.iter() {
+            if !ctx.is_template(function.id) {
+                function.calls(ctx, &mut todo)
+            }
+        }
+        for component in self.components.iter() {
+            if !ctx.is_template(component.get_id()) {
+                component.calls(ctx, &mut todo)
+            }
+        }
+
+        let mut instances: Vec<(Instance, Ident)> = vec![];
+        while let Some(instance) = todo.pop() {
+            if instances.iter().any(|(other, _)| other == &instance) {
+                continue;
+            }
+            let template = ctx.get_name(instance.id).clone();
+            let name = instance.name(ctx);
+            let is_item =
+                ctx.is_comp(&name, false) || ctx.get_function_id(&name, false, &mut vec![]).is_ok();
+            if is_item || instances.iter().any(|(_, other)| other == &name) {
+                bad!(errors, @template.loc() =>
+                    "instance `{}` of template `{}` conflicts with another item", name, template
+                )
+            }
+
+            // collect the applications of the instance
+            ctx.bind_static_params(instance.id, &instance.args, name.clone());
+            if let Some(function) = self.functions.iter().find(|f| f.id == instance.id) {
+                function.calls(ctx, &mut todo)
+            } else if let Some(component) =
+                self.components.iter().find(|c| c.get_id() == instance.id)
+            {
+                component.calls(ctx, &mut todo)
+            }
+            ctx.unbind_static_params();
+
+            instances.push((instance, name));
+        }
+        self.instances = instances
+            .into_iter()
+            .map(|(instance, _)| instance)
+            .collect();
+        Ok(())
+    }
+}
+
+/// Collects the template applications of a construct.
+trait Calls {
+    /// Pushes the instances applied by `self` to `target`.
+    fn calls(&self, ctx: &Ctx, target: &mut Vec<Instance>);
+}
+
+impl Calls for Function {
+    fn calls(&self, ctx: &Ctx, target: &mut Vec<Instance>) {
+        if let Some(body) = self.body_ref() {
+            for stmt in body.statements.iter() {
+                stmt.expr.calls(ctx, target)
+            }
+            body.returned.calls(ctx, target)
+        }
+    }
+}
+
+impl Calls for Component {
+    fn calls(&self, ctx: &Ctx, target: &mut Vec<Instance>) {
+        if let Either::Left(body) = &self.body_or_path {
+            for init in body.inits.iter() {
+                init.expr.calls(ctx, target)
+            }
+            for stmt in body.statements.iter() {
+                stmt.expr.calls(ctx, target)
+            }
+        }
+    }
+}
+
+impl Calls for Expr {
+    fn calls(&self, ctx: &Ctx, target: &mut Vec<Instance>) {
+        self.kind.calls(ctx, target)
+    }
+}
+
+impl Calls for stream::Expr {
+    fn calls(&self, ctx: &Ctx, target: &mut Vec<Instance>) {
+        match &self.kind {
+            stream::Kind::Expression { expr } => expr.calls(ctx, target),
+            stream::Kind::ComponentApplication {
+                static_args,
+                inputs,
+                reset,
+                ..
+            } => {
+                target.extend(Instance::of_static_args(static_args, ctx));
+                for (_, input) in inputs.iter() {
+                    input.calls(ctx, target)
+                }
+                if let Some(reset) = reset {
+                    reset.calls(ctx, target)
+                }
+            }
+            stream::Kind::RisingEdge { expr } | stream::Kind::SomeEvent { expr } => {
+                expr.calls(ctx, target)
+            }
+            stream::Kind::Last { .. } | stream::Kind::NoneEvent => (),
+        }
+    }
+}
+
+impl<E: Calls> Calls for expr::Kind<E> {
+    fn calls(&self, ctx: &Ctx, target: &mut Vec<Instance>) {
+        use expr::Kind::*;
+        match self {
+            Constant { .. } | Identifier { .. } | Enumeration { .. } => (),
+            Application {
+                fun,
+                static_args,
+                inputs,
+            } => {
+                target.extend(Instance::of_static_args(static_args, ctx));
+                fun.calls(ctx, target);
+                inputs.iter().for_each(|input| input.calls(ctx, target))
+            }
+            Lambda { expr, .. } => expr.calls(ctx, target),
+            UnOp { expr, .. }
+            | FieldAccess { expr, .. }
+            | TupleElementAccess { expr, .. }
+            | ArrayAccess { expr, .. } => expr.calls(ctx, target),
+            BinOp { lft, rgt, .. } => {
+                lft.calls(ctx, target);
+                rgt.calls(ctx, target)
+            }
+            IfThenElse { cnd, thn, els } => {
+                cnd.calls(ctx, target);
+                thn.calls(ctx, target);
+                els.calls(ctx, target)
+            }
+            Structure { fields, .. } => fields.iter().for_each(|(_, e)| e.calls(ctx, target)),
+            Array { elements } | Tuple { elements } | Zip { arrays: elements } => {
+                elements.iter().for_each(|e| e.calls(ctx, target))
+            }
+            MatchExpr { expr, arms } => {
+                expr.calls(ctx, target);
+                for (_, guard, stmts, expr) in arms {
+                    if let Some(guard) = guard {
+                        guard.calls(ctx, target)
+                    }
+                    stmts.iter().for_each(|stmt| stmt.expr.calls(ctx, target));
+                    expr.calls(ctx, target)
+                }
+            }
+            Map { expr, fun } | Sort { expr, fun } => {
+                expr.calls(ctx, target);
+                fun.calls(ctx, target)
+            }
+            Fold { array, init, fun } => {
+                array.calls(ctx, target);
+                init.calls(ctx, target);
+                fun.calls(ctx, target)
+            }
+        }
+    }
+}
@@ -0,0 +1,232 @@
+//! Invariant inference.
+//!
+//! Derives candidate invariants of the components' memories from the [range] analysis, which
+//! takes the `requires` clauses as assumptions:
+//!
+//! - bounds of the numerical memories, `invariant { 0 <= last count && last count <= 10 }`;
+//! - elements an enumeration memory never holds, `invariant { last state != State::Off }`;
+//! - bounds of the numerical memories when an enumeration memory holds an element, if they are
+//!   tighter than their bounds in general, `invariant { last state == State::Off => last x == 0 }`.
+//!
+//! The candidates are either added to the components' `invariant` clauses, or reported for the
+//! user to adopt.
+
+prelude! {
+    memory::Buffer,
+    range::Range,
+}
+
+/// Candidate invariant of a component.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Invariant {
+    /// Component's identifier.
+    pub comp_id: usize,
+    /// The invariant term.
+    pub term: contract::Term,
+    /// The invariant, as written in GRust.
+    pub text: String,
+}
+impl Invariant {
+    /// Warning suggesting the invariant to the user.
+    pub fn suggestion(&self, ctx: &Ctx) -> Error {
+        let comp = ctx.get_name(self.comp_id);
+        error!(@comp.loc() =>
+            "inferred invariant for component `{}`: `invariant {{ {} }}`", comp, self.text
+        )
+    }
+}
+
+impl File {
+    /// Infers candidate invariants for the components' memories.
+    pub fn infer_invariants(&self, ctx: &Ctx) -> Vec<Invariant> {
+        let ranges = self.range_analysis(ctx);
+        let mut invariants = vec![];
+        for comp in self.components.iter() {
+            if let (Either::Left(body), Some(ranges)) = (
+                &comp.body_or_path,
+                ranges.component(&ctx.get_name(comp.get_id()).to_string()),
+            ) {
+                body.infer_invariants(comp.get_id(), ranges, ctx, &mut invariants);
+            }
+        }
+        invariants
+    }
+
+    /// Adds invariants to the components' contracts.
+    pub fn add_invariants(&mut self, invariants: Vec<Invariant>) {
+        for invariant in invariants {
+            let body = self
+                .components
+                .iter_mut()
+                .find(|comp| comp.get_id() == invariant.comp_id)
+                .and_then(|comp| comp.body_or_path.as_mut().left());
+            if let Some(body) = body {
+                body.contract.invariant.push(invariant.term);
+            }
+        }
+    }
+}
+
+impl ComponentBody {
+    fn infer_invariants(
+        &self,
+        comp_id: usize,
+        ranges: &range::ComponentRanges,
+        ctx: &Ctx,
+        invariants: &mut Vec<Invariant>,
+    ) {
+        let builder = Builder {
+            loc: ctx.get_name(comp_id).loc(),
+            ctx,
+        };
+        let mut push = |(term, text)| {
+            invariants.push(Invariant {
+                comp_id,
+                term,
+                text,
+            })
+        };
+        let mut buffers = self.memory.buffers.values().collect::<Vec<_>>();
+        buffers.sort_by_key(|buffer| buffer.ident.to_string());
+
+        for buffer in buffers.iter() {
+            if let Some(bounds) = ranges
+                .memory(&buffer.ident.to_string())
+                .and_then(|range| builder.bounds(buffer, range))
+            {
+                push(bounds)
+            }
+        }
+
+        // relations between the enumeration memories and the numerical ones
+        for state in buffers.iter() {
+            let Some(Range::Enum { enum_id, elements }) = ranges.memory(&state.ident.to_string())
+            else {
+                continue;
+            };
+            for elem_id in ctx.get_enum_elements(*enum_id) {
+                if elements.iter().all(|(id, _)| id != elem_id) {
+                    push(builder.holds(state, *enum_id, *elem_id, BOp::Dif))
+                }
+            }
+            let Some(partitions) = self.partitioned_memories(comp_id, &state.ident, ctx) else {
+                continue;
+            };
+            for (elem_id, memories) in partitions {
+                for buffer in buffers.iter() {
+                    let name = buffer.ident.to_string();
+                    let (Some(range), Some(general)) =
+                        (memories.get(&buffer.ident), ranges.memory(&name))
+                    else {
+                        continue;
+                    };
+                    if range == general || &range.join(general) != general {
+                        continue;
+                    }
+                    if let Some(bounds) = builder.bounds(buffer, range) {
+                        let holds = builder.holds(state, *enum_id, elem_id, BOp::Eq);
+                        push(builder.implication(holds, bounds))
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A term and its GRust text.
+type Candidate = (contract::Term, String);
+
+/// Builds the candidate invariants of a component.
+struct Builder<'a> {
+    loc: Loc,
+    ctx: &'a Ctx,
+}
+impl Builder<'_> {
+    fn term(&self, kind: contract::Kind, typ: Typ) -> contract::Term {
+        contract::Term::new(kind, Some(typ), self.loc)
+    }
+
+    /// `last m`
+    fn last(&self, buffer: &Buffer) -> Candidate {
+        let kind = contract::Kind::last(buffer.id, buffer.id);
+        (
+            self.term(kind, buffer.typing.clone()),
+            format!("last {}", buffer.ident),
+        )
+    }
+
+    /// Integer or float literal, negated if negative.
+    fn literal(&self, value: String, typ: Typ) -> Candidate {
+        let (negative, abs) = match value.strip_prefix('-') {
+            Some(abs) => (true, abs.to_string()),
+            None => (false, value.clone()),
+        };
+        let constant = match typ {
+            Typ::Integer(_) => Constant::Integer(syn::LitInt::new(&abs, self.loc.span)),
+            _ => Constant::Float(syn::LitFloat::new(&abs, self.loc.span)),
+        };
+        let mut term = self.term(contract::Kind::constant(constant), typ.clone());
+        if negative {
+            term = self.term(contract::Kind::unary(UOp::Neg, term), typ);
+        }
+        (term, value)
+    }
+
+    fn binary(&self, op: BOp, (left, l): Candidate, (right, r): Candidate) -> Candidate {
+        let kind = contract::Kind::binary(op, left, right);
+        (self.term(kind, Typ::bool()), format!("{l}{op}{r}"))
+    }
+
+    /// `last state == E::elem` or `last state != E::elem`
+    fn holds(&self, state: &Buffer, enum_id: usize, elem_id: usize, op: BOp) -> Candidate {
+        let kind = contract::Kind::enumeration(enum_id, elem_id);
+        let text = format!(
+            "{}::{}",
+            self.ctx.get_name(enum_id),
+            self.ctx.get_name(elem_id)
+        );
+        let element = (self.term(kind, state.typing.clone()), text);
+        self.binary(op, self.last(state), element)
+    }
+
+    /// `P => (Q)`
+    fn implication(&self, (left, l): Candidate, (right, r): Candidate) -> Candidate {
+        let right = self.term(contract::Kind::paren(right), Typ::bool());
+        let kind = contract::Kind::implication(left, right);
+        (self.term(kind, Typ::bool()), format!("{l} => ({r})"))
+    }
+
+    /// Bounds of a numerical memory, if its range is smaller than its type.
+    fn bounds(&self, buffer: &Buffer, range: &Range) -> Option<Candidate> {
+        let (lo, hi, typ) = match range {
+            Range::Int { lo, hi } => (
+                (*lo > i64::MIN as i128).then(|| lo.to_string()),
+                (*hi < i64::MAX as i128).then(|| hi.to_string()),
+                Typ::int(),
+            ),
+            Range::Float { lo, hi } => (
+                lo.is_finite().then(|| format!("{lo:?}")),
+                hi.is_finite().then(|| format!("{hi:?}")),
+                Typ::float(),
+            ),
+            _ => return None,
+        };
+        match (lo, hi) {
+            (Some(lo), Some(hi)) if lo == hi => {
+                Some(self.binary(BOp::Eq, self.last(buffer), self.literal(lo, typ)))
+            }
+            (Some(lo), Some(hi)) => {
+                let lo = self.binary(BOp::Le, self.literal(lo, typ.clone()), self.last(buffer));
+                let hi = self.binary(BOp::Le, self.last(buffer), self.literal(hi, typ));
+                Some(self.binary(BOp::And, lo, hi))
+            }
+            (Some(lo), None) => {
+                Some(self.binary(BOp::Le, self.literal(lo, typ), self.last(buffer)))
+            }
+            (None, Some(hi)) => {
+                Some(self.binary(BOp::Le, self.last(buffer), self.literal(hi, typ)))
+            }
+            (None, None) => None,
+        }
+    }
+}
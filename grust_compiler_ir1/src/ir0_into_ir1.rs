@@ -14,7 +14,12 @@ pub trait Ir0IntoIr1<Ctx> {
 impl Ir0IntoIr1<ctx::Simple<'_>> for Ast {
     type Ir1 = File;
 
-    fn into_ir1(self, ctx: &mut ctx::Simple) -> TRes<Self::Ir1> {
+    fn into_ir1(mut self, ctx: &mut ctx::Simple) -> TRes<Self::Ir1> {
+        // expand multi-instance services
+        self.instantiate_services(ctx.errors)?;
+        // compile temporal contracts into observers, only when contracts are checked
+        let observe = ctx.conf.mode.greusot() || ctx.conf.mode.kani() || ctx.conf.prop_testing();
+        self.observe_temporal_contracts(observe, ctx.errors)?;
         // store elements in symbol table
         self.store(ctx)?;
 
@@ -26,6 +31,7 @@ impl Ir0IntoIr1<ctx::Simple<'_>> for Ast {
             HashMap::with_capacity(20),
             Vec::with_capacity(20),
         );
+        let mut scenarios = vec![];
 
         for item in self.items {
             match item {
@@ -48,6 +54,21 @@ impl Ir0IntoIr1<ctx::Simple<'_>> for Ast {
                 ir0::Item::ExtFun(ext) => functions.push(ext.into_ir1(ctx)?),
                 ir0::Item::ExtComp(extcomp) => components.push(extcomp.into_ir1(ctx)?),
                 ir0::Item::Const(_) => (),
+                ir0::Item::Test(scenario) => scenarios.push(scenario.into_ir1(ctx)?),
+            }
+        }
+
+        // response deadlines relate an imported flow to an exported flow
+        for response in services.iter().flat_map(|service| service.responses.iter()) {
+            if !exports.values().any(|export| export.id == response.output) {
+                bad!(ctx.errors, @response.loc =>
+                    ErrorKind::msg("a response deadline must constrain an exported flow")
+                )
+            }
+            if !imports.values().any(|import| import.id == response.input) {
+                bad!(ctx.errors, @response.loc =>
+                    ErrorKind::msg("a response deadline must be triggered by an imported flow")
+                )
             }
         }
 
@@ -62,6 +83,9 @@ impl Ir0IntoIr1<ctx::Simple<'_>> for Ast {
             functions,
             components,
             interface,
+            scenarios,
+            mutants: vec![],
+            instances: vec![],
             loc: Loc::nu_call_site(),
         })
     }
@@ -72,11 +96,13 @@ mod interface_impl {
         ir0::interface::{
             TimeRange, FlowDeclaration, FlowExport, FlowImport,
             FlowInstantiation, FlowKind, FlowPattern, FlowStatement, Service,
+            InterfaceClause, InterfaceSpec, Rate, Response,
         },
+        ir0::contract::ClauseKind,
             interface::{
                 FlowDeclaration as Ir1FlowDeclaration, FlowExport as Ir1FlowExport,
                 FlowImport as Ir1FlowImport, FlowInstantiation as Ir1FlowInstantiation,
-                FlowStatement as Ir1FlowStatement,
+                FlowStatement as Ir1FlowStatement, InterfaceCheck, ResponseCheck,
             },
     }
 
@@ -91,6 +117,147 @@ mod interface_impl {
         }
     }
 
+    /// Checks that a term only refers to the current value of `flow`.
+    ///
+    /// The term can only refer to `flow`, of identifier `flow_id`, and to the variables of its
+    /// bounded quantifiers.
+    fn check_flow_term(
+        term: &ir1::contract::Term,
+        flow: &Ident,
+        flow_id: usize,
+        bound: &mut Vec<usize>,
+    ) -> Res<()> {
+        use ir1::contract::Kind;
+        match &term.kind {
+            Kind::Constant { .. } | Kind::Enumeration { .. } => Ok(()),
+            Kind::Identifier { id } => {
+                if *id != flow_id && !bound.contains(id) {
+                    bail!(@term.loc => ErrorKind::msg(format!(
+                        "the contract of flow `{flow}` can only refer to `{flow}`"
+                    )))
+                }
+                Ok(())
+            }
+            Kind::Paren { term } | Kind::Unary { term, .. } | Kind::FieldAccess { term, .. } => {
+                check_flow_term(term, flow, flow_id, bound)
+            }
+            Kind::Binary { left, right, .. }
+            | Kind::Implication { left, right }
+            | Kind::ArrayAccess {
+                term: left,
+                index: right,
+            } => {
+                check_flow_term(left, flow, flow_id, bound)?;
+                check_flow_term(right, flow, flow_id, bound)
+            }
+            Kind::Application { inputs, .. } => inputs
+                .iter()
+                .try_for_each(|input| check_flow_term(input, flow, flow_id, bound)),
+            Kind::Bounded {
+                id, lo, hi, term, ..
+            } => {
+                check_flow_term(lo, flow, flow_id, bound)?;
+                check_flow_term(hi, flow, flow_id, bound)?;
+                bound.push(*id);
+                let res = check_flow_term(term, flow, flow_id, bound);
+                bound.pop();
+                res
+            }
+            Kind::Last { .. }
+            | Kind::ForAll { .. }
+            | Kind::Exists { .. }
+            | Kind::PresentEvent { .. }
+            | Kind::ComponentCall { .. } => bail!(@term.loc => ErrorKind::msg(
+                "interface contracts can only check the current value of the flow"
+            )),
+        }
+    }
+
+    /// Runtime checks of the contract of an imported or exported flow.
+    ///
+    /// Value clauses are terms on a local identifier named after the flow, of type `typ`.
+    fn into_checks(
+        contract: Vec<InterfaceClause>,
+        flow: &Ident,
+        typ: &Typ,
+        import: bool,
+        ctx: &mut ctx::Simple<'_>,
+    ) -> TRes<Vec<InterfaceCheck>> {
+        let mut checks = Vec::with_capacity(contract.len());
+        for InterfaceClause { kind, spec, .. } in contract {
+            let loc = spec.loc();
+            match (kind, import) {
+                (ClauseKind::Requires(_), true) | (ClauseKind::Ensures(_), false) => (),
+                (_, true) => bad!(ctx.errors, @loc =>
+                    ErrorKind::msg("imported flows only accept `requires` clauses")
+                ),
+                (_, false) => bad!(ctx.errors, @loc =>
+                    ErrorKind::msg("exported flows only accept `ensures` clauses")
+                ),
+            }
+            let check = match spec {
+                InterfaceSpec::Value(term) => {
+                    ctx.local();
+                    let term = ctx
+                        .ctx0
+                        .insert_local_ident(flow.clone(), Some(typ.clone()), true, ctx.errors)
+                        .and_then(|flow_id| {
+                            let term = term.into_ir1(ctx)?;
+                            check_flow_term(&term, flow, flow_id, &mut vec![])
+                                .dewrap(ctx.errors)?;
+                            Ok(term)
+                        });
+                    ctx.global();
+                    InterfaceCheck::Value(term?)
+                }
+                InterfaceSpec::Rate(Rate { period_ms, .. }) => {
+                    InterfaceCheck::Rate(into_u64(period_ms, ctx)?)
+                }
+                InterfaceSpec::Response(_) => bad!(ctx.errors, @loc =>
+                    ErrorKind::msg("response deadlines are only supported in service contracts")
+                ),
+            };
+            checks.push(check)
+        }
+        Ok(checks)
+    }
+
+    /// Runtime checks of the contract of a service.
+    fn into_responses(
+        contract: Vec<InterfaceClause>,
+        ctx: &mut ctx::Simple<'_>,
+    ) -> TRes<Vec<ResponseCheck>> {
+        let mut responses = Vec::with_capacity(contract.len());
+        for InterfaceClause { kind, spec, .. } in contract {
+            let loc = spec.loc();
+            match (kind, spec) {
+                (
+                    ClauseKind::Ensures(_),
+                    InterfaceSpec::Response(Response {
+                        output,
+                        deadline_ms,
+                        input,
+                        ..
+                    }),
+                ) => {
+                    let output = ctx.ctx0.get_flow_id(&output, false, ctx.errors)?;
+                    let input = ctx.ctx0.get_flow_id(&input, false, ctx.errors)?;
+                    let deadline_ms = into_u64(deadline_ms, ctx)?;
+                    responses.push(ResponseCheck {
+                        loc,
+                        output,
+                        deadline_ms,
+                        input,
+                    })
+                }
+                _ => bad!(ctx.errors, @loc => ErrorKind::msg(
+                    "services only accept `ensures { <export> within <n> ms after <import> }`"
+                )),
+            }
+        }
+        Ok(responses)
+    }
+
     impl<'a> Ir0IntoIr1<ctx::Simple<'a>> for TimeRange {
         type Ir1 = (u64, u64);
 
@@ -121,12 +288,14 @@ mod interface_impl {
                 .collect::<TRes<HashMap<_, _>>>()?;
             let graph = Default::default();
             ctx.global();
+            let responses = into_responses(self.contract, ctx)?;
 
             Ok(ir1::Service {
                 id,
                 time_range,
                 statements,
                 graph,
+                responses,
             })
         }
     }
@@ -140,12 +309,11 @@ mod interface_impl {
             let last = self.typed_path.left.segments.pop().unwrap().into_value();
             assert!(last.arguments.is_none());
             let path = self.typed_path.left;
-            let flow_type = {
-                let inner = self.typed_path.right.into_ir1(&mut ctx.add_loc(loc))?;
-                match self.kind {
-                    FlowKind::Signal(_) => Typ::signal(inner),
-                    FlowKind::Event(_) => Typ::event(inner),
-                }
+            let inner = self.typed_path.right.into_ir1(&mut ctx.add_loc(loc))?;
+            let requires = into_checks(self.contract, &last.ident, &inner, true, ctx)?;
+            let flow_type = match self.kind {
+                FlowKind::Signal(_) => Typ::signal(inner),
+                FlowKind::Event(_) => Typ::event(inner),
             };
             let id = ctx.ctx0.insert_flow(
                 last.ident,
@@ -162,6 +330,7 @@ mod interface_impl {
                 path,
                 colon_token: self.typed_path.colon,
                 flow_type,
+                requires,
                 semi_token: self.semi_token,
             })
         }
@@ -176,12 +345,11 @@ mod interface_impl {
             let last = self.typed_path.left.segments.pop().unwrap().into_value();
             assert!(last.arguments.is_none());
             let path = self.typed_path.left;
-            let flow_type = {
-                let inner = self.typed_path.right.into_ir1(&mut ctx.add_loc(loc))?;
-                match self.kind {
-                    FlowKind::Signal(_) => Typ::signal(inner),
-                    FlowKind::Event(_) => Typ::event(inner),
-                }
+            let inner = self.typed_path.right.into_ir1(&mut ctx.add_loc(loc))?;
+            let ensures = into_checks(self.contract, &last.ident, &inner, false, ctx)?;
+            let flow_type = match self.kind {
+                FlowKind::Signal(_) => Typ::signal(inner),
+                FlowKind::Event(_) => Typ::event(inner),
             };
             let id = ctx.ctx0.insert_flow(
                 last.ident,
@@ -198,6 +366,7 @@ mod interface_impl {
                 path,
                 colon_token: self.typed_path.colon,
                 flow_type,
+                ensures,
                 semi_token: self.semi_token,
             })
         }
@@ -466,6 +635,16 @@ mod interface_impl {
                 if ctx.ctx0.is_comp(&self.ident, false) {
                     // get called component id
                     let comp_id = ctx.ctx0.get_comp_id(&self.ident, false, ctx.errors)?;
+                    if !ctx.get_comp_typ_params(comp_id).is_empty() {
+                        bad!(ctx.errors, @self.ident.loc() =>
+                            "generic component `{}` cannot be called from a service", self.ident
+                        )
+                    }
+                    if ctx.is_template(comp_id) {
+                        bad!(ctx.errors, @self.ident.loc() =>
+                            "template component `{}` cannot be called from a service", self.ident
+                        )
+                    }
 
                     let comp_inputs = ctx.get_comp_inputs(comp_id).clone();
 
@@ -489,6 +668,11 @@ mod interface_impl {
                 } else {
                     // get called function id
                     let function_id = ctx.ctx0.get_function_id(&self.ident, false, ctx.errors)?;
+                    if ctx.is_template(function_id) {
+                        bad!(ctx.errors, @self.ident.loc() =>
+                            "template function `{}` cannot be called from a service", self.ident
+                        )
+                    }
 
                     let function_inputs = ctx.get_function_input(function_id).clone();
 
@@ -688,6 +872,109 @@ impl Ir0IntoIr1<ctx::Simple<'_>> for ir0::Component {
     }
 }
 
+impl Ir0IntoIr1<ctx::Simple<'_>> for ir0::Scenario {
+    type Ir1 = ir1::Scenario;
+
+    // pre-condition: the component is already stored in symbol table
+    // post-condition: construct [ir1] scenario, giving a value to every input at each step
+    fn into_ir1(self, ctx: &mut ctx::Simple) -> TRes<Self::Ir1> {
+        let loc = self.loc();
+        let comp_id = ctx.ctx0.get_comp_id(&self.comp, false, ctx.errors)?;
+        if ctx.try_get_comp_path(comp_id).is_some() {
+            bad!(ctx.errors, @self.comp.loc() =>
+                "cannot test the external component `{}`", self.comp
+            )
+        }
+        if !ctx.get_comp_typ_params(comp_id).is_empty() {
+            bad!(ctx.errors, @self.comp.loc() =>
+                "cannot test the generic component `{}`", self.comp
+            )
+        }
+        if ctx.is_template(comp_id) {
+            bad!(ctx.errors, @self.comp.loc() =>
+                "cannot test the template component `{}`", self.comp
+            )
+        }
+        let inputs = ctx.get_comp_inputs(comp_id).clone();
+        let outputs = ctx.get_comp_outputs(comp_id).clone();
+
+        // signals keep their value from a step to the next
+        let mut signals: HashMap<usize, scenario::Value> = HashMap::new();
+        let mut steps = Vec::with_capacity(self.steps.len());
+        for (idx, step) in self.steps.into_iter().enumerate() {
+            let step_loc = step.loc();
+            let mut given = HashMap::new();
+            for assign in step.inputs {
+                let Some(id) = inputs
+                    .iter()
+                    .find(|id| ctx.get_name(**id) == &assign.ident)
+                    .copied()
+                else {
+                    bad!(ctx.errors, @assign.ident.loc() =>
+                        "component `{}` has no input `{}`", self.comp, assign.ident
+                    )
+                };
+                let value = assign.value.into_ir1(ctx)?;
+                if given.insert(id, value).is_some() {
+                    bad!(ctx.errors, @assign.ident.loc() =>
+                        "input `{}` is given twice", assign.ident
+                    )
+                }
+            }
+            let mut step_inputs = Vec::with_capacity(inputs.len());
+            for id in inputs.iter() {
+                let value = match given.remove(id) {
+                    Some(value) => value,
+                    None if ctx.get_typ(*id).is_event() => scenario::Value::None,
+                    None => match signals.get(id) {
+                        Some(value) => value.clone(),
+                        None => bad!(ctx.errors, @step_loc =>
+                            "signal `{}` has no value at step {idx}", ctx.get_name(*id)
+                        ),
+                    },
+                };
+                if !ctx.get_typ(*id).is_event() {
+                    signals.insert(*id, value.clone());
+                }
+                step_inputs.push((*id, value));
+            }
+
+            let mut expect = vec![];
+            for assign in step.expect.into_iter().flat_map(|(_, _, assigns)| assigns) {
+                let Some((_, id)) = outputs
+                    .iter()
+                    .find(|(_, id)| ctx.get_name(*id) == &assign.ident)
+                else {
+                    bad!(ctx.errors, @assign.ident.loc() =>
+                        "component `{}` has no output `{}`", self.comp, assign.ident
+                    )
+                };
+                expect.push((*id, assign.value.into_ir1(ctx)?));
+            }
+            steps.push(scenario::Step::new(step_inputs, expect, step_loc));
+        }
+
+        Ok(ir1::Scenario::new(self.ident, comp_id, steps, loc))
+    }
+}
+
+impl Ir0IntoIr1<ctx::Simple<'_>> for ir0::scenario::Value {
+    type Ir1 = scenario::Value;
+
+    fn into_ir1(self, ctx: &mut ctx::Simple) -> TRes<Self::Ir1> {
+        let loc = self.loc();
+        match self {
+            Self::Some { expr, .. } => Ok(scenario::Value::Some(
+                expr.into_ir1(&mut ctx.add_pat_loc(None, loc))?,
+            )),
+            Self::None { .. } => Ok(scenario::Value::None),
+            Self::Expr(expr) => Ok(scenario::Value::Expr(
+                expr.into_ir1(&mut ctx.add_pat_loc(None, loc))?,
+            )),
+        }
+    }
+}
+
 impl Ir0IntoIr1<ctx::Simple<'_>> for ir0::Contract {
     type Ir1 = Contract;
 
@@ -749,6 +1036,11 @@ impl<'a> Ir0IntoIr1<ctx::Simple<'a>> for ir0::contract::Term {
             }
             Term::Application(app) if ctx.is_comp(&app.fun, false) => {
                 let called_comp_id = ctx.ctx0.get_comp_id(&app.fun, false, ctx.errors)?;
+                if ctx.is_template(called_comp_id) {
+                    bad!(ctx.errors, @app.fun.loc() =>
+                        "template component `{}` cannot be called in a contract", app.fun
+                    )
+                }
                 let comp_symbol = ctx
                     .get_symbol(called_comp_id)
                     .expect("internal error: there should be a symbol")
@@ -787,6 +1079,11 @@ impl<'a> Ir0IntoIr1<ctx::Simple<'a>> for ir0::contract::Term {
             }
             Term::Application(app) => {
                 let fun_id = ctx.ctx0.get_function_id(&app.fun, false, ctx.errors)?;
+                if ctx.is_template(fun_id) {
+                    bad!(ctx.errors, @app.fun.loc() =>
+                        "template function `{}` cannot be applied in a contract", app.fun
+                    )
+                }
                 let inputs = res_vec!(
                     app.inputs.len(),
                     app.inputs.into_iter().map(|term| term.into_ir1(ctx))
@@ -872,6 +1169,67 @@ impl<'a> Ir0IntoIr1<ctx::Simple<'a>> for ir0::contract::Term {
                     loc,
                 ))
             }
+            Term::Exists(Exists {
+                ident, ty, term, ..
+            }) => {
+                let ty = ty.into_ir1(&mut ctx.add_loc(loc))?;
+                ctx.local();
+                let id = ctx
+                    .ctx0
+                    .insert_local_ident(ident.clone(), Some(ty), true, ctx.errors)?;
+                let term = term.into_ir1(ctx)?;
+                ctx.global();
+                Ok(ir1::contract::Term::new(
+                    ir1::contract::Kind::exists(id, term),
+                    None,
+                    loc,
+                ))
+            }
+            Term::Bounded(Bounded {
+                quantifier,
+                ident,
+                lo,
+                hi,
+                term,
+                ..
+            }) => {
+                let quantifier = match quantifier {
+                    Quantifier::ForAll(_) => ir1::contract::Quantifier::ForAll,
+                    Quantifier::Exists(_) => ir1::contract::Quantifier::Exists,
+                };
+                let lo = lo.into_ir1(ctx)?;
+                let hi = hi.into_ir1(ctx)?;
+                ctx.local();
+                let id = ctx.ctx0.insert_local_ident(
+                    ident.clone(),
+                    Some(Typ::int()),
+                    true,
+                    ctx.errors,
+                )?;
+                let term = term.into_ir1(ctx)?;
+                ctx.global();
+                Ok(ir1::contract::Term::new(
+                    ir1::contract::Kind::bounded(quantifier, id, lo, hi, term),
+                    None,
+                    loc,
+                ))
+            }
+            Term::ArrayAccess(ArrayAccess { term, index, .. }) => Ok(ir1::contract::Term::new(
+                ir1::contract::Kind::array_access(term.into_ir1(ctx)?, index.into_ir1(ctx)?),
+                None,
+                loc,
+            )),
+            Term::FieldAccess(FieldAccess { term, field, .. }) => Ok(ir1::contract::Term::new(
+                ir1::contract::Kind::field_access(term.into_ir1(ctx)?, field),
+                None,
+                loc,
+            )),
+            // temporal clauses of components are compiled into observers beforehand
+            Term::Once(_) | Term::Historically(_) | Term::Since(_) | Term::Within(_) => {
+                bad!(ctx.errors, @loc =>
+                    "temporal operators are only supported in component contracts"
+                )
+            }
             Term::EventImplication(EventImplication {
                 pattern,
                 event,
@@ -1448,8 +1806,9 @@ where
     fn into_ir1(self, ctx: &mut ir1::ctx::PatLoc<'a>) -> TRes<expr::Kind<E::Ir1>> {
         // pre-condition: identifiers are stored in symbol table
         // post-condition: construct [ir1] expression kind and check identifiers good use
-        Ok(expr::Kind::binop(
+        Ok(expr::Kind::arith_binop(
             self.op,
+            self.arith,
             self.lft.into_ir1(ctx)?,
             self.rgt.into_ir1(ctx)?,
         ))
@@ -1474,18 +1833,31 @@ where
     }
 }
 
-impl<'a, E> Ir0IntoIr1<ir1::ctx::PatLoc<'a>> for ir0::expr::Application<E>
-where
-    E: Ir0IntoIr1<ir1::ctx::PatLoc<'a>>,
-{
-    type Ir1 = expr::Kind<E::Ir1>;
+impl<'a> Ir0IntoIr1<ir1::ctx::PatLoc<'a>> for ir0::expr::Application<ir0::Expr> {
+    type Ir1 = expr::Kind<ir1::Expr>;
 
     /// Transforms AST into [ir1] and check identifiers good use.
-    fn into_ir1(self, ctx: &mut ir1::ctx::PatLoc<'a>) -> TRes<expr::Kind<E::Ir1>> {
+    fn into_ir1(self, ctx: &mut ir1::ctx::PatLoc<'a>) -> TRes<expr::Kind<ir1::Expr>> {
         // pre-condition: identifiers are stored in symbol table
         // post-condition: construct [ir1] expression kind and check identifiers good use
-        Ok(expr::Kind::app(
-            self.fun.into_ir1(ctx)?,
+        let template = match &*self.fun {
+            ir0::Expr::Identifier(name) => template_id(name, ctx),
+            _ => None,
+        };
+        let static_args = static_args_into_ir1(template, self.static_args, ctx)?;
+        let fun = match template {
+            // templates cannot be used as values, build the identifier directly
+            Some(id) => ir1::Expr {
+                kind: expr::Kind::ident(id),
+                typing: None,
+                loc: ctx.loc,
+                dependencies: Dependencies::new(),
+            },
+            None => self.fun.into_ir1(ctx)?,
+        };
+        Ok(expr::Kind::static_app(
+            fun,
+            static_args,
             res_vec!(
                 self.inputs.len(),
                 self.inputs.into_iter().map(|input| input.into_ir1(ctx)),
@@ -1494,6 +1866,51 @@ where
     }
 }
 
+/// The template applied by `name`, if any.
+fn template_id(name: &Ident, ctx: &ir1::ctx::PatLoc) -> Option<usize> {
+    let id = if ctx.is_comp(name, false) {
+        ctx.ctx0.get_comp_id(name, false, &mut vec![]).ok()?
+    } else {
+        ctx.ctx0.get_ident(name, false, true, &mut vec![]).ok()?
+    };
+    ctx.is_template(id).then_some(id)
+}
+
+/// Transforms the static arguments of an application, paired with the static parameters of the
+/// applied `template`.
+fn static_args_into_ir1(
+    template: Option<usize>,
+    static_args: Vec<ir0::Expr>,
+    ctx: &mut ir1::ctx::PatLoc,
+) -> TRes<Vec<(usize, ir1::Expr)>> {
+    let Some(template) = template else {
+        if let Some(arg) = static_args.first() {
+            bad!(ctx.errors, @arg.loc() => "only templates take static arguments")
+        }
+        return Ok(vec![]);
+    };
+    let params = ctx.get_static_params(template).clone();
+    if static_args.is_empty() {
+        let name = ctx.get_name(template);
+        bad!(ctx.errors, @ctx.loc =>
+            "template `{}` must be applied with static arguments, as in `{}::<..>(..)`", name, name
+        )
+    }
+    if static_args.len() != params.len() {
+        bad!(ctx.errors, @ctx.loc =>
+            "template `{}` expects {} static argument{}, got {}",
+            ctx.get_name(template), params.len(), plural(params.len()), static_args.len()
+        )
+    }
+    Ok(res_vec!(
+        params.len(),
+        params
+            .into_iter()
+            .zip(static_args)
+            .map(|(param, arg)| Ok((param, arg.into_ir1(ctx)?))),
+    ))
+}
+
 impl<'a, E> Ir0IntoIr1<ir1::ctx::PatLoc<'a>> for ir0::expr::Structure<E>
 where
     E: Ir0IntoIr1<ir1::ctx::PatLoc<'a>>,
@@ -1797,8 +2214,14 @@ mod simple_expr_impl {
             use ir0::Expr::*;
             let kind = match self {
                 Constant(constant) => ir1::expr::Kind::Constant { constant },
-                Identifier(id) => {
-                    let id = ctx.ctx0.get_ident(&id, false, true, ctx.errors)?;
+                Identifier(name) => {
+                    let id = ctx.ctx0.get_ident(&name, false, true, ctx.errors)?;
+                    if ctx.is_template(id) {
+                        bad!(ctx.errors, @name.loc() =>
+                            "template `{}` must be applied with static arguments, as in `{}::<..>(..)`",
+                            name, name
+                        )
+                    }
                     if let Some(value) = ctx.ctx0.try_get_const(id) {
                         return value.clone().into_ir1(ctx);
                     } else {
@@ -1922,6 +2345,7 @@ mod expr_pattern_impl {
 
         fn into_ir1(self, ctx: &mut ir1::ctx::WithLoc) -> TRes<Self::Ir1> {
             use ir0::expr::Pattern::*;
+            let loc = self.loc();
             let kind = match self {
                 Constant(constant) => ir1::pattern::Kind::Constant { constant },
                 Identifier(name) => {
@@ -1938,7 +2362,7 @@ mod expr_pattern_impl {
             Ok(ir1::Pattern {
                 kind,
                 typing: None,
-                loc: ctx.loc,
+                loc,
             })
         }
     }
@@ -2359,8 +2783,14 @@ mod stream_impl {
                                     ))
                                 }
 
-                                Kind::call(
+                                let template =
+                                    ctx.is_template(called_comp_id).then_some(called_comp_id);
+                                let static_args =
+                                    super::static_args_into_ir1(template, app.static_args, ctx)?;
+
+                                Kind::static_call(
                                     called_comp_id,
+                                    static_args,
                                     res_vec!(
                                         app.inputs.len(),
                                         app.inputs
@@ -2379,15 +2809,34 @@ mod stream_impl {
                             }
                         }
                     }
-                    fun => Kind::expr(ir1::expr::Kind::app(
-                        fun.into_ir1(ctx)?,
-                        res_vec!(
-                            app.inputs.len(),
-                            app.inputs
-                                .into_iter()
-                                .map(|input| input.clone().into_ir1(ctx)),
-                        ),
-                    )),
+                    fun => {
+                        let template = match &fun {
+                            stream::Expr::Identifier(name) => super::template_id(name, ctx),
+                            _ => None,
+                        };
+                        let static_args =
+                            super::static_args_into_ir1(template, app.static_args, ctx)?;
+                        let fun = match template {
+                            // templates cannot be used as values, build the identifier directly
+                            Some(id) => ir1::stream::Expr {
+                                kind: Kind::expr(ir1::expr::Kind::ident(id)),
+                                typ: None,
+                                loc: ctx.loc,
+                                dependencies: ir1::Dependencies::new(),
+                            },
+                            None => fun.into_ir1(ctx)?,
+                        };
+                        Kind::expr(ir1::expr::Kind::static_app(
+                            fun,
+                            static_args,
+                            res_vec!(
+                                app.inputs.len(),
+                                app.inputs
+                                    .into_iter()
+                                    .map(|input| input.clone().into_ir1(ctx)),
+                            ),
+                        ))
+                    }
                 },
                 stream::Expr::Last(last) => {
                     let init_id = ctx.ctx0.get_init_id(&last.ident, false, ctx.errors)?;
@@ -2395,11 +2844,38 @@ mod stream_impl {
                     Kind::last(init_id, ident_id)
                 }
                 stream::Expr::Emit(emit) => Kind::some_event(emit.expr.into_ir1(ctx)?),
+                stream::Expr::Every(every) => {
+                    let is_comp_app = matches!(
+                        &*every.app.fun,
+                        stream::Expr::Identifier(component) if ctx.is_comp(component, false)
+                    );
+                    if !is_comp_app {
+                        bad!(ctx.errors, @every.app.fun.loc() => ErrorKind::msg(
+                            "only component applications can be reset with `every`"
+                        ))
+                    }
+                    let reset = every.reset.into_ir1(ctx)?;
+                    let mut kind = stream::Expr::Application(every.app).into_ir1(ctx)?.kind;
+                    if let Kind::ComponentApplication {
+                        reset: ref mut reset_opt,
+                        ..
+                    } = kind
+                    {
+                        *reset_opt = Some(Box::new(reset));
+                    }
+                    kind
+                }
                 stream::Expr::Constant(constant) => Kind::Expression {
                     expr: ir1::expr::Kind::Constant { constant },
                 },
-                stream::Expr::Identifier(id) => {
-                    let id = ctx.ctx0.get_ident(&id, false, true, ctx.errors)?;
+                stream::Expr::Identifier(name) => {
+                    let id = ctx.ctx0.get_ident(&name, false, true, ctx.errors)?;
+                    if ctx.is_template(id) {
+                        bad!(ctx.errors, @name.loc() =>
+                            "template `{}` must be applied with static arguments, as in `{}::<..>(..)`",
+                            name, name
+                        )
+                    }
                     if let Some(value) = ctx.ctx0.try_get_const(id) {
                         let expr: ir0::stream::Expr =
                             value.clone().try_into().dewrap(ctx.errors)?;
@@ -2410,7 +2886,16 @@ mod stream_impl {
                 }
                 stream::Expr::UnOp(expr) => Kind::expr(expr.into_ir1(ctx)?),
                 stream::Expr::BinOp(expr) => Kind::expr(expr.into_ir1(ctx)?),
-                stream::Expr::IfThenElse(expr) => Kind::expr(expr.into_ir1(ctx)?),
+                stream::Expr::IfThenElse(expr) => {
+                    // branches keep their own location, their coverage probes report it
+                    let (thn_loc, els_loc) = (expr.thn.loc(), expr.els.loc());
+                    let mut kind = expr.into_ir1(ctx)?;
+                    if let ir1::expr::Kind::IfThenElse { thn, els, .. } = &mut kind {
+                        thn.loc = thn_loc;
+                        els.loc = els_loc;
+                    }
+                    Kind::expr(kind)
+                }
                 stream::Expr::Lambda(expr) => Kind::expr(expr.into_ir1(ctx)?),
                 stream::Expr::Structure(expr) => Kind::expr(expr.into_ir1(ctx)?),
                 stream::Expr::Tuple(expr) => Kind::expr(expr.into_ir1(ctx)?),
@@ -2474,7 +2959,7 @@ impl Ir0IntoIr1<ir1::ctx::WithLoc<'_>> for Typ {
                     bracket_token,
                     ty: Box::new(ty.into_ir1(ctx)?),
                     semi_token,
-                    size
+                    size: size.into_ir1(ctx)?,
                 }),
                 Typ::Tuple { paren_token, elements } => Ok(Typ::Tuple {
                     paren_token,
@@ -2486,6 +2971,9 @@ impl Ir0IntoIr1<ir1::ctx::WithLoc<'_>> for Typ {
                             Ok(Pair::new(ty, comma))
                         }).collect::<TRes<_>>()?
                 }),
+                Typ::NotDefinedYet(name) if ctx.get_typ_param(&name).is_some() => {
+                    Ok(ctx.get_typ_param(&name).cloned().unwrap())
+                }
                 Typ::NotDefinedYet(name) => ctx
                     .get_struct_id(&name, false, ctx.loc, &mut vec![])
                     .map(|id| Typ::structure(name.clone(), id))
@@ -2525,11 +3013,41 @@ impl Ir0IntoIr1<ir1::ctx::WithLoc<'_>> for Typ {
                 | Typ::Structure { .. }    // no structure at this time: they are `NotDefinedYet`
                 | Typ::Any                 // users cannot write `Any` type
                 | Typ::Polymorphism(_)     // users cannot write `Polymorphism` type
+                | Typ::Param { .. }        // no parameter at this time: they are `NotDefinedYet`
                  => noErrorDesc!(),
             }
     }
 }
 
+impl Ir0IntoIr1<ir1::ctx::WithLoc<'_>> for Size {
+    type Ir1 = Size;
+
+    /// Checks that a size parameter is an integer static parameter or constant.
+    fn into_ir1(self, ctx: &mut ir1::ctx::WithLoc) -> TRes<Size> {
+        match self {
+            Size::Lit(_) => Ok(self),
+            Size::Param(name) => {
+                let id = ctx.ctx0.get_identifier_id(&name, false, ctx.errors)?;
+                if ctx.is_static_param(id) {
+                    match ctx.get_typ(id) {
+                        Typ::Integer(_) => Ok(Size::Param(name)),
+                        typ => bad!(ctx.errors, @name.loc() =>
+                            ErrorKind::incompatible_types(typ.clone(), Typ::int())
+                        ),
+                    }
+                } else {
+                    match ctx.try_get_const(id) {
+                        Some(ir0::Expr::Constant(Constant::Integer(size))) => {
+                            Ok(Size::Lit(size.clone()))
+                        }
+                        _ => bad!(ctx.errors, @name.loc() => ErrorKind::expected_constant()),
+                    }
+                }
+            }
+        }
+    }
+}
+
 impl Ir0IntoIr1<ir1::ctx::Simple<'_>> for ir0::Typedef {
     type Ir1 = ir1::Typedef;
 
@@ -11,6 +11,10 @@ impl Ir0Store for Component {
         let ctx = &mut ctx.add_loc(loc);
 
         ctx.local();
+        ctx.ctx0.set_typ_params(self.typ_params.clone());
+
+        // store static parameters and get their ids
+        let static_params = store_static_params(&self.static_params, ctx)?;
 
         // store input idents and get their ids
         let inputs = self
@@ -82,6 +86,8 @@ impl Ir0Store for Component {
 
         let _ = ctx.ctx0.insert_comp(
             self.ident.clone(),
+            self.typ_params.clone(),
+            static_params,
             (inputs, outputs),
             Some((locals, inits)),
             None,
@@ -93,6 +99,17 @@ impl Ir0Store for Component {
     }
 }
 
+/// Stores the static parameters of a template in the local context and returns their ids.
+fn store_static_params(static_params: &[(Ident, Typ)], ctx: &mut ctx::WithLoc) -> TRes<Vec<usize>> {
+    static_params
+        .iter()
+        .map(|(ident, typ)| {
+            ctx.ctx0
+                .insert_static_param(ident.clone(), typ.clone(), ctx.errors)
+        })
+        .collect::<TRes<Vec<_>>>()
+}
+
 pub trait Ir0StoreIdents {
     /// Creates identifiers for the equation (depending on the config `store_outputs`)
     ///
@@ -431,7 +448,8 @@ impl Ir0Store for Ast {
                 ir0::Item::Typedef(_) // already stored
                 | ir0::Item::Service(_)
                 | ir0::Item::Import(_)
-                | ir0::Item::Export(_) => Ok(()),
+                | ir0::Item::Export(_)
+                | ir0::Item::Test(_) => Ok(()),
             })
             .collect::<TRes<Vec<_>>>()?;
         Ok(())
@@ -443,6 +461,9 @@ impl Ir0Store for ir0::Function {
         let loc = self.loc();
         let ctx = &mut ctx.add_loc(loc);
         ctx.local();
+        ctx.ctx0.set_typ_params(self.typ_params.clone());
+
+        let static_params = store_static_params(&self.static_params, ctx)?;
 
         let inputs = self
             .args
@@ -464,9 +485,15 @@ impl Ir0Store for ir0::Function {
 
         ctx.global();
 
-        let _ =
-            ctx.ctx0
-                .insert_function(self.ident.clone(), inputs, None, self.weight, ctx.errors)?;
+        let _ = ctx.ctx0.insert_function(
+            self.ident.clone(),
+            self.typ_params.clone(),
+            static_params,
+            inputs,
+            None,
+            self.weight,
+            ctx.errors,
+        )?;
 
         Ok(())
     }
@@ -500,6 +527,8 @@ impl Ir0Store for ir0::ExtFunDecl {
 
         let _ = ctx.ctx0.insert_function(
             self.ident.clone(),
+            vec![],
+            vec![],
             inputs,
             Some(self.path.clone()),
             self.weight,
@@ -556,6 +585,8 @@ impl Ir0Store for ir0::ExtCompDecl {
 
         let _ = ctx.ctx0.insert_comp(
             self.ident.clone(),
+            vec![],
+            vec![],
             (inputs, outputs),
             None,
             Some(self.path.clone()),
@@ -582,6 +613,10 @@ impl Ir0Store for ir0::ConstDecl {
         let loc = self.loc();
         let ctx = &mut ctx.add_loc(loc);
 
+        if self.monotonic {
+            check_monotonic(&self.value, ctx.errors)?;
+        }
+
         let _id = ctx.ctx0.insert_constant(
             self.ident.clone(),
             self.ty.clone(),
@@ -593,6 +628,54 @@ impl Ir0Store for ir0::ConstDecl {
     }
 }
 
+/// Checks that the rows of a `#[monotonic]` constant table are strictly increasing.
+///
+/// Nested arrays are checked row by row, only the innermost arrays must be increasing.
+fn check_monotonic(value: &ir0::Expr, errors: &mut Vec<Error>) -> TRes<()> {
+    /// Numeric value of a literal, possibly negated.
+    fn literal(expr: &ir0::Expr) -> Option<f64> {
+        match expr {
+            ir0::Expr::Constant(Constant::Integer(i)) => {
+                i.base10_parse::<i64>().ok().map(|i| i as f64)
+            }
+            ir0::Expr::Constant(Constant::Float(f)) => f.base10_parse().ok(),
+            ir0::Expr::UnOp(ir0::expr::UnOp {
+                op: UOp::Neg, expr, ..
+            }) => literal(expr).map(|v| -v),
+            _ => None,
+        }
+    }
+
+    let ir0::Expr::Array(array) = value else {
+        bad!(errors, @value.loc() => "monotonic constants must be array literals")
+    };
+    if array
+        .elements
+        .iter()
+        .all(|elem| matches!(elem, ir0::Expr::Array(_)))
+    {
+        for row in array.elements.iter() {
+            check_monotonic(row, errors)?;
+        }
+        return Ok(());
+    }
+
+    let mut last: Option<f64> = None;
+    for elem in array.elements.iter() {
+        let Some(value) = literal(elem) else {
+            bad!(errors, @elem.loc() => "monotonic tables only contain numeric literals")
+        };
+        match last {
+            Some(last) if value <= last => bad!(
+                errors, @elem.loc() =>
+                "monotonic table is not strictly increasing: `{value}` comes after `{last}`"
+            ),
+            _ => last = Some(value),
+        }
+    }
+    Ok(())
+}
+
 pub trait Ir0StorePattern: Sized {
     fn store(&self, ctx: &mut ctx::Simple) -> TRes<Vec<(Ident, usize)>>;
 
@@ -12,6 +12,11 @@ mod ir0_store;
 mod typing;
 mod unused;
 
+pub mod instance;
+pub mod invariant;
+pub mod mutation;
+pub mod range;
+
 pub mod import {
     pub use crate::prelude::ir1::{self, Ir0IntoIr1, Ir0Store, Typing};
 }
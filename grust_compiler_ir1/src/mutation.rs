@@ -0,0 +1,311 @@
+//! Mutation of the components.
+//!
+//! Lists the mutants of the components, small changes of their statements that the tests of the
+//! project should detect:
+//!
+//! - comparison operators are flipped, `x < y` becomes `x >= y`;
+//! - enumeration elements are swapped with the next element of their enumeration;
+//! - `last x` becomes the current value `x`, when `x` does not depend on the statement;
+//! - the guards of the `when` and `match` arms are negated.
+//!
+//! Mutants are identified by their component and their index in it, `counter#2`, and one of them
+//! can be applied to the [File] before its dependency graphs are generated.
+
+prelude! {}
+
+/// A mutant of a component.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mutant {
+    /// Mutant identifier, `comp#idx`.
+    pub id: String,
+    /// Mutated component's identifier.
+    pub comp_id: usize,
+    /// The change, as written in GRust.
+    pub description: String,
+    /// Location of the mutated statement.
+    pub loc: Loc,
+}
+
+impl File {
+    /// Lists the mutants of the components, and applies the `active` one.
+    ///
+    /// Fails if `active` is given by the configuration and is not a mutant of the components.
+    pub fn mutate(&mut self, ctx: &Ctx, errors: &mut Vec<Error>) -> TRes<()> {
+        let active = ctx.conf.active_mutant();
+        let mut mutants = vec![];
+        for comp in self.components.iter_mut() {
+            let comp_id = comp.get_id();
+            if let Either::Left(body) = &mut comp.body_or_path {
+                body.mutate(comp_id, active.as_deref(), ctx, &mut mutants)
+            }
+        }
+        if let Some(mutant) = &ctx.conf.mutant {
+            if mutants.iter().all(|m| m.id != mutant.value()) {
+                bad!(errors, @mutant.span() => "unknown mutant `{}`", mutant.value())
+            }
+        }
+        self.mutants = mutants;
+        Ok(())
+    }
+}
+
+impl ComponentBody {
+    fn mutate(
+        &mut self,
+        comp_id: usize,
+        active: Option<&str>,
+        ctx: &Ctx,
+        mutants: &mut Vec<Mutant>,
+    ) {
+        // identifiers used by the statements defining each identifier, `last` excluded
+        let mut uses = HashMap::new();
+        for stmt in self.statements.iter() {
+            let mut ids = vec![];
+            stmt.expr.uses(&mut ids);
+            for def in stmt.get_identifiers() {
+                uses.insert(def, ids.clone());
+            }
+        }
+        let mut mutator = Mutator {
+            comp_id,
+            active,
+            ctx,
+            uses: &uses,
+            defs: vec![],
+            count: 0,
+            mutants,
+        };
+        for stmt in self.statements.iter_mut() {
+            mutator.defs = stmt.get_identifiers();
+            mutator.expr(&mut stmt.expr)
+        }
+    }
+}
+
+impl stream::Expr {
+    /// Sub-expressions.
+    fn children(&self) -> Vec<&Self> {
+        use expr::Kind::*;
+        match &self.kind {
+            stream::Kind::Expression { expr } => match expr {
+                Constant { .. } | Identifier { .. } | Enumeration { .. } | Lambda { .. } => vec![],
+                UnOp { expr, .. }
+                | FieldAccess { expr, .. }
+                | TupleElementAccess { expr, .. }
+                | ArrayAccess { expr, .. } => vec![expr],
+                BinOp { lft, rgt, .. } => vec![lft, rgt],
+                IfThenElse { cnd, thn, els } => vec![cnd, thn, els],
+                Application { fun, inputs, .. } => std::iter::once(&**fun).chain(inputs).collect(),
+                Structure { fields, .. } => fields.iter().map(|(_, expr)| expr).collect(),
+                Array { elements } | Tuple { elements } | Zip { arrays: elements } => {
+                    elements.iter().collect()
+                }
+                MatchExpr { expr, arms } => {
+                    let mut children = vec![&**expr];
+                    for (_, guard, stmts, expr) in arms {
+                        children.extend(guard);
+                        children.extend(stmts.iter().map(|stmt| &stmt.expr));
+                        children.push(expr)
+                    }
+                    children
+                }
+                Map { expr, fun } | Sort { expr, fun } => vec![expr, fun],
+                Fold { array, init, fun } => vec![array, init, fun],
+            },
+            stream::Kind::Last { .. } | stream::Kind::NoneEvent => vec![],
+            stream::Kind::ComponentApplication { inputs, reset, .. } => inputs
+                .iter()
+                .map(|(_, expr)| expr)
+                .chain(reset.as_deref())
+                .collect(),
+            stream::Kind::RisingEdge { expr } | stream::Kind::SomeEvent { expr } => vec![expr],
+        }
+    }
+
+    /// Mutable sub-expressions, in the order of [`Self::children`].
+    fn children_mut(&mut self) -> Vec<&mut Self> {
+        use expr::Kind::*;
+        match &mut self.kind {
+            stream::Kind::Expression { expr } => match expr {
+                Constant { .. } | Identifier { .. } | Enumeration { .. } | Lambda { .. } => vec![],
+                UnOp { expr, .. }
+                | FieldAccess { expr, .. }
+                | TupleElementAccess { expr, .. }
+                | ArrayAccess { expr, .. } => vec![expr],
+                BinOp { lft, rgt, .. } => vec![lft, rgt],
+                IfThenElse { cnd, thn, els } => vec![cnd, thn, els],
+                Application { fun, inputs, .. } => {
+                    std::iter::once(&mut **fun).chain(inputs).collect()
+                }
+                Structure { fields, .. } => fields.iter_mut().map(|(_, expr)| expr).collect(),
+                Array { elements } | Tuple { elements } | Zip { arrays: elements } => {
+                    elements.iter_mut().collect()
+                }
+                MatchExpr { expr, arms } => {
+                    let mut children = vec![&mut **expr];
+                    for (_, guard, stmts, expr) in arms {
+                        children.extend(guard);
+                        children.extend(stmts.iter_mut().map(|stmt| &mut stmt.expr));
+                        children.push(expr)
+                    }
+                    children
+                }
+                Map { expr, fun } | Sort { expr, fun } => vec![expr, fun],
+                Fold { array, init, fun } => vec![array, init, fun],
+            },
+            stream::Kind::Last { .. } | stream::Kind::NoneEvent => vec![],
+            stream::Kind::ComponentApplication { inputs, reset, .. } => inputs
+                .iter_mut()
+                .map(|(_, expr)| expr)
+                .chain(reset.as_deref_mut())
+                .collect(),
+            stream::Kind::RisingEdge { expr } | stream::Kind::SomeEvent { expr } => vec![expr],
+        }
+    }
+
+    /// Identifiers used by the expression, `last` excluded.
+    fn uses(&self, ids: &mut Vec<usize>) {
+        if let stream::Kind::Expression {
+            expr: expr::Kind::Identifier { id },
+        } = &self.kind
+        {
+            ids.push(*id)
+        }
+        for child in self.children() {
+            child.uses(ids)
+        }
+    }
+}
+
+/// Lists and applies the mutants of a component.
+struct Mutator<'a> {
+    comp_id: usize,
+    /// Identifier of the mutant to apply.
+    active: Option<&'a str>,
+    ctx: &'a Ctx,
+    /// Identifiers used by the statements defining each identifier.
+    uses: &'a HashMap<usize, Vec<usize>>,
+    /// Identifiers defined by the current statement.
+    defs: Vec<usize>,
+    /// Number of mutants of the component so far.
+    count: usize,
+    mutants: &'a mut Vec<Mutant>,
+}
+impl Mutator<'_> {
+    /// Registers a mutant, tells if it is the one to apply.
+    fn mutant(&mut self, description: String, loc: Loc) -> bool {
+        let id = format!("{}#{}", self.ctx.get_name(self.comp_id), self.count);
+        self.count += 1;
+        let apply = self.active == Some(id.as_str());
+        self.mutants.push(Mutant {
+            id,
+            comp_id: self.comp_id,
+            description,
+            loc,
+        });
+        apply
+    }
+
+    /// Tells if `last x` can become `x` in the current statement, which is the case when `x` is
+    /// in scope and does not depend on the identifiers the statement defines.
+    fn is_causal(&self, x: usize) -> bool {
+        if matches!(self.ctx.get_scope(x), Scope::VeryLocal) {
+            return false;
+        }
+        let mut stack = vec![x];
+        let mut visited = HashSet::new();
+        while let Some(id) = stack.pop() {
+            if self.defs.contains(&id) {
+                return false;
+            }
+            if visited.insert(id) {
+                stack.extend(self.uses.get(&id).into_iter().flatten())
+            }
+        }
+        true
+    }
+
+    fn expr(&mut self, expr: &mut stream::Expr) {
+        let loc = expr.loc;
+        match &mut expr.kind {
+            stream::Kind::Last { ident_id, .. } => {
+                let ident_id = *ident_id;
+                let name = self.ctx.get_name(ident_id);
+                if self.is_causal(ident_id)
+                    && self.mutant(format!("replace `last {name}` with `{name}`"), loc)
+                {
+                    expr.kind = stream::Kind::expr(expr::Kind::ident(ident_id))
+                }
+            }
+            stream::Kind::Expression { expr: kind } => match kind {
+                expr::Kind::BinOp { op, .. } => {
+                    if let Some(flipped) = flip(*op) {
+                        let description = format!(
+                            "replace `{}` with `{}`",
+                            op.to_token_stream(),
+                            flipped.to_token_stream()
+                        );
+                        if self.mutant(description, loc) {
+                            *op = flipped
+                        }
+                    }
+                }
+                expr::Kind::Enumeration { enum_id, elem_id } => {
+                    let elements = self.ctx.get_enum_elements(*enum_id);
+                    if elements.len() > 1 {
+                        let idx = elements
+                            .iter()
+                            .position(|id| id == elem_id)
+                            .expect("internal error: element should be in its enumeration");
+                        let other = elements[(idx + 1) % elements.len()];
+                        let name = self.ctx.get_name(*enum_id);
+                        let description = format!(
+                            "replace `{name}::{}` with `{name}::{}`",
+                            self.ctx.get_name(*elem_id),
+                            self.ctx.get_name(other)
+                        );
+                        if self.mutant(description, loc) {
+                            *elem_id = other
+                        }
+                    }
+                }
+                expr::Kind::MatchExpr { arms, .. } => {
+                    for (_, guard, ..) in arms.iter_mut() {
+                        if let Some(guard) = guard {
+                            if self.mutant("negate the guard of an arm".into(), guard.loc) {
+                                negate(guard)
+                            }
+                        }
+                    }
+                }
+                _ => (),
+            },
+            _ => (),
+        }
+        for child in expr.children_mut() {
+            self.expr(child)
+        }
+    }
+}
+
+/// The negation of a comparison operator.
+fn flip(op: BOp) -> Option<BOp> {
+    match op {
+        BOp::Eq => Some(BOp::Dif),
+        BOp::Dif => Some(BOp::Eq),
+        BOp::Ge => Some(BOp::Lt),
+        BOp::Le => Some(BOp::Gt),
+        BOp::Gt => Some(BOp::Le),
+        BOp::Lt => Some(BOp::Ge),
+        _ => None,
+    }
+}
+
+/// Negates a guard.
+fn negate(guard: &mut stream::Expr) {
+    let loc = guard.loc;
+    let typ = guard.typ.clone();
+    let old = std::mem::replace(guard, stream::Expr::new(loc, stream::Kind::none_event()));
+    *guard = stream::Expr::new(loc, stream::Kind::expr(expr::Kind::unop(UOp::Not, old)));
+    guard.typ = typ;
+}
@@ -30,16 +30,21 @@ pub mod ir1 {
             memory::{self, Memory},
             once_cell::OnceCell,
             pattern::{self, Pattern},
+            scenario::{self, Scenario},
             stmt::{self, Stmt},
             stream,
             typedef::{self, Typedef},
         },
         dependencies::DepCtx,
+        instance::{self, Instance},
+        invariant::{self, Invariant},
         ir0_into_ir1::Ir0IntoIr1,
         ir0_store::{
             Ir0Store, Ir0StoreEventPattern, Ir0StoreIdents, Ir0StoreInit, Ir0StorePattern,
             Ir0StoreStmtPattern,
         },
+        mutation::{self, Mutant},
+        range::{self, Ranges},
         typing::Typing,
         unused::Unused,
     };
@@ -0,0 +1,1249 @@
+//! Value-range analysis.
+//!
+//! Abstract interpretation of [ir1] components over the interval domain. Inputs range over their
+//! type unless constrained by `requires` clauses, memories are computed as a fixpoint over the
+//! component's steps, widened to the bounds of their type when they keep growing and narrowed
+//! afterwards. The conditions of `if` expressions comparing a flow or a memory restrict its range
+//! in the branches.
+//!
+//! The analysis warns on:
+//!
+//! - possible integer overflows of `+`, `-`, `*`, `/` and `-x`;
+//! - possible integer divisions by zero, `/` and `%`;
+//! - possible `NaN` sources: `0.0 / 0.0` and the `grust::std::maths` functions called out of
+//!   their domain, such as `sqrt` of negative values.
+//!
+//! Operators following a wrapping or saturating [Arith] policy cannot overflow and their division
+//! by zero is defined, they raise no warning. Array indices are literals, out-of-bound accesses are
+//! rejected at typing.
+
+prelude! {}
+
+use grust_compiler_common::{
+    json::{append_json, begin_json, end_json},
+    serde::ser::{SerializeSeq, SerializeStruct},
+};
+
+const INT_MIN: i128 = i64::MIN as i128;
+const INT_MAX: i128 = i64::MAX as i128;
+
+/// Number of iterations before widening memories.
+const WIDENING_DELAY: usize = 3;
+
+/// Maximal number of narrowing iterations, after the fixpoint.
+const NARROWING_STEPS: usize = 3;
+
+/// Abstract value of a flow.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Range {
+    /// Integer interval, bounds are included.
+    Int { lo: i128, hi: i128 },
+    /// Float interval, bounds are included and can be infinite.
+    Float { lo: f64, hi: f64 },
+    /// Ranges of the elements of a tuple.
+    Tuple(Vec<Range>),
+    /// Range of all the elements of an array.
+    Array(Box<Range>),
+    /// Possible elements of an enumeration, sorted by identifier.
+    Enum {
+        enum_id: usize,
+        elements: Vec<(usize, Ident)>,
+    },
+    /// Non-numerical value.
+    Top,
+}
+mk_new! { impl Range =>
+    Int: int { lo: i128, hi: i128 }
+}
+impl Range {
+    /// Float interval, `NaN` bounds (from `inf - inf` for instance) are unbounded.
+    pub fn float(lo: f64, hi: f64) -> Self {
+        Self::Float {
+            lo: if lo.is_nan() { f64::NEG_INFINITY } else { lo },
+            hi: if hi.is_nan() { f64::INFINITY } else { hi },
+        }
+    }
+
+    /// Integer interval of all `int` values.
+    pub fn int_top() -> Self {
+        Self::int(INT_MIN, INT_MAX)
+    }
+
+    /// Float interval of all `float` values.
+    pub fn float_top() -> Self {
+        Self::float(f64::NEG_INFINITY, f64::INFINITY)
+    }
+
+    /// Range of all the values of a type.
+    pub fn of_typ(typ: &Typ) -> Self {
+        match typ {
+            Typ::Integer(_) => Self::int_top(),
+            Typ::Float(_) => Self::float_top(),
+            Typ::Tuple { elements, .. } => Self::Tuple(elements.iter().map(Self::of_typ).collect()),
+            Typ::Array { ty, .. } => Self::Array(Box::new(Self::of_typ(ty))),
+            _ => Self::Top,
+        }
+    }
+
+    /// Range of a constant.
+    pub fn of_constant(constant: &Constant) -> Self {
+        match constant {
+            Constant::Integer(i) => match i.base10_parse::<i64>() {
+                Ok(i) => Self::int(i as i128, i as i128),
+                Err(_) => Self::int_top(),
+            },
+            Constant::Float(f) => match f.base10_parse::<f64>() {
+                Ok(f) => Self::float(f, f),
+                Err(_) => Self::float_top(),
+            },
+            _ => Self::Top,
+        }
+    }
+
+    /// Smallest range containing both ranges.
+    pub fn join(&self, other: &Self) -> Self {
+        match (self, other) {
+            (Self::Int { lo: l1, hi: h1 }, Self::Int { lo: l2, hi: h2 }) => {
+                Self::int(*l1.min(l2), *h1.max(h2))
+            }
+            (Self::Float { lo: l1, hi: h1 }, Self::Float { lo: l2, hi: h2 }) => {
+                Self::float(l1.min(*l2), h1.max(*h2))
+            }
+            (Self::Tuple(elms1), Self::Tuple(elms2)) if elms1.len() == elms2.len() => Self::Tuple(
+                elms1
+                    .iter()
+                    .zip(elms2)
+                    .map(|(elm1, elm2)| elm1.join(elm2))
+                    .collect(),
+            ),
+            (Self::Array(elm1), Self::Array(elm2)) => Self::Array(Box::new(elm1.join(elm2))),
+            (
+                Self::Enum {
+                    enum_id: id1,
+                    elements: elms1,
+                },
+                Self::Enum {
+                    enum_id: id2,
+                    elements: elms2,
+                },
+            ) if id1 == id2 => {
+                let mut elements = elms1.clone();
+                elements.extend(elms2.iter().cloned());
+                elements.sort_by_key(|(id, _)| *id);
+                elements.dedup_by_key(|(id, _)| *id);
+                Self::Enum {
+                    enum_id: *id1,
+                    elements,
+                }
+            }
+            _ => Self::Top,
+        }
+    }
+
+    /// Widens `self` with `other`, bounds that grow are pushed to the type's bounds.
+    pub fn widen(&self, other: &Self) -> Self {
+        match (self, other) {
+            (Self::Int { lo: l1, hi: h1 }, Self::Int { lo: l2, hi: h2 }) => Self::int(
+                if l2 < l1 { INT_MIN } else { *l1 },
+                if h2 > h1 { INT_MAX } else { *h1 },
+            ),
+            (Self::Float { lo: l1, hi: h1 }, Self::Float { lo: l2, hi: h2 }) => Self::float(
+                if l2 < l1 { f64::NEG_INFINITY } else { *l1 },
+                if h2 > h1 { f64::INFINITY } else { *h1 },
+            ),
+            (Self::Tuple(elms1), Self::Tuple(elms2)) if elms1.len() == elms2.len() => Self::Tuple(
+                elms1
+                    .iter()
+                    .zip(elms2)
+                    .map(|(elm1, elm2)| elm1.widen(elm2))
+                    .collect(),
+            ),
+            (Self::Array(elm1), Self::Array(elm2)) => Self::Array(Box::new(elm1.widen(elm2))),
+            // enumerations are finite
+            (Self::Enum { .. }, Self::Enum { .. }) => self.join(other),
+            _ => Self::Top,
+        }
+    }
+
+    /// Values of the range satisfying `_ op bound`, `None` if there are none.
+    pub fn restrict(&self, op: BOp, bound: &Self) -> Option<Self> {
+        let range = match (self, bound) {
+            (Self::Int { lo, hi }, Self::Int { lo: c_lo, hi: c_hi }) => match op {
+                BOp::Le => Self::int(*lo, (*hi).min(*c_hi)),
+                BOp::Lt => Self::int(*lo, (*hi).min(c_hi - 1)),
+                BOp::Ge => Self::int((*lo).max(*c_lo), *hi),
+                BOp::Gt => Self::int((*lo).max(c_lo + 1), *hi),
+                BOp::Eq => Self::int((*lo).max(*c_lo), (*hi).min(*c_hi)),
+                _ => self.clone(),
+            },
+            (Self::Float { lo, hi }, Self::Float { lo: c_lo, hi: c_hi }) => match op {
+                BOp::Le | BOp::Lt => Self::float(*lo, hi.min(*c_hi)),
+                BOp::Ge | BOp::Gt => Self::float(lo.max(*c_lo), *hi),
+                BOp::Eq => Self::float(lo.max(*c_lo), hi.min(*c_hi)),
+                _ => self.clone(),
+            },
+            (
+                Self::Enum { enum_id, elements },
+                Self::Enum {
+                    elements: bound, ..
+                },
+            ) if bound.len() == 1 => {
+                let elements = elements
+                    .iter()
+                    .filter(|(id, _)| match op {
+                        BOp::Eq => *id == bound[0].0,
+                        BOp::Dif => *id != bound[0].0,
+                        _ => true,
+                    })
+                    .cloned()
+                    .collect::<Vec<_>>();
+                if elements.is_empty() {
+                    return None;
+                }
+                Self::Enum {
+                    enum_id: *enum_id,
+                    elements,
+                }
+            }
+            _ => self.clone(),
+        };
+        match range {
+            Self::Int { lo, hi } if lo > hi => None,
+            Self::Float { lo, hi } if lo > hi => None,
+            range => Some(range),
+        }
+    }
+
+    /// True if the range contains zero.
+    fn has_zero(&self) -> bool {
+        match self {
+            Self::Int { lo, hi } => *lo <= 0 && 0 <= *hi,
+            Self::Float { lo, hi } => *lo <= 0.0 && 0.0 <= *hi,
+            _ => true,
+        }
+    }
+
+    /// Float bounds of a numerical range.
+    fn float_bounds(&self) -> Option<(f64, f64)> {
+        match self {
+            Self::Int { lo, hi } => Some((*lo as f64, *hi as f64)),
+            Self::Float { lo, hi } => Some((*lo, *hi)),
+            _ => None,
+        }
+    }
+}
+impl std::fmt::Display for Range {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Int { lo, hi } => write!(f, "[{lo}, {hi}]"),
+            Self::Float { lo, hi } => write!(f, "[{lo}, {hi}]"),
+            Self::Tuple(elements) => {
+                write!(f, "(")?;
+                for (idx, element) in elements.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ", ")?;
+                    }
+                    element.fmt(f)?;
+                }
+                write!(f, ")")
+            }
+            Self::Array(element) => write!(f, "[{element}; _]"),
+            Self::Enum { elements, .. } => {
+                write!(f, "{{")?;
+                for (idx, (_, element)) in elements.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ", ")?;
+                    }
+                    element.fmt(f)?;
+                }
+                write!(f, "}}")
+            }
+            Self::Top => write!(f, "_"),
+        }
+    }
+}
+
+/// Ranges of the flows and memories of a component.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComponentRanges {
+    /// Component's name.
+    pub name: Ident,
+    /// Ranges of the inputs, outputs and local flows.
+    pub flows: Vec<(Ident, Range)>,
+    /// Ranges of the memories, `last x`.
+    pub memories: Vec<(Ident, Range)>,
+}
+impl ComponentRanges {
+    /// Range of a flow.
+    pub fn flow(&self, name: &str) -> Option<&Range> {
+        self.flows
+            .iter()
+            .find(|(ident, _)| ident == name)
+            .map(|(_, range)| range)
+    }
+
+    /// Range of a memory.
+    pub fn memory(&self, name: &str) -> Option<&Range> {
+        self.memories
+            .iter()
+            .find(|(ident, _)| ident == name)
+            .map(|(_, range)| range)
+    }
+}
+
+/// Result of the range analysis.
+#[derive(Debug, Default)]
+pub struct Ranges {
+    /// Ranges of the components.
+    pub components: Vec<ComponentRanges>,
+    /// Warnings raised by the analysis.
+    pub warnings: Vec<Error>,
+}
+impl Ranges {
+    /// Ranges of a component.
+    pub fn component(&self, name: &str) -> Option<&ComponentRanges> {
+        self.components.iter().find(|comp| comp.name == name)
+    }
+
+    /// Dumps the ranges in a JSON file.
+    pub fn dump<P: AsRef<std::path::Path>>(&self, filepath: P) {
+        begin_json(&filepath);
+        self.components
+            .iter()
+            .for_each(|comp| append_json(&filepath, comp));
+        end_json(&filepath);
+    }
+}
+
+impl File {
+    /// Computes the ranges of the components' flows.
+    pub fn range_analysis(&self, ctx: &Ctx) -> Ranges {
+        let mut ranges = Ranges::default();
+        for comp in self.components.iter() {
+            if let Either::Left(body) = &comp.body_or_path {
+                ranges.components.push(body.range_analysis(
+                    comp.get_id(),
+                    ctx,
+                    &mut ranges.warnings,
+                ));
+            }
+        }
+        ranges
+    }
+}
+
+impl ComponentBody {
+    /// Computes the ranges of the component's flows, fixpoint over the memories.
+    fn range_analysis(
+        &self,
+        comp_id: usize,
+        ctx: &Ctx,
+        warnings: &mut Vec<Error>,
+    ) -> ComponentRanges {
+        let inputs = self.input_env(comp_id, ctx);
+        let inits = self.memory_inits(&inputs);
+
+        let mut memories = inits.clone();
+        let mut iteration = 0;
+        loop {
+            let step = self.step(&inputs, &memories);
+            let next: HashMap<Ident, Range> = inits
+                .iter()
+                .map(|(name, init)| {
+                    let range = init.join(&memories[name]).join(&step[name]);
+                    (name.clone(), range)
+                })
+                .collect();
+            if next == memories {
+                break;
+            }
+            iteration += 1;
+            memories = if iteration > WIDENING_DELAY {
+                next.iter()
+                    .map(|(name, range)| (name.clone(), memories[name].widen(range)))
+                    .collect()
+            } else {
+                next
+            };
+        }
+
+        // narrowing, recovers the bounds lost by widening
+        for _ in 0..NARROWING_STEPS {
+            let step = self.step(&inputs, &memories);
+            let next: HashMap<Ident, Range> = inits
+                .iter()
+                .map(|(name, init)| (name.clone(), init.join(&step[name])))
+                .collect();
+            if next == memories {
+                break;
+            }
+            memories = next;
+        }
+
+        // last pass with warnings, on the stable memories
+        let mut env = inputs;
+        env.memories = memories;
+        env.warnings = Some(vec![]);
+        env.stmts(&self.statements);
+        warnings.extend(env.warnings.take().unwrap_or_default());
+
+        // flows defined in several match arms share their name
+        let mut flows: Vec<(Ident, Range)> = vec![];
+        for (id, range) in env.flows {
+            if ctx.is_codegen(id) {
+                continue;
+            }
+            let name = ctx.get_name(id);
+            match flows.iter_mut().find(|(ident, _)| ident == name) {
+                Some((_, known)) => *known = known.join(&range),
+                None => flows.push((name.clone(), range)),
+            }
+        }
+        flows.sort_by_key(|(name, _)| name.to_string());
+        let mut memories = env.memories.into_iter().collect::<Vec<_>>();
+        memories.sort_by_key(|(name, _)| name.to_string());
+        ComponentRanges {
+            name: ctx.get_name(comp_id).clone(),
+            flows,
+            memories,
+        }
+    }
+
+    /// Ranges of the memories for each element the enumeration memory `state` can hold.
+    ///
+    /// The fixpoint is computed over one abstract memory per element of `state`, so that the
+    /// ranges of the other memories are related to the automaton's state. Returns `None` if the
+    /// values of `state` are not known.
+    pub fn partitioned_memories(
+        &self,
+        comp_id: usize,
+        state: &Ident,
+        ctx: &Ctx,
+    ) -> Option<BTreeMap<usize, HashMap<Ident, Range>>> {
+        let inputs = self.input_env(comp_id, ctx);
+        let mut inits = BTreeMap::new();
+        split(&mut inits, state, self.memory_inits(&inputs))?;
+
+        // steps from all the partitions, added to `next`
+        let steps = |partitions: &BTreeMap<usize, HashMap<Ident, Range>>, mut next| {
+            for memories in partitions.values() {
+                split(&mut next, state, self.step(&inputs, memories))?;
+            }
+            Some(next)
+        };
+
+        let mut partitions = inits.clone();
+        let mut iteration = 0;
+        loop {
+            let next = steps(&partitions, partitions.clone())?;
+            if next == partitions {
+                break;
+            }
+            iteration += 1;
+            partitions = if iteration > WIDENING_DELAY {
+                next.into_iter()
+                    .map(|(element, next)| match partitions.get(&element) {
+                        Some(memories) => {
+                            let widened = next
+                                .iter()
+                                .map(|(name, range)| (name.clone(), memories[name].widen(range)))
+                                .collect();
+                            (element, widened)
+                        }
+                        None => (element, next),
+                    })
+                    .collect()
+            } else {
+                next
+            };
+        }
+
+        // narrowing, recovers the bounds lost by widening
+        for _ in 0..NARROWING_STEPS {
+            let next = steps(&partitions, inits.clone())?;
+            if next == partitions {
+                break;
+            }
+            partitions = next;
+        }
+        Some(partitions)
+    }
+
+    /// Environment of the inputs, restricted by the `requires` clauses.
+    fn input_env<'a>(&self, comp_id: usize, ctx: &'a Ctx) -> Env<'a> {
+        let mut inputs = Env::new(ctx);
+        for id in ctx.get_comp_inputs(comp_id) {
+            inputs.flows.insert(*id, Range::of_typ(ctx.get_typ(*id)));
+        }
+        for term in self.contract.requires.iter() {
+            inputs.assume(term);
+        }
+        inputs
+    }
+
+    /// Initial values of the memories.
+    fn memory_inits(&self, inputs: &Env) -> HashMap<Ident, Range> {
+        self.memory
+            .buffers
+            .iter()
+            .map(|(name, buffer)| (name.clone(), inputs.fork().expr(&buffer.init)))
+            .collect()
+    }
+
+    /// Values of the memories after one step from `memories`.
+    fn step(&self, inputs: &Env, memories: &HashMap<Ident, Range>) -> HashMap<Ident, Range> {
+        let mut env = inputs.fork();
+        env.memories = memories.clone();
+        env.stmts(&self.statements);
+        memories
+            .iter()
+            .map(|(name, memory)| {
+                // flows defined in several match arms share their name
+                let range = env
+                    .flows
+                    .iter()
+                    .filter(|(id, _)| env.ctx.get_name(**id) == name)
+                    .map(|(_, range)| range)
+                    .fold(None, |acc: Option<Range>, range| {
+                        Some(acc.map_or_else(|| range.clone(), |acc| acc.join(range)))
+                    })
+                    .unwrap_or_else(|| memory.clone());
+                (name.clone(), range)
+            })
+            .collect()
+    }
+}
+
+/// Adds memories to the partitions of the elements `state` can hold.
+fn split(
+    partitions: &mut BTreeMap<usize, HashMap<Ident, Range>>,
+    state: &Ident,
+    memories: HashMap<Ident, Range>,
+) -> Option<()> {
+    let Range::Enum { enum_id, elements } = memories.get(state)? else {
+        return None;
+    };
+    for element in elements {
+        let mut memories = memories.clone();
+        let single = Range::Enum {
+            enum_id: *enum_id,
+            elements: vec![element.clone()],
+        };
+        memories.insert(state.clone(), single);
+        match partitions.get_mut(&element.0) {
+            Some(known) => {
+                for (name, range) in known.iter_mut() {
+                    *range = range.join(&memories[name]);
+                }
+            }
+            None => {
+                partitions.insert(element.0, memories);
+            }
+        }
+    }
+    Some(())
+}
+
+/// Abstract environment of a component's step.
+struct Env<'a> {
+    ctx: &'a Ctx,
+    /// Ranges of the flows computed so far.
+    flows: HashMap<usize, Range>,
+    /// Ranges of the memories.
+    memories: HashMap<Ident, Range>,
+    /// Warnings, only raised when `Some(_)`.
+    warnings: Option<Vec<Error>>,
+}
+impl<'a> Env<'a> {
+    fn new(ctx: &'a Ctx) -> Self {
+        Self {
+            ctx,
+            flows: HashMap::new(),
+            memories: HashMap::new(),
+            warnings: None,
+        }
+    }
+
+    /// Copy of the environment that raises no warning.
+    fn fork(&self) -> Self {
+        Self {
+            ctx: self.ctx,
+            flows: self.flows.clone(),
+            memories: self.memories.clone(),
+            warnings: None,
+        }
+    }
+
+    fn warn(&mut self, loc: Loc, msg: String) {
+        if let Some(warnings) = self.warnings.as_mut() {
+            warnings.push(error!(@loc => "{}", msg));
+        }
+    }
+
+    /// Restricts the inputs' ranges with a `requires` clause.
+    fn assume(&mut self, term: &contract::Term) {
+        use contract::Kind;
+        let (op, left, right) = match &term.kind {
+            Kind::Paren { term } => return self.assume(term),
+            Kind::Binary {
+                op: BOp::And,
+                left,
+                right,
+            } => {
+                self.assume(left);
+                self.assume(right);
+                return;
+            }
+            Kind::Binary { op, left, right } => (*op, left, right),
+            _ => return,
+        };
+        // `x op c` or `c op x`
+        let (id, op, bound) = match (identifier(left), identifier(right)) {
+            (Some(id), None) => (id, op, constant(right)),
+            (None, Some(id)) => (id, mirror(op), constant(left)),
+            _ => return,
+        };
+        let (Some(range), Some(bound)) = (self.flows.get_mut(&id), bound) else {
+            return;
+        };
+        if let Some(restricted) = range.restrict(op, &bound) {
+            *range = restricted;
+        }
+    }
+
+    /// Range of `expr` when the condition `cnd` is `holds`, `None` if it cannot be.
+    ///
+    /// Conditions comparing a flow or a memory are used to restrict its range.
+    fn assuming(&mut self, cnd: &stream::Expr, holds: bool, expr: &stream::Expr) -> Option<Range> {
+        let Some((target, op, bound)) = self.comparison(cnd) else {
+            return Some(self.expr(expr));
+        };
+        let op = if holds { op } else { negation(op) };
+        let previous = match &target {
+            Either::Left(id) => self.flows[id].clone(),
+            Either::Right(name) => self.memories[name].clone(),
+        };
+        let restricted = previous.restrict(op, &bound)?;
+        let set = |env: &mut Self, range| match &target {
+            Either::Left(id) => env.flows.insert(*id, range),
+            Either::Right(name) => env.memories.insert(name.clone(), range),
+        };
+        set(self, restricted);
+        let range = self.expr(expr);
+        set(self, previous);
+        Some(range)
+    }
+
+    /// Comparison `x op e` of a flow or memory `x`, with the range of `e`.
+    fn comparison(&mut self, cnd: &stream::Expr) -> Option<(Either<usize, Ident>, BOp, Range)> {
+        let stream::Kind::Expression {
+            expr: expr::Kind::BinOp { op, lft, rgt, .. },
+        } = &cnd.kind
+        else {
+            return None;
+        };
+        if !matches!(
+            op,
+            BOp::Le | BOp::Lt | BOp::Ge | BOp::Gt | BOp::Eq | BOp::Dif
+        ) {
+            return None;
+        }
+        let (target, op, other) = match (self.target(lft), self.target(rgt)) {
+            (Some(target), _) => (target, *op, rgt),
+            (None, Some(target)) => (target, mirror(*op), lft),
+            (None, None) => return None,
+        };
+        // already evaluated with the condition, no warnings
+        let warnings = self.warnings.take();
+        let bound = self.expr(other);
+        self.warnings = warnings;
+        Some((target, op, bound))
+    }
+
+    /// Flow or memory an expression reads, if its range is known.
+    fn target(&self, expr: &stream::Expr) -> Option<Either<usize, Ident>> {
+        match &expr.kind {
+            stream::Kind::Expression {
+                expr: expr::Kind::Identifier { id },
+            } if self.flows.contains_key(id) => Some(Either::Left(*id)),
+            stream::Kind::Last { ident_id, .. } => {
+                let name = self.ctx.get_name(*ident_id);
+                self.memories
+                    .contains_key(name)
+                    .then(|| Either::Right(name.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Binds the ranges of a pattern.
+    fn bind(&mut self, pattern: &stmt::Pattern, range: Range) {
+        match &pattern.kind {
+            stmt::Kind::Identifier { id } | stmt::Kind::Typed { id, .. } => {
+                let _ = self.flows.insert(*id, range);
+            }
+            stmt::Kind::Tuple { elements } => match range {
+                Range::Tuple(ranges) if ranges.len() == elements.len() => {
+                    for (pattern, range) in elements.iter().zip(ranges) {
+                        self.bind(pattern, range)
+                    }
+                }
+                _ => {
+                    for pattern in elements {
+                        for id in pattern.identifiers() {
+                            let range = Range::of_typ(self.ctx.get_typ(id));
+                            let _ = self.flows.insert(id, range);
+                        }
+                    }
+                }
+            },
+        }
+    }
+
+    fn stmts(&mut self, stmts: &[stream::Stmt]) {
+        for stmt in stmts {
+            let range = self.expr(&stmt.expr);
+            self.bind(&stmt.pattern, range);
+        }
+    }
+
+    fn expr(&mut self, expr: &stream::Expr) -> Range {
+        let top = || expr.typ.as_ref().map_or(Range::Top, Range::of_typ);
+        match &expr.kind {
+            stream::Kind::Expression { expr: kind } => self.kind(kind, expr.loc, top),
+            stream::Kind::Last { ident_id, .. } => self
+                .memories
+                .get(self.ctx.get_name(*ident_id))
+                .cloned()
+                .unwrap_or_else(top),
+            stream::Kind::ComponentApplication { inputs, reset, .. } => {
+                for (_, input) in inputs {
+                    self.expr(input);
+                }
+                if let Some(reset) = reset {
+                    self.expr(reset);
+                }
+                top()
+            }
+            stream::Kind::RisingEdge { expr } | stream::Kind::SomeEvent { expr } => {
+                self.expr(expr);
+                top()
+            }
+            stream::Kind::NoneEvent => top(),
+        }
+    }
+
+    fn kind(&mut self, kind: &stream::ExprKind, loc: Loc, top: impl Fn() -> Range) -> Range {
+        use expr::Kind;
+        match kind {
+            Kind::Constant { constant } => Range::of_constant(constant),
+            Kind::Identifier { id } => {
+                if let Some(range) = self.flows.get(id) {
+                    range.clone()
+                } else if let Some(value) = self.ctx.try_get_const(*id) {
+                    ir0_constant(value).unwrap_or_else(top)
+                } else {
+                    top()
+                }
+            }
+            Kind::UnOp { op, expr } => {
+                let range = self.expr(expr);
+                match (op, range) {
+                    (UOp::Neg, Range::Int { lo, hi }) => {
+                        self.int_result(loc, Arith::Native, -hi, -lo, || {
+                            format!("possible integer overflow, `-x` with `x` in `[{lo}, {hi}]`")
+                        })
+                    }
+                    (UOp::Neg, Range::Float { lo, hi }) => Range::float(-hi, -lo),
+                    _ => top(),
+                }
+            }
+            Kind::BinOp {
+                op,
+                arith,
+                lft,
+                rgt,
+            } => {
+                let lft = self.expr(lft);
+                let rgt = self.expr(rgt);
+                let arith = arith.unwrap_or(self.ctx.conf.arith);
+                match (lft, rgt) {
+                    (lft @ Range::Int { .. }, rgt @ Range::Int { .. }) => {
+                        self.int_binop(loc, *op, arith, lft, rgt, top)
+                    }
+                    (lft @ Range::Float { .. }, rgt @ Range::Float { .. }) => {
+                        self.float_binop(loc, *op, lft, rgt, top)
+                    }
+                    _ => top(),
+                }
+            }
+            Kind::IfThenElse { cnd, thn, els } => {
+                self.expr(cnd);
+                let thn = self.assuming(cnd, true, thn);
+                let els = self.assuming(cnd, false, els);
+                match (thn, els) {
+                    (Some(thn), Some(els)) => thn.join(&els),
+                    (Some(range), None) | (None, Some(range)) => range,
+                    (None, None) => top(),
+                }
+            }
+            Kind::Application { fun, inputs, .. } => {
+                let inputs = inputs
+                    .iter()
+                    .map(|input| self.expr(input))
+                    .collect::<Vec<_>>();
+                match &fun.kind {
+                    stream::Kind::Expression {
+                        expr: Kind::Identifier { id },
+                    } => self.application(*id, inputs, loc).unwrap_or_else(top),
+                    _ => top(),
+                }
+            }
+            Kind::Array { elements } => {
+                let mut elements = elements.iter().map(|element| self.expr(element));
+                match elements.next() {
+                    Some(first) => {
+                        Range::Array(Box::new(elements.fold(first, |acc, elm| acc.join(&elm))))
+                    }
+                    None => top(),
+                }
+            }
+            Kind::Tuple { elements } => {
+                Range::Tuple(elements.iter().map(|element| self.expr(element)).collect())
+            }
+            Kind::TupleElementAccess {
+                expr,
+                element_number,
+            } => match self.expr(expr) {
+                Range::Tuple(mut elements) if *element_number < elements.len() => {
+                    elements.swap_remove(*element_number)
+                }
+                _ => top(),
+            },
+            Kind::ArrayAccess { expr, .. } => match self.expr(expr) {
+                Range::Array(element) => *element,
+                _ => top(),
+            },
+            Kind::MatchExpr { expr, arms } => {
+                let matched = self.expr(expr);
+                let matched_id = match &expr.kind {
+                    stream::Kind::Expression {
+                        expr: Kind::Identifier { id },
+                    } => Some(*id),
+                    _ => None,
+                };
+                let mut range: Option<Range> = None;
+                for (pattern, guard, stmts, expr) in arms {
+                    // arms matching an element the enumeration cannot hold are unreachable, in
+                    // the others the matched identifier holds the element
+                    let mut refined = None;
+                    if let (
+                        pattern::Kind::Enumeration { elem_id, .. },
+                        Range::Enum { enum_id, elements },
+                    ) = (&pattern.kind, &matched)
+                    {
+                        let Some(element) = elements.iter().find(|(id, _)| id == elem_id) else {
+                            continue;
+                        };
+                        if let Some(id) = matched_id {
+                            let single = Range::Enum {
+                                enum_id: *enum_id,
+                                elements: vec![element.clone()],
+                            };
+                            refined = Some((id, self.flows.insert(id, single)));
+                        }
+                    }
+                    if let Some(guard) = guard {
+                        self.expr(guard);
+                    }
+                    self.stmts(stmts);
+                    let arm = self.expr(expr);
+                    range = Some(match range {
+                        Some(range) => range.join(&arm),
+                        None => arm,
+                    });
+                    match refined {
+                        Some((id, Some(previous))) => {
+                            self.flows.insert(id, previous);
+                        }
+                        Some((id, None)) => {
+                            self.flows.remove(&id);
+                        }
+                        None => (),
+                    }
+                }
+                range.unwrap_or_else(top)
+            }
+            Kind::Structure { fields, .. } => {
+                for (_, expr) in fields {
+                    self.expr(expr);
+                }
+                top()
+            }
+            Kind::FieldAccess { expr, .. } | Kind::Map { expr, .. } | Kind::Sort { expr, .. } => {
+                self.expr(expr);
+                top()
+            }
+            Kind::Fold { array, init, .. } => {
+                self.expr(array);
+                self.expr(init);
+                top()
+            }
+            Kind::Zip { arrays } => {
+                for array in arrays {
+                    self.expr(array);
+                }
+                top()
+            }
+            Kind::Enumeration { enum_id, elem_id } => Range::Enum {
+                enum_id: *enum_id,
+                elements: vec![(*elem_id, self.ctx.get_name(*elem_id).clone())],
+            },
+            Kind::Lambda { .. } => top(),
+        }
+    }
+
+    /// Integer result of an operation, following the arithmetic policy on overflow.
+    fn int_result(
+        &mut self,
+        loc: Loc,
+        arith: Arith,
+        lo: i128,
+        hi: i128,
+        msg: impl FnOnce() -> String,
+    ) -> Range {
+        if INT_MIN <= lo && hi <= INT_MAX {
+            return Range::int(lo, hi);
+        }
+        match arith {
+            Arith::Saturating => Range::int(lo.clamp(INT_MIN, INT_MAX), hi.clamp(INT_MIN, INT_MAX)),
+            Arith::Wrapping => Range::int_top(),
+            Arith::Native | Arith::Checked => {
+                self.warn(loc, msg());
+                Range::int_top()
+            }
+        }
+    }
+
+    fn int_binop(
+        &mut self,
+        loc: Loc,
+        op: BOp,
+        arith: Arith,
+        lft: Range,
+        rgt: Range,
+        top: impl Fn() -> Range,
+    ) -> Range {
+        let (Range::Int { lo: a, hi: b }, Range::Int { lo: c, hi: d }) = (&lft, &rgt) else {
+            return top();
+        };
+        let (a, b, c, d) = (*a, *b, *c, *d);
+        let overflow = || {
+            format!("possible integer overflow, `x{op}y` with `x` in `{lft}` and `y` in `{rgt}`")
+        };
+        let div_by_zero = || format!("possible division by zero, the divisor ranges over `{rgt}`");
+        match op {
+            BOp::Add => self.int_result(loc, arith, a + c, b + d, overflow),
+            BOp::Sub => self.int_result(loc, arith, a - d, b - c, overflow),
+            BOp::Mul => {
+                let products = [a * c, a * d, b * c, b * d];
+                let lo = *products.iter().min().unwrap();
+                let hi = *products.iter().max().unwrap();
+                self.int_result(loc, arith, lo, hi, overflow)
+            }
+            BOp::Div | BOp::Mod => {
+                let zero = c <= 0 && 0 <= d;
+                if zero && matches!(arith, Arith::Native | Arith::Checked) {
+                    self.warn(loc, div_by_zero());
+                }
+                // non-zero parts of the divisor
+                let divisors = [(c, d.min(-1)), (c.max(1), d)]
+                    .into_iter()
+                    .filter(|(lo, hi)| lo <= hi);
+                let mut range: Option<Range> = None;
+                for (c, d) in divisors {
+                    let part = if op == BOp::Div {
+                        let quotients = [a / c, a / d, b / c, b / d];
+                        let lo = *quotients.iter().min().unwrap();
+                        let hi = *quotients.iter().max().unwrap();
+                        self.int_result(loc, arith, lo, hi, overflow)
+                    } else {
+                        // the remainder has the sign of the dividend and is smaller than the divisor
+                        let m = c.abs().max(d.abs()) - 1;
+                        Range::int(a.max(-m).min(0), b.min(m).max(0))
+                    };
+                    range = Some(range.map_or(part.clone(), |range| range.join(&part)));
+                }
+                // value of `x / 0` and `x % 0`
+                if zero {
+                    let part = match (arith, op) {
+                        (Arith::Wrapping, BOp::Div) => Some(Range::int(0, 0)),
+                        (Arith::Saturating, BOp::Div) => Some(Range::int(
+                            if a < 0 { INT_MIN } else { 0 },
+                            if b > 0 { INT_MAX } else { 0 },
+                        )),
+                        (Arith::Wrapping | Arith::Saturating, _) => Some(lft.clone()),
+                        _ => None,
+                    };
+                    if let Some(part) = part {
+                        range = Some(range.map_or(part.clone(), |range| range.join(&part)));
+                    }
+                }
+                range.unwrap_or_else(Range::int_top)
+            }
+            _ => top(),
+        }
+    }
+
+    fn float_binop(
+        &mut self,
+        loc: Loc,
+        op: BOp,
+        lft: Range,
+        rgt: Range,
+        top: impl Fn() -> Range,
+    ) -> Range {
+        let (Range::Float { lo: a, hi: b }, Range::Float { lo: c, hi: d }) = (&lft, &rgt) else {
+            return top();
+        };
+        let (a, b, c, d) = (*a, *b, *c, *d);
+        // `0 * inf` bounds come from unknown finite values, they are `0`
+        let mul = |x: f64, y: f64| if x == 0.0 || y == 0.0 { 0.0 } else { x * y };
+        let hull = |values: [f64; 4]| {
+            Range::float(
+                values.iter().copied().fold(f64::INFINITY, f64::min),
+                values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+            )
+        };
+        match op {
+            BOp::Add => Range::float(a + c, b + d),
+            BOp::Sub => Range::float(a - d, b - c),
+            BOp::Mul => hull([mul(a, c), mul(a, d), mul(b, c), mul(b, d)]),
+            BOp::Div if rgt.has_zero() => {
+                if lft.has_zero() {
+                    self.warn(
+                        loc,
+                        format!(
+                            "possible NaN, `0.0 / 0.0` with `x / y`, `x` in `{lft}` and `y` in `{rgt}`"
+                        ),
+                    );
+                }
+                Range::float_top()
+            }
+            BOp::Div => hull([a / c, a / d, b / c, b / d]),
+            _ => top(),
+        }
+    }
+
+    /// Ranges of the `grust::std::maths` functions, warns when called out of their domain.
+    fn application(&mut self, fun_id: usize, inputs: Vec<Range>, loc: Loc) -> Option<Range> {
+        let path = self.ctx.try_get_function_path(fun_id)?;
+        let segments = path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect::<Vec<_>>();
+        let segments = segments.iter().map(String::as_str).collect::<Vec<_>>();
+        let ["grust", "std", "maths", _, name] = segments.as_slice() else {
+            return None;
+        };
+        let name = name.to_string();
+        if inputs.is_empty() {
+            return None;
+        }
+        let bounds = inputs
+            .iter()
+            .map(Range::float_bounds)
+            .collect::<Option<Vec<_>>>()?;
+        let mut domain = |lo: f64, hi: f64| {
+            let (x_lo, x_hi) = bounds[0];
+            if x_lo < lo || x_hi > hi {
+                self.warn(
+                    loc,
+                    format!("possible NaN, `{name}` of a value in `{}`", inputs[0]),
+                );
+            }
+        };
+        let pi = std::f64::consts::PI;
+        let (x_lo, x_hi) = bounds[0];
+        let range = match name.as_str() {
+            "sqrt" | "sqrt_i" => {
+                domain(0.0, f64::INFINITY);
+                Range::float(x_lo.max(0.0).sqrt(), x_hi.max(0.0).sqrt())
+            }
+            "ln" | "log10" | "log2" => {
+                domain(0.0, f64::INFINITY);
+                let f = match name.as_str() {
+                    "ln" => f64::ln,
+                    "log10" => f64::log10,
+                    _ => f64::log2,
+                };
+                Range::float(f(x_lo.max(0.0)), f(x_hi.max(0.0)))
+            }
+            "log" => {
+                domain(0.0, f64::INFINITY);
+                Range::float_top()
+            }
+            "acos" => {
+                domain(-1.0, 1.0);
+                Range::float(0.0, pi)
+            }
+            "asin" => {
+                domain(-1.0, 1.0);
+                Range::float(-pi / 2.0, pi / 2.0)
+            }
+            "acosh" => {
+                domain(1.0, f64::INFINITY);
+                Range::float(0.0, f64::INFINITY)
+            }
+            "atanh" => {
+                domain(-1.0, 1.0);
+                Range::float_top()
+            }
+            "cos" | "sin" | "tanh" => Range::float(-1.0, 1.0),
+            "atan" => Range::float(-pi / 2.0, pi / 2.0),
+            "exp" => Range::float(x_lo.exp(), x_hi.exp()),
+            "abs" => {
+                let lo = if x_lo <= 0.0 && 0.0 <= x_hi {
+                    0.0
+                } else {
+                    x_lo.abs().min(x_hi.abs())
+                };
+                Range::float(lo, x_lo.abs().max(x_hi.abs()))
+            }
+            "min" | "max" | "min_i" | "max_i" => {
+                let is_min = name.starts_with("min");
+                // integer bounds are not rounded through `f64`
+                if let (Range::Int { lo: a, hi: b }, Some(Range::Int { lo: c, hi: d })) =
+                    (&inputs[0], inputs.get(1))
+                {
+                    if is_min {
+                        Range::int(*a.min(c), *b.min(d))
+                    } else {
+                        Range::int(*a.max(c), *b.max(d))
+                    }
+                } else {
+                    let (y_lo, y_hi) = *bounds.get(1)?;
+                    if is_min {
+                        Range::float(x_lo.min(y_lo), x_hi.min(y_hi))
+                    } else {
+                        Range::float(x_lo.max(y_lo), x_hi.max(y_hi))
+                    }
+                }
+            }
+            "round" | "floor" | "ceil" if x_lo.is_finite() && x_hi.is_finite() => Range::int(
+                (x_lo.floor() as i128).max(INT_MIN),
+                (x_hi.ceil() as i128).min(INT_MAX),
+            ),
+            _ => return None,
+        };
+        Some(range)
+    }
+}
+
+/// Operator `op'` such that `a op b` is `b op' a`.
+fn mirror(op: BOp) -> BOp {
+    match op {
+        BOp::Le => BOp::Ge,
+        BOp::Lt => BOp::Gt,
+        BOp::Ge => BOp::Le,
+        BOp::Gt => BOp::Lt,
+        op => op,
+    }
+}
+
+/// Operator `op'` such that `a op' b` is `!(a op b)`, on comparisons.
+fn negation(op: BOp) -> BOp {
+    match op {
+        BOp::Le => BOp::Gt,
+        BOp::Lt => BOp::Ge,
+        BOp::Ge => BOp::Lt,
+        BOp::Gt => BOp::Le,
+        BOp::Eq => BOp::Dif,
+        BOp::Dif => BOp::Eq,
+        op => op,
+    }
+}
+
+/// Identifier of a term, if any.
+fn identifier(term: &contract::Term) -> Option<usize> {
+    match &term.kind {
+        contract::Kind::Identifier { id } => Some(*id),
+        contract::Kind::Paren { term } => identifier(term),
+        _ => None,
+    }
+}
+
+/// Range of a constant term, if any.
+fn constant(term: &contract::Term) -> Option<Range> {
+    match &term.kind {
+        contract::Kind::Constant { constant } => Some(Range::of_constant(constant)),
+        contract::Kind::Paren { term } => constant(term),
+        contract::Kind::Unary { op: UOp::Neg, term } => match constant(term)? {
+            Range::Int { lo, hi } => Some(Range::int(-hi, -lo)),
+            Range::Float { lo, hi } => Some(Range::float(-hi, -lo)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Range of a constant's value, if it is a literal or an array of literals.
+fn ir0_constant(value: &ir0::Expr) -> Option<Range> {
+    match value {
+        ir0::Expr::Constant(constant) => Some(Range::of_constant(constant)),
+        ir0::Expr::UnOp(ir0::expr::UnOp {
+            op: UOp::Neg, expr, ..
+        }) => match ir0_constant(expr)? {
+            Range::Int { lo, hi } => Some(Range::int(-hi, -lo)),
+            Range::Float { lo, hi } => Some(Range::float(-hi, -lo)),
+            _ => None,
+        },
+        ir0::Expr::Array(array) => {
+            let mut elements = array.elements.iter().map(ir0_constant);
+            let first = elements.next()??;
+            elements
+                .try_fold(first, |acc, elm| Some(acc.join(&elm?)))
+                .map(|element| Range::Array(Box::new(element)))
+        }
+        _ => None,
+    }
+}
+
+impl Serialize for ComponentRanges {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut comp = serializer.serialize_struct("Component", 3)?;
+        comp.serialize_field("name", &self.name.to_string())?;
+        comp.serialize_field("flows", &SerializeRanges(&self.flows))?;
+        comp.serialize_field("memories", &SerializeRanges(&self.memories))?;
+        comp.end()
+    }
+}
+struct SerializeRanges<'a>(&'a [(Ident, Range)]);
+impl Serialize for SerializeRanges<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for (name, range) in self.0 {
+            seq.serialize_element(&SerializeRange(name, range))?;
+        }
+        seq.end()
+    }
+}
+struct SerializeRange<'a>(&'a Ident, &'a Range);
+impl Serialize for SerializeRange<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut flow = serializer.serialize_struct("Flow", 2)?;
+        flow.serialize_field("name", &self.0.to_string())?;
+        flow.serialize_field("range", &self.1.to_string())?;
+        flow.end()
+    }
+}
@@ -29,10 +29,70 @@ impl Typing for File {
                 stmt.typ_check(symbols, errors)?;
             }
         }
+        let checks = self
+            .interface
+            .imports
+            .values_mut()
+            .flat_map(|import| import.requires.iter_mut())
+            .chain(
+                self.interface
+                    .exports
+                    .values_mut()
+                    .flat_map(|export| export.ensures.iter_mut()),
+            );
+        for check in checks {
+            check.typ_check(symbols, errors)?;
+        }
+        for s in self.scenarios.iter_mut() {
+            s.typ_check(symbols, errors)?;
+        }
         Ok(())
     }
 }
 
+impl Typing for Scenario {
+    fn typ_check(&mut self, symbols: &mut Ctx, errors: &mut Vec<Error>) -> TRes<()> {
+        for step in self.steps.iter_mut() {
+            for (id, value) in step.inputs.iter_mut().chain(step.expect.iter_mut()) {
+                value.typ_check(*id, step.loc, symbols, errors)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl scenario::Value {
+    /// Tries to type the value of the input or output `id`.
+    fn typ_check(
+        &mut self,
+        id: usize,
+        loc: Loc,
+        symbols: &mut Ctx,
+        errors: &mut Vec<Error>,
+    ) -> TRes<()> {
+        let expected = symbols.get_typ(id).clone();
+        let (expr, expected) = match (self, &expected) {
+            (Self::Some(expr), Typ::Option { ty, .. }) => (expr, ty.as_ref()),
+            (Self::None, Typ::Option { .. }) => return Ok(()),
+            (Self::Expr(expr), typ) if !typ.is_event() => (expr, typ),
+            (Self::Expr(expr), _) => bad!(errors, @expr.loc =>
+                "`{}` is an event, expected `some(..)` or `none`", symbols.get_name(id)
+            ),
+            (Self::Some(expr), _) => bad!(errors, @expr.loc =>
+                "`{}` is not an event, `some(..)` does not apply", symbols.get_name(id)
+            ),
+            (Self::None, _) => bad!(errors, @loc =>
+                "`{}` is not an event, `none` does not apply", symbols.get_name(id)
+            ),
+        };
+        expr.typ_check(symbols, errors)?;
+        expr.get_typ()
+            .expect("internal error: expression should be typed")
+            .expect(expr.loc, expected)
+            .dewrap(errors)
+    }
+}
+
 impl Typing for Function {
     fn typ_check(&mut self, symbols: &mut Ctx, errors: &mut Vec<Error>) -> TRes<()> {
         if let Either::Left(body) = self.body_or_path.as_mut() {
@@ -122,12 +182,38 @@ impl Typing for contract::Term {
                 let mut binop_type = op.get_typ();
                 binop_type.apply(vec![left_type, right_type], self.loc, errors)?
             }
-            contract::Kind::ForAll { term, .. } => {
+            contract::Kind::ForAll { term, .. } | contract::Kind::Exists { term, .. } => {
                 term.typ_check(symbols, errors)?;
                 let ty = term.typing.as_ref().unwrap();
                 ty.expect_bool(self.loc).dewrap(errors)?;
                 Typ::bool()
             }
+            contract::Kind::Bounded { lo, hi, term, .. } => {
+                for bound in [lo, hi] {
+                    bound.typ_check(symbols, errors)?;
+                    let ty = bound.typing.as_ref().unwrap();
+                    ty.expect(bound.loc, &Typ::int()).dewrap(errors)?;
+                }
+                term.typ_check(symbols, errors)?;
+                let ty = term.typing.as_ref().unwrap();
+                ty.expect_bool(self.loc).dewrap(errors)?;
+                Typ::bool()
+            }
+            contract::Kind::ArrayAccess { term, index } => {
+                index.typ_check(symbols, errors)?;
+                let ty = index.typing.as_ref().unwrap();
+                ty.expect(index.loc, &Typ::int()).dewrap(errors)?;
+                term.typ_check(symbols, errors)?;
+                match term.typing.as_ref().unwrap() {
+                    Typ::Array { ty, .. } => (**ty).clone(),
+                    given_type => {
+                        bad!(errors, @self.loc => ErrorKind::expected_array(given_type.clone()))
+                    }
+                }
+            }
+            contract::Kind::FieldAccess { term, field } => {
+                ExprTyping::new(self.loc, symbols, errors).field_access(term.as_mut(), field)?
+            }
             contract::Kind::Implication { left, right } => {
                 left.typ_check(symbols, errors)?;
                 let ty = left.typing.as_ref().unwrap();
@@ -165,32 +251,45 @@ impl Typing for contract::Term {
             }
 
             contract::Kind::ComponentCall {
-                comp_id, inputs, ..
+                comp_id,
+                typ_args,
+                inputs,
+                ..
             } => {
-                // type all inputs and check their types
+                // type all inputs and check their types, inferring type parameters
+                let mut subst = vec![];
                 inputs.iter_mut().try_for_each(|(id, input)| {
                     input.typ_check(symbols, errors)?;
 
                     let input_type = input.typing.as_ref().unwrap();
                     let expected_type = symbols.get_typ(*id);
-                    input_type.expect(self.loc, expected_type).dewrap(errors)
+                    expected_type
+                        .unify(self.loc, input_type, &mut subst)
+                        .dewrap(errors)
                 })?;
+                *typ_args = symbols
+                    .get_comp_typ_params(*comp_id)
+                    .iter()
+                    .map(|param| param.instantiate(self.loc, &subst))
+                    .collect::<Res<_>>()
+                    .dewrap(errors)?;
 
                 // get the called ident type
-                let comp_call_type = {
-                    let mut outputs_types = symbols
-                        .get_comp_outputs(*comp_id)
-                        .iter()
-                        .map(|(_, output_ident)| symbols.get_typ(*output_ident).clone())
-                        .collect::<Vec<_>>();
-                    if outputs_types.len() == 1 {
-                        outputs_types.pop().unwrap()
-                    } else {
-                        Typ::tuple(outputs_types)
-                    }
-                };
-
-                comp_call_type
+                let mut outputs_types = symbols
+                    .get_comp_outputs(*comp_id)
+                    .iter()
+                    .map(|(_, output_ident)| {
+                        symbols
+                            .get_typ(*output_ident)
+                            .instantiate(self.loc, &subst)
+                            .dewrap(errors)
+                    })
+                    .collect::<TRes<Vec<_>>>()?;
+                if outputs_types.len() == 1 {
+                    outputs_types.pop().unwrap()
+                } else {
+                    Typ::tuple(outputs_types)
+                }
             }
         };
         self.typing = Some(ty);
@@ -206,6 +305,19 @@ impl Typing for contract::Term {
     }
 }
 
+impl Typing for interface::InterfaceCheck {
+    fn typ_check(&mut self, symbols: &mut Ctx, errors: &mut Vec<Error>) -> TRes<()> {
+        match self {
+            interface::InterfaceCheck::Value(term) => {
+                term.typ_check(symbols, errors)?;
+                let ty = term.typing.as_ref().unwrap();
+                ty.expect_bool(term.loc).dewrap(errors)
+            }
+            interface::InterfaceCheck::Rate(_) => Ok(()),
+        }
+    }
+}
+
 impl Typing for interface::FlowStatement {
     // pre-condition: identifiers associated with statement is already typed
     // post-condition: expression associated with statement is typed and checked
@@ -452,16 +564,22 @@ impl Typing for flow::Expr {
                 ref mut inputs,
                 ..
             } => {
-                // type all inputs and check their types
+                // type all inputs and check their types, inferring type parameters
+                let mut subst = vec![];
                 inputs.iter_mut().try_for_each(|(id, input)| {
                     input.typ_check(symbols, errors)?;
                     let input_type = input.get_typ().unwrap().convert();
                     let expected_type = symbols.get_typ(*id);
-                    input_type.expect(self.loc, expected_type).dewrap(errors)
+                    expected_type
+                        .unify(self.loc, &input_type, &mut subst)
+                        .dewrap(errors)
                 })?;
 
                 // get the output type of the called function
-                let outputs_type = symbols.get_function_output_type(*function_id);
+                let outputs_type = symbols
+                    .get_function_output_type(*function_id)
+                    .instantiate(self.loc, &subst)
+                    .dewrap(errors)?;
 
                 self.typ = Some(outputs_type.rev_convert());
                 Ok(())
@@ -502,25 +620,53 @@ impl Typing for stream::Expr {
 
             stream::Kind::ComponentApplication {
                 called_comp_id,
+                ref mut typ_args,
+                ref mut static_args,
                 ref mut inputs,
+                ref mut reset,
                 ..
             } => {
-                // type all inputs and check their types
+                // the static arguments give the sizes of the arrays in the signature
+                let sizes = static_args_sizes(static_args, symbols, errors)?;
+
+                // type all inputs and check their types, inferring type parameters
+                let mut subst = vec![];
                 inputs.iter_mut().try_for_each(|(id, input)| {
                     input.typ_check(symbols, errors)?;
 
                     let input_type = input.typ.as_ref().unwrap();
-                    let expected_type = symbols.get_typ(*id);
-                    input_type.expect(self.loc, expected_type).dewrap(errors)
+                    let expected_type = symbols.get_typ(*id).instantiate_sizes(&sizes);
+                    expected_type
+                        .unify(self.loc, input_type, &mut subst)
+                        .dewrap(errors)
                 })?;
+                *typ_args = symbols
+                    .get_comp_typ_params(called_comp_id)
+                    .iter()
+                    .map(|param| param.instantiate(self.loc, &subst))
+                    .collect::<Res<_>>()
+                    .dewrap(errors)?;
+
+                // check the reset condition is a boolean
+                if let Some(reset) = reset {
+                    reset.typ_check(symbols, errors)?;
+                    let reset_type = reset.get_typ().unwrap();
+                    reset_type.expect(reset.loc, &Typ::bool()).dewrap(errors)?;
+                }
 
                 // get the called ident type
                 let comp_application_type = {
                     let mut outputs_types = symbols
                         .get_comp_outputs(called_comp_id)
                         .iter()
-                        .map(|(_, output_ident)| symbols.get_typ(*output_ident).clone())
-                        .collect::<Vec<_>>();
+                        .map(|(_, output_ident)| {
+                            symbols
+                                .get_typ(*output_ident)
+                                .instantiate_sizes(&sizes)
+                                .instantiate(self.loc, &subst)
+                                .dewrap(errors)
+                        })
+                        .collect::<TRes<Vec<_>>>()?;
                     if outputs_types.len() == 1 {
                         outputs_types.pop().unwrap()
                     } else {
@@ -782,11 +928,20 @@ impl<E: Typing> expr::Kind<E> {
                 Ok(typing.clone())
             }
             expr::Kind::UnOp { op, expr } => typing.unop(op, expr.as_mut()),
-            expr::Kind::BinOp { op, lft, rgt } => typing.binop(op, lft.as_mut(), rgt.as_mut()),
+            expr::Kind::BinOp {
+                op,
+                arith,
+                lft,
+                rgt,
+            } => typing.binop(op, *arith, lft.as_mut(), rgt.as_mut()),
             expr::Kind::IfThenElse { cnd, thn, els } => {
                 typing.if_then_else(cnd.as_mut(), thn.as_mut(), els.as_mut())
             }
-            expr::Kind::Application { fun: f, inputs } => typing.application(f.as_mut(), inputs),
+            expr::Kind::Application {
+                fun: f,
+                static_args,
+                inputs,
+            } => typing.application(f.as_mut(), static_args, inputs),
             expr::Kind::Lambda { inputs, expr, .. } => typing.lambda(inputs, expr.as_mut()),
             expr::Kind::Structure { id, fields } => typing.structure(*id, fields),
             expr::Kind::Array { elements } => typing.array(elements),
@@ -812,6 +967,42 @@ impl<E: Typing> expr::Kind<E> {
     }
 }
 
+/// Type-checks the static arguments of a template application, and returns the array sizes they
+/// give to the static parameters.
+///
+/// Static arguments are literals, negated literals or static parameters of the caller.
+fn static_args_sizes(
+    static_args: &mut [(usize, Expr)],
+    symbols: &mut Ctx,
+    errors: &mut Vec<Error>,
+) -> TRes<Vec<(Ident, Size)>> {
+    let mut sizes = Vec::with_capacity(static_args.len());
+    for (param_id, arg) in static_args.iter_mut() {
+        arg.typ_check(symbols, errors)?;
+        let expected_type = symbols.get_typ(*param_id);
+        arg.get_typ()
+            .unwrap()
+            .expect(arg.loc, expected_type)
+            .dewrap(errors)?;
+        let size = match &arg.kind {
+            expr::Kind::Identifier { id } if symbols.is_static_param(*id) => {
+                Size::Param(symbols.get_name(*id).clone())
+            }
+            _ => match instance::eval(arg, symbols) {
+                Some(Constant::Integer(size)) => Size::Lit(size),
+                Some(_) => continue,
+                None => bad!(errors, @arg.loc =>
+                    "static arguments must be literals or static parameters"
+                ),
+            },
+        };
+        if matches!(expected_type, Typ::Integer(_)) {
+            sizes.push((symbols.get_name(*param_id).clone(), size));
+        }
+    }
+    Ok(sizes)
+}
+
 struct ExprTyping<'a, E: Typing> {
     pub loc: Loc,
     table: &'a mut Ctx,
@@ -842,7 +1033,12 @@ impl<'a, E: Typing> ExprTyping<'a, E> {
         Ok(lambda_type)
     }
 
-    fn application(&mut self, f: &mut E, inputs: &mut [E]) -> TRes<Typ> {
+    fn application(
+        &mut self,
+        f: &mut E,
+        static_args: &mut [(usize, Expr)],
+        inputs: &mut [E],
+    ) -> TRes<Typ> {
         // type all inputs
         for input in inputs.iter_mut() {
             input.typ_check(self.table, self.errors)?;
@@ -853,8 +1049,13 @@ impl<'a, E: Typing> ExprTyping<'a, E> {
             .map(|input| input.get_typ().unwrap().clone())
             .collect::<Vec<_>>();
 
-        // type the function expression
+        // type the function expression, with the array sizes given by the static arguments
         f.typ_check(self.table, self.errors)?;
+        if !static_args.is_empty() {
+            let sizes = static_args_sizes(static_args, self.table, self.errors)?;
+            let fun_ty = f.get_typ_mut().unwrap();
+            *fun_ty = fun_ty.instantiate_sizes(&sizes);
+        }
 
         // compute the application type
         let application_type =
@@ -888,7 +1089,7 @@ impl<'a, E: Typing> ExprTyping<'a, E> {
         Ok(array_type)
     }
 
-    fn binop(&mut self, op: &BOp, lft: &mut E, rgt: &mut E) -> TRes<Typ> {
+    fn binop(&mut self, op: &BOp, arith: Option<Arith>, lft: &mut E, rgt: &mut E) -> TRes<Typ> {
         // get expressions type
         lft.typ_check(self.table, self.errors)?;
         let lft_type = lft.get_typ().unwrap().clone();
@@ -898,7 +1099,13 @@ impl<'a, E: Typing> ExprTyping<'a, E> {
         // get binop type
         let mut binop_type = op.get_typ();
 
-        binop_type.apply(vec![lft_type, rgt_type], self.loc, self.errors)
+        let typ = binop_type.apply(vec![lft_type, rgt_type], self.loc, self.errors)?;
+
+        // arithmetic policies only apply to integers
+        if arith.is_some() && !matches!(typ, Typ::Integer(_)) {
+            bad!(self.errors, @self.loc => ErrorKind::incompatible_types(typ, Typ::int()))
+        }
+        Ok(typ)
     }
 
     fn enumeration(&mut self, enum_id: usize) -> TRes<Typ> {
@@ -1154,8 +1361,8 @@ impl<'a, E: Typing> ExprTyping<'a, E> {
 
         match expr.get_typ().unwrap() {
             Typ::Array { ty, size, .. } => {
-                let size: usize = size.base10_parse().map_err(|_| ErrorDetected)?;
-                if size <= index {
+                // the size of a template's array can only be checked on its instances
+                if size.as_usize().is_some_and(|size| size <= index) {
                     bad!(self.errors, @self.loc => ErrorKind::oob());
                 }
                 Ok((**ty).clone())
@@ -1216,8 +1423,8 @@ impl<'a, E: Typing> ExprTyping<'a, E> {
                 Typ::Array { ty, size: n, .. } if n == length => Ok(*ty.clone()),
                 Typ::Array { size: n, .. } => {
                     bad!(self.errors, @self.loc => ErrorKind::incompatible_length(
-                        n.base10_parse().unwrap(),
-                        length.base10_parse().unwrap(),
+                        n.clone(),
+                        length.clone(),
                     ))
                 }
                 ty => {
@@ -1227,12 +1434,9 @@ impl<'a, E: Typing> ExprTyping<'a, E> {
             .collect::<TRes<Vec<Typ>>>()?;
 
         let array_type = if tuple_types.len() > 1 {
-            Typ::array(Typ::tuple(tuple_types), length.base10_parse().unwrap())
+            Typ::array_of_size(Typ::tuple(tuple_types), length.clone())
         } else {
-            Typ::array(
-                tuple_types.first().unwrap().clone(),
-                length.base10_parse().unwrap(),
-            )
+            Typ::array_of_size(tuple_types.first().unwrap().clone(), length.clone())
         };
 
         Ok(array_type)
@@ -35,9 +35,14 @@ impl Unused for Interface {
 
 impl Unused for Component {
     fn no_unused(&self, ctx: &mut Ctx) -> URes {
+        // memories read by the contract, such as temporal observers' flows, are used
+        let contract_memories = match &self.body_or_path {
+            Either::Left(body) => body.contract.memory_accesses(),
+            Either::Right(_) => vec![],
+        };
         ctx.get_comp_inputs(self.get_id())
             .iter().chain(ctx.get_comp_locals(self.get_id()))
-            .filter(|id| !ctx.is_codegen(**id)).try_for_each(|id| {
+            .filter(|id| !ctx.is_codegen(**id) && !contract_memories.contains(id)).try_for_each(|id| {
                 if let Some(graph) = self.get_graph() {
                     if graph.edges_directed(*id, graph::Direction::Incoming).next().is_none(){
                         let ident = ctx.get_name(*id);
@@ -0,0 +1,204 @@
+//! C-ABI export of components, see the `c_abi` configuration item.
+//!
+//! Components export `extern "C"` functions (see [state_machine::ExternCTokens]), this module
+//! checks that their interfaces have a C equivalent and produces the C header declaring them.
+
+prelude! {}
+
+/// C type of a GRust type, as a base type and array dimensions (`int64_t` and `[3]`).
+fn c_type(typ: &Typ) -> Option<(String, String)> {
+    match typ {
+        Typ::Integer(_) => Some(("int64_t".into(), String::new())),
+        Typ::Float(_) => Some(("double".into(), String::new())),
+        Typ::Boolean(_) => Some(("bool".into(), String::new())),
+        Typ::Enumeration { name, .. } | Typ::Structure { name, .. } => {
+            Some((name.to_string(), String::new()))
+        }
+        Typ::Array { ty, size, .. } => {
+            let (base, dims) = c_type(ty)?;
+            Some((base, format!("[{size}]{dims}")))
+        }
+        Typ::Event { ty, .. } | Typ::Signal { ty, .. } => c_type(ty),
+        Typ::Unit(_)
+        | Typ::Option { .. }
+        | Typ::Fn { .. }
+        | Typ::Tuple { .. }
+        | Typ::NotDefinedYet(_)
+        | Typ::Polymorphism(_)
+        | Typ::Param { .. }
+        | Typ::Any => None,
+    }
+}
+
+/// C declaration of `ident` with type `typ`.
+fn c_decl(ident: &Ident, typ: &Typ) -> String {
+    let (base, dims) = c_type(typ).expect("internal error: checked by `check_c_abi`");
+    format!("{base} {ident}{dims}")
+}
+
+/// C `typedef struct` of some fields.
+fn c_struct(name: &Ident, fields: &[(Ident, Typ)]) -> String {
+    let mut s = format!("typedef struct {name} {{\n");
+    for (ident, typ) in fields {
+        s.push_str(&format!("    {};\n", c_decl(ident, typ)));
+    }
+    s.push_str(&format!("}} {name};\n"));
+    s
+}
+
+/// Names of the user types a type depends on.
+fn dependencies<'a>(typ: &'a Typ, deps: &mut Vec<&'a Ident>) {
+    match typ {
+        Typ::Structure { name, .. } => deps.push(name),
+        Typ::Array { ty, .. } => dependencies(ty, deps),
+        _ => (),
+    }
+}
+
+impl Project {
+    /// Checks that exported structures and components have a C equivalent.
+    pub fn check_c_abi(&self) -> Result<(), Vec<Error>> {
+        let mut errors = vec![];
+        let mut check = |ident: &Ident, typ: &Typ, what: &str| {
+            if c_type(typ).is_none() {
+                errors.push(error!(@ident.loc() =>
+                    ErrorKind::msg(format!("{what} `{ident}` has no C equivalent type"))
+                ))
+            }
+        };
+        for item in self.items.iter() {
+            match item {
+                Item::Structure(structure) => structure
+                    .fields
+                    .iter()
+                    .for_each(|(ident, typ)| check(ident, typ, "field")),
+                Item::ArrayAlias(alias) => check(&alias.name, &alias.array_type, "array"),
+                Item::StateMachine(sm) if !sm.is_generic() => {
+                    sm.input
+                        .elements
+                        .iter()
+                        .for_each(|(ident, typ)| check(ident, typ, "input"));
+                    sm.output
+                        .elements
+                        .iter()
+                        .for_each(|(ident, typ)| check(ident, typ, "output"));
+                }
+                Item::StateMachine(_)
+                | Item::ExecutionMachine(_)
+                | Item::Function(_)
+                | Item::Enumeration(_)
+                | Item::ExtSignature(_)
+                | Item::Scenario(_) => (),
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// C header declaring user types and exported components.
+    ///
+    /// `guard` is the include-guard macro of the header.
+    pub fn c_header(&self, guard: &str) -> String {
+        let mut header = format!(
+            "/* Generated by grust, do not edit. */\n\n\
+            #ifndef {guard}\n\
+            #define {guard}\n\n\
+            #include <stdbool.h>\n\
+            #include <stdint.h>\n"
+        );
+
+        // enumerations, their variants are prefixed by the enumeration's name
+        for item in self.items.iter() {
+            if let Item::Enumeration(enumeration) = item {
+                let name = &enumeration.name;
+                header.push_str(&format!("\ntypedef enum {name} {{\n"));
+                for elem in enumeration.elements.iter() {
+                    header.push_str(&format!("    {name}_{elem},\n"));
+                }
+                header.push_str(&format!("}} {name};\n"));
+            }
+        }
+
+        // structures and arrays, declared after the structures they use
+        let mut pending = self
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Structure(structure) => {
+                    let mut deps = vec![];
+                    structure
+                        .fields
+                        .iter()
+                        .for_each(|(_, typ)| dependencies(typ, &mut deps));
+                    let decl = c_struct(&structure.name, &structure.fields);
+                    Some((&structure.name, deps, decl))
+                }
+                Item::ArrayAlias(alias) => {
+                    let mut deps = vec![];
+                    dependencies(&alias.array_type, &mut deps);
+                    let (base, dims) = c_type(&alias.array_type)
+                        .expect("internal error: checked by `check_c_abi`");
+                    let decl = format!("typedef {base} {}[{}]{dims};\n", alias.name, alias.size);
+                    Some((&alias.name, deps, decl))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let mut declared: Vec<&Ident> = vec![];
+        while !pending.is_empty() {
+            let ready = pending
+                .iter()
+                .position(|(_, deps, _)| {
+                    deps.iter().all(|dep| {
+                        declared.contains(dep) || pending.iter().all(|(name, _, _)| name != dep)
+                    })
+                })
+                // cyclic definitions are rejected by typing, just keep going
+                .unwrap_or(0);
+            let (name, _, decl) = pending.remove(ready);
+            header.push('\n');
+            header.push_str(&decl);
+            declared.push(name);
+        }
+
+        // components
+        for item in self.items.iter() {
+            if let Item::StateMachine(sm) = item {
+                if sm.is_generic() {
+                    continue;
+                }
+                let state_ty = sm.name.to_state_ty();
+                let input_ty = sm.name.to_input_ty();
+                let output_ty = sm.name.to_output_ty();
+                header.push('\n');
+                let input_arg = if sm.input.elements.is_empty() {
+                    String::new()
+                } else {
+                    header.push_str(&c_struct(&input_ty, &sm.input.elements));
+                    format!(", {input_ty} input")
+                };
+                let output_ret = if sm.output.elements.is_empty() {
+                    "void".to_string()
+                } else {
+                    header.push_str(&c_struct(&output_ty, &sm.output.elements));
+                    output_ty.to_string()
+                };
+                header.push_str(&format!(
+                    "typedef struct {state_ty} {state_ty};\n\
+                    {state_ty} *{}(void);\n\
+                    {output_ret} {}({state_ty} *state{input_arg});\n\
+                    void {}({state_ty} *state);\n",
+                    sm.c_init_fn(),
+                    sm.c_step_fn(),
+                    sm.c_free_fn(),
+                ));
+            }
+        }
+
+        header.push_str(&format!("\n#endif /* {guard} */\n"));
+        header
+    }
+}
@@ -0,0 +1,399 @@
+//! Structural coverage instrumentation, see the `coverage` configuration item.
+//!
+//! Each `if then else` branch, `match` and `when` arm of the components gets a [Probe] statement
+//! counting its executions, so does each flow handler of the services. The probes of a component
+//! or service are numbered and gathered in a static table, returned by the generated `coverage`
+//! function of its state type (see [ProbesTokens]).
+
+use itertools::Itertools;
+
+prelude! {}
+
+/// Kind of a coverage point, mirrors `grust::core::coverage::Branch`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Branch {
+    /// `then` branch of an `if then else`.
+    Then,
+    /// `else` branch of an `if then else`.
+    Else,
+    /// Arm of a `match` or a `when`.
+    Arm,
+    /// Flow handler of a service.
+    Handler,
+}
+impl ToTokens for Branch {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let variant = match self {
+            Self::Then => quote!(Then),
+            Self::Else => quote!(Else),
+            Self::Arm => quote!(Arm),
+            Self::Handler => quote!(Handler),
+        };
+        quote!(grust::core::coverage::Branch::#variant).to_tokens(tokens)
+    }
+}
+
+/// A coverage probe, counts the executions of a branch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Probe {
+    /// Index of the probe in its owner's table.
+    pub idx: usize,
+    /// Kind of the branch.
+    pub branch: Branch,
+    /// The condition, pattern or flow of the branch.
+    pub label: String,
+    /// Location of the branch.
+    pub loc: Loc,
+}
+
+mk_new! { impl Probe =>
+    new {
+        idx: usize,
+        branch: Branch,
+        label: impl Into<String> = label.into(),
+        loc: impl Into<Loc> = loc.into(),
+    }
+}
+
+impl Probe {
+    /// Probes of the branches of an [ir1] stream expression, numbered later.
+    pub fn of_ir1(kind: &ir1::expr::Kind<ir1::stream::Expr>, ctx: &ir0::Ctx) -> Vec<Self> {
+        match kind {
+            ir1::expr::Kind::IfThenElse { cnd, thn, els } => {
+                let label = expr_label(cnd, ctx);
+                vec![
+                    Self::new(0, Branch::Then, label.clone(), thn.loc),
+                    Self::new(0, Branch::Else, label, els.loc),
+                ]
+            }
+            ir1::expr::Kind::MatchExpr { arms, .. } => arms
+                .iter()
+                .map(|(pattern, ..)| {
+                    Self::new(0, Branch::Arm, pattern_label(pattern, ctx), pattern.loc)
+                })
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Counts an execution of the branch, in a method of its owner.
+    pub fn hit_tokens(&self) -> TokenStream2 {
+        let idx = syn::LitInt::new(&self.idx.to_string(), self.loc.span);
+        quote!(Self::coverage().hit(#idx);)
+    }
+
+    /// The `grust::core::coverage::Point` of the probe.
+    ///
+    /// Locations are given by `file!`, `line!` and `column!` spanned at the branch, they are
+    /// resolved in the GRust source.
+    pub fn point_tokens(&self, owner: &Ident) -> TokenStream2 {
+        let span = self.loc.span;
+        let owner = syn::LitStr::new(&owner.to_string(), owner.span());
+        let branch = self.branch;
+        let label = syn::LitStr::new(&self.label, span);
+        let file = quote_spanned!(span => file!());
+        let line = quote_spanned!(span => line!());
+        let column = quote_spanned!(span => column!());
+        quote! {
+            grust::core::coverage::Point {
+                owner: #owner,
+                branch: #branch,
+                label: #label,
+                file: #file,
+                line: #line,
+                column: #column,
+            }
+        }
+    }
+}
+
+/// GRust text of an [ir1] stream expression, labelling the branches it conditions.
+///
+/// Falls back to the generated Rust code for the constructs not written back as GRust.
+fn expr_label(expr: &ir1::stream::Expr, ctx: &ir0::Ctx) -> String {
+    use ir1::{expr::Kind, stream};
+    let label = |expr| expr_label(expr, ctx);
+    match &expr.kind {
+        stream::Kind::Last { ident_id, .. } => format!("last {}", ctx.get_name(*ident_id)),
+        stream::Kind::Expression { expr: kind } => match kind {
+            Kind::Constant { constant } => compact(constant.to_token_stream()),
+            Kind::Identifier { id } => ctx.get_name(*id).to_string(),
+            Kind::UnOp { op, expr } => format!("{op}{}", label(expr)),
+            Kind::BinOp { op, lft, rgt, .. } => format!("{}{op}{}", label(lft), label(rgt)),
+            Kind::Enumeration { enum_id, elem_id } => {
+                format!("{}::{}", ctx.get_name(*enum_id), ctx.get_name(*elem_id))
+            }
+            Kind::Application { fun, inputs, .. } => {
+                format!("{}({})", label(fun), inputs.iter().map(label).join(", "))
+            }
+            Kind::FieldAccess { expr, field } => format!("{}.{field}", label(expr)),
+            Kind::TupleElementAccess {
+                expr,
+                element_number,
+            } => format!("{}.{element_number}", label(expr)),
+            Kind::ArrayAccess { expr, index } => format!("{}[{index}]", label(expr)),
+            Kind::Tuple { elements } => format!("({})", elements.iter().map(label).join(", ")),
+            _ => compact(expr.clone().into_ir2(ctx).to_token_stream()),
+        },
+        _ => compact(expr.clone().into_ir2(ctx).to_token_stream()),
+    }
+}
+
+/// GRust text of an [ir1] pattern.
+///
+/// The tuple patterns of `when` arms only show their events, as `let d = pedestrian?`.
+fn pattern_label(pattern: &ir1::Pattern, ctx: &ir0::Ctx) -> String {
+    use ir1::pattern::Kind;
+    let label = |pattern| pattern_label(pattern, ctx);
+    match &pattern.kind {
+        Kind::Identifier { id } => ctx.get_name(*id).to_string(),
+        Kind::Constant { constant } => compact(constant.to_token_stream()),
+        Kind::Structure { id, fields } => {
+            let mut fields = fields.iter().map(|(id, pattern)| match pattern {
+                Some(pattern) => format!("{}: {}", ctx.get_name(*id), label(pattern)),
+                None => ctx.get_name(*id).to_string(),
+            });
+            format!("{} {{ {} }}", ctx.get_name(*id), fields.join(", "))
+        }
+        Kind::Enumeration { enum_id, elem_id } => {
+            format!("{}::{}", ctx.get_name(*enum_id), ctx.get_name(*elem_id))
+        }
+        Kind::PresentEvent { event_id, pattern } => {
+            format!("let {} = {}?", label(pattern), ctx.get_name(*event_id))
+        }
+        Kind::NoEvent { event_id } => format!("no {}", ctx.get_name(*event_id)),
+        Kind::Tuple { elements } => {
+            let is_event = |pattern: &&ir1::Pattern| {
+                matches!(
+                    pattern.kind,
+                    Kind::PresentEvent { .. } | Kind::NoEvent { .. }
+                )
+            };
+            if elements.iter().any(|pattern| is_event(&pattern)) {
+                elements.iter().filter(is_event).map(label).join(", ")
+            } else if elements
+                .iter()
+                .all(|pattern| matches!(pattern.kind, Kind::Default(_)))
+            {
+                "_".into()
+            } else {
+                format!("({})", elements.iter().map(label).join(", "))
+            }
+        }
+        Kind::Some { pattern } => format!("Some({})", label(pattern)),
+        Kind::None => "None".into(),
+        Kind::Default(_) => "_".into(),
+    }
+}
+
+/// Tokens as text, without the spaces `proc_macro2` puts around punctuation.
+fn compact(tokens: TokenStream2) -> String {
+    tokens
+        .to_string()
+        .replace(" :: ", "::")
+        .replace(" . ", ".")
+        .replace("( ", "(")
+        .replace(" )", ")")
+        .replace(" ,", ",")
+}
+
+/// The `coverage` function returning the probes table of a component or a service.
+pub struct ProbesTokens<'a> {
+    /// Component or service owning the probes.
+    pub owner: &'a Ident,
+    /// The probes.
+    pub probes: &'a [Probe],
+}
+impl ToTokens for ProbesTokens<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let count = syn::LitInt::new(&self.probes.len().to_string(), self.owner.span());
+        let points = self
+            .probes
+            .iter()
+            .map(|probe| probe.point_tokens(self.owner));
+        quote! {
+            pub fn coverage() -> &'static grust::core::coverage::Probes<#count> {
+                static PROBES: grust::core::coverage::Probes<#count> =
+                    grust::core::coverage::Probes::new([#(#points),*]);
+                &PROBES
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
+
+impl Expr {
+    /// Inserts the probes of [Probe::of_ir1] in the branches of the expression.
+    pub fn instrument(&mut self, probes: Vec<Probe>) {
+        match self {
+            Self::IfThenElse { thn, els, .. } => {
+                let [then_probe, else_probe]: [Probe; 2] = probes
+                    .try_into()
+                    .expect("internal error: `if then else` has two probes");
+                thn.statements.insert(0, Stmt::probe(then_probe));
+                els.statements.insert(0, Stmt::probe(else_probe));
+            }
+            Self::MatchExpr { arms, .. } => {
+                for ((_, _, code), probe) in arms.iter_mut().zip(probes) {
+                    match code {
+                        Self::Block { block } => block.statements.insert(0, Stmt::probe(probe)),
+                        _ => {
+                            let expr = std::mem::replace(code, Self::none());
+                            *code = Self::block(Block::new(vec![
+                                Stmt::probe(probe),
+                                Stmt::expr_last(expr),
+                            ]))
+                        }
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Applies `f` to the blocks of the expression, outer blocks first.
+    pub fn blocks_mut(&mut self, f: &mut impl FnMut(&mut Block)) {
+        match self {
+            Self::Literal { .. }
+            | Self::Identifier { .. }
+            | Self::Path { .. }
+            | Self::None
+            | Self::MemoryAccess { .. }
+            | Self::InputAccess { .. }
+            | Self::Enumeration { .. } => (),
+            Self::Some { expr }
+            | Self::UnOp { expr, .. }
+            | Self::FieldAccess { expr, .. }
+            | Self::ArrayAccess { expr, .. } => expr.blocks_mut(f),
+            Self::BinOp { lft, rgt, .. } => {
+                lft.blocks_mut(f);
+                rgt.blocks_mut(f)
+            }
+            Self::IfThenElse { cnd, thn, els } => {
+                cnd.blocks_mut(f);
+                thn.blocks_mut(f);
+                els.blocks_mut(f)
+            }
+            Self::Structure { fields, .. } => {
+                for (_, expr) in fields {
+                    expr.blocks_mut(f)
+                }
+            }
+            Self::Array { elements }
+            | Self::Tuple { elements }
+            | Self::Zip { arrays: elements } => {
+                for expr in elements {
+                    expr.blocks_mut(f)
+                }
+            }
+            Self::Block { block } => block.blocks_mut(f),
+            Self::FunctionCall {
+                function,
+                arguments,
+            } => {
+                function.blocks_mut(f);
+                for expr in arguments {
+                    expr.blocks_mut(f)
+                }
+            }
+            Self::ComponentCall {
+                input_fields,
+                reset,
+                ..
+            } => {
+                for (_, expr) in input_fields {
+                    expr.blocks_mut(f)
+                }
+                if let Some(reset) = reset {
+                    reset.blocks_mut(f)
+                }
+            }
+            Self::Lambda { body, .. } => body.blocks_mut(f),
+            Self::MatchExpr { matched, arms } => {
+                matched.blocks_mut(f);
+                for (_, guard, code) in arms {
+                    if let Some(guard) = guard {
+                        guard.blocks_mut(f)
+                    }
+                    code.blocks_mut(f)
+                }
+            }
+            Self::Map { mapped, function } => {
+                mapped.blocks_mut(f);
+                function.blocks_mut(f)
+            }
+            Self::Fold {
+                folded,
+                initialization,
+                function,
+            } => {
+                folded.blocks_mut(f);
+                initialization.blocks_mut(f);
+                function.blocks_mut(f)
+            }
+            Self::Sort { sorted, function } => {
+                sorted.blocks_mut(f);
+                function.blocks_mut(f)
+            }
+        }
+    }
+}
+
+impl Block {
+    /// Applies `f` to the block, then to its inner blocks.
+    pub fn blocks_mut(&mut self, f: &mut impl FnMut(&mut Block)) {
+        f(self);
+        for stmt in self.statements.iter_mut() {
+            match stmt {
+                Stmt::Let { expr, .. } | Stmt::Log { expr, .. } | Stmt::ExprLast { expr } => {
+                    expr.blocks_mut(f)
+                }
+                Stmt::Probe { .. } => (),
+            }
+        }
+    }
+}
+
+impl para::Stmts {
+    /// Numbers the probes of the statements, in order, and returns them.
+    pub fn number_probes(&mut self) -> Vec<Probe> {
+        let mut probes = vec![];
+        self.blocks_mut(&mut |block| {
+            for stmt in block.statements.iter_mut() {
+                if let Stmt::Probe { probe } = stmt {
+                    probe.idx = probes.len();
+                    probes.push(probe.clone())
+                }
+            }
+        });
+        probes
+    }
+
+    /// Removes the probes of the statements.
+    pub fn strip_probes(&mut self) {
+        self.blocks_mut(&mut |block| {
+            block
+                .statements
+                .retain(|stmt| !matches!(stmt, Stmt::Probe { .. }))
+        })
+    }
+
+    /// Applies `f` to the blocks of the statements.
+    fn blocks_mut(&mut self, f: &mut impl FnMut(&mut Block)) {
+        match self {
+            Self::Seq(_, stmts) => {
+                for stmts in stmts {
+                    stmts.blocks_mut(f)
+                }
+            }
+            Self::Para(_, subs) => {
+                for (_, _, stmts) in subs {
+                    for stmts in stmts {
+                        stmts.blocks_mut(f)
+                    }
+                }
+            }
+            Self::Stmt(_, expr) => expr.blocks_mut(f),
+        }
+    }
+}
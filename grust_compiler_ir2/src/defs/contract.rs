@@ -68,6 +68,46 @@ pub enum Term {
         /// The term
         term: Box<Term>,
     },
+    /// Exists term: `exists x, P(x)`.
+    Exists {
+        /// The identifier's name.
+        name: Ident,
+        /// The identifier's type.
+        ty: Typ,
+        /// The term
+        term: Box<Term>,
+    },
+    /// Bounded quantifier term: `forall i in lo..hi, P(i)`.
+    Bounded {
+        /// The quantifier.
+        quantifier: ir1::contract::Quantifier,
+        /// The integer identifier's name.
+        name: Ident,
+        /// Lower bound, included.
+        lo: Box<Term>,
+        /// Upper bound, excluded.
+        hi: Box<Term>,
+        /// The term
+        term: Box<Term>,
+    },
+    /// Array access term: `a[i]`.
+    ArrayAccess {
+        /// The array.
+        term: Box<Term>,
+        /// The index.
+        index: Box<Term>,
+        /// True if the element's type needs logical model.
+        views: bool,
+    },
+    /// Field access term: `s.f`.
+    FieldAccess {
+        /// The structure.
+        term: Box<Term>,
+        /// The field.
+        field: Ident,
+        /// True if the field's type needs logical model.
+        views: bool,
+    },
     /// Implication term: x => y
     Implication {
         /// Left term
@@ -102,6 +142,8 @@ pub enum Term {
     FunctionCall {
         /// The function called.
         function: Ident,
+        /// Path of the function when external, called by runtime checkers.
+        path: Option<syn::Path>,
         /// The arguments.
         arguments: Vec<Self>,
     },
@@ -153,6 +195,28 @@ mk_new! { impl Term =>
         ty: Typ,
         term: Term = term.into(),
     }
+    Exists: exists {
+        name: impl Into<Ident> = name.into(),
+        ty: Typ,
+        term: Term = term.into(),
+    }
+    Bounded: bounded {
+        quantifier: ir1::contract::Quantifier,
+        name: impl Into<Ident> = name.into(),
+        lo: Term = lo.into(),
+        hi: Term = hi.into(),
+        term: Term = term.into(),
+    }
+    ArrayAccess: array_access {
+        term: Term = term.into(),
+        index: Term = index.into(),
+        views: bool,
+    }
+    FieldAccess: field_access {
+        term: Term = term.into(),
+        field: impl Into<Ident> = field.into(),
+        views: bool,
+    }
     Implication: implication {
         left: Term = left.into(),
         right: Term = right.into(),
@@ -164,6 +228,7 @@ mk_new! { impl Term =>
     }
     FunctionCall: fun_call {
         function: impl Into<Ident> = function.into(),
+        path: Option<syn::Path>,
         arguments: impl Into<Vec<Self>> = arguments.into(),
     }
     ComponentCall: comp_call {
@@ -189,14 +254,153 @@ impl Term {
             term: self,
             prophecy,
             function_like,
+            checker: None,
+            init: false,
+            polarity: Polarity::Positive,
+            widen: false,
+        }
+    }
+
+    /// Tokens stream for a term ensured by the `init` function.
+    ///
+    /// `Self::MemoryAccess` identifiers are printed `result.last_<id>`.
+    pub fn prepare_init_tokens(&self) -> TermTokens {
+        TermTokens {
+            term: self,
+            prophecy: false,
+            function_like: false,
+            checker: None,
+            init: true,
+            polarity: Polarity::Positive,
+            widen: false,
+        }
+    }
+
+    /// Tokens stream for a term checked by a Kani harness.
+    ///
+    /// Inputs and outputs are local variables of the harness, and `prophecy` changes the way
+    /// `Self::MemoryAccess` identifiers are printed:
+    /// - `state.last_<id>` if true,
+    /// - `last_<id>`, a copy of the memory before the step, otherwise.
+    pub fn prepare_kani_tokens(&self, prophecy: bool) -> TermTokens {
+        TermTokens {
+            term: self,
+            prophecy,
+            function_like: true,
+            checker: Some(Checker::Kani),
+            init: false,
+            polarity: Polarity::Positive,
+            widen: true,
+        }
+    }
+
+    /// Tokens stream for a term checked by a property-based testing harness.
+    ///
+    /// Same as [Self::prepare_kani_tokens], `forall` quantifiers are checked on random values.
+    pub fn prepare_prop_tokens(&self, prophecy: bool) -> TermTokens {
+        TermTokens {
+            term: self,
+            prophecy,
+            function_like: true,
+            checker: Some(Checker::Prop),
+            init: false,
+            polarity: Polarity::Positive,
+            widen: true,
+        }
+    }
+
+    /// Memories accessed before the step, `last_<id>` identifiers.
+    pub fn memory_accesses(&self, accesses: &mut Vec<Ident>) {
+        match self {
+            Self::MemoryAccess { identifier, .. } => {
+                let id = identifier.to_last_var();
+                if !accesses.contains(&id) {
+                    accesses.push(id)
+                }
+            }
+            Self::Paren { term }
+            | Self::Unop { term, .. }
+            | Self::Forall { term, .. }
+            | Self::Exists { term, .. }
+            | Self::FieldAccess { term, .. }
+            | Self::Ok { term }
+            | Self::Some { term } => term.memory_accesses(accesses),
+            Self::Bounded { lo, hi, term, .. } => {
+                lo.memory_accesses(accesses);
+                hi.memory_accesses(accesses);
+                term.memory_accesses(accesses)
+            }
+            Self::ArrayAccess {
+                term: left,
+                index: right,
+                ..
+            }
+            | Self::Binop { left, right, .. }
+            | Self::Implication { left, right } => {
+                left.memory_accesses(accesses);
+                right.memory_accesses(accesses)
+            }
+            Self::Enumeration { element, .. } => {
+                if let Some(term) = element {
+                    term.memory_accesses(accesses)
+                }
+            }
+            Self::FunctionCall { arguments, .. } => arguments
+                .iter()
+                .for_each(|term| term.memory_accesses(accesses)),
+            Self::ComponentCall { input_fields, .. } => input_fields
+                .iter()
+                .for_each(|(_, term)| term.memory_accesses(accesses)),
+            Self::Literal { .. }
+            | Self::Identifier { .. }
+            | Self::InputAccess { .. }
+            | Self::OutputAccess { .. }
+            | Self::Err
+            | Self::None => (),
         }
     }
 }
+/// Harnesses checking contracts at runtime, instead of proving them with Creusot.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Checker {
+    /// Kani bounded proofs.
+    Kani,
+    /// Property-based tests.
+    Prop,
+}
+
+/// Polarity of a subterm in its clause, `forall` is `exists` under a negation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Polarity {
+    Positive,
+    Negative,
+    /// Both positive and negative, under `==` for instance.
+    Mixed,
+}
+impl Polarity {
+    fn flip(self) -> Self {
+        match self {
+            Self::Positive => Self::Negative,
+            Self::Negative => Self::Positive,
+            Self::Mixed => Self::Mixed,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct TermTokens<'a> {
     term: &'a Term,
     prophecy: bool,
     function_like: bool,
+    checker: Option<Checker>,
+    init: bool,
+    polarity: Polarity,
+    /// True if arithmetic is computed on wide values, in checkers only.
+    ///
+    /// Contract integers are mathematical ones, checkers compute with [i128] to not overflow on
+    /// `i64` values (sums, and products of two values). Arguments of calls, indices and bounds
+    /// keep their types.
+    widen: bool,
 }
 impl<'a> TermTokens<'a> {
     /// Swaps the underlying term.
@@ -205,6 +409,56 @@ impl<'a> TermTokens<'a> {
             term,
             prophecy: self.prophecy,
             function_like: self.function_like,
+            checker: self.checker,
+            init: self.init,
+            polarity: self.polarity,
+            widen: self.widen,
+        }
+    }
+
+    /// Swaps the underlying term for a negated one.
+    fn set_negated_term(&self, term: &'a Term) -> Self {
+        Self {
+            polarity: self.polarity.flip(),
+            ..self.set_term(term)
+        }
+    }
+
+    /// Swaps the underlying term for an operand of a comparison.
+    fn set_compared_term(&self, term: &'a Term) -> Self {
+        Self {
+            polarity: Polarity::Mixed,
+            ..self.set_term(term)
+        }
+    }
+
+    /// Swaps the underlying term for a value keeping its type: argument, index, bound...
+    fn set_value_term(&self, term: &'a Term) -> Self {
+        Self {
+            polarity: Polarity::Mixed,
+            widen: false,
+            ..self.set_term(term)
+        }
+    }
+
+    /// Operand of a wide arithmetic operation, leaves are widened.
+    fn wide_operand(&self) -> TokenStream2 {
+        match self.term {
+            Term::Literal { .. } => self.to_token_stream(),
+            term if term.is_arith() => self.to_token_stream(),
+            _ => quote!(grust::core::wide::Widen::widen(#self)),
+        }
+    }
+}
+
+impl Term {
+    /// True on arithmetic operations, with a numerical result.
+    fn is_arith(&self) -> bool {
+        match self {
+            Term::Paren { term } => term.is_arith(),
+            Term::Binop { op, .. } => op.is_arith(),
+            Term::Unop { op, .. } => *op == UOp::Neg,
+            _ => false,
         }
     }
 }
@@ -216,35 +470,71 @@ impl ToTokens for TermTokens<'_> {
                 let term = self.set_term(term);
                 quote!( (#term) ).to_tokens(tokens)
             }
+            Term::Unop { op: UOp::Neg, term } if self.widen => {
+                let term = self.set_term(term).wide_operand();
+                quote!(-#term).to_tokens(tokens)
+            }
+            Term::Unop {
+                op: op @ UOp::Not,
+                term,
+            } => {
+                let term = self.set_negated_term(term);
+                quote!(#op #term).to_tokens(tokens)
+            }
             Term::Unop { op, term } => {
                 let term = self.set_term(term);
                 quote!(#op #term).to_tokens(tokens)
             }
-            Term::Binop { op, left, right } => {
+            Term::Binop {
+                op: op @ (BOp::And | BOp::Or),
+                left,
+                right,
+            } => {
                 let lft = self.set_term(left);
                 let rgt = self.set_term(right);
                 quote!(#lft #op #rgt).to_tokens(tokens)
             }
+            // comparisons of arithmetic operations are wide too
+            Term::Binop { op, left, right }
+                if self.widen && (op.is_arith() || left.is_arith() || right.is_arith()) =>
+            {
+                let lft = self.set_compared_term(left).wide_operand();
+                let rgt = self.set_compared_term(right).wide_operand();
+                quote!(#lft #op #rgt).to_tokens(tokens)
+            }
+            Term::Binop { op, left, right } => {
+                let lft = self.set_compared_term(left);
+                let rgt = self.set_compared_term(right);
+                quote!(#lft #op #rgt).to_tokens(tokens)
+            }
             Term::Literal { literal } => literal.to_logic_tokens(tokens),
             Term::Identifier { identifier, views } => {
                 identifier.to_tokens(tokens);
-                if *views {
+                if *views && self.checker.is_none() {
                     quote!(@).to_tokens(tokens)
                 }
             }
             Term::MemoryAccess { identifier, views } => {
-                if self.function_like {
+                if self.checker.is_some() {
+                    let id = identifier.to_last_var();
+                    if self.prophecy {
+                        quote!(state.).to_tokens(tokens)
+                    }
+                    id.to_tokens(tokens)
+                } else if self.function_like {
                     noErrorDesc!("unexpected function-like memory access")
                 } else {
                     let id = identifier.to_last_var();
-                    if self.prophecy {
+                    if self.init {
+                        quote!(result.).to_tokens(tokens)
+                    } else if self.prophecy {
                         quote!((^self).).to_tokens(tokens)
                     } else {
                         quote!(self.).to_tokens(tokens)
                     }
                     id.to_tokens(tokens)
                 }
-                if *views {
+                if *views && self.checker.is_none() {
                     quote!(@).to_tokens(tokens)
                 }
             }
@@ -255,7 +545,7 @@ impl ToTokens for TermTokens<'_> {
                     quote!(input.).to_tokens(tokens);
                     identifier.to_tokens(tokens)
                 };
-                if *views {
+                if *views && self.checker.is_none() {
                     quote!(@).to_tokens(tokens)
                 }
             }
@@ -266,19 +556,120 @@ impl ToTokens for TermTokens<'_> {
                     quote!(result.).to_tokens(tokens);
                     identifier.to_tokens(tokens)
                 };
-                if *views {
+                if *views && self.checker.is_none() {
                     quote!(@).to_tokens(tokens)
                 }
             }
+            Term::Implication { left, right } if self.checker.is_some() => {
+                let lft = self.set_negated_term(left);
+                let rgt = self.set_term(right);
+                quote!(!(#lft) || (#rgt)).to_tokens(tokens)
+            }
             Term::Implication { left, right } => {
-                self.set_term(left).to_tokens(tokens);
+                self.set_negated_term(left).to_tokens(tokens);
                 quote!(==>).to_tokens(tokens);
                 self.set_term(right).to_tokens(tokens);
             }
+            // a nondeterministic witness would check an `exists` instead
+            Term::Forall { name, ty, .. }
+                if self.checker == Some(Checker::Kani) && self.polarity != Polarity::Positive =>
+            {
+                let msg = format!(
+                    "Kani cannot check `forall {name}: {}` under `!`, a comparison or on the left of `=>`, bound it as `forall {name} in lo..hi`",
+                    ty.to_token_stream()
+                );
+                quote!(compile_error!(#msg)).to_tokens(tokens)
+            }
+            // checked on a nondeterministic witness, exact since the quantifier is not negated
+            Term::Forall { name, ty, term } if self.checker == Some(Checker::Kani) => {
+                let term = self.set_term(term);
+                quote!({ let #name: #ty = kani::any(); #term }).to_tokens(tokens)
+            }
+            Term::Forall { name, ty, term } if self.checker == Some(Checker::Prop) => {
+                let term = self.set_term(term);
+                quote!(
+                    grust::core::prop::witnesses::<#ty>().into_iter().all(|#name| #term)
+                )
+                .to_tokens(tokens)
+            }
             Term::Forall { name, ty, term } => {
                 let term = self.set_term(term);
                 quote!(forall < #name : #ty > #term).to_tokens(tokens)
             }
+            Term::Exists { name, ty, .. } if self.checker.is_some() => {
+                let checker = match self.checker {
+                    Some(Checker::Kani) => "Kani",
+                    _ => "property tests",
+                };
+                let msg = format!(
+                    "{checker} cannot check `exists {name}: {}`, bound it as `exists {name} in lo..hi`",
+                    ty.to_token_stream()
+                );
+                quote!(compile_error!(#msg)).to_tokens(tokens)
+            }
+            Term::Exists { name, ty, term } => {
+                let term = self.set_term(term);
+                quote!(exists < #name : #ty > #term).to_tokens(tokens)
+            }
+            Term::Bounded {
+                quantifier,
+                name,
+                lo,
+                hi,
+                term,
+            } if self.checker.is_some() => {
+                let lo = self.set_value_term(lo);
+                let hi = self.set_value_term(hi);
+                let term = self.set_term(term);
+                match quantifier {
+                    ir1::contract::Quantifier::ForAll => {
+                        quote!(((#lo)..(#hi)).all(|#name: i64| #term)).to_tokens(tokens)
+                    }
+                    ir1::contract::Quantifier::Exists => {
+                        quote!(((#lo)..(#hi)).any(|#name: i64| #term)).to_tokens(tokens)
+                    }
+                }
+            }
+            Term::Bounded {
+                quantifier,
+                name,
+                lo,
+                hi,
+                term,
+            } => {
+                let lo = self.set_term(lo);
+                let hi = self.set_term(hi);
+                let term = self.set_term(term);
+                match quantifier {
+                    ir1::contract::Quantifier::ForAll => quote!(
+                        forall < #name : i64 > (#lo) <= #name@ && #name@ < (#hi) ==> (#term)
+                    )
+                    .to_tokens(tokens),
+                    ir1::contract::Quantifier::Exists => quote!(
+                        exists < #name : i64 > (#lo) <= #name@ && #name@ < (#hi) && (#term)
+                    )
+                    .to_tokens(tokens),
+                }
+            }
+            Term::ArrayAccess { term, index, views } => {
+                let term = self.set_value_term(term);
+                let index = self.set_value_term(index);
+                if self.checker.is_some() {
+                    quote!((#term)[(#index) as usize]).to_tokens(tokens)
+                } else {
+                    quote!((#term)[#index]).to_tokens(tokens);
+                    if *views {
+                        quote!(@).to_tokens(tokens)
+                    }
+                }
+            }
+            Term::FieldAccess { term, field, views } => {
+                let term = self.set_value_term(term);
+                quote!((#term).#field).to_tokens(tokens);
+                if *views && self.checker.is_none() {
+                    quote!(@).to_tokens(tokens)
+                }
+            }
             Term::Enumeration {
                 enum_name,
                 elem_name,
@@ -288,26 +679,33 @@ impl ToTokens for TermTokens<'_> {
                 syn::token::PathSep::default().to_tokens(tokens);
                 elem_name.to_tokens(tokens);
                 if let Some(term) = element {
-                    let term = self.set_term(term);
+                    let term = self.set_value_term(term);
                     quote!((#term)).to_tokens(tokens)
                 }
             }
             Term::Ok { term } => {
-                let term = self.set_term(term);
+                let term = self.set_value_term(term);
                 quote!(Ok(#term)).to_tokens(tokens)
             }
             Term::Err => quote!(Err(())).to_tokens(tokens),
             Term::Some { term } => {
-                let term = self.set_term(term);
+                let term = self.set_value_term(term);
                 quote!(Some(#term)).to_tokens(tokens)
             }
             Term::None => quote!(None).to_tokens(tokens),
             Term::FunctionCall {
                 function,
+                path,
                 arguments,
             } => {
-                let args = arguments.iter().map(|term| self.set_term(term));
-                quote!(logical::#function(#(#args),*)).to_tokens(tokens)
+                let args = arguments.iter().map(|term| self.set_value_term(term));
+                if let Some(path) = path.as_ref().filter(|_| self.checker.is_some()) {
+                    quote!(#path(#(#args),*)).to_tokens(tokens)
+                } else if self.checker.is_some() {
+                    quote!(#function(#(#args),*)).to_tokens(tokens)
+                } else {
+                    quote!(logical::#function(#(#args),*)).to_tokens(tokens)
+                }
             }
             Term::ComponentCall { .. } => {
                 panic!("`ir2::Contract::to_tokens` does not support component calls yet")
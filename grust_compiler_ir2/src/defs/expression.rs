@@ -38,6 +38,8 @@ pub enum Expr {
     BinOp {
         /// The operator.
         op: BOp,
+        /// The arithmetic policy.
+        arith: Arith,
         /// The left expression.
         lft: Box<Self>,
         /// The right expression.
@@ -104,12 +106,16 @@ pub enum Expr {
         memory_ident: Ident,
         /// The identifier to the component.
         comp_identifier: Ident,
+        /// The component's type arguments.
+        typ_args: Vec<Typ>,
         /// The filled input's fields.
         input_fields: Vec<(Ident, Self)>,
         /// Components outputs.
         outputs: Vec<Ident>,
         /// Path to call component from.
         path_opt: Option<syn::Path>,
+        /// Reset condition, re-initializes the component's state before the step.
+        reset: Option<Box<Self>>,
     },
     /// A named or unnamed field access: `my_point.x`.
     FieldAccess {
@@ -189,6 +195,13 @@ impl Expr {
         }
         BinOp: binop {
             op: BOp,
+            arith = Arith::Native,
+            lft: Self = lft.into(),
+            rgt: Self = rgt.into(),
+        }
+        BinOp: arith_binop {
+            op: BOp,
+            arith: Arith,
             lft: Self = lft.into(),
             rgt: Self = rgt.into(),
         }
@@ -217,9 +230,20 @@ impl Expr {
         ComponentCall: comp_call {
             memory_ident: impl Into<Ident> = memory_ident.into(),
             comp_identifier: impl Into<Ident> = comp_identifier.into(),
+            typ_args: Vec<Typ>,
+            input_fields: Vec<(Ident, Self)>,
+            outputs: impl Iterator<Item = Ident> = outputs.collect(),
+            path_opt: Option<syn::Path>,
+            reset = None,
+        }
+        ComponentCall: reset_comp_call {
+            memory_ident: impl Into<Ident> = memory_ident.into(),
+            comp_identifier: impl Into<Ident> = comp_identifier.into(),
+            typ_args: Vec<Typ>,
             input_fields: Vec<(Ident, Self)>,
             outputs: impl Iterator<Item = Ident> = outputs.collect(),
             path_opt: Option<syn::Path>,
+            reset: Option<Self> = reset.map(Box::new),
         }
         FieldAccess: field_access {
             expr: Self = expr.into(),
@@ -291,7 +315,18 @@ impl Expr {
     pub fn as_op_arg_requires_parens(&self) -> bool {
         use Expr::*;
         match self {
-            BinOp { .. } | IfThenElse { .. } | Lambda { .. } => true,
+            // policy divisions and remainders are `match` expressions
+            BinOp {
+                arith: Arith::Native,
+                ..
+            }
+            | BinOp {
+                op: BOp::Div | BOp::Mod,
+                ..
+            }
+            | IfThenElse { .. }
+            | Lambda { .. } => true,
+            BinOp { .. } => false,
             Literal { .. }
             | Identifier { .. }
             | Path { .. }
@@ -351,7 +386,12 @@ impl ToTokens for Expr {
                 }
             }
             Self::UnOp { op, expr } => tokens.extend(quote!(#op (#expr))),
-            Self::BinOp { op, lft, rgt } => {
+            Self::BinOp {
+                op,
+                arith: Arith::Native,
+                lft,
+                rgt,
+            } => {
                 if lft.as_op_arg_requires_parens() {
                     quote!((#lft)).to_tokens(tokens)
                 } else {
@@ -364,18 +404,86 @@ impl ToTokens for Expr {
                     rgt.to_tokens(tokens)
                 }
             }
+            Self::BinOp {
+                op,
+                arith,
+                lft,
+                rgt,
+            } => {
+                let expr = match (arith, op) {
+                    (Arith::Wrapping, BOp::Add) => quote!(i64::wrapping_add(#lft, #rgt)),
+                    (Arith::Wrapping, BOp::Sub) => quote!(i64::wrapping_sub(#lft, #rgt)),
+                    (Arith::Wrapping, BOp::Mul) => quote!(i64::wrapping_mul(#lft, #rgt)),
+                    (Arith::Saturating, BOp::Add) => quote!(i64::saturating_add(#lft, #rgt)),
+                    (Arith::Saturating, BOp::Sub) => quote!(i64::saturating_sub(#lft, #rgt)),
+                    (Arith::Saturating, BOp::Mul) => quote!(i64::saturating_mul(#lft, #rgt)),
+                    (Arith::Checked, BOp::Add) => quote! {
+                        i64::checked_add(#lft, #rgt).expect("attempt to add with overflow")
+                    },
+                    (Arith::Checked, BOp::Sub) => quote! {
+                        i64::checked_sub(#lft, #rgt).expect("attempt to subtract with overflow")
+                    },
+                    (Arith::Checked, BOp::Mul) => quote! {
+                        i64::checked_mul(#lft, #rgt).expect("attempt to multiply with overflow")
+                    },
+                    // `x / 0` is `0`
+                    (Arith::Wrapping, BOp::Div) => quote! {
+                        match (#lft, #rgt) {
+                            (_, 0) => 0,
+                            (lft, rgt) => i64::wrapping_div(lft, rgt),
+                        }
+                    },
+                    // `x / 0` saturates towards the sign of `x`
+                    (Arith::Saturating, BOp::Div) => quote! {
+                        match (#lft, #rgt) {
+                            (0, 0) => 0,
+                            (lft, 0) if lft > 0 => i64::MAX,
+                            (_, 0) => i64::MIN,
+                            (lft, rgt) => i64::saturating_div(lft, rgt),
+                        }
+                    },
+                    (Arith::Checked, BOp::Div) => quote! {
+                        i64::checked_div(#lft, #rgt)
+                            .expect("attempt to divide by zero or with overflow")
+                    },
+                    // `x % 0` is `x`, `i64::MIN % -1` is `0`
+                    (Arith::Wrapping | Arith::Saturating, BOp::Mod) => quote! {
+                        match (#lft, #rgt) {
+                            (lft, 0) => lft,
+                            (lft, rgt) => i64::wrapping_rem(lft, rgt),
+                        }
+                    },
+                    (Arith::Checked, BOp::Mod) => quote! {
+                        i64::checked_rem(#lft, #rgt).expect(
+                            "attempt to calculate the remainder with a divisor of zero or with overflow"
+                        )
+                    },
+                    (arith, op) => noErrorDesc!(
+                        "internal error: `{arith}` policy on non-arithmetic operator `{op:?}`"
+                    ),
+                };
+                expr.to_tokens(tokens)
+            }
             Self::ComponentCall {
                 memory_ident,
                 input_fields,
                 outputs,
                 path_opt,
                 comp_identifier: name,
+                typ_args,
+                reset,
             } => {
                 let state_ty = name.to_state_ty();
                 let input_ty = name.to_input_ty();
                 let output_ty = name.to_output_ty();
-                let input_fields = input_fields.iter().map(|(name, expr)| {
-                    quote! { #name : #expr }
+                let mut input_fields = input_fields
+                    .iter()
+                    .map(|(name, expr)| quote! { #name : #expr })
+                    .collect::<Vec<_>>();
+                // generic components have a marker in their inputs and outputs
+                let rest = (!typ_args.is_empty()).then(|| {
+                    input_fields.push(quote!(_marker: std::marker::PhantomData));
+                    quote!(, ..)
                 });
                 if let Some(mut path) = path_opt.clone() {
                     path.segments.pop();
@@ -385,8 +493,16 @@ impl ToTokens for Expr {
                     state_path.segments.push(state_ty.into());
                     input_path.segments.push(input_ty.into());
                     output_path.segments.push(output_ty.into());
+                    let reset = reset.as_ref().map(|reset| {
+                        quote! {
+                            if #reset {
+                                <#state_path as grust::core::Component>::reset(&mut self.#memory_ident);
+                            }
+                        }
+                    });
                     quote! {
                         {
+                            #reset
                             let #output_path { #(#outputs),* } = <#state_path as grust::core::Component>::step(
                                 &mut self.#memory_ident, #input_path { #(#input_fields),* }
                             );
@@ -395,9 +511,18 @@ impl ToTokens for Expr {
                     }
                     .to_tokens(tokens)
                 } else {
+                    let state_ty = Typ::rust_applied(state_ty, typ_args);
+                    let reset = reset.as_ref().map(|reset| {
+                        quote! {
+                            if #reset {
+                                <#state_ty as grust::core::Component>::reset(&mut self.#memory_ident);
+                            }
+                        }
+                    });
                     quote! {
                         {
-                            let #output_ty { #(#outputs),* } = <#state_ty as grust::core::Component>::step(
+                            #reset
+                            let #output_ty { #(#outputs),* #rest } = <#state_ty as grust::core::Component>::step(
                                 &mut self.#memory_ident, #input_ty { #(#input_fields),* }
                             );
                             (#(#outputs),*)
@@ -518,15 +643,90 @@ impl ToLogicTokens for Expr {
                 }
             }
             Self::UnOp { op, expr } => quote!(#op #expr).to_tokens(tokens),
-            Self::BinOp { op, lft, rgt } => {
-                if lft.as_op_arg_requires_parens() {
+            Self::BinOp {
+                op,
+                arith: arith @ (Arith::Wrapping | Arith::Saturating),
+                lft,
+                rgt,
+            } => {
+                // logic operators are mathematical, the policies are modeled on their result
+                let (lft, rgt) = (lft.to_logic(), rgt.to_logic());
+                let (min, max) = (quote!(-9223372036854775808), quote!(9223372036854775807));
+                let modulus = quote!(18446744073709551616);
+                let clamp = quote! {
+                    if res < #min {
+                        #min
+                    } else if res > #max {
+                        #max
+                    } else {
+                        res
+                    }
+                };
+                let wrap = quote! {
+                    ((res - (#min)) % #modulus + #modulus) % #modulus + (#min)
+                };
+                let model = if let Arith::Wrapping = arith {
+                    &wrap
+                } else {
+                    &clamp
+                };
+                let expr = match op {
+                    BOp::Add | BOp::Sub | BOp::Mul => quote! {
+                        match (#lft) #op (#rgt) {
+                            res => #model,
+                        }
+                    },
+                    // `x / 0` is `0` when wrapping, saturates towards the sign of `x` otherwise
+                    BOp::Div => {
+                        let by_zero = if let Arith::Wrapping = arith {
+                            quote!(0)
+                        } else {
+                            quote! {
+                                if lft == 0 {
+                                    0
+                                } else if lft > 0 {
+                                    #max
+                                } else {
+                                    #min
+                                }
+                            }
+                        };
+                        quote! {
+                            match (#lft, #rgt) {
+                                (lft, 0) => #by_zero,
+                                (lft, rgt) => match lft / rgt {
+                                    res => #model,
+                                },
+                            }
+                        }
+                    }
+                    // `x % 0` is `x`, `i64::MIN % -1` is `0` which is its mathematical value
+                    BOp::Mod => quote! {
+                        match (#lft, #rgt) {
+                            (lft, 0) => lft,
+                            (lft, rgt) => lft % rgt,
+                        }
+                    },
+                    op => noErrorDesc!(
+                        "internal error: `{arith}` policy on non-arithmetic operator `{op:?}`"
+                    ),
+                };
+                expr.to_tokens(tokens)
+            }
+            Self::BinOp { op, lft, rgt, .. } => {
+                // logic operators are mathematical, checked operations cannot overflow as their
+                // proof requires the absence of panics
+                let requires_parens = |expr: &Self| {
+                    matches!(expr, Self::BinOp { .. }) || expr.as_op_arg_requires_parens()
+                };
+                if requires_parens(lft) {
                     let expr = lft.to_logic();
                     quote!( (#expr) ).to_tokens(tokens)
                 } else {
                     lft.to_logic_tokens(tokens)
                 }
                 op.to_tokens(tokens);
-                if rgt.as_op_arg_requires_parens() {
+                if requires_parens(rgt) {
                     let expr = rgt.to_logic();
                     quote!( (#expr) ).to_tokens(tokens)
                 } else {
@@ -536,16 +736,23 @@ impl ToLogicTokens for Expr {
             Self::ComponentCall {
                 memory_ident,
                 comp_identifier: name,
+                typ_args,
                 input_fields,
                 path_opt,
                 ..
             } => {
                 let state_ty = name.to_state_ty();
                 let input_ty = name.to_input_ty();
-                let input_fields = input_fields.iter().map(|(field, expr)| {
-                    let expr = expr.to_logic();
-                    quote!(#field : #expr)
-                });
+                let mut input_fields = input_fields
+                    .iter()
+                    .map(|(field, expr)| {
+                        let expr = expr.to_logic();
+                        quote!(#field : #expr)
+                    })
+                    .collect::<Vec<_>>();
+                if !typ_args.is_empty() {
+                    input_fields.push(quote!(_marker: std::marker::PhantomData))
+                }
                 if let Some(mut path) = path_opt.clone() {
                     path.segments.pop();
                     quote! {
@@ -555,6 +762,7 @@ impl ToLogicTokens for Expr {
                     }
                     .to_tokens(tokens)
                 } else {
+                    let state_ty = Typ::rust_applied(state_ty, typ_args);
                     quote! {
                         <#state_ty as grust::core::Component>::step(
                             &mut self.#memory_ident, #input_ty { #(#input_fields),* }
@@ -780,11 +988,45 @@ mod test {
         assert_eq!(expr, control)
     }
 
+    #[test]
+    fn should_create_rust_ast_saturating_binary_from_ir2_binary() {
+        let expression = Expr::arith_binop(
+            BOp::Add,
+            Arith::Saturating,
+            Expr::test_ident("a"),
+            Expr::test_ident("b"),
+        );
+
+        let control = parse_quote! { i64::saturating_add(a, b) };
+        let expr: syn::Expr = parse_quote!(#expression);
+        assert_eq!(expr, control)
+    }
+
+    #[test]
+    fn should_create_rust_ast_wrapping_division_from_ir2_binary() {
+        let expression = Expr::arith_binop(
+            BOp::Div,
+            Arith::Wrapping,
+            Expr::test_ident("a"),
+            Expr::test_ident("b"),
+        );
+
+        let control = parse_quote! {
+            match (a, b) {
+                (_, 0) => 0,
+                (lft, rgt) => i64::wrapping_div(lft, rgt),
+            }
+        };
+        let expr: syn::Expr = parse_quote!(#expression);
+        assert_eq!(expr, control)
+    }
+
     #[test]
     fn should_create_rust_ast_method_call_from_ir2_comp_call() {
         let expression = Expr::comp_call(
             Loc::test_id("comp_state"),
             Loc::test_id("component"),
+            vec![Typ::int()],
             vec![(
                 Loc::test_id("i"),
                 Expr::Literal {
@@ -796,7 +1038,10 @@ mod test {
         );
 
         let control = parse_quote! { {
-            let ComponentOutput {out} = <ComponentState as grust::core::Component>::step(&mut self.comp_state, ComponentInput { i : 1i64 });
+            let ComponentOutput {out, ..} = <ComponentState<i64> as grust::core::Component>::step(
+                &mut self.comp_state,
+                ComponentInput { i : 1i64, _marker: std::marker::PhantomData }
+            );
             (out)
         } };
         let expr: syn::Expr = parse_quote!(#expression);
@@ -808,6 +1053,7 @@ mod test {
         let expression = Expr::comp_call(
             Loc::test_id("comp_state"),
             Loc::test_id("component"),
+            vec![],
             vec![(
                 Loc::test_id("i"),
                 Expr::Literal {
@@ -826,6 +1072,34 @@ mod test {
         assert_eq!(expr, control)
     }
 
+    #[test]
+    fn should_create_rust_ast_method_call_from_ir2_reset_comp_call() {
+        let expression = Expr::reset_comp_call(
+            Loc::test_id("comp_state"),
+            Loc::test_id("component"),
+            vec![],
+            vec![(
+                Loc::test_id("i"),
+                Expr::Literal {
+                    literal: Constant::Integer(parse_quote!(1i64)),
+                },
+            )],
+            std::iter::once(Loc::test_id("out")),
+            None,
+            Some(Expr::test_ident("r")),
+        );
+
+        let control = parse_quote! { {
+            if r {
+                <ComponentState as grust::core::Component>::reset(&mut self.comp_state);
+            }
+            let ComponentOutput {out} = <ComponentState as grust::core::Component>::step(&mut self.comp_state, ComponentInput { i : 1i64 });
+            (out)
+        } };
+        let expr: syn::Expr = parse_quote!(#expression);
+        assert_eq!(expr, control)
+    }
+
     #[test]
     fn should_create_rust_ast_field_access_from_ir2_field_access() {
         let expression = Expr::field_access(
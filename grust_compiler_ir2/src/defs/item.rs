@@ -3,12 +3,15 @@
 prelude! {}
 
 pub use self::{
-    array_alias::ArrayAlias, enumeration::Enumeration, function::Function, structure::Structure,
+    array_alias::ArrayAlias, enumeration::Enumeration, ext_signature::ExtSignature,
+    function::Function, scenario::Scenario, structure::Structure,
 };
 
 mod array_alias;
 mod enumeration;
+mod ext_signature;
 mod function;
+pub mod scenario;
 mod structure;
 
 /// An item of the project.
@@ -26,4 +29,8 @@ pub enum Item {
     Structure(Structure),
     /// An array alias definition.
     ArrayAlias(ArrayAlias),
+    /// Signature check of an external function or component.
+    ExtSignature(ExtSignature),
+    /// A test scenario of a component.
+    Scenario(Scenario),
 }
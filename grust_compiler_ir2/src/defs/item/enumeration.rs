@@ -22,13 +22,23 @@ pub struct EnumerationTokens<'a> {
     e: &'a Enumeration,
     public: bool,
     greusot: bool,
+    checker: Option<contract::Checker>,
+    c_abi: bool,
 }
 impl Enumeration {
-    pub fn prepare_tokens(&self, public: bool, greusot: bool) -> EnumerationTokens<'_> {
+    pub fn prepare_tokens(
+        &self,
+        public: bool,
+        greusot: bool,
+        checker: Option<contract::Checker>,
+        c_abi: bool,
+    ) -> EnumerationTokens<'_> {
         EnumerationTokens {
             e: self,
             public,
             greusot,
+            checker,
+            c_abi,
         }
     }
 }
@@ -40,6 +50,12 @@ impl ToTokens for EnumerationTokens<'_> {
         } else {
             quote!(#[derive(Clone, Copy, PartialEq, Default, Debug)]).to_tokens(tokens)
         }
+        if self.checker == Some(contract::Checker::Kani) {
+            quote!(#[cfg_attr(kani, derive(kani::Arbitrary))]).to_tokens(tokens)
+        }
+        if self.c_abi {
+            quote!(#[repr(C)]).to_tokens(tokens)
+        }
         let name = &self.e.name;
         let variants = self.e.elements.iter().enumerate().map(|(index, element)| {
             let attr = if !self.greusot && (index == 0) {
@@ -59,7 +75,38 @@ impl ToTokens for EnumerationTokens<'_> {
                 #(#variants),*
             }
         }
-        .to_tokens(tokens)
+        .to_tokens(tokens);
+
+        if self.checker == Some(contract::Checker::Prop) {
+            let count = syn::LitInt::new(&self.e.elements.len().to_string(), name.span());
+            let arms = self.e.elements.iter().enumerate().map(|(index, element)| {
+                if index + 1 < self.e.elements.len() {
+                    let index = syn::LitInt::new(&index.to_string(), element.span());
+                    quote! { #index => Self::#element }
+                } else {
+                    quote! { _ => Self::#element }
+                }
+            });
+            // shrinks towards the first element, the default one
+            let first = &self.e.elements[0];
+            quote! {
+                impl grust::core::prop::Arbitrary for #name {
+                    fn arbitrary(rng: &mut grust::core::prop::Rng) -> Self {
+                        match rng.below(#count) {
+                            #(#arms),*
+                        }
+                    }
+                    fn shrink(&self) -> Vec<Self> {
+                        if *self == Self::#first {
+                            vec![]
+                        } else {
+                            vec![Self::#first]
+                        }
+                    }
+                }
+            }
+            .to_tokens(tokens)
+        }
     }
 }
 
@@ -77,7 +124,7 @@ mod test {
                 Loc::test_id("Green"),
             ],
         )
-        .prepare_tokens(true, false)
+        .prepare_tokens(true, false, None, false)
         .to_token_stream();
 
         let control = parse_quote! {
@@ -0,0 +1,153 @@
+//! [ExtSignature] module.
+
+prelude! {}
+
+/// Signature of an external function or component, as declared in GRust.
+///
+/// Generates a static assertion checking the declaration against the actual Rust item, so that
+/// mismatches are reported on the `use function`/`use component` declaration instead of inside
+/// the generated code.
+#[derive(Debug, PartialEq)]
+pub enum ExtSignature {
+    /// External function `use function path(inputs) -> output;`.
+    Function {
+        /// Path to the Rust function.
+        path: syn::Path,
+        /// Input types.
+        inputs: Vec<Typ>,
+        /// Output type.
+        output: Typ,
+        /// Location of the declaration.
+        loc: Loc,
+    },
+    /// External component `use component path(inputs) -> (outputs);`.
+    Component {
+        /// Path to the component, its state is `path::NameState`.
+        path: syn::Path,
+        /// Inputs.
+        inputs: Vec<(Ident, Typ)>,
+        /// Outputs.
+        outputs: Vec<(Ident, Typ)>,
+        /// Location of the declaration.
+        loc: Loc,
+    },
+}
+mk_new! { impl ExtSignature =>
+    Function: function {
+        path: syn::Path,
+        inputs: Vec<Typ>,
+        output: Typ,
+        loc: impl Into<Loc> = loc.into(),
+    }
+    Component: component {
+        path: syn::Path,
+        inputs: Vec<(Ident, Typ)>,
+        outputs: Vec<(Ident, Typ)>,
+        loc: impl Into<Loc> = loc.into(),
+    }
+}
+
+impl ToTokens for ExtSignature {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        match self {
+            Self::Function {
+                path,
+                inputs,
+                output,
+                loc,
+            } => {
+                // coercion of the function to the declared function pointer type
+                quote_spanned! {loc.span =>
+                    const _: fn(#(#inputs),*) -> #output = #path;
+                }
+                .to_tokens(tokens)
+            }
+            Self::Component {
+                path,
+                inputs,
+                outputs,
+                loc,
+            } => {
+                let with_suffix = |ident: Ident| {
+                    let mut path = path.clone();
+                    let _ = path.segments.pop();
+                    path.segments.push(ident.into());
+                    path
+                };
+                let name = &path
+                    .segments
+                    .last()
+                    .expect("internal error: empty component path")
+                    .ident;
+                let state = with_suffix(name.to_state_ty());
+                let input = with_suffix(name.to_input_ty());
+                let output = with_suffix(name.to_output_ty());
+                let (in_names, in_typs): (Vec<_>, Vec<_>) = inputs.iter().cloned().unzip();
+                let (out_names, out_typs): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+                // the closure builds the input and destructures the output, all their fields
+                // must be declared with the right types
+                quote_spanned! {loc.span =>
+                    #[allow(clippy::type_complexity)]
+                    const _: fn(#(#in_typs),*) -> (#(#out_typs,)*) = |#(#in_names),*| {
+                        let #output { #(#out_names),* } = <#state as grust::core::Component>::step(
+                            &mut <#state as grust::core::Component>::init(),
+                            #input { #(#in_names),* },
+                        );
+                        (#(#out_names,)*)
+                    };
+                }
+                .to_tokens(tokens)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_create_rust_ast_static_assertion_from_ir2_ext_function() {
+        let signature = ExtSignature::function(
+            parse_quote!(utils::convert),
+            vec![Typ::float()],
+            Typ::float(),
+            Loc::test_dummy(),
+        );
+        let control: syn::ItemConst = parse_quote! {
+            const _: fn(f64) -> f64 = utils::convert;
+        };
+        assert_eq!(
+            syn::parse2::<syn::ItemConst>(signature.to_token_stream()).unwrap(),
+            control
+        )
+    }
+
+    #[test]
+    fn should_create_rust_ast_static_assertion_from_ir2_ext_component() {
+        let signature = ExtSignature::component(
+            parse_quote!(grust::std::time::derivation::derive),
+            vec![
+                (Loc::test_id("x"), Typ::float()),
+                (Loc::test_id("t"), Typ::float()),
+            ],
+            vec![(Loc::test_id("d"), Typ::float())],
+            Loc::test_dummy(),
+        );
+        let control: syn::ItemConst = parse_quote! {
+            #[allow(clippy::type_complexity)]
+            const _: fn(f64, f64) -> (f64,) = |x, t| {
+                let grust::std::time::derivation::DeriveOutput { d } =
+                    <grust::std::time::derivation::DeriveState as grust::core::Component>::step(
+                        &mut <grust::std::time::derivation::DeriveState as grust::core::Component>::init(),
+                        grust::std::time::derivation::DeriveInput { x, t },
+                    );
+                (d,)
+            };
+        };
+        assert_eq!(
+            syn::parse2::<syn::ItemConst>(signature.to_token_stream()).unwrap(),
+            control
+        )
+    }
+}
@@ -7,6 +7,8 @@ prelude! { Block }
 pub struct Function {
     /// The function's name.
     pub name: Ident,
+    /// The type parameters, as [Typ::Param]s.
+    pub typ_params: Vec<Typ>,
     /// The inputs.
     pub inputs: Vec<(Ident, Typ)>,
     /// The output type.
@@ -19,6 +21,7 @@ pub struct Function {
 
 mk_new! { impl Function => new {
     name: impl Into<Ident> = name.into(),
+    typ_params: Vec<Typ>,
     inputs: Vec<(Ident, Typ)>,
     output: Typ,
     body: Block,
@@ -29,6 +32,7 @@ impl Function {
     pub fn to_def_and_logic_tokens(&self, ctx: &ir0::Ctx) -> (TokenStream2, Option<TokenStream2>) {
         let inputs = self.inputs.iter().map(|(name, typ)| quote!( #name: #typ ));
         let name = &self.name;
+        let generics = Typ::rust_generics(&self.typ_params);
         let output = &self.output;
         let pub_token = if ctx.conf.public {
             quote! {pub}
@@ -63,20 +67,20 @@ impl Function {
                 quote! {
                     #contract
                     #[ensures(#logic_result == logical::#name(#(#logic_args),*))]
-                    #pub_token fn #name(#(#inputs),*) -> #output
+                    #pub_token fn #name #generics(#(#inputs),*) -> #output
                     #body
                 },
                 Some(quote! {
                     #[open]
                     #[logic]
-                    pub fn #name(#(#logic_inputs),*) -> #logic_output
+                    pub fn #name #generics(#(#logic_inputs),*) -> #logic_output
                     #logic_body
                 }),
             )
         } else {
             let body = &self.body;
             (
-                quote! { #pub_token fn #name(#(#inputs),*) -> #output #body },
+                quote! { #pub_token fn #name #generics(#(#inputs),*) -> #output #body },
                 None,
             )
         }
@@ -92,6 +96,7 @@ mod test {
         // use item::{Block, Function, Stmt};
         let (function, _) = Function {
             name: Loc::test_id("add"),
+            typ_params: vec![],
             inputs: vec![
                 (Loc::test_id("a"), Typ::int()),
                 (Loc::test_id("b"), Typ::int()),
@@ -114,4 +119,45 @@ mod test {
         let function: syn::ItemFn = parse_quote!(#function);
         assert_eq!(function, control)
     }
+
+    #[test]
+    fn should_create_rust_ast_generic_function_from_ir2_function() {
+        let typ_t = Typ::param(Loc::test_id("T"), vec![Constraint::Ordered]);
+        let (function, _) = Function {
+            name: Loc::test_id("max"),
+            typ_params: vec![typ_t.clone()],
+            inputs: vec![
+                (Loc::test_id("a"), typ_t.clone()),
+                (Loc::test_id("b"), typ_t.clone()),
+            ],
+            output: typ_t,
+            body: Block {
+                statements: vec![Stmt::ExprLast {
+                    expr: Expr::ite(
+                        Expr::binop(BOp::Ge, Expr::test_ident("a"), Expr::test_ident("b")),
+                        Block {
+                            statements: vec![Stmt::ExprLast {
+                                expr: Expr::test_ident("a"),
+                            }],
+                        },
+                        Block {
+                            statements: vec![Stmt::ExprLast {
+                                expr: Expr::test_ident("b"),
+                            }],
+                        },
+                    ),
+                }],
+            },
+            contract: Default::default(),
+        }
+        .to_def_and_logic_tokens(&ir0::Ctx::empty());
+
+        let control = parse_quote! {
+            pub fn max<T: Copy + Default + std::fmt::Debug + PartialOrd>(a: T, b: T) -> T {
+                if a >= b { a } else { b }
+            }
+        };
+        let function: syn::ItemFn = parse_quote!(#function);
+        assert_eq!(function, control)
+    }
 }
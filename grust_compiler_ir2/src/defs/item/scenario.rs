@@ -0,0 +1,138 @@
+//! [Scenario] module.
+
+prelude! {}
+
+/// Value of an input or an output in a step.
+#[derive(Debug, PartialEq)]
+pub enum Value {
+    /// Present event.
+    Some(Expr),
+    /// Absent event.
+    None,
+    /// Signal value.
+    Expr(Expr),
+}
+impl ToTokens for Value {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        match self {
+            Self::Some(expr) => quote!(Some(#expr)).to_tokens(tokens),
+            Self::None => quote!(None).to_tokens(tokens),
+            Self::Expr(expr) => expr.to_tokens(tokens),
+        }
+    }
+}
+
+/// A step of a scenario.
+#[derive(Debug, PartialEq)]
+pub struct Step {
+    /// Values of all the inputs of the component.
+    pub inputs: Vec<(Ident, Value)>,
+    /// Expected values of some outputs.
+    pub expect: Vec<(Ident, Value)>,
+}
+mk_new! { impl Step =>
+    new {
+        inputs: Vec<(Ident, Value)>,
+        expect: Vec<(Ident, Value)>,
+    }
+}
+
+/// A test scenario of a component, compiled into a `#[test]` function.
+#[derive(Debug, PartialEq)]
+pub struct Scenario {
+    /// Name of the test.
+    pub name: Ident,
+    /// Name of the component under test.
+    pub comp_name: Ident,
+    /// Steps of the scenario.
+    pub steps: Vec<Step>,
+    /// Outputs compared with the ones of the sequential step, when differential testing.
+    pub diff: Option<Vec<Ident>>,
+}
+mk_new! { impl Scenario =>
+    new {
+        name: impl Into<Ident> = name.into(),
+        comp_name: impl Into<Ident> = comp_name.into(),
+        steps: Vec<Step>,
+        diff: Option<Vec<Ident>>,
+    }
+}
+
+impl ToTokens for Scenario {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let name = &self.name;
+        let state_ty = self.comp_name.to_state_ty();
+        let input_ty = self.comp_name.to_input_ty();
+
+        let seq_state = self.diff.is_some().then(|| {
+            quote! { let mut seq_state = <#state_ty as grust::core::Component>::init(); }
+        });
+
+        let steps = self.steps.iter().enumerate().map(|(idx, step)| {
+            let (input_names, input_values): (Vec<_>, Vec<_>) = step
+                .inputs
+                .iter()
+                .map(|(name, value)| (name, value))
+                .unzip();
+            // inputs are reported with the failures
+            let inputs = (!step.expect.is_empty()).then(|| {
+                let format = input_names
+                    .iter()
+                    .map(|name| format!("{name} = {{:?}}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let format = syn::LitStr::new(&format, name.span());
+                quote! { let inputs = format!(#format, #(input.#input_names),*); }
+            });
+            let checks = step.expect.iter().map(|(output, value)| {
+                let msg = format!(
+                    "test `{name}` failed at step {idx}: `{output}` is {{:?}}, expected {{:?}}\n  \
+                    inputs: {{}}"
+                );
+                let msg = syn::LitStr::new(&msg, name.span());
+                quote! {
+                    let expected = #value;
+                    assert!(output.#output == expected, #msg, output.#output, expected, inputs);
+                }
+            });
+            let diff = self.diff.as_ref().map(|outputs| {
+                let msg = format!(
+                    "test `{name}` failed at step {idx}: outputs `({})` are {{}} in parallel \
+                    but {{}} sequentially",
+                    outputs
+                        .iter()
+                        .map(|name| name.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+                let msg = syn::LitStr::new(&msg, name.span());
+                quote! {
+                    let seq_output =
+                        seq_state.step_seq(#input_ty { #(#input_names: #input_values),* });
+                    let para_outputs = format!("{:?}", (#(output.#outputs,)*));
+                    let seq_outputs = format!("{:?}", (#(seq_output.#outputs,)*));
+                    assert!(para_outputs == seq_outputs, #msg, para_outputs, seq_outputs);
+                }
+            });
+            quote! {{
+                let input = #input_ty { #(#input_names: #input_values),* };
+                #inputs
+                let output = <#state_ty as grust::core::Component>::step(&mut state, input);
+                #(#checks)*
+                #diff
+            }}
+        });
+
+        quote! {
+            #[cfg(test)]
+            #[test]
+            #[allow(unused_variables)]
+            fn #name() {
+                let mut state = <#state_ty as grust::core::Component>::init();
+                #seq_state
+                #(#steps)*
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
@@ -22,13 +22,23 @@ pub struct StructureTokens<'a> {
     s: &'a Structure,
     public: bool,
     greusot: bool,
+    checker: Option<contract::Checker>,
+    c_abi: bool,
 }
 impl Structure {
-    pub fn prepare_tokens(&self, public: bool, greusot: bool) -> StructureTokens<'_> {
+    pub fn prepare_tokens(
+        &self,
+        public: bool,
+        greusot: bool,
+        checker: Option<contract::Checker>,
+        c_abi: bool,
+    ) -> StructureTokens<'_> {
         StructureTokens {
             s: self,
             public,
             greusot,
+            checker,
+            c_abi,
         }
     }
 }
@@ -43,6 +53,12 @@ impl ToTokens for StructureTokens<'_> {
         } else {
             quote!(#[derive(Clone, Copy, PartialEq, Default, Debug)]).to_tokens(tokens)
         };
+        if self.checker == Some(contract::Checker::Kani) {
+            quote!(#[cfg_attr(kani, derive(kani::Arbitrary))]).to_tokens(tokens)
+        }
+        if self.c_abi {
+            quote!(#[repr(C)]).to_tokens(tokens)
+        }
         let pub_token = if self.public {
             quote! {pub}
         } else {
@@ -59,7 +75,32 @@ impl ToTokens for StructureTokens<'_> {
                 #(#fields),*
             }
         }
-        .to_tokens(tokens)
+        .to_tokens(tokens);
+
+        if self.checker == Some(contract::Checker::Prop) {
+            let names = self.s.fields.iter().map(|(name, _)| name);
+            let shrunk_names = names.clone();
+            // shrinks one field at a time
+            quote! {
+                impl grust::core::prop::Arbitrary for #name {
+                    fn arbitrary(rng: &mut grust::core::prop::Rng) -> Self {
+                        Self {
+                            #(#names: grust::core::prop::Arbitrary::arbitrary(rng)),*
+                        }
+                    }
+                    fn shrink(&self) -> Vec<Self> {
+                        let mut simpler = vec![];
+                        #(
+                            for value in grust::core::prop::Arbitrary::shrink(&self.#shrunk_names) {
+                                simpler.push(Self { #shrunk_names: value, ..*self })
+                            }
+                        )*
+                        simpler
+                    }
+                }
+            }
+            .to_tokens(tokens)
+        }
     }
 }
 
@@ -76,7 +117,7 @@ mod test {
                 (Loc::test_id("y"), Typ::int()),
             ],
         )
-        .prepare_tokens(true, false)
+        .prepare_tokens(true, false, None, false)
         .to_token_stream();
 
         let control = parse_quote! {
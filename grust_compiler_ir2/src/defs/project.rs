@@ -6,6 +6,8 @@ prelude! {}
 pub struct Project {
     /// The project's items.
     pub items: Vec<Item>,
+    /// The mutants of the components, in mutation testing.
+    pub mutants: Vec<mutation::Mutant>,
 }
 
 pub struct ProjectTokens<'a> {
@@ -41,15 +43,40 @@ impl ToTokens for ProjectTokens<'_> {
             .to_tokens(tokens)
         }
 
+        let checker = if ctx.conf.mode.kani() {
+            Some(contract::Checker::Kani)
+        } else if ctx.conf.mode.test() && (ctx.conf.prop_test || ctx.conf.diff_testing()) {
+            Some(contract::Checker::Prop)
+        } else {
+            None
+        };
+        let harness = match checker {
+            Some(contract::Checker::Kani) => Some(state_machine::Harness::Kani {
+                depth: ctx.conf.kani_depth,
+            }),
+            Some(contract::Checker::Prop) if ctx.conf.prop_test => {
+                Some(state_machine::Harness::Prop {
+                    cases: ctx.conf.prop_cases,
+                    depth: ctx.conf.prop_depth,
+                })
+            }
+            Some(contract::Checker::Prop) | None => None,
+        };
+        let diff = ctx.conf.diff_testing().then_some(state_machine::Diff {
+            cases: ctx.conf.prop_cases,
+            depth: ctx.conf.prop_depth,
+        });
+
         for item in self.project.items.iter() {
             match item {
                 Item::ExecutionMachine(em) => {
-                    if ctx.conf.mode.test() || ctx.conf.mode.demo() {
+                    if ctx.conf.mode.test() || ctx.conf.mode.demo() || ctx.conf.mode.kani() {
                         em.prepare_tokens(
-                            ctx.conf.mode.demo(),
+                            ctx.conf.mode.demo() || ctx.conf.mode.kani(),
                             ctx.conf.mode.test(),
                             &ctx.conf.spawn_with,
                             &ctx.conf.get_handle,
+                            ctx.conf.instrument_coverage(),
                         )
                         .to_tokens(tokens)
                     }
@@ -60,6 +87,9 @@ impl ToTokens for ProjectTokens<'_> {
                         ctx.conf.align,
                         ctx.conf.public,
                         ctx.conf.tracing,
+                        ctx.conf.c_abi,
+                        harness,
+                        diff,
                     )
                     .to_tokens(tokens),
                 Item::Function(fun) => {
@@ -70,13 +100,41 @@ impl ToTokens for ProjectTokens<'_> {
                     }
                 }
                 Item::Enumeration(enumeration) => enumeration
-                    .prepare_tokens(ctx.conf.public, ctx.conf.mode.greusot())
+                    .prepare_tokens(
+                        ctx.conf.public,
+                        ctx.conf.mode.greusot(),
+                        checker,
+                        ctx.conf.c_abi,
+                    )
                     .to_tokens(tokens),
                 Item::Structure(structure) => structure
-                    .prepare_tokens(ctx.conf.public, ctx.conf.mode.greusot())
+                    .prepare_tokens(
+                        ctx.conf.public,
+                        ctx.conf.mode.greusot(),
+                        checker,
+                        ctx.conf.c_abi,
+                    )
                     .to_tokens(tokens),
                 Item::ArrayAlias(alias) => alias.prepare_tokens(ctx.conf.public).to_tokens(tokens),
+                Item::ExtSignature(signature) => signature.to_tokens(tokens),
+                Item::Scenario(scenario) => {
+                    if ctx.conf.mode.test() {
+                        scenario.to_tokens(tokens)
+                    }
+                }
+            }
+        }
+
+        if ctx.conf.instrument_coverage() {
+            self.coverage_tokens(tokens)
+        }
+
+        if ctx.conf.mutation_testing() {
+            mutation::MutantsTokens {
+                mutants: &self.project.mutants,
+                mutant: ctx.conf.mutant.as_ref(),
             }
+            .to_tokens(tokens)
         }
 
         if let Some(logic) = logic_fun {
@@ -91,3 +149,49 @@ impl ToTokens for ProjectTokens<'_> {
         }
     }
 }
+
+impl ProjectTokens<'_> {
+    /// The `coverage_report` and `reset_coverage` functions, over all the probes tables.
+    fn coverage_tokens(&self, tokens: &mut TokenStream2) {
+        let with_services =
+            self.ctx.conf.mode.test() || self.ctx.conf.mode.demo() || self.ctx.conf.mode.kani();
+        let mut owners = vec![];
+        for item in self.project.items.iter() {
+            match item {
+                Item::StateMachine(sm) if !sm.coverage.is_empty() => {
+                    let state_ty = sm.name.to_state_ty();
+                    if sm.is_generic() {
+                        // any instance gives the probes shared by all of them
+                        let units = sm.state.typ_params.iter().map(|_| quote!(()));
+                        owners.push(quote!(#state_ty::<#(#units),*>))
+                    } else {
+                        owners.push(quote!(#state_ty))
+                    }
+                }
+                Item::ExecutionMachine(em) if with_services => {
+                    for handler in em.services_handlers.iter() {
+                        if !handler.flow_handlers.is_empty() {
+                            let mod_ident = &handler.service_mod_ident;
+                            let service_ty = &handler.service_struct_ident;
+                            owners.push(quote!(runtime::#mod_ident::#service_ty))
+                        }
+                    }
+                }
+                _ => (),
+            }
+        }
+        quote! {
+            /// Coverage of the process, counters are shared by all instances and threads.
+            pub fn coverage_report() -> grust::core::coverage::Report {
+                let mut report = grust::core::coverage::Report::new();
+                #(report.add(#owners::coverage());)*
+                report
+            }
+            /// Resets the coverage counters of the process.
+            pub fn reset_coverage() {
+                #(#owners::coverage().reset();)*
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
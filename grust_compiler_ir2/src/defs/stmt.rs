@@ -22,6 +22,11 @@ pub enum Stmt {
         /// The returned expression.
         expr: Expr,
     },
+    /// Coverage probe: `Self::coverage().hit(3);`.
+    Probe {
+        /// The probe.
+        probe: coverage::Probe,
+    },
 }
 
 mk_new! { impl Stmt =>
@@ -31,6 +36,7 @@ mk_new! { impl Stmt =>
     }
     ExprLast: expr_last { expr: Expr }
     Log: log { ident: syn::Ident, expr: Expr }
+    Probe: probe { probe: coverage::Probe }
 }
 
 impl ToTokens for Stmt {
@@ -46,6 +52,7 @@ impl ToTokens for Stmt {
                     println!(#str, #expr);
                 })
             }
+            Self::Probe { probe } => tokens.extend(probe.hit_tokens()),
         }
     }
 }
@@ -61,6 +68,8 @@ impl ToLogicTokens for Stmt {
                 let str = format!("{}: {{:?}}", ident);
                 tokens.extend(quote!(println!(#str, #expr);))
             }
+            // probes count executions, they have no logical meaning
+            Self::Probe { .. } => (),
         }
     }
 }
@@ -90,6 +99,7 @@ mod test {
             Expr::comp_call(
                 Loc::test_id("comp_state"),
                 Loc::test_id("component"),
+                vec![],
                 vec![(
                     Loc::test_id("i"),
                     Expr::lit(Constant::int(parse_quote!(1i64))),
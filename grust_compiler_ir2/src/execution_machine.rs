@@ -19,12 +19,27 @@ pub struct ExecutionMachine {
     /// The services handlers.
     pub services_handlers: Vec<ServiceHandler>,
 }
+impl ExecutionMachine {
+    /// Tells if interface contracts are checked at runtime.
+    pub fn checks_contracts(&self) -> bool {
+        self.input_flows
+            .iter()
+            .chain(self.output_flows.iter())
+            .any(|flow| !flow.checks.is_empty())
+            || self
+                .services_handlers
+                .iter()
+                .any(|handler| !handler.contract.is_empty())
+    }
+}
+
 pub struct ExecutionMachineTokens<'a> {
     em: &'a ExecutionMachine,
     demo: bool,
     test: bool,
     spawn_fn: &'a Option<syn::Path>,
     handle_ty: &'a Option<syn::Type>,
+    coverage: bool,
 }
 impl ExecutionMachine {
     pub fn prepare_tokens<'a>(
@@ -33,6 +48,7 @@ impl ExecutionMachine {
         test: bool,
         spawn_fn: &'a Option<syn::Path>,
         handle_ty: &'a Option<syn::Type>,
+        coverage: bool,
     ) -> ExecutionMachineTokens<'a> {
         ExecutionMachineTokens {
             em: self,
@@ -40,6 +56,7 @@ impl ExecutionMachine {
             test,
             spawn_fn,
             handle_ty,
+            coverage,
         }
     }
 }
@@ -80,7 +97,8 @@ impl ToTokens for ExecutionMachineTokens<'_> {
                         }
                         TimingEventKind::Timeout(duration)
                         | TimingEventKind::ServiceTimeout(duration)
-                        | TimingEventKind::ServiceDelay(duration) => {
+                        | TimingEventKind::ServiceDelay(duration)
+                        | TimingEventKind::ResponseDeadline(duration) => {
                             timer_duration_arms.push(quote! { T::#enum_ident => {
                                 std::time::Duration::from_millis(#duration)
                             } });
@@ -107,6 +125,11 @@ impl ToTokens for ExecutionMachineTokens<'_> {
                     let enum_ident = ident.to_camel();
                     output_variants.push(quote! { #enum_ident(#typ, std::time::Instant) });
                 }
+                if self.em.checks_contracts() {
+                    // dedicated output flow reporting contract violations
+                    output_variants
+                        .push(quote! { ContractViolation(&'static str, std::time::Instant) });
+                }
 
                 if !timer_variants.is_empty() {
                     input_variants.push(parse_quote! { Timer(T, std::time::Instant) });
@@ -131,6 +154,14 @@ impl ToTokens for ExecutionMachineTokens<'_> {
                     field_values.push(service_ident.to_token_stream())
                 }
 
+                for flow in self.em.input_flows.iter() {
+                    if flow.has_rate_check() {
+                        let last = flow.last_instant_field();
+                        runtime_fields.push(quote! { #last: Option<std::time::Instant> });
+                        field_values.push(quote! { #last: None });
+                    }
+                }
+
                 runtime_fields.push(quote!(output : grust::futures::channel::mpsc::Sender<O>));
                 field_values.push(quote!(output));
                 if !timer_variants.is_empty() {
@@ -339,7 +370,7 @@ impl ToTokens for ExecutionMachineTokens<'_> {
             // services handler functions
             for handler in self.em.services_handlers.iter() {
                 handler
-                    .prepare_tokens(!timer_variants.is_empty())
+                    .prepare_tokens(!timer_variants.is_empty(), self.coverage)
                     .to_tokens(&mut tokens)
             }
 
@@ -349,7 +380,8 @@ impl ToTokens for ExecutionMachineTokens<'_> {
         // the `run` function to be used directly
         let run_fn = {
             // compute channel and stream sizes
-            let output_channel_size = self.em.output_flows.len();
+            let output_channel_size =
+                self.em.output_flows.len() + usize::from(self.em.checks_contracts());
             let timer_channel_size = self.em.timing_events.len();
             let prio_stream_size = self.em.input_flows.len() + 1;
             let timer_stream_size = self.em.timing_events.len();
@@ -475,6 +507,15 @@ impl ToTokens for ExecutionMachineTokens<'_> {
     }
 }
 
+/// Runtime check of an interface clause.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FlowCheck {
+    /// Predicate on the values of the flow, the value is named after the flow.
+    Value(contract::Term),
+    /// Minimal period between two values of the flow, in milliseconds.
+    Rate(u64),
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct InterfaceFlow {
     /// Path of the flow.
@@ -483,6 +524,74 @@ pub struct InterfaceFlow {
     pub ident: Ident,
     /// The type of the flow.
     pub typ: Typ,
+    /// Runtime checks of the flow's contract.
+    pub checks: Vec<FlowCheck>,
+}
+impl InterfaceFlow {
+    /// Identifier of the field storing the instant of the flow's last value.
+    pub fn last_instant_field(&self) -> Ident {
+        format_ident!("{}_last_instant", self.ident)
+    }
+
+    /// Tells if the flow's contract constrains its rate.
+    pub fn has_rate_check(&self) -> bool {
+        self.checks
+            .iter()
+            .any(|check| matches!(check, FlowCheck::Rate(_)))
+    }
+
+    /// Tells if the flow's contract constrains its values.
+    pub fn has_value_check(&self) -> bool {
+        self.checks
+            .iter()
+            .any(|check| matches!(check, FlowCheck::Value(_)))
+    }
+
+    /// Tokens checking the contract of the flow, whose value is in the variable `ident`.
+    ///
+    /// - `this` is the structure owning the `output` channel and the last instant field
+    /// - `kind` is the kind of the flow's clauses, `requires` or `ensures`
+    pub fn check_tokens(&self, this: TokenStream2, kind: &str, instant: &Ident) -> TokenStream2 {
+        let ident = &self.ident;
+        let mut tokens = TokenStream2::new();
+        let last = self.last_instant_field();
+        for check in self.checks.iter() {
+            match check {
+                FlowCheck::Value(term) => {
+                    let expr = term.prepare_prop_tokens(false);
+                    let message =
+                        violation_message(format!("`{ident}` {kind} `{}`", expr.to_token_stream()));
+                    quote! {
+                        if !(#expr) {
+                            #this.output.feed(O::ContractViolation(#message, #instant)).await?;
+                        }
+                    }
+                    .to_tokens(&mut tokens)
+                }
+                FlowCheck::Rate(period_ms) => {
+                    let message =
+                        violation_message(format!("`{ident}` {kind} `every {period_ms} ms`"));
+                    quote! {
+                        if #this.#last.is_some_and(|last| {
+                            #instant.duration_since(last) < std::time::Duration::from_millis(#period_ms)
+                        }) {
+                            #this.output.feed(O::ContractViolation(#message, #instant)).await?;
+                        }
+                    }
+                    .to_tokens(&mut tokens)
+                }
+            }
+        }
+        if self.has_rate_check() {
+            quote! { #this.#last = Some(#instant); }.to_tokens(&mut tokens)
+        }
+        tokens
+    }
+}
+
+/// Message of a contract violation.
+pub fn violation_message(message: String) -> syn::LitStr {
+    syn::LitStr::new(&message, Span::call_site())
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -492,6 +601,8 @@ pub enum ArrivingFlow {
     Deadline(Ident),
     ServiceDelay(Ident),
     ServiceTimeout(Ident),
+    /// Deadline of a service response.
+    ResponseDeadline(Ident),
 }
 impl ArrivingFlow {
     pub fn ident(&self) -> &Ident {
@@ -501,7 +612,8 @@ impl ArrivingFlow {
             | Period(id)
             | Deadline(id)
             | ServiceDelay(id)
-            | ServiceTimeout(id) => id,
+            | ServiceTimeout(id)
+            | ResponseDeadline(id) => id,
         }
     }
 }
@@ -520,4 +632,5 @@ pub enum TimingEventKind {
     Timeout(u64),
     ServiceTimeout(u64),
     ServiceDelay(u64),
+    ResponseDeadline(u64),
 }
@@ -53,19 +53,29 @@ impl ToTokens for RuntimeLoopTokens<'_> {
                         let ident = flow_name;
                         let function_name = flow_name.to_handle_fn();
                         let instant = Ident::instant_var();
+                        // check the contract of the imported flow
+                        let checks = self
+                            .in_flows
+                            .iter()
+                            .find(|flow| &flow.ident == flow_name)
+                            .map(|flow| flow.check_tokens(quote!(runtime), "requires", &instant));
                         let call_services_handlers = services.iter().map(|service| {
                             quote! {
                                 runtime.#service.#function_name(#instant, #ident).await?;
                             }
                         });
                         quote! {
-                            I::#enum_ident(#ident, #instant) => { #(#call_services_handlers)* }
+                            I::#enum_ident(#ident, #instant) => {
+                                #checks
+                                #(#call_services_handlers)*
+                            }
                         }
                     }
                     ArrivingFlow::Period(time_flow_name)
                     | ArrivingFlow::Deadline(time_flow_name)
                     | ArrivingFlow::ServiceDelay(time_flow_name)
-                    | ArrivingFlow::ServiceTimeout(time_flow_name) => {
+                    | ArrivingFlow::ServiceTimeout(time_flow_name)
+                    | ArrivingFlow::ResponseDeadline(time_flow_name) => {
                         let enum_ident = time_flow_name.to_camel();
                         let instant = Ident::instant_var();
                         let function_name = time_flow_name.to_handle_fn();
@@ -140,7 +150,9 @@ mod old {
             .iter()
             .filter_map(|input_flow| -> Option<syn::Stmt> {
                 match &input_flow.arriving_flow {
-                    ArrivingFlow::Channel(_, _, _) | ArrivingFlow::ServiceDelay(_) => None,
+                    ArrivingFlow::Channel(_, _, _)
+                    | ArrivingFlow::ServiceDelay(_)
+                    | ArrivingFlow::ResponseDeadline(_) => None,
                     ArrivingFlow::Period(time_flow_name)
                     | ArrivingFlow::Deadline(time_flow_name)
                     | ArrivingFlow::ServiceTimeout(time_flow_name) => {
@@ -191,7 +203,8 @@ mod old {
                         ArrivingFlow::Period(time_flow_name)
                         | ArrivingFlow::Deadline(time_flow_name)
                         | ArrivingFlow::ServiceDelay(time_flow_name)
-                        | ArrivingFlow::ServiceTimeout(time_flow_name) => {
+                        | ArrivingFlow::ServiceTimeout(time_flow_name)
+                        | ArrivingFlow::ResponseDeadline(time_flow_name) => {
                             let enum_ident = time_flow_name.to_camel();
                             let instant = Ident::instant_var();
                             let function_name = time_flow_name.to_handle_fn();
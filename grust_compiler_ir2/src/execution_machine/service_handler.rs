@@ -1,4 +1,4 @@
-prelude! { execution_machine::{ArrivingFlow, InterfaceFlow} }
+prelude! { execution_machine::{violation_message, ArrivingFlow, InterfaceFlow} }
 
 #[derive(Debug, PartialEq)]
 pub struct ComponentInfo {
@@ -20,6 +20,116 @@ impl ComponentInfo {
     }
 }
 
+/// Response deadline checked by a service: `output` is emitted at most `deadline_ms` after
+/// `input`.
+///
+/// A signal `output` also responds when the propagation of `input` leaves it unchanged, it
+/// already holds its response.
+#[derive(Debug, PartialEq)]
+pub struct ServiceResponse {
+    pub output: Ident,
+    pub deadline_ms: u64,
+    pub input: Ident,
+    /// True if `output` is a signal.
+    pub signal: bool,
+    /// Timer expiring at the deadline.
+    pub timer: Ident,
+}
+impl ServiceResponse {
+    /// Message reporting a late response of `service`.
+    fn violation_message(&self, service: &Ident) -> syn::LitStr {
+        let Self {
+            output,
+            deadline_ms,
+            input,
+            ..
+        } = self;
+        violation_message(format!(
+            "`{service}` ensures `{output} within {deadline_ms} ms after {input}`"
+        ))
+    }
+}
+
+/// Interface contracts checked by a service.
+///
+/// Imported flows are checked by the runtime loop, the service checks the flows it exports
+/// and its response deadlines. A missing response is reported by a timer at its deadline, a late
+/// one when it is emitted.
+#[derive(Debug, PartialEq, Default)]
+pub struct ServiceContract {
+    /// Exported flows with runtime checks.
+    pub exports: Vec<InterfaceFlow>,
+    /// Response deadlines.
+    pub responses: Vec<ServiceResponse>,
+}
+impl ServiceContract {
+    /// Tells if the service checks nothing.
+    pub fn is_empty(&self) -> bool {
+        self.exports.is_empty() && self.responses.is_empty()
+    }
+
+    /// Identifier of the field storing the instant of the oldest unanswered `idx`-th response.
+    fn response_field(idx: usize) -> Ident {
+        format_ident!("response_{idx}_instant")
+    }
+
+    /// Tokens starting the response deadlines at the arrival of `flow_name`.
+    fn arrival_tokens(&self, flow_name: &Ident, instant: &Ident) -> TokenStream2 {
+        let pending = self
+            .responses
+            .iter()
+            .enumerate()
+            .filter(|(_, response)| &response.input == flow_name)
+            .map(|(idx, response)| {
+                let field = Self::response_field(idx);
+                let timer = response.timer.to_camel();
+                quote! {
+                    if self.#field.is_none() {
+                        self.#field = Some(#instant);
+                        self.send_timer(T::#timer, #instant).await?;
+                    }
+                }
+            });
+        quote! { #(#pending)* }
+    }
+
+    /// Tokens answering the pending `idx`-th response, reporting it when late.
+    fn response_tokens(&self, idx: usize, service: &Ident, instant: &Ident) -> TokenStream2 {
+        let response = &self.responses[idx];
+        let deadline_ms = response.deadline_ms;
+        let field = Self::response_field(idx);
+        let message = response.violation_message(service);
+        quote! {
+            if let Some(start) = self.#field.take() {
+                if #instant.duration_since(start) > std::time::Duration::from_millis(#deadline_ms) {
+                    self.output.feed(O::ContractViolation(#message, #instant)).await?;
+                }
+            }
+        }
+    }
+
+    /// Tokens answering the signal responses after a propagation.
+    ///
+    /// With `flow_name`, the propagation of this flow only, otherwise the propagation of the
+    /// input store.
+    fn propagated_tokens(
+        &self,
+        flow_name: Option<&Ident>,
+        service: &Ident,
+        instant: &Ident,
+    ) -> TokenStream2 {
+        let answers = self
+            .responses
+            .iter()
+            .enumerate()
+            .filter(|(_, response)| {
+                response.signal && flow_name.is_none_or(|flow_name| &response.input == flow_name)
+            })
+            .map(|(idx, _)| self.response_tokens(idx, service, instant));
+        quote! { #(#answers)* }
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct ServiceHandler {
     /// The service name.
@@ -36,6 +146,8 @@ pub struct ServiceHandler {
     pub service_struct_ident: Ident,
     pub service_mod_ident: Ident,
     pub has_time_range: bool,
+    /// Interface contracts checked by the service.
+    pub contract: ServiceContract,
 }
 
 impl ServiceHandler {
@@ -46,6 +158,7 @@ impl ServiceHandler {
         init_handler: InitHandler,
         flow_handlers: Vec<FlowHandler>,
         flow_context: ir1::ctx::Flows,
+        contract: ServiceContract,
     ) -> Self {
         let service = service.into();
         let components_info = components
@@ -65,19 +178,36 @@ impl ServiceHandler {
             service_struct_ident,
             service_mod_ident,
             has_time_range,
+            contract,
         }
     }
 }
 
+impl ServiceHandler {
+    /// Coverage probes of the flow handlers.
+    pub fn probes(&self) -> Vec<Probe> {
+        self.flow_handlers
+            .iter()
+            .enumerate()
+            .map(|(idx, handler)| {
+                let ident = handler.arriving_flow.ident();
+                Probe::new(idx, coverage::Branch::Handler, ident.to_string(), ident.span())
+            })
+            .collect()
+    }
+}
+
 pub struct ServiceHandlerTokens<'a> {
     sh: &'a ServiceHandler,
     has_timer: bool,
+    coverage: bool,
 }
 impl ServiceHandler {
-    pub fn prepare_tokens(&self, has_timer: bool) -> ServiceHandlerTokens<'_> {
+    pub fn prepare_tokens(&self, has_timer: bool, coverage: bool) -> ServiceHandlerTokens<'_> {
         ServiceHandlerTokens {
             sh: self,
             has_timer,
+            coverage,
         }
     }
 }
@@ -107,7 +237,9 @@ impl ToTokens for ServiceHandlerTokens<'_> {
                         ArrivingFlow::Period(ident) | ArrivingFlow::Deadline(ident) => {
                             Some(quote! { #ident: Option<((), std::time::Instant)> })
                         }
-                        ArrivingFlow::ServiceDelay(_) | ArrivingFlow::ServiceTimeout(_) => None,
+                        ArrivingFlow::ServiceDelay(_)
+                        | ArrivingFlow::ServiceTimeout(_)
+                        | ArrivingFlow::ResponseDeadline(_) => None,
                     });
             let service_store_is_some_s =
                 self.sh
@@ -117,7 +249,9 @@ impl ToTokens for ServiceHandlerTokens<'_> {
                         ArrivingFlow::Channel(ident, ..)
                         | ArrivingFlow::Period(ident)
                         | ArrivingFlow::Deadline(ident) => Some(quote! { self.#ident.is_some() }),
-                        ArrivingFlow::ServiceDelay(_) | ArrivingFlow::ServiceTimeout(_) => None,
+                        ArrivingFlow::ServiceDelay(_)
+                        | ArrivingFlow::ServiceTimeout(_)
+                        | ArrivingFlow::ResponseDeadline(_) => None,
                     });
             // service store
             quote! {
@@ -172,6 +306,19 @@ impl ToTokens for ServiceHandlerTokens<'_> {
 
                 field_values.push(field_ident.to_token_stream());
             }
+            // with contract checks states
+            for flow in self.sh.contract.exports.iter() {
+                if flow.has_rate_check() {
+                    let last = flow.last_instant_field();
+                    service_fields.push(quote! { #last: Option<std::time::Instant> });
+                    field_values.push(quote! { #last: None });
+                }
+            }
+            for idx in 0..self.sh.contract.responses.len() {
+                let field = ServiceContract::response_field(idx);
+                service_fields.push(quote! { #field: Option<std::time::Instant> });
+                field_values.push(quote! { #field: None });
+            }
             // and sending channels
             service_fields.push(quote! { output: grust::futures::channel::mpsc::Sender<O> });
             field_values.push(quote! { output });
@@ -241,8 +388,22 @@ impl ToTokens for ServiceHandlerTokens<'_> {
 
                 self.sh.init_handler.to_tokens(&mut impl_tokens);
 
-                for handler in self.sh.flow_handlers.iter() {
-                    handler.to_tokens(&mut impl_tokens)
+                let probes = if self.coverage {
+                    self.sh.probes()
+                } else {
+                    vec![]
+                };
+                for (idx, handler) in self.sh.flow_handlers.iter().enumerate() {
+                    handler
+                        .prepare_tokens(&self.sh.service_ident, &self.sh.contract, probes.get(idx))
+                        .to_tokens(&mut impl_tokens)
+                }
+                if !probes.is_empty() {
+                    coverage::ProbesTokens {
+                        owner: &self.sh.service_ident,
+                        probes: &probes,
+                    }
+                    .to_tokens(&mut impl_tokens)
                 }
 
                 // reset service delay
@@ -270,17 +431,76 @@ impl ToTokens for ServiceHandlerTokens<'_> {
                     }
                 }
                 .to_tokens(&mut impl_tokens);
+                // check exported flows and response deadlines
+                let output_checks = {
+                    let instant = format_ident!("instant");
+                    let exports = self.sh.contract.exports.iter().map(|flow| {
+                        let ident = &flow.ident;
+                        let enum_ident = ident.to_camel();
+                        let checks = flow.check_tokens(quote!(self), "ensures", &instant);
+                        if flow.has_value_check() {
+                            quote! {
+                                if let O::#enum_ident(#ident, _) = &output {
+                                    let #ident = *#ident;
+                                    #checks
+                                }
+                            }
+                        } else {
+                            quote! { if let O::#enum_ident(..) = &output { #checks } }
+                        }
+                    });
+                    let responses =
+                        self.sh
+                            .contract
+                            .responses
+                            .iter()
+                            .enumerate()
+                            .map(|(idx, response)| {
+                                let enum_ident = response.output.to_camel();
+                                let answer = self.sh.contract.response_tokens(
+                                    idx,
+                                    &self.sh.service_ident,
+                                    &instant,
+                                );
+                                quote! { if let O::#enum_ident(..) = &output { #answer } }
+                            });
+                    quote! { #(#exports)* #(#responses)* }
+                };
                 quote! {
                     #[inline]
                     pub async fn send_output(
                         &mut self, output: O, instant: std::time::Instant
                     ) -> Result<(), grust::futures::channel::mpsc::SendError> {
                         #service_timeout
+                        #output_checks
                         self.output.feed(output).await?;
                         Ok(())
                     }
                 }
                 .to_tokens(&mut impl_tokens);
+                // report missing responses at their deadlines
+                for (idx, response) in self.sh.contract.responses.iter().enumerate() {
+                    let function_name = response.timer.to_handle_fn();
+                    let deadline_ms = response.deadline_ms;
+                    let field = ServiceContract::response_field(idx);
+                    let message = response.violation_message(&self.sh.service_ident);
+                    // the timer of an answered response can expire after a new arrival
+                    quote! {
+                        pub async fn #function_name(
+                            &mut self, instant: std::time::Instant
+                        ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                            if self.#field.is_some_and(|start| {
+                                instant.duration_since(start)
+                                    >= std::time::Duration::from_millis(#deadline_ms)
+                            }) {
+                                self.#field = None;
+                                self.output.feed(O::ContractViolation(#message, instant)).await?;
+                            }
+                            Ok(())
+                        }
+                    }
+                    .to_tokens(&mut impl_tokens);
+                }
                 if self.has_timer {
                     quote! {
                         #[inline]
@@ -309,12 +529,40 @@ pub struct FlowHandler {
     pub arriving_flow: ArrivingFlow,
     pub instruction: FlowInstruction,
 }
-impl ToTokens for FlowHandler {
+
+pub struct FlowHandlerTokens<'a> {
+    fh: &'a FlowHandler,
+    service: &'a Ident,
+    contract: &'a ServiceContract,
+    probe: Option<&'a Probe>,
+}
+impl FlowHandler {
+    pub fn prepare_tokens<'a>(
+        &'a self,
+        service: &'a Ident,
+        contract: &'a ServiceContract,
+        probe: Option<&'a Probe>,
+    ) -> FlowHandlerTokens<'a> {
+        FlowHandlerTokens {
+            fh: self,
+            service,
+            contract,
+            probe,
+        }
+    }
+}
+
+impl ToTokens for FlowHandlerTokens<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
-        let instrs = &self.instruction;
-        match &self.arriving_flow {
+        let instrs = &self.fh.instruction;
+        let hit = self.probe.map(Probe::hit_tokens);
+        match &self.fh.arriving_flow {
             ArrivingFlow::Channel(flow_name, flow_type, _) => {
                 let instant = flow_name.to_instant_var();
+                let arrival = self.contract.arrival_tokens(flow_name, &instant);
+                let answers =
+                    self.contract
+                        .propagated_tokens(Some(flow_name), self.service, &instant);
                 let function_name: Ident = flow_name.to_handle_fn();
                 let ty = flow_type;
                 let message = syn::LitStr::new(
@@ -325,6 +573,8 @@ impl ToTokens for FlowHandler {
                     pub async fn #function_name(
                         &mut self, #instant: std::time::Instant, #flow_name: #ty
                     ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                        #hit
+                        #arrival
                         if self.delayed {
                             // reset time constraints
                             self.reset_time_constraints(#instant).await?;
@@ -332,6 +582,7 @@ impl ToTokens for FlowHandler {
                             self.context.reset();
                             // propagate changes
                             #instrs
+                            #answers
                         } else {
                             // store in input_store
                             let unique =
@@ -355,6 +606,7 @@ impl ToTokens for FlowHandler {
                     pub async fn #function_name(
                         &mut self,  #instant: std::time::Instant
                     ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                        #hit
                         if self.delayed {
                             // reset time constraints
                             self.reset_time_constraints(#instant).await?;
@@ -376,15 +628,20 @@ impl ToTokens for FlowHandler {
             }
             ArrivingFlow::ServiceDelay(service_delay) => {
                 let instant = Ident::instant_var();
+                let answers = self
+                    .contract
+                    .propagated_tokens(None, self.service, &instant);
                 let function_name = service_delay.to_handle_fn();
                 quote! {
                     pub async fn #function_name(
                         &mut self, #instant: std::time::Instant
                     ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                        #hit
                         // reset all signals' update
                         self.context.reset();
                         // propagate changes
                         #instrs
+                        #answers
                         Ok(())
                     }
                 }
@@ -409,6 +666,7 @@ impl ToTokens for FlowHandler {
                     pub async fn #function_name(
                         &mut self, #instant: std::time::Instant
                     ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                        #hit
                         // reset time constraints
                         self.reset_time_constraints(#instant).await?;
                         // reset all signals' update
@@ -431,6 +689,9 @@ impl ToTokens for FlowHandler {
                 }
                 .to_tokens(tokens)
             }
+            ArrivingFlow::ResponseDeadline(_) => {
+                noErrorDesc!("response deadlines are handled by the service contract")
+            }
         }
     }
 }
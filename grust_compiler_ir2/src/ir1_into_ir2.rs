@@ -29,8 +29,9 @@ impl Ir1IntoIr2<&'_ ir0::Ctx> for ir1::Component {
     fn into_ir2(self, ctx: &ir0::Ctx) -> Self::Ir2 {
         match self.body_or_path {
             Either::Left(body) => {
-                // get component name
+                // get component name and type parameters
                 let name = ctx.get_name(self.sign.id);
+                let typ_params = ctx.get_comp_typ_params(self.sign.id);
 
                 // get component inputs
                 let inputs = ctx
@@ -51,6 +52,11 @@ impl Ir1IntoIr2<&'_ ir0::Ctx> for ir1::Component {
 
                 use state_machine::*;
 
+                // coverage probes, numbered with the 'step' body
+                let coverage;
+                // sequential 'step' body, for differential testing
+                let seq_body;
+
                 // 'init' method
                 let init = Init::new(name.clone(), state_elements_init, invariant_init);
 
@@ -67,14 +73,20 @@ impl Ir1IntoIr2<&'_ ir0::Ctx> for ir1::Component {
                         };
                         Stmt::log(ident, expr)
                     });
+                    seq_body = ctx.conf.diff_testing().then(|| {
+                        let mut seq = para::Stmts::seq_of_ir1(&body.statements, ctx);
+                        seq.strip_probes();
+                        seq
+                    });
                     // body stmts
-                    let body = match para::Stmts::of_ir1(&body.statements, ctx, &body.graph) {
+                    let mut body = match para::Stmts::of_ir1(&body.statements, ctx, &body.graph) {
                         Ok(stmts) => stmts,
                         Err(e) => panic!(
                             "failed to generate (step) synced body of component `{}`:\n{}",
                             name, e
                         ),
                     };
+                    coverage = body.number_probes();
                     Step::new(
                         name.clone(),
                         body,
@@ -88,10 +100,12 @@ impl Ir1IntoIr2<&'_ ir0::Ctx> for ir1::Component {
                 // 'input' structure
                 let input = Input {
                     comp_name: name.clone(),
+                    typ_params: typ_params.clone(),
                     elements: inputs.collect(),
                 };
                 let output = Output {
                     comp_name: name.clone(),
+                    typ_params: typ_params.clone(),
                     elements: outputs
                         .iter()
                         .map(|(_, id)| (ctx.get_name(*id).clone(), ctx.get_typ(*id).clone()))
@@ -101,12 +115,16 @@ impl Ir1IntoIr2<&'_ ir0::Ctx> for ir1::Component {
                 // 'state' structure
                 let state = State {
                     comp_name: name.clone(),
+                    typ_params: typ_params.clone(),
                     elements,
                     step,
                     init,
                 };
 
-                Some(StateMachine::new(name.clone(), input, output, state))
+                let mut sm = StateMachine::new(name.clone(), input, output, state);
+                sm.coverage = coverage;
+                sm.seq_body = seq_body;
+                Some(sm)
             }
             Either::Right(_) => None,
         }
@@ -140,7 +158,10 @@ pub fn memory_state_elements(
     {
         let scope = ctx.get_scope(id);
         let mem_ident = ident.to_last_var();
-        elements.push(StateElmInfo::buffer(mem_ident.clone(), typing));
+        elements.push(StateElmInfo::buffer(
+            mem_ident.clone(),
+            ctx.instantiate_sizes(&typing),
+        ));
         inits.push(StateElmInit::buffer(mem_ident.clone(), init.into_ir2(ctx)));
         steps.push(StateElmStep::new(
             mem_ident,
@@ -154,36 +175,52 @@ pub fn memory_state_elements(
     mem.called_comps
         .into_iter()
         .sorted_by_key(|(id, _)| *id)
-        .for_each(|(memory_id, CalledComponent { comp_id, .. })| {
-            let memory_name = ctx.get_name(memory_id);
-            let comp_name = ctx.get_name(comp_id);
-            let path_opt = ctx.try_get_comp_path(comp_id);
-            elements.push(StateElmInfo::called_comp(
-                memory_name.clone(),
-                comp_name.clone(),
-                path_opt.cloned(),
-            ));
-            inits.push(StateElmInit::called_comp(
-                memory_name.clone(),
-                comp_name.clone(),
-                path_opt.cloned(),
-            ));
-        });
+        .for_each(
+            |(
+                memory_id,
+                CalledComponent {
+                    comp_id,
+                    typ_args,
+                    static_args,
+                },
+            )| {
+                let memory_name = ctx.get_name(memory_id);
+                let comp_name = &match ir1::Instance::of_static_args(&static_args, ctx) {
+                    Some(instance) => instance.name(ctx),
+                    None => ctx.get_name(comp_id).clone(),
+                };
+                let path_opt = ctx.try_get_comp_path(comp_id);
+                elements.push(StateElmInfo::called_comp(
+                    memory_name.clone(),
+                    comp_name.clone(),
+                    typ_args.clone(),
+                    path_opt.cloned(),
+                ));
+                inits.push(StateElmInit::called_comp(
+                    memory_name.clone(),
+                    comp_name.clone(),
+                    typ_args,
+                    path_opt.cloned(),
+                ));
+            },
+        );
     mem.ghost_comps
         .into_iter()
         .sorted_by_key(|(id, _)| *id)
-        .for_each(|(memory_id, GhostComponent { comp_id, .. })| {
+        .for_each(|(memory_id, GhostComponent { comp_id, typ_args })| {
             let memory_name = ctx.get_name(memory_id);
             let comp_name = ctx.get_name(comp_id);
             let path_opt = ctx.try_get_comp_path(comp_id);
             elements.push(StateElmInfo::called_comp(
                 memory_name.clone(),
                 comp_name.clone(),
+                typ_args.clone(),
                 path_opt.cloned(),
             ));
             inits.push(StateElmInit::called_comp(
                 memory_name.clone(),
                 comp_name.clone(),
+                typ_args,
                 path_opt.cloned(),
             ));
         });
@@ -228,6 +265,10 @@ mod term {
                 Kind::Constant { constant } => contract::Term::literal(constant),
                 Kind::Paren { term } => contract::Term::paren(term.into_ir2(ctx)),
                 Kind::Identifier { id } => {
+                    // bound static parameter
+                    if let Some(ir0::Expr::Constant(constant)) = ctx.try_get_const(id) {
+                        return contract::Term::literal(constant.clone());
+                    }
                     let name = ctx.get_name(id);
                     let views = ctx.get_typ(id).needs_view();
                     match ctx.get_scope(id) {
@@ -260,6 +301,36 @@ mod term {
                     let term = term.into_ir2(ctx);
                     contract::Term::forall(name.clone(), ty, term)
                 }
+                Kind::Exists { id, term } => {
+                    let name = ctx.get_name(id);
+                    let ty = ctx.get_typ(id).clone();
+                    let term = term.into_ir2(ctx);
+                    contract::Term::exists(name.clone(), ty, term)
+                }
+                Kind::Bounded {
+                    quantifier,
+                    id,
+                    lo,
+                    hi,
+                    term,
+                } => {
+                    let name = ctx.get_name(id).clone();
+                    contract::Term::bounded(
+                        quantifier,
+                        name,
+                        lo.into_ir2(ctx),
+                        hi.into_ir2(ctx),
+                        term.into_ir2(ctx),
+                    )
+                }
+                Kind::ArrayAccess { term, index } => {
+                    let views = self.typing.as_ref().is_some_and(Typ::needs_view);
+                    contract::Term::array_access(term.into_ir2(ctx), index.into_ir2(ctx), views)
+                }
+                Kind::FieldAccess { term, field } => {
+                    let views = self.typing.as_ref().is_some_and(Typ::needs_view);
+                    contract::Term::field_access(term.into_ir2(ctx), field, views)
+                }
                 Kind::Implication { left, right } => {
                     contract::Term::implication(left.into_ir2(ctx), right.into_ir2(ctx))
                 }
@@ -272,16 +343,18 @@ mod term {
                 },
                 Kind::Application { fun_id, inputs, .. } => {
                     let function = ctx.get_name(fun_id).clone();
+                    let path = ctx.try_get_function_path(fun_id).cloned();
                     let arguments = inputs
                         .into_iter()
                         .map(|input| input.into_ir2(ctx))
                         .collect::<Vec<_>>();
-                    contract::Term::fun_call(function, arguments)
+                    contract::Term::fun_call(function, path, arguments)
                 }
                 Kind::ComponentCall {
                     memory_id,
                     comp_id,
                     inputs,
+                    ..
                 } => {
                     let memory_ident = ctx
                         .get_name(memory_id.expect(
@@ -312,7 +385,10 @@ where
             Self::Constant { constant, .. } => Expr::Literal { literal: constant },
             Self::Identifier { id, .. } => {
                 let name = ctx.get_name(id).clone();
-                if ctx.is_function(id) {
+                if let Some(ir0::Expr::Constant(constant)) = ctx.try_get_const(id) {
+                    // bound static parameter
+                    Expr::lit(constant.clone())
+                } else if ctx.is_function(id) {
                     if let Some(path) = ctx.try_get_function_path(id) {
                         Expr::Path { path: path.clone() }
                     } else {
@@ -332,10 +408,20 @@ where
                 let expr = expr.into_ir2(ctx);
                 Expr::unop(op, expr)
             }
-            Self::BinOp { op, lft, rgt } => {
+            Self::BinOp {
+                op,
+                arith,
+                lft,
+                rgt,
+            } => {
+                // arithmetic policies only apply to integer arithmetic operators
+                let arith = match lft.try_get_typ() {
+                    Some(Typ::Integer(_)) if op.is_arith() => arith.unwrap_or(ctx.conf.arith),
+                    _ => Arith::Native,
+                };
                 let lft = lft.into_ir2(ctx);
                 let rgt = rgt.into_ir2(ctx);
-                Expr::binop(op, lft, rgt)
+                Expr::arith_binop(op, arith, lft, rgt)
             }
             Self::IfThenElse { cnd, thn, els } => {
                 let cnd = cnd.into_ir2(ctx);
@@ -347,13 +433,21 @@ where
                     Block::new(vec![Stmt::ExprLast { expr: els }]),
                 )
             }
-            Self::Application { fun, inputs, .. } => {
+            Self::Application {
+                fun,
+                static_args,
+                inputs,
+            } => {
                 let arguments = inputs
                     .into_iter()
                     .map(|input| input.into_ir2(ctx))
                     .collect();
+                let function = match ir1::Instance::of_static_args(&static_args, ctx) {
+                    Some(instance) => Expr::ident(instance.name(ctx)),
+                    None => fun.into_ir2(ctx),
+                };
                 Expr::FunctionCall {
-                    function: Box::new(fun.into_ir2(ctx)),
+                    function: Box::new(function),
                     arguments,
                 }
             }
@@ -362,10 +456,10 @@ where
                     .iter()
                     .map(|id| (ctx.get_name(*id).clone(), ctx.get_typ(*id).clone()))
                     .collect();
-                let output = expr
-                    .try_get_typ()
-                    .expect("internal error: it should be typed")
-                    .clone();
+                let output = ctx.instantiate_sizes(
+                    expr.try_get_typ()
+                        .expect("internal error: it should be typed"),
+                );
                 let body = Expr::block(Block::new(vec![Stmt::expr_last(expr.into_ir2(ctx))]));
                 Expr::lambda(false, inputs, output, body)
             }
@@ -491,26 +585,135 @@ impl Ir1IntoIr2<&'_ mut ir0::Ctx> for ir1::File {
             .map(|typedef| typedef.into_ir2(ctx));
         items.extend(typedefs);
 
-        let functions = self
+        // templates are only generated through their instances
+        let (fun_templates, functions): (Vec<_>, Vec<_>) = self
             .functions
             .into_iter()
-            .filter_map(|function| function.into_ir2(ctx))
-            .map(Item::Function);
-        items.extend(functions);
-
-        let state_machines = self
+            .partition(|function| ctx.is_template(function.id));
+        let (comp_templates, components): (Vec<_>, Vec<_>) = self
             .components
             .into_iter()
-            .filter_map(|component| component.into_ir2(ctx))
-            .map(Item::StateMachine);
+            .partition(|component| ctx.is_template(component.sign.id));
+
+        let functions = functions.into_iter().filter_map(|function| {
+            if let Either::Right(path) = &function.body_or_path {
+                let inputs = ctx
+                    .get_function_input(function.id)
+                    .iter()
+                    .map(|id| ctx.get_typ(*id).clone())
+                    .collect();
+                let output = ctx.get_function_output_type(function.id).clone();
+                let signature = ExtSignature::function(path.clone(), inputs, output, function.loc);
+                Some(Item::ExtSignature(signature))
+            } else {
+                function.into_ir2(ctx).map(Item::Function)
+            }
+        });
+        items.extend(functions);
+
+        let state_machines = components.into_iter().filter_map(|component| {
+            if let Either::Right(path) = &component.body_or_path {
+                let typed = |id: &usize| (ctx.get_name(*id).clone(), ctx.get_typ(*id).clone());
+                let inputs = ctx.get_comp_inputs(component.sign.id).iter().map(typed);
+                let outputs = ctx.get_comp_outputs(component.sign.id).iter();
+                let signature = ExtSignature::component(
+                    path.clone(),
+                    inputs.collect(),
+                    outputs.map(|(_, id)| typed(id)).collect(),
+                    component.sign.loc,
+                );
+                Some(Item::ExtSignature(signature))
+            } else {
+                component.into_ir2(ctx).map(Item::StateMachine)
+            }
+        });
         items.extend(state_machines);
 
+        // template instances, generated with their static parameters bound to their arguments
+        for instance in self.instances {
+            let name = instance.name(ctx);
+            ctx.bind_static_params(instance.id, &instance.args, name);
+            let item = if let Some(function) = fun_templates.iter().find(|f| f.id == instance.id) {
+                function.clone().into_ir2(ctx).map(Item::Function)
+            } else {
+                comp_templates
+                    .iter()
+                    .find(|c| c.sign.id == instance.id)
+                    .and_then(|component| component.clone().into_ir2(ctx))
+                    .map(Item::StateMachine)
+            };
+            ctx.unbind_static_params();
+            items.extend(item);
+        }
+
         if !self.interface.services.is_empty() {
             let execution_machine = self.interface.into_ir2(&mut ctx);
             items.push(Item::ExecutionMachine(execution_machine));
         }
 
-        Project { items }
+        let scenarios = self
+            .scenarios
+            .into_iter()
+            .map(|scenario| Item::Scenario(scenario.into_ir2(ctx)));
+        items.extend(scenarios);
+
+        let mutants = self
+            .mutants
+            .into_iter()
+            .map(|mutant| mutant.into_ir2(ctx))
+            .collect();
+
+        Project { items, mutants }
+    }
+}
+
+impl Ir1IntoIr2<&'_ ir0::Ctx> for ir1::Mutant {
+    type Ir2 = mutation::Mutant;
+
+    fn into_ir2(self, ctx: &ir0::Ctx) -> Self::Ir2 {
+        mutation::Mutant::new(
+            self.id,
+            ctx.get_name(self.comp_id).clone(),
+            self.description,
+            self.loc,
+        )
+    }
+}
+
+impl Ir1IntoIr2<&'_ ir0::Ctx> for ir1::Scenario {
+    type Ir2 = Scenario;
+
+    fn into_ir2(self, ctx: &ir0::Ctx) -> Self::Ir2 {
+        let values = |values: Vec<(usize, ir1::scenario::Value)>| {
+            values
+                .into_iter()
+                .map(|(id, value)| (ctx.get_name(id).clone(), value.into_ir2(ctx)))
+                .collect()
+        };
+        let steps = self
+            .steps
+            .into_iter()
+            .map(|step| item::scenario::Step::new(values(step.inputs), values(step.expect)))
+            .collect();
+        let diff = ctx.conf.diff_testing().then(|| {
+            ctx.get_comp_outputs(self.comp_id)
+                .iter()
+                .map(|(_, id)| ctx.get_name(*id).clone())
+                .collect()
+        });
+        Scenario::new(self.ident, ctx.get_name(self.comp_id).clone(), steps, diff)
+    }
+}
+
+impl Ir1IntoIr2<&'_ ir0::Ctx> for ir1::scenario::Value {
+    type Ir2 = item::scenario::Value;
+
+    fn into_ir2(self, ctx: &ir0::Ctx) -> Self::Ir2 {
+        match self {
+            Self::Some(expr) => item::scenario::Value::Some(expr.into_ir2(ctx)),
+            Self::None => item::scenario::Value::None,
+            Self::Expr(expr) => item::scenario::Value::Expr(expr.into_ir2(ctx)),
+        }
     }
 }
 
@@ -521,6 +724,9 @@ impl Ir1IntoIr2<&'_ ir0::Ctx> for ir1::Function {
         // get function name
         let name = ctx.get_name(self.id).clone();
 
+        // get function type parameters
+        let typ_params = ctx.get_function_typ_params(self.id).clone();
+
         // get function inputs
         let inputs = ctx
             .get_function_input(self.id)
@@ -564,7 +770,9 @@ impl Ir1IntoIr2<&'_ ir0::Ctx> for ir1::Function {
                 // Body
                 let body = Block { statements };
 
-                Some(Function::new(name, inputs, output, body, contract))
+                Some(Function::new(
+                    name, typ_params, inputs, output, body, contract,
+                ))
             }
             Either::Right(_) => None,
         }
@@ -721,14 +929,20 @@ impl Ir1IntoIr2<&'_ ir0::Ctx> for ir1::stream::Expr {
             ComponentApplication {
                 memory_id,
                 called_comp_id,
+                typ_args,
+                static_args,
                 inputs,
+                reset,
             } => {
                 let memory_ident = ctx
                     .get_name(memory_id.expect(
                         "internal error: should be defined in `ir1::stream::Expr::memorize`",
                     ))
                     .clone();
-                let name = ctx.get_name(called_comp_id).clone();
+                let name = match ir1::Instance::of_static_args(&static_args, ctx) {
+                    Some(instance) => instance.name(ctx),
+                    None => ctx.get_name(called_comp_id).clone(),
+                };
                 let input_fields = inputs
                     .into_iter()
                     .map(|(id, expression)| (ctx.get_name(id).clone(), expression.into_ir2(ctx)))
@@ -738,9 +952,29 @@ impl Ir1IntoIr2<&'_ ir0::Ctx> for ir1::stream::Expr {
                     .get_comp_outputs(called_comp_id)
                     .iter()
                     .map(|(_, id)| ctx.get_name(*id).clone());
-                ir2::Expr::comp_call(memory_ident, name, input_fields, outputs, path_opt.cloned())
+                let reset = reset.map(|reset| reset.into_ir2(ctx));
+                ir2::Expr::reset_comp_call(
+                    memory_ident,
+                    name,
+                    typ_args,
+                    input_fields,
+                    outputs,
+                    path_opt.cloned(),
+                    reset,
+                )
+            }
+            Expression { expr } => {
+                let probes = if ctx.conf.instrument_coverage() {
+                    coverage::Probe::of_ir1(&expr, ctx)
+                } else {
+                    vec![]
+                };
+                let mut expr = expr.into_ir2(ctx);
+                if !probes.is_empty() {
+                    expr.instrument(probes)
+                }
+                expr
             }
-            Expression { expr } => expr.into_ir2(ctx),
             SomeEvent { expr } => ir2::Expr::some(expr.into_ir2(ctx)),
             NoneEvent => ir2::Expr::none(),
             Last { ident_id, .. } => {
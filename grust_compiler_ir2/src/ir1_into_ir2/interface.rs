@@ -1,7 +1,8 @@
 prelude! {
-    ir1::interface::{FlowExport, FlowImport, Interface, Service},
+    ir1::interface::{FlowExport, FlowImport, Interface, InterfaceCheck, Service},
     execution_machine::{
         ServiceHandler, ServiceInit, ServiceTrigger, RuntimeLoop, ExecutionMachine,TimingEvent, InterfaceFlow,
+        FlowCheck, ArrivingFlow,
     },
 }
 
@@ -37,7 +38,18 @@ impl Ir1IntoIr2<&mut Ctx> for Interface {
                 .iter()
                 .for_each(|flow_handler| {
                     input_handlers
-                        .entry(&flow_handler.arriving_flow)
+                        .entry(flow_handler.arriving_flow.clone())
+                        .or_insert_with(std::vec::Vec::new)
+                        .push(service_handler.service_ident.clone())
+                });
+            // with timers of response deadlines
+            service_handler
+                .contract
+                .responses
+                .iter()
+                .for_each(|response| {
+                    input_handlers
+                        .entry(ArrivingFlow::ResponseDeadline(response.timer.clone()))
                         .or_insert_with(std::vec::Vec::new)
                         .push(service_handler.service_ident.clone())
                 })
@@ -53,8 +65,8 @@ impl Ir1IntoIr2<&mut Ctx> for Interface {
                 .collect(),
             input_handlers: input_handlers
                 .into_iter()
-                .map(|(ref_to, services)| ServiceTrigger {
-                    arriving_flow: ref_to.clone(),
+                .map(|(arriving_flow, services)| ServiceTrigger {
+                    arriving_flow,
                     services,
                 })
                 .collect(),
@@ -94,6 +106,11 @@ impl Ir1IntoIr2<&'_ Ctx> for FlowImport {
                 path: self.path,
                 ident: symbol_table.get_name(self.id).clone(),
                 typ: self.flow_type,
+                checks: self
+                    .requires
+                    .into_iter()
+                    .map(|check| check.into_ir2(symbol_table))
+                    .collect(),
             })
         }
     }
@@ -107,6 +124,22 @@ impl Ir1IntoIr2<&'_ Ctx> for FlowExport {
             path: self.path,
             ident: symbol_table.get_name(self.id).clone(),
             typ: self.flow_type,
+            checks: self
+                .ensures
+                .into_iter()
+                .map(|check| check.into_ir2(symbol_table))
+                .collect(),
+        }
+    }
+}
+
+impl Ir1IntoIr2<&'_ Ctx> for InterfaceCheck {
+    type Ir2 = FlowCheck;
+
+    fn into_ir2(self, symbol_table: &Ctx) -> Self::Ir2 {
+        match self {
+            InterfaceCheck::Value(term) => FlowCheck::Value(term.into_ir2(symbol_table)),
+            InterfaceCheck::Rate(period_ms) => FlowCheck::Rate(period_ms),
         }
     }
 }
@@ -295,6 +328,7 @@ mod service_handler {
             .service_imports()
             .map(|(stmt_id, import_id)| flow_handler(&mut ctx, stmt_id, import_id))
             .collect();
+        let contract = ctx.contract();
         // destroy 'ctx'
         let (flows_context, components) = ctx.destroy();
 
@@ -305,6 +339,7 @@ mod service_handler {
             init_handler,
             flow_handlers,
             flows_context,
+            contract,
         )
     }
 }
@@ -324,6 +359,7 @@ mod flow_instr {
         ir1_into_ir2::trigger,
         execution_machine::{
             Expression, FlowInstruction, TimingEvent, TimingEventKind,
+            ServiceContract, ServiceResponse,
         },
     }
 
@@ -361,6 +397,8 @@ mod flow_instr {
         init_service: bool,
         /// Tells if we handle a service delay.
         delay: bool,
+        /// Timers of the service's response deadlines.
+        response_timers: Vec<Ident>,
     }
     impl ops::Deref for Builder<'_> {
         type Target = Ctx;
@@ -411,6 +449,22 @@ mod flow_instr {
                     timing_events,
                 );
             }
+            // add timers expiring at response deadlines
+            let response_timers = service
+                .responses
+                .iter()
+                .map(|response| {
+                    let fresh_name = {
+                        let s = ctx0.get_name(service.id);
+                        identifier_creator.fresh_identifier(s.loc(), "response", s.to_string())
+                    };
+                    timing_events.push(TimingEvent {
+                        identifier: fresh_name.clone(),
+                        kind: TimingEventKind::ResponseDeadline(response.deadline_ms),
+                    });
+                    fresh_name
+                })
+                .collect();
             // add edge in graph between any import (excluding service delay) and `time` stmts
             service
                 .statements
@@ -450,6 +504,7 @@ mod flow_instr {
                 multiple_inputs: false,
                 init_service: false,
                 delay: false,
+                response_timers,
             }
         }
 
@@ -485,6 +540,30 @@ mod flow_instr {
         pub fn service_name(&self) -> &Ident {
             self.ctx0.get_name(self.service.id)
         }
+        /// Interface contracts checked by the service.
+        pub fn contract(&self) -> ServiceContract {
+            let flows: HashSet<usize> = self.service.get_flows_ids(std::iter::empty()).collect();
+            let exports = self
+                .exports
+                .values()
+                .filter(|export| !export.ensures.is_empty() && flows.contains(&export.id))
+                .map(|export| export.clone().into_ir2(self.ctx0))
+                .collect();
+            let responses = self
+                .service
+                .responses
+                .iter()
+                .zip(self.response_timers.iter())
+                .map(|(response, timer)| ServiceResponse {
+                    output: self.ctx0.get_name(response.output).clone(),
+                    deadline_ms: response.deadline_ms,
+                    input: self.ctx0.get_name(response.input).clone(),
+                    signal: self.ctx0.is_signal(response.output),
+                    timer: timer.clone(),
+                })
+                .collect();
+            ServiceContract { exports, responses }
+        }
         pub fn inputs(&self) -> impl Iterator<Item = (usize, usize)> + 'a {
             self.service_imports().filter(|(_, import_id)| {
                 !(self.ctx0.is_delay(*import_id) || self.ctx0.is_timeout(*import_id))
@@ -617,6 +696,7 @@ mod flow_instr {
                                         path: format_ident!("{fresh_name}").into(),
                                         colon_token: Default::default(),
                                         flow_type: typing,
+                                        requires: vec![],
                                         semi_token: Default::default(),
                                     },
                                 );
@@ -654,6 +734,7 @@ mod flow_instr {
                                         path: format_ident!("{fresh_name}").into(),
                                         colon_token: Default::default(),
                                         flow_type: typing,
+                                        requires: vec![],
                                         semi_token: Default::default(),
                                     },
                                 );
@@ -717,6 +798,7 @@ mod flow_instr {
                     path: format_ident!("{fresh_name}").into(),
                     colon_token: Default::default(),
                     flow_type: typing,
+                    requires: vec![],
                     semi_token: Default::default(),
                 },
             );
@@ -746,6 +828,7 @@ mod flow_instr {
                     path: format_ident!("{fresh_name}").into(),
                     colon_token: Default::default(),
                     flow_type: typing,
+                    requires: vec![],
                     semi_token: Default::default(),
                 },
             );
@@ -4,8 +4,11 @@ pub mod prelude;
 
 mod defs;
 
+pub mod c_abi;
+pub mod coverage;
 pub mod execution_machine;
 pub mod ir1_into_ir2;
+pub mod mutation;
 pub mod state_machine;
 
 pub mod import {
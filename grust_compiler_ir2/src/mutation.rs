@@ -0,0 +1,83 @@
+//! Mutation testing, see the `mutation` configuration item.
+//!
+//! The mutants listed by the [ir1] mutation pass are gathered in a static table, returned by the
+//! generated `mutants` function (see [MutantsTokens]). The generated `ACTIVE_MUTANT` constant reads
+//! the `GRUST_MUTANT` environment variable with `option_env!`, so that changing it compiles the
+//! crate again with another mutant.
+
+prelude! {}
+
+/// A mutant, mirrors `grust::core::mutation::Mutant`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mutant {
+    /// Mutant identifier.
+    pub id: String,
+    /// Mutated component.
+    pub owner: Ident,
+    /// The change, as written in GRust.
+    pub description: String,
+    /// Location of the mutated statement.
+    pub loc: Loc,
+}
+
+mk_new! { impl Mutant =>
+    new {
+        id: impl Into<String> = id.into(),
+        owner: impl Into<Ident> = owner.into(),
+        description: impl Into<String> = description.into(),
+        loc: impl Into<Loc> = loc.into(),
+    }
+}
+
+impl ToTokens for Mutant {
+    /// The `grust::core::mutation::Mutant`, located like coverage points.
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let span = self.loc.span;
+        let id = syn::LitStr::new(&self.id, span);
+        let owner = syn::LitStr::new(&self.owner.to_string(), self.owner.span());
+        let description = syn::LitStr::new(&self.description, span);
+        let file = quote_spanned!(span => file!());
+        let line = quote_spanned!(span => line!());
+        let column = quote_spanned!(span => column!());
+        quote! {
+            grust::core::mutation::Mutant {
+                id: #id,
+                owner: #owner,
+                description: #description,
+                file: #file,
+                line: #line,
+                column: #column,
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
+
+/// The `ACTIVE_MUTANT` constant and the `mutants` function returning the mutants table.
+pub struct MutantsTokens<'a> {
+    /// The mutants.
+    pub mutants: &'a [Mutant],
+    /// The mutant given by the configuration, if any.
+    pub mutant: Option<&'a syn::LitStr>,
+}
+impl ToTokens for MutantsTokens<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let active = match self.mutant {
+            Some(mutant) => quote!(Some(#mutant)),
+            None => {
+                let var = syn::LitStr::new(conf::MUTANT_VAR, Span::call_site());
+                quote!(option_env!(#var))
+            }
+        };
+        let count = syn::LitInt::new(&self.mutants.len().to_string(), Span::call_site());
+        let mutants = self.mutants;
+        quote! {
+            pub const ACTIVE_MUTANT: Option<&str> = #active;
+            pub fn mutants() -> &'static [grust::core::mutation::Mutant] {
+                static MUTANTS: [grust::core::mutation::Mutant; #count] = [#(#mutants),*];
+                &MUTANTS
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
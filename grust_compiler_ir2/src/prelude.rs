@@ -66,11 +66,12 @@ pub use grust_compiler_ir1::import::*;
 
 pub mod ir2 {
     pub use crate::{
+        coverage::{self, Probe},
         defs::{
             block::Block,
             contract::{self, Contract},
             expression::*,
-            item::{self, Enumeration, Function, Item, Structure},
+            item::{self, Enumeration, ExtSignature, Function, Item, Scenario, Structure},
             para,
             pattern::Pattern,
             project::Project,
@@ -78,6 +79,7 @@ pub mod ir2 {
         },
         execution_machine::{self, ExecutionMachine},
         ir1_into_ir2::{self, Ir1IntoIr2, TriggersGraph},
+        mutation,
         state_machine::{self, StateMachine},
         tupleify,
     };
@@ -22,6 +22,8 @@ pub enum StateElm<T> {
         memory_ident: Ident,
         /// Name of the component called.
         comp_name: Ident,
+        /// Type arguments of the component called.
+        typ_args: Vec<Typ>,
         /// Component's path.
         path_opt: Option<syn::Path>,
     },
@@ -35,6 +37,7 @@ mk_new! { impl{T} StateElm<T> =>
     CalledComponent : called_comp {
         memory_ident : impl Into<Ident> = memory_ident.into(),
         comp_name : impl Into<Ident> = comp_name.into(),
+        typ_args: Vec<Typ>,
         path_opt: Option<syn::Path>,
     }
 }
@@ -50,6 +53,7 @@ impl ToTokens for StateElmInit {
             StateElmInit::CalledComponent {
                 memory_ident,
                 comp_name,
+                typ_args,
                 path_opt,
             } => {
                 memory_ident.to_tokens(tokens);
@@ -64,7 +68,7 @@ impl ToTokens for StateElmInit {
                         }
                     }
                 }
-                let called_state_ty = comp_name.to_state_ty();
+                let called_state_ty = Typ::rust_applied(comp_name.to_state_ty(), typ_args);
                 quote!(#called_state_ty as grust::core::Component>::init()).to_tokens(tokens)
             }
         }
@@ -76,6 +80,8 @@ impl ToTokens for StateElmInit {
 pub struct Input {
     /// The component's name.
     pub comp_name: Ident,
+    /// The component's type parameters.
+    pub typ_params: Vec<Typ>,
     /// The input's elements.
     pub elements: Vec<(Ident, Typ)>,
 }
@@ -83,6 +89,7 @@ pub struct Input {
 mk_new! { impl Input =>
     new {
         comp_name : impl Into<Ident> = comp_name.into(),
+        typ_params = vec![],
         elements : Vec<(Ident, Typ)>,
     }
 }
@@ -91,13 +98,15 @@ pub struct InputTokens<'a> {
     i: &'a Input,
     public: bool,
     tracing: bool,
+    c_abi: bool,
 }
 impl Input {
-    pub fn prepare_tokens(&self, public: bool, tracing: bool) -> InputTokens<'_> {
+    pub fn prepare_tokens(&self, public: bool, tracing: bool, c_abi: bool) -> InputTokens<'_> {
         InputTokens {
             i: self,
             public,
             tracing,
+            c_abi,
         }
     }
 }
@@ -114,14 +123,26 @@ impl ToTokens for InputTokens<'_> {
         } else {
             TokenStream2::new()
         };
-        let fields = self
+        let repr_attr = if self.c_abi {
+            quote! {#[repr(C)]}
+        } else {
+            TokenStream2::new()
+        };
+        let mut fields = self
             .i
             .elements
             .iter()
-            .map(|(identifier, typ)| quote!(#pub_token #identifier : #typ));
-        let input_ty = self.i.comp_name.to_input_ty();
+            .map(|(identifier, typ)| quote!(#pub_token #identifier : #typ))
+            .collect::<Vec<_>>();
+        let typ_params = &self.i.typ_params;
+        // type parameters may not appear in the inputs
+        if !typ_params.is_empty() {
+            fields.push(quote!(#pub_token _marker: std::marker::PhantomData<(#(#typ_params,)*)>))
+        }
+        let input_ty = Typ::rust_applied(self.i.comp_name.to_input_ty(), typ_params);
         quote!(
             #debug_attr
+            #repr_attr
             #pub_token struct #input_ty {
                 #(#fields,)*
             }
@@ -135,6 +156,8 @@ impl ToTokens for InputTokens<'_> {
 pub struct Output {
     /// The component's name.
     pub comp_name: Ident,
+    /// The component's type parameters.
+    pub typ_params: Vec<Typ>,
     /// The output's elements.
     pub elements: Vec<(Ident, Typ)>,
 }
@@ -142,6 +165,7 @@ pub struct Output {
 mk_new! { impl Output =>
     new {
         comp_name : impl Into<Ident> = comp_name.into(),
+        typ_params = vec![],
         elements : Vec<(Ident, Typ)>,
     }
 }
@@ -150,13 +174,15 @@ pub struct OutputTokens<'a> {
     i: &'a Output,
     public: bool,
     tracing: bool,
+    c_abi: bool,
 }
 impl Output {
-    pub fn prepare_tokens(&self, public: bool, tracing: bool) -> OutputTokens<'_> {
+    pub fn prepare_tokens(&self, public: bool, tracing: bool, c_abi: bool) -> OutputTokens<'_> {
         OutputTokens {
             i: self,
             public,
             tracing,
+            c_abi,
         }
     }
 }
@@ -173,14 +199,26 @@ impl ToTokens for OutputTokens<'_> {
         } else {
             TokenStream2::new()
         };
-        let fields = self
+        let repr_attr = if self.c_abi {
+            quote! {#[repr(C)]}
+        } else {
+            TokenStream2::new()
+        };
+        let mut fields = self
             .i
             .elements
             .iter()
-            .map(|(identifier, typ)| quote!(#pub_token #identifier : #typ));
-        let output_ty = self.i.comp_name.to_output_ty();
+            .map(|(identifier, typ)| quote!(#pub_token #identifier : #typ))
+            .collect::<Vec<_>>();
+        let typ_params = &self.i.typ_params;
+        // type parameters may not appear in the outputs
+        if !typ_params.is_empty() {
+            fields.push(quote!(#pub_token _marker: std::marker::PhantomData<(#(#typ_params,)*)>))
+        }
+        let output_ty = Typ::rust_applied(self.i.comp_name.to_output_ty(), typ_params);
         quote!(
             #debug_attr
+            #repr_attr
             #pub_token struct #output_ty {
                 #(#fields,)*
             }
@@ -211,13 +249,19 @@ mk_new! { impl Init =>
 pub struct InitTokens<'a> {
     init: &'a Init,
     with_contracts: bool,
+    typ_params: &'a [Typ],
 }
 
 impl Init {
-    pub fn prepare_tokens(&self, with_contracts: bool) -> InitTokens {
+    pub fn prepare_tokens<'a>(
+        &'a self,
+        with_contracts: bool,
+        typ_params: &'a [Typ],
+    ) -> InitTokens<'a> {
         InitTokens {
             init: self,
             with_contracts,
+            typ_params,
         }
     }
 }
@@ -226,17 +270,26 @@ impl ToTokens for InitTokens<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         if self.with_contracts {
             for term in self.init.invariant_init.iter() {
-                let term = term.prepare_tokens(false, false);
+                let term = term.prepare_init_tokens();
                 quote!(#[ensures(#term)]).to_tokens(tokens);
             }
         }
 
         let state_ty = self.init.comp_name.to_state_ty();
-        let fields = self.init.state_init.iter();
+        let mut fields = self
+            .init
+            .state_init
+            .iter()
+            .map(ToTokens::to_token_stream)
+            .collect::<Vec<_>>();
+        if !self.typ_params.is_empty() {
+            fields.push(quote!(_marker: std::marker::PhantomData))
+        }
         let id = quote_spanned!(self.init.comp_name.span() => init);
+        let ret_ty = Typ::rust_applied(&state_ty, self.typ_params);
 
         quote!(
-            fn #id() -> #state_ty {
+            fn #id() -> #ret_ty {
                 #state_ty {
                     #(#fields),*
                 }
@@ -246,6 +299,49 @@ impl ToTokens for InitTokens<'_> {
     }
 }
 
+/// Reset function, re-initializes a state from its [Init] function.
+pub struct ResetTokens<'a> {
+    init: &'a Init,
+}
+
+impl Init {
+    pub fn prepare_reset_tokens(&self) -> ResetTokens {
+        ResetTokens { init: self }
+    }
+}
+
+impl ToTokens for ResetTokens<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let stmts = self.init.state_init.iter().map(|element| match element {
+            StateElmInit::Buffer { ident, data } => quote!(self.#ident = #data;),
+            StateElmInit::CalledComponent {
+                memory_ident,
+                comp_name,
+                typ_args,
+                path_opt,
+            } => {
+                let called_state_ty = comp_name.to_state_ty();
+                if let Some(mut path) = path_opt.clone() {
+                    path.segments.pop();
+                    path.segments.push(called_state_ty.into());
+                    quote!(<#path as grust::core::Component>::reset(&mut self.#memory_ident);)
+                } else {
+                    let called_state_ty = Typ::rust_applied(called_state_ty, typ_args);
+                    quote!(<#called_state_ty as grust::core::Component>::reset(&mut self.#memory_ident);)
+                }
+            }
+        });
+        let id = quote_spanned!(self.init.comp_name.span() => reset);
+
+        quote!(
+            fn #id(&mut self) {
+                #(#stmts)*
+            }
+        )
+        .to_tokens(tokens)
+    }
+}
+
 /// A step function.
 #[derive(Debug, PartialEq)]
 pub struct Step {
@@ -277,15 +373,38 @@ mk_new! { impl Step =>
 
 pub struct StepTokens<'a> {
     step: &'a Step,
+    body: &'a para::Stmts,
+    seq: bool,
     with_contracts: bool,
     tracing: bool,
+    typ_params: &'a [Typ],
 }
 impl Step {
-    pub fn prepare_tokens(&self, with_contracts: bool, tracing: bool) -> StepTokens {
+    pub fn prepare_tokens<'a>(
+        &'a self,
+        with_contracts: bool,
+        tracing: bool,
+        typ_params: &'a [Typ],
+    ) -> StepTokens<'a> {
         StepTokens {
             step: self,
+            body: &self.body,
+            seq: false,
             with_contracts,
             tracing,
+            typ_params,
+        }
+    }
+
+    /// Tokens of the `step_seq` function, the step function with a sequential body and no logs.
+    pub fn prepare_seq_tokens<'a>(&'a self, body: &'a para::Stmts) -> StepTokens<'a> {
+        StepTokens {
+            step: self,
+            body,
+            seq: true,
+            with_contracts: false,
+            tracing: false,
+            typ_params: &[],
         }
     }
 }
@@ -296,14 +415,19 @@ impl ToTokens for StepTokens<'_> {
             self.step.contract.prepare_tokens(false).to_tokens(tokens);
         }
 
-        let input_ty = self.step.comp_name.to_input_ty();
+        let input_ty = Typ::rust_applied(self.step.comp_name.to_input_ty(), self.typ_params);
         let output_ty = self.step.comp_name.to_output_ty();
-        let id = quote_spanned!(self.step.comp_name.span() => step);
+        let ret_ty = Typ::rust_applied(&output_ty, self.typ_params);
+        let id = if self.seq {
+            quote_spanned!(self.step.comp_name.span() => step_seq)
+        } else {
+            quote_spanned!(self.step.comp_name.span() => step)
+        };
 
         let statements = {
             let mut tokens = TokenStream2::new();
 
-            self.step.body.to_tokens(&mut tokens);
+            self.body.to_tokens(&mut tokens);
             for StateElmStep {
                 identifier,
                 expression,
@@ -312,11 +436,21 @@ impl ToTokens for StepTokens<'_> {
                 quote! { self.#identifier = #expression; }.to_tokens(&mut tokens)
             }
             // add logs
-            for l in self.step.logs.iter() {
-                l.to_tokens(&mut tokens)
+            if !self.seq {
+                for l in self.step.logs.iter() {
+                    l.to_tokens(&mut tokens)
+                }
             }
             // add output expression
-            let outputs = self.step.outputs.iter();
+            let mut outputs = self
+                .step
+                .outputs
+                .iter()
+                .map(ToTokens::to_token_stream)
+                .collect::<Vec<_>>();
+            if !self.typ_params.is_empty() {
+                outputs.push(quote!(_marker: std::marker::PhantomData))
+            }
             quote! { #output_ty { #(#outputs),* } }.to_tokens(&mut tokens);
 
             tokens
@@ -330,7 +464,7 @@ impl ToTokens for StepTokens<'_> {
 
         quote! {
             #tracing_attr
-            fn #id(&mut self, input: #input_ty) -> #output_ty {
+            fn #id(&mut self, input: #input_ty) -> #ret_ty {
                 #statements
             }
         }
@@ -359,6 +493,8 @@ mk_new! { impl StateElmStep =>
 pub struct State {
     /// The component's name.
     pub comp_name: Ident,
+    /// The component's type parameters.
+    pub typ_params: Vec<Typ>,
     /// The state's elements.
     pub elements: Vec<StateElmInfo>,
     /// The init function.
@@ -369,6 +505,7 @@ pub struct State {
 
 mk_new! { impl State => new {
     comp_name : impl Into<Ident> = comp_name.into(),
+    typ_params = vec![],
     elements : Vec<StateElmInfo>,
     init : Init,
     step : Step,
@@ -401,28 +538,41 @@ impl State {
 
 impl StateTokens<'_> {
     fn to_struct_and_impl_tokens(&self) -> (TokenStream2, TokenStream2) {
-        let fields = self.state.elements.iter().map(|element| match element {
-            StateElm::Buffer { ident, data: typ } => quote!(#ident : #typ),
-            StateElm::CalledComponent {
-                memory_ident,
-                comp_name,
-                path_opt,
-            } => {
-                let name = comp_name.to_state_ty();
+        let mut fields = self
+            .state
+            .elements
+            .iter()
+            .map(|element| match element {
+                StateElm::Buffer { ident, data: typ } => quote!(#ident : #typ),
+                StateElm::CalledComponent {
+                    memory_ident,
+                    comp_name,
+                    typ_args,
+                    path_opt,
+                } => {
+                    let name = comp_name.to_state_ty();
 
-                if let Some(mut path) = path_opt.clone() {
-                    path.segments.pop();
-                    path.segments.push(name.into());
-                    quote!(#memory_ident : #path)
-                } else {
-                    quote!(#memory_ident : #name)
+                    if let Some(mut path) = path_opt.clone() {
+                        path.segments.pop();
+                        path.segments.push(name.into());
+                        quote!(#memory_ident : #path)
+                    } else {
+                        let name = Typ::rust_applied(name, typ_args);
+                        quote!(#memory_ident : #name)
+                    }
                 }
-            }
-        });
+            })
+            .collect::<Vec<_>>();
 
-        let input_ty = self.state.comp_name.to_input_ty();
-        let output_ty = &self.state.step.comp_name.to_output_ty();
-        let state_ty = self.state.comp_name.to_state_ty();
+        let typ_params = &self.state.typ_params;
+        let input_ty = Typ::rust_applied(self.state.comp_name.to_input_ty(), typ_params);
+        let output_ty = Typ::rust_applied(self.state.step.comp_name.to_output_ty(), typ_params);
+        let state_ty = Typ::rust_applied(self.state.comp_name.to_state_ty(), typ_params);
+        let generics = Typ::rust_generics(typ_params);
+        // type parameters may not appear in the state's elements
+        if !typ_params.is_empty() {
+            fields.push(quote!(_marker: std::marker::PhantomData<(#(#typ_params,)*)>))
+        }
         let align_conf = if self.align {
             quote! { #[repr(align(64))]}
         } else {
@@ -445,16 +595,21 @@ impl StateTokens<'_> {
             #pub_token struct #state_ty { #(#fields),* }
         };
 
-        let init = &self.state.init.prepare_tokens(self.with_contracts);
+        let init = &self
+            .state
+            .init
+            .prepare_tokens(self.with_contracts, typ_params);
+        let reset = &self.state.init.prepare_reset_tokens();
         let step = self
             .state
             .step
-            .prepare_tokens(self.with_contracts, self.tracing);
+            .prepare_tokens(self.with_contracts, self.tracing, typ_params);
         let implementation = quote!(
-            impl grust::core::Component for #state_ty {
+            impl #generics grust::core::Component for #state_ty {
                 type Input = #input_ty;
                 type Output = #output_ty;
                 #init
+                #reset
                 #step
             }
         );
@@ -474,6 +629,10 @@ pub struct StateMachine {
     pub output: Output,
     /// The state structure.
     pub state: State,
+    /// The coverage probes of the step function.
+    pub coverage: Vec<Probe>,
+    /// Sequential body of the step function, when differential testing.
+    pub seq_body: Option<para::Stmts>,
 }
 
 mk_new! { impl StateMachine => new {
@@ -481,22 +640,441 @@ mk_new! { impl StateMachine => new {
     input : Input,
     output: Output,
     state : State,
+    coverage = vec![],
+    seq_body = None,
 } }
 
+impl StateMachine {
+    /// True if the component has type parameters.
+    ///
+    /// Generic components are only tested and exported through the components instantiating them.
+    pub fn is_generic(&self) -> bool {
+        !self.state.typ_params.is_empty()
+    }
+    /// Name of the C function initializing the component's state.
+    pub fn c_init_fn(&self) -> Ident {
+        format_ident!("{}_init", self.name, span = self.name.span())
+    }
+    /// Name of the C function stepping the component's state.
+    pub fn c_step_fn(&self) -> Ident {
+        format_ident!("{}_step", self.name, span = self.name.span())
+    }
+    /// Name of the C function freeing the component's state.
+    pub fn c_free_fn(&self) -> Ident {
+        format_ident!("{}_free", self.name, span = self.name.span())
+    }
+    /// Name of the Kani harness checking the component's contract.
+    pub fn kani_proof_fn(&self) -> Ident {
+        format_ident!("{}_proof", self.name, span = self.name.span())
+    }
+    /// Name of the property-based test checking the component's contract.
+    pub fn prop_test_fn(&self) -> Ident {
+        format_ident!("{}_prop", self.name, span = self.name.span())
+    }
+    /// Name of the differential test of the component's parallel and sequential steps.
+    pub fn diff_test_fn(&self) -> Ident {
+        format_ident!("{}_diff", self.name, span = self.name.span())
+    }
+}
+
+/// The `extern "C"` functions exporting a component's `init` and `step`.
+///
+/// The state is opaque to C callers: it is allocated by `<comp>_init`, borrowed by `<comp>_step`
+/// and released by `<comp>_free`.
+pub struct ExternCTokens<'a> {
+    sm: &'a StateMachine,
+    public: bool,
+}
+
+impl ToTokens for ExternCTokens<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let pub_token = if self.public {
+            quote! {pub}
+        } else {
+            TokenStream2::new()
+        };
+        let state_ty = self.sm.name.to_state_ty();
+        let input_ty = self.sm.name.to_input_ty();
+        let output_ty = self.sm.name.to_output_ty();
+        let init_fn = self.sm.c_init_fn();
+        let step_fn = self.sm.c_step_fn();
+        let free_fn = self.sm.c_free_fn();
+
+        // empty structures have no C equivalent, they do not appear in signatures
+        let (input_arg, input) = if self.sm.input.elements.is_empty() {
+            (None, quote! { #input_ty {} })
+        } else {
+            (Some(quote! { input: #input_ty }), quote! { input })
+        };
+        let output_ret = if self.sm.output.elements.is_empty() {
+            None
+        } else {
+            Some(quote! { -> #output_ty })
+        };
+
+        quote! {
+            #[unsafe(no_mangle)]
+            #pub_token extern "C" fn #init_fn() -> *mut #state_ty {
+                Box::into_raw(Box::new(<#state_ty as grust::core::Component>::init()))
+            }
+            /// # Safety
+            ///
+            /// `state` must be null or a pointer returned by the init function that was not freed,
+            /// the process aborts when it is null.
+            #[unsafe(no_mangle)]
+            #pub_token unsafe extern "C" fn #step_fn(
+                state: *mut #state_ty, #input_arg
+            ) #output_ret {
+                if state.is_null() {
+                    std::process::abort()
+                }
+                // SAFETY: `state` is not null, the caller guarantees it comes from the init
+                // function and was not freed
+                let state = unsafe { &mut *state };
+                <#state_ty as grust::core::Component>::step(state, #input)
+            }
+            /// # Safety
+            ///
+            /// `state` must be null or a pointer returned by the init function that was not freed,
+            /// it cannot be used after this call.
+            #[unsafe(no_mangle)]
+            #pub_token unsafe extern "C" fn #free_fn(state: *mut #state_ty) {
+                if !state.is_null() {
+                    // SAFETY: `state` is not null, the caller guarantees it was allocated by the
+                    // init function and not freed yet
+                    drop(unsafe { Box::from_raw(state) })
+                }
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
+
+/// The Kani harness checking a component's contract on bounded executions.
+///
+/// The component is stepped `depth` times from its initial state on nondeterministic inputs
+/// satisfying the `requires` clauses, the `ensures` and `invariant` clauses are asserted after
+/// each step.
+///
+/// Harnesses are compiled under `cfg(kani)` and run by `cargo kani`, the test suite only compares
+/// them to golden outputs and never runs Kani.
+pub struct KaniHarnessTokens<'a> {
+    sm: &'a StateMachine,
+    depth: usize,
+}
+
+impl ToTokens for KaniHarnessTokens<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let contract = &self.sm.state.step.contract;
+        let state_ty = self.sm.name.to_state_ty();
+        let input_ty = self.sm.name.to_input_ty();
+        let output_ty = self.sm.name.to_output_ty();
+        let proof_fn = self.sm.kani_proof_fn();
+
+        let inputs = self.sm.input.elements.iter().map(|(name, typ)| {
+            quote! { let #name: #typ = kani::any(); }
+        });
+        let input_names = self.sm.input.elements.iter().map(|(name, _)| name);
+        let output_names = self.sm.output.elements.iter().map(|(name, _)| name);
+
+        let assumptions = contract.requires.iter().map(|term| {
+            let term = term.prepare_kani_tokens(false);
+            quote! { kani::assume(#term); }
+        });
+        let mut memories = vec![];
+        for term in contract.ensures.iter() {
+            term.memory_accesses(&mut memories)
+        }
+        // `ensures` memory accesses are before the step, `invariant` ones after the step
+        let assertion = |kind: &str, idx: usize, term: &Term, prophecy: bool| {
+            let term = term.prepare_kani_tokens(prophecy);
+            let msg = syn::LitStr::new(
+                &format!("`{}` {kind} clause #{} violated", self.sm.name, idx + 1),
+                self.sm.name.span(),
+            );
+            quote! { kani::assert(#term, #msg); }
+        };
+        let assertions = contract
+            .ensures
+            .iter()
+            .enumerate()
+            .map(|(idx, term)| assertion("ensures", idx, term, false))
+            .chain(
+                contract
+                    .invariant
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, term)| assertion("invariant", idx, term, true)),
+            );
+
+        let step = quote! {{
+            #(#inputs)*
+            #(#assumptions)*
+            #(let #memories = state.#memories;)*
+            let #output_ty { #(#output_names),* } = <#state_ty as grust::core::Component>::step(
+                &mut state,
+                #input_ty { #(#input_names),* },
+            );
+            #(#assertions)*
+        }};
+        let steps = std::iter::repeat_n(step, self.depth);
+
+        quote! {
+            #[cfg(kani)]
+            #[kani::proof]
+            #[allow(unused_variables)]
+            fn #proof_fn() {
+                let mut state = <#state_ty as grust::core::Component>::init();
+                #(#steps)*
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
+
+/// The property-based test checking a component's contract on random executions.
+///
+/// Runs `cases` random sequences of at most `depth` inputs satisfying the `requires` clauses, and
+/// checks the `ensures` and `invariant` clauses after each step. Failing sequences are shrunk
+/// before being reported.
+pub struct PropHarnessTokens<'a> {
+    sm: &'a StateMachine,
+    cases: usize,
+    depth: usize,
+}
+
+impl ToTokens for PropHarnessTokens<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let contract = &self.sm.state.step.contract;
+        let state_ty = self.sm.name.to_state_ty();
+        let input_ty = self.sm.name.to_input_ty();
+        let output_ty = self.sm.name.to_output_ty();
+        let test_fn = self.sm.prop_test_fn();
+        let name = syn::LitStr::new(&self.sm.name.to_string(), self.sm.name.span());
+        let cases = syn::LitInt::new(&self.cases.to_string(), self.sm.name.span());
+        let depth = syn::LitInt::new(&self.depth.to_string(), self.sm.name.span());
+
+        let input_names = self.sm.input.elements.iter().map(|(name, _)| name);
+        let input_tys = self.sm.input.elements.iter().map(|(_, typ)| typ);
+        let tuple = quote! { (#(#input_tys,)*) };
+        let pattern = quote! { (#(#input_names,)*) };
+        let input_names = self.sm.input.elements.iter().map(|(name, _)| name);
+        let output_names = self.sm.output.elements.iter().map(|(name, _)| name);
+
+        let assumptions = contract.requires.iter().map(|term| {
+            let term = term.prepare_prop_tokens(false);
+            quote! { && (#term) }
+        });
+        let mut memories = vec![];
+        for term in contract.ensures.iter() {
+            term.memory_accesses(&mut memories)
+        }
+        // `ensures` memory accesses are before the step, `invariant` ones after the step
+        let assertion = |kind: &str, idx: usize, term: &Term, prophecy: bool| {
+            let term = term.prepare_prop_tokens(prophecy);
+            let msg = syn::LitStr::new(
+                &format!("`{}` {kind} clause #{} violated", self.sm.name, idx + 1),
+                self.sm.name.span(),
+            );
+            quote! {
+                if !(#term) {
+                    return Err(grust::core::prop::Failure::new(step, #msg));
+                }
+            }
+        };
+        let assertions = contract
+            .ensures
+            .iter()
+            .enumerate()
+            .map(|(idx, term)| assertion("ensures", idx, term, false))
+            .chain(
+                contract
+                    .invariant
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, term)| assertion("invariant", idx, term, true)),
+            );
+
+        quote! {
+            #[cfg(test)]
+            #[test]
+            #[allow(unused_variables, unused_parens)]
+            fn #test_fn() {
+                grust::core::prop::check(
+                    #name,
+                    #cases,
+                    #depth,
+                    |input: &#tuple| {
+                        let #pattern = *input;
+                        true #(#assumptions)*
+                    },
+                    |inputs: &[#tuple]| -> Result<(), grust::core::prop::Failure> {
+                        let mut state = <#state_ty as grust::core::Component>::init();
+                        for (step, input) in inputs.iter().enumerate() {
+                            let #pattern = *input;
+                            #(let #memories = state.#memories;)*
+                            let #output_ty { #(#output_names),* } =
+                                <#state_ty as grust::core::Component>::step(
+                                    &mut state,
+                                    #input_ty { #(#input_names),* },
+                                );
+                            #(#assertions)*
+                        }
+                        Ok(())
+                    },
+                )
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
+
+/// The differential test of a component's parallel and sequential steps.
+///
+/// Runs `cases` random sequences of at most `depth` inputs satisfying the `requires` clauses on
+/// two states, one stepped by the parallel `step` and the other by the sequential `step_seq`, and
+/// checks their outputs are identical after each step. Called components are stepped with their
+/// own `step` in both, their parallel code being checked by their own differential test.
+pub struct DiffHarnessTokens<'a> {
+    sm: &'a StateMachine,
+    seq_body: &'a para::Stmts,
+    cases: usize,
+    depth: usize,
+}
+
+impl ToTokens for DiffHarnessTokens<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let contract = &self.sm.state.step.contract;
+        let state_ty = self.sm.name.to_state_ty();
+        let input_ty = self.sm.name.to_input_ty();
+        let output_ty = self.sm.name.to_output_ty();
+        let test_fn = self.sm.diff_test_fn();
+        let name = syn::LitStr::new(&test_fn.to_string(), self.sm.name.span());
+        let cases = syn::LitInt::new(&self.cases.to_string(), self.sm.name.span());
+        let depth = syn::LitInt::new(&self.depth.to_string(), self.sm.name.span());
+        let step_seq = self.sm.state.step.prepare_seq_tokens(self.seq_body);
+
+        let input_names = self.sm.input.elements.iter().map(|(name, _)| name);
+        let input_tys = self.sm.input.elements.iter().map(|(_, typ)| typ);
+        let tuple = quote! { (#(#input_tys,)*) };
+        let pattern = quote! { (#(#input_names,)*) };
+        let input_names = self
+            .sm
+            .input
+            .elements
+            .iter()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+        let output_names = self
+            .sm
+            .output
+            .elements
+            .iter()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+        let msg = syn::LitStr::new(
+            &format!(
+                "outputs `({})` are {{}} in parallel but {{}} sequentially",
+                output_names
+                    .iter()
+                    .map(|name| name.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            self.sm.name.span(),
+        );
+
+        let assumptions = contract.requires.iter().map(|term| {
+            let term = term.prepare_prop_tokens(false);
+            quote! { && (#term) }
+        });
+
+        quote! {
+            #[cfg(test)]
+            impl #state_ty {
+                #step_seq
+            }
+            #[cfg(test)]
+            #[test]
+            #[allow(unused_variables, unused_parens)]
+            fn #test_fn() {
+                grust::core::prop::check(
+                    #name,
+                    #cases,
+                    #depth,
+                    |input: &#tuple| {
+                        let #pattern = *input;
+                        true #(#assumptions)*
+                    },
+                    |inputs: &[#tuple]| -> Result<(), grust::core::prop::Failure> {
+                        let mut para = <#state_ty as grust::core::Component>::init();
+                        let mut seq = <#state_ty as grust::core::Component>::init();
+                        for (step, input) in inputs.iter().enumerate() {
+                            let #pattern = *input;
+                            let #output_ty { #(#output_names),* } =
+                                <#state_ty as grust::core::Component>::step(
+                                    &mut para,
+                                    #input_ty { #(#input_names),* },
+                                );
+                            let para_outputs = format!("{:?}", (#(#output_names,)*));
+                            let #output_ty { #(#output_names),* } =
+                                seq.step_seq(#input_ty { #(#input_names),* });
+                            let seq_outputs = format!("{:?}", (#(#output_names,)*));
+                            if para_outputs != seq_outputs {
+                                return Err(grust::core::prop::Failure::new(
+                                    step,
+                                    format!(#msg, para_outputs, seq_outputs),
+                                ));
+                            }
+                        }
+                        Ok(())
+                    },
+                )
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
+
+/// Differential test of the parallel components against their sequential version.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Diff {
+    /// Number of random executions.
+    pub cases: usize,
+    /// Maximal number of steps of the executions.
+    pub depth: usize,
+}
+
+/// Harness checking a component's contract at runtime.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Harness {
+    /// Kani proof on executions of `depth` steps.
+    Kani { depth: usize },
+    /// Property-based test on `cases` executions of at most `depth` steps.
+    Prop { cases: usize, depth: usize },
+}
+
 pub struct StateMachineTokens<'a> {
     sm: &'a StateMachine,
     with_contracts: bool,
     align: bool,
     public: bool,
     tracing: bool,
+    c_abi: bool,
+    harness: Option<Harness>,
+    diff: Option<Diff>,
 }
 impl StateMachine {
+    #[allow(clippy::too_many_arguments)]
     pub fn prepare_tokens(
         &self,
         with_contracts: bool,
         align: bool,
         public: bool,
         tracing: bool,
+        c_abi: bool,
+        harness: Option<Harness>,
+        diff: Option<Diff>,
     ) -> StateMachineTokens {
         StateMachineTokens {
             sm: self,
@@ -504,6 +1082,9 @@ impl StateMachine {
             align,
             public,
             tracing,
+            c_abi,
+            harness,
+            diff,
         }
     }
 }
@@ -513,12 +1094,12 @@ impl ToTokens for StateMachineTokens<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let input_structure = &self.sm.input;
         input_structure
-            .prepare_tokens(self.public, self.tracing)
+            .prepare_tokens(self.public, self.tracing, self.c_abi)
             .to_tokens(tokens);
 
         let output_structure = &self.sm.output;
         output_structure
-            .prepare_tokens(self.public, self.tracing)
+            .prepare_tokens(self.public, self.tracing, self.c_abi)
             .to_tokens(tokens);
 
         let (state_structure, state_implementation) = self
@@ -528,6 +1109,53 @@ impl ToTokens for StateMachineTokens<'_> {
             .to_struct_and_impl_tokens();
         state_structure.to_tokens(tokens);
         state_implementation.to_tokens(tokens);
+
+        let typ_params = &self.sm.state.typ_params;
+        if !self.sm.coverage.is_empty() {
+            // no bounds, the probes are shared by all the instances of a generic component
+            let state_ty = Typ::rust_applied(self.sm.name.to_state_ty(), typ_params);
+            let generics = (!typ_params.is_empty()).then(|| quote!(<#(#typ_params),*>));
+            let coverage = coverage::ProbesTokens {
+                owner: &self.sm.name,
+                probes: &self.sm.coverage,
+            };
+            quote!(impl #generics #state_ty { #coverage }).to_tokens(tokens)
+        }
+
+        if self.sm.is_generic() {
+            return;
+        }
+
+        if self.c_abi {
+            ExternCTokens {
+                sm: self.sm,
+                public: self.public,
+            }
+            .to_tokens(tokens)
+        }
+
+        match self.harness {
+            Some(Harness::Kani { depth }) => {
+                KaniHarnessTokens { sm: self.sm, depth }.to_tokens(tokens)
+            }
+            Some(Harness::Prop { cases, depth }) => PropHarnessTokens {
+                sm: self.sm,
+                cases,
+                depth,
+            }
+            .to_tokens(tokens),
+            None => (),
+        }
+
+        if let (Some(Diff { cases, depth }), Some(seq_body)) = (self.diff, &self.sm.seq_body) {
+            DiffHarnessTokens {
+                sm: self.sm,
+                seq_body,
+                cases,
+                depth,
+            }
+            .to_tokens(tokens)
+        }
     }
 }
 
@@ -547,12 +1175,13 @@ mod test {
                 StateElmInit::called_comp(
                     Loc::test_id("called_component_state"),
                     Loc::test_id("CalledComponent"),
+                    vec![],
                     None,
                 ),
             ],
             vec![],
         );
-        let init = binding.prepare_tokens(false);
+        let init = binding.prepare_tokens(false, &[]);
 
         let control = parse_quote! {
             fn init() -> ComponentState {
@@ -578,12 +1207,13 @@ mod test {
                 StateElmInit::called_comp(
                     Loc::test_id("called_component_state"),
                     Loc::test_id("CalledComponent"),
+                    vec![],
                     Some(parse_quote!(path::to::called_comp)),
                 ),
             ],
             vec![],
         );
-        let init = binding.prepare_tokens(false);
+        let init = binding.prepare_tokens(false, &[]);
 
         let control = parse_quote! {
             fn init() -> ComponentState {
@@ -616,6 +1246,7 @@ mod test {
                         Loc::test_id("called_component_state"),
                         Loc::test_id("called_component"),
                         vec![],
+                        vec![],
                         std::iter::once(Loc::test_id("out")),
                         None,
                     ),
@@ -651,7 +1282,7 @@ mod test {
                 ComponentOutput { out }
             }
         };
-        let step = step.prepare_tokens(false, false);
+        let step = step.prepare_tokens(false, false, &[]);
         let f: syn::ItemFn = parse_quote!(#step);
         assert_eq!(f, control)
     }
@@ -675,6 +1306,7 @@ mod test {
                         Loc::test_id("called_component_state"),
                         Loc::test_id("called_component"),
                         vec![],
+                        vec![],
                         std::iter::once(Loc::test_id("out")),
                         Some(parse_quote!(path::to::called_comp)),
                     ),
@@ -703,7 +1335,7 @@ mod test {
                 ComponentOutput { out }
             }
         };
-        let step = step.prepare_tokens(false, false);
+        let step = step.prepare_tokens(false, false, &[]);
         let f: syn::ItemFn = parse_quote!(#step);
         assert_eq!(f, control)
     }
@@ -712,9 +1344,10 @@ mod test {
     fn should_create_rust_ast_structure_from_ir2_component_input() {
         let input = Input {
             comp_name: Loc::test_id("component"),
+            typ_params: vec![],
             elements: vec![(Loc::test_id("i"), Typ::int())],
         }
-        .prepare_tokens(true, false)
+        .prepare_tokens(true, false, false)
         .to_token_stream();
         let control = parse_quote!(
             pub struct ComponentInput {
@@ -724,4 +1357,23 @@ mod test {
         let inp: syn::ItemStruct = parse_quote!(#input);
         assert_eq!(inp, control)
     }
+
+    #[test]
+    fn should_create_rust_ast_generic_structure_from_ir2_component_input() {
+        let input = Input {
+            comp_name: Loc::test_id("component"),
+            typ_params: vec![Typ::param(Loc::test_id("T"), vec![])],
+            elements: vec![(Loc::test_id("i"), Typ::param(Loc::test_id("T"), vec![]))],
+        }
+        .prepare_tokens(true, false, false)
+        .to_token_stream();
+        let control = parse_quote!(
+            pub struct ComponentInput<T> {
+                pub i: T,
+                pub _marker: std::marker::PhantomData<(T,)>,
+            }
+        );
+        let inp: syn::ItemStruct = parse_quote!(#input);
+        assert_eq!(inp, control)
+    }
 }
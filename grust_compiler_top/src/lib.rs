@@ -34,7 +34,11 @@ pub fn handle_tokens(tokens: TokenStream) -> TokenStream {
 /// Creates RustAST from GRust file using nightly funtion.
 #[cfg(feature = "diagnostics")]
 pub fn into_token_stream(ast: Ast, ctx: &mut ir0::Ctx) -> TokenStream2 {
-    match into_token_stream_res(ast, ctx) {
+    let res = into_token_stream_res(ast, ctx);
+    for warning in ctx.warnings.drain(..) {
+        warning.emit_warning();
+    }
+    match res {
         Ok(tokens) => tokens,
         Err(errors) => {
             for error in errors {
@@ -63,7 +67,11 @@ pub fn handle_tokens(tokens: TokenStream) -> TokenStream {
 /// Creates RustAST from GRust file using nightly funtion.
 #[cfg(not(feature = "diagnostics"))]
 pub fn into_token_stream(ast: Ast, ctx: &mut ir0::Ctx) -> TokenStream2 {
-    match into_token_stream_res(ast, ctx) {
+    let res = into_token_stream_res(ast, ctx);
+    for warning in ctx.warnings.drain(..) {
+        println!("compilation warning detected: {}", warning.0);
+    }
+    match res {
         Ok(tokens) => tokens,
         Err(errors) => {
             for error in errors {
@@ -77,11 +85,41 @@ pub fn into_token_stream(ast: Ast, ctx: &mut ir0::Ctx) -> TokenStream2 {
 /// Creates RustAST from GRust file that can fail.
 pub fn into_token_stream_res(ast: Ast, ctx: &mut ir0::Ctx) -> Result<TokenStream2, Vec<Error>> {
     let mut stats = Stats::new();
-    let ir1 = ir1::from_ast_timed(ast, ctx, stats.as_mut())?;
+    let mut ir1 = ir1::from_ast_timed(ast, ctx, stats.as_mut())?;
     if let Some(filepath) = &ctx.conf.dump_graph {
         ir1.dump_graph(filepath.value(), ctx);
     }
+    if ctx.conf.range_analysis || ctx.conf.dump_ranges.is_some() {
+        let ranges = stats.timed("range analysis (ir1)", || ir1.range_analysis(ctx));
+        if let Some(filepath) = &ctx.conf.dump_ranges {
+            ranges.dump(filepath.value());
+        }
+        if ctx.conf.range_analysis {
+            ctx.warnings.extend(ranges.warnings);
+        }
+    }
+    if ctx.conf.infer_invariants || ctx.conf.suggest_invariants {
+        let invariants = stats.timed("invariant inference (ir1)", || ir1.infer_invariants(ctx));
+        if ctx.conf.suggest_invariants {
+            let suggestions = invariants.iter().map(|invariant| invariant.suggestion(ctx));
+            ctx.warnings.extend(suggestions.collect::<Vec<_>>());
+        }
+        if ctx.conf.infer_invariants {
+            ir1.add_invariants(invariants);
+        }
+    }
     let ir2 = stats.timed("ir1 → ir2", || ir1.into_ir2(ctx));
+    if ctx.conf.c_abi {
+        ir2.check_c_abi()?;
+        if let Some(path) = &ctx.conf.dump_header {
+            let guard = std::path::Path::new(&path.value())
+                .file_name()
+                .map(|name| name.to_string_lossy().to_uppercase())
+                .unwrap_or_default()
+                .replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+            dump_header(path, &ir2.c_header(&guard)).map_err(|e| vec![e])?;
+        }
+    }
     let rust = stats.timed("codegen (ir2 → rust tokens)", || {
         ir2.prepare_tokens(ctx).to_token_stream()
     });
@@ -96,6 +134,12 @@ pub fn into_token_stream_res(ast: Ast, ctx: &mut ir0::Ctx) -> Result<TokenStream
     Ok(tokens)
 }
 
+/// Writes the generated C header at the given filepath.
+pub fn dump_header(path_lit: &syn::LitStr, header: &str) -> URes {
+    std::fs::write(path_lit.value(), header)
+        .map_err(|e| error!( @path_lit.span() => "failed to write to this file: {}", e ))
+}
+
 /// Writes the generated code at the given filepath.
 pub fn dump_code(path_lit: &syn::LitStr, tokens: &TokenStream2) -> URes {
     use std::{fs::OpenOptions, io::Write, path::Path, process::Command};
@@ -0,0 +1,60 @@
+grust_compiler_top::prelude! {}
+
+#[test]
+fn should_reject_arithmetic_policies_on_floats() {
+    let top: ir0::Top = parse_quote! {
+        function average(a: float, b: float) -> float {
+            return (a +| b) / 2.0;
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let errors = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap_err();
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn should_model_arithmetic_policies_greusot() {
+    let top: ir0::Top = parse_quote! {
+        #![dump = "tests/macro_outputs/arith_greusot.rs", mode = greusot, arith = saturating]
+
+        // Saturated sum of two readings and its average
+        function average(a: int, b: int) -> int
+            requires { 0 <= a && 0 <= b }
+            ensures { 0 <= result }
+        {
+            return (a + b) / 2;
+        }
+
+        // Position of a counter on a ring of size `n`
+        function ring(count: int, n: int) -> int
+            requires { 0 < n }
+            ensures { 0 <= result && result < n }
+        {
+            return (count +% 1) % n;
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let tokens = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap();
+    if let Some(path) = ctx.conf.dump_code {
+        grust_compiler_top::dump_code(&path, &tokens).unwrap();
+    }
+}
+
+#[test]
+fn should_parenthesize_policy_division_operands() {
+    let top: ir0::Top = parse_quote! {
+        #![dump = "tests/macro_outputs/arith_operands.rs"]
+
+        function div_offset(a: int, b: int, c: int) -> int {
+            return a /% b + c;
+        }
+        function rem_offset(a: int, b: int, c: int) -> int {
+            return c - a %| b;
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let tokens = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap();
+    if let Some(path) = ctx.conf.dump_code {
+        grust_compiler_top::dump_code(&path, &tokens).unwrap();
+    }
+}
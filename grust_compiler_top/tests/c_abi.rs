@@ -0,0 +1,51 @@
+grust_compiler_top::prelude! {}
+
+#[test]
+fn should_compile_c_abi() {
+    let top: ir0::Top = parse_quote! {
+        #![dump = "tests/macro_outputs/c_abi.rs", dump_header = "tests/macro_outputs/c_abi.h", c_abi]
+
+        enum Gear { Park, Drive, Reverse }
+
+        struct Wheels {
+            speeds: [float; 4],
+            gear: Gear,
+        }
+
+        function mean(speeds: [float; 4]) -> float {
+            let sum: float = speeds[0] + speeds[1] + speeds[2] + speeds[3];
+            return sum / 4.0;
+        }
+
+        component odometer(wheels: Wheels, dt: float) -> (speed: float, distance: float) {
+            init distance = 0.0;
+            speed = if wheels.gear == Gear::Reverse then -mean(wheels.speeds) else mean(wheels.speeds);
+            distance = last distance + speed * dt;
+        }
+
+        component ticker() -> (tick: int) {
+            init tick = 0;
+            tick = last tick + 1;
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let tokens = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap();
+    if let Some(path) = ctx.conf.dump_code {
+        grust_compiler_top::dump_code(&path, &tokens).unwrap();
+    }
+}
+
+#[test]
+fn should_reject_c_abi_options() {
+    let top: ir0::Top = parse_quote! {
+        #![c_abi]
+
+        component first(x: int?) -> (y: int?) {
+            y = x;
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let errors = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap_err();
+    // both the input and the output are rejected
+    assert_eq!(errors.len(), 2);
+}
@@ -0,0 +1,70 @@
+grust_compiler_top::prelude! {}
+
+#[test]
+fn should_compile_coverage_probes() {
+    let top: ir0::Top = parse_quote! {
+        #![dump = "tests/macro_outputs/coverage.rs", mode = test, coverage]
+        import signal car::speed_km_h                   : float;
+        import event  car::detect::left::pedestrian_l   : float;
+        import event  car::detect::right::pedestrian_r  : float;
+        export signal car::urban::braking::brakes       : Braking;
+
+        enum Braking { NoBrake, SoftBrake, UrgentBrake }
+
+        function brakes(distance: float, speed: float) -> Braking {
+            return if speed * speed / 100.0 < distance
+                then Braking::SoftBrake
+                else Braking::UrgentBrake;
+        }
+
+        component braking_state(pedest: float?, timeout_pedestrian: unit?, speed: float)
+            -> (state: Braking)
+        {
+            when {
+                init => { state = Braking::NoBrake; }
+                let d = pedest? => { state = brakes(d, speed); }
+                let _ = timeout_pedestrian? if last state == Braking::UrgentBrake => {
+                    state = Braking::SoftBrake;
+                }
+                let _ = timeout_pedestrian? => { state = Braking::NoBrake; }
+            }
+        }
+
+        service aeb @ [10, 3000] {
+            let event pedestrian: float = merge(pedestrian_l, pedestrian_r);
+            let event timeout_pedestrian: unit = timeout(pedestrian, 2000);
+            brakes = braking_state(pedestrian, timeout_pedestrian, speed_km_h);
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let tokens = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap();
+    if let Some(path) = ctx.conf.dump_code {
+        grust_compiler_top::dump_code(&path, &tokens).unwrap();
+    }
+}
+
+#[test]
+fn should_not_instrument_functions_nor_greusot() {
+    let top: ir0::Top = parse_quote! {
+        #![coverage]
+        function abs(x: int) -> int {
+            return if x < 0 then -x else x;
+        }
+        component dist(x: int) -> (d: int) {
+            d = abs(x);
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let tokens = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap();
+    assert!(!tokens.to_string().contains("hit"));
+
+    let top: ir0::Top = parse_quote! {
+        #![mode = greusot, coverage]
+        component sign(x: int) -> (s: int) {
+            s = if x < 0 then -1 else 1;
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let tokens = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap();
+    assert!(!tokens.to_string().contains("coverage"));
+}
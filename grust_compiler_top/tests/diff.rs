@@ -0,0 +1,52 @@
+grust_compiler_top::prelude! {}
+
+#[test]
+fn should_generate_differential_tests() {
+    let top: ir0::Top = parse_quote! {
+        #![dump = "tests/macro_outputs/diff.rs", mode = test, diff_test, component_para_threads]
+
+        component scale(i: int) -> (o: int)
+            requires { -1000 <= i && i <= 1000 }
+        {
+            let i1: int = (i - 54) * 2;
+            let i2: int = (i + 54) * 2;
+            let i3: int = 7 * i;
+            o = i1 + i2 + i3;
+        }
+
+        component sum(i: int) -> (s: int)
+            requires { -1000 <= i && i <= 1000 }
+        {
+            let a: int = scale(i);
+            let b: int = scale(2 * i);
+            init s = 0;
+            s = if last s > 100000 then 0 else last s + a + b;
+        }
+
+        test sum_nominal for sum {
+            step { i = 1 } expect { s = 33 }
+            step { i = 2 } expect { s = 99 }
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let tokens = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap();
+    if let Some(path) = ctx.conf.dump_code {
+        grust_compiler_top::dump_code(&path, &tokens).unwrap();
+    }
+}
+
+#[test]
+fn should_only_generate_differential_tests_with_parallel_components() {
+    let top: ir0::Top = parse_quote! {
+        #![mode = test, diff_test]
+        component sum(x: int) -> (s: int) {
+            init s = 0;
+            s = last s + x;
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let tokens = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap();
+    let tokens = tokens.to_string();
+    assert!(!tokens.contains("step_seq"));
+    assert!(!tokens.contains("sum_diff"));
+}
@@ -0,0 +1,50 @@
+grust_compiler_top::prelude! {}
+
+#[test]
+fn should_reject_ill_typed_generic_component_calls() {
+    let top: ir0::Top = parse_quote! {
+        component select<T: ord>(a: T, b: T, greatest: bool) -> (o: T) {
+            o = if greatest then (if a >= b then a else b) else (if a <= b then a else b);
+        }
+
+        component test(i: int, x: float) -> (o: int) {
+            o = select(i, x, true);
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    assert!(grust_compiler_top::into_token_stream_res(ast, &mut ctx).is_err());
+}
+
+#[test]
+fn should_accept_type_parameters_missing_from_outputs() {
+    let top: ir0::Top = parse_quote! {
+        component count<T: eq>(a: T, b: T) -> (n: int) {
+            init n = 0;
+            n = last n + (if a == b then 1 else 0);
+        }
+
+        component test(x: float, y: float) -> (n: int) {
+            n = count(x, y);
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    assert!(grust_compiler_top::into_token_stream_res(ast, &mut ctx).is_ok());
+}
+
+#[test]
+fn should_reject_generic_components_in_services() {
+    let top: ir0::Top = parse_quote! {
+        import signal car::speed: float;
+        export signal car::held: float;
+
+        component hold<T: ord>(x: T) -> (o: T) {
+            o = x;
+        }
+
+        service check {
+            held = hold(speed);
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    assert!(grust_compiler_top::into_token_stream_res(ast, &mut ctx).is_err());
+}
@@ -0,0 +1,113 @@
+grust_compiler_top::prelude! {}
+
+#[test]
+fn should_compile_interface_contracts() {
+    let top: ir0::Top = parse_quote! {
+        #![dump = "tests/macro_outputs/interface_contracts.rs", mode = test]
+        use std::maths::usuals::abs;
+
+        import signal car::speed: float requires { 0.0 <= speed && speed <= 60.0 };
+        import event  car::pedestrian: float requires { every 100 ms };
+        export signal car::brakes: bool;
+        export event  car::alert: float ensures { alert >= 0.0 } ensures { every 20 ms };
+        export signal car::gap: float ensures { valid(gap) } ensures { abs(gap) >= 0.0 };
+
+        // Distance from the sensor in its range
+        function valid(d: float) -> bool {
+            return abs(d) <= 100.0;
+        }
+
+        // Brakes when a pedestrian is closer than the current speed
+        component braking(pedestrian: float?, speed: float) -> (brakes: bool) {
+            brakes = when {
+                init => false,
+                let d = pedestrian? => d < speed,
+            };
+        }
+
+        service aeb ensures { brakes within 50 ms after pedestrian } {
+            brakes = braking(pedestrian, speed);
+            alert = pedestrian;
+            gap = speed;
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let tokens = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap();
+    if let Some(path) = ctx.conf.dump_code {
+        grust_compiler_top::dump_code(&path, &tokens).unwrap();
+    }
+}
+
+#[test]
+fn should_not_report_violations_without_contracts() {
+    let top: ir0::Top = parse_quote! {
+        import signal car::speed: float;
+        export signal car::filtered: float;
+
+        service check {
+            filtered = speed;
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let tokens = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap();
+    assert!(!tokens.to_string().contains("ContractViolation"));
+}
+
+#[test]
+fn should_reject_contracts_on_other_flows() {
+    let top: ir0::Top = parse_quote! {
+        import signal car::speed: float;
+        import signal car::limit: float requires { limit <= speed };
+        export signal car::filtered: float;
+
+        service check {
+            filtered = limit;
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    assert!(grust_compiler_top::into_token_stream_res(ast, &mut ctx).is_err());
+}
+
+#[test]
+fn should_reject_responses_to_exports() {
+    let top: ir0::Top = parse_quote! {
+        import signal car::speed: float;
+        export signal car::filtered: float;
+        export signal car::copy: float;
+
+        service check ensures { filtered within 10 ms after copy } {
+            filtered = speed;
+            copy = speed;
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    assert!(grust_compiler_top::into_token_stream_res(ast, &mut ctx).is_err());
+}
+
+#[test]
+fn should_reject_ill_typed_interface_contracts() {
+    let top: ir0::Top = parse_quote! {
+        import signal car::speed: float requires { speed <= 60 };
+        export signal car::filtered: float;
+
+        service check {
+            filtered = speed;
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    assert!(grust_compiler_top::into_token_stream_res(ast, &mut ctx).is_err());
+}
+
+#[test]
+fn should_reject_unknown_functions_in_interface_contracts() {
+    let top: ir0::Top = parse_quote! {
+        import signal car::speed: float;
+        export signal car::filtered: float ensures { valid(filtered) };
+
+        service check {
+            filtered = speed;
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    assert!(grust_compiler_top::into_token_stream_res(ast, &mut ctx).is_err());
+}
@@ -0,0 +1,100 @@
+grust_compiler_top::prelude! {}
+
+fn suggestions(top: ir0::Top) -> Vec<String> {
+    let (ast, mut ctx) = top.init();
+    let _ = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap();
+    ctx.warnings
+        .into_iter()
+        .map(|warning| warning.0.to_string())
+        .collect()
+}
+
+#[test]
+fn should_inject_inferred_invariants_greusot() {
+    let top: ir0::Top = parse_quote! {
+        #![dump = "tests/macro_outputs/invariants_greusot.rs", mode = greusot, infer_invariants]
+
+        enum Mode { Idle, Counting, Done }
+
+        // Counts the ticks up to 10 once started
+        component counter(start: bool, tick: bool) -> (count: int)
+            ensures { count <= 10 }
+        {
+            init (next_mode, count) = (Mode::Idle, 0);
+            let mode: Mode = last next_mode;
+            match mode {
+                Mode::Idle => {
+                    let next_mode: Mode = if start then Mode::Counting else mode;
+                    count = 0;
+                },
+                Mode::Counting => {
+                    let next_mode: Mode = if last count >= 9 then Mode::Done else mode;
+                    count = if last count >= 9 then 10
+                        else if tick then last count + 1
+                        else last count;
+                },
+                Mode::Done => {
+                    let next_mode: Mode = mode;
+                    count = 10;
+                },
+            }
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let tokens = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap();
+    if let Some(path) = ctx.conf.dump_code {
+        grust_compiler_top::dump_code(&path, &tokens).unwrap();
+    }
+}
+
+#[test]
+fn should_suggest_memory_bounds_from_requires() {
+    let top: ir0::Top = parse_quote! {
+        #![suggest_invariants]
+        component hold(x: int) -> (y: int)
+            requires { -5 <= x && x <= 5 }
+        {
+            init y = 0;
+            y = if x > 0 then x else last y;
+        }
+    };
+    assert_eq!(
+        suggestions(top),
+        vec!["inferred invariant for component `hold`: `invariant { 0 <= last y && last y <= 5 }`"]
+    );
+}
+
+#[test]
+fn should_suggest_unreachable_states() {
+    let top: ir0::Top = parse_quote! {
+        #![suggest_invariants]
+        enum Light { Red, Green, Broken }
+
+        component light(switch: bool) -> (color: Light) {
+            init color = Light::Red;
+            color = match last color {
+                Light::Red => if switch then Light::Green else Light::Red,
+                Light::Green => if switch then Light::Red else Light::Green,
+                Light::Broken => Light::Broken,
+            };
+        }
+    };
+    assert_eq!(
+        suggestions(top),
+        vec![
+            "inferred invariant for component `light`: `invariant { last color != Light::Broken }`"
+        ]
+    );
+}
+
+#[test]
+fn should_not_suggest_without_bounds() {
+    let top: ir0::Top = parse_quote! {
+        #![suggest_invariants]
+        component sum(x: int) -> (s: int) {
+            init s = 0;
+            s = last s + x;
+        }
+    };
+    assert!(suggestions(top).is_empty());
+}
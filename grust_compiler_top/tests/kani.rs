@@ -0,0 +1,60 @@
+grust_compiler_top::prelude! {}
+
+#[test]
+fn should_compile_kani_harnesses() {
+    let top: ir0::Top = parse_quote! {
+        #![dump = "tests/macro_outputs/kani.rs", mode = kani, kani_depth = 2]
+
+        const B_MAX: int = 6;
+
+        enum Gear { Park, Drive, Reverse }
+
+        function clamp(x: int, lo: int, hi: int) -> int {
+            return if x < lo then lo else if x > hi then hi else x;
+        }
+
+        // Counts activations, saturating at 100
+        component counter(tick: bool, gear: Gear) -> (count: int)
+            ensures { 0 <= count && count <= 100 }
+            ensures { last count <= count }
+            invariant { 0 <= last count && last count <= 100 }
+        {
+            init count = 0;
+            count = if tick && gear == Gear::Drive then clamp(last count + 1, 0, 100) else last count;
+        }
+
+        // Braking command in correct interval
+        component brakes(d: int, v: int) -> (b: int)
+            requires { 0 < d && d < 150 }
+            requires { v < 0 && -v <= 10 }
+            ensures  { 0 <= b && b <= B_MAX }
+            ensures  { d > 50 => b <= 1 }
+        {
+            b = clamp((v * v) / (2 * d), 0, B_MAX);
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let tokens = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap();
+    if let Some(path) = ctx.conf.dump_code {
+        grust_compiler_top::dump_code(&path, &tokens).unwrap();
+    }
+}
+
+#[test]
+fn should_reject_negated_forall_in_kani_harnesses() {
+    let top: ir0::Top = parse_quote! {
+        #![mode = kani]
+
+        component positive(x: int) -> (y: int)
+            ensures { (forall z: int, z * z >= 0) => y >= 0 }
+        {
+            y = if x < 0 then -x else x;
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let tokens = grust_compiler_top::into_token_stream_res(ast, &mut ctx)
+        .unwrap()
+        .to_string();
+    assert!(tokens.contains("compile_error"));
+    assert!(tokens.contains("on the left of `=>`"));
+}
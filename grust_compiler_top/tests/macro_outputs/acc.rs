@@ -9,6 +9,24 @@ pub fn safety_distance(sv_v_m_s: f64, fv_v_m_s: f64) -> f64 {
     let fv_d_stop_m = (fv_v_m_s * fv_v_m_s) / (2.0f64 * 5.886f64);
     sv_d_stop_m - fv_d_stop_m
 }
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64) -> (f64,) = |x, t| {
+    let core::time::integration::BackwardEulerOutput { i } =
+        <core::time::integration::BackwardEulerState as grust::core::Component>::step(
+            &mut <core::time::integration::BackwardEulerState as grust::core::Component>::init(),
+            core::time::integration::BackwardEulerInput { x, t },
+        );
+    (i,)
+};
+#[allow(clippy::type_complexity)]
+const _: fn(f64, f64) -> (f64,) = |x, t| {
+    let core::time::derivation::DeriveOutput { i } =
+        <core::time::derivation::DeriveState as grust::core::Component>::step(
+            &mut <core::time::derivation::DeriveState as grust::core::Component>::init(),
+            core::time::derivation::DeriveInput { x, t },
+        );
+    (i,)
+};
 pub struct CommandInput {
     pub distance_m: f64,
     pub sv_v_km_h: f64,
@@ -28,6 +46,9 @@ impl grust::core::Component for CommandState {
             derive: <core::time::derivation::DeriveState as grust::core::Component>::init(),
         }
     }
+    fn reset(&mut self) {
+        <core::time::derivation::DeriveState as grust::core::Component>::reset(&mut self.derive);
+    }
     fn step(&mut self, input: CommandInput) -> CommandOutput {
         let comp_app_derive = {
             let core::time::derivation::DeriveOutput { i } =
@@ -67,6 +88,9 @@ impl grust::core::Component for ErrorState {
             derive: <core::time::derivation::DeriveState as grust::core::Component>::init(),
         }
     }
+    fn reset(&mut self) {
+        <core::time::derivation::DeriveState as grust::core::Component>::reset(&mut self.derive);
+    }
     fn step(&mut self, input: ErrorInput) -> ErrorOutput {
         let sv_v_m_s = input.sv_v_km_h / 3.6f64;
         let x = sv_v_m_s * 1000.0f64;
@@ -111,6 +135,13 @@ impl grust::core::Component for PidState {
             derive: <core::time::derivation::DeriveState as grust::core::Component>::init(),
         }
     }
+    fn reset(&mut self) {
+        <ErrorState as grust::core::Component>::reset(&mut self.error);
+        <core::time::integration::BackwardEulerState as grust::core::Component>::reset(
+            &mut self.backward_euler,
+        );
+        <core::time::derivation::DeriveState as grust::core::Component>::reset(&mut self.derive);
+    }
     fn step(&mut self, input: PidInput) -> PidOutput {
         let p_e = {
             let ErrorOutput { e_m_s } = <ErrorState as grust::core::Component>::step(
@@ -175,6 +206,13 @@ impl grust::core::Component for ActivateState {
             last_x_1: false,
         }
     }
+    fn reset(&mut self) {
+        self.last_active = false;
+        self.last_approaching = false;
+        self.last_distance_m = 0.0f64;
+        self.last_x = false;
+        self.last_x_1 = false;
+    }
     fn step(&mut self, input: ActivateInput) -> ActivateOutput {
         let x = input.distance_m < self.last_distance_m;
         let x_1 = input.distance_m >= self.last_distance_m;
@@ -230,6 +268,10 @@ impl grust::core::Component for FilteredAccState {
             pid: <PidState as grust::core::Component>::init(),
         }
     }
+    fn reset(&mut self) {
+        <CommandState as grust::core::Component>::reset(&mut self.command);
+        <PidState as grust::core::Component>::reset(&mut self.pid);
+    }
     fn step(&mut self, input: FilteredAccInput) -> FilteredAccOutput {
         let (brakes_command_m_s, brakes_m_s) = match input.condition {
             true => {
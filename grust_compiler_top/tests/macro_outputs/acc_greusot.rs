@@ -31,6 +31,7 @@ impl grust::core::Component for AccState {
     fn init() -> AccState {
         AccState {}
     }
+    fn reset(&mut self) {}
     # [requires (input . d @ < 150)]
     # [requires (input . c == > (0 < input . s @ && input . s @ <= 50) && (0 < input . s @ + input . v @ && input . v @ < 0 && - input . v @ <= 10))]
     # [requires (input . c == > input . d @ - logical :: safety_distance (input . s @ , input . s @ + input . v @) > (input . v @ * input . v @) / (2 * 6))]
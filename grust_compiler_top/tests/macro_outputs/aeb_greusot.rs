@@ -41,6 +41,9 @@ impl grust::core::Component for BrakingStateState {
             last_state: Braking::NoBrake,
         }
     }
+    fn reset(&mut self) {
+        self.last_state = Braking::NoBrake;
+    }
     # [requires (0 <= input . speed @ && input . speed @ < 50)]
     # [ensures (forall < p : i64 > Some (p) == input . pedest == > result . state != Braking :: NoBrake)]
     fn step(&mut self, input: BrakingStateInput) -> BrakingStateOutput {
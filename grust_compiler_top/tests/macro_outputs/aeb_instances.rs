@@ -0,0 +1,1547 @@
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub enum Braking {
+    #[default]
+    NoBrake,
+    SoftBrake,
+    UrgentBrake,
+}
+pub fn compute_soft_braking_distance(speed: f64, acc: f64) -> f64 {
+    (speed * speed) / (100.0f64 * acc)
+}
+pub fn brakes(distance: f64, speed: f64, acc: f64) -> Braking {
+    let braking_distance = compute_soft_braking_distance(speed, acc);
+    let response = if braking_distance < distance {
+        Braking::SoftBrake
+    } else {
+        Braking::UrgentBrake
+    };
+    response
+}
+pub struct DeriveInput {
+    pub v_km_h: f64,
+    pub t: f64,
+}
+pub struct DeriveOutput {
+    pub a_km_h: f64,
+}
+pub struct DeriveState {
+    last_a: f64,
+    last_t: f64,
+    last_v: f64,
+    last_x: bool,
+}
+impl grust::core::Component for DeriveState {
+    type Input = DeriveInput;
+    type Output = DeriveOutput;
+    fn init() -> DeriveState {
+        DeriveState {
+            last_a: 0.0f64,
+            last_t: 0.0f64,
+            last_v: 0.0f64,
+            last_x: false,
+        }
+    }
+    fn reset(&mut self) {
+        self.last_a = 0.0f64;
+        self.last_t = 0.0f64;
+        self.last_v = 0.0f64;
+        self.last_x = false;
+    }
+    fn step(&mut self, input: DeriveInput) -> DeriveOutput {
+        let v = input.v_km_h / 3.6f64;
+        let dt = input.t - self.last_t;
+        let x = dt > 10.0f64;
+        let a = match () {
+            () if x && !(self.last_x) => (v - self.last_v) / dt,
+            () => {
+                let a = self.last_a;
+                a
+            }
+        };
+        let a_km_h = 3.6f64 * a;
+        self.last_a = a;
+        self.last_t = input.t;
+        self.last_v = v;
+        self.last_x = x;
+        DeriveOutput { a_km_h }
+    }
+}
+pub struct BrakingStateInput {
+    pub pedest: Option<f64>,
+    pub timeout_pedest: Option<()>,
+    pub speed: f64,
+    pub acc: f64,
+}
+pub struct BrakingStateOutput {
+    pub state: Braking,
+}
+pub struct BrakingStateState {
+    last_state: Braking,
+}
+impl grust::core::Component for BrakingStateState {
+    type Input = BrakingStateInput;
+    type Output = BrakingStateOutput;
+    fn init() -> BrakingStateState {
+        BrakingStateState {
+            last_state: Braking::NoBrake,
+        }
+    }
+    fn reset(&mut self) {
+        self.last_state = Braking::NoBrake;
+    }
+    fn step(&mut self, input: BrakingStateInput) -> BrakingStateOutput {
+        let state = match (input.pedest, input.timeout_pedest) {
+            (Some(d), _) => {
+                let state = brakes(d, input.speed, input.acc);
+                state
+            }
+            (_, Some(_)) => {
+                let state = Braking::NoBrake;
+                state
+            }
+            (_, _) => {
+                let state = self.last_state;
+                state
+            }
+        };
+        self.last_state = state;
+        BrakingStateOutput { state }
+    }
+}
+pub mod runtime {
+    use super::*;
+    use grust::futures::{sink::SinkExt, stream::StreamExt};
+    #[derive(Debug)]
+    pub enum RuntimeInput {
+        LeftSpeedKmH(f64, std::time::Instant),
+        RightSpeedKmH(f64, std::time::Instant),
+        LeftPedestrian(f64, std::time::Instant),
+        RightPedestrian(f64, std::time::Instant),
+        Timer(T, std::time::Instant),
+    }
+    use RuntimeInput as I;
+    impl grust::core::priority_stream::Reset for RuntimeInput {
+        fn do_reset(&self) -> bool {
+            match self {
+                I::Timer(timer, _) => grust::core::timer_stream::Timing::do_reset(timer),
+                _ => false,
+            }
+        }
+    }
+    impl PartialEq for RuntimeInput {
+        fn eq(&self, other: &Self) -> bool {
+            match (self, other) {
+                (I::LeftSpeedKmH(this, _), I::LeftSpeedKmH(other, _)) => this.eq(other),
+                (I::RightSpeedKmH(this, _), I::RightSpeedKmH(other, _)) => this.eq(other),
+                (I::LeftPedestrian(this, _), I::LeftPedestrian(other, _)) => this.eq(other),
+                (I::RightPedestrian(this, _), I::RightPedestrian(other, _)) => this.eq(other),
+                (I::Timer(this, _), I::Timer(other, _)) => this.eq(other),
+                _ => false,
+            }
+        }
+    }
+    impl RuntimeInput {
+        pub fn get_instant(&self) -> std::time::Instant {
+            match self {
+                I::LeftSpeedKmH(_, _grust_reserved_instant) => *_grust_reserved_instant,
+                I::RightSpeedKmH(_, _grust_reserved_instant) => *_grust_reserved_instant,
+                I::LeftPedestrian(_, _grust_reserved_instant) => *_grust_reserved_instant,
+                I::RightPedestrian(_, _grust_reserved_instant) => *_grust_reserved_instant,
+                I::Timer(_, _grust_reserved_instant) => *_grust_reserved_instant,
+            }
+        }
+        pub fn order(v1: &Self, v2: &Self) -> std::cmp::Ordering {
+            v1.get_instant().cmp(&v2.get_instant())
+        }
+    }
+    #[derive(Debug, PartialEq)]
+    pub enum RuntimeOutput {
+        LeftBrakes(Braking, std::time::Instant),
+        RightBrakes(Braking, std::time::Instant),
+    }
+    use RuntimeOutput as O;
+    #[derive(Debug, Default)]
+    pub struct RuntimeInit {
+        pub left_speed_km_h: f64,
+        pub right_speed_km_h: f64,
+    }
+    #[derive(Debug, PartialEq)]
+    pub enum RuntimeTimer {
+        TimeoutTimeoutPedest,
+        DelayLeftAeb,
+        TimeoutLeftAeb,
+        TimeoutTimeoutPedest1,
+        DelayRightAeb,
+        TimeoutRightAeb,
+    }
+    use RuntimeTimer as T;
+    impl grust::core::timer_stream::Timing for RuntimeTimer {
+        fn get_duration(&self) -> std::time::Duration {
+            match self {
+                T::TimeoutTimeoutPedest => std::time::Duration::from_millis(2000u64),
+                T::DelayLeftAeb => std::time::Duration::from_millis(10u64),
+                T::TimeoutLeftAeb => std::time::Duration::from_millis(2000u64),
+                T::TimeoutTimeoutPedest1 => std::time::Duration::from_millis(3000u64),
+                T::DelayRightAeb => std::time::Duration::from_millis(10u64),
+                T::TimeoutRightAeb => std::time::Duration::from_millis(3000u64),
+            }
+        }
+        fn do_reset(&self) -> bool {
+            match self {
+                T::TimeoutTimeoutPedest => true,
+                T::DelayLeftAeb => true,
+                T::TimeoutLeftAeb => true,
+                T::TimeoutTimeoutPedest1 => true,
+                T::DelayRightAeb => true,
+                T::TimeoutRightAeb => true,
+            }
+        }
+    }
+    pub struct Runtime {
+        _grust_reserved_init_instant: std::time::Instant,
+        left_aeb: left_aeb_service::LeftAebService,
+        right_aeb: right_aeb_service::RightAebService,
+        output: grust::futures::channel::mpsc::Sender<O>,
+        timer: grust::futures::channel::mpsc::Sender<(T, std::time::Instant)>,
+    }
+    impl Runtime {
+        pub fn new(
+            _grust_reserved_init_instant: std::time::Instant,
+            output: grust::futures::channel::mpsc::Sender<O>,
+            timer: grust::futures::channel::mpsc::Sender<(T, std::time::Instant)>,
+        ) -> Runtime {
+            let left_aeb = left_aeb_service::LeftAebService::init(
+                _grust_reserved_init_instant,
+                output.clone(),
+                timer.clone(),
+            );
+            let right_aeb = right_aeb_service::RightAebService::init(
+                _grust_reserved_init_instant,
+                output.clone(),
+                timer.clone(),
+            );
+            Runtime {
+                _grust_reserved_init_instant,
+                left_aeb,
+                right_aeb,
+                output,
+                timer,
+            }
+        }
+        #[inline]
+        pub async fn send_timer(
+            &mut self,
+            timer: T,
+            instant: std::time::Instant,
+        ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+            self.timer.send((timer, instant)).await?;
+            Ok(())
+        }
+        pub async fn run_loop(
+            self,
+            input: impl grust::futures::Stream<Item = I>,
+            init_vals: RuntimeInit,
+        ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+            grust::futures::pin_mut!(input);
+            let mut runtime = self;
+            let RuntimeInit {
+                left_speed_km_h,
+                right_speed_km_h,
+            } = init_vals;
+            runtime.left_aeb.handle_init(left_speed_km_h).await?;
+            runtime.right_aeb.handle_init(right_speed_km_h).await?;
+            while let Some(input) = input.next().await {
+                match input {
+                    I::RightSpeedKmH(right_speed_km_h, _grust_reserved_instant) => {
+                        runtime
+                            .right_aeb
+                            .handle_right_speed_km_h(_grust_reserved_instant, right_speed_km_h)
+                            .await?;
+                    }
+                    I::Timer(T::TimeoutTimeoutPedest1, _grust_reserved_instant) => {
+                        runtime
+                            .right_aeb
+                            .handle_timeout_timeout_pedest_1(_grust_reserved_instant)
+                            .await?;
+                    }
+                    I::Timer(T::DelayRightAeb, _grust_reserved_instant) => {
+                        runtime
+                            .right_aeb
+                            .handle_delay_right_aeb(_grust_reserved_instant)
+                            .await?;
+                    }
+                    I::Timer(T::TimeoutRightAeb, _grust_reserved_instant) => {
+                        runtime
+                            .right_aeb
+                            .handle_timeout_right_aeb(_grust_reserved_instant)
+                            .await?;
+                    }
+                    I::LeftPedestrian(left_pedestrian, _grust_reserved_instant) => {
+                        runtime
+                            .left_aeb
+                            .handle_left_pedestrian(_grust_reserved_instant, left_pedestrian)
+                            .await?;
+                    }
+                    I::LeftSpeedKmH(left_speed_km_h, _grust_reserved_instant) => {
+                        runtime
+                            .left_aeb
+                            .handle_left_speed_km_h(_grust_reserved_instant, left_speed_km_h)
+                            .await?;
+                    }
+                    I::Timer(T::TimeoutLeftAeb, _grust_reserved_instant) => {
+                        runtime
+                            .left_aeb
+                            .handle_timeout_left_aeb(_grust_reserved_instant)
+                            .await?;
+                    }
+                    I::Timer(T::TimeoutTimeoutPedest, _grust_reserved_instant) => {
+                        runtime
+                            .left_aeb
+                            .handle_timeout_timeout_pedest(_grust_reserved_instant)
+                            .await?;
+                    }
+                    I::RightPedestrian(right_pedestrian, _grust_reserved_instant) => {
+                        runtime
+                            .right_aeb
+                            .handle_right_pedestrian(_grust_reserved_instant, right_pedestrian)
+                            .await?;
+                    }
+                    I::Timer(T::DelayLeftAeb, _grust_reserved_instant) => {
+                        runtime
+                            .left_aeb
+                            .handle_delay_left_aeb(_grust_reserved_instant)
+                            .await?;
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+    pub mod left_aeb_service {
+        use super::*;
+        use grust::futures::{sink::SinkExt, stream::StreamExt};
+        mod ctx_ty {
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct RightSpeedKmH(f64, bool);
+            impl RightSpeedKmH {
+                pub fn set(&mut self, right_speed_km_h: f64) {
+                    self.1 = self.0 != right_speed_km_h;
+                    self.0 = right_speed_km_h;
+                }
+                pub fn get(&self) -> f64 {
+                    self.0
+                }
+                pub fn take(&mut self) -> f64 {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct RightBrakes(super::Braking, bool);
+            impl RightBrakes {
+                pub fn set(&mut self, right_brakes: super::Braking) {
+                    self.1 = self.0 != right_brakes;
+                    self.0 = right_brakes;
+                }
+                pub fn get(&self) -> super::Braking {
+                    self.0
+                }
+                pub fn take(&mut self) -> super::Braking {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct LeftBrakes(super::Braking, bool);
+            impl LeftBrakes {
+                pub fn set(&mut self, left_brakes: super::Braking) {
+                    self.1 = self.0 != left_brakes;
+                    self.0 = left_brakes;
+                }
+                pub fn get(&self) -> super::Braking {
+                    self.0
+                }
+                pub fn take(&mut self) -> super::Braking {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct X(f64, bool);
+            impl X {
+                pub fn set(&mut self, x: f64) {
+                    self.1 = self.0 != x;
+                    self.0 = x;
+                }
+                pub fn get(&self) -> f64 {
+                    self.0
+                }
+                pub fn take(&mut self) -> f64 {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct AccKmH(f64, bool);
+            impl AccKmH {
+                pub fn set(&mut self, acc_km_h: f64) {
+                    self.1 = self.0 != acc_km_h;
+                    self.0 = acc_km_h;
+                }
+                pub fn get(&self) -> f64 {
+                    self.0
+                }
+                pub fn take(&mut self) -> f64 {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct LeftSpeedKmH(f64, bool);
+            impl LeftSpeedKmH {
+                pub fn set(&mut self, left_speed_km_h: f64) {
+                    self.1 = self.0 != left_speed_km_h;
+                    self.0 = left_speed_km_h;
+                }
+                pub fn get(&self) -> f64 {
+                    self.0
+                }
+                pub fn take(&mut self) -> f64 {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+        }
+        #[derive(Clone, Copy, PartialEq, Default, Debug)]
+        pub struct Context {
+            pub right_speed_km_h: ctx_ty::RightSpeedKmH,
+            pub right_brakes: ctx_ty::RightBrakes,
+            pub left_brakes: ctx_ty::LeftBrakes,
+            pub x: ctx_ty::X,
+            pub acc_km_h: ctx_ty::AccKmH,
+            pub left_speed_km_h: ctx_ty::LeftSpeedKmH,
+        }
+        impl Context {
+            fn init() -> Context {
+                Default::default()
+            }
+            fn reset(&mut self) {
+                self.right_speed_km_h.reset();
+                self.right_brakes.reset();
+                self.left_brakes.reset();
+                self.x.reset();
+                self.acc_km_h.reset();
+                self.left_speed_km_h.reset();
+            }
+        }
+        #[derive(Default)]
+        pub struct LeftAebServiceStore {
+            left_speed_km_h: Option<(f64, std::time::Instant)>,
+            left_pedestrian: Option<(f64, std::time::Instant)>,
+            timeout_timeout_pedest: Option<((), std::time::Instant)>,
+        }
+        impl LeftAebServiceStore {
+            pub fn not_empty(&self) -> bool {
+                self.left_speed_km_h.is_some()
+                    || self.left_pedestrian.is_some()
+                    || self.timeout_timeout_pedest.is_some()
+            }
+        }
+        pub struct LeftAebService {
+            _grust_reserved_init_instant: std::time::Instant,
+            context: Context,
+            delayed: bool,
+            input_store: LeftAebServiceStore,
+            braking_state: BrakingStateState,
+            derive: DeriveState,
+            output: grust::futures::channel::mpsc::Sender<O>,
+            timer: grust::futures::channel::mpsc::Sender<(T, std::time::Instant)>,
+        }
+        impl LeftAebService {
+            pub fn init(
+                _grust_reserved_init_instant: std::time::Instant,
+                output: grust::futures::channel::mpsc::Sender<O>,
+                timer: grust::futures::channel::mpsc::Sender<(T, std::time::Instant)>,
+            ) -> LeftAebService {
+                let context = Context::init();
+                let delayed = true;
+                let input_store = Default::default();
+                let braking_state = <BrakingStateState as grust::core::Component>::init();
+                let derive = <DeriveState as grust::core::Component>::init();
+                LeftAebService {
+                    _grust_reserved_init_instant,
+                    context,
+                    delayed,
+                    input_store,
+                    braking_state,
+                    derive,
+                    output,
+                    timer,
+                }
+            }
+            pub async fn handle_init(
+                &mut self,
+                left_speed_km_h: f64,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                let _grust_reserved_instant = self._grust_reserved_init_instant;
+                self.reset_service_timeout(_grust_reserved_instant).await?;
+                self.send_timer(T::TimeoutTimeoutPedest, _grust_reserved_instant)
+                    .await?;
+                self.send_output(
+                    O::RightBrakes(self.context.right_brakes.get(), _grust_reserved_instant),
+                    _grust_reserved_instant,
+                )
+                .await?;
+                self.context.left_speed_km_h.set(left_speed_km_h);
+                let x = (_grust_reserved_instant
+                    .duration_since(self._grust_reserved_init_instant)
+                    .as_millis()) as f64;
+                self.context.x.set(x);
+                let DeriveOutput { a_km_h: acc_km_h } =
+                    <DeriveState as grust::core::Component>::step(
+                        &mut self.derive,
+                        DeriveInput {
+                            v_km_h: left_speed_km_h,
+                            t: x,
+                        },
+                    );
+                self.context.acc_km_h.set(acc_km_h);
+                let BrakingStateOutput { state: left_brakes } =
+                    <BrakingStateState as grust::core::Component>::step(
+                        &mut self.braking_state,
+                        BrakingStateInput {
+                            pedest: None,
+                            timeout_pedest: None,
+                            speed: left_speed_km_h,
+                            acc: self.context.acc_km_h.get(),
+                        },
+                    );
+                self.context.left_brakes.set(left_brakes);
+                self.send_output(
+                    O::LeftBrakes(self.context.left_brakes.get(), _grust_reserved_instant),
+                    _grust_reserved_instant,
+                )
+                .await?;
+                Ok(())
+            }
+            pub async fn handle_left_speed_km_h(
+                &mut self,
+                _left_speed_km_h_instant: std::time::Instant,
+                left_speed_km_h: f64,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                if self.delayed {
+                    self.reset_time_constraints(_left_speed_km_h_instant)
+                        .await?;
+                    self.context.reset();
+                    self.context.left_speed_km_h.set(left_speed_km_h);
+                    let x = (_left_speed_km_h_instant
+                        .duration_since(self._grust_reserved_init_instant)
+                        .as_millis()) as f64;
+                    self.context.x.set(x);
+                    if self.context.left_speed_km_h.is_new() || self.context.x.is_new() {
+                        let DeriveOutput { a_km_h: acc_km_h } =
+                            <DeriveState as grust::core::Component>::step(
+                                &mut self.derive,
+                                DeriveInput {
+                                    v_km_h: left_speed_km_h,
+                                    t: x,
+                                },
+                            );
+                        self.context.acc_km_h.set(acc_km_h);
+                    }
+                    if self.context.left_speed_km_h.is_new() || self.context.acc_km_h.is_new() {
+                        let BrakingStateOutput { state: left_brakes } =
+                            <BrakingStateState as grust::core::Component>::step(
+                                &mut self.braking_state,
+                                BrakingStateInput {
+                                    pedest: None,
+                                    timeout_pedest: None,
+                                    speed: left_speed_km_h,
+                                    acc: self.context.acc_km_h.get(),
+                                },
+                            );
+                        self.context.left_brakes.set(left_brakes);
+                    }
+                    if self.context.left_brakes.is_new() {
+                        self.send_output(
+                            O::LeftBrakes(self.context.left_brakes.get(), _left_speed_km_h_instant),
+                            _left_speed_km_h_instant,
+                        )
+                        .await?;
+                    }
+                } else {
+                    let unique = self
+                        .input_store
+                        .left_speed_km_h
+                        .replace((left_speed_km_h, _left_speed_km_h_instant));
+                    assert ! (unique . is_none () , "flow `left_speed_km_h` changes twice within one minimal delay of the service, consider reducing this delay");
+                }
+                Ok(())
+            }
+            pub async fn handle_delay_left_aeb(
+                &mut self,
+                _grust_reserved_instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.context.reset();
+                if self.input_store.not_empty() {
+                    self.reset_time_constraints(_grust_reserved_instant).await?;
+                    let left_pedestrian_ref = &mut None;
+                    let timeout_pedest_ref = &mut None;
+                    let timeout_timeout_pedest_ref = &mut None;
+                    let _timeout_timeout_pedest_input_store =
+                        self.input_store.timeout_timeout_pedest.take();
+                    *timeout_timeout_pedest_ref =
+                        _timeout_timeout_pedest_input_store.map(|(x, _)| x);
+                    let _left_pedestrian_input_store = self.input_store.left_pedestrian.take();
+                    *left_pedestrian_ref = _left_pedestrian_input_store.map(|(x, _)| x);
+                    if left_pedestrian_ref.is_some() {
+                        self.send_timer(T::TimeoutTimeoutPedest, _grust_reserved_instant)
+                            .await?;
+                    } else {
+                        if timeout_timeout_pedest_ref.is_some() {
+                            *timeout_pedest_ref = Some(());
+                            if let Some((_, _timeout_timeout_pedest_instant)) =
+                                _timeout_timeout_pedest_input_store
+                            {
+                                self.send_timer(
+                                    T::TimeoutTimeoutPedest,
+                                    _timeout_timeout_pedest_instant,
+                                )
+                                .await?;
+                            }
+                        }
+                    }
+                    let _left_speed_km_h_input_store = self.input_store.left_speed_km_h.take();
+                    if let Some((left_speed_km_h, _)) = _left_speed_km_h_input_store {
+                        self.context.left_speed_km_h.set(left_speed_km_h);
+                    }
+                    let x = (_grust_reserved_instant
+                        .duration_since(self._grust_reserved_init_instant)
+                        .as_millis()) as f64;
+                    self.context.x.set(x);
+                    if self.context.left_speed_km_h.is_new() || self.context.x.is_new() {
+                        let DeriveOutput { a_km_h: acc_km_h } =
+                            <DeriveState as grust::core::Component>::step(
+                                &mut self.derive,
+                                DeriveInput {
+                                    v_km_h: self.context.left_speed_km_h.get(),
+                                    t: x,
+                                },
+                            );
+                        self.context.acc_km_h.set(acc_km_h);
+                    }
+                    if left_pedestrian_ref.is_some()
+                        || timeout_pedest_ref.is_some()
+                        || self.context.left_speed_km_h.is_new()
+                        || self.context.acc_km_h.is_new()
+                    {
+                        let BrakingStateOutput { state: left_brakes } =
+                            <BrakingStateState as grust::core::Component>::step(
+                                &mut self.braking_state,
+                                BrakingStateInput {
+                                    pedest: *left_pedestrian_ref,
+                                    timeout_pedest: *timeout_pedest_ref,
+                                    speed: self.context.left_speed_km_h.get(),
+                                    acc: self.context.acc_km_h.get(),
+                                },
+                            );
+                        self.context.left_brakes.set(left_brakes);
+                    }
+                    if self.context.left_brakes.is_new() {
+                        self.send_output(
+                            O::LeftBrakes(self.context.left_brakes.get(), _grust_reserved_instant),
+                            _grust_reserved_instant,
+                        )
+                        .await?;
+                    }
+                } else {
+                    self.delayed = true;
+                }
+                Ok(())
+            }
+            #[inline]
+            pub async fn reset_service_delay(
+                &mut self,
+                _grust_reserved_instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.timer
+                    .send((T::DelayLeftAeb, _grust_reserved_instant))
+                    .await?;
+                self.delayed = false;
+                Ok(())
+            }
+            pub async fn handle_timeout_left_aeb(
+                &mut self,
+                _timeout_left_aeb_instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.reset_time_constraints(_timeout_left_aeb_instant)
+                    .await?;
+                self.context.reset();
+                self.send_output(
+                    O::RightBrakes(self.context.right_brakes.get(), _timeout_left_aeb_instant),
+                    _timeout_left_aeb_instant,
+                )
+                .await?;
+                let x = (_timeout_left_aeb_instant
+                    .duration_since(self._grust_reserved_init_instant)
+                    .as_millis()) as f64;
+                self.context.x.set(x);
+                if self.context.left_speed_km_h.is_new() || self.context.x.is_new() {
+                    let DeriveOutput { a_km_h: acc_km_h } =
+                        <DeriveState as grust::core::Component>::step(
+                            &mut self.derive,
+                            DeriveInput {
+                                v_km_h: self.context.left_speed_km_h.get(),
+                                t: x,
+                            },
+                        );
+                    self.context.acc_km_h.set(acc_km_h);
+                }
+                if self.context.left_speed_km_h.is_new() || self.context.acc_km_h.is_new() {
+                    let BrakingStateOutput { state: left_brakes } =
+                        <BrakingStateState as grust::core::Component>::step(
+                            &mut self.braking_state,
+                            BrakingStateInput {
+                                pedest: None,
+                                timeout_pedest: None,
+                                speed: self.context.left_speed_km_h.get(),
+                                acc: self.context.acc_km_h.get(),
+                            },
+                        );
+                    self.context.left_brakes.set(left_brakes);
+                }
+                self.send_output(
+                    O::LeftBrakes(self.context.left_brakes.get(), _timeout_left_aeb_instant),
+                    _timeout_left_aeb_instant,
+                )
+                .await?;
+                Ok(())
+            }
+            #[inline]
+            pub async fn reset_service_timeout(
+                &mut self,
+                _timeout_left_aeb_instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.timer
+                    .send((T::TimeoutLeftAeb, _timeout_left_aeb_instant))
+                    .await?;
+                Ok(())
+            }
+            pub async fn handle_left_pedestrian(
+                &mut self,
+                _left_pedestrian_instant: std::time::Instant,
+                left_pedestrian: f64,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                if self.delayed {
+                    self.reset_time_constraints(_left_pedestrian_instant)
+                        .await?;
+                    self.context.reset();
+                    let left_pedestrian_ref = &mut None;
+                    *left_pedestrian_ref = Some(left_pedestrian);
+                    if left_pedestrian_ref.is_some() {
+                        self.send_timer(T::TimeoutTimeoutPedest, _left_pedestrian_instant)
+                            .await?;
+                    }
+                    let x = (_left_pedestrian_instant
+                        .duration_since(self._grust_reserved_init_instant)
+                        .as_millis()) as f64;
+                    self.context.x.set(x);
+                    if self.context.left_speed_km_h.is_new() || self.context.x.is_new() {
+                        let DeriveOutput { a_km_h: acc_km_h } =
+                            <DeriveState as grust::core::Component>::step(
+                                &mut self.derive,
+                                DeriveInput {
+                                    v_km_h: self.context.left_speed_km_h.get(),
+                                    t: x,
+                                },
+                            );
+                        self.context.acc_km_h.set(acc_km_h);
+                    }
+                    if left_pedestrian_ref.is_some()
+                        || self.context.left_speed_km_h.is_new()
+                        || self.context.acc_km_h.is_new()
+                    {
+                        let BrakingStateOutput { state: left_brakes } =
+                            <BrakingStateState as grust::core::Component>::step(
+                                &mut self.braking_state,
+                                BrakingStateInput {
+                                    pedest: *left_pedestrian_ref,
+                                    timeout_pedest: None,
+                                    speed: self.context.left_speed_km_h.get(),
+                                    acc: self.context.acc_km_h.get(),
+                                },
+                            );
+                        self.context.left_brakes.set(left_brakes);
+                    }
+                    if self.context.left_brakes.is_new() {
+                        self.send_output(
+                            O::LeftBrakes(self.context.left_brakes.get(), _left_pedestrian_instant),
+                            _left_pedestrian_instant,
+                        )
+                        .await?;
+                    }
+                } else {
+                    let unique = self
+                        .input_store
+                        .left_pedestrian
+                        .replace((left_pedestrian, _left_pedestrian_instant));
+                    assert ! (unique . is_none () , "flow `left_pedestrian` changes twice within one minimal delay of the service, consider reducing this delay");
+                }
+                Ok(())
+            }
+            pub async fn handle_timeout_timeout_pedest(
+                &mut self,
+                _timeout_timeout_pedest_instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                if self.delayed {
+                    self.reset_time_constraints(_timeout_timeout_pedest_instant)
+                        .await?;
+                    self.context.reset();
+                    let timeout_pedest_ref = &mut None;
+                    *timeout_pedest_ref = Some(());
+                    self.send_timer(T::TimeoutTimeoutPedest, _timeout_timeout_pedest_instant)
+                        .await?;
+                    let x = (_timeout_timeout_pedest_instant
+                        .duration_since(self._grust_reserved_init_instant)
+                        .as_millis()) as f64;
+                    self.context.x.set(x);
+                    if self.context.left_speed_km_h.is_new() || self.context.x.is_new() {
+                        let DeriveOutput { a_km_h: acc_km_h } =
+                            <DeriveState as grust::core::Component>::step(
+                                &mut self.derive,
+                                DeriveInput {
+                                    v_km_h: self.context.left_speed_km_h.get(),
+                                    t: x,
+                                },
+                            );
+                        self.context.acc_km_h.set(acc_km_h);
+                    }
+                    if timeout_pedest_ref.is_some()
+                        || self.context.left_speed_km_h.is_new()
+                        || self.context.acc_km_h.is_new()
+                    {
+                        let BrakingStateOutput { state: left_brakes } =
+                            <BrakingStateState as grust::core::Component>::step(
+                                &mut self.braking_state,
+                                BrakingStateInput {
+                                    pedest: None,
+                                    timeout_pedest: *timeout_pedest_ref,
+                                    speed: self.context.left_speed_km_h.get(),
+                                    acc: self.context.acc_km_h.get(),
+                                },
+                            );
+                        self.context.left_brakes.set(left_brakes);
+                    }
+                    if self.context.left_brakes.is_new() {
+                        self.send_output(
+                            O::LeftBrakes(
+                                self.context.left_brakes.get(),
+                                _timeout_timeout_pedest_instant,
+                            ),
+                            _timeout_timeout_pedest_instant,
+                        )
+                        .await?;
+                    }
+                } else {
+                    let unique = self
+                        .input_store
+                        .timeout_timeout_pedest
+                        .replace(((), _timeout_timeout_pedest_instant));
+                    assert ! (unique . is_none () , "flow `timeout_timeout_pedest` changes twice within one minimal delay of the service, consider reducing this delay");
+                }
+                Ok(())
+            }
+            #[inline]
+            pub async fn reset_time_constraints(
+                &mut self,
+                instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.reset_service_delay(instant).await?;
+                Ok(())
+            }
+            #[inline]
+            pub async fn send_output(
+                &mut self,
+                output: O,
+                instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.reset_service_timeout(instant).await?;
+                self.output.feed(output).await?;
+                Ok(())
+            }
+            #[inline]
+            pub async fn send_timer(
+                &mut self,
+                timer: T,
+                instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.timer.feed((timer, instant)).await?;
+                Ok(())
+            }
+        }
+    }
+    pub mod right_aeb_service {
+        use super::*;
+        use grust::futures::{sink::SinkExt, stream::StreamExt};
+        mod ctx_ty {
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct RightSpeedKmH(f64, bool);
+            impl RightSpeedKmH {
+                pub fn set(&mut self, right_speed_km_h: f64) {
+                    self.1 = self.0 != right_speed_km_h;
+                    self.0 = right_speed_km_h;
+                }
+                pub fn get(&self) -> f64 {
+                    self.0
+                }
+                pub fn take(&mut self) -> f64 {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct RightBrakes(super::Braking, bool);
+            impl RightBrakes {
+                pub fn set(&mut self, right_brakes: super::Braking) {
+                    self.1 = self.0 != right_brakes;
+                    self.0 = right_brakes;
+                }
+                pub fn get(&self) -> super::Braking {
+                    self.0
+                }
+                pub fn take(&mut self) -> super::Braking {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct LeftBrakes(super::Braking, bool);
+            impl LeftBrakes {
+                pub fn set(&mut self, left_brakes: super::Braking) {
+                    self.1 = self.0 != left_brakes;
+                    self.0 = left_brakes;
+                }
+                pub fn get(&self) -> super::Braking {
+                    self.0
+                }
+                pub fn take(&mut self) -> super::Braking {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct X(f64, bool);
+            impl X {
+                pub fn set(&mut self, x: f64) {
+                    self.1 = self.0 != x;
+                    self.0 = x;
+                }
+                pub fn get(&self) -> f64 {
+                    self.0
+                }
+                pub fn take(&mut self) -> f64 {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct AccKmH(f64, bool);
+            impl AccKmH {
+                pub fn set(&mut self, acc_km_h: f64) {
+                    self.1 = self.0 != acc_km_h;
+                    self.0 = acc_km_h;
+                }
+                pub fn get(&self) -> f64 {
+                    self.0
+                }
+                pub fn take(&mut self) -> f64 {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct LeftSpeedKmH(f64, bool);
+            impl LeftSpeedKmH {
+                pub fn set(&mut self, left_speed_km_h: f64) {
+                    self.1 = self.0 != left_speed_km_h;
+                    self.0 = left_speed_km_h;
+                }
+                pub fn get(&self) -> f64 {
+                    self.0
+                }
+                pub fn take(&mut self) -> f64 {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+        }
+        #[derive(Clone, Copy, PartialEq, Default, Debug)]
+        pub struct Context {
+            pub right_speed_km_h: ctx_ty::RightSpeedKmH,
+            pub right_brakes: ctx_ty::RightBrakes,
+            pub left_brakes: ctx_ty::LeftBrakes,
+            pub x: ctx_ty::X,
+            pub acc_km_h: ctx_ty::AccKmH,
+            pub left_speed_km_h: ctx_ty::LeftSpeedKmH,
+        }
+        impl Context {
+            fn init() -> Context {
+                Default::default()
+            }
+            fn reset(&mut self) {
+                self.right_speed_km_h.reset();
+                self.right_brakes.reset();
+                self.left_brakes.reset();
+                self.x.reset();
+                self.acc_km_h.reset();
+                self.left_speed_km_h.reset();
+            }
+        }
+        #[derive(Default)]
+        pub struct RightAebServiceStore {
+            right_speed_km_h: Option<(f64, std::time::Instant)>,
+            timeout_timeout_pedest_1: Option<((), std::time::Instant)>,
+            right_pedestrian: Option<(f64, std::time::Instant)>,
+        }
+        impl RightAebServiceStore {
+            pub fn not_empty(&self) -> bool {
+                self.right_speed_km_h.is_some()
+                    || self.timeout_timeout_pedest_1.is_some()
+                    || self.right_pedestrian.is_some()
+            }
+        }
+        pub struct RightAebService {
+            _grust_reserved_init_instant: std::time::Instant,
+            context: Context,
+            delayed: bool,
+            input_store: RightAebServiceStore,
+            derive: DeriveState,
+            braking_state: BrakingStateState,
+            output: grust::futures::channel::mpsc::Sender<O>,
+            timer: grust::futures::channel::mpsc::Sender<(T, std::time::Instant)>,
+        }
+        impl RightAebService {
+            pub fn init(
+                _grust_reserved_init_instant: std::time::Instant,
+                output: grust::futures::channel::mpsc::Sender<O>,
+                timer: grust::futures::channel::mpsc::Sender<(T, std::time::Instant)>,
+            ) -> RightAebService {
+                let context = Context::init();
+                let delayed = true;
+                let input_store = Default::default();
+                let derive = <DeriveState as grust::core::Component>::init();
+                let braking_state = <BrakingStateState as grust::core::Component>::init();
+                RightAebService {
+                    _grust_reserved_init_instant,
+                    context,
+                    delayed,
+                    input_store,
+                    derive,
+                    braking_state,
+                    output,
+                    timer,
+                }
+            }
+            pub async fn handle_init(
+                &mut self,
+                right_speed_km_h: f64,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                let _grust_reserved_instant = self._grust_reserved_init_instant;
+                self.reset_service_timeout(_grust_reserved_instant).await?;
+                self.send_timer(T::TimeoutTimeoutPedest1, _grust_reserved_instant)
+                    .await?;
+                self.send_output(
+                    O::LeftBrakes(self.context.left_brakes.get(), _grust_reserved_instant),
+                    _grust_reserved_instant,
+                )
+                .await?;
+                self.context.right_speed_km_h.set(right_speed_km_h);
+                let x = (_grust_reserved_instant
+                    .duration_since(self._grust_reserved_init_instant)
+                    .as_millis()) as f64;
+                self.context.x.set(x);
+                let DeriveOutput { a_km_h: acc_km_h } =
+                    <DeriveState as grust::core::Component>::step(
+                        &mut self.derive,
+                        DeriveInput {
+                            v_km_h: right_speed_km_h,
+                            t: x,
+                        },
+                    );
+                self.context.acc_km_h.set(acc_km_h);
+                let BrakingStateOutput {
+                    state: right_brakes,
+                } = <BrakingStateState as grust::core::Component>::step(
+                    &mut self.braking_state,
+                    BrakingStateInput {
+                        pedest: None,
+                        timeout_pedest: None,
+                        speed: right_speed_km_h,
+                        acc: self.context.acc_km_h.get(),
+                    },
+                );
+                self.context.right_brakes.set(right_brakes);
+                self.send_output(
+                    O::RightBrakes(self.context.right_brakes.get(), _grust_reserved_instant),
+                    _grust_reserved_instant,
+                )
+                .await?;
+                Ok(())
+            }
+            pub async fn handle_delay_right_aeb(
+                &mut self,
+                _grust_reserved_instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.context.reset();
+                if self.input_store.not_empty() {
+                    self.reset_time_constraints(_grust_reserved_instant).await?;
+                    let timeout_timeout_pedest_1_ref = &mut None;
+                    let right_pedestrian_ref = &mut None;
+                    let timeout_pedest_ref = &mut None;
+                    let _right_pedestrian_input_store = self.input_store.right_pedestrian.take();
+                    *right_pedestrian_ref = _right_pedestrian_input_store.map(|(x, _)| x);
+                    let _timeout_timeout_pedest_1_input_store =
+                        self.input_store.timeout_timeout_pedest_1.take();
+                    *timeout_timeout_pedest_1_ref =
+                        _timeout_timeout_pedest_1_input_store.map(|(x, _)| x);
+                    if right_pedestrian_ref.is_some() {
+                        self.send_timer(T::TimeoutTimeoutPedest1, _grust_reserved_instant)
+                            .await?;
+                    } else {
+                        if timeout_timeout_pedest_1_ref.is_some() {
+                            *timeout_pedest_ref = Some(());
+                            if let Some((_, _timeout_timeout_pedest_1_instant)) =
+                                _timeout_timeout_pedest_1_input_store
+                            {
+                                self.send_timer(
+                                    T::TimeoutTimeoutPedest1,
+                                    _timeout_timeout_pedest_1_instant,
+                                )
+                                .await?;
+                            }
+                        }
+                    }
+                    let _right_speed_km_h_input_store = self.input_store.right_speed_km_h.take();
+                    if let Some((right_speed_km_h, _)) = _right_speed_km_h_input_store {
+                        self.context.right_speed_km_h.set(right_speed_km_h);
+                    }
+                    let x = (_grust_reserved_instant
+                        .duration_since(self._grust_reserved_init_instant)
+                        .as_millis()) as f64;
+                    self.context.x.set(x);
+                    if self.context.right_speed_km_h.is_new() || self.context.x.is_new() {
+                        let DeriveOutput { a_km_h: acc_km_h } =
+                            <DeriveState as grust::core::Component>::step(
+                                &mut self.derive,
+                                DeriveInput {
+                                    v_km_h: self.context.right_speed_km_h.get(),
+                                    t: x,
+                                },
+                            );
+                        self.context.acc_km_h.set(acc_km_h);
+                    }
+                    if right_pedestrian_ref.is_some()
+                        || timeout_pedest_ref.is_some()
+                        || self.context.right_speed_km_h.is_new()
+                        || self.context.acc_km_h.is_new()
+                    {
+                        let BrakingStateOutput {
+                            state: right_brakes,
+                        } = <BrakingStateState as grust::core::Component>::step(
+                            &mut self.braking_state,
+                            BrakingStateInput {
+                                pedest: *right_pedestrian_ref,
+                                timeout_pedest: *timeout_pedest_ref,
+                                speed: self.context.right_speed_km_h.get(),
+                                acc: self.context.acc_km_h.get(),
+                            },
+                        );
+                        self.context.right_brakes.set(right_brakes);
+                    }
+                    if self.context.right_brakes.is_new() {
+                        self.send_output(
+                            O::RightBrakes(
+                                self.context.right_brakes.get(),
+                                _grust_reserved_instant,
+                            ),
+                            _grust_reserved_instant,
+                        )
+                        .await?;
+                    }
+                } else {
+                    self.delayed = true;
+                }
+                Ok(())
+            }
+            #[inline]
+            pub async fn reset_service_delay(
+                &mut self,
+                _grust_reserved_instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.timer
+                    .send((T::DelayRightAeb, _grust_reserved_instant))
+                    .await?;
+                self.delayed = false;
+                Ok(())
+            }
+            pub async fn handle_right_speed_km_h(
+                &mut self,
+                _right_speed_km_h_instant: std::time::Instant,
+                right_speed_km_h: f64,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                if self.delayed {
+                    self.reset_time_constraints(_right_speed_km_h_instant)
+                        .await?;
+                    self.context.reset();
+                    self.context.right_speed_km_h.set(right_speed_km_h);
+                    let x = (_right_speed_km_h_instant
+                        .duration_since(self._grust_reserved_init_instant)
+                        .as_millis()) as f64;
+                    self.context.x.set(x);
+                    if self.context.right_speed_km_h.is_new() || self.context.x.is_new() {
+                        let DeriveOutput { a_km_h: acc_km_h } =
+                            <DeriveState as grust::core::Component>::step(
+                                &mut self.derive,
+                                DeriveInput {
+                                    v_km_h: right_speed_km_h,
+                                    t: x,
+                                },
+                            );
+                        self.context.acc_km_h.set(acc_km_h);
+                    }
+                    if self.context.right_speed_km_h.is_new() || self.context.acc_km_h.is_new() {
+                        let BrakingStateOutput {
+                            state: right_brakes,
+                        } = <BrakingStateState as grust::core::Component>::step(
+                            &mut self.braking_state,
+                            BrakingStateInput {
+                                pedest: None,
+                                timeout_pedest: None,
+                                speed: right_speed_km_h,
+                                acc: self.context.acc_km_h.get(),
+                            },
+                        );
+                        self.context.right_brakes.set(right_brakes);
+                    }
+                    if self.context.right_brakes.is_new() {
+                        self.send_output(
+                            O::RightBrakes(
+                                self.context.right_brakes.get(),
+                                _right_speed_km_h_instant,
+                            ),
+                            _right_speed_km_h_instant,
+                        )
+                        .await?;
+                    }
+                } else {
+                    let unique = self
+                        .input_store
+                        .right_speed_km_h
+                        .replace((right_speed_km_h, _right_speed_km_h_instant));
+                    assert ! (unique . is_none () , "flow `right_speed_km_h` changes twice within one minimal delay of the service, consider reducing this delay");
+                }
+                Ok(())
+            }
+            pub async fn handle_timeout_right_aeb(
+                &mut self,
+                _timeout_right_aeb_instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.reset_time_constraints(_timeout_right_aeb_instant)
+                    .await?;
+                self.context.reset();
+                self.send_output(
+                    O::LeftBrakes(self.context.left_brakes.get(), _timeout_right_aeb_instant),
+                    _timeout_right_aeb_instant,
+                )
+                .await?;
+                let x = (_timeout_right_aeb_instant
+                    .duration_since(self._grust_reserved_init_instant)
+                    .as_millis()) as f64;
+                self.context.x.set(x);
+                if self.context.right_speed_km_h.is_new() || self.context.x.is_new() {
+                    let DeriveOutput { a_km_h: acc_km_h } =
+                        <DeriveState as grust::core::Component>::step(
+                            &mut self.derive,
+                            DeriveInput {
+                                v_km_h: self.context.right_speed_km_h.get(),
+                                t: x,
+                            },
+                        );
+                    self.context.acc_km_h.set(acc_km_h);
+                }
+                if self.context.right_speed_km_h.is_new() || self.context.acc_km_h.is_new() {
+                    let BrakingStateOutput {
+                        state: right_brakes,
+                    } = <BrakingStateState as grust::core::Component>::step(
+                        &mut self.braking_state,
+                        BrakingStateInput {
+                            pedest: None,
+                            timeout_pedest: None,
+                            speed: self.context.right_speed_km_h.get(),
+                            acc: self.context.acc_km_h.get(),
+                        },
+                    );
+                    self.context.right_brakes.set(right_brakes);
+                }
+                self.send_output(
+                    O::RightBrakes(self.context.right_brakes.get(), _timeout_right_aeb_instant),
+                    _timeout_right_aeb_instant,
+                )
+                .await?;
+                Ok(())
+            }
+            #[inline]
+            pub async fn reset_service_timeout(
+                &mut self,
+                _timeout_right_aeb_instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.timer
+                    .send((T::TimeoutRightAeb, _timeout_right_aeb_instant))
+                    .await?;
+                Ok(())
+            }
+            pub async fn handle_timeout_timeout_pedest_1(
+                &mut self,
+                _timeout_timeout_pedest_1_instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                if self.delayed {
+                    self.reset_time_constraints(_timeout_timeout_pedest_1_instant)
+                        .await?;
+                    self.context.reset();
+                    let timeout_pedest_ref = &mut None;
+                    *timeout_pedest_ref = Some(());
+                    self.send_timer(T::TimeoutTimeoutPedest1, _timeout_timeout_pedest_1_instant)
+                        .await?;
+                    let x = (_timeout_timeout_pedest_1_instant
+                        .duration_since(self._grust_reserved_init_instant)
+                        .as_millis()) as f64;
+                    self.context.x.set(x);
+                    if self.context.right_speed_km_h.is_new() || self.context.x.is_new() {
+                        let DeriveOutput { a_km_h: acc_km_h } =
+                            <DeriveState as grust::core::Component>::step(
+                                &mut self.derive,
+                                DeriveInput {
+                                    v_km_h: self.context.right_speed_km_h.get(),
+                                    t: x,
+                                },
+                            );
+                        self.context.acc_km_h.set(acc_km_h);
+                    }
+                    if timeout_pedest_ref.is_some()
+                        || self.context.right_speed_km_h.is_new()
+                        || self.context.acc_km_h.is_new()
+                    {
+                        let BrakingStateOutput {
+                            state: right_brakes,
+                        } = <BrakingStateState as grust::core::Component>::step(
+                            &mut self.braking_state,
+                            BrakingStateInput {
+                                pedest: None,
+                                timeout_pedest: *timeout_pedest_ref,
+                                speed: self.context.right_speed_km_h.get(),
+                                acc: self.context.acc_km_h.get(),
+                            },
+                        );
+                        self.context.right_brakes.set(right_brakes);
+                    }
+                    if self.context.right_brakes.is_new() {
+                        self.send_output(
+                            O::RightBrakes(
+                                self.context.right_brakes.get(),
+                                _timeout_timeout_pedest_1_instant,
+                            ),
+                            _timeout_timeout_pedest_1_instant,
+                        )
+                        .await?;
+                    }
+                } else {
+                    let unique = self
+                        .input_store
+                        .timeout_timeout_pedest_1
+                        .replace(((), _timeout_timeout_pedest_1_instant));
+                    assert ! (unique . is_none () , "flow `timeout_timeout_pedest_1` changes twice within one minimal delay of the service, consider reducing this delay");
+                }
+                Ok(())
+            }
+            pub async fn handle_right_pedestrian(
+                &mut self,
+                _right_pedestrian_instant: std::time::Instant,
+                right_pedestrian: f64,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                if self.delayed {
+                    self.reset_time_constraints(_right_pedestrian_instant)
+                        .await?;
+                    self.context.reset();
+                    let right_pedestrian_ref = &mut None;
+                    *right_pedestrian_ref = Some(right_pedestrian);
+                    if right_pedestrian_ref.is_some() {
+                        self.send_timer(T::TimeoutTimeoutPedest1, _right_pedestrian_instant)
+                            .await?;
+                    }
+                    let x = (_right_pedestrian_instant
+                        .duration_since(self._grust_reserved_init_instant)
+                        .as_millis()) as f64;
+                    self.context.x.set(x);
+                    if self.context.right_speed_km_h.is_new() || self.context.x.is_new() {
+                        let DeriveOutput { a_km_h: acc_km_h } =
+                            <DeriveState as grust::core::Component>::step(
+                                &mut self.derive,
+                                DeriveInput {
+                                    v_km_h: self.context.right_speed_km_h.get(),
+                                    t: x,
+                                },
+                            );
+                        self.context.acc_km_h.set(acc_km_h);
+                    }
+                    if right_pedestrian_ref.is_some()
+                        || self.context.right_speed_km_h.is_new()
+                        || self.context.acc_km_h.is_new()
+                    {
+                        let BrakingStateOutput {
+                            state: right_brakes,
+                        } = <BrakingStateState as grust::core::Component>::step(
+                            &mut self.braking_state,
+                            BrakingStateInput {
+                                pedest: *right_pedestrian_ref,
+                                timeout_pedest: None,
+                                speed: self.context.right_speed_km_h.get(),
+                                acc: self.context.acc_km_h.get(),
+                            },
+                        );
+                        self.context.right_brakes.set(right_brakes);
+                    }
+                    if self.context.right_brakes.is_new() {
+                        self.send_output(
+                            O::RightBrakes(
+                                self.context.right_brakes.get(),
+                                _right_pedestrian_instant,
+                            ),
+                            _right_pedestrian_instant,
+                        )
+                        .await?;
+                    }
+                } else {
+                    let unique = self
+                        .input_store
+                        .right_pedestrian
+                        .replace((right_pedestrian, _right_pedestrian_instant));
+                    assert ! (unique . is_none () , "flow `right_pedestrian` changes twice within one minimal delay of the service, consider reducing this delay");
+                }
+                Ok(())
+            }
+            #[inline]
+            pub async fn reset_time_constraints(
+                &mut self,
+                instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.reset_service_delay(instant).await?;
+                Ok(())
+            }
+            #[inline]
+            pub async fn send_output(
+                &mut self,
+                output: O,
+                instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.reset_service_timeout(instant).await?;
+                self.output.feed(output).await?;
+                Ok(())
+            }
+            #[inline]
+            pub async fn send_timer(
+                &mut self,
+                timer: T,
+                instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.timer.feed((timer, instant)).await?;
+                Ok(())
+            }
+        }
+    }
+}
+use grust::futures::{Stream, StreamExt};
+pub fn run(
+    _grust_reserved_init_instant: std::time::Instant,
+    input_stream: impl Stream<Item = runtime::RuntimeInput> + Send + 'static,
+    init_signals: runtime::RuntimeInit,
+) -> grust::futures::channel::mpsc::Receiver<runtime::RuntimeOutput> {
+    const TIMER_CHANNEL_SIZE: usize = 6usize + 2;
+    const TIMER_STREAM_SIZE: usize = 6usize + 2;
+    let (timers_sink, timers_stream) = grust::futures::channel::mpsc::channel(TIMER_CHANNEL_SIZE);
+    let timers_stream =
+        grust::core::timer_stream::timer_stream::<_, _, TIMER_STREAM_SIZE>(timers_stream)
+            .map(|(timer, deadline)| runtime::RuntimeInput::Timer(timer, deadline));
+    const OUTPUT_CHANNEL_SIZE: usize = 2usize;
+    let (output_sink, output_stream) = grust::futures::channel::mpsc::channel(OUTPUT_CHANNEL_SIZE);
+    const PRIO_STREAM_SIZE: usize = 5usize;
+    let prio_stream = grust::core::priority_stream::prio_stream::<_, _, PRIO_STREAM_SIZE>(
+        grust::futures::stream::select(input_stream, timers_stream),
+        runtime::RuntimeInput::order,
+    );
+    let service = runtime::Runtime::new(_grust_reserved_init_instant, output_sink, timers_sink);
+    grust::tokio::spawn(async move {
+        let result = service.run_loop(prio_stream, init_signals).await;
+        assert!(result.is_ok())
+    });
+    output_stream
+}
@@ -41,6 +41,12 @@ impl grust::core::Component for DeriveState {
             last_x: false,
         }
     }
+    fn reset(&mut self) {
+        self.last_a = 0.0f64;
+        self.last_t = 0.0f64;
+        self.last_v = 0.0f64;
+        self.last_x = false;
+    }
     fn step(&mut self, input: DeriveInput) -> DeriveOutput {
         let v = input.v_km_h / 3.6f64;
         let dt = input.t - self.last_t;
@@ -80,6 +86,9 @@ impl grust::core::Component for BrakingStateState {
             last_state: Braking::NoBrake,
         }
     }
+    fn reset(&mut self) {
+        self.last_state = Braking::NoBrake;
+    }
     fn step(&mut self, input: BrakingStateInput) -> BrakingStateOutput {
         let state = match (input.pedest, input.timeout_pedest) {
             (Some(d), _) => {
@@ -0,0 +1,81 @@
+use creusot_contracts::{ensures, logic, open, prelude, requires, DeepModel};
+# [requires (0 <= a @ && 0 <= b @)]
+# [ensures (0 <= result @)]
+# [ensures (result @ == logical :: average (a @ , b @))]
+pub fn average(a: i64, b: i64) -> i64 {
+    match (i64::saturating_add(a, b), 2i64) {
+        (0, 0) => 0,
+        (lft, 0) if lft > 0 => i64::MAX,
+        (_, 0) => i64::MIN,
+        (lft, rgt) => i64::saturating_div(lft, rgt),
+    }
+}
+# [requires (0 < n @)]
+# [ensures (0 <= result @ && result @ < n @)]
+# [ensures (result @ == logical :: ring (count @ , n @))]
+pub fn ring(count: i64, n: i64) -> i64 {
+    match (i64::wrapping_add(count, 1i64), n) {
+        (lft, 0) => lft,
+        (lft, rgt) => i64::wrapping_rem(lft, rgt),
+    }
+}
+mod logical {
+    use super::*;
+    use creusot_contracts::{logic, open, Int};
+    #[open]
+    #[logic]
+    pub fn average(a: Int, b: Int) -> Int {
+        match (
+            match (a) + (b) {
+                res => {
+                    if res < -9223372036854775808 {
+                        -9223372036854775808
+                    } else if res > 9223372036854775807 {
+                        9223372036854775807
+                    } else {
+                        res
+                    }
+                }
+            },
+            2,
+        ) {
+            (lft, 0) => {
+                if lft == 0 {
+                    0
+                } else if lft > 0 {
+                    9223372036854775807
+                } else {
+                    -9223372036854775808
+                }
+            }
+            (lft, rgt) => match lft / rgt {
+                res => {
+                    if res < -9223372036854775808 {
+                        -9223372036854775808
+                    } else if res > 9223372036854775807 {
+                        9223372036854775807
+                    } else {
+                        res
+                    }
+                }
+            },
+        }
+    }
+    #[open]
+    #[logic]
+    pub fn ring(count: Int, n: Int) -> Int {
+        match (
+            match (count) + (1) {
+                res => {
+                    ((res - (-9223372036854775808)) % 18446744073709551616 + 18446744073709551616)
+                        % 18446744073709551616
+                        + (-9223372036854775808)
+                }
+            },
+            n,
+        ) {
+            (lft, 0) => lft,
+            (lft, rgt) => lft % rgt,
+        }
+    }
+}
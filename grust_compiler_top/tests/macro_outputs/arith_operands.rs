@@ -0,0 +1,12 @@
+pub fn div_offset(a: i64, b: i64, c: i64) -> i64 {
+    (match (a, b) {
+        (_, 0) => 0,
+        (lft, rgt) => i64::wrapping_div(lft, rgt),
+    }) + c
+}
+pub fn rem_offset(a: i64, b: i64, c: i64) -> i64 {
+    c - (match (a, b) {
+        (lft, 0) => lft,
+        (lft, rgt) => i64::wrapping_rem(lft, rgt),
+    })
+}
@@ -17,6 +17,9 @@ impl grust::core::Component for DefineEventsState {
     fn init() -> DefineEventsState {
         DefineEventsState { last_z: 0i64 }
     }
+    fn reset(&mut self) {
+        self.last_z = 0i64;
+    }
     fn step(&mut self, input: DefineEventsInput) -> DefineEventsOutput {
         let (z, y, x) = match (input.a, input.b) {
             (Some(a), Some(e)) => {
@@ -0,0 +1,244 @@
+pub struct ScaleInput {
+    pub i: i64,
+}
+pub struct ScaleOutput {
+    pub o: i64,
+}
+pub struct ScaleState {}
+impl grust::core::Component for ScaleState {
+    type Input = ScaleInput;
+    type Output = ScaleOutput;
+    fn init() -> ScaleState {
+        ScaleState {}
+    }
+    fn reset(&mut self) {}
+    fn step(&mut self, input: ScaleInput) -> ScaleOutput {
+        let ((i3, i1, i2), ()) = {
+            let (i3, i1, i2) = ({ 7i64 * input.i }, { (input.i - 54i64) * 2i64 }, {
+                (input.i + 54i64) * 2i64
+            });
+            ((i3, i1, i2), ())
+        };
+        let o = (i1 + i2) + i3;
+        ScaleOutput { o }
+    }
+}
+#[cfg(test)]
+impl ScaleState {
+    fn step_seq(&mut self, input: ScaleInput) -> ScaleOutput {
+        let i1 = (input.i - 54i64) * 2i64;
+        let i2 = (input.i + 54i64) * 2i64;
+        let i3 = 7i64 * input.i;
+        let o = (i1 + i2) + i3;
+        ScaleOutput { o }
+    }
+}
+#[cfg(test)]
+#[test]
+#[allow(unused_variables, unused_parens)]
+fn scale_diff() {
+    grust::core::prop::check(
+        "scale_diff",
+        256,
+        16,
+        |input: &(i64,)| {
+            let (i,) = *input;
+            true && (-1000 <= i && i <= 1000)
+        },
+        |inputs: &[(i64,)]| -> Result<(), grust::core::prop::Failure> {
+            let mut para = <ScaleState as grust::core::Component>::init();
+            let mut seq = <ScaleState as grust::core::Component>::init();
+            for (step, input) in inputs.iter().enumerate() {
+                let (i,) = *input;
+                let ScaleOutput { o } =
+                    <ScaleState as grust::core::Component>::step(&mut para, ScaleInput { i });
+                let para_outputs = format!("{:?}", (o,));
+                let ScaleOutput { o } = seq.step_seq(ScaleInput { i });
+                let seq_outputs = format!("{:?}", (o,));
+                if para_outputs != seq_outputs {
+                    return Err(grust::core::prop::Failure::new(
+                        step,
+                        format!(
+                            "outputs `(o)` are {} in parallel but {} sequentially",
+                            para_outputs, seq_outputs
+                        ),
+                    ));
+                }
+            }
+            Ok(())
+        },
+    )
+}
+pub struct SumInput {
+    pub i: i64,
+}
+pub struct SumOutput {
+    pub s: i64,
+}
+pub struct SumState {
+    last_s: i64,
+    scale: ScaleState,
+    scale_1: ScaleState,
+}
+impl grust::core::Component for SumState {
+    type Input = SumInput;
+    type Output = SumOutput;
+    fn init() -> SumState {
+        SumState {
+            last_s: 0i64,
+            scale: <ScaleState as grust::core::Component>::init(),
+            scale_1: <ScaleState as grust::core::Component>::init(),
+        }
+    }
+    fn reset(&mut self) {
+        self.last_s = 0i64;
+        <ScaleState as grust::core::Component>::reset(&mut self.scale);
+        <ScaleState as grust::core::Component>::reset(&mut self.scale_1);
+    }
+    fn step(&mut self, input: SumInput) -> SumOutput {
+        let (a, (x, b)) = std::thread::scope(|reserved_grust_thread_scope| {
+            let reserved_grust_thread_kid_0 = reserved_grust_thread_scope.spawn(|| {
+                let ScaleOutput { o } = <ScaleState as grust::core::Component>::step(
+                    &mut self.scale,
+                    ScaleInput { i: input.i },
+                );
+                (o)
+            });
+            let reserved_grust_thread_kid_1 = reserved_grust_thread_scope.spawn(|| {
+                let x = 2i64 * input.i;
+                let b = {
+                    let ScaleOutput { o } = <ScaleState as grust::core::Component>::step(
+                        &mut self.scale_1,
+                        ScaleInput { i: x },
+                    );
+                    (o)
+                };
+                (x, b)
+            });
+            let (a, (x, b)) = (
+                {
+                    reserved_grust_thread_kid_0
+                        .join()
+                        .expect("unexpected panic in sub-thread")
+                },
+                {
+                    reserved_grust_thread_kid_1
+                        .join()
+                        .expect("unexpected panic in sub-thread")
+                },
+            );
+            (a, (x, b))
+        });
+        let s = if self.last_s > 100000i64 {
+            0i64
+        } else {
+            (self.last_s + a) + b
+        };
+        self.last_s = s;
+        SumOutput { s }
+    }
+}
+#[cfg(test)]
+impl SumState {
+    fn step_seq(&mut self, input: SumInput) -> SumOutput {
+        let a = {
+            let ScaleOutput { o } = <ScaleState as grust::core::Component>::step(
+                &mut self.scale,
+                ScaleInput { i: input.i },
+            );
+            (o)
+        };
+        let x = 2i64 * input.i;
+        let b = {
+            let ScaleOutput { o } = <ScaleState as grust::core::Component>::step(
+                &mut self.scale_1,
+                ScaleInput { i: x },
+            );
+            (o)
+        };
+        let s = if self.last_s > 100000i64 {
+            0i64
+        } else {
+            (self.last_s + a) + b
+        };
+        self.last_s = s;
+        SumOutput { s }
+    }
+}
+#[cfg(test)]
+#[test]
+#[allow(unused_variables, unused_parens)]
+fn sum_diff() {
+    grust::core::prop::check(
+        "sum_diff",
+        256,
+        16,
+        |input: &(i64,)| {
+            let (i,) = *input;
+            true && (-1000 <= i && i <= 1000)
+        },
+        |inputs: &[(i64,)]| -> Result<(), grust::core::prop::Failure> {
+            let mut para = <SumState as grust::core::Component>::init();
+            let mut seq = <SumState as grust::core::Component>::init();
+            for (step, input) in inputs.iter().enumerate() {
+                let (i,) = *input;
+                let SumOutput { s } =
+                    <SumState as grust::core::Component>::step(&mut para, SumInput { i });
+                let para_outputs = format!("{:?}", (s,));
+                let SumOutput { s } = seq.step_seq(SumInput { i });
+                let seq_outputs = format!("{:?}", (s,));
+                if para_outputs != seq_outputs {
+                    return Err(grust::core::prop::Failure::new(
+                        step,
+                        format!(
+                            "outputs `(s)` are {} in parallel but {} sequentially",
+                            para_outputs, seq_outputs
+                        ),
+                    ));
+                }
+            }
+            Ok(())
+        },
+    )
+}
+#[cfg(test)]
+#[test]
+#[allow(unused_variables)]
+fn sum_nominal() {
+    let mut state = <SumState as grust::core::Component>::init();
+    let mut seq_state = <SumState as grust::core::Component>::init();
+    {
+        let input = SumInput { i: 1i64 };
+        let inputs = format!("i = {:?}", input.i);
+        let output = <SumState as grust::core::Component>::step(&mut state, input);
+        let expected = 33i64;
+        assert!(
+            output.s == expected,
+            "test `sum_nominal` failed at step 0: `s` is {:?}, expected {:?}\n  inputs: {}",
+            output.s,
+            expected,
+            inputs
+        );
+        let seq_output = seq_state.step_seq(SumInput { i: 1i64 });
+        let para_outputs = format!("{:?}", (output.s,));
+        let seq_outputs = format!("{:?}", (seq_output.s,));
+        assert ! (para_outputs == seq_outputs , "test `sum_nominal` failed at step 0: outputs `(s)` are {} in parallel but {} sequentially" , para_outputs , seq_outputs);
+    }
+    {
+        let input = SumInput { i: 2i64 };
+        let inputs = format!("i = {:?}", input.i);
+        let output = <SumState as grust::core::Component>::step(&mut state, input);
+        let expected = 99i64;
+        assert!(
+            output.s == expected,
+            "test `sum_nominal` failed at step 1: `s` is {:?}, expected {:?}\n  inputs: {}",
+            output.s,
+            expected,
+            inputs
+        );
+        let seq_output = seq_state.step_seq(SumInput { i: 2i64 });
+        let para_outputs = format!("{:?}", (output.s,));
+        let seq_outputs = format!("{:?}", (seq_output.s,));
+        assert ! (para_outputs == seq_outputs , "test `sum_nominal` failed at step 1: outputs `(s)` are {} in parallel but {} sequentially" , para_outputs , seq_outputs);
+    }
+}
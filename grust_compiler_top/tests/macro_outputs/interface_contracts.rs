@@ -0,0 +1,564 @@
+const _: fn(f64) -> f64 = grust::std::maths::usuals::abs;
+pub fn valid(d: f64) -> bool {
+    grust::std::maths::usuals::abs(d) <= 100.0f64
+}
+pub struct BrakingInput {
+    pub pedestrian: Option<f64>,
+    pub speed: f64,
+}
+pub struct BrakingOutput {
+    pub brakes: bool,
+}
+pub struct BrakingState {
+    last_brakes: bool,
+}
+impl grust::core::Component for BrakingState {
+    type Input = BrakingInput;
+    type Output = BrakingOutput;
+    fn init() -> BrakingState {
+        BrakingState { last_brakes: false }
+    }
+    fn reset(&mut self) {
+        self.last_brakes = false;
+    }
+    fn step(&mut self, input: BrakingInput) -> BrakingOutput {
+        let brakes = match (input.pedestrian) {
+            (Some(d)) => d < input.speed,
+            (_) => {
+                let brakes = self.last_brakes;
+                brakes
+            }
+        };
+        self.last_brakes = brakes;
+        BrakingOutput { brakes }
+    }
+}
+pub mod runtime {
+    use super::*;
+    use grust::futures::{sink::SinkExt, stream::StreamExt};
+    #[derive(Debug)]
+    pub enum RuntimeInput {
+        Speed(f64, std::time::Instant),
+        Pedestrian(f64, std::time::Instant),
+        Timer(T, std::time::Instant),
+    }
+    use RuntimeInput as I;
+    impl grust::core::priority_stream::Reset for RuntimeInput {
+        fn do_reset(&self) -> bool {
+            match self {
+                I::Timer(timer, _) => grust::core::timer_stream::Timing::do_reset(timer),
+                _ => false,
+            }
+        }
+    }
+    impl PartialEq for RuntimeInput {
+        fn eq(&self, other: &Self) -> bool {
+            match (self, other) {
+                (I::Speed(this, _), I::Speed(other, _)) => this.eq(other),
+                (I::Pedestrian(this, _), I::Pedestrian(other, _)) => this.eq(other),
+                (I::Timer(this, _), I::Timer(other, _)) => this.eq(other),
+                _ => false,
+            }
+        }
+    }
+    impl RuntimeInput {
+        pub fn get_instant(&self) -> std::time::Instant {
+            match self {
+                I::Speed(_, _grust_reserved_instant) => *_grust_reserved_instant,
+                I::Pedestrian(_, _grust_reserved_instant) => *_grust_reserved_instant,
+                I::Timer(_, _grust_reserved_instant) => *_grust_reserved_instant,
+            }
+        }
+        pub fn order(v1: &Self, v2: &Self) -> std::cmp::Ordering {
+            v1.get_instant().cmp(&v2.get_instant())
+        }
+    }
+    #[derive(Debug, PartialEq)]
+    pub enum RuntimeOutput {
+        Gap(f64, std::time::Instant),
+        Alert(f64, std::time::Instant),
+        Brakes(bool, std::time::Instant),
+        ContractViolation(&'static str, std::time::Instant),
+    }
+    use RuntimeOutput as O;
+    #[derive(Debug, Default)]
+    pub struct RuntimeInit {
+        pub speed: f64,
+    }
+    #[derive(Debug, PartialEq)]
+    pub enum RuntimeTimer {
+        ResponseAeb,
+    }
+    use RuntimeTimer as T;
+    impl grust::core::timer_stream::Timing for RuntimeTimer {
+        fn get_duration(&self) -> std::time::Duration {
+            match self {
+                T::ResponseAeb => std::time::Duration::from_millis(50u64),
+            }
+        }
+        fn do_reset(&self) -> bool {
+            match self {
+                T::ResponseAeb => true,
+            }
+        }
+    }
+    pub struct Runtime {
+        _grust_reserved_init_instant: std::time::Instant,
+        aeb: aeb_service::AebService,
+        pedestrian_last_instant: Option<std::time::Instant>,
+        output: grust::futures::channel::mpsc::Sender<O>,
+        timer: grust::futures::channel::mpsc::Sender<(T, std::time::Instant)>,
+    }
+    impl Runtime {
+        pub fn new(
+            _grust_reserved_init_instant: std::time::Instant,
+            output: grust::futures::channel::mpsc::Sender<O>,
+            timer: grust::futures::channel::mpsc::Sender<(T, std::time::Instant)>,
+        ) -> Runtime {
+            let aeb = aeb_service::AebService::init(
+                _grust_reserved_init_instant,
+                output.clone(),
+                timer.clone(),
+            );
+            Runtime {
+                _grust_reserved_init_instant,
+                aeb,
+                pedestrian_last_instant: None,
+                output,
+                timer,
+            }
+        }
+        #[inline]
+        pub async fn send_timer(
+            &mut self,
+            timer: T,
+            instant: std::time::Instant,
+        ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+            self.timer.send((timer, instant)).await?;
+            Ok(())
+        }
+        pub async fn run_loop(
+            self,
+            input: impl grust::futures::Stream<Item = I>,
+            init_vals: RuntimeInit,
+        ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+            grust::futures::pin_mut!(input);
+            let mut runtime = self;
+            let RuntimeInit { speed } = init_vals;
+            runtime.aeb.handle_init(speed).await?;
+            while let Some(input) = input.next().await {
+                match input {
+                    I::Pedestrian(pedestrian, _grust_reserved_instant) => {
+                        if runtime.pedestrian_last_instant.is_some_and(|last| {
+                            _grust_reserved_instant.duration_since(last)
+                                < std::time::Duration::from_millis(100u64)
+                        }) {
+                            runtime
+                                .output
+                                .feed(O::ContractViolation(
+                                    "`pedestrian` requires `every 100 ms`",
+                                    _grust_reserved_instant,
+                                ))
+                                .await?;
+                        }
+                        runtime.pedestrian_last_instant = Some(_grust_reserved_instant);
+                        runtime
+                            .aeb
+                            .handle_pedestrian(_grust_reserved_instant, pedestrian)
+                            .await?;
+                    }
+                    I::Timer(T::ResponseAeb, _grust_reserved_instant) => {
+                        runtime
+                            .aeb
+                            .handle_response_aeb(_grust_reserved_instant)
+                            .await?;
+                    }
+                    I::Speed(speed, _grust_reserved_instant) => {
+                        if !(0.0 <= speed && speed <= 60.0) {
+                            runtime
+                                .output
+                                .feed(O::ContractViolation(
+                                    "`speed` requires `0.0 <= speed && speed <= 60.0`",
+                                    _grust_reserved_instant,
+                                ))
+                                .await?;
+                        }
+                        runtime
+                            .aeb
+                            .handle_speed(_grust_reserved_instant, speed)
+                            .await?;
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+    pub mod aeb_service {
+        use super::*;
+        use grust::futures::{sink::SinkExt, stream::StreamExt};
+        mod ctx_ty {
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct Brakes(bool, bool);
+            impl Brakes {
+                pub fn set(&mut self, brakes: bool) {
+                    self.1 = self.0 != brakes;
+                    self.0 = brakes;
+                }
+                pub fn get(&self) -> bool {
+                    self.0
+                }
+                pub fn take(&mut self) -> bool {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct Gap(f64, bool);
+            impl Gap {
+                pub fn set(&mut self, gap: f64) {
+                    self.1 = self.0 != gap;
+                    self.0 = gap;
+                }
+                pub fn get(&self) -> f64 {
+                    self.0
+                }
+                pub fn take(&mut self) -> f64 {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+            #[derive(Clone, Copy, PartialEq, Default, Debug)]
+            pub struct Speed(f64, bool);
+            impl Speed {
+                pub fn set(&mut self, speed: f64) {
+                    self.1 = self.0 != speed;
+                    self.0 = speed;
+                }
+                pub fn get(&self) -> f64 {
+                    self.0
+                }
+                pub fn take(&mut self) -> f64 {
+                    std::mem::take(&mut self.0)
+                }
+                pub fn is_new(&self) -> bool {
+                    self.1
+                }
+                pub fn reset(&mut self) {
+                    self.1 = false;
+                }
+            }
+        }
+        #[derive(Clone, Copy, PartialEq, Default, Debug)]
+        pub struct Context {
+            pub brakes: ctx_ty::Brakes,
+            pub gap: ctx_ty::Gap,
+            pub speed: ctx_ty::Speed,
+        }
+        impl Context {
+            fn init() -> Context {
+                Default::default()
+            }
+            fn reset(&mut self) {
+                self.brakes.reset();
+                self.gap.reset();
+                self.speed.reset();
+            }
+        }
+        #[derive(Default)]
+        pub struct AebServiceStore {
+            speed: Option<(f64, std::time::Instant)>,
+            pedestrian: Option<(f64, std::time::Instant)>,
+        }
+        impl AebServiceStore {
+            pub fn not_empty(&self) -> bool {
+                self.speed.is_some() || self.pedestrian.is_some()
+            }
+        }
+        pub struct AebService {
+            _grust_reserved_init_instant: std::time::Instant,
+            context: Context,
+            delayed: bool,
+            input_store: AebServiceStore,
+            braking: BrakingState,
+            alert_last_instant: Option<std::time::Instant>,
+            response_0_instant: Option<std::time::Instant>,
+            output: grust::futures::channel::mpsc::Sender<O>,
+            timer: grust::futures::channel::mpsc::Sender<(T, std::time::Instant)>,
+        }
+        impl AebService {
+            pub fn init(
+                _grust_reserved_init_instant: std::time::Instant,
+                output: grust::futures::channel::mpsc::Sender<O>,
+                timer: grust::futures::channel::mpsc::Sender<(T, std::time::Instant)>,
+            ) -> AebService {
+                let context = Context::init();
+                let delayed = true;
+                let input_store = Default::default();
+                let braking = <BrakingState as grust::core::Component>::init();
+                AebService {
+                    _grust_reserved_init_instant,
+                    context,
+                    delayed,
+                    input_store,
+                    braking,
+                    alert_last_instant: None,
+                    response_0_instant: None,
+                    output,
+                    timer,
+                }
+            }
+            pub async fn handle_init(
+                &mut self,
+                speed: f64,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                let _grust_reserved_instant = self._grust_reserved_init_instant;
+                self.context.speed.set(speed);
+                let gap = speed;
+                self.context.gap.set(gap);
+                self.send_output(
+                    O::Gap(gap, _grust_reserved_instant),
+                    _grust_reserved_instant,
+                )
+                .await?;
+                let BrakingOutput { brakes: brakes } =
+                    <BrakingState as grust::core::Component>::step(
+                        &mut self.braking,
+                        BrakingInput {
+                            pedestrian: None,
+                            speed: speed,
+                        },
+                    );
+                self.context.brakes.set(brakes);
+                self.send_output(
+                    O::Brakes(self.context.brakes.get(), _grust_reserved_instant),
+                    _grust_reserved_instant,
+                )
+                .await?;
+                Ok(())
+            }
+            pub async fn handle_speed(
+                &mut self,
+                _speed_instant: std::time::Instant,
+                speed: f64,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                if self.delayed {
+                    self.reset_time_constraints(_speed_instant).await?;
+                    self.context.reset();
+                    self.context.speed.set(speed);
+                    let gap = speed;
+                    self.context.gap.set(gap);
+                    if self.context.gap.is_new() {
+                        self.send_output(O::Gap(gap, _speed_instant), _speed_instant)
+                            .await?;
+                    }
+                    if self.context.speed.is_new() {
+                        let BrakingOutput { brakes: brakes } =
+                            <BrakingState as grust::core::Component>::step(
+                                &mut self.braking,
+                                BrakingInput {
+                                    pedestrian: None,
+                                    speed: speed,
+                                },
+                            );
+                        self.context.brakes.set(brakes);
+                    }
+                    if self.context.brakes.is_new() {
+                        self.send_output(
+                            O::Brakes(self.context.brakes.get(), _speed_instant),
+                            _speed_instant,
+                        )
+                        .await?;
+                    }
+                } else {
+                    let unique = self.input_store.speed.replace((speed, _speed_instant));
+                    assert ! (unique . is_none () , "flow `speed` changes twice within one minimal delay of the service, consider reducing this delay");
+                }
+                Ok(())
+            }
+            pub async fn handle_pedestrian(
+                &mut self,
+                _pedestrian_instant: std::time::Instant,
+                pedestrian: f64,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                if self.response_0_instant.is_none() {
+                    self.response_0_instant = Some(_pedestrian_instant);
+                    self.send_timer(T::ResponseAeb, _pedestrian_instant).await?;
+                }
+                if self.delayed {
+                    self.reset_time_constraints(_pedestrian_instant).await?;
+                    self.context.reset();
+                    let pedestrian_ref = &mut None;
+                    let alert_ref = &mut None;
+                    *pedestrian_ref = Some(pedestrian);
+                    if pedestrian_ref.is_some() || self.context.speed.is_new() {
+                        let BrakingOutput { brakes: brakes } =
+                            <BrakingState as grust::core::Component>::step(
+                                &mut self.braking,
+                                BrakingInput {
+                                    pedestrian: *pedestrian_ref,
+                                    speed: self.context.speed.get(),
+                                },
+                            );
+                        self.context.brakes.set(brakes);
+                    }
+                    if self.context.brakes.is_new() {
+                        self.send_output(
+                            O::Brakes(self.context.brakes.get(), _pedestrian_instant),
+                            _pedestrian_instant,
+                        )
+                        .await?;
+                    }
+                    *alert_ref = *pedestrian_ref;
+                    if let Some(alert) = *alert_ref {
+                        self.send_output(O::Alert(alert, _pedestrian_instant), _pedestrian_instant)
+                            .await?;
+                    }
+                    if let Some(start) = self.response_0_instant.take() {
+                        if _pedestrian_instant.duration_since(start)
+                            > std::time::Duration::from_millis(50u64)
+                        {
+                            self.output
+                                .feed(O::ContractViolation(
+                                    "`aeb` ensures `brakes within 50 ms after pedestrian`",
+                                    _pedestrian_instant,
+                                ))
+                                .await?;
+                        }
+                    }
+                } else {
+                    let unique = self
+                        .input_store
+                        .pedestrian
+                        .replace((pedestrian, _pedestrian_instant));
+                    assert ! (unique . is_none () , "flow `pedestrian` changes twice within one minimal delay of the service, consider reducing this delay");
+                }
+                Ok(())
+            }
+            #[inline]
+            pub async fn reset_time_constraints(
+                &mut self,
+                instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                Ok(())
+            }
+            #[inline]
+            pub async fn send_output(
+                &mut self,
+                output: O,
+                instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                if let O::Gap(gap, _) = &output {
+                    let gap = *gap;
+                    if !(valid(gap)) {
+                        self.output
+                            .feed(O::ContractViolation("`gap` ensures `valid (gap)`", instant))
+                            .await?;
+                    }
+                    if !(grust::std::maths::usuals::abs(gap) >= 0.0) {
+                        self . output . feed (O :: ContractViolation ("`gap` ensures `grust :: std :: maths :: usuals :: abs (gap) >= 0.0`" , instant)) . await ? ;
+                    }
+                }
+                if let O::Alert(alert, _) = &output {
+                    let alert = *alert;
+                    if !(alert >= 0.0) {
+                        self.output
+                            .feed(O::ContractViolation(
+                                "`alert` ensures `alert >= 0.0`",
+                                instant,
+                            ))
+                            .await?;
+                    }
+                    if self.alert_last_instant.is_some_and(|last| {
+                        instant.duration_since(last) < std::time::Duration::from_millis(20u64)
+                    }) {
+                        self.output
+                            .feed(O::ContractViolation(
+                                "`alert` ensures `every 20 ms`",
+                                instant,
+                            ))
+                            .await?;
+                    }
+                    self.alert_last_instant = Some(instant);
+                }
+                if let O::Brakes(..) = &output {
+                    if let Some(start) = self.response_0_instant.take() {
+                        if instant.duration_since(start) > std::time::Duration::from_millis(50u64) {
+                            self.output
+                                .feed(O::ContractViolation(
+                                    "`aeb` ensures `brakes within 50 ms after pedestrian`",
+                                    instant,
+                                ))
+                                .await?;
+                        }
+                    }
+                }
+                self.output.feed(output).await?;
+                Ok(())
+            }
+            pub async fn handle_response_aeb(
+                &mut self,
+                instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                if self.response_0_instant.is_some_and(|start| {
+                    instant.duration_since(start) >= std::time::Duration::from_millis(50u64)
+                }) {
+                    self.response_0_instant = None;
+                    self.output
+                        .feed(O::ContractViolation(
+                            "`aeb` ensures `brakes within 50 ms after pedestrian`",
+                            instant,
+                        ))
+                        .await?;
+                }
+                Ok(())
+            }
+            #[inline]
+            pub async fn send_timer(
+                &mut self,
+                timer: T,
+                instant: std::time::Instant,
+            ) -> Result<(), grust::futures::channel::mpsc::SendError> {
+                self.timer.feed((timer, instant)).await?;
+                Ok(())
+            }
+        }
+    }
+}
+use grust::futures::{Stream, StreamExt};
+pub fn run(
+    _grust_reserved_init_instant: std::time::Instant,
+    input_stream: impl Stream<Item = runtime::RuntimeInput> + Send + 'static,
+    init_signals: runtime::RuntimeInit,
+) -> grust::futures::channel::mpsc::Receiver<runtime::RuntimeOutput> {
+    const TIMER_CHANNEL_SIZE: usize = 1usize + 2;
+    let (timers_sink, timers_stream) = grust::futures::channel::mpsc::channel(TIMER_CHANNEL_SIZE);
+    let timers_stream = timers_stream.map(
+        |(timer, instant): (runtime::RuntimeTimer, std::time::Instant)| {
+            let deadline = instant + grust::core::timer_stream::Timing::get_duration(&timer);
+            runtime::RuntimeInput::Timer(timer, deadline)
+        },
+    );
+    const OUTPUT_CHANNEL_SIZE: usize = 4usize;
+    let (output_sink, output_stream) = grust::futures::channel::mpsc::channel(OUTPUT_CHANNEL_SIZE);
+    const PRIO_STREAM_SIZE: usize = 100usize;
+    let prio_stream = grust::core::priority_stream::prio_stream::<_, _, PRIO_STREAM_SIZE>(
+        grust::futures::stream::select(input_stream, timers_stream),
+        runtime::RuntimeInput::order,
+    );
+    let service = runtime::Runtime::new(_grust_reserved_init_instant, output_sink, timers_sink);
+    grust::tokio::spawn(async move {
+        let result = service.run_loop(prio_stream, init_signals).await;
+        assert!(result.is_ok())
+    });
+    output_stream
+}
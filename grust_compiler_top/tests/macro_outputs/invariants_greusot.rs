@@ -0,0 +1,78 @@
+use creusot_contracts::{ensures, logic, open, prelude, requires, DeepModel};
+#[derive(prelude :: Clone, Copy, prelude :: PartialEq, DeepModel)]
+pub enum Mode {
+    Idle,
+    Counting,
+    Done,
+}
+pub struct CounterInput {
+    pub start: bool,
+    pub tick: bool,
+}
+pub struct CounterOutput {
+    pub count: i64,
+}
+pub struct CounterState {
+    last_count: i64,
+    last_next_mode: Mode,
+}
+impl grust::core::Component for CounterState {
+    type Input = CounterInput;
+    type Output = CounterOutput;
+    # [ensures (0 <= result . last_count @ && result . last_count @ <= 10)]
+    # [ensures (result . last_next_mode == Mode :: Idle == > (result . last_count @ == 0))]
+    fn init() -> CounterState {
+        CounterState {
+            last_count: 0i64,
+            last_next_mode: Mode::Idle,
+        }
+    }
+    fn reset(&mut self) {
+        self.last_count = 0i64;
+        self.last_next_mode = Mode::Idle;
+    }
+    # [ensures (result . count @ <= 10)]
+    # [requires (0 <= self . last_count @ && self . last_count @ <= 10)]
+    # [ensures (0 <= (^ self) . last_count @ && (^ self) . last_count @ <= 10)]
+    # [requires (self . last_next_mode == Mode :: Idle == > (self . last_count @ == 0))]
+    # [ensures ((^ self) . last_next_mode == Mode :: Idle == > ((^ self) . last_count @ == 0))]
+    fn step(&mut self, input: CounterInput) -> CounterOutput {
+        let mode = self.last_next_mode;
+        let (count, next_mode) = match mode {
+            Mode::Idle => {
+                let next_mode = if input.start { Mode::Counting } else { mode };
+                let count = 0i64;
+                (count, next_mode)
+            }
+            Mode::Counting => {
+                let next_mode = if self.last_count >= 9i64 {
+                    Mode::Done
+                } else {
+                    mode
+                };
+                let count = if self.last_count >= 9i64 {
+                    10i64
+                } else {
+                    if input.tick {
+                        self.last_count + 1i64
+                    } else {
+                        self.last_count
+                    }
+                };
+                (count, next_mode)
+            }
+            Mode::Done => {
+                let next_mode = mode;
+                let count = 10i64;
+                (count, next_mode)
+            }
+        };
+        self.last_count = count;
+        self.last_next_mode = next_mode;
+        CounterOutput { count }
+    }
+}
+mod logical {
+    use super::*;
+    use creusot_contracts::{logic, open, Int};
+}
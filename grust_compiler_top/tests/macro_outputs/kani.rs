@@ -0,0 +1,132 @@
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+#[cfg_attr(kani, derive(kani::Arbitrary))]
+pub enum Gear {
+    #[default]
+    Park,
+    Drive,
+    Reverse,
+}
+pub fn clamp(x: i64, lo: i64, hi: i64) -> i64 {
+    if x < lo {
+        lo
+    } else {
+        if x > hi {
+            hi
+        } else {
+            x
+        }
+    }
+}
+pub struct CounterInput {
+    pub tick: bool,
+    pub gear: Gear,
+}
+pub struct CounterOutput {
+    pub count: i64,
+}
+pub struct CounterState {
+    last_count: i64,
+}
+impl grust::core::Component for CounterState {
+    type Input = CounterInput;
+    type Output = CounterOutput;
+    fn init() -> CounterState {
+        CounterState { last_count: 0i64 }
+    }
+    fn reset(&mut self) {
+        self.last_count = 0i64;
+    }
+    fn step(&mut self, input: CounterInput) -> CounterOutput {
+        let count = if input.tick && (input.gear == Gear::Drive) {
+            clamp(self.last_count + 1i64, 0i64, 100i64)
+        } else {
+            self.last_count
+        };
+        self.last_count = count;
+        CounterOutput { count }
+    }
+}
+#[cfg(kani)]
+#[kani::proof]
+#[allow(unused_variables)]
+fn counter_proof() {
+    let mut state = <CounterState as grust::core::Component>::init();
+    {
+        let tick: bool = kani::any();
+        let gear: Gear = kani::any();
+        let last_count = state.last_count;
+        let CounterOutput { count } =
+            <CounterState as grust::core::Component>::step(&mut state, CounterInput { tick, gear });
+        kani::assert(
+            0 <= count && count <= 100,
+            "`counter` ensures clause #1 violated",
+        );
+        kani::assert(last_count <= count, "`counter` ensures clause #2 violated");
+        kani::assert(
+            0 <= state.last_count && state.last_count <= 100,
+            "`counter` invariant clause #1 violated",
+        );
+    }
+    {
+        let tick: bool = kani::any();
+        let gear: Gear = kani::any();
+        let last_count = state.last_count;
+        let CounterOutput { count } =
+            <CounterState as grust::core::Component>::step(&mut state, CounterInput { tick, gear });
+        kani::assert(
+            0 <= count && count <= 100,
+            "`counter` ensures clause #1 violated",
+        );
+        kani::assert(last_count <= count, "`counter` ensures clause #2 violated");
+        kani::assert(
+            0 <= state.last_count && state.last_count <= 100,
+            "`counter` invariant clause #1 violated",
+        );
+    }
+}
+pub struct BrakesInput {
+    pub d: i64,
+    pub v: i64,
+}
+pub struct BrakesOutput {
+    pub b: i64,
+}
+pub struct BrakesState {}
+impl grust::core::Component for BrakesState {
+    type Input = BrakesInput;
+    type Output = BrakesOutput;
+    fn init() -> BrakesState {
+        BrakesState {}
+    }
+    fn reset(&mut self) {}
+    fn step(&mut self, input: BrakesInput) -> BrakesOutput {
+        let b = clamp((input.v * input.v) / (2i64 * input.d), 0i64, 6i64);
+        BrakesOutput { b }
+    }
+}
+#[cfg(kani)]
+#[kani::proof]
+#[allow(unused_variables)]
+fn brakes_proof() {
+    let mut state = <BrakesState as grust::core::Component>::init();
+    {
+        let d: i64 = kani::any();
+        let v: i64 = kani::any();
+        kani::assume(0 < d && d < 150);
+        kani::assume(v < 0 && -grust::core::wide::Widen::widen(v) <= 10);
+        let BrakesOutput { b } =
+            <BrakesState as grust::core::Component>::step(&mut state, BrakesInput { d, v });
+        kani::assert(0 <= b && b <= 6, "`brakes` ensures clause #1 violated");
+        kani::assert(!(d > 50) || (b <= 1), "`brakes` ensures clause #2 violated");
+    }
+    {
+        let d: i64 = kani::any();
+        let v: i64 = kani::any();
+        kani::assume(0 < d && d < 150);
+        kani::assume(v < 0 && -grust::core::wide::Widen::widen(v) <= 10);
+        let BrakesOutput { b } =
+            <BrakesState as grust::core::Component>::step(&mut state, BrakesInput { d, v });
+        kani::assert(0 <= b && b <= 6, "`brakes` ensures clause #1 violated");
+        kani::assert(!(d > 50) || (b <= 1), "`brakes` ensures clause #2 violated");
+    }
+}
@@ -22,6 +22,11 @@ impl grust::core::Component for MultipleEventsState {
             last_z: 0i64,
         }
     }
+    fn reset(&mut self) {
+        self.last_a_bis = 0i64;
+        self.last_d = 0i64;
+        self.last_z = 0i64;
+    }
     fn step(&mut self, input: MultipleEventsInput) -> MultipleEventsOutput {
         let (a_bis, z) = match (input.a, input.b) {
             (Some(a), Some(b)) if input.v > 50i64 => {
@@ -0,0 +1,174 @@
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub enum Braking {
+    #[default]
+    NoBrake,
+    SoftBrake,
+    UrgentBrake,
+}
+pub struct BrakingStateInput {
+    pub pedest: Option<f64>,
+    pub timeout_pedestrian: Option<()>,
+    pub speed: f64,
+}
+pub struct BrakingStateOutput {
+    pub state: Braking,
+}
+pub struct BrakingStateState {
+    last_state: Braking,
+}
+impl grust::core::Component for BrakingStateState {
+    type Input = BrakingStateInput;
+    type Output = BrakingStateOutput;
+    fn init() -> BrakingStateState {
+        BrakingStateState {
+            last_state: Braking::NoBrake,
+        }
+    }
+    fn reset(&mut self) {
+        self.last_state = Braking::NoBrake;
+    }
+    fn step(&mut self, input: BrakingStateInput) -> BrakingStateOutput {
+        let state = match (input.pedest, input.timeout_pedestrian) {
+            (Some(d), _) => {
+                let state = if ((input.speed * input.speed) / 100.0f64) < d {
+                    Braking::SoftBrake
+                } else {
+                    Braking::UrgentBrake
+                };
+                state
+            }
+            (_, Some(_)) if self.last_state == Braking::UrgentBrake => {
+                let state = Braking::SoftBrake;
+                state
+            }
+            (_, Some(_)) => {
+                let state = Braking::NoBrake;
+                state
+            }
+            (_, _) => {
+                let state = self.last_state;
+                state
+            }
+        };
+        self.last_state = state;
+        BrakingStateOutput { state }
+    }
+}
+pub struct HoldInput {
+    pub x: i64,
+}
+pub struct HoldOutput {
+    pub y: i64,
+    pub z: i64,
+}
+pub struct HoldState {
+    last_y: i64,
+    last_z: i64,
+}
+impl grust::core::Component for HoldState {
+    type Input = HoldInput;
+    type Output = HoldOutput;
+    fn init() -> HoldState {
+        HoldState {
+            last_y: 0i64,
+            last_z: 0i64,
+        }
+    }
+    fn reset(&mut self) {
+        self.last_y = 0i64;
+        self.last_z = 0i64;
+    }
+    fn step(&mut self, input: HoldInput) -> HoldOutput {
+        let y = self.last_z;
+        let z = if input.x > 0i64 { input.x } else { self.last_z };
+        self.last_y = y;
+        self.last_z = z;
+        HoldOutput { y, z }
+    }
+}
+pub const ACTIVE_MUTANT: Option<&str> = option_env!("GRUST_MUTANT");
+pub fn mutants() -> &'static [grust::core::mutation::Mutant] {
+    static MUTANTS: [grust::core::mutation::Mutant; 10] = [
+        grust::core::mutation::Mutant {
+            id: "braking_state#0",
+            owner: "braking_state",
+            description: "negate the guard of an arm",
+            file: file!(),
+            line: line!(),
+            column: column!(),
+        },
+        grust::core::mutation::Mutant {
+            id: "braking_state#1",
+            owner: "braking_state",
+            description: "replace `<` with `>=`",
+            file: file!(),
+            line: line!(),
+            column: column!(),
+        },
+        grust::core::mutation::Mutant {
+            id: "braking_state#2",
+            owner: "braking_state",
+            description: "replace `Braking::SoftBrake` with `Braking::UrgentBrake`",
+            file: file!(),
+            line: line!(),
+            column: column!(),
+        },
+        grust::core::mutation::Mutant {
+            id: "braking_state#3",
+            owner: "braking_state",
+            description: "replace `Braking::UrgentBrake` with `Braking::NoBrake`",
+            file: file!(),
+            line: line!(),
+            column: column!(),
+        },
+        grust::core::mutation::Mutant {
+            id: "braking_state#4",
+            owner: "braking_state",
+            description: "replace `==` with `!=`",
+            file: file!(),
+            line: line!(),
+            column: column!(),
+        },
+        grust::core::mutation::Mutant {
+            id: "braking_state#5",
+            owner: "braking_state",
+            description: "replace `Braking::UrgentBrake` with `Braking::NoBrake`",
+            file: file!(),
+            line: line!(),
+            column: column!(),
+        },
+        grust::core::mutation::Mutant {
+            id: "braking_state#6",
+            owner: "braking_state",
+            description: "replace `Braking::SoftBrake` with `Braking::UrgentBrake`",
+            file: file!(),
+            line: line!(),
+            column: column!(),
+        },
+        grust::core::mutation::Mutant {
+            id: "braking_state#7",
+            owner: "braking_state",
+            description: "replace `Braking::NoBrake` with `Braking::SoftBrake`",
+            file: file!(),
+            line: line!(),
+            column: column!(),
+        },
+        grust::core::mutation::Mutant {
+            id: "hold#0",
+            owner: "hold",
+            description: "replace `last z` with `z`",
+            file: file!(),
+            line: line!(),
+            column: column!(),
+        },
+        grust::core::mutation::Mutant {
+            id: "hold#1",
+            owner: "hold",
+            description: "replace `>` with `<=`",
+            file: file!(),
+            line: line!(),
+            column: column!(),
+        },
+    ];
+    &MUTANTS
+}
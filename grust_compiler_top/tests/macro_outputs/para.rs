@@ -14,6 +14,9 @@ impl grust::core::Component for C1State {
     fn init() -> C1State {
         C1State { last_s2: 0i64 }
     }
+    fn reset(&mut self) {
+        self.last_s2 = 0i64;
+    }
     fn step(&mut self, input: C1Input) -> C1Output {
         let prev_s2 = self.last_s2;
         let (s2, e1) = match (input.e0) {
@@ -55,6 +58,10 @@ impl grust::core::Component for C2State {
             last_x: false,
         }
     }
+    fn reset(&mut self) {
+        self.last_s3 = 0i64;
+        self.last_x = false;
+    }
     fn step(&mut self, input: C2Input) -> C2Output {
         let prev_s3 = self.last_s3;
         let x = prev_s3 > 0i64;
@@ -93,6 +100,9 @@ impl grust::core::Component for C3State {
     fn init() -> C3State {
         C3State { last_x: false }
     }
+    fn reset(&mut self) {
+        self.last_x = false;
+    }
     fn step(&mut self, input: C3Input) -> C3Output {
         let x = input.s2 > 1i64;
         let e2 = match () {
@@ -118,6 +128,9 @@ impl grust::core::Component for C4State {
     fn init() -> C4State {
         C4State { last_s4: 0i64 }
     }
+    fn reset(&mut self) {
+        self.last_s4 = 0i64;
+    }
     fn step(&mut self, input: C4Input) -> C4Output {
         let s4 = match (input.e2) {
             (Some(e2)) => e2,
@@ -153,6 +166,11 @@ impl grust::core::Component for C5State {
             last_x_1: false,
         }
     }
+    fn reset(&mut self) {
+        self.last_o = 0i64;
+        self.last_x = false;
+        self.last_x_1 = false;
+    }
     fn step(&mut self, input: C5Input) -> C5Output {
         let x = input.s4 <= 0i64;
         let x_1 = input.s3 >= 0i64;
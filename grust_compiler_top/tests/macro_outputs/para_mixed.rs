@@ -13,6 +13,9 @@ impl grust::core::Component for TestMixedAuxState {
     fn init() -> TestMixedAuxState {
         TestMixedAuxState { last_i: 0i64 }
     }
+    fn reset(&mut self) {
+        self.last_i = 0i64;
+    }
     fn step(&mut self, input: TestMixedAuxInput) -> TestMixedAuxOutput {
         let ((i3, i1, i2), ()) = {
             let (i3, i1, i2) = ({ 7i64 * input.i }, { (input.i - 54i64) * 2i64 }, {
@@ -66,6 +69,12 @@ impl grust::core::Component for TestMixedState {
             test_mixed_aux_2: <TestMixedAuxState as grust::core::Component>::init(),
         }
     }
+    fn reset(&mut self) {
+        self.last_i = 0i64;
+        <TestMixedAuxState as grust::core::Component>::reset(&mut self.test_mixed_aux);
+        <TestMixedAuxState as grust::core::Component>::reset(&mut self.test_mixed_aux_1);
+        <TestMixedAuxState as grust::core::Component>::reset(&mut self.test_mixed_aux_2);
+    }
     fn step(&mut self, input: TestMixedInput) -> TestMixedOutput {
         let ((i1_1, i1_2), i1_3) = std::thread::scope(|reserved_grust_thread_scope| {
             let reserved_grust_thread_kid_0 = reserved_grust_thread_scope.spawn(|| {
@@ -13,6 +13,9 @@ impl grust::core::Component for TestRayon1AuxState {
     fn init() -> TestRayon1AuxState {
         TestRayon1AuxState { last_i: 0i64 }
     }
+    fn reset(&mut self) {
+        self.last_i = 0i64;
+    }
     fn step(&mut self, input: TestRayon1AuxInput) -> TestRayon1AuxOutput {
         let ((i3, i1, i2), ()) = {
             let (i3, i1, i2) = ({ 7i64 * input.i }, { (input.i - 54i64) * 2i64 }, {
@@ -66,6 +69,12 @@ impl grust::core::Component for TestRayon1State {
             test_rayon1_aux_2: <TestRayon1AuxState as grust::core::Component>::init(),
         }
     }
+    fn reset(&mut self) {
+        self.last_i = 0i64;
+        <TestRayon1AuxState as grust::core::Component>::reset(&mut self.test_rayon1_aux);
+        <TestRayon1AuxState as grust::core::Component>::reset(&mut self.test_rayon1_aux_1);
+        <TestRayon1AuxState as grust::core::Component>::reset(&mut self.test_rayon1_aux_2);
+    }
     fn step(&mut self, input: TestRayon1Input) -> TestRayon1Output {
         let ((i1_1, i1_2, i1_3), ()) = {
             let (i1_1, i1_2, i1_3) = (
@@ -13,6 +13,9 @@ impl grust::core::Component for TestRayon3AuxState {
     fn init() -> TestRayon3AuxState {
         TestRayon3AuxState { last_i: 0i64 }
     }
+    fn reset(&mut self) {
+        self.last_i = 0i64;
+    }
     fn step(&mut self, input: TestRayon3AuxInput) -> TestRayon3AuxOutput {
         let ((i3, i1, i2), ()) = {
             let (i3, i1, i2) = ({ 7i64 * input.i }, { (input.i - 54i64) * 2i64 }, {
@@ -66,6 +69,12 @@ impl grust::core::Component for TestRayon3State {
             test_rayon3_aux_2: <TestRayon3AuxState as grust::core::Component>::init(),
         }
     }
+    fn reset(&mut self) {
+        self.last_i = 0i64;
+        <TestRayon3AuxState as grust::core::Component>::reset(&mut self.test_rayon3_aux);
+        <TestRayon3AuxState as grust::core::Component>::reset(&mut self.test_rayon3_aux_1);
+        <TestRayon3AuxState as grust::core::Component>::reset(&mut self.test_rayon3_aux_2);
+    }
     fn step(&mut self, input: TestRayon3Input) -> TestRayon3Output {
         let ((i1_1, i1_2, i1_3), ()) = {
             let (i1_1, i1_2, i1_3) = (
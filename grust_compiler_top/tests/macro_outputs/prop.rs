@@ -0,0 +1,243 @@
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub enum Gear {
+    #[default]
+    Park,
+    Drive,
+    Reverse,
+}
+impl grust::core::prop::Arbitrary for Gear {
+    fn arbitrary(rng: &mut grust::core::prop::Rng) -> Self {
+        match rng.below(3) {
+            0 => Self::Park,
+            1 => Self::Drive,
+            _ => Self::Reverse,
+        }
+    }
+    fn shrink(&self) -> Vec<Self> {
+        if *self == Self::Park {
+            vec![]
+        } else {
+            vec![Self::Park]
+        }
+    }
+}
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+impl grust::core::prop::Arbitrary for Point {
+    fn arbitrary(rng: &mut grust::core::prop::Rng) -> Self {
+        Self {
+            x: grust::core::prop::Arbitrary::arbitrary(rng),
+            y: grust::core::prop::Arbitrary::arbitrary(rng),
+        }
+    }
+    fn shrink(&self) -> Vec<Self> {
+        let mut simpler = vec![];
+        for value in grust::core::prop::Arbitrary::shrink(&self.x) {
+            simpler.push(Self { x: value, ..*self })
+        }
+        for value in grust::core::prop::Arbitrary::shrink(&self.y) {
+            simpler.push(Self { y: value, ..*self })
+        }
+        simpler
+    }
+}
+pub fn clamp(x: i64, lo: i64, hi: i64) -> i64 {
+    if x < lo {
+        lo
+    } else {
+        if x > hi {
+            hi
+        } else {
+            x
+        }
+    }
+}
+pub struct CounterInput {
+    pub tick: bool,
+    pub gear: Gear,
+}
+pub struct CounterOutput {
+    pub count: i64,
+}
+pub struct CounterState {
+    last_count: i64,
+}
+impl grust::core::Component for CounterState {
+    type Input = CounterInput;
+    type Output = CounterOutput;
+    fn init() -> CounterState {
+        CounterState { last_count: 0i64 }
+    }
+    fn reset(&mut self) {
+        self.last_count = 0i64;
+    }
+    fn step(&mut self, input: CounterInput) -> CounterOutput {
+        let count = if input.tick && (input.gear == Gear::Drive) {
+            clamp(self.last_count + 1i64, 0i64, 100i64)
+        } else {
+            self.last_count
+        };
+        self.last_count = count;
+        CounterOutput { count }
+    }
+}
+#[cfg(test)]
+#[test]
+#[allow(unused_variables, unused_parens)]
+fn counter_prop() {
+    grust::core::prop::check(
+        "counter",
+        64,
+        8,
+        |input: &(bool, Gear)| {
+            let (tick, gear) = *input;
+            true
+        },
+        |inputs: &[(bool, Gear)]| -> Result<(), grust::core::prop::Failure> {
+            let mut state = <CounterState as grust::core::Component>::init();
+            for (step, input) in inputs.iter().enumerate() {
+                let (tick, gear) = *input;
+                let last_count = state.last_count;
+                let CounterOutput { count } = <CounterState as grust::core::Component>::step(
+                    &mut state,
+                    CounterInput { tick, gear },
+                );
+                if !(0 <= count && count <= 100) {
+                    return Err(grust::core::prop::Failure::new(
+                        step,
+                        "`counter` ensures clause #1 violated",
+                    ));
+                }
+                if !(last_count <= count) {
+                    return Err(grust::core::prop::Failure::new(
+                        step,
+                        "`counter` ensures clause #2 violated",
+                    ));
+                }
+                if !(0 <= state.last_count && state.last_count <= 100) {
+                    return Err(grust::core::prop::Failure::new(
+                        step,
+                        "`counter` invariant clause #1 violated",
+                    ));
+                }
+            }
+            Ok(())
+        },
+    )
+}
+pub struct BrakesInput {
+    pub d: i64,
+    pub v: i64,
+}
+pub struct BrakesOutput {
+    pub b: i64,
+}
+pub struct BrakesState {}
+impl grust::core::Component for BrakesState {
+    type Input = BrakesInput;
+    type Output = BrakesOutput;
+    fn init() -> BrakesState {
+        BrakesState {}
+    }
+    fn reset(&mut self) {}
+    fn step(&mut self, input: BrakesInput) -> BrakesOutput {
+        let b = clamp((input.v * input.v) / (2i64 * input.d), 0i64, 6i64);
+        BrakesOutput { b }
+    }
+}
+#[cfg(test)]
+#[test]
+#[allow(unused_variables, unused_parens)]
+fn brakes_prop() {
+    grust::core::prop::check(
+        "brakes",
+        64,
+        8,
+        |input: &(i64, i64)| {
+            let (d, v) = *input;
+            true && (0 < d && d < 150) && (v < 0 && -grust::core::wide::Widen::widen(v) <= 10)
+        },
+        |inputs: &[(i64, i64)]| -> Result<(), grust::core::prop::Failure> {
+            let mut state = <BrakesState as grust::core::Component>::init();
+            for (step, input) in inputs.iter().enumerate() {
+                let (d, v) = *input;
+                let BrakesOutput { b } =
+                    <BrakesState as grust::core::Component>::step(&mut state, BrakesInput { d, v });
+                if !(0 <= b && b <= 6) {
+                    return Err(grust::core::prop::Failure::new(
+                        step,
+                        "`brakes` ensures clause #1 violated",
+                    ));
+                }
+                if !(!(d > 50) || (b <= 1)) {
+                    return Err(grust::core::prop::Failure::new(
+                        step,
+                        "`brakes` ensures clause #2 violated",
+                    ));
+                }
+            }
+            Ok(())
+        },
+    )
+}
+pub struct ManhattanInput {
+    pub p: Point,
+}
+pub struct ManhattanOutput {
+    pub d: i64,
+}
+pub struct ManhattanState {}
+impl grust::core::Component for ManhattanState {
+    type Input = ManhattanInput;
+    type Output = ManhattanOutput;
+    fn init() -> ManhattanState {
+        ManhattanState {}
+    }
+    fn reset(&mut self) {}
+    fn step(&mut self, input: ManhattanInput) -> ManhattanOutput {
+        let d = (if input.p.x < 0i64 {
+            -(input.p.x)
+        } else {
+            input.p.x
+        }) + (if input.p.y < 0i64 {
+            -(input.p.y)
+        } else {
+            input.p.y
+        });
+        ManhattanOutput { d }
+    }
+}
+#[cfg(test)]
+#[test]
+#[allow(unused_variables, unused_parens)]
+fn manhattan_prop() {
+    grust::core::prop::check(
+        "manhattan",
+        64,
+        8,
+        |input: &(Point,)| {
+            let (p,) = *input;
+            true && (-1000 <= (p).x && (p).x <= 1000 && -1000 <= (p).y && (p).y <= 1000)
+        },
+        |inputs: &[(Point,)]| -> Result<(), grust::core::prop::Failure> {
+            let mut state = <ManhattanState as grust::core::Component>::init();
+            for (step, input) in inputs.iter().enumerate() {
+                let (p,) = *input;
+                let ManhattanOutput { d } = <ManhattanState as grust::core::Component>::step(
+                    &mut state,
+                    ManhattanInput { p },
+                );
+                if !(d >= 0) {
+                    return Err(grust::core::prop::Failure::new(
+                        step,
+                        "`manhattan` ensures clause #1 violated",
+                    ));
+                }
+            }
+            Ok(())
+        },
+    )
+}
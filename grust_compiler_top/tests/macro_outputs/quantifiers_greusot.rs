@@ -0,0 +1,46 @@
+use creusot_contracts::{ensures, logic, open, prelude, requires, DeepModel};
+#[derive(prelude :: Clone, Copy, prelude :: PartialEq, DeepModel)]
+pub struct Window {
+    pub lo: i64,
+    pub hi: i64,
+}
+pub struct InWindowInput {
+    pub samples: [i64; 3usize],
+    pub w: Window,
+}
+pub struct InWindowOutput {
+    pub count: i64,
+}
+pub struct InWindowState {}
+impl grust::core::Component for InWindowState {
+    type Input = InWindowInput;
+    type Output = InWindowOutput;
+    fn init() -> InWindowState {
+        InWindowState {}
+    }
+    fn reset(&mut self) {}
+    # [requires ((input . w) . lo @ <= (input . w) . hi @)]
+    # [requires (forall < i : i64 > (0) <= i @ && i @ < (3) == > (0 <= (input . samples @) [i @] @ && (input . samples @) [i @] @ <= 100))]
+    # [ensures (0 <= result . count @ && result . count @ <= 3)]
+    # [ensures (result . count @ > 0 == > exists < i : i64 > (0) <= i @ && i @ < (3) && ((input . w) . lo @ <= (input . samples @) [i @] @ && (input . samples @) [i @] @ <= (input . w) . hi @))]
+    fn step(&mut self, input: InWindowInput) -> InWindowOutput {
+        let count = ((if (input.w.lo <= input.samples[0]) && (input.samples[0] <= input.w.hi) {
+            1i64
+        } else {
+            0i64
+        }) + (if (input.w.lo <= input.samples[1]) && (input.samples[1] <= input.w.hi) {
+            1i64
+        } else {
+            0i64
+        })) + (if (input.w.lo <= input.samples[2]) && (input.samples[2] <= input.w.hi) {
+            1i64
+        } else {
+            0i64
+        });
+        InWindowOutput { count }
+    }
+}
+mod logical {
+    use super::*;
+    use creusot_contracts::{logic, open, Int};
+}
@@ -0,0 +1,113 @@
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+#[cfg_attr(kani, derive(kani::Arbitrary))]
+pub struct Window {
+    pub lo: i64,
+    pub hi: i64,
+}
+pub struct InWindowInput {
+    pub samples: [i64; 3usize],
+    pub w: Window,
+}
+pub struct InWindowOutput {
+    pub count: i64,
+}
+pub struct InWindowState {}
+impl grust::core::Component for InWindowState {
+    type Input = InWindowInput;
+    type Output = InWindowOutput;
+    fn init() -> InWindowState {
+        InWindowState {}
+    }
+    fn reset(&mut self) {}
+    fn step(&mut self, input: InWindowInput) -> InWindowOutput {
+        let count = ((if (input.w.lo <= input.samples[0]) && (input.samples[0] <= input.w.hi) {
+            1i64
+        } else {
+            0i64
+        }) + (if (input.w.lo <= input.samples[1]) && (input.samples[1] <= input.w.hi) {
+            1i64
+        } else {
+            0i64
+        })) + (if (input.w.lo <= input.samples[2]) && (input.samples[2] <= input.w.hi) {
+            1i64
+        } else {
+            0i64
+        });
+        InWindowOutput { count }
+    }
+}
+#[cfg(kani)]
+#[kani::proof]
+#[allow(unused_variables)]
+fn in_window_proof() {
+    let mut state = <InWindowState as grust::core::Component>::init();
+    {
+        let samples: [i64; 3usize] = kani::any();
+        let w: Window = kani::any();
+        kani::assume((w).lo <= (w).hi);
+        kani::assume(
+            ((0)..(3)).all(|i: i64| 0 <= (samples)[(i) as usize] && (samples)[(i) as usize] <= 100),
+        );
+        let InWindowOutput { count } = <InWindowState as grust::core::Component>::step(
+            &mut state,
+            InWindowInput { samples, w },
+        );
+        kani::assert(
+            0 <= count && count <= 3,
+            "`in_window` ensures clause #1 violated",
+        );
+        kani::assert(
+            !(count > 0)
+                || (((0)..(3)).any(|i: i64| {
+                    (w).lo <= (samples)[(i) as usize] && (samples)[(i) as usize] <= (w).hi
+                })),
+            "`in_window` ensures clause #2 violated",
+        );
+    }
+    {
+        let samples: [i64; 3usize] = kani::any();
+        let w: Window = kani::any();
+        kani::assume((w).lo <= (w).hi);
+        kani::assume(
+            ((0)..(3)).all(|i: i64| 0 <= (samples)[(i) as usize] && (samples)[(i) as usize] <= 100),
+        );
+        let InWindowOutput { count } = <InWindowState as grust::core::Component>::step(
+            &mut state,
+            InWindowInput { samples, w },
+        );
+        kani::assert(
+            0 <= count && count <= 3,
+            "`in_window` ensures clause #1 violated",
+        );
+        kani::assert(
+            !(count > 0)
+                || (((0)..(3)).any(|i: i64| {
+                    (w).lo <= (samples)[(i) as usize] && (samples)[(i) as usize] <= (w).hi
+                })),
+            "`in_window` ensures clause #2 violated",
+        );
+    }
+    {
+        let samples: [i64; 3usize] = kani::any();
+        let w: Window = kani::any();
+        kani::assume((w).lo <= (w).hi);
+        kani::assume(
+            ((0)..(3)).all(|i: i64| 0 <= (samples)[(i) as usize] && (samples)[(i) as usize] <= 100),
+        );
+        let InWindowOutput { count } = <InWindowState as grust::core::Component>::step(
+            &mut state,
+            InWindowInput { samples, w },
+        );
+        kani::assert(
+            0 <= count && count <= 3,
+            "`in_window` ensures clause #1 violated",
+        );
+        kani::assert(
+            !(count > 0)
+                || (((0)..(3)).any(|i: i64| {
+                    (w).lo <= (samples)[(i) as usize] && (samples)[(i) as usize] <= (w).hi
+                })),
+            "`in_window` ensures clause #2 violated",
+        );
+    }
+}
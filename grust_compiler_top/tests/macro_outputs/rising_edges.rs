@@ -29,6 +29,14 @@ impl grust::core::Component for RisingEdgesState {
             last_z: 0i64,
         }
     }
+    fn reset(&mut self) {
+        self.last_c = 0i64;
+        self.last_d = 0.0f64;
+        self.last_x_1 = false;
+        self.last_x_2 = false;
+        self.last_x_3 = false;
+        self.last_z = 0i64;
+    }
     fn step(&mut self, input: RisingEdgesInput) -> RisingEdgesOutput {
         let x_1 = input.v > 50i64;
         let w = match () {
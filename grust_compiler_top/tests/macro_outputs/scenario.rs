@@ -0,0 +1,145 @@
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub enum Braking {
+    #[default]
+    NoBrake,
+    SoftBrake,
+    UrgentBrake,
+}
+pub fn brakes(distance: f64, speed: f64) -> Braking {
+    if ((speed * speed) / 100.0f64) < distance {
+        Braking::SoftBrake
+    } else {
+        Braking::UrgentBrake
+    }
+}
+pub struct BrakingStateInput {
+    pub pedest: Option<f64>,
+    pub timeout_pedestrian: Option<()>,
+    pub speed: f64,
+}
+pub struct BrakingStateOutput {
+    pub state: Braking,
+}
+pub struct BrakingStateState {
+    last_state: Braking,
+}
+impl grust::core::Component for BrakingStateState {
+    type Input = BrakingStateInput;
+    type Output = BrakingStateOutput;
+    fn init() -> BrakingStateState {
+        BrakingStateState {
+            last_state: Braking::NoBrake,
+        }
+    }
+    fn reset(&mut self) {
+        self.last_state = Braking::NoBrake;
+    }
+    fn step(&mut self, input: BrakingStateInput) -> BrakingStateOutput {
+        let state = match (input.pedest, input.timeout_pedestrian) {
+            (Some(d), _) => {
+                let state = brakes(d, input.speed);
+                state
+            }
+            (_, Some(_)) if self.last_state == Braking::UrgentBrake => {
+                let state = Braking::SoftBrake;
+                state
+            }
+            (_, Some(_)) => {
+                let state = Braking::NoBrake;
+                state
+            }
+            (_, _) => {
+                let state = self.last_state;
+                state
+            }
+        };
+        self.last_state = state;
+        BrakingStateOutput { state }
+    }
+}
+#[cfg(test)]
+#[test]
+#[allow(unused_variables)]
+fn braking_nominal() {
+    let mut state = <BrakingStateState as grust::core::Component>::init();
+    {
+        let input = BrakingStateInput {
+            pedest: Some(10.0f64),
+            timeout_pedestrian: None,
+            speed: 30.0f64,
+        };
+        let inputs = format!(
+            "pedest = {:?}, timeout_pedestrian = {:?}, speed = {:?}",
+            input.pedest, input.timeout_pedestrian, input.speed
+        );
+        let output = <BrakingStateState as grust::core::Component>::step(&mut state, input);
+        let expected = Braking::SoftBrake;
+        assert!(
+            output.state == expected,
+            "test `braking_nominal` failed at step 0: `state` is {:?}, expected {:?}\n  inputs: {}",
+            output.state,
+            expected,
+            inputs
+        );
+    }
+    {
+        let input = BrakingStateInput {
+            pedest: Some(5.0f64),
+            timeout_pedestrian: None,
+            speed: 30.0f64,
+        };
+        let inputs = format!(
+            "pedest = {:?}, timeout_pedestrian = {:?}, speed = {:?}",
+            input.pedest, input.timeout_pedestrian, input.speed
+        );
+        let output = <BrakingStateState as grust::core::Component>::step(&mut state, input);
+        let expected = Braking::UrgentBrake;
+        assert!(
+            output.state == expected,
+            "test `braking_nominal` failed at step 1: `state` is {:?}, expected {:?}\n  inputs: {}",
+            output.state,
+            expected,
+            inputs
+        );
+    }
+    {
+        let input = BrakingStateInput {
+            pedest: None,
+            timeout_pedestrian: Some(()),
+            speed: 30.0f64,
+        };
+        let inputs = format!(
+            "pedest = {:?}, timeout_pedestrian = {:?}, speed = {:?}",
+            input.pedest, input.timeout_pedestrian, input.speed
+        );
+        let output = <BrakingStateState as grust::core::Component>::step(&mut state, input);
+        let expected = Braking::SoftBrake;
+        assert!(
+            output.state == expected,
+            "test `braking_nominal` failed at step 2: `state` is {:?}, expected {:?}\n  inputs: {}",
+            output.state,
+            expected,
+            inputs
+        );
+    }
+    {
+        let input = BrakingStateInput {
+            pedest: None,
+            timeout_pedestrian: Some(()),
+            speed: 30.0f64,
+        };
+        let inputs = format!(
+            "pedest = {:?}, timeout_pedestrian = {:?}, speed = {:?}",
+            input.pedest, input.timeout_pedestrian, input.speed
+        );
+        let output = <BrakingStateState as grust::core::Component>::step(&mut state, input);
+        let expected = Braking::NoBrake;
+        assert!(
+            output.state == expected,
+            "test `braking_nominal` failed at step 3: `state` is {:?}, expected {:?}\n  inputs: {}",
+            output.state,
+            expected,
+            inputs
+        );
+    }
+}
@@ -1,3 +1,6 @@
+const _: fn(f64) -> f64 = module::invsqrt;
+const _: fn(f64) -> f64 = module::asinf;
+const _: fn(f64, f64) -> f64 = module::atan2f;
 pub struct AccZInput {
     pub ax: f64,
     pub ay: f64,
@@ -16,6 +19,7 @@ impl grust::core::Component for AccZState {
     fn init() -> AccZState {
         AccZState {}
     }
+    fn reset(&mut self) {}
     fn step(&mut self, input: AccZInput) -> AccZOutput {
         let accz = ((input.ax * input.gravx) + (input.ay * input.gravy)) + (input.az * input.gravz);
         AccZOutput { accz }
@@ -43,6 +47,9 @@ impl grust::core::Component for AccZWithoutGravityState {
             acc_z_1: <AccZState as grust::core::Component>::init(),
         }
     }
+    fn reset(&mut self) {
+        <AccZState as grust::core::Component>::reset(&mut self.acc_z_1);
+    }
     fn step(&mut self, input: AccZWithoutGravityInput) -> AccZWithoutGravityOutput {
         let acc_z_g = {
             let AccZOutput { accz } = <AccZState as grust::core::Component>::step(
@@ -79,6 +86,7 @@ impl grust::core::Component for NormalizeVec3State {
     fn init() -> NormalizeVec3State {
         NormalizeVec3State {}
     }
+    fn reset(&mut self) {}
     fn step(&mut self, input: NormalizeVec3Input) -> NormalizeVec3Output {
         let r = module::invsqrt(((input.x * input.x) + (input.y * input.y)) + (input.z * input.z));
         let nx = r * input.x;
@@ -106,6 +114,7 @@ impl grust::core::Component for NormalizeQuatState {
     fn init() -> NormalizeQuatState {
         NormalizeQuatState {}
     }
+    fn reset(&mut self) {}
     fn step(&mut self, input: NormalizeQuatInput) -> NormalizeQuatOutput {
         let r = module::invsqrt(
             (((input.qw * input.qw) + (input.qx * input.qx)) + (input.qy * input.qy))
@@ -135,6 +144,9 @@ impl grust::core::Component for IntegralFeedbackState {
             last_integral_f_b: 0.0f64,
         }
     }
+    fn reset(&mut self) {
+        self.last_integral_f_b = 0.0f64;
+    }
     fn step(&mut self, input: IntegralFeedbackInput) -> IntegralFeedbackOutput {
         let twoKi = 2.0f64 * 0.001f64;
         let estimator_attitude_update_dt = 1.0f64 / 250.0f64;
@@ -185,6 +197,17 @@ impl grust::core::Component for Sensfusion6QuatState {
             normalize_quat: <NormalizeQuatState as grust::core::Component>::init(),
         }
     }
+    fn reset(&mut self) {
+        self.last_qw = 1.0f64;
+        self.last_qx = 0.0f64;
+        self.last_qy = 0.0f64;
+        self.last_qz = 0.0f64;
+        <NormalizeVec3State as grust::core::Component>::reset(&mut self.normalize_vec3);
+        <IntegralFeedbackState as grust::core::Component>::reset(&mut self.integral_feedback);
+        <IntegralFeedbackState as grust::core::Component>::reset(&mut self.integral_feedback_1);
+        <IntegralFeedbackState as grust::core::Component>::reset(&mut self.integral_feedback_2);
+        <NormalizeQuatState as grust::core::Component>::reset(&mut self.normalize_quat);
+    }
     fn step(&mut self, input: Sensfusion6QuatInput) -> Sensfusion6QuatOutput {
         let twoKp = 2.0f64 * 0.4f64;
         let estimator_attitude_update_dt = 1.0f64 / 250.0f64;
@@ -150,6 +150,9 @@ impl grust::core::Component for ProcessSetSpeedState {
     fn init() -> ProcessSetSpeedState {
         ProcessSetSpeedState { last_v_set: 0.0f64 }
     }
+    fn reset(&mut self) {
+        self.last_v_set = 0.0f64;
+    }
     fn step(&mut self, input: ProcessSetSpeedInput) -> ProcessSetSpeedOutput {
         let v_set = threshold_set_speed(input.set_speed);
         let prev_v_set = self.last_v_set;
@@ -181,6 +184,9 @@ impl grust::core::Component for SpeedLimiterOnState {
             last_hysterisis: new_hysterisis(0.0f64),
         }
     }
+    fn reset(&mut self) {
+        self.last_hysterisis = new_hysterisis(0.0f64);
+    }
     fn step(&mut self, input: SpeedLimiterOnInput) -> SpeedLimiterOnOutput {
         let prev_hysterisis = self.last_hysterisis;
         let (hysterisis, on_state) = match input.prev_on_state {
@@ -296,6 +302,12 @@ impl grust::core::Component for SpeedLimiterState {
             speed_limiter_on: <SpeedLimiterOnState as grust::core::Component>::init(),
         }
     }
+    fn reset(&mut self) {
+        self.last_in_regulation = true;
+        self.last_on_state = SpeedLimiterOn::StandBy;
+        self.last_state = SpeedLimiter::Off;
+        <SpeedLimiterOnState as grust::core::Component>::reset(&mut self.speed_limiter_on);
+    }
     fn step(&mut self, input: SpeedLimiterInput) -> SpeedLimiterOutput {
         let failure = false;
         let prev_state = self.last_state;
@@ -0,0 +1,133 @@
+use creusot_contracts::{ensures, logic, open, prelude, requires, DeepModel};
+#[derive(prelude :: Clone, Copy, prelude :: PartialEq, DeepModel)]
+pub enum Braking {
+    UrgentBrake,
+    SoftBrake,
+    NoBrake,
+}
+pub struct BrakingInput {
+    pub pedestrian: bool,
+}
+pub struct BrakingOutput {
+    pub brakes: Braking,
+}
+pub struct BrakingState {
+    last_brakes: Braking,
+    last_braking_observer_0_c1: i64,
+    last_braking_observer_0_holds: bool,
+    last_braking_observer_0_p0: bool,
+    last_braking_observer_0_started: bool,
+    last_braking_observer_1_holds: bool,
+    last_braking_observer_1_p0: bool,
+    last_braking_observer_1_started: bool,
+    last_braking_observer_1_t1: bool,
+    last_braking_observer_2_holds: bool,
+    last_braking_observer_2_started: bool,
+    last_braking_observer_2_t0: bool,
+    last_braking_observer_2_t1: bool,
+}
+impl grust::core::Component for BrakingState {
+    type Input = BrakingInput;
+    type Output = BrakingOutput;
+    # [ensures (result . last_braking_observer_0_holds)]
+    # [ensures ((result . last_braking_observer_0_started == > (result . last_braking_observer_0_holds == ((! (result . last_braking_observer_0_p0) || (result . last_braking_observer_0_c1 @ < 3))))) && (result . last_braking_observer_0_started || (result . last_braking_observer_0_c1 @ == 3)))]
+    # [ensures (result . last_braking_observer_1_holds)]
+    # [ensures ((result . last_braking_observer_1_started == > (result . last_braking_observer_1_holds == ((! (result . last_braking_observer_1_p0) || ((result . last_braking_observer_1_t1)))))) && (result . last_braking_observer_1_started || (result . last_braking_observer_1_t1 == false)))]
+    # [ensures (result . last_braking_observer_2_holds)]
+    # [ensures ((result . last_braking_observer_2_started == > (result . last_braking_observer_2_holds == ((result . last_braking_observer_2_t0 || result . last_braking_observer_2_t1)))) && (result . last_braking_observer_2_started || (result . last_braking_observer_2_t0 == false && result . last_braking_observer_2_t1 == true)))]
+    fn init() -> BrakingState {
+        BrakingState {
+            last_brakes: Braking::NoBrake,
+            last_braking_observer_0_c1: 3i64,
+            last_braking_observer_0_holds: true,
+            last_braking_observer_0_p0: false,
+            last_braking_observer_0_started: false,
+            last_braking_observer_1_holds: true,
+            last_braking_observer_1_p0: false,
+            last_braking_observer_1_started: false,
+            last_braking_observer_1_t1: false,
+            last_braking_observer_2_holds: true,
+            last_braking_observer_2_started: false,
+            last_braking_observer_2_t0: false,
+            last_braking_observer_2_t1: true,
+        }
+    }
+    fn reset(&mut self) {
+        self.last_brakes = Braking::NoBrake;
+        self.last_braking_observer_0_c1 = 3i64;
+        self.last_braking_observer_0_holds = true;
+        self.last_braking_observer_0_p0 = false;
+        self.last_braking_observer_0_started = false;
+        self.last_braking_observer_1_holds = true;
+        self.last_braking_observer_1_p0 = false;
+        self.last_braking_observer_1_started = false;
+        self.last_braking_observer_1_t1 = false;
+        self.last_braking_observer_2_holds = true;
+        self.last_braking_observer_2_started = false;
+        self.last_braking_observer_2_t0 = false;
+        self.last_braking_observer_2_t1 = true;
+    }
+    # [requires (self . last_braking_observer_0_holds)]
+    # [ensures ((^ self) . last_braking_observer_0_holds)]
+    # [requires ((self . last_braking_observer_0_started == > (self . last_braking_observer_0_holds == ((! (self . last_braking_observer_0_p0) || (self . last_braking_observer_0_c1 @ < 3))))) && (self . last_braking_observer_0_started || (self . last_braking_observer_0_c1 @ == 3)))]
+    # [ensures (((^ self) . last_braking_observer_0_started == > ((^ self) . last_braking_observer_0_holds == ((! ((^ self) . last_braking_observer_0_p0) || ((^ self) . last_braking_observer_0_c1 @ < 3))))) && ((^ self) . last_braking_observer_0_started || ((^ self) . last_braking_observer_0_c1 @ == 3)))]
+    # [requires (self . last_braking_observer_1_holds)]
+    # [ensures ((^ self) . last_braking_observer_1_holds)]
+    # [requires ((self . last_braking_observer_1_started == > (self . last_braking_observer_1_holds == ((! (self . last_braking_observer_1_p0) || ((self . last_braking_observer_1_t1)))))) && (self . last_braking_observer_1_started || (self . last_braking_observer_1_t1 == false)))]
+    # [ensures (((^ self) . last_braking_observer_1_started == > ((^ self) . last_braking_observer_1_holds == ((! ((^ self) . last_braking_observer_1_p0) || (((^ self) . last_braking_observer_1_t1)))))) && ((^ self) . last_braking_observer_1_started || ((^ self) . last_braking_observer_1_t1 == false)))]
+    # [requires (self . last_braking_observer_2_holds)]
+    # [ensures ((^ self) . last_braking_observer_2_holds)]
+    # [requires ((self . last_braking_observer_2_started == > (self . last_braking_observer_2_holds == ((self . last_braking_observer_2_t0 || self . last_braking_observer_2_t1)))) && (self . last_braking_observer_2_started || (self . last_braking_observer_2_t0 == false && self . last_braking_observer_2_t1 == true)))]
+    # [ensures (((^ self) . last_braking_observer_2_started == > ((^ self) . last_braking_observer_2_holds == (((^ self) . last_braking_observer_2_t0 || (^ self) . last_braking_observer_2_t1)))) && ((^ self) . last_braking_observer_2_started || ((^ self) . last_braking_observer_2_t0 == false && (^ self) . last_braking_observer_2_t1 == true)))]
+    fn step(&mut self, input: BrakingInput) -> BrakingOutput {
+        let brakes = if input.pedestrian && (self.last_brakes != Braking::NoBrake) {
+            Braking::UrgentBrake
+        } else {
+            if input.pedestrian {
+                Braking::SoftBrake
+            } else {
+                Braking::NoBrake
+            }
+        };
+        let braking_observer_0_p0 = input.pedestrian;
+        let braking_observer_0_c1 = if brakes != Braking::NoBrake {
+            0i64
+        } else {
+            if self.last_braking_observer_0_c1 < 3i64 {
+                self.last_braking_observer_0_c1 + 1i64
+            } else {
+                3i64
+            }
+        };
+        let braking_observer_0_t2 = braking_observer_0_c1 < 3i64;
+        let braking_observer_0_holds = !(braking_observer_0_p0) || braking_observer_0_t2;
+        let braking_observer_0_started = true;
+        let braking_observer_1_p0 = brakes == Braking::UrgentBrake;
+        let braking_observer_1_t1 = input.pedestrian
+            || ((self.last_brakes != Braking::NoBrake) && self.last_braking_observer_1_t1);
+        let braking_observer_1_holds = !(braking_observer_1_p0) || braking_observer_1_t1;
+        let braking_observer_1_started = true;
+        let braking_observer_2_t0 = input.pedestrian || self.last_braking_observer_2_t0;
+        let braking_observer_2_t1 = (brakes == Braking::NoBrake) && self.last_braking_observer_2_t1;
+        let braking_observer_2_holds = braking_observer_2_t0 || braking_observer_2_t1;
+        let braking_observer_2_started = true;
+        self.last_brakes = brakes;
+        self.last_braking_observer_0_c1 = braking_observer_0_c1;
+        self.last_braking_observer_0_holds = braking_observer_0_holds;
+        self.last_braking_observer_0_p0 = braking_observer_0_p0;
+        self.last_braking_observer_0_started = braking_observer_0_started;
+        self.last_braking_observer_1_holds = braking_observer_1_holds;
+        self.last_braking_observer_1_p0 = braking_observer_1_p0;
+        self.last_braking_observer_1_started = braking_observer_1_started;
+        self.last_braking_observer_1_t1 = braking_observer_1_t1;
+        self.last_braking_observer_2_holds = braking_observer_2_holds;
+        self.last_braking_observer_2_started = braking_observer_2_started;
+        self.last_braking_observer_2_t0 = braking_observer_2_t0;
+        self.last_braking_observer_2_t1 = braking_observer_2_t1;
+        BrakingOutput { brakes }
+    }
+}
+mod logical {
+    use super::*;
+    use creusot_contracts::{logic, open, Int};
+}
@@ -0,0 +1,62 @@
+use creusot_contracts::{ensures, logic, open, prelude, requires, DeepModel};
+pub struct BrakingInput {
+    pub pedestrian: bool,
+}
+pub struct BrakingOutput {
+    pub brakes: bool,
+}
+pub struct BrakingState {
+    last_braking_observer_0_c1: i64,
+    last_braking_observer_0_holds: bool,
+    last_braking_observer_0_p0: bool,
+    last_braking_observer_0_started: bool,
+}
+impl grust::core::Component for BrakingState {
+    type Input = BrakingInput;
+    type Output = BrakingOutput;
+    # [ensures (result . last_braking_observer_0_holds)]
+    # [ensures ((result . last_braking_observer_0_started == > (result . last_braking_observer_0_holds == ((! (result . last_braking_observer_0_p0) || (result . last_braking_observer_0_c1 @ < 2))))) && (result . last_braking_observer_0_started || (result . last_braking_observer_0_c1 @ == 2)))]
+    fn init() -> BrakingState {
+        BrakingState {
+            last_braking_observer_0_c1: 2i64,
+            last_braking_observer_0_holds: true,
+            last_braking_observer_0_p0: false,
+            last_braking_observer_0_started: false,
+        }
+    }
+    fn reset(&mut self) {
+        self.last_braking_observer_0_c1 = 2i64;
+        self.last_braking_observer_0_holds = true;
+        self.last_braking_observer_0_p0 = false;
+        self.last_braking_observer_0_started = false;
+    }
+    # [requires (self . last_braking_observer_0_holds)]
+    # [ensures ((^ self) . last_braking_observer_0_holds)]
+    # [requires ((self . last_braking_observer_0_started == > (self . last_braking_observer_0_holds == ((! (self . last_braking_observer_0_p0) || (self . last_braking_observer_0_c1 @ < 2))))) && (self . last_braking_observer_0_started || (self . last_braking_observer_0_c1 @ == 2)))]
+    # [ensures (((^ self) . last_braking_observer_0_started == > ((^ self) . last_braking_observer_0_holds == ((! ((^ self) . last_braking_observer_0_p0) || ((^ self) . last_braking_observer_0_c1 @ < 2))))) && ((^ self) . last_braking_observer_0_started || ((^ self) . last_braking_observer_0_c1 @ == 2)))]
+    fn step(&mut self, input: BrakingInput) -> BrakingOutput {
+        let brakes = input.pedestrian;
+        let braking_observer_0_p0 = input.pedestrian;
+        let braking_observer_0_c1 = if brakes {
+            0i64
+        } else {
+            if self.last_braking_observer_0_c1 < 2i64 {
+                self.last_braking_observer_0_c1 + 1i64
+            } else {
+                2i64
+            }
+        };
+        let braking_observer_0_t2 = braking_observer_0_c1 < 2i64;
+        let braking_observer_0_holds = !(braking_observer_0_p0) || braking_observer_0_t2;
+        let braking_observer_0_started = true;
+        self.last_braking_observer_0_c1 = braking_observer_0_c1;
+        self.last_braking_observer_0_holds = braking_observer_0_holds;
+        self.last_braking_observer_0_p0 = braking_observer_0_p0;
+        self.last_braking_observer_0_started = braking_observer_0_started;
+        BrakingOutput { brakes }
+    }
+}
+mod logical {
+    use super::*;
+    use creusot_contracts::{logic, open, Int};
+}
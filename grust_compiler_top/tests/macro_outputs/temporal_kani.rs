@@ -0,0 +1,206 @@
+pub struct AlarmInput {
+    pub x: i64,
+}
+pub struct AlarmOutput {
+    pub raised: bool,
+}
+pub struct AlarmState {
+    last_alarm_observer_0_holds: bool,
+    last_alarm_observer_0_p0: bool,
+    last_alarm_observer_0_started: bool,
+    last_alarm_observer_0_t1: bool,
+    last_alarm_observer_1_c1: i64,
+    last_alarm_observer_1_holds: bool,
+    last_alarm_observer_1_p0: bool,
+    last_alarm_observer_1_started: bool,
+    last_high: bool,
+    last_raised: bool,
+}
+impl grust::core::Component for AlarmState {
+    type Input = AlarmInput;
+    type Output = AlarmOutput;
+    fn init() -> AlarmState {
+        AlarmState {
+            last_alarm_observer_0_holds: true,
+            last_alarm_observer_0_p0: false,
+            last_alarm_observer_0_started: false,
+            last_alarm_observer_0_t1: true,
+            last_alarm_observer_1_c1: 2i64,
+            last_alarm_observer_1_holds: true,
+            last_alarm_observer_1_p0: false,
+            last_alarm_observer_1_started: false,
+            last_high: false,
+            last_raised: false,
+        }
+    }
+    fn reset(&mut self) {
+        self.last_alarm_observer_0_holds = true;
+        self.last_alarm_observer_0_p0 = false;
+        self.last_alarm_observer_0_started = false;
+        self.last_alarm_observer_0_t1 = true;
+        self.last_alarm_observer_1_c1 = 2i64;
+        self.last_alarm_observer_1_holds = true;
+        self.last_alarm_observer_1_p0 = false;
+        self.last_alarm_observer_1_started = false;
+        self.last_high = false;
+        self.last_raised = false;
+    }
+    fn step(&mut self, input: AlarmInput) -> AlarmOutput {
+        let high = input.x > 90i64;
+        let raised = high && self.last_high;
+        let alarm_observer_0_p0 = raised;
+        let alarm_observer_0_t1 = (input.x >= 0i64) && self.last_alarm_observer_0_t1;
+        let alarm_observer_0_holds = !(alarm_observer_0_p0) || alarm_observer_0_t1;
+        let alarm_observer_0_started = true;
+        let alarm_observer_1_p0 = raised;
+        let alarm_observer_1_c1 = if input.x > 90i64 {
+            0i64
+        } else {
+            if self.last_alarm_observer_1_c1 < 2i64 {
+                self.last_alarm_observer_1_c1 + 1i64
+            } else {
+                2i64
+            }
+        };
+        let alarm_observer_1_t2 = alarm_observer_1_c1 < 2i64;
+        let alarm_observer_1_holds = !(alarm_observer_1_p0) || alarm_observer_1_t2;
+        let alarm_observer_1_started = true;
+        self.last_alarm_observer_0_holds = alarm_observer_0_holds;
+        self.last_alarm_observer_0_p0 = alarm_observer_0_p0;
+        self.last_alarm_observer_0_started = alarm_observer_0_started;
+        self.last_alarm_observer_0_t1 = alarm_observer_0_t1;
+        self.last_alarm_observer_1_c1 = alarm_observer_1_c1;
+        self.last_alarm_observer_1_holds = alarm_observer_1_holds;
+        self.last_alarm_observer_1_p0 = alarm_observer_1_p0;
+        self.last_alarm_observer_1_started = alarm_observer_1_started;
+        self.last_high = high;
+        self.last_raised = raised;
+        AlarmOutput { raised }
+    }
+}
+#[cfg(kani)]
+#[kani::proof]
+#[allow(unused_variables)]
+fn alarm_proof() {
+    let mut state = <AlarmState as grust::core::Component>::init();
+    {
+        let x: i64 = kani::any();
+        kani::assume(0 <= x && x <= 100);
+        let AlarmOutput { raised } =
+            <AlarmState as grust::core::Component>::step(&mut state, AlarmInput { x });
+        kani::assert(
+            state.last_alarm_observer_0_holds,
+            "`alarm` invariant clause #1 violated",
+        );
+        kani::assert(
+            (!(state.last_alarm_observer_0_started)
+                || (state.last_alarm_observer_0_holds
+                    == (!(state.last_alarm_observer_0_p0) || (state.last_alarm_observer_0_t1))))
+                && (state.last_alarm_observer_0_started
+                    || (state.last_alarm_observer_0_t1 == true)),
+            "`alarm` invariant clause #2 violated",
+        );
+        kani::assert(
+            state.last_alarm_observer_1_holds,
+            "`alarm` invariant clause #3 violated",
+        );
+        kani::assert(
+            (!(state.last_alarm_observer_1_started)
+                || (state.last_alarm_observer_1_holds
+                    == (!(state.last_alarm_observer_1_p0)
+                        || (state.last_alarm_observer_1_c1 < 2))))
+                && (state.last_alarm_observer_1_started || (state.last_alarm_observer_1_c1 == 2)),
+            "`alarm` invariant clause #4 violated",
+        );
+    }
+    {
+        let x: i64 = kani::any();
+        kani::assume(0 <= x && x <= 100);
+        let AlarmOutput { raised } =
+            <AlarmState as grust::core::Component>::step(&mut state, AlarmInput { x });
+        kani::assert(
+            state.last_alarm_observer_0_holds,
+            "`alarm` invariant clause #1 violated",
+        );
+        kani::assert(
+            (!(state.last_alarm_observer_0_started)
+                || (state.last_alarm_observer_0_holds
+                    == (!(state.last_alarm_observer_0_p0) || (state.last_alarm_observer_0_t1))))
+                && (state.last_alarm_observer_0_started
+                    || (state.last_alarm_observer_0_t1 == true)),
+            "`alarm` invariant clause #2 violated",
+        );
+        kani::assert(
+            state.last_alarm_observer_1_holds,
+            "`alarm` invariant clause #3 violated",
+        );
+        kani::assert(
+            (!(state.last_alarm_observer_1_started)
+                || (state.last_alarm_observer_1_holds
+                    == (!(state.last_alarm_observer_1_p0)
+                        || (state.last_alarm_observer_1_c1 < 2))))
+                && (state.last_alarm_observer_1_started || (state.last_alarm_observer_1_c1 == 2)),
+            "`alarm` invariant clause #4 violated",
+        );
+    }
+    {
+        let x: i64 = kani::any();
+        kani::assume(0 <= x && x <= 100);
+        let AlarmOutput { raised } =
+            <AlarmState as grust::core::Component>::step(&mut state, AlarmInput { x });
+        kani::assert(
+            state.last_alarm_observer_0_holds,
+            "`alarm` invariant clause #1 violated",
+        );
+        kani::assert(
+            (!(state.last_alarm_observer_0_started)
+                || (state.last_alarm_observer_0_holds
+                    == (!(state.last_alarm_observer_0_p0) || (state.last_alarm_observer_0_t1))))
+                && (state.last_alarm_observer_0_started
+                    || (state.last_alarm_observer_0_t1 == true)),
+            "`alarm` invariant clause #2 violated",
+        );
+        kani::assert(
+            state.last_alarm_observer_1_holds,
+            "`alarm` invariant clause #3 violated",
+        );
+        kani::assert(
+            (!(state.last_alarm_observer_1_started)
+                || (state.last_alarm_observer_1_holds
+                    == (!(state.last_alarm_observer_1_p0)
+                        || (state.last_alarm_observer_1_c1 < 2))))
+                && (state.last_alarm_observer_1_started || (state.last_alarm_observer_1_c1 == 2)),
+            "`alarm` invariant clause #4 violated",
+        );
+    }
+    {
+        let x: i64 = kani::any();
+        kani::assume(0 <= x && x <= 100);
+        let AlarmOutput { raised } =
+            <AlarmState as grust::core::Component>::step(&mut state, AlarmInput { x });
+        kani::assert(
+            state.last_alarm_observer_0_holds,
+            "`alarm` invariant clause #1 violated",
+        );
+        kani::assert(
+            (!(state.last_alarm_observer_0_started)
+                || (state.last_alarm_observer_0_holds
+                    == (!(state.last_alarm_observer_0_p0) || (state.last_alarm_observer_0_t1))))
+                && (state.last_alarm_observer_0_started
+                    || (state.last_alarm_observer_0_t1 == true)),
+            "`alarm` invariant clause #2 violated",
+        );
+        kani::assert(
+            state.last_alarm_observer_1_holds,
+            "`alarm` invariant clause #3 violated",
+        );
+        kani::assert(
+            (!(state.last_alarm_observer_1_started)
+                || (state.last_alarm_observer_1_holds
+                    == (!(state.last_alarm_observer_1_p0)
+                        || (state.last_alarm_observer_1_c1 < 2))))
+                && (state.last_alarm_observer_1_started || (state.last_alarm_observer_1_c1 == 2)),
+            "`alarm` invariant clause #4 violated",
+        );
+    }
+}
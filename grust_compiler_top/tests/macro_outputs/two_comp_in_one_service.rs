@@ -1,3 +1,11 @@
+#[allow(clippy::type_complexity)]
+const _: fn(bool, Option<()>) -> (i64,) = |res, tick| {
+    let utils::CounterOutput { o } = <utils::CounterState as grust::core::Component>::step(
+        &mut <utils::CounterState as grust::core::Component>::init(),
+        utils::CounterInput { res, tick },
+    );
+    (o,)
+};
 pub mod runtime {
     use super::*;
     use grust::futures::{sink::SinkExt, stream::StreamExt};
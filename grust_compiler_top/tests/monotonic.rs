@@ -0,0 +1,42 @@
+grust_compiler_top::prelude! {}
+
+#[test]
+fn should_reject_non_monotonic_tables() {
+    let top: ir0::Top = parse_quote! {
+        #[monotonic]
+        const SPEEDS: [float; 3] = [0.0, 20.0, 10.0];
+
+        component test(speed: float) -> (y: float) {
+            y = speed + SPEEDS[0];
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let errors = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap_err();
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn should_check_tables_row_by_row() {
+    let top: ir0::Top = parse_quote! {
+        #[monotonic]
+        const MAP: [[float; 2]; 2] = [[-1.0, 1.0], [1.0, 1.0]];
+
+        component test(speed: float) -> (y: float) {
+            y = speed + MAP[0][0];
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let errors = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap_err();
+    assert_eq!(errors.len(), 1);
+
+    let top: ir0::Top = parse_quote! {
+        #[monotonic]
+        const MAP: [[float; 2]; 2] = [[-1.0, 1.0], [0.0, 2.0]];
+
+        component test(speed: float) -> (y: float) {
+            y = speed + MAP[0][0];
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    assert!(grust_compiler_top::into_token_stream_res(ast, &mut ctx).is_ok());
+}
@@ -0,0 +1,90 @@
+grust_compiler_top::prelude! {}
+
+fn compile(top: ir0::Top) -> Result<String, Vec<String>> {
+    let (ast, mut ctx) = top.init();
+    match grust_compiler_top::into_token_stream_res(ast, &mut ctx) {
+        Ok(tokens) => {
+            if let Some(path) = ctx.conf.dump_code {
+                grust_compiler_top::dump_code(&path, &tokens).unwrap();
+            }
+            Ok(tokens.to_string())
+        }
+        Err(errors) => Err(errors
+            .into_iter()
+            .map(|error| error.0.to_string())
+            .collect()),
+    }
+}
+
+#[test]
+fn should_list_mutants() {
+    let top: ir0::Top = parse_quote! {
+        #![dump = "tests/macro_outputs/mutation.rs", mode = test, mutation]
+
+        enum Braking { NoBrake, SoftBrake, UrgentBrake }
+
+        component braking_state(pedest: float?, timeout_pedestrian: unit?, speed: float)
+            -> (state: Braking)
+        {
+            when {
+                init => { state = Braking::NoBrake; }
+                let d = pedest? => {
+                    state = if speed * speed / 100.0 < d
+                        then Braking::SoftBrake
+                        else Braking::UrgentBrake;
+                }
+                let _ = timeout_pedestrian? if last state == Braking::UrgentBrake => {
+                    state = Braking::SoftBrake;
+                }
+                let _ = timeout_pedestrian? => { state = Braking::NoBrake; }
+            }
+        }
+
+        component hold(x: int) -> (y: int, z: int) {
+            init (y, z) = (0, 0);
+            y = last z;
+            z = if x > 0 then x else last z;
+        }
+    };
+    let tokens = compile(top).unwrap();
+    assert!(tokens.contains("pub fn mutants ()"));
+    assert!(tokens.contains("option_env ! (\"GRUST_MUTANT\")"));
+}
+
+#[test]
+fn should_apply_the_given_mutant() {
+    let top = |mutant: &str| -> ir0::Top {
+        let mutant = syn::LitStr::new(mutant, Span::call_site());
+        parse_quote! {
+            #![mode = test, mutant = #mutant]
+            component hold(x: int) -> (y: int, z: int) {
+                init (y, z) = (0, 0);
+                y = last z;
+                z = if x > 0 then x else last z;
+            }
+        }
+    };
+    // `hold#0` replaces `last z` with `z` in `y`
+    let tokens = compile(top("hold#0")).unwrap();
+    assert!(tokens.contains("let y = z ;"));
+    assert!(tokens.contains("pub const ACTIVE_MUTANT : Option < & str > = Some (\"hold#0\")"));
+    // `hold#1` flips `x > 0`
+    let tokens = compile(top("hold#1")).unwrap();
+    assert!(tokens.contains("input . x <= 0i64"));
+    // `last z` can not become `z` in `z` itself
+    assert_eq!(
+        compile(top("hold#2")).unwrap_err(),
+        vec!["unknown mutant `hold#2`"]
+    );
+}
+
+#[test]
+fn should_only_mutate_in_test_mode() {
+    let top: ir0::Top = parse_quote! {
+        #![mutation]
+        component hold(x: int) -> (y: int) {
+            y = if x > 0 then x else 0;
+        }
+    };
+    assert!(!compile(top).unwrap().contains("mutants"));
+}
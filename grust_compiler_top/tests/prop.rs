@@ -0,0 +1,67 @@
+grust_compiler_top::prelude! {}
+
+#[test]
+fn should_compile_prop_harnesses() {
+    let top: ir0::Top = parse_quote! {
+        #![dump = "tests/macro_outputs/prop.rs", mode = test, prop_test, prop_cases = 64, prop_depth = 8]
+
+        const B_MAX: int = 6;
+
+        enum Gear { Park, Drive, Reverse }
+
+        struct Point { x: int, y: int }
+
+        function clamp(x: int, lo: int, hi: int) -> int {
+            return if x < lo then lo else if x > hi then hi else x;
+        }
+
+        // Counts activations, saturating at 100
+        component counter(tick: bool, gear: Gear) -> (count: int)
+            ensures { 0 <= count && count <= 100 }
+            ensures { last count <= count }
+            invariant { 0 <= last count && last count <= 100 }
+        {
+            init count = 0;
+            count = if tick && gear == Gear::Drive then clamp(last count + 1, 0, 100) else last count;
+        }
+
+        // Braking command in correct interval
+        component brakes(d: int, v: int) -> (b: int)
+            requires { 0 < d && d < 150 }
+            requires { v < 0 && -v <= 10 }
+            ensures  { 0 <= b && b <= B_MAX }
+            ensures  { d > 50 => b <= 1 }
+        {
+            b = clamp((v * v) / (2 * d), 0, B_MAX);
+        }
+
+        // Distance to the origin, along the axes
+        component manhattan(p: Point) -> (d: int)
+            requires { -1000 <= p.x && p.x <= 1000 && -1000 <= p.y && p.y <= 1000 }
+            ensures  { d >= 0 }
+        {
+            d = (if p.x < 0 then -p.x else p.x) + (if p.y < 0 then -p.y else p.y);
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let tokens = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap();
+    if let Some(path) = ctx.conf.dump_code {
+        grust_compiler_top::dump_code(&path, &tokens).unwrap();
+    }
+}
+
+#[test]
+fn should_not_generate_prop_harnesses_by_default() {
+    let top: ir0::Top = parse_quote! {
+        #![mode = test]
+        component counter(tick: bool) -> (count: int)
+            ensures { last count <= count }
+        {
+            init count = 0;
+            count = if tick then last count + 1 else last count;
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let tokens = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap();
+    assert!(!tokens.to_string().contains("prop"));
+}
@@ -0,0 +1,72 @@
+grust_compiler_top::prelude! {}
+
+fn quantified_top(dump: &str, mode: &str) -> ir0::Top {
+    let dump = syn::LitStr::new(dump, Span::call_site());
+    let mode = syn::Ident::new(mode, Span::call_site());
+    parse_quote! {
+        #![dump = #dump, mode = #mode]
+
+        struct Window {
+            lo: int,
+            hi: int,
+        }
+
+        // Counts the samples lying in the window
+        component in_window(samples: [int; 3], w: Window) -> (count: int)
+            requires { w.lo <= w.hi }
+            requires { forall i in 0..3, 0 <= samples[i] && samples[i] <= 100 }
+            ensures  { 0 <= count && count <= 3 }
+            ensures  { count > 0 => exists i in 0..3, w.lo <= samples[i] && samples[i] <= w.hi }
+        {
+            count = (if w.lo <= samples[0] && samples[0] <= w.hi then 1 else 0)
+                + (if w.lo <= samples[1] && samples[1] <= w.hi then 1 else 0)
+                + (if w.lo <= samples[2] && samples[2] <= w.hi then 1 else 0);
+        }
+    }
+}
+
+#[test]
+fn should_compile_bounded_quantifiers_greusot() {
+    let top = quantified_top("tests/macro_outputs/quantifiers_greusot.rs", "greusot");
+    let (ast, mut ctx) = top.init();
+    let tokens = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap();
+    if let Some(path) = ctx.conf.dump_code {
+        grust_compiler_top::dump_code(&path, &tokens).unwrap();
+    }
+}
+
+#[test]
+fn should_compile_bounded_quantifiers_kani() {
+    let top = quantified_top("tests/macro_outputs/quantifiers_kani.rs", "kani");
+    let (ast, mut ctx) = top.init();
+    let tokens = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap();
+    if let Some(path) = ctx.conf.dump_code {
+        grust_compiler_top::dump_code(&path, &tokens).unwrap();
+    }
+}
+
+#[test]
+fn should_reject_non_array_access() {
+    let top: ir0::Top = parse_quote! {
+        component f(x: int) -> (y: int)
+            ensures { x[0] == y }
+        {
+            y = x;
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    assert!(grust_compiler_top::into_token_stream_res(ast, &mut ctx).is_err());
+}
+
+#[test]
+fn should_reject_non_int_bounds() {
+    let top: ir0::Top = parse_quote! {
+        component f(x: int) -> (y: int)
+            ensures { forall i in 0.0..3.0, y >= 0 }
+        {
+            y = x;
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    assert!(grust_compiler_top::into_token_stream_res(ast, &mut ctx).is_err());
+}
@@ -0,0 +1,139 @@
+grust_compiler_top::prelude! {}
+
+fn warnings(top: ir0::Top) -> Vec<String> {
+    let (ast, mut ctx) = top.init();
+    let _ = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap();
+    ctx.warnings
+        .into_iter()
+        .map(|warning| warning.0.to_string())
+        .collect()
+}
+
+#[test]
+fn should_warn_on_possible_overflow() {
+    let top: ir0::Top = parse_quote! {
+        #![range_analysis]
+        component counter(tick: bool) -> (count: int) {
+            init count = 0;
+            count = if tick then last count + 1 else last count;
+        }
+    };
+    let warnings = warnings(top);
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("overflow"));
+}
+
+#[test]
+fn should_not_warn_on_saturating_overflow() {
+    let top: ir0::Top = parse_quote! {
+        #![range_analysis]
+        component counter(tick: bool) -> (count: int) {
+            init count = 0;
+            count = if tick then last count +| 1 else last count;
+        }
+    };
+    assert!(warnings(top).is_empty());
+}
+
+#[test]
+fn should_use_requires_as_assumptions() {
+    let top: ir0::Top = parse_quote! {
+        #![range_analysis]
+        component scale(x: int, d: int) -> (y: int)
+            requires { -1000 <= x && x <= 1000 }
+            requires { 0 < d && d < 10 }
+        {
+            y = x * 1000 / d;
+        }
+    };
+    assert!(warnings(top).is_empty());
+}
+
+#[test]
+fn should_warn_on_possible_division_by_zero() {
+    let top: ir0::Top = parse_quote! {
+        #![range_analysis]
+        component scale(x: int, d: int) -> (y: int, z: int)
+            requires { -1000 <= x && x <= 1000 }
+            requires { 0 <= d && d < 10 }
+        {
+            y = x / d;
+            z = x % d;
+        }
+    };
+    let warnings = warnings(top);
+    assert_eq!(warnings.len(), 2);
+    assert!(warnings.iter().all(|w| w.contains("division by zero")));
+}
+
+#[test]
+fn should_warn_on_possible_nan() {
+    let top: ir0::Top = parse_quote! {
+        #![range_analysis]
+        use std::maths::*;
+        component norm(x: float, y: float) -> (n: float, r: float, a: float)
+            requires { 0.0 <= y }
+        {
+            n = sqrt(x);
+            r = sqrt(y);
+            a = 0.0 / y;
+        }
+    };
+    let warnings = warnings(top);
+    assert_eq!(warnings.len(), 2);
+    assert!(warnings[0].contains("sqrt"));
+    assert!(warnings[1].contains("NaN"));
+}
+
+#[test]
+fn should_compute_memory_ranges() {
+    let top: ir0::Top = parse_quote! {
+        component mode(up: bool) -> (m: int) {
+            init m = 0;
+            m = if up then 1 else if last m == 1 then 2 else 0;
+        }
+        component integrator(x: float) -> (s: float) {
+            init s = 0.0;
+            s = last s + x - last s * 0.5;
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let ir1 = ir1::from_ast(ast, &mut ctx).unwrap();
+    let ranges = ir1.range_analysis(&ctx);
+    let mode = ranges.component("mode").unwrap();
+    assert_eq!(mode.flow("m"), Some(&ir1::range::Range::int(0, 2)));
+    assert_eq!(mode.memory("m"), Some(&ir1::range::Range::int(0, 2)));
+    let integrator = ranges.component("integrator").unwrap();
+    assert_eq!(
+        integrator.memory("s"),
+        Some(&ir1::range::Range::float_top())
+    );
+    assert!(ranges.warnings.is_empty());
+}
+
+#[test]
+fn should_compute_maths_ranges() {
+    let top: ir0::Top = parse_quote! {
+        #![range_analysis]
+        use function grust::std::maths::consts::pi() -> float;
+        use function grust::std::maths::usuals::max_i(x: int, y: int) -> int;
+        component bounded(x: int) -> (y: int, p: float)
+            requires { 9223372036854775000 <= x }
+        {
+            y = max_i(x, 0);
+            p = pi();
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let ir1 = ir1::from_ast(ast, &mut ctx).unwrap();
+    let ranges = ir1.range_analysis(&ctx);
+    let bounded = ranges.component("bounded").unwrap();
+    assert_eq!(
+        bounded.flow("y"),
+        Some(&ir1::range::Range::int(
+            9223372036854775000,
+            i64::MAX as i128
+        ))
+    );
+    assert_eq!(bounded.flow("p"), Some(&ir1::range::Range::float_top()));
+}
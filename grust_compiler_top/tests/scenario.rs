@@ -0,0 +1,110 @@
+grust_compiler_top::prelude! {}
+
+fn errors(top: ir0::Top) -> Vec<String> {
+    let (ast, mut ctx) = top.init();
+    grust_compiler_top::into_token_stream_res(ast, &mut ctx)
+        .unwrap_err()
+        .into_iter()
+        .map(|error| error.0.to_string())
+        .collect()
+}
+
+#[test]
+fn should_compile_test_scenarios() {
+    let top: ir0::Top = parse_quote! {
+        #![dump = "tests/macro_outputs/scenario.rs", mode = test]
+
+        enum Braking { NoBrake, SoftBrake, UrgentBrake }
+
+        function brakes(distance: float, speed: float) -> Braking {
+            return if speed * speed / 100.0 < distance
+                then Braking::SoftBrake
+                else Braking::UrgentBrake;
+        }
+
+        component braking_state(pedest: float?, timeout_pedestrian: unit?, speed: float)
+            -> (state: Braking)
+        {
+            when {
+                init => { state = Braking::NoBrake; }
+                let d = pedest? => { state = brakes(d, speed); }
+                let _ = timeout_pedestrian? if last state == Braking::UrgentBrake => {
+                    state = Braking::SoftBrake;
+                }
+                let _ = timeout_pedestrian? => { state = Braking::NoBrake; }
+            }
+        }
+
+        test braking_nominal for braking_state {
+            step { pedest = some(10.), speed = 30. }
+            expect { state = Braking::SoftBrake }
+            step { pedest = some(5.) }
+            expect { state = Braking::UrgentBrake }
+            step { timeout_pedestrian = some(()) }
+            expect { state = Braking::SoftBrake }
+            step { pedest = none, timeout_pedestrian = some(()) }
+            expect { state = Braking::NoBrake }
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let tokens = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap();
+    if let Some(path) = ctx.conf.dump_code {
+        grust_compiler_top::dump_code(&path, &tokens).unwrap();
+    }
+}
+
+#[test]
+fn should_only_generate_scenarios_in_test_mode() {
+    let top: ir0::Top = parse_quote! {
+        component sum(x: int) -> (s: int) {
+            init s = 0;
+            s = last s + x;
+        }
+        test sum_increases for sum {
+            step { x = 1 } expect { s = 1 }
+            step { } expect { s = 2 }
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let tokens = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap();
+    assert!(!tokens.to_string().contains("sum_increases"));
+}
+
+#[test]
+fn should_reject_ill_formed_scenarios() {
+    let top: ir0::Top = parse_quote! {
+        component hold(x: int?) -> (y: int) {
+            when {
+                init => { y = 0; }
+                let v = x? => { y = v; }
+            }
+        }
+        test missing_event for hold {
+            step { x = 1 }
+        }
+    };
+    assert_eq!(
+        errors(top),
+        vec!["`x` is an event, expected `some(..)` or `none`"]
+    );
+
+    let top: ir0::Top = parse_quote! {
+        component sum(x: int, y: int) -> (s: int) {
+            s = x + y;
+        }
+        test missing_signal for sum {
+            step { x = 1 }
+        }
+    };
+    assert_eq!(errors(top), vec!["signal `y` has no value at step 0"]);
+
+    let top: ir0::Top = parse_quote! {
+        component sum(x: int, y: int) -> (s: int) {
+            s = x + y;
+        }
+        test unknown_output for sum {
+            step { x = 1, y = 2 } expect { z = 3 }
+        }
+    };
+    assert_eq!(errors(top), vec!["component `sum` has no output `z`"]);
+}
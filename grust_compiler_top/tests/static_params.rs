@@ -0,0 +1,95 @@
+grust_compiler_top::prelude! {}
+
+#[test]
+fn should_reject_ill_typed_templates_without_instances() {
+    let top: ir0::Top = parse_quote! {
+        function scale<const K: int>(x: float) -> float {
+            return K * x;
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    assert!(grust_compiler_top::into_token_stream_res(ast, &mut ctx).is_err());
+}
+
+#[test]
+fn should_reject_ill_typed_static_arguments() {
+    let top: ir0::Top = parse_quote! {
+        function sum<const N: int>(a: [float; N]) -> float {
+            return a.fold(0.0, |acc: float, x: float| acc + x);
+        }
+
+        component test(a: [float; 3]) -> (s: float) {
+            s = sum::<0.5>(a);
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    assert!(grust_compiler_top::into_token_stream_res(ast, &mut ctx).is_err());
+}
+
+#[test]
+fn should_reject_sizes_mismatching_static_arguments() {
+    let top: ir0::Top = parse_quote! {
+        function sum<const N: int>(a: [float; N]) -> float {
+            return a.fold(0.0, |acc: float, x: float| acc + x);
+        }
+
+        component test(a: [float; 3]) -> (s: float) {
+            s = sum::<4>(a);
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    assert!(grust_compiler_top::into_token_stream_res(ast, &mut ctx).is_err());
+}
+
+#[test]
+fn should_reject_templates_without_static_arguments() {
+    let top: ir0::Top = parse_quote! {
+        component counter<const START: int>(tick: bool) -> (o: int) {
+            init o = START;
+            o = if tick then last o + 1 else last o;
+        }
+
+        component test(tick: bool) -> (o: int) {
+            o = counter(tick);
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    assert!(grust_compiler_top::into_token_stream_res(ast, &mut ctx).is_err());
+}
+
+#[test]
+fn should_reject_static_arguments_of_non_templates() {
+    let top: ir0::Top = parse_quote! {
+        function double(x: int) -> int {
+            return 2 * x;
+        }
+
+        component test(i: int) -> (o: int) {
+            o = double::<2>(i);
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    assert!(grust_compiler_top::into_token_stream_res(ast, &mut ctx).is_err());
+}
+
+#[test]
+fn should_share_instances_of_equal_static_arguments() {
+    let top: ir0::Top = parse_quote! {
+        function scale<const K: float>(x: float) -> float {
+            return K * x;
+        }
+
+        component test(x: float) -> (a: float, b: float, c: float) {
+            a = scale::<1.0>(x);
+            b = scale::<1.>(x);
+            c = scale::<-0.0>(x);
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let tokens = grust_compiler_top::into_token_stream_res(ast, &mut ctx)
+        .unwrap()
+        .to_string();
+    assert_eq!(tokens.matches("fn scale_1p0 (").count(), 1);
+    assert_eq!(tokens.matches("fn scale_0p0 (").count(), 1);
+    assert!(!tokens.contains("scale_1p ("));
+}
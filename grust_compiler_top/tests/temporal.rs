@@ -0,0 +1,128 @@
+grust_compiler_top::prelude! {}
+
+#[test]
+fn should_compile_temporal_observers_greusot() {
+    let top: ir0::Top = parse_quote! {
+        #![dump = "tests/macro_outputs/temporal_greusot.rs", mode = greusot]
+
+        enum Braking { UrgentBrake, SoftBrake, NoBrake }
+
+        // Brakes when a pedestrian is detected, urgently if it persists
+        component braking(pedestrian: bool) -> (brakes: Braking)
+            ensures { pedestrian => within 3 brakes != Braking::NoBrake }
+            ensures { brakes == Braking::UrgentBrake => (last brakes != Braking::NoBrake since pedestrian) }
+            invariant { once pedestrian || historically brakes == Braking::NoBrake }
+        {
+            init brakes = Braking::NoBrake;
+            brakes = if pedestrian && last brakes != Braking::NoBrake then Braking::UrgentBrake
+                else if pedestrian then Braking::SoftBrake
+                else Braking::NoBrake;
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let tokens = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap();
+    if let Some(path) = ctx.conf.dump_code {
+        grust_compiler_top::dump_code(&path, &tokens).unwrap();
+    }
+}
+
+#[test]
+fn should_tie_temporal_observers_to_the_component_state_greusot() {
+    let top: ir0::Top = parse_quote! {
+        #![dump = "tests/macro_outputs/temporal_inductive_greusot.rs", mode = greusot]
+
+        // Brakes as soon as a pedestrian is detected
+        component braking(pedestrian: bool) -> (brakes: bool)
+            ensures { pedestrian => within 2 brakes }
+        {
+            brakes = pedestrian;
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let tokens = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap();
+    if let Some(path) = ctx.conf.dump_code {
+        grust_compiler_top::dump_code(&path, &tokens).unwrap();
+    }
+    let tokens = tokens.to_string();
+    // the observer is not an opaque component call, its equations are in the step
+    assert!(!tokens.contains("BrakingObserver0State"));
+    assert!(tokens.contains("let braking_observer_0_c1 = if brakes"));
+    // its verdict is tied to its state, making the invariant inductive
+    assert!(tokens.contains(
+        "self . last_braking_observer_0_started == > (self . last_braking_observer_0_holds == "
+    ));
+}
+
+#[test]
+fn should_compile_temporal_observers_kani() {
+    let top: ir0::Top = parse_quote! {
+        #![dump = "tests/macro_outputs/temporal_kani.rs", mode = kani, kani_depth = 4]
+
+        // Raises an alarm after two consecutive high samples
+        component alarm(x: int) -> (raised: bool)
+            requires { 0 <= x && x <= 100 }
+            ensures  { raised => historically (x >= 0) }
+            ensures  { raised => within 2 x > 90 }
+        {
+            init raised = false;
+            let high: bool = x > 90;
+            init high = false;
+            raised = high && last high;
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let tokens = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap();
+    if let Some(path) = ctx.conf.dump_code {
+        grust_compiler_top::dump_code(&path, &tokens).unwrap();
+    }
+}
+
+#[test]
+fn should_compile_temporal_observers_prop_test() {
+    let top: ir0::Top = parse_quote! {
+        #![dump = "tests/macro_outputs/temporal_prop.rs", mode = test, prop_test, prop_depth = 4]
+
+        // Raises an alarm after two consecutive high samples
+        component alarm(x: int) -> (raised: bool)
+            ensures  { raised => within 2 x > 90 }
+        {
+            init raised = false;
+            let high: bool = x > 90;
+            init high = false;
+            raised = high && last high;
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let tokens = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap();
+    if let Some(path) = ctx.conf.dump_code {
+        grust_compiler_top::dump_code(&path, &tokens).unwrap();
+    }
+}
+
+#[test]
+fn should_drop_temporal_clauses_without_contracts() {
+    let top: ir0::Top = parse_quote! {
+        component alarm(x: int) -> (raised: bool)
+            ensures { raised => once x > 90 }
+        {
+            raised = x > 90;
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    let tokens = grust_compiler_top::into_token_stream_res(ast, &mut ctx).unwrap();
+    assert!(!tokens.to_string().contains("observer"));
+}
+
+#[test]
+fn should_reject_temporal_requires() {
+    let top: ir0::Top = parse_quote! {
+        #![mode = greusot]
+        component alarm(x: int) -> (raised: bool)
+            requires { historically x >= 0 }
+        {
+            raised = x > 90;
+        }
+    };
+    let (ast, mut ctx) = top.init();
+    assert!(grust_compiler_top::into_token_stream_res(ast, &mut ctx).is_err());
+}
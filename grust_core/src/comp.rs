@@ -1,7 +1,8 @@
 /// Component trait to implement.
 ///
 /// It defines the state machine primitives of components:
-/// - [Component::init] creates a new initialized state; and
+/// - [Component::init] creates a new initialized state;
+/// - [Component::reset] re-initializes a state, as in `counter(res, tick) every r`; and
 /// - [Component::step] performs a step in the state machine, returning the output and updating the state.
 ///
 /// # Example
@@ -35,6 +36,9 @@
 ///     fn init() -> CounterState {
 ///         CounterState { last_o: 0i64 }
 ///     }
+///     fn reset(&mut self) {
+///         self.last_o = 0i64;
+///     }
 ///     fn step(&mut self, input: CounterInput) -> i64 {
 ///         let inc = if input.tick { 1i64 } else { 0i64 };
 ///         let o = if input.res {
@@ -51,5 +55,11 @@ pub trait Component {
     type Input;
     type Output;
     fn init() -> Self;
+    fn reset(&mut self)
+    where
+        Self: Sized,
+    {
+        *self = Self::init()
+    }
     fn step(&mut self, input: Self::Input) -> Self::Output;
 }
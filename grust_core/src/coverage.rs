@@ -0,0 +1,217 @@
+//! Structural coverage of components and services.
+//!
+//! In coverage mode, generated code counts the executions of each `if then else` branch, `match`
+//! and `when` arm of the components, and of each flow handler of the services. Each component and
+//! service owns a static table of [Probes], the generated `coverage_report` function gathers them
+//! in a [Report] mapped back to the GRust source.
+//!
+//! [Probes] are process-wide statics: all the instances of a component or a service share the
+//! same counters, and so do the threads running them, tests included. A test asserting coverage
+//! should call `reset_coverage` first and not run concurrently with other users of the same
+//! components, *e.g.* by living in its own integration test file.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Kind of a coverage point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Branch {
+    /// `then` branch of an `if then else`.
+    Then,
+    /// `else` branch of an `if then else`.
+    Else,
+    /// Arm of a `match` or a `when`.
+    Arm,
+    /// Flow handler of a service.
+    Handler,
+}
+impl std::fmt::Display for Branch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Then => write!(f, "then branch"),
+            Self::Else => write!(f, "else branch"),
+            Self::Arm => write!(f, "arm"),
+            Self::Handler => write!(f, "handler"),
+        }
+    }
+}
+
+/// A coverage point, located in the GRust source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Point {
+    /// Component or service owning the point.
+    pub owner: &'static str,
+    /// Kind of the point.
+    pub branch: Branch,
+    /// The condition, pattern or flow of the point.
+    pub label: &'static str,
+    /// Source file.
+    pub file: &'static str,
+    /// Source line.
+    pub line: u32,
+    /// Source column.
+    pub column: u32,
+}
+impl std::fmt::Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: `{}` {} `{}`",
+            self.file, self.line, self.column, self.owner, self.branch, self.label
+        )
+    }
+}
+
+/// Execution counters of the coverage points of a component or a service.
+///
+/// Stored in a `static`, the counters are shared by all the instances and threads of the process.
+pub struct Probes<const N: usize> {
+    points: [Point; N],
+    hits: [AtomicU64; N],
+}
+impl<const N: usize> Probes<N> {
+    /// Constructor, no point is covered.
+    pub const fn new(points: [Point; N]) -> Self {
+        Self {
+            points,
+            hits: [const { AtomicU64::new(0) }; N],
+        }
+    }
+
+    /// Counts an execution of a point.
+    #[inline]
+    pub fn hit(&self, idx: usize) {
+        self.hits[idx].fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Resets the counters.
+    pub fn reset(&self) {
+        for hits in self.hits.iter() {
+            hits.store(0, Ordering::Relaxed)
+        }
+    }
+
+    /// Points and their execution counts.
+    pub fn counts(&self) -> impl Iterator<Item = (Point, u64)> + '_ {
+        self.points
+            .iter()
+            .zip(self.hits.iter())
+            .map(|(point, hits)| (*point, hits.load(Ordering::Relaxed)))
+    }
+}
+
+/// Coverage report, the execution counts of coverage points.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Report {
+    /// Points and their execution counts.
+    pub counts: Vec<(Point, u64)>,
+}
+impl Report {
+    /// Empty report.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the counts of some probes.
+    pub fn add<const N: usize>(&mut self, probes: &Probes<N>) {
+        self.counts.extend(probes.counts())
+    }
+
+    /// Number of points.
+    pub fn total(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Number of executed points.
+    pub fn covered(&self) -> usize {
+        self.counts.iter().filter(|(_, hits)| *hits > 0).count()
+    }
+
+    /// Points never executed.
+    pub fn uncovered(&self) -> impl Iterator<Item = &Point> {
+        self.counts
+            .iter()
+            .filter(|(_, hits)| *hits == 0)
+            .map(|(point, _)| point)
+    }
+
+    /// Ratio of executed points, `1.0` when there is no point.
+    pub fn ratio(&self) -> f64 {
+        if self.counts.is_empty() {
+            1.0
+        } else {
+            self.covered() as f64 / self.total() as f64
+        }
+    }
+}
+impl std::fmt::Display for Report {
+    /// Summary followed by the points never executed.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "coverage: {}/{} points ({:.1}%)",
+            self.covered(),
+            self.total(),
+            self.ratio() * 100.0
+        )?;
+        for point in self.uncovered() {
+            writeln!(f, "  not covered: {point}")?
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    static PROBES: Probes<3> = Probes::new([
+        Point {
+            owner: "light",
+            branch: Branch::Arm,
+            label: "Light::Red",
+            file: "light.rs",
+            line: 4,
+            column: 12,
+        },
+        Point {
+            owner: "light",
+            branch: Branch::Arm,
+            label: "Light::Green",
+            file: "light.rs",
+            line: 5,
+            column: 12,
+        },
+        Point {
+            owner: "light",
+            branch: Branch::Arm,
+            label: "Light::Broken",
+            file: "light.rs",
+            line: 6,
+            column: 12,
+        },
+    ]);
+
+    #[test]
+    fn should_report_uncovered_points() {
+        PROBES.reset();
+        PROBES.hit(0);
+        PROBES.hit(0);
+        PROBES.hit(1);
+
+        let mut report = Report::new();
+        report.add(&PROBES);
+        assert_eq!(report.covered(), 2);
+        assert_eq!(report.total(), 3);
+        assert_eq!(report.counts[0].1, 2);
+        assert_eq!(
+            report.to_string(),
+            "coverage: 2/3 points (66.7%)\n  \
+            not covered: light.rs:6:12: `light` arm `Light::Broken`\n"
+        );
+
+        PROBES.reset();
+        let mut report = Report::new();
+        report.add(&PROBES);
+        assert_eq!(report.covered(), 0);
+    }
+}
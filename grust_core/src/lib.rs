@@ -3,7 +3,11 @@ pub extern crate futures;
 pub extern crate tokio;
 pub extern crate tracing;
 mod comp;
+pub mod coverage;
+pub mod mutation;
 pub mod priority_stream;
+pub mod prop;
 pub mod timer_stream;
+pub mod wide;
 
 pub use comp::Component;
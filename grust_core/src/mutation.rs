@@ -0,0 +1,160 @@
+//! Mutation testing of components.
+//!
+//! In mutation mode, the compiler lists the mutants of the components: flipped comparisons,
+//! swapped enumeration elements, `last x` replaced by `x` and negated arm guards. The generated
+//! `mutants` function returns their table, and the mutant named by the [MUTANT_VAR] environment
+//! variable at compile time is applied. [run] executes the tests of the project against each
+//! mutant, the mutants no test detects are the survivors of the [Report].
+//!
+//! ```ignore
+//! let report = grust::core::mutation::run(mutants(), || {
+//!     let mut cmd = std::process::Command::new("cargo");
+//!     cmd.args(["test", "--quiet"]).env("CARGO_TARGET_DIR", "target/mutants");
+//!     cmd
+//! })?;
+//! print!("{report}");
+//! ```
+
+use std::process::Command;
+
+/// Environment variable selecting the mutant to compile.
+pub const MUTANT_VAR: &str = "GRUST_MUTANT";
+
+/// A mutant of a component, located in the GRust source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mutant {
+    /// Identifier of the mutant, the value of [MUTANT_VAR] selecting it.
+    pub id: &'static str,
+    /// Mutated component.
+    pub owner: &'static str,
+    /// The change.
+    pub description: &'static str,
+    /// Source file.
+    pub file: &'static str,
+    /// Source line.
+    pub line: u32,
+    /// Source column.
+    pub column: u32,
+}
+impl std::fmt::Display for Mutant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: `{}` {} ({})",
+            self.file, self.line, self.column, self.owner, self.description, self.id
+        )
+    }
+}
+
+/// Mutation testing report, the mutants and whether the tests detected them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Report {
+    /// Mutants and whether they are killed.
+    pub outcomes: Vec<(Mutant, bool)>,
+}
+impl Report {
+    /// Empty report.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of mutants.
+    pub fn total(&self) -> usize {
+        self.outcomes.len()
+    }
+
+    /// Number of mutants detected by the tests.
+    pub fn killed(&self) -> usize {
+        self.outcomes.iter().filter(|(_, killed)| *killed).count()
+    }
+
+    /// Mutants not detected by the tests.
+    pub fn survivors(&self) -> impl Iterator<Item = &Mutant> {
+        self.outcomes
+            .iter()
+            .filter(|(_, killed)| !*killed)
+            .map(|(mutant, _)| mutant)
+    }
+
+    /// Ratio of killed mutants, `1.0` when there is no mutant.
+    pub fn score(&self) -> f64 {
+        if self.outcomes.is_empty() {
+            1.0
+        } else {
+            self.killed() as f64 / self.total() as f64
+        }
+    }
+}
+impl std::fmt::Display for Report {
+    /// Summary followed by the surviving mutants.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "mutation score: {}/{} mutants killed ({:.1}%)",
+            self.killed(),
+            self.total(),
+            self.score() * 100.0
+        )?;
+        for mutant in self.survivors() {
+            writeln!(f, "  survived: {mutant}")?
+        }
+        Ok(())
+    }
+}
+
+/// Runs the tests against each mutant.
+///
+/// `command` builds the test command, typically `cargo test`, run once per mutant with
+/// [MUTANT_VAR] set: the mutant is killed when the command fails. Cargo locks the target directory
+/// it builds in, so the command should use its own `CARGO_TARGET_DIR` when [run] is itself called
+/// from cargo.
+pub fn run(mutants: &[Mutant], mut command: impl FnMut() -> Command) -> std::io::Result<Report> {
+    let mut report = Report::new();
+    for mutant in mutants {
+        let status = command().env(MUTANT_VAR, mutant.id).status()?;
+        report.outcomes.push((*mutant, !status.success()));
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MUTANTS: [Mutant; 2] = [
+        Mutant {
+            id: "light#0",
+            owner: "light",
+            description: "replace `Light::Red` with `Light::Green`",
+            file: "light.rs",
+            line: 4,
+            column: 12,
+        },
+        Mutant {
+            id: "light#1",
+            owner: "light",
+            description: "replace `==` with `!=`",
+            file: "light.rs",
+            line: 5,
+            column: 12,
+        },
+    ];
+
+    #[test]
+    fn should_report_surviving_mutants() {
+        // the "tests" only detect `light#0`
+        let report = run(&MUTANTS, || {
+            let mut cmd = Command::new("sh");
+            cmd.args(["-c", &format!("test \"${MUTANT_VAR}\" != light#0")]);
+            cmd
+        })
+        .unwrap();
+        assert_eq!(report.killed(), 1);
+        assert_eq!(report.total(), 2);
+        assert_eq!(
+            report.to_string(),
+            "mutation score: 1/2 mutants killed (50.0%)\n  \
+            survived: light.rs:5:12: `light` replace `==` with `!=` (light#1)\n"
+        );
+    }
+}
@@ -0,0 +1,392 @@
+//! Property-based testing of components.
+//!
+//! Generated harnesses drive a component's `step` with random input sequences satisfying its
+//! `requires` clauses, and check its `ensures` and `invariant` clauses after each step. Failing
+//! sequences are shrunk, by removing steps and simplifying inputs, before being reported.
+//!
+//! Runs are deterministic: the generator is seeded by the component's name.
+
+use std::fmt::Debug;
+
+/// Number of tries to draw an input satisfying the `requires` clauses.
+const MAX_REJECTS: usize = 100;
+/// Maximal number of sequences run while shrinking.
+const MAX_SHRINK_RUNS: usize = 1024;
+/// Number of values checked by a `forall` quantifier.
+const WITNESSES: usize = 32;
+
+/// Pseudo-random generator (SplitMix64).
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+impl Rng {
+    /// Generator with a given seed.
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// Generator seeded by a name (FNV-1a hash).
+    pub fn from_name(name: &str) -> Self {
+        let seed = name.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        });
+        Self(seed)
+    }
+
+    /// Next random value.
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Random value in `0..n`, `n` is positive.
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    /// Random value in `lo..=hi`.
+    pub fn between(&mut self, lo: i64, hi: i64) -> i64 {
+        lo + self.below((hi - lo) as u64 + 1) as i64
+    }
+}
+
+/// Types with random values, and simpler versions of a value.
+pub trait Arbitrary: Sized + Clone + Debug {
+    /// Random value.
+    fn arbitrary(rng: &mut Rng) -> Self;
+
+    /// Simpler values, tried in order when shrinking a failing sequence.
+    fn shrink(&self) -> Vec<Self> {
+        vec![]
+    }
+}
+
+impl Arbitrary for () {
+    fn arbitrary(_: &mut Rng) -> Self {}
+}
+
+impl Arbitrary for bool {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        rng.below(2) == 1
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        if *self {
+            vec![false]
+        } else {
+            vec![]
+        }
+    }
+}
+
+impl Arbitrary for i64 {
+    /// Mostly small values, which satisfy usual `requires` clauses, and sometimes the extremes.
+    fn arbitrary(rng: &mut Rng) -> Self {
+        match rng.below(8) {
+            0 => [0, 1, -1, i64::MIN, i64::MAX][rng.below(5) as usize],
+            1 => rng.next_u64() as i64,
+            2 | 3 => rng.between(-1000, 1000),
+            _ => rng.between(-10, 10),
+        }
+    }
+
+    /// Values closer to zero.
+    fn shrink(&self) -> Vec<Self> {
+        let mut simpler = vec![];
+        for value in [0, *self / 2, *self - self.signum()] {
+            if value != *self && !simpler.contains(&value) {
+                simpler.push(value)
+            }
+        }
+        simpler
+    }
+}
+
+impl Arbitrary for f64 {
+    /// Mostly small values, never `NaN` nor infinite.
+    fn arbitrary(rng: &mut Rng) -> Self {
+        let unit = (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        match rng.below(8) {
+            0 => [0.0, 1.0, -1.0][rng.below(3) as usize],
+            1 => (unit - 0.5) * 2e9,
+            2 | 3 => (unit - 0.5) * 2e3,
+            _ => (unit - 0.5) * 20.0,
+        }
+    }
+
+    /// Values closer to zero, with less decimals.
+    fn shrink(&self) -> Vec<Self> {
+        let mut simpler = vec![];
+        for value in [0.0, self.trunc(), *self / 2.0] {
+            if value.abs() < self.abs() && !simpler.contains(&value) {
+                simpler.push(value)
+            }
+        }
+        simpler
+    }
+}
+
+impl<T: Arbitrary> Arbitrary for Option<T> {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        if rng.below(4) == 0 {
+            None
+        } else {
+            Some(T::arbitrary(rng))
+        }
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        match self {
+            None => vec![],
+            Some(value) => std::iter::once(None)
+                .chain(value.shrink().into_iter().map(Some))
+                .collect(),
+        }
+    }
+}
+
+impl<T: Arbitrary, const N: usize> Arbitrary for [T; N] {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        std::array::from_fn(|_| T::arbitrary(rng))
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        let mut simpler = vec![];
+        for (idx, element) in self.iter().enumerate() {
+            for element in element.shrink() {
+                let mut array = self.clone();
+                array[idx] = element;
+                simpler.push(array)
+            }
+        }
+        simpler
+    }
+}
+
+macro_rules! arbitrary_tuple {
+    ($($ty:ident: $idx:tt),*) => {
+        impl<$($ty: Arbitrary),*> Arbitrary for ($($ty,)*) {
+            fn arbitrary(rng: &mut Rng) -> Self {
+                ($($ty::arbitrary(rng),)*)
+            }
+
+            fn shrink(&self) -> Vec<Self> {
+                let mut simpler = vec![];
+                $(
+                    for element in self.$idx.shrink() {
+                        let mut tuple = self.clone();
+                        tuple.$idx = element;
+                        simpler.push(tuple)
+                    }
+                )*
+                simpler
+            }
+        }
+    };
+}
+arbitrary_tuple!(A: 0);
+arbitrary_tuple!(A: 0, B: 1);
+arbitrary_tuple!(A: 0, B: 1, C: 2);
+arbitrary_tuple!(A: 0, B: 1, C: 2, D: 3);
+arbitrary_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4);
+arbitrary_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+arbitrary_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6);
+arbitrary_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7);
+arbitrary_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8);
+arbitrary_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9);
+arbitrary_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9, K: 10);
+arbitrary_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9, K: 10, L: 11);
+
+/// Values checked by a `forall` quantifier over `T`.
+pub fn witnesses<T: Arbitrary>() -> Vec<T> {
+    let mut rng = Rng::new(WITNESSES as u64);
+    (0..WITNESSES).map(|_| T::arbitrary(&mut rng)).collect()
+}
+
+/// Contract violation at some step of an input sequence.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Failure {
+    /// Index of the failing step.
+    pub step: usize,
+    /// The violated clause.
+    pub msg: String,
+}
+impl Failure {
+    /// Constructor.
+    pub fn new(step: usize, msg: impl Into<String>) -> Self {
+        Self {
+            step,
+            msg: msg.into(),
+        }
+    }
+}
+
+/// Checks a component on `cases` random sequences of at most `depth` inputs.
+///
+/// Inputs are drawn until they satisfy `assume`, the component's `requires` clauses, and `run`
+/// steps the component on a sequence, checking its contract. Panics in `run`, such as integer
+/// overflows, are failures too, while inputs on which `assume` panics are rejected.
+///
+/// # Panics
+///
+/// Panics with the shrunk failing sequence if a case fails.
+pub fn check<I: Arbitrary>(
+    name: &str,
+    cases: usize,
+    depth: usize,
+    assume: impl Fn(&I) -> bool,
+    run: impl Fn(&[I]) -> Result<(), Failure>,
+) {
+    let mut rng = Rng::from_name(name);
+    let mut valid = false;
+    for _ in 0..cases {
+        let length = 1 + rng.below(depth.max(1) as u64) as usize;
+        let mut inputs = Vec::with_capacity(length);
+        'steps: for _ in 0..length {
+            for _ in 0..MAX_REJECTS {
+                let input = I::arbitrary(&mut rng);
+                if assumed(&assume, &input) {
+                    inputs.push(input);
+                    continue 'steps;
+                }
+            }
+            break;
+        }
+        valid |= !inputs.is_empty();
+        if let Some(failure) = run_caught(&run, &inputs) {
+            let (inputs, failure) = shrink(inputs, failure, &assume, &run);
+            panic!(
+                "property test of `{name}` failed at step {}: {}\nminimal failing inputs: {:#?}",
+                failure.step, failure.msg, inputs
+            )
+        }
+    }
+    assert!(
+        valid || cases == 0,
+        "property test of `{name}`: no random input satisfies the `requires` clauses"
+    )
+}
+
+/// Whether an input satisfies the `requires` clauses, panics are rejections.
+fn assumed<I: Arbitrary>(assume: &impl Fn(&I) -> bool, input: &I) -> bool {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| assume(input))).unwrap_or(false)
+}
+
+/// Runs a sequence, panics are failures at its last step.
+fn run_caught<I: Arbitrary>(
+    run: &impl Fn(&[I]) -> Result<(), Failure>,
+    inputs: &[I],
+) -> Option<Failure> {
+    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| run(inputs))) {
+        Ok(res) => res.err(),
+        Err(payload) => {
+            let msg = payload
+                .downcast_ref::<&str>()
+                .map(|msg| msg.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            let step = inputs.len().saturating_sub(1);
+            Some(Failure::new(step, format!("panicked: {msg}")))
+        }
+    }
+}
+
+/// Shrinks a failing sequence, by removing steps then simplifying its inputs.
+fn shrink<I: Arbitrary>(
+    mut inputs: Vec<I>,
+    mut failure: Failure,
+    assume: &impl Fn(&I) -> bool,
+    run: &impl Fn(&[I]) -> Result<(), Failure>,
+) -> (Vec<I>, Failure) {
+    let mut budget = MAX_SHRINK_RUNS;
+    inputs.truncate(failure.step + 1);
+    'improve: loop {
+        let mut candidates = (0..inputs.len())
+            .map(|idx| {
+                let mut candidate = inputs.clone();
+                candidate.remove(idx);
+                candidate
+            })
+            .chain((0..inputs.len()).flat_map(|idx| {
+                let inputs = &inputs;
+                inputs[idx]
+                    .shrink()
+                    .into_iter()
+                    .filter(|simpler| assumed(assume, simpler))
+                    .map(move |simpler| {
+                        let mut candidate = inputs.clone();
+                        candidate[idx] = simpler;
+                        candidate
+                    })
+            }))
+            .collect::<Vec<_>>()
+            .into_iter();
+        while budget > 0 {
+            let Some(mut candidate) = candidates.next() else {
+                break 'improve;
+            };
+            budget -= 1;
+            if let Some(smaller) = run_caught(run, &candidate) {
+                candidate.truncate(smaller.step + 1);
+                (inputs, failure) = (candidate, smaller);
+                continue 'improve;
+            }
+        }
+        break;
+    }
+    (inputs, failure)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_generate_the_same_values_from_the_same_name() {
+        let mut rng1 = Rng::from_name("counter");
+        let mut rng2 = Rng::from_name("counter");
+        let values1: Vec<i64> = (0..10).map(|_| i64::arbitrary(&mut rng1)).collect();
+        let values2: Vec<i64> = (0..10).map(|_| i64::arbitrary(&mut rng2)).collect();
+        assert_eq!(values1, values2)
+    }
+
+    #[test]
+    fn should_pass_on_valid_properties() {
+        check(
+            "abs",
+            64,
+            8,
+            |x: &(i64,)| -1000 <= x.0 && x.0 <= 1000,
+            |inputs| {
+                for (step, (x,)) in inputs.iter().enumerate() {
+                    if x.abs() < 0 {
+                        return Err(Failure::new(step, "abs is negative"));
+                    }
+                }
+                Ok(())
+            },
+        )
+    }
+
+    #[test]
+    fn should_shrink_failing_sequences() {
+        let run = |inputs: &[(i64,)]| {
+            let mut sum = 0;
+            for (step, (x,)) in inputs.iter().enumerate() {
+                sum += x;
+                if sum > 100 {
+                    return Err(Failure::new(step, "sum exceeds 100"));
+                }
+            }
+            Ok(())
+        };
+        let assume = |x: &(i64,)| 0 <= x.0 && x.0 <= 1000;
+        let inputs = vec![(5,), (600,), (3,), (7,)];
+        let failure = run(&inputs).unwrap_err();
+        let (inputs, failure) = shrink(inputs, failure, &assume, &run);
+        assert_eq!(inputs, vec![(101,)]);
+        assert_eq!(failure.step, 0);
+    }
+}
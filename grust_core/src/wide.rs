@@ -0,0 +1,29 @@
+//! Wide numbers, used by contract checkers.
+//!
+//! Contract integers are mathematical ones: Kani and property-based testing harnesses compute
+//! arithmetic operations on [i128] values, sums and products of two [i64] values cannot overflow.
+
+/// Numbers with a wide version.
+pub trait Widen {
+    /// Wide type.
+    type Wide;
+
+    /// Wide value.
+    fn widen(self) -> Self::Wide;
+}
+
+impl Widen for i64 {
+    type Wide = i128;
+
+    fn widen(self) -> i128 {
+        self as i128
+    }
+}
+
+impl Widen for f64 {
+    type Wide = f64;
+
+    fn widen(self) -> f64 {
+        self
+    }
+}
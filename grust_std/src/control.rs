@@ -0,0 +1,404 @@
+//! Control-engineering blocks.
+//!
+//! Time-aware blocks take the current time `t` (in seconds) as input, like the integrators of
+//! [crate::time::integration]. They are resilient to oversampling: a step with the same time as
+//! the previous one does not update their state.
+
+/// Clamps `x` in `[lo, hi]`.
+fn clamp(x: f64, lo: f64, hi: f64) -> f64 {
+    x.max(lo).min(hi)
+}
+
+/// PID controller with anti-windup and derivative filtering.
+///
+/// Computes `u = kp * e + ki * ∫e + kd * de/dt` where:
+/// - the derivative term is filtered by a first-order low-pass filter of time constant `tf`
+///   (`tf = 0` gives the pure derivative);
+/// - the output is saturated in `[u_min, u_max]`, and the integral is frozen while the output
+///   is saturated and the error drives it further into saturation (conditional integration).
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::control::pid(e: float, t: float, kp: float, ki: float, kd: float,
+/// tf: float, u_min: float, u_max: float) -> (u: float);`.
+pub struct PidState {
+    integral: f64,
+    derivative: f64,
+    last_e: f64,
+    last_t: f64,
+    started: bool,
+}
+pub struct PidInput {
+    pub e: f64,
+    pub t: f64,
+    pub kp: f64,
+    pub ki: f64,
+    pub kd: f64,
+    pub tf: f64,
+    pub u_min: f64,
+    pub u_max: f64,
+}
+pub struct PidOutput {
+    pub u: f64,
+}
+impl grust_core::Component for PidState {
+    type Input = PidInput;
+    type Output = PidOutput;
+
+    fn init() -> Self {
+        PidState {
+            integral: 0.,
+            derivative: 0.,
+            last_e: 0.,
+            last_t: 0.,
+            started: false,
+        }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        let dt = input.t - self.last_t;
+        let (mut integral, mut derivative) = (self.integral, self.derivative);
+        if self.started && dt > 0. {
+            integral += input.ki * input.e * dt;
+            derivative = (input.tf * self.derivative + input.kd * (input.e - self.last_e))
+                / (input.tf + dt);
+        }
+
+        let proportional = input.kp * input.e;
+        let unsaturated = proportional + integral + derivative;
+        let u = clamp(unsaturated, input.u_min, input.u_max);
+
+        // anti-windup: do not integrate further into saturation
+        let winding_up = (unsaturated > input.u_max && input.e > 0.)
+            || (unsaturated < input.u_min && input.e < 0.);
+        if winding_up {
+            integral = self.integral;
+        }
+
+        if !self.started || dt > 0. {
+            self.integral = integral;
+            self.derivative = derivative;
+            self.last_e = input.e;
+            self.last_t = input.t;
+            self.started = true;
+        }
+        Self::Output { u }
+    }
+}
+
+#[cfg(test)]
+mod pid {
+    use grust_core::Component;
+
+    use super::{PidInput, PidState};
+
+    fn input(e: f64, t: f64) -> PidInput {
+        PidInput {
+            e,
+            t,
+            kp: 2.,
+            ki: 1.,
+            kd: 0.,
+            tf: 0.,
+            u_min: -10.,
+            u_max: 10.,
+        }
+    }
+
+    #[test]
+    fn should_integrate_error() {
+        let mut pid = PidState::init();
+        assert_eq!(pid.step(input(1., 0.)).u, 2.);
+        assert_eq!(pid.step(input(1., 1.)).u, 3.);
+        assert_eq!(pid.step(input(1., 2.)).u, 4.);
+    }
+
+    #[test]
+    fn should_not_wind_up_when_saturated() {
+        let mut pid = PidState::init();
+        for t in 0..100 {
+            assert!(pid.step(input(4., t as f64)).u <= 10.);
+        }
+        // the integral did not grow while saturated: the output leaves saturation at once
+        let u = pid.step(input(-1., 100.)).u;
+        assert!(u < 10., "output still saturated: {u}");
+    }
+
+    #[test]
+    fn should_filter_derivative() {
+        let derive = |tf: f64| {
+            let mut pid = PidState::init();
+            let input = |e, t| PidInput {
+                e,
+                t,
+                kp: 0.,
+                ki: 0.,
+                kd: 1.,
+                tf,
+                u_min: -100.,
+                u_max: 100.,
+            };
+            let _ = pid.step(input(0., 0.));
+            pid.step(input(1., 0.1)).u
+        };
+        assert_eq!(derive(0.), 10.);
+        assert!(derive(0.1) < 10.);
+    }
+
+    #[test]
+    fn should_be_resilient_to_oversampling() {
+        let mut pid_1 = PidState::init();
+        let mut pid_2 = PidState::init();
+
+        let _ = (pid_1.step(input(1., 0.)), pid_2.step(input(1., 0.)));
+        let _ = pid_1.step(input(1., 0.)); // over sample
+        let (o1, o2) = (pid_1.step(input(2., 0.5)), pid_2.step(input(2., 0.5)));
+
+        assert_eq!(o1.u, o2.u)
+    }
+}
+
+/// Saturation with hysteresis.
+///
+/// The output `y` is `x` clamped in `[lo, hi]`. The flag `sat` raises when `x` reaches one of the
+/// bounds and falls only when `x` is back in `[lo + h, hi - h]`.
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::control::saturation(x: float, lo: float, hi: float, h: float)
+/// -> (y: float, sat: bool);`.
+pub struct SaturationState {
+    sat: bool,
+}
+pub struct SaturationInput {
+    pub x: f64,
+    pub lo: f64,
+    pub hi: f64,
+    pub h: f64,
+}
+pub struct SaturationOutput {
+    pub y: f64,
+    pub sat: bool,
+}
+impl grust_core::Component for SaturationState {
+    type Input = SaturationInput;
+    type Output = SaturationOutput;
+
+    fn init() -> Self {
+        SaturationState { sat: false }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        let y = clamp(input.x, input.lo, input.hi);
+        self.sat = if self.sat {
+            input.x < input.lo + input.h || input.x > input.hi - input.h
+        } else {
+            input.x <= input.lo || input.x >= input.hi
+        };
+        Self::Output { y, sat: self.sat }
+    }
+}
+
+#[cfg(test)]
+mod saturation {
+    use grust_core::Component;
+
+    use super::{SaturationInput, SaturationState};
+
+    #[test]
+    fn should_saturate_with_hysteresis() {
+        let mut saturation = SaturationState::init();
+        let mut step = |x| {
+            let output = saturation.step(SaturationInput {
+                x,
+                lo: -1.,
+                hi: 1.,
+                h: 0.5,
+            });
+            (output.y, output.sat)
+        };
+        assert_eq!(step(0.9), (0.9, false));
+        assert_eq!(step(1.5), (1., true));
+        assert_eq!(step(0.9), (0.9, true));
+        assert_eq!(step(0.4), (0.4, false));
+        assert_eq!(step(-2.), (-1., true));
+    }
+}
+
+/// Slew-rate limiter.
+///
+/// The output `y` follows `x` with a rate of at most `rising` when increasing and `falling`
+/// when decreasing (both positive, in units per second).
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::control::rate_limiter(x: float, t: float, rising: float,
+/// falling: float) -> (y: float);`.
+pub struct RateLimiterState {
+    last_y: f64,
+    last_t: f64,
+    started: bool,
+}
+pub struct RateLimiterInput {
+    pub x: f64,
+    pub t: f64,
+    pub rising: f64,
+    pub falling: f64,
+}
+pub struct RateLimiterOutput {
+    pub y: f64,
+}
+impl grust_core::Component for RateLimiterState {
+    type Input = RateLimiterInput;
+    type Output = RateLimiterOutput;
+
+    fn init() -> Self {
+        RateLimiterState {
+            last_y: 0.,
+            last_t: 0.,
+            started: false,
+        }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        let dt = input.t - self.last_t;
+        let y = if !self.started {
+            input.x
+        } else if dt > 0. {
+            clamp(
+                input.x,
+                self.last_y - input.falling * dt,
+                self.last_y + input.rising * dt,
+            )
+        } else {
+            self.last_y
+        };
+        self.last_y = y;
+        self.last_t = input.t;
+        self.started = true;
+        Self::Output { y }
+    }
+}
+
+#[cfg(test)]
+mod rate_limiter {
+    use grust_core::Component;
+
+    use super::{RateLimiterInput, RateLimiterState};
+
+    #[test]
+    fn should_limit_rising_and_falling_rates() {
+        let mut limiter = RateLimiterState::init();
+        let mut step = |x, t| {
+            limiter
+                .step(RateLimiterInput {
+                    x,
+                    t,
+                    rising: 2.,
+                    falling: 4.,
+                })
+                .y
+        };
+        assert_eq!(step(0., 0.), 0.);
+        assert_eq!(step(10., 1.), 2.);
+        assert_eq!(step(10., 1.), 2.); // over sample
+        assert_eq!(step(10., 2.), 4.);
+        assert_eq!(step(-10., 3.), 0.);
+        assert_eq!(step(1., 4.), 1.);
+    }
+}
+
+/// Bumpless transfer between automatic and manual commands.
+///
+/// The output `u` is `manual_u` when `manual` holds, `auto_u` otherwise. When switching, the
+/// output starts from its previous value and converges to the selected command, the offset
+/// decaying exponentially with time constant `tau` (`tau = 0` switches instantly).
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::control::bumpless_transfer(auto_u: float, manual_u: float,
+/// manual: bool, t: float, tau: float) -> (u: float);`.
+pub struct BumplessTransferState {
+    offset: f64,
+    last_u: f64,
+    last_manual: bool,
+    last_t: f64,
+    started: bool,
+}
+pub struct BumplessTransferInput {
+    pub auto_u: f64,
+    pub manual_u: f64,
+    pub manual: bool,
+    pub t: f64,
+    pub tau: f64,
+}
+pub struct BumplessTransferOutput {
+    pub u: f64,
+}
+impl grust_core::Component for BumplessTransferState {
+    type Input = BumplessTransferInput;
+    type Output = BumplessTransferOutput;
+
+    fn init() -> Self {
+        BumplessTransferState {
+            offset: 0.,
+            last_u: 0.,
+            last_manual: false,
+            last_t: 0.,
+            started: false,
+        }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        let command = if input.manual {
+            input.manual_u
+        } else {
+            input.auto_u
+        };
+        let dt = input.t - self.last_t;
+        let offset = if !self.started {
+            0.
+        } else if input.manual != self.last_manual {
+            // switching: start from the previous output
+            self.last_u - command
+        } else if dt > 0. && input.tau > 0. {
+            self.offset * (-dt / input.tau).exp()
+        } else if dt > 0. {
+            0.
+        } else {
+            self.offset
+        };
+        let u = command + offset;
+        self.offset = offset;
+        self.last_u = u;
+        self.last_manual = input.manual;
+        self.last_t = input.t;
+        self.started = true;
+        Self::Output { u }
+    }
+}
+
+#[cfg(test)]
+mod bumpless_transfer {
+    use grust_core::Component;
+
+    use super::{BumplessTransferInput, BumplessTransferState};
+
+    #[test]
+    fn should_not_bump_when_switching() {
+        let mut transfer = BumplessTransferState::init();
+        let mut step = |manual, t| {
+            transfer
+                .step(BumplessTransferInput {
+                    auto_u: 10.,
+                    manual_u: 2.,
+                    manual,
+                    t,
+                    tau: 1.,
+                })
+                .u
+        };
+        assert_eq!(step(false, 0.), 10.);
+        assert_eq!(step(true, 1.), 10.);
+        let u = step(true, 2.);
+        assert!(2. < u && u < 10.);
+        let u = step(true, 20.);
+        assert!((u - 2.).abs() < 1e-6);
+    }
+}
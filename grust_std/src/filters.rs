@@ -0,0 +1,510 @@
+//! Digital filters.
+//!
+//! Filters take the current time `t` (in seconds) as input, like the integrators of
+//! [crate::time::integration]. They are resilient to oversampling: a step with the same time as
+//! the previous one does not update their state.
+
+use std::collections::VecDeque;
+
+/// First-order low-pass filter.
+///
+/// Implements `tau * dy/dt + y = x` with the backward Euler method. The output starts at the
+/// first input.
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::filters::low_pass(x: float, t: float, tau: float) -> (y: float);`.
+pub struct LowPassState {
+    last_y: f64,
+    last_t: f64,
+    started: bool,
+}
+pub struct LowPassInput {
+    pub x: f64,
+    pub t: f64,
+    pub tau: f64,
+}
+pub struct LowPassOutput {
+    pub y: f64,
+}
+impl grust_core::Component for LowPassState {
+    type Input = LowPassInput;
+    type Output = LowPassOutput;
+
+    fn init() -> Self {
+        LowPassState {
+            last_y: 0.,
+            last_t: 0.,
+            started: false,
+        }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        let dt = input.t - self.last_t;
+        if !self.started {
+            self.last_y = input.x;
+        } else if dt > 0. {
+            self.last_y += dt / (input.tau + dt) * (input.x - self.last_y);
+        } else {
+            return Self::Output { y: self.last_y };
+        }
+        self.last_t = input.t;
+        self.started = true;
+        Self::Output { y: self.last_y }
+    }
+}
+
+#[cfg(test)]
+mod low_pass {
+    use grust_core::Component;
+
+    use super::{LowPassInput, LowPassState};
+
+    #[test]
+    fn should_converge_to_constant_input() {
+        let mut filter = LowPassState::init();
+        let _ = filter.step(LowPassInput {
+            x: 0.,
+            t: 0.,
+            tau: 0.5,
+        });
+        let mut y = 0.;
+        for t in 1..100 {
+            let output = filter.step(LowPassInput {
+                x: 1.,
+                t: t as f64 * 0.1,
+                tau: 0.5,
+            });
+            assert!(y < output.y && output.y <= 1.);
+            y = output.y;
+        }
+        assert!((y - 1.).abs() < 1e-6)
+    }
+}
+
+/// First-order high-pass filter.
+///
+/// Implements `y = x - low_pass(x)`, discretized with the backward Euler method: constant
+/// inputs are filtered out.
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::filters::high_pass(x: float, t: float, tau: float) -> (y: float);`.
+pub struct HighPassState {
+    last_x: f64,
+    last_y: f64,
+    last_t: f64,
+    started: bool,
+}
+pub struct HighPassInput {
+    pub x: f64,
+    pub t: f64,
+    pub tau: f64,
+}
+pub struct HighPassOutput {
+    pub y: f64,
+}
+impl grust_core::Component for HighPassState {
+    type Input = HighPassInput;
+    type Output = HighPassOutput;
+
+    fn init() -> Self {
+        HighPassState {
+            last_x: 0.,
+            last_y: 0.,
+            last_t: 0.,
+            started: false,
+        }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        let dt = input.t - self.last_t;
+        if !self.started {
+            self.last_y = 0.;
+        } else if dt > 0. {
+            self.last_y = input.tau / (input.tau + dt) * (self.last_y + input.x - self.last_x);
+        } else {
+            return Self::Output { y: self.last_y };
+        }
+        self.last_x = input.x;
+        self.last_t = input.t;
+        self.started = true;
+        Self::Output { y: self.last_y }
+    }
+}
+
+#[cfg(test)]
+mod high_pass {
+    use grust_core::Component;
+
+    use super::{HighPassInput, HighPassState};
+
+    #[test]
+    fn should_filter_out_constant_input() {
+        let mut filter = HighPassState::init();
+        let mut step = |x, t| filter.step(HighPassInput { x, t, tau: 0.5 }).y;
+        assert_eq!(step(0., 0.), 0.);
+        assert!(step(1., 0.1) > 0.5);
+        let mut y = 1.;
+        for t in 2..100 {
+            y = step(1., t as f64 * 0.1);
+        }
+        assert!(y.abs() < 1e-6)
+    }
+}
+
+/// State of second-order filters: the low-pass output and its derivative.
+struct SecondOrder {
+    y: f64,
+    v: f64,
+    last_t: f64,
+    started: bool,
+}
+impl SecondOrder {
+    fn init() -> Self {
+        SecondOrder {
+            y: 0.,
+            v: 0.,
+            last_t: 0.,
+            started: false,
+        }
+    }
+
+    /// Implements `d²y/dt² + 2 * zeta * w * dy/dt + w² * y = w² * x` with the backward Euler
+    /// method, which is stable for any time step.
+    fn step(&mut self, x: f64, t: f64, w: f64, zeta: f64) {
+        let dt = t - self.last_t;
+        if !self.started {
+            self.y = x;
+            self.v = 0.;
+        } else if dt > 0. {
+            self.v =
+                (self.v + dt * w * w * (x - self.y)) / (1. + 2. * zeta * w * dt + dt * dt * w * w);
+            self.y += dt * self.v;
+        } else {
+            return;
+        }
+        self.last_t = t;
+        self.started = true;
+    }
+}
+
+/// Second-order low-pass filter.
+///
+/// Filter of natural pulsation `w` (in radians per second) and damping ratio `zeta`. The output
+/// starts at the first input.
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::filters::low_pass2(x: float, t: float, w: float, zeta: float)
+/// -> (y: float);`.
+pub struct LowPass2State {
+    filter: SecondOrder,
+}
+pub struct LowPass2Input {
+    pub x: f64,
+    pub t: f64,
+    pub w: f64,
+    pub zeta: f64,
+}
+pub struct LowPass2Output {
+    pub y: f64,
+}
+impl grust_core::Component for LowPass2State {
+    type Input = LowPass2Input;
+    type Output = LowPass2Output;
+
+    fn init() -> Self {
+        LowPass2State {
+            filter: SecondOrder::init(),
+        }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        self.filter.step(input.x, input.t, input.w, input.zeta);
+        Self::Output { y: self.filter.y }
+    }
+}
+
+/// Second-order high-pass filter.
+///
+/// Filter of natural pulsation `w` (in radians per second) and damping ratio `zeta`, computed
+/// as `x - y - 2 * zeta / w * dy/dt` with `y` the second-order low-pass output.
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::filters::high_pass2(x: float, t: float, w: float, zeta: float)
+/// -> (y: float);`.
+pub struct HighPass2State {
+    filter: SecondOrder,
+}
+pub struct HighPass2Input {
+    pub x: f64,
+    pub t: f64,
+    pub w: f64,
+    pub zeta: f64,
+}
+pub struct HighPass2Output {
+    pub y: f64,
+}
+impl grust_core::Component for HighPass2State {
+    type Input = HighPass2Input;
+    type Output = HighPass2Output;
+
+    fn init() -> Self {
+        HighPass2State {
+            filter: SecondOrder::init(),
+        }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        self.filter.step(input.x, input.t, input.w, input.zeta);
+        let y = input.x - self.filter.y - 2. * input.zeta / input.w * self.filter.v;
+        Self::Output { y }
+    }
+}
+
+#[cfg(test)]
+mod second_order {
+    use grust_core::Component;
+
+    use super::{HighPass2Input, HighPass2State, LowPass2Input, LowPass2State};
+
+    #[test]
+    fn should_split_step_input() {
+        let mut low = LowPass2State::init();
+        let mut high = HighPass2State::init();
+        let mut step = |x, t| {
+            let (w, zeta) = (10., 0.7);
+            let y_low = low.step(LowPass2Input { x, t, w, zeta }).y;
+            let y_high = high.step(HighPass2Input { x, t, w, zeta }).y;
+            (y_low, y_high)
+        };
+        assert_eq!(step(0., 0.), (0., 0.));
+        let (y_low, y_high) = step(1., 0.01);
+        assert!(y_low < 0.1 && y_high > 0.8);
+        let (mut y_low, mut y_high) = (0., 1.);
+        for t in 2..1000 {
+            (y_low, y_high) = step(1., t as f64 * 0.01);
+        }
+        assert!((y_low - 1.).abs() < 1e-6 && y_high.abs() < 1e-6);
+    }
+}
+
+/// Samples of a sliding window of at most `n` samples, `n` being at least 1.
+struct Window {
+    samples: VecDeque<f64>,
+    last_t: f64,
+    started: bool,
+}
+impl Window {
+    fn init() -> Self {
+        Window {
+            samples: VecDeque::new(),
+            last_t: 0.,
+            started: false,
+        }
+    }
+
+    fn push(&mut self, x: f64, t: f64, n: i64) {
+        if self.started && t <= self.last_t {
+            return;
+        }
+        self.samples.push_back(x);
+        while self.samples.len() > n.max(1) as usize {
+            let _ = self.samples.pop_front();
+        }
+        self.last_t = t;
+        self.started = true;
+    }
+}
+
+/// Moving average over a window of the last `n` samples.
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::filters::moving_average(x: float, t: float, n: int) -> (y: float);`.
+pub struct MovingAverageState {
+    window: Window,
+}
+pub struct MovingAverageInput {
+    pub x: f64,
+    pub t: f64,
+    pub n: i64,
+}
+pub struct MovingAverageOutput {
+    pub y: f64,
+}
+impl grust_core::Component for MovingAverageState {
+    type Input = MovingAverageInput;
+    type Output = MovingAverageOutput;
+
+    fn init() -> Self {
+        MovingAverageState {
+            window: Window::init(),
+        }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        self.window.push(input.x, input.t, input.n);
+        let samples = &self.window.samples;
+        Self::Output {
+            y: samples.iter().sum::<f64>() / samples.len() as f64,
+        }
+    }
+}
+
+/// Median of a window of the last `n` samples.
+///
+/// With an even number of samples, the median is the mean of the two middle samples.
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::filters::median(x: float, t: float, n: int) -> (y: float);`.
+pub struct MedianState {
+    window: Window,
+}
+pub struct MedianInput {
+    pub x: f64,
+    pub t: f64,
+    pub n: i64,
+}
+pub struct MedianOutput {
+    pub y: f64,
+}
+impl grust_core::Component for MedianState {
+    type Input = MedianInput;
+    type Output = MedianOutput;
+
+    fn init() -> Self {
+        MedianState {
+            window: Window::init(),
+        }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        self.window.push(input.x, input.t, input.n);
+        let mut sorted = self.window.samples.iter().copied().collect::<Vec<_>>();
+        sorted.sort_by(f64::total_cmp);
+        let mid = sorted.len() / 2;
+        let y = if sorted.len() % 2 == 0 {
+            (sorted[mid - 1] + sorted[mid]) / 2.
+        } else {
+            sorted[mid]
+        };
+        Self::Output { y }
+    }
+}
+
+#[cfg(test)]
+mod window {
+    use grust_core::Component;
+
+    use super::{MedianInput, MedianState, MovingAverageInput, MovingAverageState};
+
+    #[test]
+    fn should_average_last_samples() {
+        let mut average = MovingAverageState::init();
+        let mut step = |x, t| average.step(MovingAverageInput { x, t, n: 3 }).y;
+        assert_eq!(step(3., 0.), 3.);
+        assert_eq!(step(6., 1.), 4.5);
+        assert_eq!(step(6., 1.), 4.5); // over sample
+        assert_eq!(step(9., 2.), 6.);
+        assert_eq!(step(0., 3.), 5.);
+    }
+
+    #[test]
+    fn should_reject_outliers() {
+        let mut median = MedianState::init();
+        let mut step = |x, t| median.step(MedianInput { x, t, n: 3 }).y;
+        assert_eq!(step(1., 0.), 1.);
+        assert_eq!(step(2., 1.), 1.5);
+        assert_eq!(step(100., 2.), 2.);
+        assert_eq!(step(3., 3.), 3.);
+    }
+}
+
+/// Discrete Kalman filter for 1-D state estimation.
+///
+/// Estimates a state following a random walk of spectral density `q` (variance per second) from
+/// measurements `z` of variance `r`. Outputs the estimate `est` and its variance `var`.
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::filters::kalman(z: float, t: float, q: float, r: float)
+/// -> (est: float, var: float);`.
+pub struct KalmanState {
+    est: f64,
+    var: f64,
+    last_t: f64,
+    started: bool,
+}
+pub struct KalmanInput {
+    pub z: f64,
+    pub t: f64,
+    pub q: f64,
+    pub r: f64,
+}
+pub struct KalmanOutput {
+    pub est: f64,
+    pub var: f64,
+}
+impl grust_core::Component for KalmanState {
+    type Input = KalmanInput;
+    type Output = KalmanOutput;
+
+    fn init() -> Self {
+        KalmanState {
+            est: 0.,
+            var: 0.,
+            last_t: 0.,
+            started: false,
+        }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        let dt = input.t - self.last_t;
+        if !self.started {
+            self.est = input.z;
+            self.var = input.r;
+        } else if dt > 0. {
+            // prediction
+            let var = self.var + input.q * dt;
+            // correction
+            let gain = var / (var + input.r);
+            self.est += gain * (input.z - self.est);
+            self.var = (1. - gain) * var;
+        }
+        if !self.started || dt > 0. {
+            self.last_t = input.t;
+            self.started = true;
+        }
+        Self::Output {
+            est: self.est,
+            var: self.var,
+        }
+    }
+}
+
+#[cfg(test)]
+mod kalman {
+    use grust_core::Component;
+
+    use super::{KalmanInput, KalmanState};
+
+    #[test]
+    fn should_reduce_variance() {
+        let mut kalman = KalmanState::init();
+        let mut step = |z, t| {
+            let output = kalman.step(KalmanInput {
+                z,
+                t,
+                q: 0.01,
+                r: 1.,
+            });
+            (output.est, output.var)
+        };
+        let (_, mut var) = step(10.5, 0.);
+        for t in 1..50 {
+            let z = if t % 2 == 0 { 10.5 } else { 9.5 };
+            let (est, new_var) = step(z, t as f64);
+            assert!(new_var <= var);
+            assert!((est - 10.).abs() < 0.6);
+            var = new_var;
+        }
+        assert!(var < 0.2);
+    }
+}
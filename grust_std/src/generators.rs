@@ -0,0 +1,452 @@
+//! Signal generators, stimuli for closed-loop simulations.
+//!
+//! Generators take the current time `t` (in seconds) as input and are defined relative to the
+//! time of their first step, so that resetting a generator (for instance with `every`) restarts
+//! its signal. They are resilient to oversampling: a step with the same time as the previous one
+//! produces the same output.
+
+use std::f64::consts::TAU;
+
+/// Time elapsed since the first step.
+#[derive(Default)]
+struct Elapsed {
+    start: Option<f64>,
+}
+impl Elapsed {
+    fn since_start(&mut self, t: f64) -> f64 {
+        t - *self.start.get_or_insert(t)
+    }
+}
+
+/// Step signal.
+///
+/// The output `y` is `initial` until `delay` seconds elapsed, `last` afterwards.
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::generators::step(t: float, delay: float, initial: float,
+/// last: float) -> (y: float);`.
+pub struct StepState {
+    elapsed: Elapsed,
+}
+pub struct StepInput {
+    pub t: f64,
+    pub delay: f64,
+    pub initial: f64,
+    pub last: f64,
+}
+pub struct StepOutput {
+    pub y: f64,
+}
+impl grust_core::Component for StepState {
+    type Input = StepInput;
+    type Output = StepOutput;
+
+    fn init() -> Self {
+        StepState {
+            elapsed: Elapsed::default(),
+        }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        let elapsed = self.elapsed.since_start(input.t);
+        let y = if elapsed < input.delay {
+            input.initial
+        } else {
+            input.last
+        };
+        Self::Output { y }
+    }
+}
+
+/// Ramp signal.
+///
+/// The output `y` is `initial` until `delay` seconds elapsed, then increases by `slope` per
+/// second. With `delay = 0`, `slope = 1` and `initial = 0`, it is the time elapsed since the first
+/// step.
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::generators::ramp(t: float, delay: float, slope: float,
+/// initial: float) -> (y: float);`.
+pub struct RampState {
+    elapsed: Elapsed,
+}
+pub struct RampInput {
+    pub t: f64,
+    pub delay: f64,
+    pub slope: f64,
+    pub initial: f64,
+}
+pub struct RampOutput {
+    pub y: f64,
+}
+impl grust_core::Component for RampState {
+    type Input = RampInput;
+    type Output = RampOutput;
+
+    fn init() -> Self {
+        RampState {
+            elapsed: Elapsed::default(),
+        }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        let elapsed = self.elapsed.since_start(input.t);
+        let y = input.initial + input.slope * (elapsed - input.delay).max(0.);
+        Self::Output { y }
+    }
+}
+
+#[cfg(test)]
+mod step_ramp {
+    use grust_core::Component;
+
+    use super::*;
+
+    #[test]
+    fn should_start_at_first_step() {
+        let mut step = StepState::init();
+        let mut ramp = RampState::init();
+        let mut generate = |t| {
+            let step = step.step(StepInput {
+                t,
+                delay: 1.,
+                initial: 0.,
+                last: 5.,
+            });
+            let ramp = ramp.step(RampInput {
+                t,
+                delay: 1.,
+                slope: 2.,
+                initial: -1.,
+            });
+            (step.y, ramp.y)
+        };
+        assert_eq!(generate(10.), (0., -1.));
+        assert_eq!(generate(10.5), (0., -1.));
+        assert_eq!(generate(11.), (5., -1.));
+        assert_eq!(generate(13.), (5., 3.));
+    }
+}
+
+/// Sine wave.
+///
+/// The output `y` is `offset + amplitude * sin(2π * frequency * elapsed + phase)`, where
+/// `frequency` is in hertz and `phase` in radians.
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::generators::sine(t: float, amplitude: float, frequency: float,
+/// phase: float, offset: float) -> (y: float);`.
+pub struct SineState {
+    elapsed: Elapsed,
+}
+pub struct SineInput {
+    pub t: f64,
+    pub amplitude: f64,
+    pub frequency: f64,
+    pub phase: f64,
+    pub offset: f64,
+}
+pub struct SineOutput {
+    pub y: f64,
+}
+impl grust_core::Component for SineState {
+    type Input = SineInput;
+    type Output = SineOutput;
+
+    fn init() -> Self {
+        SineState {
+            elapsed: Elapsed::default(),
+        }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        let elapsed = self.elapsed.since_start(input.t);
+        let y =
+            input.offset + input.amplitude * (TAU * input.frequency * elapsed + input.phase).sin();
+        Self::Output { y }
+    }
+}
+
+/// Square wave.
+///
+/// The output `y` is `offset + amplitude` during the first `duty` fraction of each `period`
+/// (in seconds), `offset - amplitude` during the rest of it.
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::generators::square(t: float, amplitude: float, period: float,
+/// duty: float, offset: float) -> (y: float);`.
+pub struct SquareState {
+    elapsed: Elapsed,
+}
+pub struct SquareInput {
+    pub t: f64,
+    pub amplitude: f64,
+    pub period: f64,
+    pub duty: f64,
+    pub offset: f64,
+}
+pub struct SquareOutput {
+    pub y: f64,
+}
+impl grust_core::Component for SquareState {
+    type Input = SquareInput;
+    type Output = SquareOutput;
+
+    fn init() -> Self {
+        SquareState {
+            elapsed: Elapsed::default(),
+        }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        let elapsed = self.elapsed.since_start(input.t);
+        let high = (elapsed / input.period).fract() < input.duty;
+        let y = if high {
+            input.offset + input.amplitude
+        } else {
+            input.offset - input.amplitude
+        };
+        Self::Output { y }
+    }
+}
+
+/// Linear chirp.
+///
+/// Sine wave of amplitude `amplitude` whose frequency sweeps linearly from `f0` to `f1` (in
+/// hertz) in `duration` seconds, and stays at `f1` afterwards.
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::generators::chirp(t: float, amplitude: float, f0: float, f1: float,
+/// duration: float) -> (y: float);`.
+pub struct ChirpState {
+    elapsed: Elapsed,
+}
+pub struct ChirpInput {
+    pub t: f64,
+    pub amplitude: f64,
+    pub f0: f64,
+    pub f1: f64,
+    pub duration: f64,
+}
+pub struct ChirpOutput {
+    pub y: f64,
+}
+impl grust_core::Component for ChirpState {
+    type Input = ChirpInput;
+    type Output = ChirpOutput;
+
+    fn init() -> Self {
+        ChirpState {
+            elapsed: Elapsed::default(),
+        }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        let elapsed = self.elapsed.since_start(input.t);
+        // number of cycles since the start, integral of the instantaneous frequency
+        let sweep = elapsed.min(input.duration);
+        let cycles = input.f0 * sweep
+            + (input.f1 - input.f0) * sweep * sweep / (2. * input.duration)
+            + input.f1 * (elapsed - sweep);
+        let y = input.amplitude * (TAU * cycles).sin();
+        Self::Output { y }
+    }
+}
+
+#[cfg(test)]
+mod periodic {
+    use grust_core::Component;
+
+    use super::*;
+
+    #[test]
+    fn should_generate_sine_wave() {
+        let mut sine = SineState::init();
+        let mut generate = |t| {
+            sine.step(SineInput {
+                t,
+                amplitude: 2.,
+                frequency: 0.25,
+                phase: 0.,
+                offset: 1.,
+            })
+            .y
+        };
+        assert_eq!(generate(5.), 1.);
+        assert!((generate(6.) - 3.).abs() < 1e-10);
+        assert!((generate(8.) + 1.).abs() < 1e-10);
+    }
+
+    #[test]
+    fn should_generate_square_wave() {
+        let mut square = SquareState::init();
+        let mut generate = |t| {
+            square
+                .step(SquareInput {
+                    t,
+                    amplitude: 1.,
+                    period: 4.,
+                    duty: 0.25,
+                    offset: 0.,
+                })
+                .y
+        };
+        assert_eq!(generate(0.), 1.);
+        assert_eq!(generate(0.5), 1.);
+        assert_eq!(generate(1.), -1.);
+        assert_eq!(generate(3.5), -1.);
+        assert_eq!(generate(4.), 1.);
+    }
+
+    #[test]
+    fn should_sweep_frequency() {
+        let mut chirp = ChirpState::init();
+        let mut zero_crossings = 0;
+        let mut last = 0.;
+        for i in 0..=10_000 {
+            let y = chirp
+                .step(ChirpInput {
+                    t: i as f64 * 1e-3,
+                    amplitude: 1.,
+                    f0: 1.,
+                    f1: 9.,
+                    duration: 10.,
+                })
+                .y;
+            if last < 0. && y >= 0. {
+                zero_crossings += 1;
+            }
+            last = y;
+        }
+        // 50 cycles in 10 seconds, from 1 to 9 hertz
+        assert_eq!(zero_crossings, 50);
+    }
+}
+
+/// Uniform pseudo-random noise.
+///
+/// The output `y` is drawn uniformly in `[-amplitude, amplitude]` at each new time. The sequence
+/// is fully determined by the `seed` read at the first step, making simulations reproducible.
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::generators::noise(t: float, seed: int, amplitude: float)
+/// -> (y: float);`.
+pub struct NoiseState {
+    state: Option<u64>,
+    last_t: f64,
+    last_sample: f64,
+}
+pub struct NoiseInput {
+    pub t: f64,
+    pub seed: i64,
+    pub amplitude: f64,
+}
+pub struct NoiseOutput {
+    pub y: f64,
+}
+impl NoiseState {
+    /// Next sample in `[-1, 1)`, using the SplitMix64 generator.
+    fn next_sample(state: &mut u64) -> f64 {
+        *state = state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = *state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^= z >> 31;
+        // 53 random bits in [0, 1)
+        let unit = (z >> 11) as f64 / (1u64 << 53) as f64;
+        2. * unit - 1.
+    }
+}
+impl grust_core::Component for NoiseState {
+    type Input = NoiseInput;
+    type Output = NoiseOutput;
+
+    fn init() -> Self {
+        NoiseState {
+            state: None,
+            last_t: 0.,
+            last_sample: 0.,
+        }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        let sample = match &mut self.state {
+            Some(_) if input.t == self.last_t => self.last_sample,
+            Some(state) => Self::next_sample(state),
+            None => Self::next_sample(self.state.insert(input.seed as u64)),
+        };
+        self.last_t = input.t;
+        self.last_sample = sample;
+        Self::Output {
+            y: input.amplitude * sample,
+        }
+    }
+}
+
+#[cfg(test)]
+mod noise {
+    use grust_core::Component;
+
+    use super::*;
+
+    fn samples(seed: i64) -> Vec<f64> {
+        let mut noise = NoiseState::init();
+        (0..100)
+            .map(|i| {
+                noise
+                    .step(NoiseInput {
+                        t: i as f64 * 0.1,
+                        seed,
+                        amplitude: 2.,
+                    })
+                    .y
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_be_reproducible() {
+        assert_eq!(samples(42), samples(42));
+        assert_ne!(samples(42), samples(43));
+    }
+
+    #[test]
+    fn should_stay_in_amplitude() {
+        let samples = samples(7);
+        assert!(samples.iter().all(|y| (-2. ..=2.).contains(y)));
+        assert!(samples.iter().any(|y| *y > 1.) && samples.iter().any(|y| *y < -1.));
+    }
+
+    #[test]
+    fn should_be_resilient_to_oversampling() {
+        let mut noise = NoiseState::init();
+        let mut generate = |t| {
+            noise
+                .step(NoiseInput {
+                    t,
+                    seed: 0,
+                    amplitude: 1.,
+                })
+                .y
+        };
+        let y = generate(0.);
+        assert_eq!(generate(0.), y);
+        assert_ne!(generate(1.), y);
+    }
+}
+
+/// Piecewise-linear profile.
+///
+/// The output is the value of the profile going through the points `(ts[i], values[i])` at time
+/// `t`, constant before the first and after the last point. The times `ts` must be increasing,
+/// which is checked at compile time when the table is a `#[monotonic]` constant.
+///
+/// As array sizes are part of component types, profiles are functions rather than components.
+/// The time origin can be given by a [RampState] of slope `1`.
+///
+/// Called in `grust!` macro via
+/// `use function grust::std::generators::profile(ts: [float; N], values: [float; N], t: float)
+/// -> float;` where `N` is the number of points.
+pub fn profile<const N: usize>(ts: [f64; N], values: [f64; N], t: f64) -> f64 {
+    crate::lut::interp1(ts, values, t)
+}
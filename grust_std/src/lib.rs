@@ -1,5 +1,10 @@
 #![allow(missing_docs)]
 pub extern crate rayon;
+pub mod control;
+pub mod filters;
+pub mod generators;
+pub mod logic;
+pub mod lut;
 pub mod maths;
 pub mod rising_edge;
 pub mod time;
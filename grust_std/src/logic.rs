@@ -0,0 +1,615 @@
+//! IEC 61131-3 style logic blocks: edge detectors, latches, timers and counters.
+//!
+//! Timers take the current time `t` (in seconds) as input, like the integrators of
+//! [crate::time::integration], and their preset time `pt` in seconds.
+
+/// Rising edge detector.
+///
+/// The output `y` holds when `x` holds and did not at the previous step. As the memory of `x`
+/// starts `false`, `y` holds at the first step if `x` holds.
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::logic::rising_edge(x: bool) -> (y: bool);`.
+pub struct RisingEdgeState {
+    last_x: bool,
+}
+pub struct RisingEdgeInput {
+    pub x: bool,
+}
+pub struct RisingEdgeOutput {
+    pub y: bool,
+}
+impl grust_core::Component for RisingEdgeState {
+    type Input = RisingEdgeInput;
+    type Output = RisingEdgeOutput;
+
+    fn init() -> Self {
+        RisingEdgeState { last_x: false }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        let y = input.x && !self.last_x;
+        self.last_x = input.x;
+        Self::Output { y }
+    }
+}
+
+/// Falling edge detector.
+///
+/// The output `y` holds when `x` does not hold but did at the previous step. As the memory of
+/// `x` starts `false`, `y` never holds at the first step.
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::logic::falling_edge(x: bool) -> (y: bool);`.
+pub struct FallingEdgeState {
+    last_x: bool,
+}
+pub struct FallingEdgeInput {
+    pub x: bool,
+}
+pub struct FallingEdgeOutput {
+    pub y: bool,
+}
+impl grust_core::Component for FallingEdgeState {
+    type Input = FallingEdgeInput;
+    type Output = FallingEdgeOutput;
+
+    fn init() -> Self {
+        FallingEdgeState { last_x: false }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        let y = !input.x && self.last_x;
+        self.last_x = input.x;
+        Self::Output { y }
+    }
+}
+
+/// Edge detector, rising or falling.
+///
+/// The output `y` holds when `x` differs from its value at the previous step (starting `false`).
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::logic::any_edge(x: bool) -> (y: bool);`.
+pub struct AnyEdgeState {
+    last_x: bool,
+}
+pub struct AnyEdgeInput {
+    pub x: bool,
+}
+pub struct AnyEdgeOutput {
+    pub y: bool,
+}
+impl grust_core::Component for AnyEdgeState {
+    type Input = AnyEdgeInput;
+    type Output = AnyEdgeOutput;
+
+    fn init() -> Self {
+        AnyEdgeState { last_x: false }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        let y = input.x != self.last_x;
+        self.last_x = input.x;
+        Self::Output { y }
+    }
+}
+
+#[cfg(test)]
+mod edges {
+    use grust_core::Component;
+
+    use super::*;
+
+    #[test]
+    fn should_detect_edges() {
+        let mut rising = RisingEdgeState::init();
+        let mut falling = FallingEdgeState::init();
+        let mut any = AnyEdgeState::init();
+        let mut step = |x| {
+            (
+                rising.step(RisingEdgeInput { x }).y,
+                falling.step(FallingEdgeInput { x }).y,
+                any.step(AnyEdgeInput { x }).y,
+            )
+        };
+        assert_eq!(step(false), (false, false, false));
+        assert_eq!(step(true), (true, false, true));
+        assert_eq!(step(true), (false, false, false));
+        assert_eq!(step(false), (false, true, true));
+        assert_eq!(step(false), (false, false, false));
+    }
+
+    #[test]
+    fn should_detect_rising_edge_at_first_step() {
+        let mut rising = RisingEdgeState::init();
+        assert!(rising.step(RisingEdgeInput { x: true }).y);
+        let mut falling = FallingEdgeState::init();
+        assert!(!falling.step(FallingEdgeInput { x: false }).y);
+    }
+}
+
+/// Set-dominant bistable (SR latch).
+///
+/// The output `q` raises when `s` holds and falls when `r` holds alone.
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::logic::sr(s: bool, r: bool) -> (q: bool);`.
+pub struct SrState {
+    q: bool,
+}
+pub struct SrInput {
+    pub s: bool,
+    pub r: bool,
+}
+pub struct SrOutput {
+    pub q: bool,
+}
+impl grust_core::Component for SrState {
+    type Input = SrInput;
+    type Output = SrOutput;
+
+    fn init() -> Self {
+        SrState { q: false }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        self.q = input.s || (self.q && !input.r);
+        Self::Output { q: self.q }
+    }
+}
+
+/// Reset-dominant bistable (RS latch).
+///
+/// The output `q` falls when `r` holds and raises when `s` holds alone.
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::logic::rs(s: bool, r: bool) -> (q: bool);`.
+pub struct RsState {
+    q: bool,
+}
+pub struct RsInput {
+    pub s: bool,
+    pub r: bool,
+}
+pub struct RsOutput {
+    pub q: bool,
+}
+impl grust_core::Component for RsState {
+    type Input = RsInput;
+    type Output = RsOutput;
+
+    fn init() -> Self {
+        RsState { q: false }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        self.q = !input.r && (input.s || self.q);
+        Self::Output { q: self.q }
+    }
+}
+
+#[cfg(test)]
+mod latches {
+    use grust_core::Component;
+
+    use super::*;
+
+    #[test]
+    fn should_latch_with_set_or_reset_dominance() {
+        let mut sr = SrState::init();
+        let mut rs = RsState::init();
+        let mut step = |s, r| (sr.step(SrInput { s, r }).q, rs.step(RsInput { s, r }).q);
+        assert_eq!(step(false, false), (false, false));
+        assert_eq!(step(true, false), (true, true));
+        assert_eq!(step(false, false), (true, true));
+        assert_eq!(step(true, true), (true, false));
+        assert_eq!(step(false, true), (false, false));
+    }
+}
+
+/// On-delay timer (TON).
+///
+/// The output `q` raises once `x` held for `pt` seconds, and falls with `x`. The elapsed time
+/// `et` counts from the rising edge of `x`, up to `pt`, and is reset when `x` falls.
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::logic::ton(x: bool, pt: float, t: float) -> (q: bool, et: float);`.
+pub struct TonState {
+    start: Option<f64>,
+}
+pub struct TonInput {
+    pub x: bool,
+    pub pt: f64,
+    pub t: f64,
+}
+pub struct TonOutput {
+    pub q: bool,
+    pub et: f64,
+}
+impl grust_core::Component for TonState {
+    type Input = TonInput;
+    type Output = TonOutput;
+
+    fn init() -> Self {
+        TonState { start: None }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        if !input.x {
+            self.start = None;
+            return Self::Output { q: false, et: 0. };
+        }
+        let start = *self.start.get_or_insert(input.t);
+        let et = (input.t - start).min(input.pt);
+        Self::Output {
+            q: et >= input.pt,
+            et,
+        }
+    }
+}
+
+/// Off-delay timer (TOF).
+///
+/// The output `q` raises with `x`, and falls once `x` did not hold for `pt` seconds. The elapsed
+/// time `et` counts from the falling edge of `x`, up to `pt`, and is reset when `x` raises.
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::logic::tof(x: bool, pt: float, t: float) -> (q: bool, et: float);`.
+pub struct TofState {
+    q: bool,
+    start: Option<f64>,
+}
+pub struct TofInput {
+    pub x: bool,
+    pub pt: f64,
+    pub t: f64,
+}
+pub struct TofOutput {
+    pub q: bool,
+    pub et: f64,
+}
+impl grust_core::Component for TofState {
+    type Input = TofInput;
+    type Output = TofOutput;
+
+    fn init() -> Self {
+        TofState {
+            q: false,
+            start: None,
+        }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        if input.x {
+            self.q = true;
+            self.start = None;
+            return Self::Output { q: true, et: 0. };
+        }
+        if !self.q {
+            return Self::Output { q: false, et: 0. };
+        }
+        let start = *self.start.get_or_insert(input.t);
+        let et = (input.t - start).min(input.pt);
+        self.q = et < input.pt;
+        Self::Output { q: self.q, et }
+    }
+}
+
+/// Pulse timer (TP).
+///
+/// On a rising edge of `x`, the output `q` holds for `pt` seconds whatever `x` does in the
+/// meantime: the pulse is not retriggerable. The elapsed time `et` counts from the start of the
+/// pulse, up to `pt`, and is reset when both the pulse is over and `x` does not hold.
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::logic::tp(x: bool, pt: float, t: float) -> (q: bool, et: float);`.
+pub struct TpState {
+    last_x: bool,
+    start: Option<f64>,
+}
+pub struct TpInput {
+    pub x: bool,
+    pub pt: f64,
+    pub t: f64,
+}
+pub struct TpOutput {
+    pub q: bool,
+    pub et: f64,
+}
+impl grust_core::Component for TpState {
+    type Input = TpInput;
+    type Output = TpOutput;
+
+    fn init() -> Self {
+        TpState {
+            last_x: false,
+            start: None,
+        }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        if self.start.is_none() && input.x && !self.last_x {
+            self.start = Some(input.t);
+        }
+        self.last_x = input.x;
+        let Some(start) = self.start else {
+            return Self::Output { q: false, et: 0. };
+        };
+        let et = (input.t - start).min(input.pt);
+        let q = et < input.pt;
+        if !q && !input.x {
+            self.start = None;
+        }
+        Self::Output { q, et }
+    }
+}
+
+#[cfg(test)]
+mod timers {
+    use grust_core::Component;
+
+    use super::*;
+
+    #[test]
+    fn should_delay_rising_edges() {
+        let mut ton = TonState::init();
+        let mut step = |x, t| {
+            let output = ton.step(TonInput { x, pt: 2., t });
+            (output.q, output.et)
+        };
+        assert_eq!(step(false, 0.), (false, 0.));
+        assert_eq!(step(true, 1.), (false, 0.));
+        assert_eq!(step(true, 2.), (false, 1.));
+        assert_eq!(step(true, 3.), (true, 2.));
+        assert_eq!(step(true, 10.), (true, 2.));
+        assert_eq!(step(false, 11.), (false, 0.));
+        assert_eq!(step(true, 12.), (false, 0.));
+    }
+
+    #[test]
+    fn should_delay_falling_edges() {
+        let mut tof = TofState::init();
+        let mut step = |x, t| {
+            let output = tof.step(TofInput { x, pt: 2., t });
+            (output.q, output.et)
+        };
+        assert_eq!(step(false, 0.), (false, 0.));
+        assert_eq!(step(true, 1.), (true, 0.));
+        assert_eq!(step(false, 2.), (true, 0.));
+        assert_eq!(step(false, 3.), (true, 1.));
+        assert_eq!(step(true, 3.5), (true, 0.));
+        assert_eq!(step(false, 4.), (true, 0.));
+        assert_eq!(step(false, 6.), (false, 2.));
+        assert_eq!(step(false, 7.), (false, 0.));
+    }
+
+    #[test]
+    fn should_generate_non_retriggerable_pulses() {
+        let mut tp = TpState::init();
+        let mut step = |x, t| {
+            let output = tp.step(TpInput { x, pt: 2., t });
+            (output.q, output.et)
+        };
+        assert_eq!(step(false, 0.), (false, 0.));
+        assert_eq!(step(true, 1.), (true, 0.));
+        assert_eq!(step(false, 2.), (true, 1.));
+        // retriggering during the pulse is ignored
+        assert_eq!(step(true, 2.5), (true, 1.5));
+        assert_eq!(step(true, 3.), (false, 2.));
+        // `x` still holds: no new pulse
+        assert_eq!(step(true, 4.), (false, 2.));
+        assert_eq!(step(false, 5.), (false, 2.));
+        assert_eq!(step(false, 6.), (false, 0.));
+        assert_eq!(step(true, 7.), (true, 0.));
+    }
+}
+
+/// Up counter (CTU).
+///
+/// The count `cv` increments on each rising edge of `cu` and is reset to `0` when `reset` holds.
+/// The output `q` holds when `cv` reached the preset `pv`.
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::logic::ctu(cu: bool, reset: bool, pv: int) -> (q: bool, cv: int);`.
+pub struct CtuState {
+    last_cu: bool,
+    cv: i64,
+}
+pub struct CtuInput {
+    pub cu: bool,
+    pub reset: bool,
+    pub pv: i64,
+}
+pub struct CtuOutput {
+    pub q: bool,
+    pub cv: i64,
+}
+impl grust_core::Component for CtuState {
+    type Input = CtuInput;
+    type Output = CtuOutput;
+
+    fn init() -> Self {
+        CtuState {
+            last_cu: false,
+            cv: 0,
+        }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        if input.reset {
+            self.cv = 0;
+        } else if input.cu && !self.last_cu {
+            self.cv = self.cv.saturating_add(1);
+        }
+        self.last_cu = input.cu;
+        Self::Output {
+            q: self.cv >= input.pv,
+            cv: self.cv,
+        }
+    }
+}
+
+/// Down counter (CTD).
+///
+/// The count `cv` decrements on each rising edge of `cd` and is set to the preset `pv` when
+/// `load` holds. The output `q` holds when `cv` reached `0`.
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::logic::ctd(cd: bool, load: bool, pv: int) -> (q: bool, cv: int);`.
+pub struct CtdState {
+    last_cd: bool,
+    cv: i64,
+}
+pub struct CtdInput {
+    pub cd: bool,
+    pub load: bool,
+    pub pv: i64,
+}
+pub struct CtdOutput {
+    pub q: bool,
+    pub cv: i64,
+}
+impl grust_core::Component for CtdState {
+    type Input = CtdInput;
+    type Output = CtdOutput;
+
+    fn init() -> Self {
+        CtdState {
+            last_cd: false,
+            cv: 0,
+        }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        if input.load {
+            self.cv = input.pv;
+        } else if input.cd && !self.last_cd {
+            self.cv = self.cv.saturating_sub(1);
+        }
+        self.last_cd = input.cd;
+        Self::Output {
+            q: self.cv <= 0,
+            cv: self.cv,
+        }
+    }
+}
+
+/// Up/down counter (CTUD).
+///
+/// The count `cv` increments on rising edges of `cu` and decrements on rising edges of `cd`
+/// (both edges at once cancel out). It is reset to `0` when `reset` holds, else set to the
+/// preset `pv` when `load` holds. The output `qu` holds when `cv` reached `pv`, `qd` when it
+/// reached `0`.
+///
+/// Called in `grust!` macro via
+/// `use component grust::std::logic::ctud(cu: bool, cd: bool, reset: bool, load: bool, pv: int)
+/// -> (qu: bool, qd: bool, cv: int);`.
+pub struct CtudState {
+    last_cu: bool,
+    last_cd: bool,
+    cv: i64,
+}
+pub struct CtudInput {
+    pub cu: bool,
+    pub cd: bool,
+    pub reset: bool,
+    pub load: bool,
+    pub pv: i64,
+}
+pub struct CtudOutput {
+    pub qu: bool,
+    pub qd: bool,
+    pub cv: i64,
+}
+impl grust_core::Component for CtudState {
+    type Input = CtudInput;
+    type Output = CtudOutput;
+
+    fn init() -> Self {
+        CtudState {
+            last_cu: false,
+            last_cd: false,
+            cv: 0,
+        }
+    }
+
+    fn step(&mut self, input: Self::Input) -> Self::Output {
+        if input.reset {
+            self.cv = 0;
+        } else if input.load {
+            self.cv = input.pv;
+        } else {
+            let up = input.cu && !self.last_cu;
+            let down = input.cd && !self.last_cd;
+            if up && !down {
+                self.cv = self.cv.saturating_add(1);
+            } else if down && !up {
+                self.cv = self.cv.saturating_sub(1);
+            }
+        }
+        self.last_cu = input.cu;
+        self.last_cd = input.cd;
+        Self::Output {
+            qu: self.cv >= input.pv,
+            qd: self.cv <= 0,
+            cv: self.cv,
+        }
+    }
+}
+
+#[cfg(test)]
+mod counters {
+    use grust_core::Component;
+
+    use super::*;
+
+    #[test]
+    fn should_count_rising_edges_up() {
+        let mut ctu = CtuState::init();
+        let mut step = |cu, reset| {
+            let output = ctu.step(CtuInput { cu, reset, pv: 2 });
+            (output.q, output.cv)
+        };
+        assert_eq!(step(true, false), (false, 1));
+        assert_eq!(step(true, false), (false, 1));
+        assert_eq!(step(false, false), (false, 1));
+        assert_eq!(step(true, false), (true, 2));
+        assert_eq!(step(false, true), (false, 0));
+    }
+
+    #[test]
+    fn should_count_rising_edges_down_from_preset() {
+        let mut ctd = CtdState::init();
+        let mut step = |cd, load| {
+            let output = ctd.step(CtdInput { cd, load, pv: 2 });
+            (output.q, output.cv)
+        };
+        assert_eq!(step(false, false), (true, 0));
+        assert_eq!(step(false, true), (false, 2));
+        assert_eq!(step(true, false), (false, 1));
+        assert_eq!(step(false, false), (false, 1));
+        assert_eq!(step(true, false), (true, 0));
+    }
+
+    #[test]
+    fn should_count_up_and_down() {
+        let mut ctud = CtudState::init();
+        let mut step = |cu, cd, reset, load| {
+            let output = ctud.step(CtudInput {
+                cu,
+                cd,
+                reset,
+                load,
+                pv: 3,
+            });
+            (output.qu, output.qd, output.cv)
+        };
+        assert_eq!(step(false, false, false, true), (true, false, 3));
+        assert_eq!(step(false, true, false, false), (false, false, 2));
+        assert_eq!(step(true, false, false, false), (true, false, 3));
+        assert_eq!(step(false, false, false, false), (true, false, 3));
+        assert_eq!(step(true, true, false, false), (true, false, 3));
+        assert_eq!(step(false, false, true, true), (false, true, 0));
+    }
+}
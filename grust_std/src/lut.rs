@@ -0,0 +1,179 @@
+//! Lookup tables.
+//!
+//! Tables are given by strictly increasing breakpoints and the values at those breakpoints.
+//! Between breakpoints, values are linearly interpolated. Outside of the breakpoints,
+//! [interp1] and [interp2] return the value at the nearest breakpoint while the `_extrap`
+//! variants extend the first/last segment.
+//!
+//! In `grust!` macro, breakpoints and tables are best declared as `#[monotonic]` constants, for
+//! which the compiler checks that breakpoints are strictly increasing:
+//! ```text
+//! #[monotonic]
+//! const SPEEDS: [float; 4] = [0.0, 10.0, 20.0, 30.0];
+//! ```
+
+/// Segment of the breakpoints `xs` to use for `x`.
+///
+/// Returns the index `i` of the segment `[xs[i], xs[i + 1]]` and the position of `x` in this
+/// segment (`0` at `xs[i]`, `1` at `xs[i + 1]`). When `extrapolate` is false, the position is
+/// clamped in `[0, 1]`.
+fn segment(xs: &[f64], x: f64, extrapolate: bool) -> (usize, f64) {
+    let last = xs.len().saturating_sub(2);
+    let i = xs[1..xs.len() - 1].partition_point(|bp| *bp <= x).min(last);
+    let ratio = (x - xs[i]) / (xs[i + 1] - xs[i]);
+    if extrapolate {
+        (i, ratio)
+    } else {
+        (i, ratio.clamp(0., 1.))
+    }
+}
+
+/// Linear interpolation between `a` and `b`.
+fn lerp(a: f64, b: f64, ratio: f64) -> f64 {
+    a + ratio * (b - a)
+}
+
+fn lookup1(xs: &[f64], ys: &[f64], x: f64, extrapolate: bool) -> f64 {
+    match xs.len() {
+        0 => f64::NAN,
+        1 => ys[0],
+        _ => {
+            let (i, ratio) = segment(xs, x, extrapolate);
+            lerp(ys[i], ys[i + 1], ratio)
+        }
+    }
+}
+
+fn lookup2<const N: usize, const M: usize>(
+    xs: &[f64; N],
+    ys: &[f64; M],
+    table: &[[f64; M]; N],
+    x: f64,
+    y: f64,
+    extrapolate: bool,
+) -> f64 {
+    match N {
+        0 => f64::NAN,
+        1 => lookup1(ys, &table[0], y, extrapolate),
+        _ => {
+            let (i, ratio) = segment(xs, x, extrapolate);
+            let low = lookup1(ys, &table[i], y, extrapolate);
+            let high = lookup1(ys, &table[i + 1], y, extrapolate);
+            lerp(low, high, ratio)
+        }
+    }
+}
+
+/// 1-D lookup table, clamped to the table's values outside of the breakpoints.
+///
+/// Called in `grust!` macro via
+/// `use function grust::std::lut::interp1(xs: [float; N], ys: [float; N], x: float) -> float;`
+/// where `N` is the size of the table.
+pub fn interp1<const N: usize>(xs: [f64; N], ys: [f64; N], x: f64) -> f64 {
+    lookup1(&xs, &ys, x, false)
+}
+
+/// 1-D lookup table, linearly extrapolated outside of the breakpoints.
+///
+/// Called in `grust!` macro via
+/// `use function grust::std::lut::interp1_extrap(xs: [float; N], ys: [float; N], x: float)
+/// -> float;` where `N` is the size of the table.
+pub fn interp1_extrap<const N: usize>(xs: [f64; N], ys: [f64; N], x: f64) -> f64 {
+    lookup1(&xs, &ys, x, true)
+}
+
+/// Bilinear 2-D lookup table, clamped to the table's values outside of the breakpoints.
+///
+/// The value at `(xs[i], ys[j])` is `table[i][j]`.
+///
+/// Called in `grust!` macro via
+/// `use function grust::std::lut::interp2(xs: [float; N], ys: [float; M],
+/// table: [[float; M]; N], x: float, y: float) -> float;`.
+pub fn interp2<const N: usize, const M: usize>(
+    xs: [f64; N],
+    ys: [f64; M],
+    table: [[f64; M]; N],
+    x: f64,
+    y: f64,
+) -> f64 {
+    lookup2(&xs, &ys, &table, x, y, false)
+}
+
+/// Bilinear 2-D lookup table, linearly extrapolated outside of the breakpoints.
+///
+/// The value at `(xs[i], ys[j])` is `table[i][j]`.
+///
+/// Called in `grust!` macro via
+/// `use function grust::std::lut::interp2_extrap(xs: [float; N], ys: [float; M],
+/// table: [[float; M]; N], x: float, y: float) -> float;`.
+pub fn interp2_extrap<const N: usize, const M: usize>(
+    xs: [f64; N],
+    ys: [f64; M],
+    table: [[f64; M]; N],
+    x: f64,
+    y: f64,
+) -> f64 {
+    lookup2(&xs, &ys, &table, x, y, true)
+}
+
+#[cfg(test)]
+mod interp1 {
+    use super::{interp1, interp1_extrap};
+
+    const XS: [f64; 4] = [0., 1., 2., 4.];
+    const YS: [f64; 4] = [0., 10., 5., 25.];
+
+    #[test]
+    fn should_interpolate_between_breakpoints() {
+        assert_eq!(interp1(XS, YS, 0.), 0.);
+        assert_eq!(interp1(XS, YS, 0.5), 5.);
+        assert_eq!(interp1(XS, YS, 1.), 10.);
+        assert_eq!(interp1(XS, YS, 1.5), 7.5);
+        assert_eq!(interp1(XS, YS, 3.), 15.);
+        assert_eq!(interp1(XS, YS, 4.), 25.);
+    }
+
+    #[test]
+    fn should_clamp_outside_of_breakpoints() {
+        assert_eq!(interp1(XS, YS, -1.), 0.);
+        assert_eq!(interp1(XS, YS, 10.), 25.);
+    }
+
+    #[test]
+    fn should_extrapolate_outside_of_breakpoints() {
+        assert_eq!(interp1_extrap(XS, YS, -1.), -10.);
+        assert_eq!(interp1_extrap(XS, YS, 6.), 45.);
+        assert_eq!(interp1_extrap(XS, YS, 3.), 15.);
+    }
+
+    #[test]
+    fn should_handle_single_breakpoint() {
+        assert_eq!(interp1([1.], [3.], 0.), 3.);
+        assert_eq!(interp1_extrap([1.], [3.], 5.), 3.);
+    }
+}
+
+#[cfg(test)]
+mod interp2 {
+    use super::{interp2, interp2_extrap};
+
+    const XS: [f64; 2] = [0., 1.];
+    const YS: [f64; 3] = [0., 1., 2.];
+    const TABLE: [[f64; 3]; 2] = [[0., 1., 2.], [10., 11., 12.]];
+
+    #[test]
+    fn should_interpolate_bilinearly() {
+        assert_eq!(interp2(XS, YS, TABLE, 0., 1.), 1.);
+        assert_eq!(interp2(XS, YS, TABLE, 1., 2.), 12.);
+        assert_eq!(interp2(XS, YS, TABLE, 0.5, 0.5), 5.5);
+        assert_eq!(interp2(XS, YS, TABLE, 0.25, 1.5), 4.);
+    }
+
+    #[test]
+    fn should_clamp_or_extrapolate_outside_of_breakpoints() {
+        assert_eq!(interp2(XS, YS, TABLE, 2., 3.), 12.);
+        assert_eq!(interp2(XS, YS, TABLE, -1., -1.), 0.);
+        assert_eq!(interp2_extrap(XS, YS, TABLE, 2., 3.), 23.);
+        assert_eq!(interp2_extrap(XS, YS, TABLE, -1., -1.), -11.);
+    }
+}
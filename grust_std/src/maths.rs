@@ -466,3 +466,284 @@ pub mod usuals {
         }
     }
 }
+
+/// Vectors as fixed-size arrays `[float; N]`.
+///
+/// Used in `grust!` macro via `use function`, giving the concrete size of the arrays, for instance
+/// `use function grust::std::maths::vector::dot(u: [float; 3], v: [float; 3]) -> float;`.
+pub mod vector {
+    /// Dot product of two vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use grust_std::maths::vector;
+    ///
+    /// assert_eq!(vector::dot([1.0, 2.0, 3.0], [4.0, -5.0, 6.0]), 12.0);
+    /// ```
+    #[inline]
+    pub fn dot<const N: usize>(u: [f64; N], v: [f64; N]) -> f64 {
+        u.iter().zip(v.iter()).map(|(u, v)| u * v).sum()
+    }
+
+    /// Cross product of two 3-D vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use grust_std::maths::vector;
+    ///
+    /// assert_eq!(vector::cross([1.0, 0.0, 0.0], [0.0, 1.0, 0.0]), [0.0, 0.0, 1.0]);
+    /// ```
+    #[inline]
+    pub fn cross(u: [f64; 3], v: [f64; 3]) -> [f64; 3] {
+        [
+            u[1] * v[2] - u[2] * v[1],
+            u[2] * v[0] - u[0] * v[2],
+            u[0] * v[1] - u[1] * v[0],
+        ]
+    }
+
+    /// Euclidean norm of a vector.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use grust_std::maths::vector;
+    ///
+    /// assert_eq!(vector::norm([3.0, 4.0]), 5.0);
+    /// ```
+    #[inline]
+    pub fn norm<const N: usize>(u: [f64; N]) -> f64 {
+        dot(u, u).sqrt()
+    }
+
+    /// Euclidean distance between two points.
+    #[inline]
+    pub fn distance<const N: usize>(u: [f64; N], v: [f64; N]) -> f64 {
+        norm(sub(u, v))
+    }
+
+    /// Unit vector with the direction of `u`.
+    ///
+    /// The null vector is returned unchanged.
+    #[inline]
+    pub fn normalize<const N: usize>(u: [f64; N]) -> [f64; N] {
+        let norm = norm(u);
+        if norm == 0. {
+            u
+        } else {
+            scale(u, 1. / norm)
+        }
+    }
+
+    /// Sum of two vectors.
+    #[inline]
+    pub fn add<const N: usize>(u: [f64; N], v: [f64; N]) -> [f64; N] {
+        std::array::from_fn(|i| u[i] + v[i])
+    }
+
+    /// Difference of two vectors.
+    #[inline]
+    pub fn sub<const N: usize>(u: [f64; N], v: [f64; N]) -> [f64; N] {
+        std::array::from_fn(|i| u[i] - v[i])
+    }
+
+    /// Product of a vector by a scalar.
+    #[inline]
+    pub fn scale<const N: usize>(u: [f64; N], k: f64) -> [f64; N] {
+        u.map(|x| k * x)
+    }
+}
+
+/// Row-major matrices as fixed-size arrays `[[float; M]; N]` (`N` rows of `M` columns).
+///
+/// Used in `grust!` macro via `use function`, giving the concrete size of the arrays, for instance
+/// `use function grust::std::maths::matrix::inverse3(a: [[float; 3]; 3]) -> [[float; 3]; 3];`.
+pub mod matrix {
+    /// Product of a `N×M` matrix by a `M×P` matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use grust_std::maths::matrix;
+    ///
+    /// let a = [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+    /// let b = [[1.0, 0.0, 1.0], [0.0, 1.0, 1.0]];
+    /// assert_eq!(
+    ///     matrix::mul(a, b),
+    ///     [[1.0, 2.0, 3.0], [3.0, 4.0, 7.0], [5.0, 6.0, 11.0]],
+    /// );
+    /// ```
+    #[inline]
+    pub fn mul<const N: usize, const M: usize, const P: usize>(
+        a: [[f64; M]; N],
+        b: [[f64; P]; M],
+    ) -> [[f64; P]; N] {
+        std::array::from_fn(|i| std::array::from_fn(|j| (0..M).map(|k| a[i][k] * b[k][j]).sum()))
+    }
+
+    /// Product of a `N×M` matrix by a vector of size `M`.
+    #[inline]
+    pub fn mul_vec<const N: usize, const M: usize>(a: [[f64; M]; N], v: [f64; M]) -> [f64; N] {
+        a.map(|row| super::vector::dot(row, v))
+    }
+
+    /// Transpose of a `N×M` matrix.
+    #[inline]
+    pub fn transpose<const N: usize, const M: usize>(a: [[f64; M]; N]) -> [[f64; N]; M] {
+        std::array::from_fn(|i| std::array::from_fn(|j| a[j][i]))
+    }
+
+    /// Determinant of a 2×2 matrix.
+    #[inline]
+    pub fn det2(a: [[f64; 2]; 2]) -> f64 {
+        a[0][0] * a[1][1] - a[0][1] * a[1][0]
+    }
+
+    /// Determinant of a 3×3 matrix.
+    #[inline]
+    pub fn det3(a: [[f64; 3]; 3]) -> f64 {
+        a[0][0] * (a[1][1] * a[2][2] - a[1][2] * a[2][1])
+            - a[0][1] * (a[1][0] * a[2][2] - a[1][2] * a[2][0])
+            + a[0][2] * (a[1][0] * a[2][1] - a[1][1] * a[2][0])
+    }
+
+    /// Inverse of a 2×2 matrix.
+    ///
+    /// The inverse of a singular matrix has non-finite coefficients.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use grust_std::maths::matrix;
+    ///
+    /// assert_eq!(
+    ///     matrix::inverse2([[2.0, 1.0], [0.0, 4.0]]),
+    ///     [[0.5, -0.125], [0.0, 0.25]],
+    /// );
+    /// assert!(matrix::inverse2([[1.0, 2.0], [2.0, 4.0]])[0][0].is_infinite());
+    /// ```
+    #[inline]
+    pub fn inverse2(a: [[f64; 2]; 2]) -> [[f64; 2]; 2] {
+        let inv_det = 1. / det2(a);
+        [
+            [a[1][1] * inv_det, -a[0][1] * inv_det],
+            [-a[1][0] * inv_det, a[0][0] * inv_det],
+        ]
+    }
+
+    /// Inverse of a 3×3 matrix.
+    ///
+    /// The inverse of a singular matrix has non-finite coefficients.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use grust_std::maths::matrix;
+    ///
+    /// let a = [[2.0, 0.0, 0.0], [0.0, 0.0, 4.0], [0.0, 1.0, 0.0]];
+    /// assert_eq!(
+    ///     matrix::inverse3(a),
+    ///     [[0.5, 0.0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.25, 0.0]],
+    /// );
+    /// ```
+    #[inline]
+    pub fn inverse3(a: [[f64; 3]; 3]) -> [[f64; 3]; 3] {
+        let inv_det = 1. / det3(a);
+        // transpose of the cofactor matrix, divided by the determinant
+        std::array::from_fn(|i| {
+            std::array::from_fn(|j| {
+                let (r0, r1) = ((j + 1) % 3, (j + 2) % 3);
+                let (c0, c1) = ((i + 1) % 3, (i + 2) % 3);
+                (a[r0][c0] * a[r1][c1] - a[r0][c1] * a[r1][c0]) * inv_det
+            })
+        })
+    }
+}
+
+/// Quaternions as arrays `[w, x, y, z]` of type `[float; 4]`.
+///
+/// Rotations are represented by unit quaternions, vectors by arrays `[float; 3]`.
+/// Used in `grust!` macro via `use function`, for instance
+/// `use function grust::std::maths::quaternion::rotate(q: [float; 4], v: [float; 3]) -> [float; 3];`.
+pub mod quaternion {
+    /// Hamilton product of two quaternions.
+    ///
+    /// The rotation `mul(p, q)` is the rotation `q` followed by `p`.
+    #[inline]
+    pub fn mul(p: [f64; 4], q: [f64; 4]) -> [f64; 4] {
+        let [pw, px, py, pz] = p;
+        let [qw, qx, qy, qz] = q;
+        [
+            pw * qw - px * qx - py * qy - pz * qz,
+            pw * qx + px * qw + py * qz - pz * qy,
+            pw * qy - px * qz + py * qw + pz * qx,
+            pw * qz + px * qy - py * qx + pz * qw,
+        ]
+    }
+
+    /// Conjugate of a quaternion, the inverse rotation of a unit quaternion.
+    #[inline]
+    pub fn conjugate(q: [f64; 4]) -> [f64; 4] {
+        [q[0], -q[1], -q[2], -q[3]]
+    }
+
+    /// Unit quaternion with the direction of `q`.
+    ///
+    /// The null quaternion is returned unchanged.
+    #[inline]
+    pub fn normalize(q: [f64; 4]) -> [f64; 4] {
+        super::vector::normalize(q)
+    }
+
+    /// Rotation of `angle` radians around `axis`.
+    ///
+    /// The axis does not need to be normalized.
+    #[inline]
+    pub fn from_axis_angle(axis: [f64; 3], angle: f64) -> [f64; 4] {
+        let [x, y, z] = super::vector::normalize(axis);
+        let (sin, cos) = (angle / 2.).sin_cos();
+        [cos, sin * x, sin * y, sin * z]
+    }
+
+    /// Rotation of vector `v` by the unit quaternion `q`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use grust_std::maths::{quaternion, vector};
+    ///
+    /// let quarter_turn = quaternion::from_axis_angle([0.0, 0.0, 1.0], std::f64::consts::FRAC_PI_2);
+    /// let v = quaternion::rotate(quarter_turn, [1.0, 0.0, 0.0]);
+    /// assert!(vector::distance(v, [0.0, 1.0, 0.0]) < 1e-10);
+    /// ```
+    #[inline]
+    pub fn rotate(q: [f64; 4], v: [f64; 3]) -> [f64; 3] {
+        let [_, x, y, z] = mul(mul(q, [0., v[0], v[1], v[2]]), conjugate(q));
+        [x, y, z]
+    }
+
+    /// Rotation matrix of the unit quaternion `q`.
+    #[inline]
+    pub fn to_matrix(q: [f64; 4]) -> [[f64; 3]; 3] {
+        let [w, x, y, z] = q;
+        [
+            [
+                1. - 2. * (y * y + z * z),
+                2. * (x * y - w * z),
+                2. * (x * z + w * y),
+            ],
+            [
+                2. * (x * y + w * z),
+                1. - 2. * (x * x + z * z),
+                2. * (y * z - w * x),
+            ],
+            [
+                2. * (x * z - w * y),
+                2. * (y * z + w * x),
+                1. - 2. * (x * x + y * y),
+            ],
+        ]
+    }
+}
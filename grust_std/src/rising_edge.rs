@@ -1,3 +1,8 @@
+//! Standalone rising edge detector.
+//!
+//! See [crate::logic::RisingEdgeState] for the component implementing [grust_core::Component], usable
+//! with `use component`.
+
 pub struct RisingEdgeInput {
     pub test: bool,
 }